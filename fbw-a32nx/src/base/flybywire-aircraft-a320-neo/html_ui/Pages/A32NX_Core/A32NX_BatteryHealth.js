@@ -0,0 +1,34 @@
+// Keeps the battery state of health computed by the systems from one session to the next.
+// The stored value is handed back to the systems once on load, which then take it over.
+
+const BATTERY_NUMBERS = [1, 2];
+
+class A32NX_BatteryHealth {
+    init() {
+        this.lastStoredStateOfHealth = {};
+
+        BATTERY_NUMBERS.forEach((number) => {
+            const storedStateOfHealth = NXDataStore.get(`ELEC_BAT_${number}_STATE_OF_HEALTH`);
+            if (storedStateOfHealth !== undefined) {
+                this.lastStoredStateOfHealth[number] = storedStateOfHealth;
+                SimVar.SetSimVarValue(`L:A32NX_ELEC_BAT_${number}_STORED_STATE_OF_HEALTH`, "percent", parseFloat(storedStateOfHealth));
+            }
+        });
+    }
+
+    update(_deltaTime, _core) {
+        BATTERY_NUMBERS.forEach((number) => {
+            const stateOfHealth = SimVar.GetSimVarValue(`L:A32NX_ELEC_BAT_${number}_STATE_OF_HEALTH`, "percent");
+            // Zero until the systems are running
+            if (stateOfHealth <= 0) {
+                return;
+            }
+
+            const stateOfHealthToStore = stateOfHealth.toFixed(2);
+            if (stateOfHealthToStore !== this.lastStoredStateOfHealth[number]) {
+                NXDataStore.set(`ELEC_BAT_${number}_STATE_OF_HEALTH`, stateOfHealthToStore);
+                this.lastStoredStateOfHealth[number] = stateOfHealthToStore;
+            }
+        });
+    }
+}
@@ -18,11 +18,6 @@ class A32NX_Core {
                 module: new A32NX_BaroSelector(),
                 updateInterval: 300,
             },
-            {
-                name: 'BrakeTemp',
-                module: new A32NX_BrakeTemp(),
-                updateInterval: 150,
-            },
             {
                 name: 'Refuel',
                 module: new A32NX_Refuel(),
@@ -57,6 +52,11 @@ class A32NX_Core {
                 name: 'Speeds',
                 module: new A32NX_Speeds(),
                 updateInterval: 500,
+            },
+            {
+                name: 'BatteryHealth',
+                module: new A32NX_BatteryHealth(),
+                updateInterval: 10000,
             }
         ];
         this.moduleThrottlers = {};
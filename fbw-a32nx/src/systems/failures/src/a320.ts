@@ -6,6 +6,12 @@ export const A320Failure = Object.freeze({
     TransformerRectifier1: 24000,
     TransformerRectifier2: 24001,
     TransformerRectifierEssential: 24002,
+    Battery1ThermalRunaway: 24003,
+    Battery2ThermalRunaway: 24004,
+    ExternalPowerOverVoltage: 24005,
+    ExternalPowerUnderVoltage: 24006,
+    ExternalPowerWrongPhaseRotation: 24007,
+    ExternalPowerFrequencyDeviation: 24008,
     Elac1Failure: 27000,
     Elac2Failure: 27001,
     Sec1Failure: 27002,
@@ -27,6 +33,29 @@ export const A320Failure = Object.freeze({
     BlueEpumpOverheat: 29010,
     YellowEdpOverheat: 29011,
     YellowEpumpOverheat: 29012,
+    GreenFluidContamination: 29013,
+    BlueFluidContamination: 29014,
+    YellowFluidContamination: 29015,
+    PtuShaftShear: 29016,
+    PtuInternalLeakage: 29017,
+    PtuJam: 29018,
+    PtuBarkThresholdDrift: 29019,
+
+    CaptPitotHeat: 30000,
+    CaptStaticPortHeat: 30001,
+    CaptAoaProbeHeat: 30002,
+    CaptTatProbeHeat: 30003,
+    CaptWindshieldHeat: 30004,
+    CaptSideWindowHeat: 30005,
+    FoPitotHeat: 30010,
+    FoStaticPortHeat: 30011,
+    FoAoaProbeHeat: 30012,
+    FoTatProbeHeat: 30013,
+    FoWindshieldHeat: 30014,
+    FoSideWindowHeat: 30015,
+    StbyPitotHeat: 30020,
+    StbyStaticPortHeat: 30021,
+    StbyAoaProbeHeat: 30022,
 
     LeftPfdDisplay: 31000,
     RightPfdDisplay: 31001,
@@ -61,6 +90,24 @@ export const A320Failure = Object.freeze({
     YellowBrakeHydraulicLeak: 32101,
     YellowBrakeAccumulatorGasLeak: 32150,
 
+    TyreBurst1: 32200,
+    TyreBurst2: 32201,
+    TyreBurst3: 32202,
+    TyreBurst4: 32203,
+    TyreDeflation1: 32210,
+    TyreDeflation2: 32211,
+    TyreDeflation3: 32212,
+    TyreDeflation4: 32213,
+    BrakeSeizure1: 32220,
+    BrakeSeizure2: 32221,
+    BrakeSeizure3: 32222,
+    BrakeSeizure4: 32223,
+
     RadioAltimeter1: 34000,
     RadioAltimeter2: 34001,
+
+    Eng1OilLeak: 79001,
+    Eng2OilLeak: 79002,
+    Eng1OilPumpFailure: 79011,
+    Eng2OilPumpFailure: 79012,
 });
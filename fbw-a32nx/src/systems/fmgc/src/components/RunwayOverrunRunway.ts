@@ -0,0 +1,43 @@
+import { FlightPlans } from '@fmgc/flightplanning/FlightPlanManager';
+import { FlightPlanManager } from '@fmgc/wtsdk';
+import { FmgcComponent } from './FmgcComponent';
+
+/**
+ * Supplies the destination runway of the active flight plan to the runway overrun warning and protection.
+ * The runway condition is not known to the flight management and is left to its default of dry.
+ */
+export class RunwayOverrunRunway implements FmgcComponent {
+    private flightPlanManager: FlightPlanManager;
+
+    private updateThrottler = new A32NX_Util.UpdateThrottler(1000);
+
+    private lastRunwayIdent: string;
+
+    init(_baseInstrument: BaseInstrument, flightPlanManager: FlightPlanManager): void {
+        this.flightPlanManager = flightPlanManager;
+    }
+
+    update(deltaTime: number): void {
+        if (this.updateThrottler.canUpdate(deltaTime) === -1) {
+            return;
+        }
+
+        const runway = this.flightPlanManager.getDestinationRunway(FlightPlans.Active);
+        const runwayIdent = runway ? `${this.flightPlanManager.getDestination(FlightPlans.Active)?.ident}${runway.designation}` : undefined;
+        if (runwayIdent === this.lastRunwayIdent) {
+            return;
+        }
+        this.lastRunwayIdent = runwayIdent;
+
+        if (!runway) {
+            SimVar.SetSimVarValue('L:A32NX_ROW_ROP_RUNWAY_LENGTH', 'number', 0);
+            return;
+        }
+
+        // The landing distance available starts at the displaced threshold
+        SimVar.SetSimVarValue('L:A32NX_ROW_ROP_THRESHOLD_LATITUDE', 'degrees', runway.thresholdCoordinates.lat);
+        SimVar.SetSimVarValue('L:A32NX_ROW_ROP_THRESHOLD_LONGITUDE', 'degrees', runway.thresholdCoordinates.long);
+        SimVar.SetSimVarValue('L:A32NX_ROW_ROP_RUNWAY_HEADING', 'degrees', runway.direction);
+        SimVar.SetSimVarValue('L:A32NX_ROW_ROP_RUNWAY_LENGTH', 'number', runway.length - runway.thresholdLength);
+    }
+}
@@ -4,6 +4,7 @@ import { FlightPlanManager } from '@fmgc/wtsdk';
 import { EfisLabels } from './EfisLabels';
 import { FmgcComponent } from './FmgcComponent';
 import { FmsMessages } from './fms-messages';
+import { RunwayOverrunRunway } from './RunwayOverrunRunway';
 
 const fmsMessages = new FmsMessages();
 
@@ -12,6 +13,7 @@ const components: FmgcComponent[] = [
     new EfisLabels(),
     new ReadySignal(),
     new FcuSync(),
+    new RunwayOverrunRunway(),
 ];
 
 export function initComponents(baseInstrument: BaseInstrument, flightPlanManager: FlightPlanManager): void {
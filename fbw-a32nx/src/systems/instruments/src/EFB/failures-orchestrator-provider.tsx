@@ -17,6 +17,12 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [24, A320Failure.TransformerRectifier1, 'TR 1'],
     [24, A320Failure.TransformerRectifier2, 'TR 2'],
     [24, A320Failure.TransformerRectifierEssential, 'ESS TR'],
+    [24, A320Failure.Battery1ThermalRunaway, 'BAT 1 thermal runaway'],
+    [24, A320Failure.Battery2ThermalRunaway, 'BAT 2 thermal runaway'],
+    [24, A320Failure.ExternalPowerOverVoltage, 'EXT PWR over voltage'],
+    [24, A320Failure.ExternalPowerUnderVoltage, 'EXT PWR under voltage'],
+    [24, A320Failure.ExternalPowerWrongPhaseRotation, 'EXT PWR wrong phase rotation'],
+    [24, A320Failure.ExternalPowerFrequencyDeviation, 'EXT PWR frequency deviation'],
 
     [27, A320Failure.Elac1Failure, 'ELAC 1'],
     [27, A320Failure.Elac2Failure, 'ELAC 2'],
@@ -39,6 +45,29 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [29, A320Failure.BlueEpumpOverheat, 'Blue electric pump overheat'],
     [29, A320Failure.YellowEdpOverheat, 'Yellow engine pump overheat'],
     [29, A320Failure.YellowEpumpOverheat, 'Yellow electric pump overheat'],
+    [29, A320Failure.GreenFluidContamination, 'Green fluid contamination'],
+    [29, A320Failure.BlueFluidContamination, 'Blue fluid contamination'],
+    [29, A320Failure.YellowFluidContamination, 'Yellow fluid contamination'],
+    [29, A320Failure.PtuShaftShear, 'PTU shaft shear'],
+    [29, A320Failure.PtuInternalLeakage, 'PTU internal leakage'],
+    [29, A320Failure.PtuJam, 'PTU jam'],
+    [29, A320Failure.PtuBarkThresholdDrift, 'PTU activation threshold drift'],
+
+    [30, A320Failure.CaptPitotHeat, 'CAPT pitot heat'],
+    [30, A320Failure.CaptStaticPortHeat, 'CAPT static port heat'],
+    [30, A320Failure.CaptAoaProbeHeat, 'CAPT AOA probe heat'],
+    [30, A320Failure.CaptTatProbeHeat, 'CAPT TAT probe heat'],
+    [30, A320Failure.CaptWindshieldHeat, 'CAPT windshield heat'],
+    [30, A320Failure.CaptSideWindowHeat, 'CAPT side window heat'],
+    [30, A320Failure.FoPitotHeat, 'F/O pitot heat'],
+    [30, A320Failure.FoStaticPortHeat, 'F/O static port heat'],
+    [30, A320Failure.FoAoaProbeHeat, 'F/O AOA probe heat'],
+    [30, A320Failure.FoTatProbeHeat, 'F/O TAT probe heat'],
+    [30, A320Failure.FoWindshieldHeat, 'F/O windshield heat'],
+    [30, A320Failure.FoSideWindowHeat, 'F/O side window heat'],
+    [30, A320Failure.StbyPitotHeat, 'STBY pitot heat'],
+    [30, A320Failure.StbyStaticPortHeat, 'STBY static port heat'],
+    [30, A320Failure.StbyAoaProbeHeat, 'STBY AOA probe heat'],
 
     [31, A320Failure.LeftPfdDisplay, 'Captain PFD display'],
     [31, A320Failure.RightPfdDisplay, 'F/O PFD display'],
@@ -72,8 +101,26 @@ const createOrchestrator = () => new FailuresOrchestrator('A32NX', [
     [32, A320Failure.YellowBrakeHydraulicLeak, 'Yellow brakes circuit leak'],
     [32, A320Failure.YellowBrakeAccumulatorGasLeak, 'Yellow brake accumulator gas leak'],
 
+    [32, A320Failure.TyreBurst1, 'Tyre 1 burst'],
+    [32, A320Failure.TyreBurst2, 'Tyre 2 burst'],
+    [32, A320Failure.TyreBurst3, 'Tyre 3 burst'],
+    [32, A320Failure.TyreBurst4, 'Tyre 4 burst'],
+    [32, A320Failure.TyreDeflation1, 'Tyre 1 deflation'],
+    [32, A320Failure.TyreDeflation2, 'Tyre 2 deflation'],
+    [32, A320Failure.TyreDeflation3, 'Tyre 3 deflation'],
+    [32, A320Failure.TyreDeflation4, 'Tyre 4 deflation'],
+    [32, A320Failure.BrakeSeizure1, 'Brake 1 seizure'],
+    [32, A320Failure.BrakeSeizure2, 'Brake 2 seizure'],
+    [32, A320Failure.BrakeSeizure3, 'Brake 3 seizure'],
+    [32, A320Failure.BrakeSeizure4, 'Brake 4 seizure'],
+
     [34, A320Failure.RadioAltimeter1, 'RA 1'],
     [34, A320Failure.RadioAltimeter2, 'RA 2'],
+
+    [79, A320Failure.Eng1OilLeak, 'Engine 1 oil leak'],
+    [79, A320Failure.Eng2OilLeak, 'Engine 2 oil leak'],
+    [79, A320Failure.Eng1OilPumpFailure, 'Engine 1 oil pump'],
+    [79, A320Failure.Eng2OilPumpFailure, 'Engine 2 oil pump'],
 ]);
 
 const Context = React.createContext<FailuresOrchestratorContext>({
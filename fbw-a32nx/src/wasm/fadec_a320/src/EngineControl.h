@@ -46,6 +46,7 @@ class EngineControl {
   double simOnGround;
   double devState;
   double isReady;
+  bool isFuelConfigurationRestored = false;
 
   int engine;
   int egtImbalance;
@@ -66,12 +67,6 @@ class EngineControl {
   double simN2LeftPre;
   double simN2RightPre;
   double deltaN2;
-  double thermalEnergy1;
-  double thermalEnergy2;
-  double oilTemperature;
-  double oilTemperatureLeftPre;
-  double oilTemperatureRightPre;
-  double oilTemperatureMax;
   double idleN1;
   double idleN2;
   double idleFF;
@@ -88,7 +83,8 @@ class EngineControl {
 
   const double LBS_TO_KGS = 0.4535934;
   const double KGS_TO_LBS = 1 / 0.4535934;
-  const double FUEL_THRESHOLD = 661;  // lbs/sec
+  // EngineStartPhase::Aborted of the systems WASM FADEC
+  const double START_PHASE_ABORTED = 5;
 
   bool isFlexActive = false;
   double prevThrustLimitType = 0;
@@ -176,6 +172,11 @@ class EngineControl {
     }
   }
 
+  /// <summary>
+  /// Phase of the start sequence run by the FADEC in the systems WASM
+  /// </summary>
+  double engineStartPhase(int engine) { return engine == 1 ? simVars->getEngine1StartPhase() : simVars->getEngine2StartPhase(); }
+
   /// <summary>
   /// Engine State Machine
   /// 0 - Engine OFF, 1 - Engine ON, 2 - Engine Starting, 3 - Engine Re-starting & 4 - Engine Shutting
@@ -345,7 +346,12 @@ class EngineControl {
 
         simVars->setEngine1N2(newN2Fbw);
         simVars->setEngine1N1(poly->startN1(newN2Fbw, idleN2 - n2Imbalance, idleN1));
-        simVars->setEngine1FF(poly->startFF(newN2Fbw, idleN2 - n2Imbalance, idleFF - ffImbalance));
+        // The engine only lights up once the FADEC start sequencer commanded fuel on
+        if (simVars->getEngine1FuelCommandedOn()) {
+          simVars->setEngine1FF(poly->startFF(newN2Fbw, idleN2 - n2Imbalance, idleFF - ffImbalance));
+        } else {
+          simVars->setEngine1FF(0);
+        }
 
         if (engineState == 3) {
           if (abs(startEgtFbw - preEgtFbw) <= 1.5) {
@@ -359,11 +365,6 @@ class EngineControl {
         } else {
           simVars->setEngine1EGT(startEgtFbw);
         }
-
-        oilTemperature = poly->startOilTemp(newN2Fbw, idleN2, ambientTemp);
-        oilTemperatureLeftPre = oilTemperature;
-        SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempLeft, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                      &oilTemperature);
       }
     } else {
       if (timer < 1.7) {
@@ -383,7 +384,12 @@ class EngineControl {
 
         simVars->setEngine2N2(newN2Fbw);
         simVars->setEngine2N1(poly->startN1(newN2Fbw, idleN2 - n2Imbalance, idleN1));
-        simVars->setEngine2FF(poly->startFF(newN2Fbw, idleN2 - n2Imbalance, idleFF - ffImbalance));
+        // The engine only lights up once the FADEC start sequencer commanded fuel on
+        if (simVars->getEngine2FuelCommandedOn()) {
+          simVars->setEngine2FF(poly->startFF(newN2Fbw, idleN2 - n2Imbalance, idleFF - ffImbalance));
+        } else {
+          simVars->setEngine2FF(0);
+        }
 
         if (engineState == 3) {
           if (abs(startEgtFbw - preEgtFbw) <= 1.5) {
@@ -397,11 +403,6 @@ class EngineControl {
         } else {
           simVars->setEngine2EGT(startEgtFbw);
         }
-
-        oilTemperature = poly->startOilTemp(newN2Fbw, idleN2, ambientTemp);
-        oilTemperatureRightPre = oilTemperature;
-        SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempRight, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                      &oilTemperature);
       }
     }
   }
@@ -566,101 +567,35 @@ class EngineControl {
 
   /// <summary>
   /// FBW Oil Qty, Pressure and Temperature (in Quarts, PSI and degree Celsius)
-  /// Updates Oil with realistic values visualized in the SD
+  /// The lubrication system is simulated by the Rust systems, its outputs are visualized in the SD
   /// </summary>
-  void updateOil(int engine, double imbalance, double thrust, double simN2, double deltaN2, double deltaTime, double ambientTemp) {
-    double steadyTemperature;
-    double thermalEnergy;
-    double oilTemperaturePre;
-    double oilQtyActual;
-    double oilTotalActual;
-    double oilQtyObjective;
-    double oilBurn;
-    double oilIdleRandom;
+  void updateOil(int engine) {
+    double oilQuantity;
     double oilPressure;
+    double oilTemp;
 
-    //--------------------------------------------
-    // Engine Reading
-    //--------------------------------------------
-    if (engine == 1) {
-      steadyTemperature = simVars->getEngine1EGT();
-      thermalEnergy = thermalEnergy1;
-      oilTemperaturePre = oilTemperatureLeftPre;
-      oilQtyActual = simVars->getEngine1Oil();
-      oilTotalActual = simVars->getEngine1TotalOil();
-    } else {
-      steadyTemperature = simVars->getEngine2EGT();
-      thermalEnergy = thermalEnergy2;
-      oilTemperaturePre = oilTemperatureRightPre;
-      oilQtyActual = simVars->getEngine2Oil();
-      oilTotalActual = simVars->getEngine2TotalOil();
-    }
-
-    //--------------------------------------------
-    // Oil Temperature
-    //--------------------------------------------
-    if (simOnGround == 1 && engineState == 0 && ambientTemp > oilTemperaturePre - 10) {
-      oilTemperature = ambientTemp;
-    } else {
-      if (steadyTemperature > oilTemperatureMax) {
-        steadyTemperature = oilTemperatureMax;
-      }
-      thermalEnergy = (0.995 * thermalEnergy) + (deltaN2 / deltaTime);
-      oilTemperature = poly->oilTemperature(thermalEnergy, oilTemperaturePre, steadyTemperature, deltaTime);
-    }
-
-    //--------------------------------------------
-    // Oil Quantity
-    //--------------------------------------------
-    // Calculating Oil Qty as a function of thrust
-    oilQtyObjective = oilTotalActual * (1 - poly->oilGulpPct(thrust));
-    oilQtyActual = oilQtyActual - (oilTemperature - oilTemperaturePre);
-
-    // Oil burnt taken into account for tank and total oil
-    oilBurn = (0.00011111 * deltaTime);
-    oilQtyActual = oilQtyActual - oilBurn;
-    oilTotalActual = oilTotalActual - oilBurn;
-
-    //--------------------------------------------
-    // Oil Pressure
-    //--------------------------------------------
-    // Engine imbalance
-    engineImbalanced = imbalanceExtractor(imbalance, 1);
-    paramImbalance = imbalanceExtractor(imbalance, 6) / 10;
-    oilIdleRandom = imbalanceExtractor(imbalance, 7) - 6;
-
-    // Checking engine imbalance
-    if (engineImbalanced != engine) {
-      paramImbalance = 0;
-    }
-
-    oilPressure = poly->oilPressure(simN2) - paramImbalance + oilIdleRandom;
-
-    //--------------------------------------------
-    // Engine Writing
-    //--------------------------------------------
     if (engine == 1) {
-      thermalEnergy1 = thermalEnergy;
-      oilTemperatureLeftPre = oilTemperature;
-      simVars->setEngine1Oil(oilQtyActual);
-      simVars->setEngine1TotalOil(oilTotalActual);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempLeft, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &oilTemperature);
+      oilQuantity = simVars->getEngine1OilQuantity();
+      oilPressure = simVars->getEngine1OilPressure();
+      oilTemp = simVars->getEngine1OilTemperature();
+      simVars->setEngine1Oil(oilQuantity);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempLeft, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilTemp);
       SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilPsiLeft, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilPressure);
     } else {
-      thermalEnergy2 = thermalEnergy;
-      oilTemperatureRightPre = oilTemperature;
-      simVars->setEngine2Oil(oilQtyActual);
-      simVars->setEngine2TotalOil(oilTotalActual);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempRight, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &oilTemperature);
+      oilQuantity = simVars->getEngine2OilQuantity();
+      oilPressure = simVars->getEngine2OilPressure();
+      oilTemp = simVars->getEngine2OilTemperature();
+      simVars->setEngine2Oil(oilQuantity);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempRight, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilTemp);
       SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilPsiRight, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilPressure);
     }
   }
 
   /// <summary>
-  /// FBW Fuel Consumption and Tankering
-  /// Updates Fuel Consumption with realistic values
+  /// FBW Fuel Consumption
+  /// The tank quantities are owned by the fuel system of the Rust systems, which burns the engine fuel flow
+  /// from the feeding tanks and writes the quantities to the simulator. This only accumulates the fuel used,
+  /// restores the saved quantities once and saves the current quantities.
   /// </summary>
   void updateFuel(double deltaTime) {
     double m = 0;
@@ -668,24 +603,8 @@ class EngineControl {
     double fuelBurn1 = 0;
     double fuelBurn2 = 0;
 
-    double refuelRate = simVars->getRefuelRate();
-    double refuelStartedByUser = simVars->getRefuelStartedByUser();
-    bool uiFuelTamper = false;
     double pumpStateLeft = simVars->getPumpStateLeft();
     double pumpStateRight = simVars->getPumpStateRight();
-    bool xfrCenterLeftManual = simVars->getJunctionSetting(4) > 1.5;
-    bool xfrCenterRightManual = simVars->getJunctionSetting(5) > 1.5;
-    bool xfrCenterLeftAuto = simVars->getValve(11) > 0.0 && !xfrCenterLeftManual;
-    bool xfrCenterRightAuto = simVars->getValve(12) > 0.0 && !xfrCenterRightManual;
-    bool xfrValveCenterLeftOpen = simVars->getValve(9) > 0.0 && (xfrCenterLeftAuto || xfrCenterLeftManual);
-    bool xfrValveCenterRightOpen = simVars->getValve(10) > 0.0 && (xfrCenterRightAuto || xfrCenterRightManual);
-    double xfrValveOuterLeft1 = simVars->getValve(6);
-    double xfrValveOuterLeft2 = simVars->getValve(4);
-    double xfrValveOuterRight1 = simVars->getValve(7);
-    double xfrValveOuterRight2 = simVars->getValve(5);
-    double lineLeftToCenterFlow = simVars->getLineFlow(27);
-    double lineRightToCenterFlow = simVars->getLineFlow(28);
-    double lineFlowRatio = 0;
 
     double engine1PreFF = simVars->getEngine1PreFF();  // KG/H
     double engine2PreFF = simVars->getEngine2PreFF();  // KG/H
@@ -698,41 +617,38 @@ class EngineControl {
 
     double fuelLeftPre = simVars->getFuelLeftPre();                                // LBS
     double fuelRightPre = simVars->getFuelRightPre();                              // LBS
-    double fuelAuxLeftPre = simVars->getFuelAuxLeftPre();                          // LBS
-    double fuelAuxRightPre = simVars->getFuelAuxRightPre();                        // LBS
-    double fuelCenterPre = simVars->getFuelCenterPre();                            // LBS
     double leftQuantity = simVars->getFuelTankQuantity(2) * fuelWeightGallon;      // LBS
     double rightQuantity = simVars->getFuelTankQuantity(3) * fuelWeightGallon;     // LBS
     double leftAuxQuantity = simVars->getFuelTankQuantity(4) * fuelWeightGallon;   // LBS
     double rightAuxQuantity = simVars->getFuelTankQuantity(5) * fuelWeightGallon;  // LBS
     double centerQuantity = simVars->getFuelTankQuantity(1) * fuelWeightGallon;    // LBS
-    double fuelLeft = 0;                                                           // LBS
-    double fuelRight = 0;
-    double fuelLeftAux = 0;
-    double fuelRightAux = 0;
-    double fuelCenter = 0;
-    double xfrCenterToLeft = 0;
-    double xfrCenterToRight = 0;
-    double xfrAuxLeft = 0;
-    double xfrAuxRight = 0;
-    double fuelTotalActual = leftQuantity + rightQuantity + leftAuxQuantity + rightAuxQuantity + centerQuantity;  // LBS
-    double fuelTotalPre = fuelLeftPre + fuelRightPre + fuelAuxLeftPre + fuelAuxRightPre + fuelCenterPre;          // LBS
-    double deltaFuelRate = abs(fuelTotalActual - fuelTotalPre) / (fuelWeightGallon * deltaTime);                  // LBS/ sec
 
     double engine1State = simVars->getEngine1State();
     double engine2State = simVars->getEngine2State();
 
-    int isTankClosed = 0;
-    double xFeedValve = simVars->getValve(3);
-    double leftPump1 = simVars->getPump(2);
-    double leftPump2 = simVars->getPump(5);
-    double rightPump1 = simVars->getPump(3);
-    double rightPump2 = simVars->getPump(6);
-
     // Check Ready & Development State for UI
     isReady = simVars->getIsReady();
     devState = simVars->getDeveloperState();
 
+    // The saved quantities are handed to the simulator once. The fuel system of the Rust systems
+    // takes them over from there.
+    if (!isFuelConfigurationRestored && isReady == 1) {
+      double fuelLeft = fuelLeftPre / fuelWeightGallon;                        // USG
+      double fuelRight = fuelRightPre / fuelWeightGallon;                      // USG
+      double fuelCenter = simVars->getFuelCenterPre() / fuelWeightGallon;      // USG
+      double fuelLeftAux = simVars->getFuelAuxLeftPre() / fuelWeightGallon;    // USG
+      double fuelRightAux = simVars->getFuelAuxRightPre() / fuelWeightGallon;  // USG
+
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelCenterMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelCenter);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelLeftMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelLeft);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelRightMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelRight);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelLeftAux, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelLeftAux);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelRightAux, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelRightAux);
+
+      isFuelConfigurationRestored = true;
+      return;
+    }
+
     deltaTime = deltaTime / 3600;
 
     // Pump State Logic for Left Wing
@@ -773,160 +689,35 @@ class EngineControl {
       timerRight.reset();
     }
 
-    // Checking for in-game UI Fuel tampering
-    if ((isReady == 1 && refuelStartedByUser == 0 && deltaFuelRate > FUEL_THRESHOLD) ||
-        (isReady == 1 && refuelStartedByUser == 1 && deltaFuelRate > FUEL_THRESHOLD && refuelRate < 2)) {
-      uiFuelTamper = true;
-    }
-
-    if (simPaused || uiFuelTamper && devState == 0) {  // Detects whether the Sim is paused or the Fuel UI is being tampered with
-      simVars->setFuelLeftPre(fuelLeftPre);            // in LBS
-      simVars->setFuelRightPre(fuelRightPre);          // in LBS
-      simVars->setFuelAuxLeftPre(fuelAuxLeftPre);      // in LBS
-      simVars->setFuelAuxRightPre(fuelAuxRightPre);    // in LBS
-      simVars->setFuelCenterPre(fuelCenterPre);        // in LBS
-
-      fuelLeft = (fuelLeftPre / fuelWeightGallon);          // USG
-      fuelRight = (fuelRightPre / fuelWeightGallon);        // USG
-      fuelCenter = (fuelCenterPre / fuelWeightGallon);      // USG
-      fuelLeftAux = (fuelAuxLeftPre / fuelWeightGallon);    // USG
-      fuelRightAux = (fuelAuxRightPre / fuelWeightGallon);  // USG
-
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelCenterMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelCenter);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelLeftMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelLeft);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelRightMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelRight);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelLeftAux, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelLeftAux);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelRightAux, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelRightAux);
-    } else if (!uiFuelTamper && refuelStartedByUser == 1) {  // Detects refueling from the EFB
-      simVars->setFuelLeftPre(leftQuantity);                 // in LBS
-      simVars->setFuelRightPre(rightQuantity);               // in LBS
-      simVars->setFuelAuxLeftPre(leftAuxQuantity);           // in LBS
-      simVars->setFuelAuxRightPre(rightAuxQuantity);         // in LBS
-      simVars->setFuelCenterPre(centerQuantity);             // in LBS
-    } else {
-      if (uiFuelTamper == 1) {
-        fuelLeftPre = leftQuantity;          // LBS
-        fuelRightPre = rightQuantity;        // LBS
-        fuelAuxLeftPre = leftAuxQuantity;    // LBS
-        fuelAuxRightPre = rightAuxQuantity;  // LBS
-        fuelCenterPre = centerQuantity;      // LBS
-      }
-      //-----------------------------------------------------------
-      // Cross-feed Logic
-      // isTankClosed = 0, both tanks can supply fuel
-      // isTankClosed = 1, left tank does not supply fuel
-      // isTankClosed = 2, right tank does not supply fuel
-      // isTankClosed = 3, left & right tanks do not supply fuel
-      if (xFeedValve > 0.0) {
-        if (leftPump1 == 0 && leftPump2 == 0)
-          isTankClosed = 1;
-        if (rightPump1 == 0 && rightPump2 == 0)
-          isTankClosed = 2;
-        if (leftPump1 == 0 && leftPump2 == 0 && rightPump1 == 0 && rightPump2 == 0)
-          isTankClosed = 3;
-      }
-
+    if (!simPaused && devState != 2) {
       //--------------------------------------------
-      // Left Engine and Wing routine
-      if (fuelLeftPre > 0) {
-        // Cycle Fuel Burn for Engine 1
-        if (devState != 2) {
-          m = (engine1FF - engine1PreFF) / deltaTime;
-          b = engine1PreFF;
-          fuelBurn1 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
-        }
+      // Cycle Fuel Burn for Engine 1 and 2
+      m = (engine1FF - engine1PreFF) / deltaTime;
+      b = engine1PreFF;
+      fuelBurn1 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
 
-        // Fuel transfer routine for Left Wing
-        if (xfrValveOuterLeft1 > 0.0 || xfrValveOuterLeft2 > 0.0)
-          xfrAuxLeft = fuelAuxLeftPre - leftAuxQuantity;
-      } else {
-        fuelBurn1 = 0;
-        fuelLeftPre = 0;
-      }
-
-      //--------------------------------------------
-      // Right Engine and Wing routine
-      if (fuelRightPre > 0) {
-        // Cycle Fuel Burn for Engine 2
-        if (devState != 2) {
-          m = (engine2FF - engine2PreFF) / deltaTime;
-          b = engine2PreFF;
-          fuelBurn2 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
-        }
-        // Fuel transfer routine for Right Wing
-        if (xfrValveOuterRight1 > 0.0 || xfrValveOuterRight2 > 0.0)
-          xfrAuxRight = fuelAuxRightPre - rightAuxQuantity;
-      } else {
-        fuelBurn2 = 0;
-        fuelRightPre = 0;
-      }
+      m = (engine2FF - engine2PreFF) / deltaTime;
+      b = engine2PreFF;
+      fuelBurn2 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
 
       //--------------------------------------------
       // Fuel used accumulators
       fuelUsedLeft += fuelBurn1;
       fuelUsedRight += fuelBurn2;
-
-      //--------------------------------------------
-      // Cross-feed fuel burn routine
-      // If fuel pumps for a given tank are closed,
-      // all fuel will be burnt on the other tank
-      switch (isTankClosed) {
-        case 1:
-          fuelBurn2 = fuelBurn1 + fuelBurn2;
-          fuelBurn1 = 0;
-          break;
-        case 2:
-          fuelBurn1 = fuelBurn1 + fuelBurn2;
-          fuelBurn2 = 0;
-          break;
-        case 3:
-          fuelBurn1 = 0;
-          fuelBurn2 = 0;
-          break;
-        default:
-          break;
-      }
-
-      //--------------------------------------------
-      // Center Tank transfer routine
-      if (xfrValveCenterLeftOpen && xfrValveCenterRightOpen) {
-        if (lineLeftToCenterFlow < 0.1 && lineRightToCenterFlow < 0.1)
-          lineFlowRatio = 0.5;
-        else
-          lineFlowRatio = lineLeftToCenterFlow / (lineLeftToCenterFlow + lineRightToCenterFlow);
-
-        xfrCenterToLeft = (fuelCenterPre - centerQuantity) * lineFlowRatio;
-        xfrCenterToRight = (fuelCenterPre - centerQuantity) * (1 - lineFlowRatio);
-      } else if (xfrValveCenterLeftOpen)
-        xfrCenterToLeft = fuelCenterPre - centerQuantity;
-      else if (xfrValveCenterRightOpen)
-        xfrCenterToRight = fuelCenterPre - centerQuantity;
-
-      //--------------------------------------------
-      // Final Fuel levels for left and right inner tanks
-      fuelLeft = (fuelLeftPre - (fuelBurn1 * KGS_TO_LBS)) + xfrAuxLeft + xfrCenterToLeft;      // LBS
-      fuelRight = (fuelRightPre - (fuelBurn2 * KGS_TO_LBS)) + xfrAuxRight + xfrCenterToRight;  // LBS
-
-      //--------------------------------------------
-      // Setting new pre-cycle conditions
-      simVars->setEngine1PreFF(engine1FF);
-      simVars->setEngine2PreFF(engine2FF);
-      simVars->setFuelUsedLeft(fuelUsedLeft);         // in KG
-      simVars->setFuelUsedRight(fuelUsedRight);       // in KG
-      simVars->setFuelAuxLeftPre(leftAuxQuantity);    // in LBS
-      simVars->setFuelAuxRightPre(rightAuxQuantity);  // in LBS
-      simVars->setFuelCenterPre(centerQuantity);      // in LBS
-
-      simVars->setFuelLeftPre(fuelLeft);    // in LBS
-      simVars->setFuelRightPre(fuelRight);  // in LBS
-
-      fuelLeft = (fuelLeft / fuelWeightGallon);    // USG
-      fuelRight = (fuelRight / fuelWeightGallon);  // USG
-
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelLeftMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelLeft);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelRightMain, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelRight);
     }
 
+    //--------------------------------------------
+    // Setting new pre-cycle conditions
+    simVars->setEngine1PreFF(engine1FF);
+    simVars->setEngine2PreFF(engine2FF);
+    simVars->setFuelUsedLeft(fuelUsedLeft);         // in KG
+    simVars->setFuelUsedRight(fuelUsedRight);       // in KG
+    simVars->setFuelLeftPre(leftQuantity);          // in LBS
+    simVars->setFuelRightPre(rightQuantity);        // in LBS
+    simVars->setFuelAuxLeftPre(leftAuxQuantity);    // in LBS
+    simVars->setFuelAuxRightPre(rightAuxQuantity);  // in LBS
+    simVars->setFuelCenterPre(centerQuantity);      // in LBS
+
     //--------------------------------------------
     // Will save the current fuel quantities if on
     // the ground AND engines being shutdown
@@ -1103,30 +894,7 @@ class EngineControl {
       }
     }
 
-    // Setting initial Oil Temperature
-    thermalEnergy1 = 0;
-    thermalEnergy2 = 0;
-    oilTemperatureMax = imbalanceExtractor(imbalance, 8);
     simOnGround = simVars->getSimOnGround();
-    double engine1Combustion = simVars->getEngineCombustion(1);
-    double engine2Combustion = simVars->getEngineCombustion(2);
-
-    if (simOnGround == 1 && engine1Combustion == 1 && engine2Combustion == 1) {
-      oilTemperatureLeftPre = 75;
-      oilTemperatureRightPre = 75;
-    } else if (simOnGround == 0 && engine1Combustion == 1 && engine2Combustion == 1) {
-      oilTemperatureLeftPre = 85;
-      oilTemperatureRightPre = 85;
-
-    } else {
-      oilTemperatureLeftPre = ambientTemp;
-      oilTemperatureRightPre = ambientTemp;
-    }
-
-    SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempLeft, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                  &oilTemperatureLeftPre);
-    SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempRight, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                  &oilTemperatureRightPre);
 
     // Initialize Engine State
     simVars->setEngine1State(10);
@@ -1190,6 +958,10 @@ class EngineControl {
     // Timer timer;
     for (engine = 1; engine <= 2; engine++) {
       engineStarter = simVars->getEngineStarter(engine);
+      // An aborted start ends the start sequence, the starter then only dry cranks the engine
+      if (engineStartPhase(engine) == START_PHASE_ABORTED) {
+        engineStarter = 0;
+      }
       engineIgniter = simVars->getEngineIgniter(engine);
       simCN1 = simVars->getCN1(engine);
       simN1 = simVars->getN1(engine);
@@ -1224,9 +996,10 @@ class EngineControl {
           updatePrimaryParameters(engine, imbalance, simN1, simN2);
           cFbwFF = updateFF(engine, imbalance, simCN1, mach, pressAltitude, ambientTemp, ambientPressure);
           updateEGT(engine, imbalance, deltaTime, simOnGround, engineState, simCN1, cFbwFF, mach, pressAltitude, ambientTemp);
-          // updateOil(engine, imbalance, thrust, simN2, deltaN2, deltaTime, ambientTemp);
       }
 
+      updateOil(engine);
+
       // set highest N1 from either engine
       simN1highest = max(simN1highest, simN1);
     }
@@ -150,6 +150,16 @@ class SimVars {
   ID Engine2State;
   ID Engine1Timer;
   ID Engine2Timer;
+  ID Engine1StartPhase;
+  ID Engine2StartPhase;
+  ID Engine1FuelCommandedOn;
+  ID Engine2FuelCommandedOn;
+  ID Engine1OilQuantity;
+  ID Engine2OilQuantity;
+  ID Engine1OilPressure;
+  ID Engine2OilPressure;
+  ID Engine1OilTemperature;
+  ID Engine2OilTemperature;
   ID PumpStateLeft;
   ID PumpStateRight;
   ID ThrustLimitType;
@@ -200,6 +210,16 @@ class SimVars {
     Engine2State = register_named_variable("A32NX_ENGINE_STATE:2");
     Engine1Timer = register_named_variable("A32NX_ENGINE_TIMER:1");
     Engine2Timer = register_named_variable("A32NX_ENGINE_TIMER:2");
+    Engine1StartPhase = register_named_variable("A32NX_ENGINE_1_START_PHASE");
+    Engine2StartPhase = register_named_variable("A32NX_ENGINE_2_START_PHASE");
+    Engine1FuelCommandedOn = register_named_variable("A32NX_ENGINE_1_FUEL_COMMANDED_ON");
+    Engine2FuelCommandedOn = register_named_variable("A32NX_ENGINE_2_FUEL_COMMANDED_ON");
+    Engine1OilQuantity = register_named_variable("A32NX_ENGINE_1_OIL_QUANTITY");
+    Engine2OilQuantity = register_named_variable("A32NX_ENGINE_2_OIL_QUANTITY");
+    Engine1OilPressure = register_named_variable("A32NX_ENGINE_1_OIL_PRESSURE");
+    Engine2OilPressure = register_named_variable("A32NX_ENGINE_2_OIL_PRESSURE");
+    Engine1OilTemperature = register_named_variable("A32NX_ENGINE_1_OIL_TEMPERATURE");
+    Engine2OilTemperature = register_named_variable("A32NX_ENGINE_2_OIL_TEMPERATURE");
     PumpStateLeft = register_named_variable("A32NX_PUMP_STATE:1");
     PumpStateRight = register_named_variable("A32NX_PUMP_STATE:2");
 
@@ -345,6 +365,16 @@ class SimVars {
   FLOAT64 getEngine2State() { return get_named_variable_value(Engine2State); }
   FLOAT64 getEngine1Timer() { return get_named_variable_value(Engine1Timer); }
   FLOAT64 getEngine2Timer() { return get_named_variable_value(Engine2Timer); }
+  FLOAT64 getEngine1StartPhase() { return get_named_variable_value(Engine1StartPhase); }
+  FLOAT64 getEngine2StartPhase() { return get_named_variable_value(Engine2StartPhase); }
+  FLOAT64 getEngine1FuelCommandedOn() { return get_named_variable_value(Engine1FuelCommandedOn); }
+  FLOAT64 getEngine2FuelCommandedOn() { return get_named_variable_value(Engine2FuelCommandedOn); }
+  FLOAT64 getEngine1OilQuantity() { return get_named_variable_value(Engine1OilQuantity); }
+  FLOAT64 getEngine2OilQuantity() { return get_named_variable_value(Engine2OilQuantity); }
+  FLOAT64 getEngine1OilPressure() { return get_named_variable_value(Engine1OilPressure); }
+  FLOAT64 getEngine2OilPressure() { return get_named_variable_value(Engine2OilPressure); }
+  FLOAT64 getEngine1OilTemperature() { return get_named_variable_value(Engine1OilTemperature); }
+  FLOAT64 getEngine2OilTemperature() { return get_named_variable_value(Engine2OilTemperature); }
   FLOAT64 getFF(int index) { return aircraft_varget(correctedFF, m_Units->Pph, index); }
   FLOAT64 getMach() { return aircraft_varget(AirSpeedMach, m_Units->Mach, 0); }
   FLOAT64 getPlaneAltitude() { return aircraft_varget(PlaneAltitude, m_Units->Feet, 0); }
@@ -0,0 +1,793 @@
+use std::time::Duration;
+
+use systems::{
+    electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+    hydraulic::{
+        linear_actuator::Actuator, pumps::PumpCharacteristics, ElectricPump, EngineDrivenPump,
+        HydraulicCircuit, HydraulicCircuitController, PowerTransferUnit,
+        PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSource,
+        PressureSwitchState, PumpController, RamAirTurbine,
+    },
+    shared::{
+        update_iterator::FixedStepLoop, AirbusElectricPumpId, AirbusEngineDrivenPumpId,
+        ElectricalBusType, HydraulicColor, PotentialOrigin, RamAirTurbineController,
+    },
+    simulation::{
+        Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+    },
+};
+use uom::si::{
+    angular_velocity::revolution_per_minute,
+    electric_current::ampere,
+    electric_potential::volt,
+    f64::*,
+    pressure::psi,
+    ratio::ratio,
+    thermodynamic_temperature::degree_celsius,
+    volume::{cubic_inch, gallon},
+    volume_rate::gallon_per_minute,
+};
+
+use a320_systems::hydraulic::A320HydraulicCircuitFactory;
+
+use crate::scenario::{Scenario, ScenarioAction};
+
+struct TestHydraulicCircuitController {
+    should_open_fire_shutoff_valve: bool,
+}
+impl TestHydraulicCircuitController {
+    fn commanding_open_fire_shutoff_valve() -> Self {
+        Self {
+            should_open_fire_shutoff_valve: true,
+        }
+    }
+}
+impl HydraulicCircuitController for TestHydraulicCircuitController {
+    fn should_open_fire_shutoff_valve(&self, _: usize) -> bool {
+        self.should_open_fire_shutoff_valve
+    }
+
+    fn should_open_leak_measurement_valve(&self) -> bool {
+        true
+    }
+
+    fn should_route_pump_to_auxiliary(&self, _: usize) -> bool {
+        false
+    }
+}
+
+struct TestPumpController {
+    should_pressurise: bool,
+}
+impl TestPumpController {
+    fn commanding_pressurise() -> Self {
+        Self {
+            should_pressurise: true,
+        }
+    }
+
+    fn commanding_depressurise() -> Self {
+        Self {
+            should_pressurise: false,
+        }
+    }
+}
+impl PumpController for TestPumpController {
+    fn should_pressurise(&self) -> bool {
+        self.should_pressurise
+    }
+}
+
+struct TestPowerTransferUnitController {
+    should_enable: bool,
+}
+impl PowerTransferUnitController for TestPowerTransferUnitController {
+    fn should_enable(&self) -> bool {
+        self.should_enable
+    }
+}
+
+/// Nominal A320 PTU, without the randomised wear of the aircraft's PTU so that runs can be
+/// compared.
+struct TestPowerTransferUnitCharacteristics;
+impl PowerTransferUnitCharacteristics for TestPowerTransferUnitCharacteristics {
+    fn efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(0.85)
+    }
+
+    fn deactivation_delta_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(90.)
+    }
+
+    fn activation_delta_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(500.)
+    }
+
+    fn shot_to_shot_variability(&self) -> Ratio {
+        Ratio::new::<ratio>(0.05)
+    }
+
+    fn wear(&self) -> Ratio {
+        Ratio::default()
+    }
+}
+
+struct TestRamAirTurbineController {
+    should_deploy: bool,
+}
+impl RamAirTurbineController for TestRamAirTurbineController {
+    fn should_deploy(&self) -> bool {
+        self.should_deploy
+    }
+}
+
+/// Consumer taking a constant flow from a section and returning it to the reservoir.
+struct TestLoad {
+    flow: VolumeRate,
+    used_volume: Volume,
+}
+impl TestLoad {
+    fn new() -> Self {
+        Self {
+            flow: VolumeRate::default(),
+            used_volume: Volume::default(),
+        }
+    }
+
+    fn update(&mut self, context: &UpdateContext) {
+        self.used_volume += self.flow * context.delta_as_time();
+    }
+}
+impl Actuator for TestLoad {
+    fn used_volume(&self) -> Volume {
+        self.used_volume
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.used_volume
+    }
+
+    fn reset_volumes(&mut self) {
+        self.used_volume = Volume::default();
+    }
+}
+
+/// A hydraulic circuit with the pumps it has on the A320.
+pub struct TestCircuit {
+    color: HydraulicColor,
+    circuit: HydraulicCircuit,
+    controller: TestHydraulicCircuitController,
+    load: TestLoad,
+
+    engine_driven_pump: Option<EngineDrivenPump>,
+    engine_driven_pump_controller: TestPumpController,
+    engine_driven_pump_speed: AngularVelocity,
+
+    electric_pump: Option<ElectricPump>,
+    electric_pump_controller: TestPumpController,
+}
+impl TestCircuit {
+    const ELECTRIC_PUMP_MAX_CURRENT_AMPERE: f64 = 45.;
+
+    fn new(context: &mut InitContext, color: HydraulicColor) -> Self {
+        let (circuit, engine_driven_pump, electric_pump) = match color {
+            HydraulicColor::Green => (
+                A320HydraulicCircuitFactory::new_green_circuit(context),
+                Some(Self::new_engine_driven_pump(
+                    context,
+                    AirbusEngineDrivenPumpId::Green,
+                )),
+                None,
+            ),
+            HydraulicColor::Yellow => (
+                A320HydraulicCircuitFactory::new_yellow_circuit(context, Volume::default()),
+                Some(Self::new_engine_driven_pump(
+                    context,
+                    AirbusEngineDrivenPumpId::Yellow,
+                )),
+                Some(Self::new_electric_pump(
+                    context,
+                    AirbusElectricPumpId::Yellow,
+                    ElectricalBusType::AlternatingCurrentGndFltService,
+                )),
+            ),
+            HydraulicColor::Blue => (
+                A320HydraulicCircuitFactory::new_blue_circuit(context),
+                None,
+                Some(Self::new_electric_pump(
+                    context,
+                    AirbusElectricPumpId::Blue,
+                    ElectricalBusType::AlternatingCurrent(1),
+                )),
+            ),
+        };
+
+        Self {
+            color,
+            circuit,
+            controller: TestHydraulicCircuitController::commanding_open_fire_shutoff_valve(),
+            load: TestLoad::new(),
+            engine_driven_pump,
+            engine_driven_pump_controller: TestPumpController::commanding_pressurise(),
+            engine_driven_pump_speed: AngularVelocity::default(),
+            electric_pump,
+            electric_pump_controller: TestPumpController::commanding_depressurise(),
+        }
+    }
+
+    fn new_engine_driven_pump(
+        context: &mut InitContext,
+        id: AirbusEngineDrivenPumpId,
+    ) -> EngineDrivenPump {
+        EngineDrivenPump::new(context, id, PumpCharacteristics::a320_edp())
+    }
+
+    fn new_electric_pump(
+        context: &mut InitContext,
+        id: AirbusElectricPumpId,
+        bus: ElectricalBusType,
+    ) -> ElectricPump {
+        ElectricPump::new(
+            context,
+            id,
+            bus,
+            ElectricCurrent::new::<ampere>(Self::ELECTRIC_PUMP_MAX_CURRENT_AMPERE),
+            PumpCharacteristics::a320_electric_pump(),
+        )
+    }
+
+    fn update_pumps(&mut self, context: &UpdateContext) {
+        if let Some(pump) = &mut self.engine_driven_pump {
+            pump.update(
+                context,
+                self.circuit.pump_section(0),
+                self.circuit.reservoir(),
+                self.engine_driven_pump_speed,
+                &self.engine_driven_pump_controller,
+            );
+        }
+
+        if let Some(pump) = &mut self.electric_pump {
+            // Without an engine driven pump the electric pump is the main pump of the circuit,
+            // otherwise it is connected to the system section.
+            if self.engine_driven_pump.is_some() {
+                pump.update(
+                    context,
+                    self.circuit.system_section(),
+                    self.circuit.reservoir(),
+                    &self.electric_pump_controller,
+                );
+            } else {
+                pump.update(
+                    context,
+                    self.circuit.pump_section(0),
+                    self.circuit.reservoir(),
+                    &self.electric_pump_controller,
+                );
+            }
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        ptu: Option<&PowerTransferUnit>,
+        ram_air_turbine: Option<&mut RamAirTurbine>,
+        reservoir_pressure: Pressure,
+    ) {
+        self.load.update(context);
+        self.circuit.update_system_actuator_volumes(&mut self.load);
+
+        match (&mut self.engine_driven_pump, &mut self.electric_pump) {
+            (Some(engine_driven_pump), Some(electric_pump)) => self.circuit.update(
+                context,
+                &mut [engine_driven_pump],
+                Some(electric_pump),
+                None::<&mut ElectricPump>,
+                ptu,
+                &self.controller,
+                reservoir_pressure,
+            ),
+            (Some(engine_driven_pump), None) => self.circuit.update(
+                context,
+                &mut [engine_driven_pump],
+                None::<&mut ElectricPump>,
+                None::<&mut ElectricPump>,
+                ptu,
+                &self.controller,
+                reservoir_pressure,
+            ),
+            (None, Some(electric_pump)) => self.circuit.update(
+                context,
+                &mut [electric_pump],
+                ram_air_turbine,
+                None::<&mut ElectricPump>,
+                ptu,
+                &self.controller,
+                reservoir_pressure,
+            ),
+            (None, None) => unreachable!("Every circuit has at least one pump"),
+        }
+    }
+
+    pub fn pump_section_pressure(&self) -> Pressure {
+        self.circuit.pump_pressure(0)
+    }
+
+    pub fn system_section_pressure(&self) -> Pressure {
+        self.circuit.system_section_pressure()
+    }
+
+    pub fn pump_section_is_pressurised(&self) -> bool {
+        self.circuit.pump_section_pressure_switch(0) == PressureSwitchState::Pressurised
+    }
+
+    pub fn system_section_is_pressurised(&self) -> bool {
+        self.circuit.system_section_pressure_switch() == PressureSwitchState::Pressurised
+    }
+
+    pub fn accumulator_fluid_volume(&self) -> Volume {
+        self.circuit.system_accumulator_fluid_volume()
+    }
+
+    pub fn reservoir_level(&self) -> Volume {
+        self.circuit.reservoir_level()
+    }
+
+    pub fn fluid_temperature(&self) -> ThermodynamicTemperature {
+        self.circuit.fluid().temperature()
+    }
+
+    pub fn engine_driven_pump(&self) -> Option<&EngineDrivenPump> {
+        self.engine_driven_pump.as_ref()
+    }
+
+    pub fn electric_pump(&self) -> Option<&ElectricPump> {
+        self.electric_pump.as_ref()
+    }
+}
+impl SimulationElement for TestCircuit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.circuit.accept(visitor);
+        if let Some(pump) = &mut self.engine_driven_pump {
+            pump.accept(visitor);
+        }
+        if let Some(pump) = &mut self.electric_pump {
+            pump.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
+
+/// Aircraft made of the hydraulic circuits, PTU and RAT selected by a scenario. All electrical
+/// buses are powered and the reservoirs are pressurised at a nominal air pressure.
+pub struct HydraulicTestAircraft {
+    updater: FixedStepLoop,
+
+    circuits: Vec<TestCircuit>,
+
+    power_transfer_unit: Option<PowerTransferUnit>,
+    power_transfer_unit_controller: TestPowerTransferUnitController,
+
+    ram_air_turbine: Option<RamAirTurbine>,
+    ram_air_turbine_controller: TestRamAirTurbineController,
+
+    powered_source_ac: TestElectricitySource,
+    buses: Vec<ElectricalBus>,
+}
+impl HydraulicTestAircraft {
+    const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(33);
+    const RESERVOIR_AIR_PRESSURE_PSI: f64 = 50.;
+
+    pub fn new(context: &mut InitContext, scenario: &Scenario) -> Self {
+        Self {
+            updater: FixedStepLoop::new(Self::HYDRAULIC_SIM_TIME_STEP),
+            circuits: scenario
+                .circuits()
+                .iter()
+                .map(|color| TestCircuit::new(context, *color))
+                .collect(),
+            power_transfer_unit: scenario
+                .has_power_transfer_unit()
+                .then(|| PowerTransferUnit::new(context, &TestPowerTransferUnitCharacteristics)),
+            power_transfer_unit_controller: TestPowerTransferUnitController {
+                should_enable: false,
+            },
+            ram_air_turbine: scenario
+                .has_ram_air_turbine()
+                .then(|| RamAirTurbine::new(context, PumpCharacteristics::a320_rat())),
+            ram_air_turbine_controller: TestRamAirTurbineController {
+                should_deploy: false,
+            },
+            powered_source_ac: TestElectricitySource::powered(
+                context,
+                PotentialOrigin::EngineGenerator(1),
+            ),
+            buses: [
+                ElectricalBusType::AlternatingCurrentGndFltService,
+                ElectricalBusType::DirectCurrentGndFltService,
+                ElectricalBusType::AlternatingCurrent(1),
+                ElectricalBusType::AlternatingCurrent(2),
+                ElectricalBusType::DirectCurrent(1),
+                ElectricalBusType::DirectCurrent(2),
+                ElectricalBusType::DirectCurrentEssential,
+                ElectricalBusType::DirectCurrentHot(1),
+                ElectricalBusType::DirectCurrentHot(2),
+            ]
+            .into_iter()
+            .map(|bus_type| ElectricalBus::new(context, bus_type))
+            .collect(),
+        }
+    }
+
+    /// Applies a scenario action, except for variable writes which go through the simulation.
+    pub fn apply(&mut self, action: &ScenarioAction) {
+        match *action {
+            ScenarioAction::EngineDrivenPump(color, should_pressurise) => {
+                self.circuit_mut(color)
+                    .engine_driven_pump_controller
+                    .should_pressurise = should_pressurise;
+            }
+            ScenarioAction::EngineDrivenPumpSpeed(color, speed) => {
+                self.circuit_mut(color).engine_driven_pump_speed = speed;
+            }
+            ScenarioAction::ElectricPump(color, should_pressurise) => {
+                self.circuit_mut(color)
+                    .electric_pump_controller
+                    .should_pressurise = should_pressurise;
+            }
+            ScenarioAction::FireValve(color, should_open) => {
+                self.circuit_mut(color)
+                    .controller
+                    .should_open_fire_shutoff_valve = should_open;
+            }
+            ScenarioAction::Load(color, flow) => {
+                self.circuit_mut(color).load.flow = flow;
+            }
+            ScenarioAction::PowerTransferUnit(should_enable) => {
+                self.power_transfer_unit_controller.should_enable = should_enable;
+            }
+            ScenarioAction::RamAirTurbineDeployment => {
+                self.ram_air_turbine_controller.should_deploy = true;
+            }
+            ScenarioAction::Write(..) => {}
+        }
+    }
+
+    fn circuit_mut(&mut self, color: HydraulicColor) -> &mut TestCircuit {
+        self.circuits
+            .iter_mut()
+            .find(|circuit| circuit.color == color)
+            .expect("Scenario actions were validated against the simulated circuits.")
+    }
+
+    pub fn circuits(&self) -> &[TestCircuit] {
+        &self.circuits
+    }
+
+    pub fn power_transfer_unit(&self) -> Option<&PowerTransferUnit> {
+        self.power_transfer_unit.as_ref()
+    }
+
+    pub fn ram_air_turbine(&self) -> Option<&RamAirTurbine> {
+        self.ram_air_turbine.as_ref()
+    }
+
+    fn update_hydraulics(&mut self, context: &UpdateContext) {
+        if let (Some(ptu), Some(green), Some(yellow)) = (
+            self.power_transfer_unit.as_mut(),
+            find_circuit(&self.circuits, HydraulicColor::Green),
+            find_circuit(&self.circuits, HydraulicColor::Yellow),
+        ) {
+            ptu.update(
+                context,
+                green.circuit.system_section(),
+                yellow.circuit.system_section(),
+                &self.power_transfer_unit_controller,
+            );
+        }
+
+        if let Some(rat) = self.ram_air_turbine.as_mut() {
+            let blue = find_circuit(&self.circuits, HydraulicColor::Blue)
+                .expect("The RAT requires the blue circuit.");
+
+            rat.update_position(&context.delta());
+            rat.update_physics(context, blue.circuit.system_section());
+            rat.update(
+                context,
+                blue.circuit.system_section(),
+                blue.circuit.reservoir(),
+                &self.ram_air_turbine_controller,
+            );
+        }
+
+        let reservoir_pressure = Pressure::new::<psi>(Self::RESERVOIR_AIR_PRESSURE_PSI);
+        for circuit in &mut self.circuits {
+            circuit.update_pumps(context);
+
+            let ram_air_turbine = if circuit.color == HydraulicColor::Blue {
+                self.ram_air_turbine.as_mut()
+            } else {
+                None
+            };
+            circuit.update(
+                context,
+                self.power_transfer_unit.as_ref(),
+                ram_air_turbine,
+                reservoir_pressure,
+            );
+        }
+    }
+}
+impl Aircraft for HydraulicTestAircraft {
+    fn update_before_power_distribution(
+        &mut self,
+        _: &UpdateContext,
+        electricity: &mut Electricity,
+    ) {
+        self.powered_source_ac
+            .power_with_potential(ElectricPotential::new::<volt>(115.));
+        electricity.supplied_by(&self.powered_source_ac);
+
+        for bus in &self.buses {
+            electricity.flow(&self.powered_source_ac, bus);
+        }
+    }
+
+    fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+        self.updater.update(context);
+
+        for cur_time_step in self.updater {
+            self.update_hydraulics(&context.with_delta(cur_time_step));
+        }
+    }
+}
+impl SimulationElement for HydraulicTestAircraft {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for circuit in &mut self.circuits {
+            circuit.accept(visitor);
+        }
+        if let Some(ptu) = &mut self.power_transfer_unit {
+            ptu.accept(visitor);
+        }
+        if let Some(rat) = &mut self.ram_air_turbine {
+            rat.accept(visitor);
+        }
+        for bus in &mut self.buses {
+            bus.accept(visitor);
+        }
+
+        visitor.visit(self);
+    }
+}
+
+fn find_circuit(circuits: &[TestCircuit], color: HydraulicColor) -> Option<&TestCircuit> {
+    circuits.iter().find(|circuit| circuit.color == color)
+}
+
+/// A recorded value of the aircraft.
+pub struct Signal {
+    name: String,
+    value: Box<dyn Fn(&HydraulicTestAircraft) -> f64>,
+}
+impl Signal {
+    fn new(name: String, value: impl Fn(&HydraulicTestAircraft) -> f64 + 'static) -> Self {
+        Self {
+            name,
+            value: Box::new(value),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self, aircraft: &HydraulicTestAircraft) -> f64 {
+        (self.value)(aircraft)
+    }
+}
+
+/// Signals recorded for the equipment simulated in the scenario. Names are suffixed with
+/// their unit.
+pub fn signals(scenario: &Scenario) -> Vec<Signal> {
+    let mut signals = Vec::new();
+
+    for (index, color) in scenario.circuits().iter().enumerate() {
+        let prefix = color.to_string().to_lowercase();
+
+        signals.push(Signal::new(
+            format!("{}_pump_section_pressure_psi", prefix),
+            move |a| a.circuits()[index].pump_section_pressure().get::<psi>(),
+        ));
+        signals.push(Signal::new(
+            format!("{}_system_section_pressure_psi", prefix),
+            move |a| a.circuits()[index].system_section_pressure().get::<psi>(),
+        ));
+        signals.push(Signal::new(
+            format!("{}_pump_section_pressure_switch", prefix),
+            move |a| a.circuits()[index].pump_section_is_pressurised() as u8 as f64,
+        ));
+        signals.push(Signal::new(
+            format!("{}_system_section_pressure_switch", prefix),
+            move |a| a.circuits()[index].system_section_is_pressurised() as u8 as f64,
+        ));
+        signals.push(Signal::new(
+            format!("{}_accumulator_fluid_volume_gal", prefix),
+            move |a| {
+                a.circuits()[index]
+                    .accumulator_fluid_volume()
+                    .get::<gallon>()
+            },
+        ));
+        signals.push(Signal::new(
+            format!("{}_reservoir_level_gal", prefix),
+            move |a| a.circuits()[index].reservoir_level().get::<gallon>(),
+        ));
+        signals.push(Signal::new(
+            format!("{}_fluid_temperature_degc", prefix),
+            move |a| {
+                a.circuits()[index]
+                    .fluid_temperature()
+                    .get::<degree_celsius>()
+            },
+        ));
+
+        if *color != HydraulicColor::Blue {
+            signals.push(Signal::new(format!("{}_edp_flow_gpm", prefix), move |a| {
+                a.circuits()[index]
+                    .engine_driven_pump()
+                    .map_or(0., |pump| pump.flow().get::<gallon_per_minute>())
+            }));
+            signals.push(Signal::new(
+                format!("{}_edp_displacement_cubic_inch", prefix),
+                move |a| {
+                    a.circuits()[index]
+                        .engine_driven_pump()
+                        .map_or(0., |pump| pump.displacement().get::<cubic_inch>())
+                },
+            ));
+        }
+
+        if *color != HydraulicColor::Green {
+            signals.push(Signal::new(format!("{}_epump_rpm", prefix), move |a| {
+                a.circuits()[index]
+                    .electric_pump()
+                    .map_or(0., |pump| pump.speed().get::<revolution_per_minute>())
+            }));
+            signals.push(Signal::new(
+                format!("{}_epump_flow_gpm", prefix),
+                move |a| {
+                    a.circuits()[index]
+                        .electric_pump()
+                        .map_or(0., |pump| pump.flow().get::<gallon_per_minute>())
+                },
+            ));
+        }
+    }
+
+    if scenario.has_power_transfer_unit() {
+        signals.push(Signal::new("ptu_flow_gpm".to_owned(), |a| {
+            a.power_transfer_unit()
+                .map_or(0., |ptu| ptu.flow().get::<gallon_per_minute>())
+        }));
+        signals.push(Signal::new("ptu_direction".to_owned(), |a| {
+            a.power_transfer_unit().map_or(0., |ptu| {
+                if ptu.is_active_left_to_right() {
+                    1.
+                } else if ptu.is_active_right_to_left() {
+                    -1.
+                } else {
+                    0.
+                }
+            })
+        }));
+    }
+
+    if scenario.has_ram_air_turbine() {
+        signals.push(Signal::new("rat_flow_gpm".to_owned(), |a| {
+            a.ram_air_turbine()
+                .map_or(0., |rat| rat.flow().get::<gallon_per_minute>())
+        }));
+    }
+
+    signals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use systems::simulation::test::{SimulationTestBed, TestBed};
+
+    fn test_bed(text: &str) -> SimulationTestBed<HydraulicTestAircraft> {
+        let scenario = Scenario::parse(text.as_bytes()).unwrap();
+        SimulationTestBed::new(|context| HydraulicTestAircraft::new(context, &scenario))
+    }
+
+    fn run_for(test_bed: &mut SimulationTestBed<HydraulicTestAircraft>, duration: Duration) {
+        for _ in 0..(duration.as_millis() / 33) {
+            test_bed.run_with_delta(Duration::from_millis(33));
+        }
+    }
+
+    fn system_pressure(
+        test_bed: &SimulationTestBed<HydraulicTestAircraft>,
+        color: HydraulicColor,
+    ) -> Pressure {
+        test_bed.query(|a| {
+            find_circuit(a.circuits(), color)
+                .unwrap()
+                .system_section_pressure()
+        })
+    }
+
+    #[test]
+    fn blue_electric_pump_pressurises_blue_circuit() {
+        let mut test_bed = test_bed("circuit blue");
+        run_for(&mut test_bed, Duration::from_secs(5));
+        assert!(system_pressure(&test_bed, HydraulicColor::Blue) < Pressure::new::<psi>(100.));
+
+        test_bed.command(|a| a.apply(&ScenarioAction::ElectricPump(HydraulicColor::Blue, true)));
+        run_for(&mut test_bed, Duration::from_secs(5));
+        assert!(system_pressure(&test_bed, HydraulicColor::Blue) > Pressure::new::<psi>(2500.));
+    }
+
+    #[test]
+    fn engine_driven_pump_needs_speed_to_pressurise() {
+        let mut test_bed = test_bed("circuit green");
+        run_for(&mut test_bed, Duration::from_secs(5));
+        assert!(system_pressure(&test_bed, HydraulicColor::Green) < Pressure::new::<psi>(100.));
+
+        test_bed.command(|a| {
+            a.apply(&ScenarioAction::EngineDrivenPumpSpeed(
+                HydraulicColor::Green,
+                AngularVelocity::new::<revolution_per_minute>(3500.),
+            ))
+        });
+        run_for(&mut test_bed, Duration::from_secs(5));
+        assert!(system_pressure(&test_bed, HydraulicColor::Green) > Pressure::new::<psi>(2500.));
+    }
+
+    #[test]
+    fn closed_fire_valve_isolates_engine_driven_pump() {
+        let mut test_bed = test_bed("circuit green");
+        test_bed.command(|a| {
+            a.apply(&ScenarioAction::EngineDrivenPumpSpeed(
+                HydraulicColor::Green,
+                AngularVelocity::new::<revolution_per_minute>(3500.),
+            ));
+            a.apply(&ScenarioAction::FireValve(HydraulicColor::Green, false));
+        });
+        run_for(&mut test_bed, Duration::from_secs(5));
+
+        assert!(system_pressure(&test_bed, HydraulicColor::Green) < Pressure::new::<psi>(100.));
+    }
+
+    #[test]
+    fn ptu_pressurises_green_from_yellow_electric_pump() {
+        let mut test_bed = test_bed("circuit green\ncircuit yellow\nptu");
+        test_bed.command(|a| {
+            a.apply(&ScenarioAction::ElectricPump(HydraulicColor::Yellow, true));
+            a.apply(&ScenarioAction::PowerTransferUnit(true));
+        });
+        run_for(&mut test_bed, Duration::from_secs(20));
+
+        assert!(system_pressure(&test_bed, HydraulicColor::Yellow) > Pressure::new::<psi>(2500.));
+        assert!(system_pressure(&test_bed, HydraulicColor::Green) > Pressure::new::<psi>(2000.));
+    }
+
+    #[test]
+    fn signals_cover_the_simulated_equipment() {
+        let scenario = Scenario::parse("circuit green\ncircuit yellow\nptu".as_bytes()).unwrap();
+        let names: Vec<String> = signals(&scenario)
+            .iter()
+            .map(|signal| signal.name().to_owned())
+            .collect();
+
+        assert!(names.contains(&"green_system_section_pressure_psi".to_owned()));
+        assert!(names.contains(&"yellow_epump_flow_gpm".to_owned()));
+        assert!(names.contains(&"ptu_flow_gpm".to_owned()));
+        assert!(!names.iter().any(|name| name.starts_with("blue")));
+        assert!(!names.iter().any(|name| name.starts_with("rat")));
+    }
+}
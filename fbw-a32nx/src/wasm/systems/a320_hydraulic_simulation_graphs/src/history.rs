@@ -0,0 +1,128 @@
+use std::{
+    error::Error,
+    io::{self, Write},
+    path::Path,
+};
+
+use plotlib::{page::Page, repr::Plot, style::LineStyle, view::ContinuousView};
+
+/// History class to record a simulation
+pub struct History {
+    /// Simulation time starting from 0
+    time_vector: Vec<f64>,
+    /// Name of each var saved
+    name_vector: Vec<String>,
+    /// Vector data for each var saved
+    data_vector: Vec<Vec<f64>>,
+}
+impl History {
+    const LINE_COLOUR: &'static str = "#1F77B4";
+
+    // Margin added around the data so that flat lines don't sit on the plot's border
+    const Y_RANGE_MARGIN_RATIO: f64 = 0.05;
+    const MIN_RANGE: f64 = 1.;
+
+    pub fn new(names: Vec<String>) -> History {
+        History {
+            time_vector: Vec::new(),
+            data_vector: vec![Vec::new(); names.len()],
+            name_vector: names,
+        }
+    }
+
+    /// Records the values of each var at the given time
+    pub fn update(&mut self, time: f64, values: Vec<f64>) {
+        self.time_vector.push(time);
+        for (idx, v) in values.into_iter().enumerate() {
+            self.data_vector[idx].push(v);
+        }
+    }
+
+    /// Writes the history as CSV with a time column followed by a column per var
+    pub fn write_csv(&self, output: &mut impl Write) -> io::Result<()> {
+        write!(output, "time")?;
+        for name in &self.name_vector {
+            write!(output, ",{}", name)?;
+        }
+        writeln!(output)?;
+
+        for (idx, time) in self.time_vector.iter().enumerate() {
+            write!(output, "{}", time)?;
+            for data in &self.data_vector {
+                write!(output, ",{}", data[idx])?;
+            }
+            writeln!(output)?;
+        }
+
+        output.flush()
+    }
+
+    /// Builds a graph per var using rust crate plotlib and saves it as `<prefix>_<name>.svg`
+    pub fn save_svg(&self, directory: &Path, prefix: &str) -> Result<(), Box<dyn Error>> {
+        let last_time = self.time_vector.last().copied().unwrap_or_default();
+
+        for (name, data) in self.name_vector.iter().zip(&self.data_vector) {
+            let (y_min, y_max) = Self::y_range(data);
+            let view = ContinuousView::new()
+                .add(
+                    Plot::new(
+                        self.time_vector
+                            .iter()
+                            .copied()
+                            .zip(data.iter().copied())
+                            .collect(),
+                    )
+                    .line_style(LineStyle::new().colour(Self::LINE_COLOUR)),
+                )
+                .x_range(0., last_time.max(Self::MIN_RANGE))
+                .y_range(y_min, y_max)
+                .x_label("Time (s)")
+                .y_label(name);
+
+            Page::single(&view)
+                .save(directory.join(format!("{}_{}.svg", prefix, name)))
+                .map_err(|error| error.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    fn y_range(data: &[f64]) -> (f64, f64) {
+        let min = data.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if !min.is_finite() || !max.is_finite() {
+            return (0., Self::MIN_RANGE);
+        }
+
+        let margin = ((max - min) * Self::Y_RANGE_MARGIN_RATIO).max(Self::MIN_RANGE / 2.);
+
+        (min - margin, max + margin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_csv_with_a_column_per_var() {
+        let mut history = History::new(vec!["pressure_psi".to_owned(), "flow_gpm".to_owned()]);
+        history.update(0.5, vec![3000., 1.5]);
+        history.update(1., vec![2950., 0.]);
+
+        let mut output = Vec::new();
+        history.write_csv(&mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "time,pressure_psi,flow_gpm\n0.5,3000,1.5\n1,2950,0\n"
+        );
+    }
+
+    #[test]
+    fn y_range_surrounds_data() {
+        assert_eq!(History::y_range(&[0., 100.]), (-5., 105.));
+        assert_eq!(History::y_range(&[1., 1.]), (0.5, 1.5));
+        assert_eq!(History::y_range(&[]), (0., 1.));
+    }
+}
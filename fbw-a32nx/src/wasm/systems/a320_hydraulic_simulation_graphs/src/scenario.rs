@@ -0,0 +1,412 @@
+//! Scenario files describe which hydraulic circuits are simulated and what happens to their
+//! pumps, valves and loads over time.
+//!
+//! Scenario files contain one statement per line, `#` starts a comment:
+//!
+//! ```text
+//! circuit green
+//! circuit yellow
+//! ptu
+//! duration 60
+//! step 0.033
+//! at 0 yellow epump on
+//! at 0 ptu on
+//! at 20 green edp_rpm 3500
+//! at 30 green fire_valve closed
+//! at 40 yellow load 0.2
+//! at 50 yellow epump off
+//! ```
+//!
+//! A `circuit` gets the pumps it has on the A320: the green circuit an engine driven pump,
+//! the yellow circuit an engine driven pump and an electric pump, the blue circuit an electric
+//! pump. `ptu` adds the power transfer unit between the green and yellow circuits, `rat` adds
+//! the ram air turbine to the blue circuit.
+//!
+//! At the start engine driven pumps are commanded to pressurise but don't turn until an
+//! `edp_rpm` is given, electric pumps are off, the PTU is disabled, the RAT is stowed, fire
+//! valves are open and there is no load. A `load` is the flow in gallon per second taken by
+//! the system section and returned to the reservoir. `at <time> write <variable> <value>`
+//! writes a simulation variable in its simulator unit, e.g. `AIRSPEED TRUE` and
+//! `AMBIENT DENSITY` which drive the RAT.
+use std::{
+    io::{self, BufRead},
+    time::Duration,
+};
+
+use systems::{
+    shared::HydraulicColor,
+    simulation::runner::{parse_duration, parse_statements},
+};
+use uom::si::{angular_velocity::revolution_per_minute, f64::*, volume_rate::gallon_per_second};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScenarioAction {
+    EngineDrivenPump(HydraulicColor, bool),
+    EngineDrivenPumpSpeed(HydraulicColor, AngularVelocity),
+    ElectricPump(HydraulicColor, bool),
+    FireValve(HydraulicColor, bool),
+    Load(HydraulicColor, VolumeRate),
+    PowerTransferUnit(bool),
+    RamAirTurbineDeployment,
+    Write(String, f64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScenarioEvent {
+    time: Duration,
+    action: ScenarioAction,
+}
+impl ScenarioEvent {
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn action(&self) -> &ScenarioAction {
+        &self.action
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scenario {
+    circuits: Vec<HydraulicColor>,
+    has_power_transfer_unit: bool,
+    has_ram_air_turbine: bool,
+    duration: Duration,
+    step: Duration,
+    events: Vec<ScenarioEvent>,
+}
+impl Scenario {
+    const DEFAULT_STEP_SECONDS: f64 = 0.033;
+
+    pub fn parse(reader: impl BufRead) -> io::Result<Self> {
+        let mut scenario = Self {
+            circuits: Vec::new(),
+            has_power_transfer_unit: false,
+            has_ram_air_turbine: false,
+            duration: Duration::ZERO,
+            step: Duration::from_secs_f64(Self::DEFAULT_STEP_SECONDS),
+            events: Vec::new(),
+        };
+
+        parse_statements(reader, |fields| {
+            match fields[0] {
+                "circuit" if fields.len() == 2 => {
+                    let color = parse_color(fields[1])?;
+                    if !scenario.circuits.contains(&color) {
+                        scenario.circuits.push(color);
+                    }
+                }
+                "ptu" if fields.len() == 1 => scenario.has_power_transfer_unit = true,
+                "rat" if fields.len() == 1 => scenario.has_ram_air_turbine = true,
+                "duration" if fields.len() == 2 => {
+                    scenario.duration = parse_duration(fields[1])?;
+                }
+                "step" if fields.len() == 2 => {
+                    scenario.step = parse_duration(fields[1]).filter(|step| !step.is_zero())?;
+                }
+                "at" if fields.len() >= 3 => {
+                    let time = parse_duration(fields[1])?;
+                    let action = parse_action(&fields[2..])?;
+
+                    scenario.events.push(ScenarioEvent { time, action });
+                }
+                _ => return None,
+            }
+
+            Some(())
+        })?;
+
+        scenario.validate()?;
+
+        // Events at the same time are applied in the order in which they were written.
+        scenario.events.sort_by_key(|event| event.time);
+
+        Ok(scenario)
+    }
+
+    fn validate(&self) -> io::Result<()> {
+        let invalid =
+            |description: String| Err(io::Error::new(io::ErrorKind::InvalidInput, description));
+
+        if self.circuits.is_empty() {
+            return invalid("The scenario doesn't simulate any circuit".to_owned());
+        }
+
+        if self.has_power_transfer_unit
+            && !(self.has_circuit(HydraulicColor::Green)
+                && self.has_circuit(HydraulicColor::Yellow))
+        {
+            return invalid("The PTU requires the green and yellow circuits".to_owned());
+        }
+
+        if self.has_ram_air_turbine && !self.has_circuit(HydraulicColor::Blue) {
+            return invalid("The RAT requires the blue circuit".to_owned());
+        }
+
+        for event in &self.events {
+            match event.action {
+                ScenarioAction::EngineDrivenPump(color, _)
+                | ScenarioAction::EngineDrivenPumpSpeed(color, _)
+                    if !self.has_circuit(color) || color == HydraulicColor::Blue =>
+                {
+                    return invalid(format!("No {} engine driven pump", color));
+                }
+                ScenarioAction::ElectricPump(color, _)
+                    if !self.has_circuit(color) || color == HydraulicColor::Green =>
+                {
+                    return invalid(format!("No {} electric pump", color));
+                }
+                ScenarioAction::FireValve(color, _) | ScenarioAction::Load(color, _)
+                    if !self.has_circuit(color) =>
+                {
+                    return invalid(format!("No {} circuit", color));
+                }
+                ScenarioAction::PowerTransferUnit(_) if !self.has_power_transfer_unit => {
+                    return invalid("No PTU".to_owned());
+                }
+                ScenarioAction::RamAirTurbineDeployment if !self.has_ram_air_turbine => {
+                    return invalid("No RAT".to_owned());
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The simulated circuits, in the order of their first `circuit` statement.
+    pub fn circuits(&self) -> &[HydraulicColor] {
+        &self.circuits
+    }
+
+    pub fn has_circuit(&self, color: HydraulicColor) -> bool {
+        self.circuits.contains(&color)
+    }
+
+    pub fn has_power_transfer_unit(&self) -> bool {
+        self.has_power_transfer_unit
+    }
+
+    pub fn has_ram_air_turbine(&self) -> bool {
+        self.has_ram_air_turbine
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    pub fn events(&self) -> &[ScenarioEvent] {
+        &self.events
+    }
+}
+
+fn parse_action(fields: &[&str]) -> Option<ScenarioAction> {
+    match fields {
+        ["ptu", state] => Some(ScenarioAction::PowerTransferUnit(parse_on_off(state)?)),
+        ["rat", "deploy"] => Some(ScenarioAction::RamAirTurbineDeployment),
+        ["write", .., value] if fields.len() >= 3 => Some(ScenarioAction::Write(
+            fields[1..fields.len() - 1].join(" "),
+            value.parse().ok()?,
+        )),
+        [color, "edp", state] => Some(ScenarioAction::EngineDrivenPump(
+            parse_color(color)?,
+            parse_on_off(state)?,
+        )),
+        [color, "edp_rpm", speed] => Some(ScenarioAction::EngineDrivenPumpSpeed(
+            parse_color(color)?,
+            AngularVelocity::new::<revolution_per_minute>(parse_non_negative(speed)?),
+        )),
+        [color, "epump", state] => Some(ScenarioAction::ElectricPump(
+            parse_color(color)?,
+            parse_on_off(state)?,
+        )),
+        [color, "fire_valve", state] => Some(ScenarioAction::FireValve(
+            parse_color(color)?,
+            match *state {
+                "open" => true,
+                "closed" => false,
+                _ => return None,
+            },
+        )),
+        [color, "load", flow] => Some(ScenarioAction::Load(
+            parse_color(color)?,
+            VolumeRate::new::<gallon_per_second>(parse_non_negative(flow)?),
+        )),
+        _ => None,
+    }
+}
+
+fn parse_color(field: &str) -> Option<HydraulicColor> {
+    match field {
+        "green" => Some(HydraulicColor::Green),
+        "blue" => Some(HydraulicColor::Blue),
+        "yellow" => Some(HydraulicColor::Yellow),
+        _ => None,
+    }
+}
+
+fn parse_on_off(field: &str) -> Option<bool> {
+    match field {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_non_negative(field: &str) -> Option<f64> {
+    field
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite() && *value >= 0.)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> io::Result<Scenario> {
+        Scenario::parse(text.as_bytes())
+    }
+
+    #[test]
+    fn parses_configuration() {
+        let scenario = parse(
+            "# Green and yellow linked by the PTU\n\
+             circuit yellow\n\
+             circuit green # comment\n\
+             circuit yellow\n\
+             ptu\n\
+             duration 12.5\n\
+             step 0.1\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            scenario.circuits(),
+            &[HydraulicColor::Yellow, HydraulicColor::Green]
+        );
+        assert!(scenario.has_power_transfer_unit());
+        assert!(!scenario.has_ram_air_turbine());
+        assert_eq!(scenario.duration(), Duration::from_secs_f64(12.5));
+        assert_eq!(scenario.step(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn parses_events_in_time_order() {
+        let scenario = parse(
+            "circuit blue\n\
+             rat\n\
+             at 10 blue epump off\n\
+             at 0 blue epump on\n\
+             at 0 write AIRSPEED TRUE 150\n\
+             at 5 blue load 0.25\n\
+             at 5 blue fire_valve closed\n\
+             at 20 rat deploy\n",
+        )
+        .unwrap();
+
+        let actions: Vec<(Duration, &ScenarioAction)> = scenario
+            .events()
+            .iter()
+            .map(|event| (event.time(), event.action()))
+            .collect();
+        assert_eq!(
+            actions,
+            vec![
+                (
+                    Duration::ZERO,
+                    &ScenarioAction::ElectricPump(HydraulicColor::Blue, true)
+                ),
+                (
+                    Duration::ZERO,
+                    &ScenarioAction::Write("AIRSPEED TRUE".to_owned(), 150.)
+                ),
+                (
+                    Duration::from_secs(5),
+                    &ScenarioAction::Load(
+                        HydraulicColor::Blue,
+                        VolumeRate::new::<gallon_per_second>(0.25)
+                    )
+                ),
+                (
+                    Duration::from_secs(5),
+                    &ScenarioAction::FireValve(HydraulicColor::Blue, false)
+                ),
+                (
+                    Duration::from_secs(10),
+                    &ScenarioAction::ElectricPump(HydraulicColor::Blue, false)
+                ),
+                (
+                    Duration::from_secs(20),
+                    &ScenarioAction::RamAirTurbineDeployment
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_engine_driven_pump_events() {
+        let scenario = parse(
+            "circuit green\n\
+             at 1 green edp_rpm 3500\n\
+             at 2 green edp off\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            scenario.events()[0].action(),
+            &ScenarioAction::EngineDrivenPumpSpeed(
+                HydraulicColor::Green,
+                AngularVelocity::new::<revolution_per_minute>(3500.)
+            )
+        );
+        assert_eq!(
+            scenario.events()[1].action(),
+            &ScenarioAction::EngineDrivenPump(HydraulicColor::Green, false)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        for line in [
+            "circuit red",
+            "duration -1",
+            "step 0",
+            "at 1 green edp maybe",
+            "at 1 green load -0.1",
+            "at 1 green fire_valve on",
+            "at x green edp on",
+            "at 1 write AIRSPEED TRUE fast",
+            "unknown",
+        ] {
+            let error = parse(&format!("circuit green\n{}\n", line)).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{}", line);
+            assert!(error.to_string().contains("line 2"), "{}", line);
+        }
+    }
+
+    #[test]
+    fn rejects_configurations_without_the_equipment() {
+        for text in [
+            "duration 10",
+            "circuit green\nptu",
+            "circuit yellow\nrat",
+            "circuit green\nat 0 green epump on",
+            "circuit blue\nat 0 blue edp on",
+            "circuit yellow\nat 0 blue fire_valve closed",
+            "circuit green\ncircuit yellow\nat 0 ptu on",
+            "circuit blue\nat 0 rat deploy",
+        ] {
+            assert_eq!(
+                parse(text).unwrap_err().kind(),
+                io::ErrorKind::InvalidInput,
+                "{}",
+                text
+            );
+        }
+    }
+}
@@ -23,7 +23,7 @@ use systems::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 
@@ -131,6 +131,20 @@ impl PackFlowControllers<2> for A320AirConditioning {
     }
 }
 
+impl CabinSimulation for A320AirConditioning {
+    fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+        self.a320_cabin.cabin_temperature()
+    }
+
+    fn exterior_pressure(&self) -> Pressure {
+        self.a320_cabin.exterior_pressure()
+    }
+
+    fn cabin_pressure(&self) -> Pressure {
+        self.a320_cabin.cabin_pressure()
+    }
+}
+
 impl SimulationElement for A320AirConditioning {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.a320_cabin.accept(visitor);
@@ -604,6 +618,16 @@ impl SimulationElement for A320PressurizationSystem {
         writer.write(&self.active_cpc_sys_id, self.active_system);
     }
 
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.active_system as f64);
+        self.safety_valve.snapshot(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.active_system = snapshot.read::<f64>() as usize;
+        self.safety_valve.restore(snapshot);
+    }
+
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         accept_iterable!(self.cpc, visitor);
         accept_iterable!(self.outflow_valve, visitor);
@@ -99,7 +99,11 @@ impl A320DirectCurrentElectrical {
         apu: &mut impl AuxiliaryPowerUnitElectrical,
         apu_overhead: &(impl ApuMaster + ApuStart),
         lgciu1: &impl LgciuWeightOnWheels,
+        avionics_bay_temperature: ThermodynamicTemperature,
     ) {
+        self.battery_1.update(context, avionics_bay_temperature);
+        self.battery_2.update(context, avionics_bay_temperature);
+
         self.tr_1_contactor
             .close_when(electricity.is_powered(ac_state.tr_1()));
         electricity.flow(ac_state.tr_1(), &self.tr_1_contactor);
@@ -286,6 +290,14 @@ impl A320DirectCurrentElectrical {
             == self.hot_bus_2_to_dc_ess_bus_contactor.is_closed()
     }
 
+    pub fn battery_has_fault(&self, number: usize) -> bool {
+        match number {
+            1 => self.battery_1_charge_limiter.has_fault(),
+            2 => self.battery_2_charge_limiter.has_fault(),
+            _ => panic!("Unknown battery number {}.", number),
+        }
+    }
+
     #[cfg(test)]
     pub fn battery_1(&self) -> &Battery {
         &self.battery_1
@@ -27,9 +27,9 @@ use systems::{
         NormalAltnFaultPushButton, OnOffAvailablePushButton, OnOffFaultPushButton,
     },
     shared::{
-        ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical, EmergencyElectricalRatPushButton,
-        EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
-        EngineCorrectedN2, EngineFirePushButtons, LgciuWeightOnWheels,
+        ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical, CabinSimulation,
+        EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
+        EmergencyGeneratorPower, EngineCorrectedN2, EngineFirePushButtons, LgciuWeightOnWheels,
     },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -79,6 +79,7 @@ impl A320Electrical {
         engines: [&impl EngineCorrectedN2; 2],
         gcu: &impl EmergencyGeneratorControlUnit,
         lgciu1: &impl LgciuWeightOnWheels,
+        cabin_simulation: &impl CabinSimulation,
     ) {
         self.alternating_current.update_main_power_sources(
             context,
@@ -104,6 +105,9 @@ impl A320Electrical {
             &self.emergency_gen,
         );
 
+        // The batteries are installed in the avionics compartment, which is ventilated with cockpit air
+        let avionics_bay_temperature = cabin_simulation.cabin_temperature()[0];
+
         // Elec using LGCIU1 L&R compressed (14A output  ASM 32_62_00)
         self.direct_current.update(
             context,
@@ -115,6 +119,7 @@ impl A320Electrical {
             apu,
             apu_overhead,
             lgciu1,
+            avionics_bay_temperature,
         );
 
         self.alternating_current.update_after_direct_current(
@@ -145,6 +150,14 @@ impl A320Electrical {
         self.main_galley.is_shed() || self.secondary_galley.is_shed()
     }
 
+    pub fn main_galley_is_shed(&self) -> bool {
+        self.main_galley.is_shed()
+    }
+
+    pub fn secondary_galley_is_shed(&self) -> bool {
+        self.secondary_galley.is_shed()
+    }
+
     fn debug_assert_invariants(&self) {
         self.alternating_current.debug_assert_invariants();
         self.direct_current.debug_assert_invariants();
@@ -192,6 +205,10 @@ impl A320Electrical {
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_active()
     }
+
+    fn battery_has_fault(&self, number: usize) -> bool {
+        self.direct_current.battery_has_fault(number)
+    }
 }
 impl SimulationElement for A320Electrical {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -278,6 +295,13 @@ impl A320ElectricalOverheadPanel {
             .for_each(|(index, gen)| {
                 gen.set_fault(electrical.gen_contactor_open(index + 1) && gen.is_on());
             });
+
+        self.batteries
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, bat)| {
+                bat.set_fault(electrical.battery_has_fault(index + 1));
+            });
     }
 
     fn generator_is_on(&self, number: usize) -> bool {
@@ -308,11 +332,11 @@ impl A320ElectricalOverheadPanel {
         self.ac_ess_feed.is_altn()
     }
 
-    fn commercial_is_off(&self) -> bool {
+    pub fn commercial_is_off(&self) -> bool {
         self.commercial.is_off()
     }
 
-    fn galy_and_cab_is_off(&self) -> bool {
+    pub fn galy_and_cab_is_off(&self) -> bool {
         self.galy_and_cab.is_off()
     }
 }
@@ -441,7 +465,7 @@ mod a320_electrical_circuit_tests {
 
     use uom::si::{
         angular_velocity::revolution_per_minute, electric_potential::volt, length::foot,
-        power::watt, ratio::percent, velocity::knot,
+        power::watt, ratio::percent, thermodynamic_temperature::degree_celsius, velocity::knot,
     };
 
     #[test]
@@ -747,23 +771,27 @@ mod a320_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_bus_output(1)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_bus_output(2)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_ess_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_ess_shed_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.static_inverter_input().is_unpowered());
         assert!(test_bed.ac_stat_inv_bus_output().is_unpowered());
         assert!(test_bed
             .ac_gnd_flt_service_bus_output()
-            .is_single(PotentialOrigin::External));
-        assert!(test_bed.tr_1_input().is_single(PotentialOrigin::External));
-        assert!(test_bed.tr_2_input().is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
+        assert!(test_bed
+            .tr_1_input()
+            .is_single(PotentialOrigin::External(1)));
+        assert!(test_bed
+            .tr_2_input()
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.tr_ess_input().is_unpowered());
         assert!(test_bed
             .dc_bus_output(1)
@@ -1187,9 +1215,11 @@ mod a320_electrical_circuit_tests {
             .is_single(PotentialOrigin::StaticInverter));
         assert!(test_bed
             .ac_gnd_flt_service_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.tr_1_input().is_unpowered());
-        assert!(test_bed.tr_2_input().is_single(PotentialOrigin::External));
+        assert!(test_bed
+            .tr_2_input()
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.tr_ess_input().is_unpowered());
         assert!(test_bed.dc_bus_output(1).is_unpowered());
         assert!(test_bed.dc_bus_output(2).is_unpowered());
@@ -1257,7 +1287,7 @@ mod a320_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_bus_output(ac_bus)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
     }
 
     #[test]
@@ -1270,10 +1300,46 @@ mod a320_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_bus_output(1)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_bus_output(2)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
+    }
+
+    #[rstest]
+    #[case(FailureType::ExternalPowerOverVoltage(1))]
+    #[case(FailureType::ExternalPowerUnderVoltage(1))]
+    #[case(FailureType::ExternalPowerWrongPhaseRotation(1))]
+    #[case(FailureType::ExternalPowerFrequencyDeviation(1))]
+    fn when_external_power_abnormal_ext_pwr_contactor_does_not_close(
+        #[case] failure_type: FailureType,
+    ) {
+        let mut test_bed = test_bed_with()
+            .failed_external_power(failure_type)
+            .connected_external_power()
+            .and()
+            .ext_pwr_on()
+            .run();
+
+        assert!(!test_bed.ext_pwr_contactor_is_closed());
+        assert!(test_bed.ac_bus_output(1).is_unpowered());
+        assert!(test_bed.ac_bus_output(2).is_unpowered());
+    }
+
+    #[test]
+    fn when_external_power_becomes_abnormal_ext_pwr_contactor_opens() {
+        let mut test_bed = test_bed_with()
+            .connected_external_power()
+            .and()
+            .ext_pwr_on()
+            .run();
+        assert!(test_bed.ext_pwr_contactor_is_closed());
+
+        test_bed = test_bed
+            .failed_external_power(FailureType::ExternalPowerUnderVoltage(1))
+            .run();
+
+        assert!(!test_bed.ext_pwr_contactor_is_closed());
     }
 
     #[test]
@@ -1287,10 +1353,10 @@ mod a320_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_bus_output(1)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_bus_output(2)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
     }
 
     #[test]
@@ -1786,6 +1852,63 @@ mod a320_electrical_circuit_tests {
     #[ignore = "Generator overloading is not yet supported."]
     fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {}
 
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn when_battery_in_thermal_runaway_bat_push_button_has_fault_and_contactor_opens(
+        #[case] bat_number: usize,
+    ) {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .failed_battery_thermal_runaway(bat_number);
+
+        for _ in 0..20 {
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(60));
+        }
+
+        assert!(
+            test_bed
+                .battery_temperature(bat_number)
+                .get::<degree_celsius>()
+                > 60.
+        );
+        assert!(test_bed.bat_has_fault(bat_number));
+        assert!(!test_bed.battery_contactor_is_closed(bat_number));
+        assert!(!test_bed.bat_has_fault(3 - bat_number));
+    }
+
+    #[test]
+    fn battery_thermal_runaway_fault_is_reset_by_switching_bat_off() {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .failed_battery_thermal_runaway(1);
+
+        for _ in 0..20 {
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(60));
+        }
+
+        test_bed = test_bed.bat_off(1).run();
+
+        assert!(!test_bed.bat_has_fault(1));
+    }
+
+    #[test]
+    fn battery_temperature_follows_avionics_bay_temperature() {
+        let mut test_bed = test_bed_with().running_engines().run();
+
+        test_bed.command(|a| {
+            a.cabin.temperature = ThermodynamicTemperature::new::<degree_celsius>(35.)
+        });
+        for _ in 0..60 {
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(60));
+        }
+
+        assert!(test_bed.battery_temperature(1).get::<degree_celsius>() > 25.);
+        assert!(!test_bed.bat_has_fault(1));
+    }
+
     #[rstest]
     #[case(1)]
     #[case(2)]
@@ -1904,7 +2027,7 @@ mod a320_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_gnd_flt_service_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .dc_gnd_flt_service_bus_output()
             .is_single(PotentialOrigin::TransformerRectifier(2)));
@@ -2288,6 +2411,22 @@ mod a320_electrical_circuit_tests {
         }
     }
 
+    struct TestCabin {
+        temperature: ThermodynamicTemperature,
+    }
+    impl TestCabin {
+        fn new() -> Self {
+            Self {
+                temperature: ThermodynamicTemperature::new::<degree_celsius>(20.),
+            }
+        }
+    }
+    impl CabinSimulation for TestCabin {
+        fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+            vec![self.temperature; 3]
+        }
+    }
+
     struct A320ElectricalTestAircraft {
         engines: [TestEngine; 2],
         ext_pwr: ExternalPowerSource,
@@ -2298,13 +2437,14 @@ mod a320_electrical_circuit_tests {
         apu_overhead: TestApuOverhead,
         engine_fire_push_buttons: TestEngineFirePushButtons,
         hydraulics: TestHydraulicSystem,
+        cabin: TestCabin,
         force_run_emergency_gen: bool,
     }
     impl A320ElectricalTestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 engines: [TestEngine::new(), TestEngine::new()],
-                ext_pwr: ExternalPowerSource::new(context),
+                ext_pwr: ExternalPowerSource::new(context, 1),
                 elec: A320Electrical::new(context),
                 overhead: A320ElectricalOverheadPanel::new(context),
                 emergency_overhead: A320EmergencyElectricalOverheadPanel::new(context),
@@ -2312,6 +2452,7 @@ mod a320_electrical_circuit_tests {
                 apu_overhead: TestApuOverhead::new(),
                 engine_fire_push_buttons: TestEngineFirePushButtons::new(),
                 hydraulics: TestHydraulicSystem::new(),
+                cabin: TestCabin::new(),
                 force_run_emergency_gen: false,
             }
         }
@@ -2409,6 +2550,7 @@ mod a320_electrical_circuit_tests {
                 [&self.engines[0], &self.engines[1]],
                 &self.hydraulics,
                 &TestLandingGear::new(),
+                &self.cabin,
             );
             self.overhead
                 .update_after_electrical(&self.elec, electricity);
@@ -2518,6 +2660,17 @@ mod a320_electrical_circuit_tests {
             self
         }
 
+        fn failed_external_power(mut self, failure_type: FailureType) -> Self {
+            self.test_bed.fail(failure_type);
+            self
+        }
+
+        fn failed_battery_thermal_runaway(mut self, number: usize) -> Self {
+            self.test_bed
+                .fail(FailureType::BatteryThermalRunaway(number));
+            self
+        }
+
         fn running_emergency_generator(mut self) -> Self {
             self.command(|a| a.running_emergency_generator());
             self.run_waiting_for(Duration::from_secs(100))
@@ -2720,6 +2873,22 @@ mod a320_electrical_circuit_tests {
             self.read_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_HAS_FAULT", number))
         }
 
+        fn ext_pwr_contactor_is_closed(&mut self) -> bool {
+            self.read_by_name("ELEC_CONTACTOR_3XG_IS_CLOSED")
+        }
+
+        fn bat_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_ELEC_BAT_{}_PB_HAS_FAULT", number))
+        }
+
+        fn battery_contactor_is_closed(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("ELEC_CONTACTOR_6PB{}_IS_CLOSED", number))
+        }
+
+        fn battery_temperature(&mut self, number: usize) -> ThermodynamicTemperature {
+            self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE", number))
+        }
+
         fn rat_and_emer_gen_has_fault(&mut self) -> bool {
             self.read_by_name("OVHD_EMER_ELEC_RAT_AND_EMER_GEN_HAS_FAULT")
         }
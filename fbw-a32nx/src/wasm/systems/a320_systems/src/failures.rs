@@ -0,0 +1,218 @@
+use systems::{
+    failures::FailureType,
+    shared::{
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, GearActuatorId, HeatedElement,
+        HydraulicColor, LgciuId, ProximityDetectorId,
+    },
+};
+
+/// All failures of the aircraft which can be activated, with the identifier used by
+/// the failure orchestrator to activate and deactivate them.
+pub fn failures() -> Vec<(u64, FailureType)> {
+    vec![
+        (24_000, FailureType::TransformerRectifier(1)),
+        (24_001, FailureType::TransformerRectifier(2)),
+        (24_002, FailureType::TransformerRectifier(3)),
+        (24_003, FailureType::BatteryThermalRunaway(1)),
+        (24_004, FailureType::BatteryThermalRunaway(2)),
+        (24_005, FailureType::ExternalPowerOverVoltage(1)),
+        (24_006, FailureType::ExternalPowerUnderVoltage(1)),
+        (24_007, FailureType::ExternalPowerWrongPhaseRotation(1)),
+        (24_008, FailureType::ExternalPowerFrequencyDeviation(1)),
+        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+        (
+            29_005,
+            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_006,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+        ),
+        (
+            29_007,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+        ),
+        (
+            29_008,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_009,
+            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
+        ),
+        (
+            29_010,
+            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
+        ),
+        (
+            29_011,
+            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
+        ),
+        (
+            29_012,
+            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
+        ),
+        (
+            29_013,
+            FailureType::HydraulicFluidContamination(HydraulicColor::Green),
+        ),
+        (
+            29_014,
+            FailureType::HydraulicFluidContamination(HydraulicColor::Blue),
+        ),
+        (
+            29_015,
+            FailureType::HydraulicFluidContamination(HydraulicColor::Yellow),
+        ),
+        (29_016, FailureType::PtuShaftShear),
+        (29_017, FailureType::PtuInternalLeakage),
+        (29_018, FailureType::PtuJam),
+        (29_019, FailureType::PtuBarkThresholdDrift),
+        (30_000, FailureType::ProbeHeat(1, HeatedElement::PitotProbe)),
+        (
+            30_001,
+            FailureType::ProbeHeat(1, HeatedElement::StaticPorts),
+        ),
+        (
+            30_002,
+            FailureType::ProbeHeat(1, HeatedElement::AngleOfAttackProbe),
+        ),
+        (
+            30_003,
+            FailureType::ProbeHeat(1, HeatedElement::TotalAirTemperatureProbe),
+        ),
+        (30_004, FailureType::ProbeHeat(1, HeatedElement::Windshield)),
+        (30_005, FailureType::ProbeHeat(1, HeatedElement::SideWindow)),
+        (30_010, FailureType::ProbeHeat(2, HeatedElement::PitotProbe)),
+        (
+            30_011,
+            FailureType::ProbeHeat(2, HeatedElement::StaticPorts),
+        ),
+        (
+            30_012,
+            FailureType::ProbeHeat(2, HeatedElement::AngleOfAttackProbe),
+        ),
+        (
+            30_013,
+            FailureType::ProbeHeat(2, HeatedElement::TotalAirTemperatureProbe),
+        ),
+        (30_014, FailureType::ProbeHeat(2, HeatedElement::Windshield)),
+        (30_015, FailureType::ProbeHeat(2, HeatedElement::SideWindow)),
+        (30_020, FailureType::ProbeHeat(3, HeatedElement::PitotProbe)),
+        (
+            30_021,
+            FailureType::ProbeHeat(3, HeatedElement::StaticPorts),
+        ),
+        (
+            30_022,
+            FailureType::ProbeHeat(3, HeatedElement::AngleOfAttackProbe),
+        ),
+        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+        (
+            32_004,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+        ),
+        (
+            32_005,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+        ),
+        (
+            32_006,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+        ),
+        (
+            32_007,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+        ),
+        (
+            32_008,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+        ),
+        (
+            32_009,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+        ),
+        (
+            32_010,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+        ),
+        (
+            32_011,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+        ),
+        (
+            32_012,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+        ),
+        (
+            32_013,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+        ),
+        (
+            32_014,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+        ),
+        (
+            32_015,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+        ),
+        (
+            32_020,
+            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_021,
+            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_022,
+            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_023,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_024,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_025,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+        ),
+        (
+            32_100,
+            FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
+        ),
+        (
+            32_101,
+            FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
+        ),
+        (32_150, FailureType::BrakeAccumulatorGasLeak),
+        (32_200, FailureType::TyreBurst(1)),
+        (32_201, FailureType::TyreBurst(2)),
+        (32_202, FailureType::TyreBurst(3)),
+        (32_203, FailureType::TyreBurst(4)),
+        (32_210, FailureType::TyreDeflation(1)),
+        (32_211, FailureType::TyreDeflation(2)),
+        (32_212, FailureType::TyreDeflation(3)),
+        (32_213, FailureType::TyreDeflation(4)),
+        (32_220, FailureType::BrakeSeizure(1)),
+        (32_221, FailureType::BrakeSeizure(2)),
+        (32_222, FailureType::BrakeSeizure(3)),
+        (32_223, FailureType::BrakeSeizure(4)),
+        (34_000, FailureType::RadioAltimeter(1)),
+        (34_001, FailureType::RadioAltimeter(2)),
+        (79_001, FailureType::EngineOilLeak(1)),
+        (79_002, FailureType::EngineOilLeak(2)),
+        (79_011, FailureType::EngineOilPumpFailure(1)),
+        (79_012, FailureType::EngineOilPumpFailure(2)),
+    ]
+}
@@ -1,34 +1,1038 @@
-use systems::simulation::{
-    InitContext, Read, SimulationElement, SimulatorReader, VariableIdentifier,
+use systems::{
+    accept_iterable,
+    apu::AuxiliaryPowerUnitFireOverheadPanel,
+    fuel::{FuelPump, FuelTank, FuelValve},
+    overhead::{OnOffFaultPushButton, OnOffPushButton},
+    shared::{ElectricalBusType, EngineFirePushButtons},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
 };
-use uom::si::{f64::*, mass::kilogram};
+use uom::si::{
+    f64::*,
+    mass::kilogram,
+    mass_rate::{kilogram_per_hour, kilogram_per_second},
+    power::watt,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum A320FuelTankType {
+    LeftOuter,
+    LeftInner,
+    Center,
+    RightInner,
+    RightOuter,
+}
+impl A320FuelTankType {
+    fn inner(side: usize) -> Self {
+        match side {
+            1 => A320FuelTankType::LeftInner,
+            _ => A320FuelTankType::RightInner,
+        }
+    }
+
+    fn outer(side: usize) -> Self {
+        match side {
+            1 => A320FuelTankType::LeftOuter,
+            _ => A320FuelTankType::RightOuter,
+        }
+    }
+}
 
 pub struct A320Fuel {
     unlimited_fuel_id: VariableIdentifier,
-    fuel_tank_left_main_quantity_id: VariableIdentifier,
+    sim_tank_quantity_ids: [VariableIdentifier; 5],
+    engine_fuel_flow_ids: [VariableIdentifier; 2],
 
     unlimited_fuel: bool,
-    left_inner_tank_fuel_quantity: Mass,
+    sim_tank_quantities: [Mass; 5],
+    engine_fuel_flows: [MassRate; 2],
+    is_initialised: bool,
+
+    tanks: [FuelTank; 5],
+    wing_tank_pumps: [[FuelPump; 2]; 2],
+    center_tank_pumps: [FuelPump; 2],
+    engine_lp_valves: [FuelValve; 2],
+    apu_lp_valve: FuelValve,
+    crossfeed_valve: FuelValve,
+    transfer_valves: [FuelValve; 2],
+    transfer_valve_is_latched_open: [bool; 2],
 }
 impl A320Fuel {
+    const OUTER_TANK_CAPACITY_KG: f64 = 691.;
+    const INNER_TANK_CAPACITY_KG: f64 = 5436.;
+    const CENTER_TANK_CAPACITY_KG: f64 = 6476.;
+    const PUMP_POWER_DEMAND_WATT: f64 = 900.;
+
+    // The transfer valves open when the inner tank reaches its low level
+    // and remain open until the next refuel.
+    const INNER_TANK_LOW_LEVEL_KG: f64 = 750.;
+    const OUTER_TO_INNER_TRANSFER_RATE_KG_PER_S: f64 = 0.6;
+
+    // Differences below this are caused by the unit conversions of the simulator.
+    const SIMULATOR_QUANTITY_TOLERANCE_KG: f64 = 1.;
+
     pub fn new(context: &mut InitContext) -> Self {
         A320Fuel {
             unlimited_fuel_id: context.get_identifier("UNLIMITED FUEL".to_owned()),
-            fuel_tank_left_main_quantity_id: context
-                .get_identifier("FUEL TANK LEFT MAIN QUANTITY".to_owned()),
+            sim_tank_quantity_ids: [
+                context.get_identifier("FUEL TANK LEFT AUX QUANTITY".to_owned()),
+                context.get_identifier("FUEL TANK LEFT MAIN QUANTITY".to_owned()),
+                context.get_identifier("FUEL TANK CENTER QUANTITY".to_owned()),
+                context.get_identifier("FUEL TANK RIGHT MAIN QUANTITY".to_owned()),
+                context.get_identifier("FUEL TANK RIGHT AUX QUANTITY".to_owned()),
+            ],
+            engine_fuel_flow_ids: [
+                context.get_identifier("ENGINE_FF:1".to_owned()),
+                context.get_identifier("ENGINE_FF:2".to_owned()),
+            ],
 
             unlimited_fuel: false,
-            left_inner_tank_fuel_quantity: Mass::new::<kilogram>(0.),
+            sim_tank_quantities: [Mass::new::<kilogram>(0.); 5],
+            engine_fuel_flows: [MassRate::new::<kilogram_per_second>(0.); 2],
+            is_initialised: false,
+
+            tanks: [
+                FuelTank::new(
+                    context,
+                    "LEFT_OUTER",
+                    Mass::new::<kilogram>(Self::OUTER_TANK_CAPACITY_KG),
+                ),
+                FuelTank::new(
+                    context,
+                    "LEFT_INNER",
+                    Mass::new::<kilogram>(Self::INNER_TANK_CAPACITY_KG),
+                ),
+                FuelTank::new(
+                    context,
+                    "CENTER",
+                    Mass::new::<kilogram>(Self::CENTER_TANK_CAPACITY_KG),
+                ),
+                FuelTank::new(
+                    context,
+                    "RIGHT_INNER",
+                    Mass::new::<kilogram>(Self::INNER_TANK_CAPACITY_KG),
+                ),
+                FuelTank::new(
+                    context,
+                    "RIGHT_OUTER",
+                    Mass::new::<kilogram>(Self::OUTER_TANK_CAPACITY_KG),
+                ),
+            ],
+            wing_tank_pumps: [
+                [
+                    FuelPump::new(context, "L_TK_1", ElectricalBusType::AlternatingCurrent(1))
+                        .with_power_demand(Power::new::<watt>(Self::PUMP_POWER_DEMAND_WATT)),
+                    FuelPump::new(context, "L_TK_2", ElectricalBusType::AlternatingCurrent(2))
+                        .with_power_demand(Power::new::<watt>(Self::PUMP_POWER_DEMAND_WATT)),
+                ],
+                [
+                    FuelPump::new(context, "R_TK_1", ElectricalBusType::AlternatingCurrent(1))
+                        .with_power_demand(Power::new::<watt>(Self::PUMP_POWER_DEMAND_WATT)),
+                    FuelPump::new(context, "R_TK_2", ElectricalBusType::AlternatingCurrent(2))
+                        .with_power_demand(Power::new::<watt>(Self::PUMP_POWER_DEMAND_WATT)),
+                ],
+            ],
+            center_tank_pumps: [
+                FuelPump::new(
+                    context,
+                    "CTR_TK_1",
+                    ElectricalBusType::AlternatingCurrent(1),
+                )
+                .with_power_demand(Power::new::<watt>(Self::PUMP_POWER_DEMAND_WATT)),
+                FuelPump::new(
+                    context,
+                    "CTR_TK_2",
+                    ElectricalBusType::AlternatingCurrent(2),
+                )
+                .with_power_demand(Power::new::<watt>(Self::PUMP_POWER_DEMAND_WATT)),
+            ],
+            engine_lp_valves: [
+                FuelValve::new(
+                    context,
+                    "ENG_1_LP",
+                    ElectricalBusType::DirectCurrentEssential,
+                    true,
+                ),
+                FuelValve::new(
+                    context,
+                    "ENG_2_LP",
+                    ElectricalBusType::DirectCurrent(2),
+                    true,
+                ),
+            ],
+            apu_lp_valve: FuelValve::new(
+                context,
+                "APU_LP",
+                ElectricalBusType::DirectCurrentBattery,
+                true,
+            ),
+            crossfeed_valve: FuelValve::new(
+                context,
+                "XFEED",
+                ElectricalBusType::DirectCurrentEssential,
+                false,
+            ),
+            transfer_valves: [
+                FuelValve::new(
+                    context,
+                    "L_TRANSFER",
+                    ElectricalBusType::DirectCurrentEssential,
+                    false,
+                ),
+                FuelValve::new(
+                    context,
+                    "R_TRANSFER",
+                    ElectricalBusType::DirectCurrent(2),
+                    false,
+                ),
+            ],
+            transfer_valve_is_latched_open: [false; 2],
         }
     }
 
-    pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
-        self.unlimited_fuel || self.left_inner_tank_fuel_quantity > Mass::new::<kilogram>(0.)
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A320FuelOverheadPanel,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
+        slats_are_retracted: bool,
+    ) {
+        if !self.is_initialised || self.is_refuelled(context) {
+            self.take_quantities_from_simulator();
+            self.is_initialised = true;
+        }
+
+        // In automatic mode the center tank pumps stop when the slats are extended, such
+        // that the engines are fed from the wing tanks during takeoff and landing.
+        let center_tank_pumps_may_run = overhead.center_tank_pumps_are_in_manual_mode()
+            || (slats_are_retracted && !self.tank(A320FuelTankType::Center).is_empty());
+        for side in 1..=2 {
+            for (number, pump) in self.wing_tank_pumps[side - 1].iter_mut().enumerate() {
+                pump.update(overhead.wing_tank_pump_is_on(side, number + 1));
+            }
+            self.center_tank_pumps[side - 1]
+                .update(overhead.center_tank_pump_is_on(side) && center_tank_pumps_may_run);
+
+            self.engine_lp_valves[side - 1].update(!engine_fire_push_buttons.is_released(side));
+        }
+        self.apu_lp_valve
+            .update(!apu_fire_overhead.fire_button_is_released());
+        self.crossfeed_valve.update(overhead.crossfeed_is_on());
+        self.update_transfer_valves();
+
+        if !self.unlimited_fuel {
+            self.transfer_outer_to_inner_tanks(context);
+            self.burn_engine_fuel(context);
+        }
+    }
+
+    /// The modelled quantities are written to the simulator's tanks. Thus the simulator's tanks
+    /// only differ from them when fuel is loaded through the simulator. This is only taken into
+    /// account while on the ground with both engines not consuming any fuel.
+    fn is_refuelled(&self, context: &UpdateContext) -> bool {
+        context.is_on_ground()
+            && self
+                .engine_fuel_flows
+                .iter()
+                .all(|flow| flow.get::<kilogram_per_second>() <= 0.)
+            && self
+                .tanks
+                .iter()
+                .zip(self.sim_tank_quantities)
+                .any(|(tank, quantity)| {
+                    (tank.quantity() - quantity).abs().get::<kilogram>()
+                        > Self::SIMULATOR_QUANTITY_TOLERANCE_KG
+                })
+    }
+
+    fn take_quantities_from_simulator(&mut self) {
+        for (tank, quantity) in self.tanks.iter_mut().zip(self.sim_tank_quantities) {
+            tank.set_quantity(quantity);
+        }
+
+        self.transfer_valve_is_latched_open = [false; 2];
+    }
+
+    fn update_transfer_valves(&mut self) {
+        for side in 1..=2 {
+            if self.tank(A320FuelTankType::inner(side)).quantity()
+                < Mass::new::<kilogram>(Self::INNER_TANK_LOW_LEVEL_KG)
+            {
+                self.transfer_valve_is_latched_open[side - 1] = true;
+            }
+
+            self.transfer_valves[side - 1].update(self.transfer_valve_is_latched_open[side - 1]);
+        }
+    }
+
+    fn transfer_outer_to_inner_tanks(&mut self, context: &UpdateContext) {
+        for side in 1..=2 {
+            if self.transfer_valves[side - 1].is_open() {
+                let transferred = self.tank_mut(A320FuelTankType::outer(side)).take(
+                    MassRate::new::<kilogram_per_second>(
+                        Self::OUTER_TO_INNER_TRANSFER_RATE_KG_PER_S,
+                    ) * context.delta_as_time(),
+                );
+                let remainder = self
+                    .tank_mut(A320FuelTankType::inner(side))
+                    .fill(transferred);
+                self.tank_mut(A320FuelTankType::outer(side)).fill(remainder);
+            }
+        }
+    }
+
+    /// Burns the engine fuel flow from the feeding tank. When the feeding tank runs empty,
+    /// the remainder is taken from the tank which feeds the engine next.
+    fn burn_engine_fuel(&mut self, context: &UpdateContext) {
+        for engine_number in 1..=2 {
+            if !self.engine_lp_valves[engine_number - 1].is_open() {
+                continue;
+            }
+
+            let mut required = self.engine_fuel_flows[engine_number - 1] * context.delta_as_time();
+            while required > Mass::new::<kilogram>(0.) {
+                match self.feeding_tank(engine_number) {
+                    Some(tank_type) => required -= self.tank_mut(tank_type).take(required),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// The tank from which the given side's fuel manifold is fed. The center tank pumps
+    /// have priority over the wing tank pumps. When no pump delivers fuel, the crossfeed
+    /// valve allows the other side to feed the manifold. As a last resort the engine
+    /// suction feeds from its inner tank.
+    fn feeding_tank(&self, side: usize) -> Option<A320FuelTankType> {
+        self.pumped_tank(side)
+            .or_else(|| {
+                if self.crossfeed_valve.is_open() {
+                    self.pumped_tank(if side == 1 { 2 } else { 1 })
+                } else {
+                    None
+                }
+            })
+            .or_else(|| {
+                let inner = A320FuelTankType::inner(side);
+                if self.tank(inner).is_empty() {
+                    None
+                } else {
+                    Some(inner)
+                }
+            })
+    }
+
+    fn pumped_tank(&self, side: usize) -> Option<A320FuelTankType> {
+        if self.center_tank_pumps[side - 1].is_running()
+            && !self.tank(A320FuelTankType::Center).is_empty()
+        {
+            Some(A320FuelTankType::Center)
+        } else if self.wing_tank_pumps[side - 1]
+            .iter()
+            .any(|pump| pump.is_running())
+            && !self.tank(A320FuelTankType::inner(side)).is_empty()
+        {
+            Some(A320FuelTankType::inner(side))
+        } else {
+            None
+        }
+    }
+
+    fn tank(&self, tank_type: A320FuelTankType) -> &FuelTank {
+        &self.tanks[tank_type as usize]
+    }
+
+    fn tank_mut(&mut self, tank_type: A320FuelTankType) -> &mut FuelTank {
+        &mut self.tanks[tank_type as usize]
+    }
+
+    /// The APU is fed from the left fuel manifold.
+    pub fn apu_fuel_is_available(&self) -> bool {
+        self.apu_lp_valve.is_open() && (self.unlimited_fuel || self.feeding_tank(1).is_some())
+    }
+
+    fn wing_tank_pump_is_running(&self, side: usize, number: usize) -> bool {
+        self.wing_tank_pumps[side - 1][number - 1].is_running()
+    }
+
+    fn center_tank_pump_has_fault(&self, side: usize) -> bool {
+        let pump = &self.center_tank_pumps[side - 1];
+        pump.is_commanded_on() && !pump.is_running()
     }
 }
 impl SimulationElement for A320Fuel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.tanks, visitor);
+        for pumps in self.wing_tank_pumps.iter_mut() {
+            accept_iterable!(pumps, visitor);
+        }
+        accept_iterable!(self.center_tank_pumps, visitor);
+        accept_iterable!(self.engine_lp_valves, visitor);
+        self.apu_lp_valve.accept(visitor);
+        self.crossfeed_valve.accept(visitor);
+        accept_iterable!(self.transfer_valves, visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
-        self.left_inner_tank_fuel_quantity = reader.read(&self.fuel_tank_left_main_quantity_id);
+
+        for (quantity, id) in self
+            .sim_tank_quantities
+            .iter_mut()
+            .zip(&self.sim_tank_quantity_ids)
+        {
+            *quantity = reader.read(id);
+        }
+
+        for (flow, id) in self
+            .engine_fuel_flows
+            .iter_mut()
+            .zip(&self.engine_fuel_flow_ids)
+        {
+            let flow_kg_per_hour: f64 = reader.read(id);
+            *flow = MassRate::new::<kilogram_per_hour>(flow_kg_per_hour.max(0.));
+        }
+    }
+}
+
+pub struct A320FuelOverheadPanel {
+    mode_select_is_manual_id: VariableIdentifier,
+    mode_select_is_manual: bool,
+
+    wing_tank_pumps: [[OnOffFaultPushButton; 2]; 2],
+    center_tank_pumps: [OnOffFaultPushButton; 2],
+    crossfeed: OnOffPushButton,
+}
+impl A320FuelOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            mode_select_is_manual_id: context.get_identifier("OVHD_FUEL_MODESEL_MANUAL".to_owned()),
+            mode_select_is_manual: false,
+
+            wing_tank_pumps: [
+                [
+                    OnOffFaultPushButton::new_on(context, "FUEL_L_TK_1"),
+                    OnOffFaultPushButton::new_on(context, "FUEL_L_TK_2"),
+                ],
+                [
+                    OnOffFaultPushButton::new_on(context, "FUEL_R_TK_1"),
+                    OnOffFaultPushButton::new_on(context, "FUEL_R_TK_2"),
+                ],
+            ],
+            center_tank_pumps: [
+                OnOffFaultPushButton::new_on(context, "FUEL_CTR_TK_1"),
+                OnOffFaultPushButton::new_on(context, "FUEL_CTR_TK_2"),
+            ],
+            crossfeed: OnOffPushButton::new_off(context, "FUEL_XFEED"),
+        }
+    }
+
+    pub fn update_after_fuel(&mut self, fuel: &A320Fuel) {
+        for side in 1..=2 {
+            for (number, pb) in self.wing_tank_pumps[side - 1].iter_mut().enumerate() {
+                pb.set_fault(pb.is_on() && !fuel.wing_tank_pump_is_running(side, number + 1));
+            }
+
+            self.center_tank_pumps[side - 1].set_fault(fuel.center_tank_pump_has_fault(side));
+        }
+    }
+
+    fn wing_tank_pump_is_on(&self, side: usize, number: usize) -> bool {
+        self.wing_tank_pumps[side - 1][number - 1].is_on()
+    }
+
+    fn center_tank_pump_is_on(&self, side: usize) -> bool {
+        self.center_tank_pumps[side - 1].is_on()
+    }
+
+    fn center_tank_pumps_are_in_manual_mode(&self) -> bool {
+        self.mode_select_is_manual
+    }
+
+    fn crossfeed_is_on(&self) -> bool {
+        self.crossfeed.is_on()
+    }
+}
+impl SimulationElement for A320FuelOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for pbs in self.wing_tank_pumps.iter_mut() {
+            accept_iterable!(pbs, visitor);
+        }
+        accept_iterable!(self.center_tank_pumps, visitor);
+        self.crossfeed.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.mode_select_is_manual = reader.read(&self.mode_select_is_manual_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::EngineFireOverheadPanel,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use uom::si::{electric_potential::volt, mass::pound};
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        buses: Vec<ElectricalBus>,
+        is_powered: bool,
+
+        fuel: A320Fuel,
+        fuel_overhead: A320FuelOverheadPanel,
+        engine_fire_overhead: EngineFireOverheadPanel<2>,
+        apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
+        slats_are_retracted: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                buses: vec![
+                    ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                    ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                    ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                    ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                    ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
+                ],
+                is_powered: true,
+                fuel: A320Fuel::new(context),
+                fuel_overhead: A320FuelOverheadPanel::new(context),
+                engine_fire_overhead: EngineFireOverheadPanel::new(context),
+                apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
+                slats_are_retracted: true,
+            }
+        }
+
+        fn unpower(&mut self) {
+            self.is_powered = false;
+        }
+
+        fn extend_slats(&mut self) {
+            self.slats_are_retracted = false;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_powered {
+                for bus in &self.buses {
+                    electricity.flow(&self.electricity_source, bus);
+                }
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fuel.update(
+                context,
+                &self.fuel_overhead,
+                &self.engine_fire_overhead,
+                &self.apu_fire_overhead,
+                self.slats_are_retracted,
+            );
+            self.fuel_overhead.update_after_fuel(&self.fuel);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fuel.accept(visitor);
+            self.fuel_overhead.accept(visitor);
+            self.engine_fire_overhead.accept(visitor);
+            self.apu_fire_overhead.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct A320FuelTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl A320FuelTestBed {
+        const TANK_NAMES: [&'static str; 5] = [
+            "LEFT_OUTER",
+            "LEFT_INNER",
+            "CENTER",
+            "RIGHT_INNER",
+            "RIGHT_OUTER",
+        ];
+        const SIM_TANK_NAMES: [&'static str; 5] = [
+            "FUEL TANK LEFT AUX QUANTITY",
+            "FUEL TANK LEFT MAIN QUANTITY",
+            "FUEL TANK CENTER QUANTITY",
+            "FUEL TANK RIGHT MAIN QUANTITY",
+            "FUEL TANK RIGHT AUX QUANTITY",
+        ];
+
+        fn new() -> Self {
+            let mut fuel_test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            fuel_test_bed = fuel_test_bed
+                .with_sim_tank_quantities([0., 0., 0., 0., 0.])
+                .on_ground();
+            fuel_test_bed.run();
+
+            fuel_test_bed
+        }
+
+        /// Quantities in kg in the order left outer, left inner, center, right inner, right outer.
+        fn with_sim_tank_quantities(mut self, quantities: [f64; 5]) -> Self {
+            for (name, quantity) in Self::SIM_TANK_NAMES.iter().zip(quantities) {
+                self.write_by_name(name, Mass::new::<kilogram>(quantity));
+            }
+
+            self
+        }
+
+        /// Writes the modelled quantities to the simulator's tanks, as the fuel aspect does.
+        fn write_quantities_to_sim_tanks(&mut self) {
+            for (name, sim_name) in Self::TANK_NAMES.iter().zip(Self::SIM_TANK_NAMES) {
+                let quantity: Mass = self.read_by_name(&format!("FUEL_{}_QUANTITY", name));
+                self.write_by_name(sim_name, quantity);
+            }
+        }
+
+        fn loaded_with(self, quantities: [f64; 5]) -> Self {
+            let mut test_bed = self.with_sim_tank_quantities(quantities).on_ground();
+            test_bed.run();
+
+            test_bed.in_flight()
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.set_on_ground(true);
+            self
+        }
+
+        fn in_flight(mut self) -> Self {
+            self.set_on_ground(false);
+            self
+        }
+
+        fn engine_fuel_flow(mut self, engine_number: usize, kg_per_hour: f64) -> Self {
+            self.write_by_name(&format!("ENGINE_FF:{}", engine_number), kg_per_hour);
+            self
+        }
+
+        fn wing_tank_pumps_off(mut self, side: char) -> Self {
+            self.write_by_name(&format!("OVHD_FUEL_{}_TK_1_PB_IS_ON", side), false);
+            self.write_by_name(&format!("OVHD_FUEL_{}_TK_2_PB_IS_ON", side), false);
+            self
+        }
+
+        fn center_tank_pumps_in_manual_mode(mut self) -> Self {
+            self.write_by_name("OVHD_FUEL_MODESEL_MANUAL", true);
+            self
+        }
+
+        fn slats_extended(mut self) -> Self {
+            self.command(|a| a.extend_slats());
+            self
+        }
+
+        fn center_tank_pumps_off(mut self) -> Self {
+            self.write_by_name("OVHD_FUEL_CTR_TK_1_PB_IS_ON", false);
+            self.write_by_name("OVHD_FUEL_CTR_TK_2_PB_IS_ON", false);
+            self
+        }
+
+        fn crossfeed_on(mut self) -> Self {
+            self.write_by_name("OVHD_FUEL_XFEED_PB_IS_ON", true);
+            self
+        }
+
+        fn release_engine_fire_push_button(mut self, engine_number: usize) -> Self {
+            self.write_by_name(&format!("FIRE_BUTTON_ENG{}", engine_number), true);
+            self
+        }
+
+        fn release_apu_fire_push_button(mut self) -> Self {
+            self.write_by_name("FIRE_BUTTON_APU", true);
+            self
+        }
+
+        fn unlimited_fuel(mut self) -> Self {
+            self.write_by_name("UNLIMITED FUEL", true);
+            self
+        }
+
+        fn unpowered(mut self) -> Self {
+            self.command(|a| a.unpower());
+            self
+        }
+
+        fn run_for(mut self, delta: Duration) -> Self {
+            self.run_with_delta(delta);
+            self.write_quantities_to_sim_tanks();
+            self
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn tank_quantity_kg(&mut self, name: &str) -> f64 {
+            let quantity: Mass = self.read_by_name(&format!("FUEL_{}_QUANTITY", name));
+            quantity.get::<kilogram>()
+        }
+
+        fn apu_fuel_is_available(&self) -> bool {
+            self.query(|a| a.fuel.apu_fuel_is_available())
+        }
+
+        fn valve_is_open(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("FUEL_{}_VALVE_IS_OPEN", name))
+        }
+
+        fn pump_is_running(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("FUEL_{}_PUMP_IS_RUNNING", name))
+        }
+
+        fn pump_push_button_has_fault(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("OVHD_FUEL_{}_PB_HAS_FAULT", name))
+        }
+    }
+    impl TestBed for A320FuelTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> A320FuelTestBed {
+        A320FuelTestBed::new()
+    }
+
+    fn test_bed_with() -> A320FuelTestBed {
+        test_bed()
+    }
+
+    #[test]
+    fn tanks_are_loaded_from_the_simulator() {
+        let mut test_bed = test_bed_with().loaded_with([500., 4000., 3000., 4000., 500.]);
+
+        assert!((test_bed.tank_quantity_kg("LEFT_OUTER") - 500.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("LEFT_INNER") - 4000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("CENTER") - 3000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("RIGHT_INNER") - 4000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("RIGHT_OUTER") - 500.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn simulator_fuel_is_ignored_in_flight() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .and()
+            .with_sim_tank_quantities([0., 0., 0., 0., 0.])
+            .run_for(Duration::from_secs(1));
+
+        assert!((test_bed.tank_quantity_kg("CENTER") - 3000.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn engines_burn_center_tank_fuel_first() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .engine_fuel_flow(1, 3600.)
+            .engine_fuel_flow(2, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!((test_bed.tank_quantity_kg("CENTER") - 2980.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("LEFT_INNER") - 4000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("RIGHT_INNER") - 4000.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn engines_burn_inner_tank_fuel_when_center_tank_pumps_are_off() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .center_tank_pumps_off()
+            .engine_fuel_flow(1, 3600.)
+            .engine_fuel_flow(2, 1800.)
+            .run_for(Duration::from_secs(10));
+
+        assert!((test_bed.tank_quantity_kg("CENTER") - 3000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("LEFT_INNER") - 3990.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("RIGHT_INNER") - 3995.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn fuel_demand_not_met_by_an_emptying_tank_is_taken_from_the_next_feeding_tank() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 5., 4000., 500.])
+            .engine_fuel_flow(1, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.tank_quantity_kg("CENTER").abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("LEFT_INNER") - 3995.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn center_tank_pumps_stop_in_automatic_mode_when_slats_are_extended() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .slats_extended()
+            .engine_fuel_flow(1, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.pump_is_running("CTR_TK_1"));
+        assert!(!test_bed.pump_push_button_has_fault("CTR_TK_1"));
+        assert!((test_bed.tank_quantity_kg("CENTER") - 3000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("LEFT_INNER") - 3990.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn center_tank_pumps_stop_in_automatic_mode_when_center_tank_is_empty() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 0., 4000., 500.])
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.pump_is_running("CTR_TK_1"));
+        assert!(!test_bed.pump_push_button_has_fault("CTR_TK_1"));
+    }
+
+    #[test]
+    fn center_tank_pumps_run_in_manual_mode_when_slats_are_extended() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .center_tank_pumps_in_manual_mode()
+            .slats_extended()
+            .engine_fuel_flow(1, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.pump_is_running("CTR_TK_1"));
+        assert!((test_bed.tank_quantity_kg("CENTER") - 2990.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn modelled_quantities_are_kept_on_the_ground_while_the_simulator_tanks_are_unchanged() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 700., 0., 4000., 500.])
+            .on_ground()
+            .run_for(Duration::from_secs(50))
+            .and()
+            .run_for(Duration::from_secs(50));
+
+        assert!((test_bed.tank_quantity_kg("LEFT_OUTER") - 440.).abs() < 1e-6);
+        assert!(test_bed.valve_is_open("L_TRANSFER"));
+    }
+
+    #[test]
+    fn fuel_loaded_through_the_simulator_on_the_ground_is_taken_over() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .on_ground()
+            .run_for(Duration::from_secs(1))
+            .and()
+            .with_sim_tank_quantities([691., 5000., 3000., 5000., 691.])
+            .run_for(Duration::from_secs(1));
+
+        assert!((test_bed.tank_quantity_kg("LEFT_INNER") - 5000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("RIGHT_OUTER") - 691.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn crossfeed_allows_an_engine_to_be_fed_from_the_opposite_side() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 0., 4000., 500.])
+            .wing_tank_pumps_off('L')
+            .crossfeed_on()
+            .engine_fuel_flow(1, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.valve_is_open("XFEED"));
+        assert!((test_bed.tank_quantity_kg("LEFT_INNER") - 4000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("RIGHT_INNER") - 3990.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn without_pumps_engine_suction_feeds_from_its_inner_tank() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .wing_tank_pumps_off('L')
+            .wing_tank_pumps_off('R')
+            .center_tank_pumps_off()
+            .engine_fuel_flow(2, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!((test_bed.tank_quantity_kg("CENTER") - 3000.).abs() < 1e-6);
+        assert!((test_bed.tank_quantity_kg("RIGHT_INNER") - 3990.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn released_engine_fire_push_button_closes_lp_valve_and_stops_burn() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .release_engine_fire_push_button(1)
+            .engine_fuel_flow(1, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.valve_is_open("ENG_1_LP"));
+        assert!(test_bed.valve_is_open("ENG_2_LP"));
+        assert!((test_bed.tank_quantity_kg("CENTER") - 3000.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn transfer_valve_opens_at_inner_tank_low_level() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 800., 0., 4000., 500.])
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.valve_is_open("L_TRANSFER"));
+
+        test_bed = test_bed
+            .engine_fuel_flow(1, 36000.)
+            .run_for(Duration::from_secs(10))
+            .and()
+            .engine_fuel_flow(1, 0.)
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.valve_is_open("L_TRANSFER"));
+        assert!(!test_bed.valve_is_open("R_TRANSFER"));
+    }
+
+    #[test]
+    fn open_transfer_valve_moves_outer_tank_fuel_to_inner_tank() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 700., 0., 4000., 500.])
+            .run_for(Duration::from_secs(100));
+
+        assert!(test_bed.tank_quantity_kg("LEFT_OUTER") < 500.);
+        assert!(
+            (test_bed.tank_quantity_kg("LEFT_OUTER") + test_bed.tank_quantity_kg("LEFT_INNER")
+                - 1200.)
+                .abs()
+                < 1e-6
+        );
+        assert!((test_bed.tank_quantity_kg("RIGHT_OUTER") - 500.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn transfer_valve_stays_open_after_inner_tank_is_refilled_in_flight() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([691., 700., 0., 4000., 500.])
+            .run_for(Duration::from_secs(200));
+
+        assert!(test_bed.tank_quantity_kg("LEFT_INNER") > 750.);
+        assert!(test_bed.valve_is_open("L_TRANSFER"));
+    }
+
+    #[test]
+    fn unlimited_fuel_does_not_burn_fuel() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .unlimited_fuel()
+            .engine_fuel_flow(1, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!((test_bed.tank_quantity_kg("CENTER") - 3000.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn apu_has_fuel_available_with_fuel_in_the_left_inner_tank() {
+        let test_bed = test_bed_with()
+            .loaded_with([0., 1000., 0., 0., 0.])
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn apu_has_no_fuel_available_when_left_side_is_empty() {
+        let test_bed = test_bed_with()
+            .loaded_with([0., 0., 0., 1000., 0.])
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn apu_is_fed_through_crossfeed_from_the_right_side() {
+        let test_bed = test_bed_with()
+            .loaded_with([0., 0., 0., 1000., 0.])
+            .crossfeed_on()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn apu_has_no_fuel_available_when_apu_fire_push_button_released() {
+        let test_bed = test_bed_with()
+            .loaded_with([0., 1000., 0., 0., 0.])
+            .release_apu_fire_push_button()
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn apu_has_fuel_available_with_unlimited_fuel() {
+        let test_bed = test_bed_with()
+            .unlimited_fuel()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn unpowered_pump_push_buttons_show_fault() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .unpowered()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.pump_push_button_has_fault("L_TK_1"));
+        assert!(test_bed.pump_push_button_has_fault("CTR_TK_2"));
+    }
+
+    #[test]
+    fn powered_pump_push_buttons_do_not_show_fault() {
+        let mut test_bed = test_bed_with()
+            .loaded_with([500., 4000., 3000., 4000., 500.])
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.pump_push_button_has_fault("L_TK_1"));
+        assert!(!test_bed.pump_push_button_has_fault("R_TK_2"));
+        assert!(!test_bed.pump_push_button_has_fault("CTR_TK_1"));
+    }
+
+    #[test]
+    fn tank_quantity_is_written_in_pounds() {
+        let mut test_bed = test_bed_with().loaded_with([0., 1000., 0., 0., 0.]);
+
+        let quantity: f64 = test_bed.read_by_name("FUEL_LEFT_INNER_QUANTITY");
+        assert!((quantity - Mass::new::<kilogram>(1000.).get::<pound>()).abs() < 1e-6);
     }
 }
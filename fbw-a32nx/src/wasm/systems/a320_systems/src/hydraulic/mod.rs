@@ -8,6 +8,7 @@ use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     electric_current::ampere,
     f64::*,
+    force::newton,
     length::meter,
     mass::kilogram,
     pressure::psi,
@@ -22,9 +23,11 @@ use systems::{
     engine::Engine,
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
+        anti_skid::AntiSkid,
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
-            BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
+            BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController, BrakeMode,
+            BrakeModeSelector,
         },
         cargo_doors::{CargoDoor, HydraulicDoorController},
         electrical_generator::{GeneratorControlUnit, HydraulicGeneratorMotor},
@@ -44,6 +47,7 @@ use systems::{
         rudder_control::{
             AngularPositioningController, RudderMechanicalControl, YawDamperActuatorController,
         },
+        runway_overrun_protection::RunwayOverrunProtection,
         trimmable_horizontal_stabilizer::{
             ManualPitchTrimController, PitchTrimActuatorController,
             TrimmableHorizontalStabilizerAssembly,
@@ -53,23 +57,28 @@ use systems::{
         PowerTransferUnitCharacteristics, PowerTransferUnitController, PressureSwitch,
         PressureSwitchType, PriorityValve, PumpController, RamAirTurbine, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet},
+    landing_gear::{
+        brake_heat::{BrakeFans, BrakeTemperatureMonitoring},
+        wheel::MainGearWheels,
+        GearSystemSensors, LandingGearControlInterfaceUnitSet,
+    },
     overhead::{
         AutoOffFaultPushButton, AutoOnFaultPushButton, MomentaryOnPushButton, MomentaryPushButton,
     },
     shared::{
         interpolation, random_from_normal_distribution, random_from_range,
-        update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AirbusElectricPumpId,
-        AirbusEngineDrivenPumpId, DelayedFalseLogicGate, DelayedPulseTrueLogicGate,
-        DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
-        EmergencyElectricalState, EmergencyGeneratorControlUnit, EmergencyGeneratorPower,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
-        LgciuWeightOnWheels, RamAirTurbineController, ReservoirAirPressure, SectionPressure,
-        TrimmableHorizontalStabilizer,
+        update_iterator::MaxStepLoop, AdirsDiscreteOutputs, AdirsMeasurementOutputs,
+        AirbusElectricPumpId, AirbusEngineDrivenPumpId, DelayedFalseLogicGate,
+        DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
+        EmergencyElectricalRatPushButton, EmergencyElectricalState, EmergencyGeneratorControlUnit,
+        EmergencyGeneratorPower, EngineFirePushButtons, GearWheel, HydraulicColor,
+        LandingGearHandle, LgciuInterface, LgciuWeightOnWheels, RamAirTurbineController,
+        ReservoirAirPressure, SectionPressure, TrimmableHorizontalStabilizer,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, StartState, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, StartState, UpdateContext,
+        VariableIdentifier, Write,
     },
 };
 
@@ -1302,6 +1311,8 @@ struct A320PowerTransferUnitCharacteristics {
     activation_delta_pressure: Pressure,
 
     shot_to_shot_variability: Ratio,
+
+    wear: Ratio,
 }
 impl A320PowerTransferUnitCharacteristics {
     // Randomisation parameters
@@ -1333,6 +1344,11 @@ impl A320PowerTransferUnitCharacteristics {
 
     const SHOT_TO_SHOT_VARIABILITY_PERCENT_RATIO: f64 = 0.05;
 
+    const WORN_WEAR_MEAN: f64 = 0.75;
+    const WORN_WEAR_STD_DEV: f64 = 0.1;
+    const NOMINAL_WEAR_MEAN: f64 = 0.2;
+    const NOMINAL_WEAR_STD_DEV: f64 = 0.1;
+
     fn new_randomized() -> Self {
         let randomized_is_ptu_worn_out = Self::randomized_is_ptu_worn_out();
 
@@ -1351,6 +1367,8 @@ impl A320PowerTransferUnitCharacteristics {
             shot_to_shot_variability: Ratio::new::<ratio>(
                 Self::SHOT_TO_SHOT_VARIABILITY_PERCENT_RATIO,
             ),
+
+            wear: Self::randomized_wear(randomized_is_ptu_worn_out),
         }
     }
 
@@ -1371,6 +1389,8 @@ impl A320PowerTransferUnitCharacteristics {
             shot_to_shot_variability: Ratio::new::<ratio>(
                 Self::SHOT_TO_SHOT_VARIABILITY_PERCENT_RATIO,
             ),
+
+            wear: Ratio::new::<ratio>(1.),
         }
     }
 
@@ -1400,6 +1420,20 @@ impl A320PowerTransferUnitCharacteristics {
         }
     }
 
+    fn randomized_wear(is_worn_out: bool) -> Ratio {
+        let (mean, std_dev) = if is_worn_out {
+            (Self::WORN_WEAR_MEAN, Self::WORN_WEAR_STD_DEV)
+        } else {
+            (Self::NOMINAL_WEAR_MEAN, Self::NOMINAL_WEAR_STD_DEV)
+        };
+
+        Ratio::new::<ratio>(
+            random_from_normal_distribution(mean, std_dev)
+                .max(0.)
+                .min(1.),
+        )
+    }
+
     fn randomized_deactivation_delta_pressure(is_worn_out: bool) -> Pressure {
         if is_worn_out {
             Pressure::new::<psi>(
@@ -1438,6 +1472,10 @@ impl PowerTransferUnitCharacteristics for A320PowerTransferUnitCharacteristics {
     fn shot_to_shot_variability(&self) -> Ratio {
         self.shot_to_shot_variability
     }
+
+    fn wear(&self) -> Ratio {
+        self.wear
+    }
 }
 
 pub(super) struct A320Hydraulic {
@@ -1481,6 +1519,9 @@ pub(super) struct A320Hydraulic {
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
     braking_force: A320BrakingForce,
+    brake_temperature: BrakeTemperatureMonitoring<4>,
+    main_gear_wheels: MainGearWheels<4>,
+    brake_fans: BrakeFans,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1565,6 +1606,12 @@ impl A320Hydraulic {
 
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
 
+    const BRAKE_HEAT_SINK_MASS_KG: f64 = 70.;
+    // Braking force of each brake at full braking force factor
+    const MAX_BRAKING_FORCE_PER_BRAKE_N: f64 = 80000.;
+    const NOMINAL_TYRE_PRESSURE_PSI: f64 = 200.;
+    const BRAKE_FANS_POWER_BUS: ElectricalBusType = ElectricalBusType::AlternatingCurrent(2);
+
     // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
 
@@ -1583,6 +1630,7 @@ impl A320Hydraulic {
 
             nose_steering: SteeringActuator::new(
                 context,
+                "NOSE_WHEEL",
                 Angle::new::<degree>(75.),
                 AngularVelocity::new::<radian_per_second>(0.35),
                 Length::new::<meter>(0.075),
@@ -1699,6 +1747,16 @@ impl A320Hydraulic {
             ),
 
             braking_force: A320BrakingForce::new(context),
+            brake_temperature: BrakeTemperatureMonitoring::new(
+                context,
+                Mass::new::<kilogram>(Self::BRAKE_HEAT_SINK_MASS_KG),
+                Force::new::<newton>(Self::MAX_BRAKING_FORCE_PER_BRAKE_N),
+            ),
+            main_gear_wheels: MainGearWheels::new(
+                context,
+                Pressure::new::<psi>(Self::NOMINAL_TYRE_PRESSURE_PSI),
+            ),
+            brake_fans: BrakeFans::new(context, Self::BRAKE_FANS_POWER_BUS),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -1802,7 +1860,7 @@ impl A320Hydraulic {
         rat_and_emer_gen_man_on: &impl EmergencyElectricalRatPushButton,
         emergency_elec: &(impl EmergencyElectricalState + EmergencyGeneratorPower),
         reservoir_pneumatics: &impl ReservoirAirPressure,
-        adirs: &impl AdirsDiscreteOutputs,
+        adirs: &(impl AdirsDiscreteOutputs + AdirsMeasurementOutputs),
     ) {
         self.core_hydraulic_updater.update(context);
 
@@ -1837,6 +1895,14 @@ impl A320Hydraulic {
                 lgcius.lgciu2(),
                 reservoir_pneumatics,
             );
+
+            self.update_brakes_and_wheels(
+                &context.with_delta(cur_time_step),
+                engine1,
+                engine2,
+                lgcius.lgciu1(),
+                adirs,
+            );
         }
 
         self.ptu_high_pitch_sound_active
@@ -2071,6 +2137,7 @@ impl A320Hydraulic {
         self.brake_steer_computer.update(
             context,
             self.green_circuit.system_section(),
+            self.yellow_circuit.system_section(),
             &self.braking_circuit_altn,
             lgciu1,
             lgciu2,
@@ -2092,14 +2159,7 @@ impl A320Hydraulic {
 
         self.pushback_tug.update(context);
 
-        self.braking_force.update_forces(
-            context,
-            &self.braking_circuit_norm,
-            &self.braking_circuit_altn,
-            engine1,
-            engine2,
-            &self.pushback_tug,
-        );
+        self.brake_fans.update(lgciu1.main_down_and_locked());
 
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
@@ -2438,6 +2498,44 @@ impl A320Hydraulic {
         );
     }
 
+    // Braking forces, wheel slip and anti skid are updated at the fixed step rate so wheels can't
+    // lock between two anti skid updates at low frame rates
+    fn update_brakes_and_wheels(
+        &mut self,
+        context: &UpdateContext,
+        engine1: &impl Engine,
+        engine2: &impl Engine,
+        lgciu1: &impl LgciuInterface,
+        adirs: &impl AdirsMeasurementOutputs,
+    ) {
+        self.braking_force.update_forces(
+            context,
+            &self.braking_circuit_norm,
+            &self.braking_circuit_altn,
+            engine1,
+            engine2,
+            &self.pushback_tug,
+            &self.main_gear_wheels,
+        );
+
+        self.brake_temperature.update(
+            context,
+            self.braking_force.left_braking_force(),
+            self.braking_force.right_braking_force(),
+            !lgciu1.main_up_and_locked(),
+            self.brake_fans.is_running(),
+        );
+        self.main_gear_wheels.update(
+            context,
+            &self.brake_temperature,
+            self.braking_force.left_braking_force(),
+            self.braking_force.right_braking_force(),
+        );
+
+        self.brake_steer_computer
+            .update_anti_skid(context, &self.main_gear_wheels, adirs);
+    }
+
     // Actual logic of HYD PTU memo computed here until done within FWS
     fn should_show_hyd_ptu_message_on_ecam(&self) -> bool {
         let ptu_valve_ctrol_off = !self.power_transfer_unit_controller.should_enable();
@@ -2491,6 +2589,10 @@ impl A320Hydraulic {
     pub fn gear_system(&self) -> &impl GearSystemSensors {
         &self.gear_system
     }
+
+    pub fn slats_are_retracted(&self) -> bool {
+        self.slat_system.position_feedback() < Angle::new::<degree>(1.)
+    }
 }
 impl SimulationElement for A320Hydraulic {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -2529,6 +2631,9 @@ impl SimulationElement for A320Hydraulic {
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
         self.braking_force.accept(visitor);
+        self.brake_temperature.accept(visitor);
+        self.main_gear_wheels.accept(visitor);
+        self.brake_fans.accept(visitor);
 
         self.emergency_gen.accept(visitor);
         self.nose_steering.accept(visitor);
@@ -3428,6 +3533,8 @@ impl SimulationElement for A320RamAirTurbineController {
 struct A320BrakeSystemOutputs {
     left_demand: Ratio,
     right_demand: Ratio,
+    left_anti_skid_ratio: f64,
+    right_anti_skid_ratio: f64,
     pressure_limit: Pressure,
 }
 impl A320BrakeSystemOutputs {
@@ -3435,6 +3542,8 @@ impl A320BrakeSystemOutputs {
         Self {
             left_demand: Ratio::new::<ratio>(0.),
             right_demand: Ratio::new::<ratio>(0.),
+            left_anti_skid_ratio: 1.,
+            right_anti_skid_ratio: 1.,
             pressure_limit: Pressure::new::<psi>(3000.),
         }
     }
@@ -3452,6 +3561,12 @@ impl A320BrakeSystemOutputs {
             .max(Ratio::new::<ratio>(0.));
     }
 
+    /// Share of the brake demands actually sent to the brakes once anti skid released slipping wheels
+    fn set_anti_skid_ratios(&mut self, left_ratio: f64, right_ratio: f64) {
+        self.left_anti_skid_ratio = left_ratio;
+        self.right_anti_skid_ratio = right_ratio;
+    }
+
     fn set_no_demands(&mut self) {
         self.left_demand = Ratio::new::<ratio>(0.);
         self.right_demand = Ratio::new::<ratio>(0.);
@@ -3476,11 +3591,11 @@ impl BrakeCircuitController for A320BrakeSystemOutputs {
     }
 
     fn left_brake_demand(&self) -> Ratio {
-        self.left_demand
+        self.left_demand * self.left_anti_skid_ratio
     }
 
     fn right_brake_demand(&self) -> Ratio {
-        self.right_demand
+        self.right_demand * self.right_anti_skid_ratio
     }
 }
 
@@ -3507,9 +3622,11 @@ struct A320HydraulicBrakeSteerComputerUnit {
     norm_brake_outputs: A320BrakeSystemOutputs,
     alternate_brake_outputs: A320BrakeSystemOutputs,
 
-    normal_brakes_available: bool,
+    brake_mode_selector: BrakeModeSelector,
+    is_in_flight_gear_lever_up: bool,
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
+    anti_skid: AntiSkid<4>,
 
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
@@ -3544,10 +3661,11 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
     const MAX_STEERING_ANGLE_DEMAND_DEGREES: f64 = 74.;
 
-    // Minimum pressure hysteresis on green until main switched on ALTN brakes
-    // Feedback by Cpt. Chaos — 25/04/2021 #pilot-feedback
-    const MIN_PRESSURE_BRAKE_ALTN_HYST_LO: f64 = 1305.;
-    const MIN_PRESSURE_BRAKE_ALTN_HYST_HI: f64 = 2176.;
+    // Nominal braking from pedals is limited to 2538psi, alternate without anti skid to 1160psi
+    const NORMAL_BRAKE_PRESSURE_LIMIT_PSI: f64 = 2538.;
+    const ALTERNATE_BRAKE_PRESSURE_LIMIT_PSI: f64 = 2538.;
+    const ALTERNATE_WITHOUT_ANTI_SKID_BRAKE_PRESSURE_LIMIT_PSI: f64 = 1160.;
+    const PARKING_BRAKE_PRESSURE_LIMIT_PSI: f64 = 2103.;
 
     // Min pressure when parking brake enabled. Lower normal braking is allowed to use pilot input as emergency braking
     // Feedback by avteknisyan — 25/04/2021 #pilot-feedback
@@ -3555,8 +3673,6 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
     const AUTOBRAKE_GEAR_RETRACTION_DURATION_S: f64 = 3.;
 
-    const PILOT_INPUT_DETECTION_TRESHOLD: f64 = 0.2;
-
     fn new(context: &mut InitContext) -> Self {
         Self {
             park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
@@ -3580,11 +3696,20 @@ impl A320HydraulicBrakeSteerComputerUnit {
             right_brake_pilot_input: Ratio::new::<ratio>(0.0),
             norm_brake_outputs: A320BrakeSystemOutputs::new(),
             alternate_brake_outputs: A320BrakeSystemOutputs::new(),
-            normal_brakes_available: false,
+            brake_mode_selector: BrakeModeSelector::new(
+                context,
+                false,
+                Pressure::new::<psi>(Self::NORMAL_BRAKE_PRESSURE_LIMIT_PSI),
+                Pressure::new::<psi>(Self::ALTERNATE_BRAKE_PRESSURE_LIMIT_PSI),
+                Pressure::new::<psi>(Self::ALTERNATE_WITHOUT_ANTI_SKID_BRAKE_PRESSURE_LIMIT_PSI),
+                Pressure::new::<psi>(Self::PARKING_BRAKE_PRESSURE_LIMIT_PSI),
+            ),
+            is_in_flight_gear_lever_up: false,
             should_disable_auto_brake_when_retracting: DelayedTrueLogicGate::new(
                 Duration::from_secs_f64(Self::AUTOBRAKE_GEAR_RETRACTION_DURATION_S),
             ),
             anti_skid_activated: true,
+            anti_skid: AntiSkid::new(),
 
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
@@ -3616,21 +3741,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
     }
 
     fn allow_autobrake_arming(&self) -> bool {
-        self.anti_skid_activated && self.normal_brakes_available
-    }
-
-    fn update_normal_braking_availability(&mut self, normal_braking_circuit_pressure: Pressure) {
-        if normal_braking_circuit_pressure.get::<psi>() > Self::MIN_PRESSURE_BRAKE_ALTN_HYST_HI
-            && (self.left_brake_pilot_input.get::<ratio>() < Self::PILOT_INPUT_DETECTION_TRESHOLD
-                && self.right_brake_pilot_input.get::<ratio>()
-                    < Self::PILOT_INPUT_DETECTION_TRESHOLD)
-        {
-            self.normal_brakes_available = true;
-        } else if normal_braking_circuit_pressure.get::<psi>()
-            < Self::MIN_PRESSURE_BRAKE_ALTN_HYST_LO
-        {
-            self.normal_brakes_available = false;
-        }
+        self.anti_skid_activated && self.brake_mode_selector.is_normal_braking_available()
     }
 
     fn update_brake_pressure_limitation(&mut self) {
@@ -3639,27 +3750,14 @@ impl A320HydraulicBrakeSteerComputerUnit {
             || self.right_brake_pilot_input
                 > self.alternate_brake_outputs.right_demand() + Ratio::new::<ratio>(0.2);
 
-        // Nominal braking from pedals is limited to 2538psi
         self.norm_brake_outputs
-            .set_pressure_limit(Pressure::new::<psi>(2538.));
+            .set_pressure_limit(self.brake_mode_selector.normal_pressure_limit());
 
-        let alternate_brake_pressure_limit = Pressure::new::<psi>(if self.parking_brake_demand {
-            // If no pilot action, standard park brake pressure limit
-            if !yellow_manual_braking_input {
-                2103.
-            } else {
-                // Else manual action limited to a higher max nominal pressure
-                2538.
-            }
-        } else if !self.anti_skid_activated {
-            1160.
-        } else {
-            // Else if any manual braking we use standard limit
-            2538.
-        });
-
-        self.alternate_brake_outputs
-            .set_pressure_limit(alternate_brake_pressure_limit);
+        // Parking brake pressure is limited unless the pilot is braking manually
+        self.alternate_brake_outputs.set_pressure_limit(
+            self.brake_mode_selector
+                .alternate_pressure_limit(yellow_manual_braking_input),
+        );
     }
 
     /// Updates brakes and nose steering demands
@@ -3667,6 +3765,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
         &mut self,
         context: &UpdateContext,
         current_pressure: &impl SectionPressure,
+        alternate_pressure: &impl SectionPressure,
         alternate_circuit: &BrakeCircuit,
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
@@ -3676,7 +3775,14 @@ impl A320HydraulicBrakeSteerComputerUnit {
     ) {
         self.update_steering_demands(lgciu1, engine1, engine2);
 
-        self.update_normal_braking_availability(current_pressure.pressure());
+        self.brake_mode_selector.update(
+            current_pressure,
+            alternate_pressure,
+            self.anti_skid_activated,
+            self.parking_brake_demand,
+            self.left_brake_pilot_input,
+            self.right_brake_pilot_input,
+        );
         self.update_brake_pressure_limitation();
 
         self.autobrake_controller.update(
@@ -3685,18 +3791,19 @@ impl A320HydraulicBrakeSteerComputerUnit {
             self.allow_autobrake_arming(),
             self.left_brake_pilot_input,
             self.right_brake_pilot_input,
+            self.ground_speed,
             lgciu1,
             lgciu2,
         );
 
-        let is_in_flight_gear_lever_up = !(lgciu1.left_and_right_gear_compressed(true)
+        self.is_in_flight_gear_lever_up = !(lgciu1.left_and_right_gear_compressed(true)
             || lgciu2.left_and_right_gear_compressed(true)
             || lgciu1.gear_handle_is_down());
 
         self.should_disable_auto_brake_when_retracting
-            .update(context, is_in_flight_gear_lever_up);
+            .update(context, self.is_in_flight_gear_lever_up);
 
-        if is_in_flight_gear_lever_up {
+        if self.is_in_flight_gear_lever_up {
             if self.should_disable_auto_brake_when_retracting.output() {
                 self.norm_brake_outputs.set_no_demands();
             } else {
@@ -3707,9 +3814,7 @@ impl A320HydraulicBrakeSteerComputerUnit {
 
             self.alternate_brake_outputs.set_no_demands();
         } else {
-            let green_used_for_brakes = self.normal_brakes_available
-                && self.anti_skid_activated
-                && !self.parking_brake_demand;
+            let green_used_for_brakes = self.brake_mode_selector.mode() == BrakeMode::Normal;
 
             if green_used_for_brakes {
                 // Final output on normal brakes is max(pilot demand , autobrake demand) to allow pilot override autobrake demand
@@ -3750,6 +3855,37 @@ impl A320HydraulicBrakeSteerComputerUnit {
         }
     }
 
+    /// Anti skid releases pressure of slipping wheels, in normal braking and in alternate braking
+    /// as long as the alternate supply is pressurised.
+    /// Updated at the hydraulic time step, as it needs to catch a slipping wheel before it locks.
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        main_gear_wheels: &MainGearWheels<4>,
+        adirs: &impl AdirsMeasurementOutputs,
+    ) {
+        let is_active = self.anti_skid_activated
+            && !self.is_in_flight_gear_lever_up
+            && matches!(
+                self.brake_mode_selector.mode(),
+                BrakeMode::Normal | BrakeMode::AlternateWithAntiSkid
+            );
+
+        let reference_speed = [1, 2, 3]
+            .iter()
+            .find_map(|&adiru_number| adirs.ground_speed(adiru_number).normal_value());
+
+        self.anti_skid
+            .update(context, is_active, reference_speed, main_gear_wheels);
+
+        let left_ratio = self.anti_skid.left_demand_ratio();
+        let right_ratio = self.anti_skid.right_demand_ratio();
+        self.norm_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+        self.alternate_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+    }
+
     fn update_steering_demands(
         &mut self,
         lgciu1: &impl LgciuInterface,
@@ -3815,9 +3951,18 @@ impl A320HydraulicBrakeSteerComputerUnit {
 impl SimulationElement for A320HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.brake_mode_selector.accept(visitor);
         visitor.visit(self);
     }
 
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.anti_skid.snapshot(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.anti_skid.restore(snapshot);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.parking_brake_demand = reader.read(&self.park_brake_lever_pos_id);
 
@@ -3855,6 +4000,10 @@ struct A320BrakingForce {
     left_braking_force: f64,
     right_braking_force: f64,
 
+    // Braking forces corrected with the state of the wheels, which are generated in the simulator
+    left_force_factor: f64,
+    right_force_factor: f64,
+
     flap_position: f64,
 
     is_chocks_enabled: bool,
@@ -3883,6 +4032,9 @@ impl A320BrakingForce {
             left_braking_force: 0.,
             right_braking_force: 0.,
 
+            left_force_factor: 0.,
+            right_force_factor: 0.,
+
             flap_position: 0.,
 
             is_chocks_enabled: false,
@@ -3898,6 +4050,7 @@ impl A320BrakingForce {
         engine1: &impl Engine,
         engine2: &impl Engine,
         pushback_tug: &PushbackTug,
+        main_gear_wheels: &MainGearWheels<4>,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -3919,9 +4072,24 @@ impl A320BrakingForce {
 
         self.correct_with_flaps_state(context);
 
+        self.left_force_factor = main_gear_wheels
+            .left_braking_force(self.left_braking_force())
+            .get::<ratio>();
+        self.right_force_factor = main_gear_wheels
+            .right_braking_force(self.right_braking_force())
+            .get::<ratio>();
+
         self.update_chocks_braking(context, engine1, engine2, pushback_tug);
     }
 
+    fn left_braking_force(&self) -> Ratio {
+        Ratio::new::<ratio>(self.left_braking_force)
+    }
+
+    fn right_braking_force(&self) -> Ratio {
+        Ratio::new::<ratio>(self.right_braking_force)
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -3960,6 +4128,8 @@ impl A320BrakingForce {
         if self.is_chocks_enabled && chocks_on_wheels {
             self.left_braking_force = 1.;
             self.right_braking_force = 1.;
+            self.left_force_factor = 1.;
+            self.right_force_factor = 1.;
         }
     }
 }
@@ -3967,8 +4137,8 @@ impl A320BrakingForce {
 impl SimulationElement for A320BrakingForce {
     fn write(&self, writer: &mut SimulatorWriter) {
         // BRAKE XXXX FORCE FACTOR is the actual braking force we want the plane to generate in the simulator
-        writer.write(&self.brake_left_force_factor_id, self.left_braking_force);
-        writer.write(&self.brake_right_force_factor_id, self.right_braking_force);
+        writer.write(&self.brake_left_force_factor_id, self.left_force_factor);
+        writer.write(&self.brake_right_force_factor_id, self.right_force_factor);
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -3994,6 +4164,7 @@ pub struct A320AutobrakeController {
     external_disarm_event_id: VariableIdentifier,
 
     deceleration_governor: AutobrakeDecelerationGovernor,
+    runway_overrun_protection: RunwayOverrunProtection,
 
     target: Acceleration,
     mode: AutobrakeMode,
@@ -4041,6 +4212,10 @@ impl A320AutobrakeController {
             external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
+            runway_overrun_protection: RunwayOverrunProtection::new(
+                context,
+                Force::new::<newton>(4. * A320Hydraulic::MAX_BRAKING_FORCE_PER_BRAKE_N),
+            ),
             target: Acceleration::new::<meter_per_second_squared>(0.),
             mode: AutobrakeMode::NONE,
             arming_is_allowed_by_bcu: context.is_in_flight(),
@@ -4155,7 +4330,23 @@ impl A320AutobrakeController {
                 && self.should_reject_max_mode_after_time_in_flight.output())
     }
 
+    /// Steady deceleration the armed mode settles to once its profile is complete
+    fn armed_mode_deceleration(&self) -> Option<Acceleration> {
+        let deceleration_ms2 = match self.mode {
+            AutobrakeMode::LOW => Self::LOW_MODE_DECEL_PROFILE_ACCEL_MS2.last().copied(),
+            AutobrakeMode::MED => Self::MED_MODE_DECEL_PROFILE_ACCEL_MS2.last().copied(),
+            AutobrakeMode::MAX => Some(Self::MAX_MODE_DECEL_TARGET_MS2),
+            _ => None,
+        };
+
+        deceleration_ms2.map(Acceleration::new::<meter_per_second_squared>)
+    }
+
     fn calculate_target(&mut self) -> Acceleration {
+        if self.runway_overrun_protection.rop_is_active() {
+            return Acceleration::new::<meter_per_second_squared>(Self::MAX_MODE_DECEL_TARGET_MS2);
+        }
+
         Acceleration::new::<meter_per_second_squared>(match self.mode {
             AutobrakeMode::NONE => Self::OFF_MODE_DECEL_TARGET_MS2,
             AutobrakeMode::LOW => interpolation(
@@ -4204,6 +4395,7 @@ impl A320AutobrakeController {
         allow_arming: bool,
         pedal_input_left: Ratio,
         pedal_input_right: Ratio,
+        ground_speed: Velocity,
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
     ) {
@@ -4220,11 +4412,25 @@ impl A320AutobrakeController {
         self.deceleration_governor
             .engage_when(self.should_engage_deceleration_governor(context));
 
+        self.runway_overrun_protection.update(
+            context,
+            ground_speed,
+            self.armed_mode_deceleration(),
+            self.deceleration_governor.is_engaged(),
+            self.deceleration_governor.acceleration(),
+        );
+
         self.target = self.calculate_target();
         self.deceleration_governor.update(context, self.target);
     }
 }
 impl SimulationElement for A320AutobrakeController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.runway_overrun_protection.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.armed_mode_id, self.mode as u8 as f64);
         writer.write(&self.armed_mode_id_set, -1.);
@@ -5650,11 +5856,16 @@ mod tests {
                 cargo_doors::{DoorControlState, HydraulicDoorController},
                 electrical_generator::TestGenerator,
             },
-            landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
+            landing_gear::{
+                wheel::SurfaceCondition, GearSystemState, LandingGear,
+                LandingGearControlInterfaceUnitSet,
+            },
             shared::{
+                arinc429::{Arinc429Word, SignStatus},
                 EmergencyElectricalState, EmergencyGeneratorControlUnit, LgciuId, PotentialOrigin,
             },
             simulation::{
+                manifest::VariableManifest,
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
                 Aircraft, InitContext,
             },
@@ -5667,6 +5878,7 @@ mod tests {
             length::foot,
             mass_density::kilogram_per_cubic_meter,
             ratio::{percent, ratio},
+            velocity::{foot_per_second, meter_per_second},
             volume::liter,
         };
 
@@ -5700,10 +5912,51 @@ mod tests {
         #[derive(Default)]
         struct A320TestAdirus {
             airspeed: Velocity,
+            ground_speed: Velocity,
         }
         impl A320TestAdirus {
             fn update(&mut self, context: &UpdateContext) {
-                self.airspeed = context.true_airspeed()
+                self.airspeed = context.true_airspeed();
+                self.ground_speed = Velocity::new::<meter_per_second>(
+                    context.local_velocity().to_ms_vector()[2].abs(),
+                );
+            }
+        }
+        impl AdirsMeasurementOutputs for A320TestAdirus {
+            fn is_fully_aligned(&self, _: usize) -> bool {
+                true
+            }
+
+            fn latitude(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn longitude(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn heading(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn true_heading(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn vertical_speed(&self, _: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(Velocity::default(), SignStatus::NoComputedData)
+            }
+
+            fn altitude(&self, _: usize) -> Arinc429Word<Length> {
+                Arinc429Word::new(Length::default(), SignStatus::NoComputedData)
+            }
+
+            fn computed_airspeed(&self, _: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(self.airspeed, SignStatus::NormalOperation)
+            }
+
+            fn ground_speed(&self, _: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(self.ground_speed, SignStatus::NormalOperation)
             }
         }
         impl AdirsDiscreteOutputs for A320TestAdirus {
@@ -5853,7 +6106,7 @@ mod tests {
                     ),
                     adirus: A320TestAdirus::default(),
                     electrical: A320TestElectrical::new(),
-                    ext_pwr: ExternalPowerSource::new(context),
+                    ext_pwr: ExternalPowerSource::new(context, 1),
                     powered_source_ac: TestElectricitySource::powered(
                         context,
                         PotentialOrigin::EngineGenerator(1),
@@ -6304,6 +6557,40 @@ mod tests {
                 self.read_by_name("HYD_BRAKE_ALTN_ACC_PRESS")
             }
 
+            fn brake_mode(&mut self) -> BrakeMode {
+                ReadByName::<A320HydraulicsTestBed, f64>::read_by_name(self, "HYD_BRAKE_MODE")
+                    .into()
+            }
+
+            fn get_brake_temperature_celsius(&mut self, number: usize) -> f64 {
+                self.read_by_name(&format!("BRAKE_TEMPERATURE_{}", number))
+            }
+
+            fn get_brake_left_force_factor(&mut self) -> f64 {
+                self.read_by_name("BRAKE LEFT FORCE FACTOR")
+            }
+
+            fn get_brake_right_force_factor(&mut self) -> f64 {
+                self.read_by_name("BRAKE RIGHT FORCE FACTOR")
+            }
+
+            fn is_main_wheel_locked(&self, number: usize) -> bool {
+                self.query(|a| a.hydraulics.main_gear_wheels.wheel(number).is_locked())
+            }
+
+            fn is_anti_skid_releasing(&self, number: usize) -> bool {
+                self.query(|a| {
+                    a.hydraulics
+                        .brake_steer_computer
+                        .anti_skid
+                        .is_releasing(number)
+                })
+            }
+
+            fn get_tyre_pressure_psi(&mut self, number: usize) -> f64 {
+                self.read_by_name(&format!("TYRE_PRESSURE_{}", number))
+            }
+
             fn get_brake_yellow_accumulator_fluid_volume(&self) -> Volume {
                 self.query(|a| a.get_yellow_brake_accumulator_fluid_volume())
             }
@@ -6440,6 +6727,11 @@ mod tests {
                 self
             }
 
+            fn rolling_at(mut self, ground_speed: Velocity) -> Self {
+                self.write_by_name("VELOCITY BODY Z", ground_speed.get::<foot_per_second>());
+                self
+            }
+
             fn on_the_ground_after_touchdown(mut self) -> Self {
                 self.set_indicated_altitude(Length::new::<foot>(0.));
                 self.set_on_ground(true);
@@ -6614,6 +6906,11 @@ mod tests {
                 self
             }
 
+            fn set_surface_condition(mut self, surface_condition: SurfaceCondition) -> Self {
+                self.write_by_name("SURFACE CONDITION", surface_condition);
+                self
+            }
+
             fn set_yellow_e_pump(mut self, is_auto: bool) -> Self {
                 self.write_by_name("OVHD_HYD_EPUMPY_PB_IS_AUTO", is_auto);
                 self
@@ -6818,6 +7115,32 @@ mod tests {
                 self
             }
 
+            fn set_landing_runway(mut self, length: Length) -> Self {
+                self.write_by_name("ROW_ROP_RUNWAY_LENGTH", length.get::<meter>());
+                self.write_by_name("ROW_ROP_THRESHOLD_LATITUDE", 0.);
+                self.write_by_name("ROW_ROP_THRESHOLD_LONGITUDE", 0.);
+                self.write_by_name("ROW_ROP_RUNWAY_HEADING", 90.);
+                self
+            }
+
+            fn rolling_on_runway(
+                mut self,
+                ground_speed: Velocity,
+                distance_from_threshold: Length,
+            ) -> Self {
+                self.write_by_name("GPS GROUND SPEED", ground_speed);
+                self.write_by_name("PLANE LATITUDE", 0.);
+                self.write_by_name(
+                    "PLANE LONGITUDE",
+                    Angle::new::<radian>(distance_from_threshold.get::<meter>() / 6_371_000.),
+                );
+                self
+            }
+
+            fn is_rop_active(&mut self) -> bool {
+                self.read_by_name("ROP_ACTIVE")
+            }
+
             fn set_deploy_ground_spoilers(mut self) -> Self {
                 self.write_by_name("SEC_1_GROUND_SPOILER_OUT", true);
                 self.write_by_name("SEC_2_GROUND_SPOILER_OUT", true);
@@ -7330,6 +7653,49 @@ mod tests {
             assert!(test_bed.yellow_pressure() < Pressure::new::<psi>(3100.));
         }
 
+        #[test]
+        fn hydraulic_state_is_restored_from_snapshot() {
+            let mut pressurised = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+            assert!(pressurised.is_green_pressure_switch_pressurised());
+            let green_reservoir_volume = pressurised.get_green_reservoir_volume();
+            let snapshot = pressurised.snapshot();
+
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .run_one_tick();
+            assert!(test_bed.green_pressure() < Pressure::new::<psi>(100.));
+
+            test_bed.restore(&snapshot).unwrap();
+
+            assert!(test_bed.green_pressure() > Pressure::new::<psi>(2500.));
+            assert!(
+                (test_bed.get_green_reservoir_volume() - green_reservoir_volume).abs()
+                    < Volume::new::<gallon>(0.01)
+            );
+        }
+
+        #[test]
+        fn hydraulic_pressures_are_published_in_the_variable_manifest() {
+            let manifest =
+                VariableManifest::new(Default::default(), A320HydraulicsTestAircraft::new);
+
+            let entry = manifest
+                .entry("HYD_GREEN_SYSTEM_1_SECTION_PRESSURE")
+                .unwrap();
+            assert!(entry.is_written());
+            assert!(entry
+                .accesses()
+                .iter()
+                .all(|access| access.unit == Some("psi")));
+        }
+
         #[test]
         fn green_edp_buildup() {
             let mut test_bed = test_bed_on_ground_with()
@@ -8494,6 +8860,51 @@ mod tests {
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(3500.));
         }
 
+        #[test]
+        fn braking_while_rolling_heats_the_brakes() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            let initial_temperature = test_bed.get_brake_temperature_celsius(1);
+
+            test_bed = test_bed
+                .rolling_at(Velocity::new::<knot>(60.))
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.get_brake_temperature_celsius(1) > initial_temperature + 20.);
+            assert!(test_bed.get_brake_temperature_celsius(4) > initial_temperature + 20.);
+        }
+
+        #[test]
+        fn tyre_burst_drags_the_aircraft_towards_its_side() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed.fail(FailureType::TyreBurst(3));
+            test_bed = test_bed
+                .rolling_at(Velocity::new::<knot>(60.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.get_tyre_pressure_psi(3) < 1.);
+            assert!(test_bed.get_tyre_pressure_psi(1) > 150.);
+            assert!(test_bed.get_brake_left_force_factor() < 0.01);
+            assert!(test_bed.get_brake_right_force_factor() > 0.1);
+        }
+
         #[test]
         fn no_norm_brake_inversion() {
             let mut test_bed = test_bed_on_ground_with()
@@ -8669,6 +9080,138 @@ mod tests {
             );
         }
 
+        #[test]
+        fn anti_skid_keeps_wheels_rolling_on_icy_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(15));
+
+            test_bed = test_bed
+                .rolling_at(Velocity::new::<knot>(100.))
+                .set_surface_condition(SurfaceCondition::Icy)
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.brake_mode() == BrakeMode::Normal);
+            assert!((1..=4).all(|number| !test_bed.is_main_wheel_locked(number)));
+        }
+
+        #[test]
+        fn wheels_lock_on_icy_runway_without_anti_skid() {
+            let mut test_bed = test_bed_on_ground_with()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .set_anti_skid(false)
+                .run_waiting_for(Duration::from_secs(15));
+
+            test_bed = test_bed
+                .rolling_at(Velocity::new::<knot>(100.))
+                .set_surface_condition(SurfaceCondition::Icy)
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(!test_bed.is_anti_skid_releasing(1));
+            assert!((1..=4).all(|number| test_bed.is_main_wheel_locked(number)));
+        }
+
+        #[test]
+        fn no_anti_skid_release_when_braking_on_dry_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(15));
+
+            test_bed = test_bed
+                .rolling_at(Velocity::new::<knot>(100.))
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!((1..=4).all(|number| !test_bed.is_anti_skid_releasing(number)));
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(2000.));
+        }
+
+        #[test]
+        fn alternate_braking_on_accumulator_only_is_without_anti_skid() {
+            let mut test_bed = test_bed_on_ground_with()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(15));
+
+            assert!(test_bed.brake_mode() == BrakeMode::Normal);
+
+            // Losing green: alternate braking from yellow keeps anti skid
+            test_bed = test_bed
+                .set_ptu_state(false)
+                .set_green_ed_pump(false)
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(test_bed.green_pressure() <= Pressure::new::<psi>(100.));
+            assert!(test_bed.brake_mode() == BrakeMode::AlternateWithAntiSkid);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(2000.));
+            assert!(test_bed.get_brake_right_yellow_pressure() > Pressure::new::<psi>(2000.));
+
+            // Losing yellow: braking on accumulator only, limited pressure without anti skid
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(0.))
+                .set_right_brake(Ratio::new::<percent>(0.))
+                .set_yellow_ed_pump(false)
+                .set_yellow_e_pump(true)
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(test_bed.yellow_pressure() <= Pressure::new::<psi>(100.));
+            assert!(test_bed.brake_mode() == BrakeMode::AlternateWithoutAntiSkid);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(950.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(1200.));
+            assert!(test_bed.get_brake_right_yellow_pressure() > Pressure::new::<psi>(950.));
+            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(1200.));
+        }
+
+        #[test]
+        fn parking_brake_sets_parking_mode() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .set_park_brake(true)
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.brake_mode() == BrakeMode::Parking);
+
+            test_bed = test_bed
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.brake_mode() != BrakeMode::Parking);
+        }
+
         #[test]
         fn brakes_inactive_in_flight() {
             let mut test_bed = test_bed_on_ground_with()
@@ -9003,6 +9546,38 @@ mod tests {
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
         }
 
+        #[test]
+        fn autobrakes_rop_commands_max_braking_when_low_would_overrun() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_landing_runway(Length::new::<meter>(3000.))
+                .rolling_on_runway(Velocity::new::<knot>(120.), Length::new::<meter>(600.))
+                .set_autobrake_low()
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::LOW);
+            assert!(!test_bed.is_rop_active());
+
+            test_bed = test_bed
+                .rolling_on_runway(Velocity::new::<knot>(120.), Length::new::<meter>(2200.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.is_rop_active());
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(1000.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(1000.));
+        }
+
         #[test]
         fn autobrakes_disengage_on_spoiler_retract() {
             let mut test_bed = test_bed_on_ground_with()
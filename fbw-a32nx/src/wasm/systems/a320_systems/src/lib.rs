@@ -2,6 +2,7 @@ extern crate systems;
 
 mod air_conditioning;
 mod electrical;
+mod failures;
 mod fuel;
 pub mod hydraulic;
 mod navigation;
@@ -11,7 +12,7 @@ mod power_consumption;
 
 use self::{
     air_conditioning::{A320AirConditioning, A320PressurizationOverheadPanel},
-    fuel::A320Fuel,
+    fuel::{A320Fuel, A320FuelOverheadPanel},
     payload::A320Payload,
     pneumatic::{A320Pneumatic, A320PneumaticOverheadPanel},
 };
@@ -19,6 +20,7 @@ use electrical::{
     A320Electrical, A320ElectricalOverheadPanel, A320EmergencyElectricalOverheadPanel,
     APU_START_MOTOR_BUS_TYPE,
 };
+pub use failures::failures;
 use hydraulic::{A320Hydraulic, A320HydraulicOverheadPanel};
 use navigation::A320RadioAltimeters;
 use power_consumption::A320PowerConsumption;
@@ -38,6 +40,7 @@ use systems::{
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
+    probe_heat::ProbeHeat,
     shared::ElectricalBusType,
     simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
@@ -55,11 +58,13 @@ pub struct A320 {
     emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel,
     payload: A320Payload,
     fuel: A320Fuel,
+    fuel_overhead: A320FuelOverheadPanel,
     engine_1: LeapEngine,
     engine_2: LeapEngine,
     engine_fire_overhead: EngineFireOverheadPanel<2>,
     electrical: A320Electrical,
     power_consumption: A320PowerConsumption,
+    probe_heat: ProbeHeat,
     ext_pwr: ExternalPowerSource,
     lgcius: LandingGearControlInterfaceUnitSet,
     hydraulic: A320Hydraulic,
@@ -91,12 +96,30 @@ impl A320 {
             emergency_electrical_overhead: A320EmergencyElectricalOverheadPanel::new(context),
             payload: A320Payload::new(context),
             fuel: A320Fuel::new(context),
+            fuel_overhead: A320FuelOverheadPanel::new(context),
             engine_1: LeapEngine::new(context, 1),
             engine_2: LeapEngine::new(context, 2),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
             electrical: A320Electrical::new(context),
             power_consumption: A320PowerConsumption::new(context),
-            ext_pwr: ExternalPowerSource::new(context),
+            probe_heat: ProbeHeat::new(
+                context,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::DirectCurrent(1),
+                ],
+                [
+                    ElectricalBusType::AlternatingCurrentEssentialShed,
+                    ElectricalBusType::AlternatingCurrent(2),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ],
+                [
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(2),
+                ],
+            ),
+            ext_pwr: ExternalPowerSource::new(context, 1),
             lgcius: LandingGearControlInterfaceUnitSet::new(
                 context,
                 ElectricalBusType::DirectCurrentEssential,
@@ -130,6 +153,9 @@ impl Aircraft for A320 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(context);
+        self.engine_2.update(context);
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
@@ -142,7 +168,7 @@ impl Aircraft for A320 {
                 && !(self.electrical_overhead.external_power_is_on()
                     && self.electrical_overhead.external_power_is_available()),
             self.pneumatic.apu_bleed_air_valve(),
-            self.fuel.left_inner_tank_has_fuel_remaining(),
+            self.fuel.apu_fuel_is_available(),
         );
 
         self.electrical.update(
@@ -157,6 +183,7 @@ impl Aircraft for A320 {
             [&self.engine_1, &self.engine_2],
             &self.hydraulic,
             self.lgcius.lgciu1(),
+            &self.air_conditioning,
         );
 
         self.electrical_overhead
@@ -201,10 +228,28 @@ impl Aircraft for A320 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.engine_fire_overhead,
+            &self.apu_fire_overhead,
+            self.hydraulic.slats_are_retracted(),
+        );
+        self.fuel_overhead.update_after_fuel(&self.fuel);
+
+        self.probe_heat
+            .update(context, [&self.engine_1, &self.engine_2]);
+
+        self.adirs
+            .update(context, &self.adirs_overhead, &self.probe_heat);
         self.adirs_overhead.update(context, &self.adirs);
 
-        self.power_consumption.update(context);
+        self.power_consumption.update(
+            context,
+            &self.electrical,
+            &self.electrical_overhead,
+            &self.adirs_overhead,
+        );
 
         self.pneumatic.update(
             context,
@@ -229,7 +274,12 @@ impl Aircraft for A320 {
             [self.lgcius.lgciu1(), self.lgcius.lgciu2()],
         );
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.lgcius.lgciu1(),
+            self.radio_altimeters.radio_altimeter_1(),
+        );
     }
 }
 impl SimulationElement for A320 {
@@ -244,6 +294,7 @@ impl SimulationElement for A320 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.pressurization_overhead.accept(visitor);
         self.engine_1.accept(visitor);
@@ -251,6 +302,7 @@ impl SimulationElement for A320 {
         self.engine_fire_overhead.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
+        self.probe_heat.accept(visitor);
         self.ext_pwr.accept(visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
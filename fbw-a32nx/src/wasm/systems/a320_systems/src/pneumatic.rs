@@ -12,6 +12,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::fadec::FullAuthorityDigitalEngineControl,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerChannelOperationMode,
@@ -25,12 +26,13 @@ use systems::{
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
         ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, LgciuWeightOnWheels,
-        PackFlowValveState, PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        EngineFirePushButtons, EngineStartState, EngineUncorrectedN2, HydraulicColor,
+        LgciuWeightOnWheels, PackFlowValveState, PneumaticBleed, PneumaticValve,
+        ReservoirAirPressure,
     },
     simulation::{
-        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
     },
 };
 
@@ -118,7 +120,7 @@ pub struct A320Pneumatic {
 
     cross_bleed_valve: CrossBleedValve,
 
-    fadec: FullAuthorityDigitalEngineControl,
+    fadec: FullAuthorityDigitalEngineControl<2>,
     engine_starter_valve_controllers: [EngineStarterValveController; 2],
 
     apu_compression_chamber: CompressionChamber,
@@ -217,13 +219,15 @@ impl A320Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 2],
+        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2 + EngineUncorrectedN2); 2],
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers<2>,
         lgciu: [&impl LgciuWeightOnWheels; 2],
     ) {
+        self.fadec.update(context, engines);
+
         self.physics_updater.update(context);
 
         for cur_time_step in self.physics_updater {
@@ -242,7 +246,7 @@ impl A320Pneumatic {
     pub(crate) fn update_physics(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 2],
+        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2 + EngineUncorrectedN2); 2],
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -435,13 +439,14 @@ impl ReservoirAirPressure for A320Pneumatic {
 
 struct EngineStarterValveController {
     number: usize,
-    engine_state: EngineState,
+    should_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            EngineState::Starting => Some(EngineStarterValveSignal::new_open()),
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.should_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
@@ -449,12 +454,12 @@ impl EngineStarterValveController {
     fn new(number: usize) -> Self {
         Self {
             number,
-            engine_state: EngineState::Off,
+            should_open: false,
         }
     }
 
-    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
+    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl<2>) {
+        self.should_open = fadec.starter_valve_should_open(self.number);
     }
 }
 
@@ -496,7 +501,7 @@ impl BleedMonitoringComputer {
         overhead_panel: &A320PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         cross_bleed_valve: &impl PneumaticValve,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadec: &FullAuthorityDigitalEngineControl<2>,
     ) {
         self.main_channel.update(
             context,
@@ -639,7 +644,7 @@ impl BleedMonitoringComputerChannel {
         apu_bleed_valve: &impl PneumaticValve,
         cross_bleed_valve: &impl PneumaticValve,
         overhead_panel: &A320PneumaticOverheadPanel,
-        fadec: &FullAuthorityDigitalEngineControl,
+        fadec: &FullAuthorityDigitalEngineControl<2>,
     ) {
         self.high_pressure_compressor_pressure = sensors.high_pressure();
         self.transfer_pressure = sensors.transfer_pressure();
@@ -1193,54 +1198,6 @@ impl SimulationElement for A320PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
-pub struct FullAuthorityDigitalEngineControl {
-    engine_1_state_id: VariableIdentifier,
-    engine_2_state_id: VariableIdentifier,
-
-    engine_1_state: EngineState,
-    engine_2_state: EngineState,
-
-    engine_mode_selector1_id: VariableIdentifier,
-    engine_mode_selector1_position: EngineModeSelector,
-}
-impl FullAuthorityDigitalEngineControl {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
-            engine_1_state: EngineState::Off,
-            engine_2_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
-            engine_mode_selector1_position: EngineModeSelector::Norm,
-        }
-    }
-
-    fn engine_state(&self, number: usize) -> EngineState {
-        match number {
-            1 => self.engine_1_state,
-            2 => self.engine_2_state,
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn is_single_vs_dual_bleed_config(&self) -> bool {
-        (self.engine_1_state == EngineState::On) ^ (self.engine_2_state == EngineState::On)
-    }
-
-    fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.engine_mode_selector1_position
-    }
-}
-impl SimulationElement for FullAuthorityDigitalEngineControl {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.engine_1_state = reader.read(&self.engine_1_state_id);
-        self.engine_2_state = reader.read(&self.engine_2_state_id);
-        self.engine_mode_selector1_position = reader.read(&self.engine_mode_selector1_id);
-    }
-}
-
 /// A struct to hold all the pack related components
 pub struct PackComplex {
     engine_number: usize,
@@ -1420,8 +1377,8 @@ mod tests {
         failures::FailureType,
         pneumatic::{
             BleedMonitoringComputerChannelOperationMode, ControllablePneumaticValve,
-            CrossBleedValveSelectorMode, EngineState, PneumaticContainer, PneumaticValveSignal,
-            TargetPressureTemperatureSignal, WingAntiIcePushButtonMode,
+            CrossBleedValveSelectorMode, EngineModeSelector, EngineState, PneumaticContainer,
+            PneumaticValveSignal, TargetPressureTemperatureSignal, WingAntiIcePushButtonMode,
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
@@ -1873,7 +1830,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1882,7 +1839,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1891,7 +1848,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.99));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1900,7 +1857,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.99));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1909,7 +1866,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.92));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.92));
 
             self
         }
@@ -1918,7 +1875,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.92));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.92));
 
             self
         }
@@ -1927,7 +1884,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:1", EngineState::Off);
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -1936,21 +1893,29 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:2", EngineState::Off);
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.));
 
             self
         }
 
         fn start_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
-            self.write_by_name("ENGINE_STATE:1", EngineState::Starting);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("ENGINE_N2:1", Ratio::new::<ratio>(0.));
 
             self
         }
 
         fn start_eng2(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
-            self.write_by_name("ENGINE_STATE:2", EngineState::Starting);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("ENGINE_N2:2", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -2136,8 +2101,26 @@ mod tests {
             self
         }
 
-        fn set_engine_state(mut self, number: usize, engine_state: EngineState) -> Self {
-            self.write_by_name(&format!("ENGINE_STATE:{}", number), engine_state);
+        fn engine_master(mut self, number: usize, is_on: bool) -> Self {
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), is_on);
+
+            self
+        }
+
+        fn engine_n2(mut self, number: usize, n2: Ratio) -> Self {
+            self.write_by_name(&format!("ENGINE_N2:{}", number), n2);
+
+            self
+        }
+
+        fn engine_mode_selector(mut self, position: EngineModeSelector) -> Self {
+            self.write_by_name("TURB ENG IGNITION SWITCH EX1:1", position);
+
+            self
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.set_on_ground(true);
 
             self
         }
@@ -2962,50 +2945,56 @@ mod tests {
 
     #[test]
     fn fadec_represents_engine_state() {
-        let mut test_bed = test_bed_with()
-            .set_engine_state(1, EngineState::Off)
-            .set_engine_state(2, EngineState::Off);
+        let mut test_bed = test_bed_with().on_ground().and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Off);
         assert_eq!(test_bed.engine_state(2), EngineState::Off);
 
-        test_bed = test_bed
-            .set_engine_state(1, EngineState::Starting)
-            .and_run();
+        test_bed = test_bed.start_eng1().and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Starting);
         assert_eq!(test_bed.engine_state(2), EngineState::Off);
 
-        test_bed = test_bed
-            .set_engine_state(2, EngineState::Starting)
-            .and_run();
+        test_bed = test_bed.start_eng2().and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Starting);
         assert_eq!(test_bed.engine_state(2), EngineState::Starting);
 
-        test_bed = test_bed.set_engine_state(1, EngineState::On).and_run();
+        test_bed = test_bed.idle_eng1().and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::On);
         assert_eq!(test_bed.engine_state(2), EngineState::Starting);
 
-        test_bed = test_bed.set_engine_state(2, EngineState::On).and_run();
+        test_bed = test_bed.idle_eng2().and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::On);
         assert_eq!(test_bed.engine_state(2), EngineState::On);
 
-        test_bed = test_bed
-            .set_engine_state(1, EngineState::Shutting)
-            .and_run();
+        test_bed = test_bed.engine_master(1, false).and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Shutting);
         assert_eq!(test_bed.engine_state(2), EngineState::On);
 
-        test_bed = test_bed
-            .set_engine_state(2, EngineState::Shutting)
-            .and_run();
+        test_bed = test_bed.engine_master(2, false).and_run();
 
         assert_eq!(test_bed.engine_state(1), EngineState::Shutting);
         assert_eq!(test_bed.engine_state(2), EngineState::Shutting);
+
+        test_bed = test_bed
+            .engine_n2(1, Ratio::new::<ratio>(0.))
+            .engine_n2(2, Ratio::new::<ratio>(0.))
+            .and_run();
+
+        assert_eq!(test_bed.engine_state(1), EngineState::Off);
+        assert_eq!(test_bed.engine_state(2), EngineState::Off);
+    }
+
+    #[test]
+    fn engine_restarted_in_flight_is_restarting() {
+        let test_bed = test_bed_with().start_eng1().and_run();
+
+        assert_eq!(test_bed.engine_state(1), EngineState::Restarting);
+        assert!(test_bed.es_valve_is_open(1));
     }
 
     #[test]
@@ -1,148 +1,249 @@
-use systems::simulation::InitContext;
+use super::electrical::{A320Electrical, A320ElectricalOverheadPanel};
 use systems::{
-    electrical::consumption::{FlightPhasePowerConsumer, PowerConsumerFlightPhase},
+    accept_iterable,
+    electrical::consumption::{
+        FlightPhasePowerConsumer, PowerConsumerFlightPhase, SwitchedPowerConsumer,
+    },
+    navigation::adirs::AirDataInertialReferenceSystemOverheadPanel,
     shared::ElectricalBusType,
-    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
 };
 use uom::si::{f64::*, power::watt};
 
-/// This type provides an aggregated form of power consumption.
-/// We haven't yet implemented all power consumers and thus need something to
-/// consume power, as otherwise electrical load is nearly 0.
+/// The catalogue of electrical loads of the aircraft. Each load declares the bus it
+/// receives power from and derives its demand from the state of the aircraft, such that
+/// the generator loads reflect what the crew actually switched on.
+///
+/// Consumers which are part of a modelled system (e.g. fuel pumps, hydraulic pumps, probe
+/// and window heat and the APU) consume power themselves and are thus not part of this catalogue.
+/// Buses for which the consumers haven't been catalogued yet keep an aggregated consumer.
 pub(super) struct A320PowerConsumption {
-    ac_bus_1_consumer: FlightPhasePowerConsumer,
-    ac_bus_2_consumer: FlightPhasePowerConsumer,
-    ac_ess_bus_consumer: FlightPhasePowerConsumer,
-    ac_ess_shed_bus_consumer: FlightPhasePowerConsumer,
-    ac_stat_inv_bus_consumer: FlightPhasePowerConsumer,
+    avionics: A320AvionicsLoads,
+    cabin: A320CabinLoads,
+    exterior_lighting: A320ExteriorLightingLoads,
+    uncatalogued: A320UncataloguedLoads,
+}
+impl A320PowerConsumption {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            avionics: A320AvionicsLoads::new(),
+            cabin: A320CabinLoads::new(),
+            exterior_lighting: A320ExteriorLightingLoads::new(context),
+            uncatalogued: A320UncataloguedLoads::new(context),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        electrical: &A320Electrical,
+        electrical_overhead: &A320ElectricalOverheadPanel,
+        adirs_overhead: &AirDataInertialReferenceSystemOverheadPanel,
+    ) {
+        self.avionics.update(adirs_overhead);
+        self.cabin.update(electrical, electrical_overhead);
+        self.exterior_lighting.update();
+        self.uncatalogued.update(context);
+    }
+}
+impl SimulationElement for A320PowerConsumption {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.avionics.accept(visitor);
+        self.cabin.accept(visitor);
+        self.exterior_lighting.accept(visitor);
+        self.uncatalogued.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The avionics racks and their ventilation. The racks hold the computers which have no
+/// crew control and thus run whenever their bus is powered. The ADIRUs only run when their
+/// mode selector is set to NAV or ATT.
+struct A320AvionicsLoads {
+    racks: [SwitchedPowerConsumer; 9],
+    adirus: [SwitchedPowerConsumer; 3],
+}
+impl A320AvionicsLoads {
+    const ADIRU_POWER_DEMAND_WATT: f64 = 115.;
+
+    fn new() -> Self {
+        Self {
+            racks: [
+                (ElectricalBusType::AlternatingCurrent(1), 7885.),
+                (ElectricalBusType::AlternatingCurrent(2), 5885.),
+                (ElectricalBusType::AlternatingCurrentEssential, 585.),
+                (ElectricalBusType::AlternatingCurrentEssentialShed, 560.),
+                (ElectricalBusType::AlternatingCurrentStaticInverter, 135.),
+                (ElectricalBusType::DirectCurrent(1), 300.),
+                (ElectricalBusType::DirectCurrent(2), 450.),
+                (ElectricalBusType::DirectCurrentEssential, 150.),
+                (ElectricalBusType::DirectCurrentEssentialShed, 190.),
+            ]
+            .map(|(bus, demand)| SwitchedPowerConsumer::new(bus, Power::new::<watt>(demand))),
+            adirus: [
+                ElectricalBusType::AlternatingCurrentEssential,
+                ElectricalBusType::AlternatingCurrent(2),
+                ElectricalBusType::AlternatingCurrent(1),
+            ]
+            .map(|bus| {
+                SwitchedPowerConsumer::new(bus, Power::new::<watt>(Self::ADIRU_POWER_DEMAND_WATT))
+            }),
+        }
+    }
+
+    fn update(&mut self, adirs_overhead: &AirDataInertialReferenceSystemOverheadPanel) {
+        self.racks.iter_mut().for_each(|rack| rack.update(true));
+        self.adirus
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, adiru)| {
+                adiru.update(adirs_overhead.adiru_is_selected_on(index + 1))
+            });
+    }
+}
+impl SimulationElement for A320AvionicsLoads {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.racks, visitor);
+        accept_iterable!(self.adirus, visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The galleys, cabin lighting and in-flight entertainment. These are commercial loads,
+/// which are shed by the COMMERCIAL and GALY & CAB push buttons and by the galley load shedding.
+struct A320CabinLoads {
+    main_galley: SwitchedPowerConsumer,
+    secondary_galley: SwitchedPowerConsumer,
+    cabin_lighting: SwitchedPowerConsumer,
+    in_flight_entertainment: SwitchedPowerConsumer,
+}
+impl A320CabinLoads {
+    fn new() -> Self {
+        Self {
+            main_galley: SwitchedPowerConsumer::new(
+                ElectricalBusType::AlternatingCurrent(2),
+                Power::new::<watt>(12000.),
+            ),
+            secondary_galley: SwitchedPowerConsumer::new(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(6000.),
+            ),
+            cabin_lighting: SwitchedPowerConsumer::new(
+                ElectricalBusType::AlternatingCurrent(1),
+                Power::new::<watt>(2000.),
+            ),
+            in_flight_entertainment: SwitchedPowerConsumer::new(
+                ElectricalBusType::AlternatingCurrent(2),
+                Power::new::<watt>(2500.),
+            ),
+        }
+    }
+
+    fn update(&mut self, electrical: &A320Electrical, overhead: &A320ElectricalOverheadPanel) {
+        self.main_galley.update(!electrical.main_galley_is_shed());
+        self.secondary_galley
+            .update(!electrical.secondary_galley_is_shed());
+        self.cabin_lighting.update(!overhead.commercial_is_off());
+        self.in_flight_entertainment
+            .update(!overhead.commercial_is_off() && !overhead.galy_and_cab_is_off());
+    }
+}
+impl SimulationElement for A320CabinLoads {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.main_galley.accept(visitor);
+        self.secondary_galley.accept(visitor);
+        self.cabin_lighting.accept(visitor);
+        self.in_flight_entertainment.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+/// The exterior lights, switched by the crew on the overhead panel.
+struct A320ExteriorLightingLoads {
+    light_ids: [VariableIdentifier; 8],
+    lights_are_on: [bool; 8],
+
+    lights: [SwitchedPowerConsumer; 8],
+}
+impl A320ExteriorLightingLoads {
+    const LIGHTS: [(&'static str, ElectricalBusType, f64); 8] = [
+        (
+            "LIGHT LANDING",
+            ElectricalBusType::AlternatingCurrent(1),
+            600.,
+        ),
+        (
+            "LIGHT LANDING",
+            ElectricalBusType::AlternatingCurrent(2),
+            600.,
+        ),
+        ("LIGHT TAXI", ElectricalBusType::AlternatingCurrent(1), 400.),
+        ("LIGHT NAV", ElectricalBusType::AlternatingCurrent(1), 100.),
+        (
+            "LIGHT BEACON",
+            ElectricalBusType::AlternatingCurrent(2),
+            100.,
+        ),
+        (
+            "LIGHT STROBE",
+            ElectricalBusType::AlternatingCurrent(2),
+            200.,
+        ),
+        ("LIGHT WING", ElectricalBusType::AlternatingCurrent(1), 300.),
+        ("LIGHT LOGO", ElectricalBusType::AlternatingCurrent(2), 300.),
+    ];
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            light_ids: Self::LIGHTS.map(|(name, _, _)| context.get_identifier(name.to_owned())),
+            lights_are_on: [false; 8],
+
+            lights: Self::LIGHTS.map(|(_, bus, demand)| {
+                SwitchedPowerConsumer::new(bus, Power::new::<watt>(demand))
+            }),
+        }
+    }
+
+    fn update(&mut self) {
+        self.lights
+            .iter_mut()
+            .zip(self.lights_are_on)
+            .for_each(|(light, is_on)| light.update(is_on));
+    }
+}
+impl SimulationElement for A320ExteriorLightingLoads {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.lights, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        for (is_on, id) in self.lights_are_on.iter_mut().zip(&self.light_ids) {
+            *is_on = reader.read(id);
+        }
+    }
+}
+
+/// This type provides an aggregated form of power consumption for the buses
+/// whose consumers haven't been catalogued yet.
+struct A320UncataloguedLoads {
     ac_gnd_flt_service_consumer: FlightPhasePowerConsumer,
-    dc_bus_1_consumer: FlightPhasePowerConsumer,
-    dc_bus_2_consumer: FlightPhasePowerConsumer,
-    dc_ess_bus_consumer: FlightPhasePowerConsumer,
-    dc_ess_shed_bus_consumer: FlightPhasePowerConsumer,
     dc_bat_bus_consumer: FlightPhasePowerConsumer,
     dc_hot_bus_1_consumer: FlightPhasePowerConsumer,
     dc_hot_bus_2_consumer: FlightPhasePowerConsumer,
     dc_gnd_flt_service_consumer: FlightPhasePowerConsumer,
 }
-impl A320PowerConsumption {
-    pub fn new(context: &mut InitContext) -> Self {
+impl A320UncataloguedLoads {
+    fn new(context: &mut InitContext) -> Self {
         // The watts in this function are all provided by komp.
         Self {
-            ac_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26816.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(30350.1),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(33797.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(39032.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(30733.3),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(30243.1),
-                ),
-            ]),
-            ac_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(26960.2),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(21735.8),
-                ),
-                (
-                    PowerConsumerFlightPhase::Takeoff,
-                    Power::new::<watt>(25183.),
-                ),
-                (
-                    PowerConsumerFlightPhase::Flight,
-                    Power::new::<watt>(29777.4),
-                ),
-                (
-                    PowerConsumerFlightPhase::Landing,
-                    Power::new::<watt>(22119.),
-                ),
-                (
-                    PowerConsumerFlightPhase::TaxiIn,
-                    Power::new::<watt>(24475.8),
-                ),
-            ]),
-            ac_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(455.7),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(715.7),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(875.7)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(715.7)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(715.7)),
-            ]),
-            ac_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(560.5),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(823.5),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(823.5)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(823.5)),
-            ]),
-            ac_stat_inv_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::AlternatingCurrentStaticInverter,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(135.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(135.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(135.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(135.)),
-            ]),
             ac_gnd_flt_service_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::AlternatingCurrentGndFltService,
@@ -161,78 +262,6 @@ impl A320PowerConsumption {
                 (PowerConsumerFlightPhase::Landing, Power::new::<watt>(2628.)),
                 (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(3663.)),
             ]),
-            dc_bus_1_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(1),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(252.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(308.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(280.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(364.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(336.)),
-            ]),
-            dc_bus_2_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrent(2),
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(532.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(448.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(392.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(448.)),
-            ]),
-            dc_ess_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssential,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(168.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(140.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(140.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(168.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(140.)),
-            ]),
-            dc_ess_shed_bus_consumer: FlightPhasePowerConsumer::new(
-                context,
-                ElectricalBusType::DirectCurrentEssentialShed,
-            )
-            .demand([
-                (
-                    PowerConsumerFlightPhase::BeforeStart,
-                    Power::new::<watt>(224.),
-                ),
-                (
-                    PowerConsumerFlightPhase::AfterStart,
-                    Power::new::<watt>(168.),
-                ),
-                (PowerConsumerFlightPhase::Takeoff, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Flight, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::Landing, Power::new::<watt>(196.)),
-                (PowerConsumerFlightPhase::TaxiIn, Power::new::<watt>(168.)),
-            ]),
             dc_bat_bus_consumer: FlightPhasePowerConsumer::new(
                 context,
                 ElectricalBusType::DirectCurrentBattery,
@@ -308,35 +337,17 @@ impl A320PowerConsumption {
         }
     }
 
-    pub fn update(&mut self, context: &UpdateContext) {
-        self.ac_bus_1_consumer.update(context);
-        self.ac_bus_2_consumer.update(context);
-        self.ac_ess_bus_consumer.update(context);
-        self.ac_ess_shed_bus_consumer.update(context);
-        self.ac_stat_inv_bus_consumer.update(context);
+    fn update(&mut self, context: &UpdateContext) {
         self.ac_gnd_flt_service_consumer.update(context);
-        self.dc_bus_1_consumer.update(context);
-        self.dc_bus_2_consumer.update(context);
-        self.dc_ess_bus_consumer.update(context);
-        self.dc_ess_shed_bus_consumer.update(context);
         self.dc_bat_bus_consumer.update(context);
         self.dc_hot_bus_1_consumer.update(context);
         self.dc_hot_bus_2_consumer.update(context);
         self.dc_gnd_flt_service_consumer.update(context);
     }
 }
-impl SimulationElement for A320PowerConsumption {
+impl SimulationElement for A320UncataloguedLoads {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.ac_bus_1_consumer.accept(visitor);
-        self.ac_bus_2_consumer.accept(visitor);
-        self.ac_ess_bus_consumer.accept(visitor);
-        self.ac_ess_shed_bus_consumer.accept(visitor);
-        self.ac_stat_inv_bus_consumer.accept(visitor);
         self.ac_gnd_flt_service_consumer.accept(visitor);
-        self.dc_bus_1_consumer.accept(visitor);
-        self.dc_bus_2_consumer.accept(visitor);
-        self.dc_ess_bus_consumer.accept(visitor);
-        self.dc_ess_shed_bus_consumer.accept(visitor);
         self.dc_bat_bus_consumer.accept(visitor);
         self.dc_hot_bus_1_consumer.accept(visitor);
         self.dc_hot_bus_2_consumer.accept(visitor);
@@ -345,3 +356,177 @@ impl SimulationElement for A320PowerConsumption {
         visitor.visit(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::A320;
+    use std::time::Duration;
+    use systems::simulation::test::{ReadByName, SimulationTestBed, TestBed, WriteByName};
+    use uom::si::ratio::percent;
+
+    struct A320PowerConsumptionTestBed {
+        test_bed: SimulationTestBed<A320>,
+    }
+    impl A320PowerConsumptionTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(A320::new),
+            };
+            test_bed.set_on_ground(true);
+
+            test_bed
+        }
+
+        fn running_engines(mut self) -> Self {
+            for number in 1..=2 {
+                self.write_by_name(
+                    &format!("TURB ENG CORRECTED N2:{}", number),
+                    Ratio::new::<percent>(80.),
+                );
+                self.write_by_name(&format!("ENGINE_N2:{}", number), Ratio::new::<percent>(80.));
+            }
+
+            self.run_for(Duration::from_secs(5))
+        }
+
+        fn gen_off(mut self, number: usize) -> Self {
+            self.write_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_IS_ON", number), false);
+            self
+        }
+
+        fn light_on(mut self, name: &str) -> Self {
+            self.write_by_name(name, true);
+            self
+        }
+
+        fn commercial_off(mut self) -> Self {
+            self.write_by_name("OVHD_ELEC_COMMERCIAL_PB_IS_ON", false);
+            self
+        }
+
+        fn adirus_off(mut self) -> Self {
+            for number in 1..=3 {
+                self.write_by_name(&format!("OVHD_ADIRS_IR_{}_MODE_SELECTOR_KNOB", number), 0.);
+            }
+            self
+        }
+
+        fn galy_and_cab_off(mut self) -> Self {
+            self.write_by_name("OVHD_ELEC_GALY_AND_CAB_PB_IS_AUTO", false);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.test_bed.run_multiple_frames(duration);
+            self
+        }
+
+        fn run(self) -> Self {
+            self.run_for(Duration::from_secs(1))
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn gen_load(&mut self, number: usize) -> Ratio {
+            self.read_by_name(&format!("ELEC_ENG_GEN_{}_LOAD", number))
+        }
+
+        fn galley_is_shed(&mut self) -> bool {
+            self.read_by_name("ELEC_GALLEY_IS_SHED")
+        }
+    }
+    impl TestBed for A320PowerConsumptionTestBed {
+        type Aircraft = A320;
+
+        fn test_bed(&self) -> &SimulationTestBed<A320> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<A320> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed_with() -> A320PowerConsumptionTestBed {
+        A320PowerConsumptionTestBed::new()
+    }
+
+    #[test]
+    fn exterior_lights_increase_generator_load() {
+        let mut test_bed = test_bed_with().running_engines();
+        let gen_1_load_lights_off = test_bed.gen_load(1);
+        let gen_2_load_lights_off = test_bed.gen_load(2);
+
+        test_bed = test_bed
+            .light_on("LIGHT LANDING")
+            .light_on("LIGHT TAXI")
+            .and()
+            .light_on("LIGHT STROBE")
+            .run();
+
+        assert!(test_bed.gen_load(1) > gen_1_load_lights_off);
+        assert!(test_bed.gen_load(2) > gen_2_load_lights_off);
+    }
+
+    #[test]
+    fn commercial_off_decreases_generator_loads() {
+        let mut test_bed = test_bed_with().running_engines();
+        let gen_1_load_commercial_on = test_bed.gen_load(1);
+        let gen_2_load_commercial_on = test_bed.gen_load(2);
+
+        test_bed = test_bed.commercial_off().run();
+
+        assert!(test_bed.gen_load(1) < gen_1_load_commercial_on);
+        assert!(test_bed.gen_load(2) < gen_2_load_commercial_on);
+    }
+
+    #[test]
+    fn adirus_off_decreases_generator_loads() {
+        let mut test_bed = test_bed_with().running_engines();
+        let gen_1_load_adirus_on = test_bed.gen_load(1);
+        let gen_2_load_adirus_on = test_bed.gen_load(2);
+
+        test_bed = test_bed.adirus_off().run();
+
+        assert!(test_bed.gen_load(1) < gen_1_load_adirus_on);
+        assert!(test_bed.gen_load(2) < gen_2_load_adirus_on);
+    }
+
+    #[test]
+    fn galy_and_cab_off_decreases_generator_loads() {
+        let mut test_bed = test_bed_with().running_engines();
+        let gen_1_load_galy_and_cab_auto = test_bed.gen_load(1);
+        let gen_2_load_galy_and_cab_auto = test_bed.gen_load(2);
+
+        test_bed = test_bed.galy_and_cab_off().run();
+
+        assert!(test_bed.gen_load(1) < gen_1_load_galy_and_cab_auto);
+        assert!(test_bed.gen_load(2) < gen_2_load_galy_and_cab_auto);
+    }
+
+    #[test]
+    fn galy_and_cab_off_keeps_cabin_lighting_load() {
+        let mut galy_and_cab_off_test_bed =
+            test_bed_with().running_engines().galy_and_cab_off().run();
+        let mut commercial_off_test_bed = test_bed_with().running_engines().commercial_off().run();
+
+        assert!(galy_and_cab_off_test_bed.gen_load(1) > commercial_off_test_bed.gen_load(1));
+    }
+
+    #[test]
+    fn galley_shedding_removes_main_galley_load_from_remaining_generator() {
+        let mut test_bed = test_bed_with().running_engines();
+        let total_load_both_gens = test_bed.gen_load(1) + test_bed.gen_load(2);
+
+        test_bed = test_bed.gen_off(2).run();
+
+        assert!(test_bed.galley_is_shed());
+        assert_eq!(test_bed.gen_load(2), Ratio::new::<percent>(0.));
+        // The main galley draws 12 kW, which is nearly 11% of the generator rated load after
+        // power factor correction
+        assert!(test_bed.gen_load(1) < total_load_both_gens - Ratio::new::<percent>(10.));
+    }
+}
@@ -0,0 +1,61 @@
+use std::{
+    env,
+    error::Error,
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    process,
+};
+
+use a320_systems::{failures, A320};
+use systems::simulation::{
+    manifest::VariableManifest,
+    runner::{run_scenario, Scenario},
+    StartState,
+};
+
+/// Runs the A320 systems headless through a scenario file and writes the requested outputs as CSV.
+///
+/// Usage: `a320_systems_runner <scenario> [output.csv]`. Without an output file the CSV is
+/// written to the standard output.
+///
+/// With `a320_systems_runner --manifest [output.tsv]` the manifest of all variables the
+/// aircraft reads and writes is written instead.
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() < 2 || args.len() > 3 {
+        eprintln!("Usage: {} <scenario> [output.csv]", args[0]);
+        eprintln!("       {} --manifest [output.tsv]", args[0]);
+        process::exit(2);
+    }
+
+    let result = match args[1].as_str() {
+        "--manifest" => write_manifest(args.get(2)),
+        scenario_path => run(scenario_path, args.get(2)),
+    };
+
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn run(scenario_path: &str, output_path: Option<&String>) -> Result<(), Box<dyn Error>> {
+    let scenario = Scenario::parse(BufReader::new(File::open(scenario_path)?))?;
+
+    run_scenario(&scenario, A320::new, &failures(), &mut output(output_path)?)?;
+
+    Ok(())
+}
+
+fn write_manifest(output_path: Option<&String>) -> Result<(), Box<dyn Error>> {
+    VariableManifest::new(StartState::default(), A320::new).save(&mut output(output_path)?)?;
+
+    Ok(())
+}
+
+fn output(path: Option<&String>) -> io::Result<Box<dyn Write>> {
+    Ok(match path {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    })
+}
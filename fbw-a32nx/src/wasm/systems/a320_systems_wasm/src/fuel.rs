@@ -0,0 +1,71 @@
+use std::error::Error;
+
+use msfs::sim_connect;
+use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+
+use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
+use systems_wasm::{set_data_on_sim_object, Variable};
+
+pub(super) fn fuel(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    // The fuel system owns the tank quantities: the FADEC doesn't burn fuel from the tanks.
+    // The modelled quantities are written to the simulator's tanks every frame, such that
+    // the fuel burnt by the simulator itself is overridden and its weight and CG follow the
+    // fuel system. Only fuel loaded on the ground changes the simulator's tanks otherwise,
+    // which the fuel system then takes over.
+    builder.variables_to_object(Box::new(FuelTankQuantities {
+        left_outer: 0.,
+        left_inner: 0.,
+        center: 0.,
+        right_inner: 0.,
+        right_outer: 0.,
+    }));
+
+    Ok(())
+}
+
+#[sim_connect::data_definition]
+struct FuelTankQuantities {
+    #[name = "FUEL TANK LEFT AUX QUANTITY"]
+    #[unit = "Pounds"]
+    left_outer: f64,
+
+    #[name = "FUEL TANK LEFT MAIN QUANTITY"]
+    #[unit = "Pounds"]
+    left_inner: f64,
+
+    #[name = "FUEL TANK CENTER QUANTITY"]
+    #[unit = "Pounds"]
+    center: f64,
+
+    #[name = "FUEL TANK RIGHT MAIN QUANTITY"]
+    #[unit = "Pounds"]
+    right_inner: f64,
+
+    #[name = "FUEL TANK RIGHT AUX QUANTITY"]
+    #[unit = "Pounds"]
+    right_outer: f64,
+}
+
+impl VariablesToObject for FuelTankQuantities {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::named("FUEL_LEFT_OUTER_QUANTITY"),
+            Variable::named("FUEL_LEFT_INNER_QUANTITY"),
+            Variable::named("FUEL_CENTER_QUANTITY"),
+            Variable::named("FUEL_RIGHT_INNER_QUANTITY"),
+            Variable::named("FUEL_RIGHT_OUTER_QUANTITY"),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+        self.left_outer = values[0];
+        self.left_inner = values[1];
+        self.center = values[2];
+        self.right_inner = values[3];
+        self.right_outer = values[4];
+
+        ObjectWrite::default()
+    }
+
+    set_data_on_sim_object!();
+}
@@ -3,6 +3,7 @@ mod autobrakes;
 mod brakes;
 mod elevators;
 mod flaps;
+mod fuel;
 mod gear;
 mod nose_wheel_steering;
 mod payload;
@@ -10,23 +11,20 @@ mod rudder;
 mod spoilers;
 mod trimmable_horizontal_stabilizer;
 
-use a320_systems::A320;
+use a320_systems::{failures, A320};
 use ailerons::ailerons;
 use autobrakes::autobrakes;
 use brakes::brakes;
 use elevators::elevators;
 use flaps::flaps;
+use fuel::fuel;
 use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
 use payload::payload;
 use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
-use systems::failures::FailureType;
-use systems::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, ElectricalBusType, GearActuatorId,
-    HydraulicColor, LgciuId, ProximityDetectorId,
-};
+use systems::shared::ElectricalBusType;
 use systems_wasm::aspects::ExecuteOn;
 use systems_wasm::{MsfsSimulationBuilder, Variable};
 use trimmable_horizontal_stabilizer::trimmable_horizontal_stabilizer;
@@ -41,6 +39,15 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         Variable::named(&format!("{}START_STATE", key_prefix)),
         sim_connect.as_mut().get_mut(),
     )
+    .with_recording(
+        Variable::named(&format!("{}SYSTEMS_RECORDING_ENABLED", key_prefix)),
+        "\\work\\systems_recording.txt",
+    )
+    .with_manifest(
+        Variable::named(&format!("{}SYSTEMS_MANIFEST_ENABLED", key_prefix)),
+        "\\work\\systems_manifest.tsv",
+    )
+    .with_snapshots("\\work\\systems_snapshot.txt")
     .with_electrical_buses([
         (ElectricalBusType::AlternatingCurrent(1), 2),
         (ElectricalBusType::AlternatingCurrent(2), 3),
@@ -58,135 +65,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (ElectricalBusType::DirectCurrentGndFltService, 15),
     ])?
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8)?
-    .with_failures(vec![
-        (24_000, FailureType::TransformerRectifier(1)),
-        (24_001, FailureType::TransformerRectifier(2)),
-        (24_002, FailureType::TransformerRectifier(3)),
-        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
-        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
-        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
-        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
-        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
-        (
-            29_005,
-            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_006,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
-        ),
-        (
-            29_007,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
-        ),
-        (
-            29_008,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_009,
-            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Green),
-        ),
-        (
-            29_010,
-            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Blue),
-        ),
-        (
-            29_011,
-            FailureType::EnginePumpOverheat(AirbusEngineDrivenPumpId::Yellow),
-        ),
-        (
-            29_012,
-            FailureType::ElecPumpOverheat(AirbusElectricPumpId::Yellow),
-        ),
-        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
-        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
-        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
-        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
-        (
-            32_004,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
-        ),
-        (
-            32_005,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
-        ),
-        (
-            32_006,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
-        ),
-        (
-            32_007,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
-        ),
-        (
-            32_008,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
-        ),
-        (
-            32_009,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
-        ),
-        (
-            32_010,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
-        ),
-        (
-            32_011,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
-        ),
-        (
-            32_012,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
-        ),
-        (
-            32_013,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
-        ),
-        (
-            32_014,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
-        ),
-        (
-            32_015,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
-        ),
-        (
-            32_020,
-            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
-        ),
-        (
-            32_021,
-            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
-        ),
-        (
-            32_022,
-            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
-        ),
-        (
-            32_023,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
-        ),
-        (
-            32_024,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
-        ),
-        (
-            32_025,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
-        ),
-        (
-            32_100,
-            FailureType::BrakeHydraulicLeak(HydraulicColor::Green),
-        ),
-        (
-            32_101,
-            FailureType::BrakeHydraulicLeak(HydraulicColor::Yellow),
-        ),
-        (32_150, FailureType::BrakeAccumulatorGasLeak),
-        (34_000, FailureType::RadioAltimeter(1)),
-        (34_001, FailureType::RadioAltimeter(2)),
-    ])
+    .with_failures(failures())
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Z", "feet per second squared", 0)?
@@ -194,6 +73,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("AIRSPEED MACH", "Mach", 0)?
     .provides_aircraft_variable("AIRSPEED TRUE", "Knots", 0)?
     .provides_aircraft_variable("AMBIENT DENSITY", "Slugs per cubic feet", 0)?
+    .provides_aircraft_variable("AMBIENT IN CLOUD", "Bool", 0)?
+    .provides_aircraft_variable("AMBIENT PRECIP RATE", "millimeters of water", 0)?
     .provides_aircraft_variable("AMBIENT PRESSURE", "inHg", 0)?
     .provides_aircraft_variable("AMBIENT TEMPERATURE", "celsius", 0)?
     .provides_aircraft_variable("AMBIENT WIND DIRECTION", "Degrees", 0)?
@@ -203,7 +84,11 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("AMBIENT WIND Z", "meter per second", 0)?
     .provides_aircraft_variable("ANTISKID BRAKES ACTIVE", "Bool", 0)?
     .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 1)?
+    .provides_aircraft_variable("FUEL TANK CENTER QUANTITY", "Pounds", 0)?
+    .provides_aircraft_variable("FUEL TANK LEFT AUX QUANTITY", "Pounds", 0)?
     .provides_aircraft_variable("FUEL TANK LEFT MAIN QUANTITY", "Pounds", 0)?
+    .provides_aircraft_variable("FUEL TANK RIGHT AUX QUANTITY", "Pounds", 0)?
+    .provides_aircraft_variable("FUEL TANK RIGHT MAIN QUANTITY", "Pounds", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
@@ -221,6 +106,12 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("KOHLSMAN SETTING MB", "Millibars", 1)?
     .provides_aircraft_variable("LIGHT BEACON", "Bool", 0)?
     .provides_aircraft_variable("LIGHT BEACON ON", "Bool", 0)?
+    .provides_aircraft_variable("LIGHT LANDING", "Bool", 0)?
+    .provides_aircraft_variable("LIGHT LOGO", "Bool", 0)?
+    .provides_aircraft_variable("LIGHT NAV", "Bool", 0)?
+    .provides_aircraft_variable("LIGHT STROBE", "Bool", 0)?
+    .provides_aircraft_variable("LIGHT TAXI", "Bool", 0)?
+    .provides_aircraft_variable("LIGHT WING", "Bool", 0)?
     .provides_aircraft_variable("PLANE ALT ABOVE GROUND", "Feet", 0)?
     .provides_aircraft_variable("PLANE PITCH DEGREES", "Degrees", 0)?
     .provides_aircraft_variable("PLANE BANK DEGREES", "Degrees", 0)?
@@ -233,7 +124,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
     .provides_aircraft_variable("SEA LEVEL PRESSURE", "Millibars", 0)?
     .provides_aircraft_variable("SIM ON GROUND", "Bool", 0)?
+    .provides_aircraft_variable("SURFACE CONDITION", "Enum", 0)?
     .provides_aircraft_variable("TOTAL AIR TEMPERATURE", "celsius", 0)?
+    .provides_aircraft_variable("TOTAL WEIGHT", "Pounds", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS LEFT PERCENT", "Percent", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS RIGHT PERCENT", "Percent", 0)?
     .provides_aircraft_variable("TURB ENG CORRECTED N1", "Percent", 1)?
@@ -315,6 +208,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_aspect(autobrakes)?
     .with_aspect(nose_wheel_steering)?
     .with_aspect(flaps)?
+    .with_aspect(fuel)?
     .with_aspect(spoilers)?
     .with_aspect(ailerons)?
     .with_aspect(elevators)?
@@ -24,7 +24,12 @@
 #define CONFIGURATION_SECTION_FUEL_RIGHT_OUTER_QTY "FUEL_RIGHT_OUTER_QTY"
 #define CONFIGURATION_SECTION_FUEL_TRIM_QTY "FUEL_TRIM_QTY"
 
-/* Values in gallons */
+#define CONFIGURATION_SECTION_BATTERY "BATTERY"
+
+#define CONFIGURATION_SECTION_BATTERY_ONE_HEALTH "BAT_1_STATE_OF_HEALTH"
+#define CONFIGURATION_SECTION_BATTERY_TWO_HEALTH "BAT_2_STATE_OF_HEALTH"
+
+/* Fuel values in gallons, battery state of health in percent (zero when not stored) */
 struct Configuration {
   double fuelLeftOuter = 2731.0;
   double fuelFeedOne = 1082.0;
@@ -37,6 +42,8 @@ struct Configuration {
   double fuelFeedFour = fuelFeedOne;
   double fuelRightOuter = fuelLeftOuter;
   double fuelTrim = 6259.0;
+  double batteryOneStateOfHealth = 0.0;
+  double batteryTwoStateOfHealth = 0.0;
 };
 
 class EngineControl {
@@ -49,6 +56,7 @@ class EngineControl {
   Timer timerEngine3;
   Timer timerEngine4;
   Timer timerFuel;
+  Timer timerBatteryHealth;
 
   std::string confFilename = FILENAME_FADEC_CONF_DIRECTORY;
 
@@ -60,6 +68,9 @@ class EngineControl {
   double simOnGround;
   double devState;
   double isReady;
+  bool isFuelConfigurationRestored = false;
+  double storedBatteryOneStateOfHealth;
+  double storedBatteryTwoStateOfHealth;
 
   int engine;
   double engineState;
@@ -79,16 +90,6 @@ class EngineControl {
   double simN3Engine3Pre;
   double simN3Engine4Pre;
   double deltaN3;
-  double thermalEnergy1;
-  double thermalEnergy2;
-  double thermalEnergy3;
-  double thermalEnergy4;
-  double oilTemperature;
-  double oilTemperatureEngine1Pre;
-  double oilTemperatureEngine2Pre;
-  double oilTemperatureEngine3Pre;
-  double oilTemperatureEngine4Pre;
-  double oilTemperatureMax;
   double idleN1;
   double idleN3;
   double idleFF;
@@ -102,7 +103,8 @@ class EngineControl {
 
   const double LBS_TO_KGS = 0.4535934;
   const double KGS_TO_LBS = 1 / 0.4535934;
-  const double FUEL_THRESHOLD = 661;  // lbs/sec
+  // EngineStartPhase::Aborted of the systems WASM FADEC
+  const double START_PHASE_ABORTED = 5;
 
   bool isFlexActive = false;
   double prevThrustLimitType = 0;
@@ -140,6 +142,22 @@ class EngineControl {
     return idleOil;
   }
 
+  /// <summary>
+  /// Phase of the start sequence run by the FADEC in the systems WASM
+  /// </summary>
+  double engineStartPhase(int engine) {
+    switch (engine) {
+      case 1:
+        return simVars->getEngine1StartPhase();
+      case 2:
+        return simVars->getEngine2StartPhase();
+      case 3:
+        return simVars->getEngine3StartPhase();
+      default:
+        return simVars->getEngine4StartPhase();
+    }
+  }
+
   /// <summary>
   /// Engine State Machine
   /// 0 - Engine OFF, 1 - Engine ON, 2 - Engine Starting, 3 - Engine Re-starting & 4 - Engine Shutting
@@ -319,7 +337,12 @@ class EngineControl {
         simVars->setEngine1N3(newN3Fbw);
         simVars->setEngine1N2(newN3Fbw + 0.7);
         simVars->setEngine1N1(poly->startN1(newN3Fbw, idleN3, idleN1));
-        simVars->setEngine1FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        // The engine only lights up once the FADEC start sequencer commanded fuel on
+        if (simVars->getEngine1FuelCommandedOn()) {
+          simVars->setEngine1FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        } else {
+          simVars->setEngine1FF(0);
+        }
 
         if (engineState == 3) {
           if (abs(startEgtFbw - preEgtFbw) <= 1.5) {
@@ -333,11 +356,6 @@ class EngineControl {
         } else {
           simVars->setEngine1EGT(startEgtFbw);
         }
-
-        oilTemperature = poly->startOilTemp(newN3Fbw, idleN3, ambientTemp);
-        oilTemperatureEngine1Pre = oilTemperature;
-        SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine1, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                      &oilTemperature);
       }
     } else if (engine == 2) {
       if (timer < 1.7) {
@@ -358,7 +376,12 @@ class EngineControl {
         simVars->setEngine2N3(newN3Fbw);
         simVars->setEngine2N2(newN3Fbw + 0.7);
         simVars->setEngine2N1(poly->startN1(newN3Fbw, idleN3, idleN1));
-        simVars->setEngine2FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        // The engine only lights up once the FADEC start sequencer commanded fuel on
+        if (simVars->getEngine2FuelCommandedOn()) {
+          simVars->setEngine2FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        } else {
+          simVars->setEngine2FF(0);
+        }
 
         if (engineState == 3) {
           if (abs(startEgtFbw - preEgtFbw) <= 1.5) {
@@ -372,11 +395,6 @@ class EngineControl {
         } else {
           simVars->setEngine2EGT(startEgtFbw);
         }
-
-        oilTemperature = poly->startOilTemp(newN3Fbw, idleN3, ambientTemp);
-        oilTemperatureEngine2Pre = oilTemperature;
-        SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine2, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                      &oilTemperature);
       }
     } else if (engine == 3) {
       if (timer < 1.7) {
@@ -397,7 +415,12 @@ class EngineControl {
         simVars->setEngine3N3(newN3Fbw);
         simVars->setEngine3N2(newN3Fbw + 0.7);
         simVars->setEngine3N1(poly->startN1(newN3Fbw, idleN3, idleN1));
-        simVars->setEngine3FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        // The engine only lights up once the FADEC start sequencer commanded fuel on
+        if (simVars->getEngine3FuelCommandedOn()) {
+          simVars->setEngine3FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        } else {
+          simVars->setEngine3FF(0);
+        }
 
         if (engineState == 3) {
           if (abs(startEgtFbw - preEgtFbw) <= 1.5) {
@@ -411,11 +434,6 @@ class EngineControl {
         } else {
           simVars->setEngine3EGT(startEgtFbw);
         }
-
-        oilTemperature = poly->startOilTemp(newN3Fbw, idleN3, ambientTemp);
-        oilTemperatureEngine3Pre = oilTemperature;
-        SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine3, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                      &oilTemperature);
       }
     } else {
       if (timer < 1.7) {
@@ -436,7 +454,12 @@ class EngineControl {
         simVars->setEngine4N3(newN3Fbw);
         simVars->setEngine4N2(newN3Fbw + 0.7);
         simVars->setEngine4N1(poly->startN1(newN3Fbw, idleN3, idleN1));
-        simVars->setEngine4FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        // The engine only lights up once the FADEC start sequencer commanded fuel on
+        if (simVars->getEngine4FuelCommandedOn()) {
+          simVars->setEngine4FF(poly->startFF(newN3Fbw, idleN3, idleFF));
+        } else {
+          simVars->setEngine4FF(0);
+        }
 
         if (engineState == 3) {
           if (abs(startEgtFbw - preEgtFbw) <= 1.5) {
@@ -450,11 +473,6 @@ class EngineControl {
         } else {
           simVars->setEngine4EGT(startEgtFbw);
         }
-
-        oilTemperature = poly->startOilTemp(newN3Fbw, idleN3, ambientTemp);
-        oilTemperatureEngine4Pre = oilTemperature;
-        SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine4, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                      &oilTemperature);
       }
     }
   }
@@ -662,114 +680,40 @@ class EngineControl {
 
   /// <summary>
   /// FBW Oil Qty, Pressure and Temperature (in Quarts, PSI and degree Celsius)
-  /// Updates Oil with realistic values visualized in the SD
+  /// The lubrication system is simulated by the Rust systems, its outputs are visualized in the SD
   /// </summary>
-  void updateOil(int engine, double thrust, double simN3, double deltaN3, double deltaTime, double ambientTemp) {
-    double steadyTemperature;
-    double thermalEnergy;
-    double oilTemperaturePre;
-    double oilQtyActual;
-    double oilTotalActual;
-    double oilQtyObjective;
-    double oilBurn;
-    double oilPressureIdle;
+  void updateOil(int engine) {
+    double oilQuantity;
     double oilPressure;
+    double oilTemp;
 
-    //--------------------------------------------
-    // Engine Reading
-    //--------------------------------------------
-    if (engine == 1) {
-      steadyTemperature = simVars->getEngine1EGT();
-      thermalEnergy = thermalEnergy1;
-      oilTemperaturePre = oilTemperatureEngine1Pre;
-      oilQtyActual = simVars->getEngine1Oil();
-      oilTotalActual = simVars->getEngine1TotalOil();
-    } else if (engine == 2) {
-      steadyTemperature = simVars->getEngine2EGT();
-      thermalEnergy = thermalEnergy2;
-      oilTemperaturePre = oilTemperatureEngine2Pre;
-      oilQtyActual = simVars->getEngine2Oil();
-      oilTotalActual = simVars->getEngine2TotalOil();
-    } else if (engine == 3) {
-      steadyTemperature = simVars->getEngine3EGT();
-      thermalEnergy = thermalEnergy3;
-      oilTemperaturePre = oilTemperatureEngine3Pre;
-      oilQtyActual = simVars->getEngine3Oil();
-      oilTotalActual = simVars->getEngine3TotalOil();
-    } else {
-      steadyTemperature = simVars->getEngine4EGT();
-      thermalEnergy = thermalEnergy4;
-      oilTemperaturePre = oilTemperatureEngine4Pre;
-      oilQtyActual = simVars->getEngine4Oil();
-      oilTotalActual = simVars->getEngine4TotalOil();
-    }
-
-    //--------------------------------------------
-    // Oil Temperature
-    //--------------------------------------------
-    if (simOnGround == 1 && engineState == 0 && ambientTemp > oilTemperaturePre - 10) {
-      oilTemperature = ambientTemp;
-    } else {
-      if (steadyTemperature > oilTemperatureMax) {
-        steadyTemperature = oilTemperatureMax;
-      }
-      thermalEnergy = (0.995 * thermalEnergy) + (deltaN3 / deltaTime);
-      oilTemperature = poly->oilTemperature(thermalEnergy, oilTemperaturePre, steadyTemperature, deltaTime);
-    }
-
-    //--------------------------------------------
-    // Oil Quantity
-    //--------------------------------------------
-    // Calculating Oil Qty as a function of thrust
-    oilQtyObjective = oilTotalActual * (1 - poly->oilGulpPct(thrust));
-    oilQtyActual = oilQtyActual - (oilTemperature - oilTemperaturePre);
-
-    // Oil burnt taken into account for tank and total oil
-    oilBurn = (0.00011111 * deltaTime);
-    oilQtyActual = oilQtyActual - oilBurn;
-    oilTotalActual = oilTotalActual - oilBurn;
-
-    //--------------------------------------------
-    // Oil Pressure
-    //--------------------------------------------
-    oilPressureIdle = 0;
-
-    oilPressure = poly->oilPressure(simN3) + oilPressureIdle;
-
-    //--------------------------------------------
-    // Engine Writing
-    //--------------------------------------------
     if (engine == 1) {
-      thermalEnergy1 = thermalEnergy;
-      oilTemperatureEngine1Pre = oilTemperature;
-      simVars->setEngine1Oil(oilQtyActual);
-      simVars->setEngine1TotalOil(oilTotalActual);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine1, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &oilTemperature);
+      oilQuantity = simVars->getEngine1OilQuantity();
+      oilPressure = simVars->getEngine1OilPressure();
+      oilTemp = simVars->getEngine1OilTemperature();
+      simVars->setEngine1Oil(oilQuantity);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine1, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilTemp);
       SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilPsiEngine1, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilPressure);
     } else if (engine == 2) {
-      thermalEnergy2 = thermalEnergy;
-      oilTemperatureEngine2Pre = oilTemperature;
-      simVars->setEngine2Oil(oilQtyActual);
-      simVars->setEngine2TotalOil(oilTotalActual);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine2, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &oilTemperature);
+      oilQuantity = simVars->getEngine2OilQuantity();
+      oilPressure = simVars->getEngine2OilPressure();
+      oilTemp = simVars->getEngine2OilTemperature();
+      simVars->setEngine2Oil(oilQuantity);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine2, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilTemp);
       SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilPsiEngine2, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilPressure);
     } else if (engine == 3) {
-      thermalEnergy3 = thermalEnergy;
-      oilTemperatureEngine3Pre = oilTemperature;
-      simVars->setEngine3Oil(oilQtyActual);
-      simVars->setEngine3TotalOil(oilTotalActual);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine3, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &oilTemperature);
+      oilQuantity = simVars->getEngine3OilQuantity();
+      oilPressure = simVars->getEngine3OilPressure();
+      oilTemp = simVars->getEngine3OilTemperature();
+      simVars->setEngine3Oil(oilQuantity);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine3, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilTemp);
       SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilPsiEngine3, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilPressure);
     } else {
-      thermalEnergy4 = thermalEnergy;
-      oilTemperatureEngine4Pre = oilTemperature;
-      simVars->setEngine4Oil(oilQtyActual);
-      simVars->setEngine4TotalOil(oilTotalActual);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine4, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &oilTemperature);
+      oilQuantity = simVars->getEngine4OilQuantity();
+      oilPressure = simVars->getEngine4OilPressure();
+      oilTemp = simVars->getEngine4OilTemperature();
+      simVars->setEngine4Oil(oilQuantity);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine4, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilTemp);
       SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilPsiEngine4, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &oilPressure);
     }
   }
@@ -828,8 +772,10 @@ class EngineControl {
   }
 
   /// <summary>
-  /// FBW Fuel Consumption and Tankering
-  /// Updates Fuel Consumption with realistic values
+  /// FBW Fuel Consumption
+  /// The tank quantities are owned by the fuel system of the Rust systems, which burns the engine fuel flow
+  /// from the feed tanks and writes the quantities to the simulator. This only accumulates the fuel used,
+  /// restores the saved quantities once and saves the current quantities.
   /// </summary>
   void updateFuel(double deltaTime) {
     double m = 0;
@@ -839,10 +785,6 @@ class EngineControl {
     double fuelBurn3 = 0;
     double fuelBurn4 = 0;
 
-    double refuelRate = simVars->getRefuelRate();
-    double refuelStartedByUser = simVars->getRefuelStartedByUser();
-    bool uiFuelTamper = false;
-
     double pumpStateEngine1 = simVars->getPumpStateEngine1();
     double pumpStateEngine2 = simVars->getPumpStateEngine2();
     double pumpStateEngine3 = simVars->getPumpStateEngine3();
@@ -887,24 +829,6 @@ class EngineControl {
     double rightOuterQty = simVars->getTankFuelQuantity(10) * fuelWeightGallon;  // LBS
     double trimQty = simVars->getTankFuelQuantity(11) * fuelWeightGallon;        // LBS
 
-    double fuelLeftOuter = 0;
-    double fuelFeedOne = 0;
-    double fuelLeftMid = 0;
-    double fuelLeftInner = 0;
-    double fuelFeedTwo = 0;
-    double fuelFeedThree = 0;
-    double fuelRightInner = 0;
-    double fuelRightMid = 0;
-    double fuelFeedFour = 0;
-    double fuelRightOuter = 0;
-    double fuelTrim = 0;
-
-    double fuelTotalActual = leftOuterQty + feedOneQty + leftMidQty + leftInnerQty + feedTwoQty + feedThreeQty + rightInnerQty +
-                             rightMidQty + feedFourQty + rightOuterQty + trimQty;  // LBS
-    double fuelTotalPre = fuelLeftOuterPre + fuelFeedOnePre + fuelLeftMidPre + fuelLeftInnerPre + fuelFeedTwoPre + fuelFeedThreePre +
-                          fuelRightInnerPre + fuelRightMidPre + fuelFeedFourPre + fuelRightOuterPre + fuelTrimPre;  // LBS
-    double deltaFuelRate = abs(fuelTotalActual - fuelTotalPre) / (fuelWeightGallon * deltaTime);                    // LBS/ sec
-
     double engine1State = simVars->getEngine1State();
     double engine2State = simVars->getEngine2State();
     double engine3State = simVars->getEngine3State();
@@ -914,6 +838,47 @@ class EngineControl {
     isReady = simVars->getIsReady();
     devState = simVars->getDeveloperState();
 
+    // The saved quantities are handed to the simulator once. The fuel system of the Rust systems
+    // takes them over from there.
+    if (!isFuelConfigurationRestored && isReady == 1) {
+      double fuelLeftOuter = (fuelLeftOuterPre / fuelWeightGallon);    // USG
+      double fuelFeedOne = (fuelFeedOnePre / fuelWeightGallon);        // USG
+      double fuelLeftMid = (fuelLeftMidPre / fuelWeightGallon);        // USG
+      double fuelLeftInner = (fuelLeftInnerPre / fuelWeightGallon);    // USG
+      double fuelFeedTwo = (fuelFeedTwoPre / fuelWeightGallon);        // USG
+      double fuelFeedThree = (fuelFeedThreePre / fuelWeightGallon);    // USG
+      double fuelRightInner = (fuelRightInnerPre / fuelWeightGallon);  // USG
+      double fuelRightMid = (fuelRightMidPre / fuelWeightGallon);      // USG
+      double fuelFeedFour = (fuelFeedFourPre / fuelWeightGallon);      // USG
+      double fuelRightOuter = (fuelRightOuterPre / fuelWeightGallon);  // USG
+      double fuelTrim = (fuelTrimPre / fuelWeightGallon);              // USG
+
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemLeftOuter, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelLeftOuter);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedOne, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelFeedOne);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemLeftMid, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelLeftMid);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemLeftInner, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelLeftInner);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedTwo, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelFeedTwo);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedThree, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelFeedThree);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemRightInner, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelRightInner);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemRightMid, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelRightMid);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedFour, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelFeedFour);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemRightOuter, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
+                                    &fuelRightOuter);
+      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemTrim, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelTrim);
+
+      isFuelConfigurationRestored = true;
+      return;
+    }
+
     deltaTime = deltaTime / 3600;
 
     /*--------------------------------------------
@@ -1000,183 +965,54 @@ class EngineControl {
     }
     --------------------------------------------*/
 
-    // Checking for in-game UI Fuel tampering
-    if ((isReady == 1 && refuelStartedByUser == 0 && deltaFuelRate > FUEL_THRESHOLD) ||
-        (isReady == 1 && refuelStartedByUser == 1 && deltaFuelRate > FUEL_THRESHOLD && refuelRate < 2)) {
-      uiFuelTamper = true;
-    }
-
     //--------------------------------------------
-    // Main Fuel Burn Logic
+    // Fuel Used Accumulators
     //--------------------------------------------
-    if (simPaused || uiFuelTamper && devState == 0) {    // Detects whether the Sim is paused or the Fuel UI is being tampered with
-      simVars->setFuelLeftOuterPre(fuelLeftOuterPre);    // in LBS
-      simVars->setFuelFeedOnePre(fuelFeedOnePre);        // in LBS
-      simVars->setFuelLeftMidPre(fuelLeftMidPre);        // in LBS
-      simVars->setFuelLeftInnerPre(fuelLeftInnerPre);    // in LBS
-      simVars->setFuelFeedTwoPre(fuelFeedTwoPre);        // in LBS
-      simVars->setFuelFeedThreePre(fuelFeedThreePre);    // in LBS
-      simVars->setFuelRightInnerPre(fuelRightInnerPre);  // in LBS
-      simVars->setFuelRightMidPre(fuelRightMidPre);      // in LBS
-      simVars->setFuelFeedFourPre(fuelFeedFourPre);      // in LBS
-      simVars->setFuelRightOuterPre(fuelRightOuterPre);  // in LBS
-      simVars->setFuelTrimPre(fuelTrimPre);              // in LBS
-
-      fuelLeftOuter = (fuelLeftOuterPre / fuelWeightGallon);    // USG
-      fuelFeedOne = (fuelFeedOnePre / fuelWeightGallon);        // USG
-      fuelLeftMid = (fuelLeftMidPre / fuelWeightGallon);        // USG
-      fuelLeftInner = (fuelLeftInnerPre / fuelWeightGallon);    // USG
-      fuelFeedTwo = (fuelFeedTwoPre / fuelWeightGallon);        // USG
-      fuelFeedThree = (fuelFeedThreePre / fuelWeightGallon);    // USG
-      fuelRightInner = (fuelRightInnerPre / fuelWeightGallon);  // USG
-      fuelRightMid = (fuelRightMidPre / fuelWeightGallon);      // USG
-      fuelFeedFour = (fuelFeedFourPre / fuelWeightGallon);      // USG
-      fuelRightOuter = (fuelRightOuterPre / fuelWeightGallon);  // USG
-      fuelTrim = (fuelTrimPre / fuelWeightGallon);              // USG
-
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemLeftOuter, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelLeftOuter);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedOne, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedOne);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemLeftMid, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelLeftMid);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemLeftInner, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelLeftInner);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedTwo, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedTwo);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedThree, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedThree);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemRightInner, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelRightInner);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemRightMid, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelRightMid);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedFour, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedFour);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemRightOuter, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelRightOuter);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemTrim, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double), &fuelTrim);
-    } else if (!uiFuelTamper && refuelStartedByUser == 1) {  // Detects refueling from the EFB
-      simVars->setFuelLeftOuterPre(leftOuterQty);            // in LBS
-      simVars->setFuelFeedOnePre(feedOneQty);                // in LBS
-      simVars->setFuelLeftMidPre(leftMidQty);                // in LBS
-      simVars->setFuelLeftInnerPre(leftInnerQty);            // in LBS
-      simVars->setFuelFeedTwoPre(feedTwoQty);                // in LBS
-      simVars->setFuelFeedThreePre(feedThreeQty);            // in LBS
-      simVars->setFuelRightInnerPre(rightInnerQty);          // in LBS
-      simVars->setFuelRightMidPre(rightMidQty);              // in LBS
-      simVars->setFuelFeedFourPre(feedFourQty);              // in LBS
-      simVars->setFuelRightOuterPre(rightOuterQty);          // in LBS
-      simVars->setFuelTrimPre(trimQty);                      // in LBS
-    } else {
-      if (uiFuelTamper == 1) {
-        fuelLeftOuterPre = leftOuterQty;    // in LBS
-        fuelFeedOnePre = feedOneQty;        // in LBS
-        fuelLeftMidPre = leftMidQty;        // in LBS
-        fuelLeftInnerPre = leftInnerQty;    // in LBS
-        fuelFeedTwoPre = feedTwoQty;        // in LBS
-        fuelFeedThreePre = feedThreeQty;    // in LBS
-        fuelRightInnerPre = rightInnerQty;  // in LBS
-        fuelRightMidPre = rightMidQty;      // in LBS
-        fuelFeedFourPre = feedFourQty;      // in LBS
-        fuelRightOuterPre = rightOuterQty;  // in LBS
-        fuelTrimPre = trimQty;              // in LBS
-      }
-      //--------------------------------------------
-      // Engine 1 Fuel Burn routine
-      if (fuelFeedOnePre > 0) {
-        // Cycle Fuel Burn for Engine 1
-        if (devState != 2) {
-          m = (engine1FF - engine1PreFF) / deltaTime;
-          b = engine1PreFF;
-          fuelBurn1 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
-        }
-        // Fuel Used Accumulators - Engine 1
-        fuelUsedEngine1 += fuelBurn1;
-      } else {
-        fuelBurn1 = 0;
-        fuelFeedOnePre = 0;
-      }
-      //--------------------------------------------
-      // Engine 2 Fuel Burn routine
-      if (fuelFeedTwoPre > 0) {
-        // Cycle Fuel Burn for Engine 2
-        if (devState != 2) {
-          m = (engine2FF - engine2PreFF) / deltaTime;
-          b = engine2PreFF;
-          fuelBurn2 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
-        }
-        // Fuel Used Accumulators - Engine 2
-        fuelUsedEngine2 += fuelBurn2;
-      } else {
-        fuelBurn2 = 0;
-        fuelFeedTwoPre = 0;
-      }
-      //--------------------------------------------
-      // Engine 3 Fuel Burn routine
-      if (fuelFeedThreePre > 0) {
-        // Cycle Fuel Burn for Engine 3
-        if (devState != 2) {
-          m = (engine3FF - engine3PreFF) / deltaTime;
-          b = engine3PreFF;
-          fuelBurn3 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
-        }
-        // Fuel Used Accumulators - Engine 3
-        fuelUsedEngine3 += fuelBurn3;
-      } else {
-        fuelBurn3 = 0;
-        fuelFeedThreePre = 0;
-      }
-      //--------------------------------------------
-      // Engine 4 Fuel Burn routine
-      if (fuelFeedFourPre > 0) {
-        // Cycle Fuel Burn for Engine 4
-        if (devState != 2) {
-          m = (engine4FF - engine4PreFF) / deltaTime;
-          b = engine4PreFF;
-          fuelBurn4 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
-        }
-        // Fuel Used Accumulators - Engine 4
-        fuelUsedEngine4 += fuelBurn4;
-      } else {
-        fuelBurn4 = 0;
-        fuelFeedFourPre = 0;
-      }
-
-      fuelFeedOne = fuelFeedOnePre - (fuelBurn1 * KGS_TO_LBS);      // LBS
-      fuelFeedTwo = fuelFeedTwoPre - (fuelBurn2 * KGS_TO_LBS);      // LBS
-      fuelFeedThree = fuelFeedThreePre - (fuelBurn3 * KGS_TO_LBS);  // LBS
-      fuelFeedFour = fuelFeedFourPre - (fuelBurn4 * KGS_TO_LBS);    // LBS
-
-      // Setting new pre-cycle conditions
-      simVars->setEngine1PreFF(engine1FF);
-      simVars->setEngine2PreFF(engine2FF);
-      simVars->setEngine3PreFF(engine3FF);
-      simVars->setEngine4PreFF(engine4FF);
-      simVars->setFuelUsedEngine1(fuelUsedEngine1);  // in KG
-      simVars->setFuelUsedEngine2(fuelUsedEngine2);  // in KG
-      simVars->setFuelUsedEngine3(fuelUsedEngine3);  // in KG
-      simVars->setFuelUsedEngine4(fuelUsedEngine4);  // in KG
-
-      simVars->setFuelFeedOnePre(fuelFeedOne);      // in LBS
-      simVars->setFuelFeedTwoPre(fuelFeedTwo);      // in LBS
-      simVars->setFuelFeedThreePre(fuelFeedThree);  // in LBS
-      simVars->setFuelFeedFourPre(fuelFeedFour);    // in LBS
-
-      fuelFeedOne = (fuelFeedOne / fuelWeightGallon);      // USG
-      fuelFeedTwo = (fuelFeedTwo / fuelWeightGallon);      // USG
-      fuelFeedThree = (fuelFeedThree / fuelWeightGallon);  // USG
-      fuelFeedFour = (fuelFeedFour / fuelWeightGallon);    // USG
-
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedOne, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedOne);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedTwo, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedTwo);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedThree, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedThree);
-      SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::FuelSystemFeedFour, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                    &fuelFeedFour);
+    if (!simPaused && devState != 2) {
+      m = (engine1FF - engine1PreFF) / deltaTime;
+      b = engine1PreFF;
+      fuelBurn1 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
+
+      m = (engine2FF - engine2PreFF) / deltaTime;
+      b = engine2PreFF;
+      fuelBurn2 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
+
+      m = (engine3FF - engine3PreFF) / deltaTime;
+      b = engine3PreFF;
+      fuelBurn3 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
+
+      m = (engine4FF - engine4PreFF) / deltaTime;
+      b = engine4PreFF;
+      fuelBurn4 = (m * pow(deltaTime, 2) / 2) + (b * deltaTime);  // KG
+
+      fuelUsedEngine1 += fuelBurn1;
+      fuelUsedEngine2 += fuelBurn2;
+      fuelUsedEngine3 += fuelBurn3;
+      fuelUsedEngine4 += fuelBurn4;
     }
 
+    // Setting new pre-cycle conditions
+    simVars->setEngine1PreFF(engine1FF);
+    simVars->setEngine2PreFF(engine2FF);
+    simVars->setEngine3PreFF(engine3FF);
+    simVars->setEngine4PreFF(engine4FF);
+    simVars->setFuelUsedEngine1(fuelUsedEngine1);  // in KG
+    simVars->setFuelUsedEngine2(fuelUsedEngine2);  // in KG
+    simVars->setFuelUsedEngine3(fuelUsedEngine3);  // in KG
+    simVars->setFuelUsedEngine4(fuelUsedEngine4);  // in KG
+
+    simVars->setFuelLeftOuterPre(leftOuterQty);    // in LBS
+    simVars->setFuelFeedOnePre(feedOneQty);        // in LBS
+    simVars->setFuelLeftMidPre(leftMidQty);        // in LBS
+    simVars->setFuelLeftInnerPre(leftInnerQty);    // in LBS
+    simVars->setFuelFeedTwoPre(feedTwoQty);        // in LBS
+    simVars->setFuelFeedThreePre(feedThreeQty);    // in LBS
+    simVars->setFuelRightInnerPre(rightInnerQty);  // in LBS
+    simVars->setFuelRightMidPre(rightMidQty);      // in LBS
+    simVars->setFuelFeedFourPre(feedFourQty);      // in LBS
+    simVars->setFuelRightOuterPre(rightOuterQty);  // in LBS
+    simVars->setFuelTrimPre(trimQty);              // in LBS
+
     // Will save the current fuel quantities if on the ground AND engines being shutdown
     if (timerFuel.elapsed() >= 1000 && simVars->getSimOnGround() &&
         (engine1State == 0 || engine1State == 10 || engine1State == 4 || engine1State == 14 || engine2State == 0 || engine2State == 10 ||
@@ -1201,6 +1037,28 @@ class EngineControl {
     }
   }
 
+  /// Keeps the battery state of health computed by the Rust systems from one session to the next.
+  /// The stored value is handed back to the systems on load, which then take it over.
+  void updateBatteryHealth() {
+    if (timerBatteryHealth.elapsed() < 1000) {
+      return;
+    }
+    timerBatteryHealth.reset();
+
+    // Zero until the systems are running
+    double batteryOneStateOfHealth = round(simVars->getBatteryStateOfHealth(1) * 100) / 100;
+    double batteryTwoStateOfHealth = round(simVars->getBatteryStateOfHealth(2) * 100) / 100;
+    if (batteryOneStateOfHealth <= 0 || batteryTwoStateOfHealth <= 0) {
+      return;
+    }
+
+    if (batteryOneStateOfHealth != storedBatteryOneStateOfHealth || batteryTwoStateOfHealth != storedBatteryTwoStateOfHealth) {
+      saveBatteryHealthInConfiguration(batteryOneStateOfHealth, batteryTwoStateOfHealth);
+      storedBatteryOneStateOfHealth = batteryOneStateOfHealth;
+      storedBatteryTwoStateOfHealth = batteryTwoStateOfHealth;
+    }
+  }
+
   void updateThrustLimits(double simulationTime,
                           double altitude,
                           double ambientTemp,
@@ -1328,6 +1186,16 @@ class EngineControl {
 
     Configuration configuration = getConfigurationFromFile();
 
+    // Restore the battery state of health
+    storedBatteryOneStateOfHealth = configuration.batteryOneStateOfHealth;
+    storedBatteryTwoStateOfHealth = configuration.batteryTwoStateOfHealth;
+    if (storedBatteryOneStateOfHealth > 0) {
+      simVars->setStoredBatteryStateOfHealth(1, storedBatteryOneStateOfHealth);
+    }
+    if (storedBatteryTwoStateOfHealth > 0) {
+      simVars->setStoredBatteryStateOfHealth(2, storedBatteryTwoStateOfHealth);
+    }
+
     for (engine = 1; engine <= 4; engine++) {
       // Obtain Engine Time
       engTime = simVars->getEngineTime(engine) + engTime;
@@ -1347,43 +1215,7 @@ class EngineControl {
       }
     }
 
-    // Setting initial Oil Temperature
-    thermalEnergy1 = 0;
-    thermalEnergy2 = 0;
-    thermalEnergy3 = 0;
-    thermalEnergy4 = 0;
-    oilTemperatureMax = 85;
     simOnGround = simVars->getSimOnGround();
-    double engine1Combustion = simVars->getEngineCombustion(1);
-    double engine2Combustion = simVars->getEngineCombustion(2);
-    double engine3Combustion = simVars->getEngineCombustion(3);
-    double engine4Combustion = simVars->getEngineCombustion(4);
-
-    if (simOnGround == 1 && engine1Combustion == 1 && engine2Combustion == 1 && engine3Combustion == 1 && engine4Combustion == 1) {
-      oilTemperatureEngine1Pre = 75;
-      oilTemperatureEngine2Pre = 75;
-      oilTemperatureEngine3Pre = 75;
-      oilTemperatureEngine4Pre = 75;
-    } else if (simOnGround == 0 && engine1Combustion == 1 && engine2Combustion == 1 && engine3Combustion == 1 && engine4Combustion == 1) {
-      oilTemperatureEngine1Pre = 85;
-      oilTemperatureEngine2Pre = 85;
-      oilTemperatureEngine3Pre = 85;
-      oilTemperatureEngine4Pre = 85;
-    } else {
-      oilTemperatureEngine1Pre = ambientTemp;
-      oilTemperatureEngine2Pre = ambientTemp;
-      oilTemperatureEngine3Pre = ambientTemp;
-      oilTemperatureEngine4Pre = ambientTemp;
-    }
-
-    SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine1, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                  &oilTemperatureEngine1Pre);
-    SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine2, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                  &oilTemperatureEngine2Pre);
-    SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine3, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                  &oilTemperatureEngine3Pre);
-    SimConnect_SetDataOnSimObject(hSimConnect, DataTypesID::OilTempEngine4, SIMCONNECT_OBJECT_ID_USER, 0, 0, sizeof(double),
-                                  &oilTemperatureEngine4Pre);
 
     // Initialize Engine State
     simVars->setEngine1State(10);
@@ -1459,6 +1291,10 @@ class EngineControl {
     // Timer timer;
     for (engine = 1; engine <= 4; engine++) {
       engineStarter = simVars->getEngineStarter(engine);
+      // An aborted start ends the start sequence, the starter then only dry cranks the engine
+      if (engineStartPhase(engine) == START_PHASE_ABORTED) {
+        engineStarter = 0;
+      }
       engineIgniter = simVars->getEngineIgniter(engine);
       simCN1 = simVars->getCN1(engine);
       simN1 = simVars->getN1(engine);
@@ -1503,9 +1339,10 @@ class EngineControl {
           updatePrimaryParameters(engine, simN1, simN3);
           cFbwFF = updateFF(engine, simCN1, mach, pressAltitude, ambientTemp, ambientPressure);
           updateEGT(engine, deltaTime, simOnGround, engineState, simCN1, cFbwFF, mach, pressAltitude, ambientTemp);
-          // updateOil(engine, imbalance, thrust, simN3, deltaN3, deltaTime, ambientTemp);
       }
 
+      updateOil(engine);
+
       // set highest N1 from either engine
       simN1highest = max(simN1highest, simN1);
     }
@@ -1517,6 +1354,8 @@ class EngineControl {
 
     updateFuel(deltaTime);
 
+    updateBatteryHealth();
+
     updateThrustLimits(simulationTime, pressAltitude, ambientTemp, ambientPressure, mach, simN1highest, packs, nai, wai);
     // timer.elapsed();
   }
@@ -1552,6 +1391,8 @@ class EngineControl {
         mINI::INITypeConversion::getDouble(structure, CONFIGURATION_SECTION_FUEL, CONFIGURATION_SECTION_FUEL_FEED_FOUR_QTY, 1082.0),
         mINI::INITypeConversion::getDouble(structure, CONFIGURATION_SECTION_FUEL, CONFIGURATION_SECTION_FUEL_RIGHT_OUTER_QTY, 2731.0),
         mINI::INITypeConversion::getDouble(structure, CONFIGURATION_SECTION_FUEL, CONFIGURATION_SECTION_FUEL_TRIM_QTY, 6259.0),
+        mINI::INITypeConversion::getDouble(structure, CONFIGURATION_SECTION_BATTERY, CONFIGURATION_SECTION_BATTERY_ONE_HEALTH, 0.0),
+        mINI::INITypeConversion::getDouble(structure, CONFIGURATION_SECTION_BATTERY, CONFIGURATION_SECTION_BATTERY_TWO_HEALTH, 0.0),
     };
   }
 
@@ -1574,6 +1415,21 @@ class EngineControl {
     stInitStructure[CONFIGURATION_SECTION_FUEL][CONFIGURATION_SECTION_FUEL_RIGHT_OUTER_QTY] = std::to_string(configuration.fuelRightOuter);
     stInitStructure[CONFIGURATION_SECTION_FUEL][CONFIGURATION_SECTION_FUEL_TRIM_QTY] = std::to_string(configuration.fuelTrim);
 
+    if (!iniFile.write(stInitStructure, true)) {
+      std::cout << "EngineControl: failed to write engine conf " << confFilename << " due to error \"" << strerror(errno) << "\""
+                << std::endl;
+    }
+  }
+  void saveBatteryHealthInConfiguration(double batteryOneStateOfHealth, double batteryTwoStateOfHealth) {
+    mINI::INIStructure stInitStructure;
+    mINI::INIFile iniFile(confFilename);
+
+    // Do not check a possible error since the file may not exist yet
+    iniFile.read(stInitStructure);
+
+    stInitStructure[CONFIGURATION_SECTION_BATTERY][CONFIGURATION_SECTION_BATTERY_ONE_HEALTH] = std::to_string(batteryOneStateOfHealth);
+    stInitStructure[CONFIGURATION_SECTION_BATTERY][CONFIGURATION_SECTION_BATTERY_TWO_HEALTH] = std::to_string(batteryTwoStateOfHealth);
+
     if (!iniFile.write(stInitStructure, true)) {
       std::cout << "EngineControl: failed to write engine conf " << confFilename << " due to error \"" << strerror(errno) << "\""
                 << std::endl;
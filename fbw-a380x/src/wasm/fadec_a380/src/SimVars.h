@@ -177,6 +177,11 @@ class SimVars {
   ID FuelRightOuterPre;
   ID FuelTrimPre;
 
+  ID BatteryOneStateOfHealth;
+  ID BatteryTwoStateOfHealth;
+  ID StoredBatteryOneStateOfHealth;
+  ID StoredBatteryTwoStateOfHealth;
+
   ID RefuelRate;
   ID RefuelStartedByUser;
   ID FuelOverflowLeft;
@@ -189,6 +194,26 @@ class SimVars {
   ID Engine2Timer;
   ID Engine3Timer;
   ID Engine4Timer;
+  ID Engine1StartPhase;
+  ID Engine2StartPhase;
+  ID Engine3StartPhase;
+  ID Engine4StartPhase;
+  ID Engine1FuelCommandedOn;
+  ID Engine2FuelCommandedOn;
+  ID Engine3FuelCommandedOn;
+  ID Engine4FuelCommandedOn;
+  ID Engine1OilQuantity;
+  ID Engine2OilQuantity;
+  ID Engine3OilQuantity;
+  ID Engine4OilQuantity;
+  ID Engine1OilPressure;
+  ID Engine2OilPressure;
+  ID Engine3OilPressure;
+  ID Engine4OilPressure;
+  ID Engine1OilTemperature;
+  ID Engine2OilTemperature;
+  ID Engine3OilTemperature;
+  ID Engine4OilTemperature;
   ID PumpStateEngine1;
   ID PumpStateEngine2;
   ID PumpStateEngine3;
@@ -281,6 +306,11 @@ class SimVars {
     FuelRightOuterPre = register_named_variable("A32NX_FUEL_RIGHTOUTER_PRE");
     FuelTrimPre = register_named_variable("A32NX_FUEL_TRIM_PRE");
 
+    BatteryOneStateOfHealth = register_named_variable("A32NX_ELEC_BAT_1_STATE_OF_HEALTH");
+    BatteryTwoStateOfHealth = register_named_variable("A32NX_ELEC_BAT_2_STATE_OF_HEALTH");
+    StoredBatteryOneStateOfHealth = register_named_variable("A32NX_ELEC_BAT_1_STORED_STATE_OF_HEALTH");
+    StoredBatteryTwoStateOfHealth = register_named_variable("A32NX_ELEC_BAT_2_STORED_STATE_OF_HEALTH");
+
     RefuelRate = register_named_variable("A32NX_EFB_REFUEL_RATE_SETTING");
     RefuelStartedByUser = register_named_variable("A32NX_REFUEL_STARTED_BY_USR");
     Engine1State = register_named_variable("A32NX_ENGINE_STATE:1");
@@ -291,6 +321,26 @@ class SimVars {
     Engine2Timer = register_named_variable("A32NX_ENGINE_TIMER:2");
     Engine3Timer = register_named_variable("A32NX_ENGINE_TIMER:3");
     Engine4Timer = register_named_variable("A32NX_ENGINE_TIMER:4");
+    Engine1StartPhase = register_named_variable("A32NX_ENGINE_1_START_PHASE");
+    Engine2StartPhase = register_named_variable("A32NX_ENGINE_2_START_PHASE");
+    Engine3StartPhase = register_named_variable("A32NX_ENGINE_3_START_PHASE");
+    Engine4StartPhase = register_named_variable("A32NX_ENGINE_4_START_PHASE");
+    Engine1FuelCommandedOn = register_named_variable("A32NX_ENGINE_1_FUEL_COMMANDED_ON");
+    Engine2FuelCommandedOn = register_named_variable("A32NX_ENGINE_2_FUEL_COMMANDED_ON");
+    Engine3FuelCommandedOn = register_named_variable("A32NX_ENGINE_3_FUEL_COMMANDED_ON");
+    Engine4FuelCommandedOn = register_named_variable("A32NX_ENGINE_4_FUEL_COMMANDED_ON");
+    Engine1OilQuantity = register_named_variable("A32NX_ENGINE_1_OIL_QUANTITY");
+    Engine2OilQuantity = register_named_variable("A32NX_ENGINE_2_OIL_QUANTITY");
+    Engine3OilQuantity = register_named_variable("A32NX_ENGINE_3_OIL_QUANTITY");
+    Engine4OilQuantity = register_named_variable("A32NX_ENGINE_4_OIL_QUANTITY");
+    Engine1OilPressure = register_named_variable("A32NX_ENGINE_1_OIL_PRESSURE");
+    Engine2OilPressure = register_named_variable("A32NX_ENGINE_2_OIL_PRESSURE");
+    Engine3OilPressure = register_named_variable("A32NX_ENGINE_3_OIL_PRESSURE");
+    Engine4OilPressure = register_named_variable("A32NX_ENGINE_4_OIL_PRESSURE");
+    Engine1OilTemperature = register_named_variable("A32NX_ENGINE_1_OIL_TEMPERATURE");
+    Engine2OilTemperature = register_named_variable("A32NX_ENGINE_2_OIL_TEMPERATURE");
+    Engine3OilTemperature = register_named_variable("A32NX_ENGINE_3_OIL_TEMPERATURE");
+    Engine4OilTemperature = register_named_variable("A32NX_ENGINE_4_OIL_TEMPERATURE");
     PumpStateEngine1 = register_named_variable("A32NX_PUMP_STATE:1");
     PumpStateEngine2 = register_named_variable("A32NX_PUMP_STATE:2");
     PumpStateEngine3 = register_named_variable("A32NX_PUMP_STATE:3");
@@ -454,6 +504,10 @@ class SimVars {
   void setFuelRightOuterPre(FLOAT64 value) { set_named_variable_value(FuelRightOuterPre, value); };
   void setFuelTrimPre(FLOAT64 value) { set_named_variable_value(FuelTrimPre, value); };
 
+  void setStoredBatteryStateOfHealth(int index, FLOAT64 value) {
+    set_named_variable_value(index == 1 ? StoredBatteryOneStateOfHealth : StoredBatteryTwoStateOfHealth, value);
+  }
+
   void setEngine1State(FLOAT64 value) { set_named_variable_value(Engine1State, value); }
   void setEngine2State(FLOAT64 value) { set_named_variable_value(Engine2State, value); }
   void setEngine3State(FLOAT64 value) { set_named_variable_value(Engine3State, value); }
@@ -531,6 +585,10 @@ class SimVars {
   FLOAT64 getFuelRightOuterPre() { return get_named_variable_value(FuelRightOuterPre); }
   FLOAT64 getFuelTrimPre() { return get_named_variable_value(FuelTrimPre); }
 
+  FLOAT64 getBatteryStateOfHealth(int index) {
+    return get_named_variable_value(index == 1 ? BatteryOneStateOfHealth : BatteryTwoStateOfHealth);
+  }
+
   FLOAT64 getRefuelRate() { return get_named_variable_value(RefuelRate); }
   FLOAT64 getRefuelStartedByUser() { return get_named_variable_value(RefuelStartedByUser); }
   FLOAT64 getPumpStateEngine1() { return get_named_variable_value(PumpStateEngine1); }
@@ -574,6 +632,26 @@ class SimVars {
   FLOAT64 getEngine2Timer() { return get_named_variable_value(Engine2Timer); }
   FLOAT64 getEngine3Timer() { return get_named_variable_value(Engine3Timer); }
   FLOAT64 getEngine4Timer() { return get_named_variable_value(Engine4Timer); }
+  FLOAT64 getEngine1StartPhase() { return get_named_variable_value(Engine1StartPhase); }
+  FLOAT64 getEngine2StartPhase() { return get_named_variable_value(Engine2StartPhase); }
+  FLOAT64 getEngine3StartPhase() { return get_named_variable_value(Engine3StartPhase); }
+  FLOAT64 getEngine4StartPhase() { return get_named_variable_value(Engine4StartPhase); }
+  FLOAT64 getEngine1FuelCommandedOn() { return get_named_variable_value(Engine1FuelCommandedOn); }
+  FLOAT64 getEngine2FuelCommandedOn() { return get_named_variable_value(Engine2FuelCommandedOn); }
+  FLOAT64 getEngine3FuelCommandedOn() { return get_named_variable_value(Engine3FuelCommandedOn); }
+  FLOAT64 getEngine4FuelCommandedOn() { return get_named_variable_value(Engine4FuelCommandedOn); }
+  FLOAT64 getEngine1OilQuantity() { return get_named_variable_value(Engine1OilQuantity); }
+  FLOAT64 getEngine2OilQuantity() { return get_named_variable_value(Engine2OilQuantity); }
+  FLOAT64 getEngine3OilQuantity() { return get_named_variable_value(Engine3OilQuantity); }
+  FLOAT64 getEngine4OilQuantity() { return get_named_variable_value(Engine4OilQuantity); }
+  FLOAT64 getEngine1OilPressure() { return get_named_variable_value(Engine1OilPressure); }
+  FLOAT64 getEngine2OilPressure() { return get_named_variable_value(Engine2OilPressure); }
+  FLOAT64 getEngine3OilPressure() { return get_named_variable_value(Engine3OilPressure); }
+  FLOAT64 getEngine4OilPressure() { return get_named_variable_value(Engine4OilPressure); }
+  FLOAT64 getEngine1OilTemperature() { return get_named_variable_value(Engine1OilTemperature); }
+  FLOAT64 getEngine2OilTemperature() { return get_named_variable_value(Engine2OilTemperature); }
+  FLOAT64 getEngine3OilTemperature() { return get_named_variable_value(Engine3OilTemperature); }
+  FLOAT64 getEngine4OilTemperature() { return get_named_variable_value(Engine4OilTemperature); }
   FLOAT64 getFF(int index) { return aircraft_varget(correctedFF, m_Units->Pph, index); }
   FLOAT64 getMach() { return aircraft_varget(AirSpeedMach, m_Units->Mach, 0); }
   FLOAT64 getPlaneAltitude() { return aircraft_varget(PlaneAltitude, m_Units->Feet, 0); }
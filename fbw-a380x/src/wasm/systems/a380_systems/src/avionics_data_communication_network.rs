@@ -69,6 +69,8 @@ pub struct A380AvionicsDataCommunicationNetwork {
 }
 
 impl A380AvionicsDataCommunicationNetwork {
+    const CPIOM_F_INDICES: std::ops::Range<usize> = 14..18;
+
     pub fn new(context: &mut InitContext) -> Self {
         let mut first_network = FxHashMap::default();
         first_network.insert(0, vec![1, 2, 7]);
@@ -374,6 +376,13 @@ impl A380AvionicsDataCommunicationNetwork {
         }
     }
 
+    /// The fuel quantity and management system is hosted by CPIOM F1 to F4.
+    pub fn fuel_quantity_management_is_available(&self) -> bool {
+        self.cpio_modules[Self::CPIOM_F_INDICES]
+            .iter()
+            .any(|cpiom| cpiom.is_available())
+    }
+
     pub fn update(&mut self) {
         let mut update_network_a = false;
         let mut update_network_b = false;
@@ -2,16 +2,18 @@ use super::{
     A380AlternatingCurrentElectricalSystem, A380DirectCurrentElectricalSystem,
     A380ElectricalOverheadPanel, A380EmergencyElectricalOverheadPanel,
 };
-use std::time::Duration;
+use std::{cell::Ref, time::Duration};
 use systems::simulation::InitContext;
 use systems::{
+    accept_iterable,
     electrical::{
         AlternatingCurrentElectricalSystem, Contactor, ElectricalBus, Electricity,
-        EmergencyGenerator, EngineGenerator, ExternalPowerSource, TransformerRectifier,
+        EmergencyGenerator, ExternalPowerSource, Potential, TransformerRectifier,
+        VariableFrequencyGenerator,
     },
     shared::{
-        AuxiliaryPowerUnitElectrical, DelayedTrueLogicGate, ElectricalBusType, EngineCorrectedN2,
-        EngineFirePushButtons,
+        AuxiliaryPowerUnitElectrical, AuxiliaryPowerUnitGenerators, DelayedTrueLogicGate,
+        ElectricalBusType, EngineCorrectedN2, EngineFirePushButtons,
     },
     simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
 };
@@ -20,8 +22,7 @@ use uom::si::{f64::*, velocity::knot};
 pub(super) struct A380AlternatingCurrentElectrical {
     main_power_sources: A380MainPowerSources,
     ac_ess_feed_contactors: A380AcEssFeedContactors,
-    ac_bus_1: ElectricalBus,
-    ac_bus_2: ElectricalBus,
+    ac_buses: [ElectricalBus; 4],
     ac_ess_bus: ElectricalBus,
     ac_ess_shed_bus: ElectricalBus,
     ac_ess_shed_contactor: Contactor,
@@ -41,8 +42,9 @@ impl A380AlternatingCurrentElectrical {
         A380AlternatingCurrentElectrical {
             main_power_sources: A380MainPowerSources::new(context),
             ac_ess_feed_contactors: A380AcEssFeedContactors::new(context),
-            ac_bus_1: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
-            ac_bus_2: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+            ac_buses: [1, 2, 3, 4].map(|number| {
+                ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(number))
+            }),
             ac_ess_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrentEssential),
             ac_ess_shed_bus: ElectricalBus::new(
                 context,
@@ -72,17 +74,17 @@ impl A380AlternatingCurrentElectrical {
         &mut self,
         context: &UpdateContext,
         electricity: &mut Electricity,
-        ext_pwr: &ExternalPowerSource,
+        ext_pwrs: &[ExternalPowerSource; 4],
         overhead: &A380ElectricalOverheadPanel,
         emergency_overhead: &A380EmergencyElectricalOverheadPanel,
-        apu: &impl AuxiliaryPowerUnitElectrical,
+        apu: &(impl AuxiliaryPowerUnitElectrical + AuxiliaryPowerUnitGenerators),
         engine_fire_push_buttons: &impl EngineFirePushButtons,
-        engines: [&impl EngineCorrectedN2; 2],
+        engines: [&impl EngineCorrectedN2; 4],
     ) {
         self.main_power_sources.update(
             context,
             electricity,
-            ext_pwr,
+            ext_pwrs,
             overhead,
             emergency_overhead,
             apu,
@@ -91,29 +93,31 @@ impl A380AlternatingCurrentElectrical {
         );
 
         self.main_power_sources
-            .power_ac_bus_1(electricity, &self.ac_bus_1);
-        self.main_power_sources
-            .power_ac_bus_2(electricity, &self.ac_bus_2);
+            .power_ac_buses(electricity, &self.ac_buses);
     }
 
     pub fn update(
         &mut self,
         context: &UpdateContext,
         electricity: &mut Electricity,
-        ext_pwr: &ExternalPowerSource,
+        ext_pwrs: &[ExternalPowerSource; 4],
         overhead: &A380ElectricalOverheadPanel,
         emergency_generator: &EmergencyGenerator,
     ) {
+        // Only the first external power receptacle can supply the ground and flight service
+        // bus and TR 2 directly. The other receptacles reach them through AC bus 2 only.
+        let ext_pwr = &ext_pwrs[0];
+
         self.ac_bus_2_to_tr_2_contactor
-            .close_when(electricity.is_powered(&self.ac_bus_2) && !self.tr_2.has_failed());
-        electricity.flow(&self.ac_bus_2, &self.ac_bus_2_to_tr_2_contactor);
+            .close_when(electricity.is_powered(&self.ac_buses[1]) && !self.tr_2.has_failed());
+        electricity.flow(&self.ac_buses[1], &self.ac_bus_2_to_tr_2_contactor);
 
         // On the real aircraft there is a button inside the galley which is taken into
         // account when determining whether to close this contactor or not.
         // As we're not building a galley simulator, for now we assume the button is ON.
         self.ext_pwr_to_ac_gnd_flt_service_bus_and_tr_2_contactor
             .close_when(
-                !electricity.is_powered(&self.ac_bus_2)
+                !electricity.is_powered(&self.ac_buses[1])
                     && !self.tr_2.has_failed()
                     && electricity.is_powered(ext_pwr),
             );
@@ -131,7 +135,7 @@ impl A380AlternatingCurrentElectrical {
             &self.ac_gnd_flt_service_bus,
         );
 
-        electricity.flow(&self.ac_bus_1, &self.tr_1);
+        electricity.flow(&self.ac_buses[0], &self.tr_1);
         electricity.transform_in(&self.tr_1);
 
         electricity.flow(&self.ac_bus_2_to_tr_2_contactor, &self.tr_2);
@@ -144,8 +148,8 @@ impl A380AlternatingCurrentElectrical {
         self.ac_ess_feed_contactors.update(
             context,
             electricity,
-            &self.ac_bus_1,
-            &self.ac_bus_2,
+            &self.ac_buses[0],
+            &self.ac_buses[1],
             overhead,
         );
 
@@ -208,8 +212,8 @@ impl A380AlternatingCurrentElectrical {
         emergency_generator: &EmergencyGenerator,
         electricity: &mut Electricity,
     ) {
-        let ac_bus_or_emergency_gen_provides_power = electricity.is_powered(&self.ac_bus_1)
-            || electricity.is_powered(&self.ac_bus_2)
+        let ac_bus_or_emergency_gen_provides_power = self
+            .any_non_essential_bus_powered(electricity)
             || electricity.is_powered(emergency_generator);
         self.ac_ess_shed_contactor
             .close_when(ac_bus_or_emergency_gen_provides_power);
@@ -217,35 +221,42 @@ impl A380AlternatingCurrentElectrical {
         electricity.flow(&self.ac_ess_shed_contactor, &self.ac_ess_shed_bus);
     }
 
-    /// Whether or not AC BUS 1 and AC BUS 2 are powered by a single engine
-    /// generator exclusively. Also returns true when one of the buses is
-    /// unpowered and the other bus is powered by an engine generator.
+    /// Whether or not the powered AC buses 1 to 4 are all powered by the same
+    /// single engine generator exclusively. Unpowered buses are ignored.
     pub fn main_ac_buses_powered_by_single_engine_generator_only(
         &self,
         electricity: &Electricity,
     ) -> bool {
-        let ac_bus_1_potential = electricity.output_of(&self.ac_bus_1);
-        let ac_bus_2_potential = electricity.output_of(&self.ac_bus_2);
-
-        (ac_bus_1_potential.is_unpowered()
-            && ac_bus_2_potential.is_only_powered_by_single_engine_generator())
-            || (ac_bus_2_potential.is_unpowered()
-                && ac_bus_1_potential.is_only_powered_by_single_engine_generator())
-            || (ac_bus_1_potential.is_only_powered_by_single_engine_generator()
-                && ac_bus_1_potential.is_powered_by_same_single_source(ac_bus_2_potential))
+        let mut powered_buses = self.powered_ac_bus_potentials(electricity);
+
+        match powered_buses.next() {
+            Some(first) => {
+                first.is_only_powered_by_single_engine_generator()
+                    && powered_buses.all(|potential| {
+                        potential.is_powered_by_same_single_source(Ref::clone(&first))
+                    })
+            }
+            None => false,
+        }
     }
 
-    /// Whether or not AC BUS 1 and AC BUS 2 are powered by the APU generator
-    /// exclusively. Also returns true when one of the buses is unpowered and
-    /// the other bus is powered by the APU generator.
+    /// Whether or not the powered AC buses 1 to 4 are all powered by the APU
+    /// exclusively. Unpowered buses are ignored.
     pub fn main_ac_buses_powered_by_apu_generator_only(&self, electricity: &Electricity) -> bool {
-        let ac_bus_1_potential = electricity.output_of(&self.ac_bus_1);
-        let ac_bus_2_potential = electricity.output_of(&self.ac_bus_2);
+        let mut powered_buses = self.powered_ac_bus_potentials(electricity).peekable();
 
-        (ac_bus_1_potential.is_unpowered() && ac_bus_2_potential.is_only_powered_by_apu())
-            || (ac_bus_2_potential.is_unpowered() && ac_bus_1_potential.is_only_powered_by_apu())
-            || (ac_bus_1_potential.is_only_powered_by_apu()
-                && ac_bus_2_potential.is_only_powered_by_apu())
+        powered_buses.peek().is_some()
+            && powered_buses.all(|potential| potential.is_only_powered_by_apu())
+    }
+
+    fn powered_ac_bus_potentials<'a>(
+        &'a self,
+        electricity: &'a Electricity,
+    ) -> impl Iterator<Item = Ref<'a, Potential>> {
+        self.ac_buses
+            .iter()
+            .map(|bus| electricity.output_of(bus))
+            .filter(|potential| potential.is_powered())
     }
 
     /// Determines if 15XE2 should be closed. 15XE2 is the contactor which connects
@@ -284,7 +295,7 @@ impl A380AlternatingCurrentElectrical {
 }
 impl A380AlternatingCurrentElectricalSystem for A380AlternatingCurrentElectrical {
     fn ac_bus_2_powered(&self, electricity: &Electricity) -> bool {
-        electricity.is_powered(&self.ac_bus_2)
+        electricity.is_powered(&self.ac_buses[1])
     }
 
     fn tr_1_and_2_available(&self, electricity: &Electricity) -> bool {
@@ -305,7 +316,7 @@ impl A380AlternatingCurrentElectricalSystem for A380AlternatingCurrentElectrical
 }
 impl AlternatingCurrentElectricalSystem for A380AlternatingCurrentElectrical {
     fn any_non_essential_bus_powered(&self, electricity: &Electricity) -> bool {
-        electricity.is_powered(&self.ac_bus_1) || electricity.is_powered(&self.ac_bus_2)
+        self.ac_buses.iter().any(|bus| electricity.is_powered(bus))
     }
 }
 impl SimulationElement for A380AlternatingCurrentElectrical {
@@ -322,8 +333,7 @@ impl SimulationElement for A380AlternatingCurrentElectrical {
         self.emergency_gen_contactor.accept(visitor);
         self.static_inv_to_ac_ess_bus_contactor.accept(visitor);
 
-        self.ac_bus_1.accept(visitor);
-        self.ac_bus_2.accept(visitor);
+        accept_iterable!(self.ac_buses, visitor);
         self.ac_ess_bus.accept(visitor);
         self.ac_ess_shed_bus.accept(visitor);
         self.ac_stat_inv_bus.accept(visitor);
@@ -336,117 +346,230 @@ impl SimulationElement for A380AlternatingCurrentElectrical {
     }
 }
 
+/// The source feeding the AC tie of one side of the aircraft, which the bus tie
+/// contactors of that side's buses connect to.
+#[derive(Clone, Copy, PartialEq)]
+enum TieSource {
+    /// The AC bus with the given index, connected to its external power receptacle.
+    ExternalPowerBus(usize),
+    /// The APU generator line of the given side.
+    ApuGenerator(usize),
+    /// The AC bus with the given index, connected to its engine generator.
+    EngineGeneratorBus(usize),
+}
+
+/// AC buses 1 and 2 are on the left side, AC buses 3 and 4 on the right side.
+/// Each AC bus is normally supplied by the variable frequency generator of its engine,
+/// or otherwise by its external power receptacle. A bus without a source of its own is
+/// connected through its bus tie contactor to the AC tie of its side, which is fed by
+/// an external power supplied bus, the APU generator line of that side or an engine
+/// generator supplied bus, in that order of priority. When a side has nothing to feed its
+/// tie with, the system isolation contactor connects it to the tie of the other side.
+///
+/// The APU drives two generators, generator 1 supplies the APU generator line of the left
+/// side and generator 2 the one of the right side.
 struct A380MainPowerSources {
-    engine_1_gen: EngineGenerator,
-    engine_2_gen: EngineGenerator,
-    engine_generator_contactors: [Contactor; 2],
-    bus_tie_1_contactor: Contactor,
-    bus_tie_2_contactor: Contactor,
-    apu_gen_contactor: Contactor,
-    ext_pwr_contactor: Contactor,
+    engine_generators: [VariableFrequencyGenerator; 4],
+    engine_generator_contactors: [Contactor; 4],
+    ext_pwr_contactors: [Contactor; 4],
+    apu_gen_contactors: [Contactor; 2],
+    bus_tie_contactors: [Contactor; 4],
+    system_isolation_contactor: Contactor,
 }
 impl A380MainPowerSources {
+    const LEFT: usize = 0;
+    const RIGHT: usize = 1;
+
     fn new(context: &mut InitContext) -> Self {
         A380MainPowerSources {
-            engine_1_gen: EngineGenerator::new(context, 1),
-            engine_2_gen: EngineGenerator::new(context, 2),
-            engine_generator_contactors: [
-                Contactor::new(context, "9XU1"),
-                Contactor::new(context, "9XU2"),
-            ],
-            bus_tie_1_contactor: Contactor::new(context, "11XU1"),
-            bus_tie_2_contactor: Contactor::new(context, "11XU2"),
-            apu_gen_contactor: Contactor::new(context, "3XS"),
-            ext_pwr_contactor: Contactor::new(context, "3XG"),
+            engine_generators: [1, 2, 3, 4]
+                .map(|number| VariableFrequencyGenerator::new(context, number)),
+            engine_generator_contactors: [1, 2, 3, 4]
+                .map(|number| Contactor::new(context, &format!("9XU{}", number))),
+            ext_pwr_contactors: [1, 2, 3, 4]
+                .map(|number| Contactor::new(context, &format!("3XG{}", number))),
+            apu_gen_contactors: [1, 2]
+                .map(|number| Contactor::new(context, &format!("3XS{}", number))),
+            bus_tie_contactors: [1, 2, 3, 4]
+                .map(|number| Contactor::new(context, &format!("11XU{}", number))),
+            system_isolation_contactor: Contactor::new(context, "1XU"),
         }
     }
 
+    fn side_of(bus_index: usize) -> usize {
+        bus_index / 2
+    }
+
+    fn buses_of(side: usize) -> [usize; 2] {
+        [side * 2, side * 2 + 1]
+    }
+
     fn update(
         &mut self,
         context: &UpdateContext,
         electricity: &mut Electricity,
-        ext_pwr: &ExternalPowerSource,
+        ext_pwrs: &[ExternalPowerSource; 4],
         overhead: &A380ElectricalOverheadPanel,
         emergency_overhead: &A380EmergencyElectricalOverheadPanel,
-        apu: &impl AuxiliaryPowerUnitElectrical,
+        apu: &(impl AuxiliaryPowerUnitElectrical + AuxiliaryPowerUnitGenerators),
         engine_fire_push_buttons: &impl EngineFirePushButtons,
-        engines: [&impl EngineCorrectedN2; 2],
+        engines: [&impl EngineCorrectedN2; 4],
     ) {
-        self.engine_1_gen
-            .update(context, engines[0], overhead, engine_fire_push_buttons);
-        electricity.supplied_by(&self.engine_1_gen);
-
-        self.engine_2_gen
-            .update(context, engines[1], overhead, engine_fire_push_buttons);
-        electricity.supplied_by(&self.engine_2_gen);
-
-        electricity.supplied_by(apu);
-        electricity.supplied_by(ext_pwr);
-
-        let gen_1_provides_power = overhead.generator_is_on(1)
-            && emergency_overhead.generator_1_line_is_on()
-            && !engine_fire_push_buttons.is_released(1)
-            && self.engine_1_gen.output_within_normal_parameters();
-        let gen_2_provides_power = overhead.generator_is_on(2)
-            && !engine_fire_push_buttons.is_released(2)
-            && self.engine_2_gen.output_within_normal_parameters();
-        let only_one_engine_gen_is_powered = gen_1_provides_power ^ gen_2_provides_power;
-        let both_engine_gens_provide_power = gen_1_provides_power && gen_2_provides_power;
-        let ext_pwr_provides_power = overhead.external_power_is_on()
-            && ext_pwr.output_within_normal_parameters()
-            && !both_engine_gens_provide_power;
-        let apu_gen_provides_power = overhead.apu_generator_is_on()
-            && apu.output_within_normal_parameters()
-            && !ext_pwr_provides_power
-            && !both_engine_gens_provide_power;
-
-        self.engine_generator_contactors[0].close_when(gen_1_provides_power);
-        self.engine_generator_contactors[1].close_when(gen_2_provides_power);
-        self.apu_gen_contactor.close_when(apu_gen_provides_power);
-        self.ext_pwr_contactor.close_when(ext_pwr_provides_power);
-
-        let apu_or_ext_pwr_provides_power = ext_pwr_provides_power || apu_gen_provides_power;
-        self.bus_tie_1_contactor.close_when(
-            overhead.bus_tie_is_auto()
-                && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
-                    || (apu_or_ext_pwr_provides_power && !gen_1_provides_power)),
-        );
-        self.bus_tie_2_contactor.close_when(
-            overhead.bus_tie_is_auto()
-                && ((only_one_engine_gen_is_powered && !apu_or_ext_pwr_provides_power)
-                    || (apu_or_ext_pwr_provides_power && !gen_2_provides_power)),
-        );
+        for (generator, engine) in self.engine_generators.iter_mut().zip(engines) {
+            generator.update(context, engine, overhead, engine_fire_push_buttons);
+            electricity.supplied_by(generator);
+        }
+
+        electricity.supplied_by(apu.generator(1));
+        electricity.supplied_by(apu.generator(2));
+        ext_pwrs
+            .iter()
+            .for_each(|ext_pwr| electricity.supplied_by(ext_pwr));
+
+        let gen_provides_power: [bool; 4] = std::array::from_fn(|index| {
+            overhead.generator_is_on(index + 1)
+                && (index != 0 || emergency_overhead.generator_1_line_is_on())
+                && !engine_fire_push_buttons.is_released(index + 1)
+                && self.engine_generators[index].output_within_normal_parameters()
+        });
+        let ext_pwr_provides_power: [bool; 4] = std::array::from_fn(|index| {
+            overhead.external_power_is_on(index + 1)
+                && ext_pwrs[index].output_within_normal_parameters()
+                && !gen_provides_power[index]
+        });
+        let has_own_source: [bool; 4] =
+            std::array::from_fn(|index| gen_provides_power[index] || ext_pwr_provides_power[index]);
+        let apu_gen_available: [bool; 2] = std::array::from_fn(|side| {
+            overhead.apu_generator_is_on(side + 1) && apu.output_within_normal_parameters()
+        });
+
+        let bus_tie_is_auto = overhead.bus_tie_is_auto();
+        let side_needs_tie: [bool; 2] = std::array::from_fn(|side| {
+            bus_tie_is_auto
+                && Self::buses_of(side)
+                    .iter()
+                    .any(|&index| !has_own_source[index])
+        });
+        let own_tie_source: [Option<TieSource>; 2] = std::array::from_fn(|side| {
+            let buses = Self::buses_of(side);
+
+            buses
+                .iter()
+                .find(|&&index| ext_pwr_provides_power[index])
+                .map(|&index| TieSource::ExternalPowerBus(index))
+                .or_else(|| apu_gen_available[side].then_some(TieSource::ApuGenerator(side)))
+                .or_else(|| {
+                    buses
+                        .iter()
+                        .find(|&&index| gen_provides_power[index])
+                        .map(|&index| TieSource::EngineGeneratorBus(index))
+                })
+        });
+
+        let side_fed_by_other_side = |side: usize, other: usize| {
+            side_needs_tie[side]
+                && own_tie_source[side].is_none()
+                && own_tie_source[other].is_some()
+        };
+        let left_fed_by_right = side_fed_by_other_side(Self::LEFT, Self::RIGHT);
+        let right_fed_by_left = side_fed_by_other_side(Self::RIGHT, Self::LEFT);
+        let system_isolation_closed = bus_tie_is_auto && (left_fed_by_right || right_fed_by_left);
+
+        let tie_in_use: [bool; 2] = [
+            side_needs_tie[Self::LEFT] || right_fed_by_left,
+            side_needs_tie[Self::RIGHT] || left_fed_by_right,
+        ];
+        let tie_is_fed: [bool; 2] = [
+            own_tie_source[Self::LEFT].is_some() || left_fed_by_right,
+            own_tie_source[Self::RIGHT].is_some() || right_fed_by_left,
+        ];
+
+        for (index, contactor) in self.engine_generator_contactors.iter_mut().enumerate() {
+            contactor.close_when(gen_provides_power[index]);
+        }
+        for (index, contactor) in self.ext_pwr_contactors.iter_mut().enumerate() {
+            contactor.close_when(ext_pwr_provides_power[index]);
+        }
+        for (side, contactor) in self.apu_gen_contactors.iter_mut().enumerate() {
+            contactor.close_when(
+                tie_in_use[side] && own_tie_source[side] == Some(TieSource::ApuGenerator(side)),
+            );
+        }
+        for (index, contactor) in self.bus_tie_contactors.iter_mut().enumerate() {
+            let side = Self::side_of(index);
+            let feeds_tie = matches!(
+                own_tie_source[side],
+                Some(TieSource::ExternalPowerBus(source) | TieSource::EngineGeneratorBus(source))
+                    if source == index
+            );
 
-        electricity.flow(apu, &self.apu_gen_contactor);
-        electricity.flow(ext_pwr, &self.ext_pwr_contactor);
+            contactor.close_when(
+                bus_tie_is_auto
+                    && tie_in_use[side]
+                    && if has_own_source[index] {
+                        feeds_tie
+                    } else {
+                        tie_is_fed[side]
+                    },
+            );
+        }
+        self.system_isolation_contactor
+            .close_when(system_isolation_closed);
 
-        electricity.flow(&self.engine_1_gen, &self.engine_generator_contactors[0]);
-        electricity.flow(
-            &self.engine_generator_contactors[0],
-            &self.bus_tie_1_contactor,
-        );
-        electricity.flow(&self.apu_gen_contactor, &self.bus_tie_1_contactor);
-        electricity.flow(&self.ext_pwr_contactor, &self.bus_tie_1_contactor);
+        for (index, ext_pwr) in ext_pwrs.iter().enumerate() {
+            electricity.flow(
+                &self.engine_generators[index],
+                &self.engine_generator_contactors[index],
+            );
+            electricity.flow(ext_pwr, &self.ext_pwr_contactors[index]);
 
-        electricity.flow(&self.engine_2_gen, &self.engine_generator_contactors[1]);
-        electricity.flow(
-            &self.engine_generator_contactors[1],
-            &self.bus_tie_2_contactor,
-        );
-        electricity.flow(&self.apu_gen_contactor, &self.bus_tie_2_contactor);
-        electricity.flow(&self.ext_pwr_contactor, &self.bus_tie_2_contactor);
+            electricity.flow(
+                &self.engine_generator_contactors[index],
+                &self.bus_tie_contactors[index],
+            );
+            electricity.flow(
+                &self.ext_pwr_contactors[index],
+                &self.bus_tie_contactors[index],
+            );
+        }
 
-        electricity.flow(&self.bus_tie_1_contactor, &self.bus_tie_2_contactor);
-    }
+        for side in [Self::LEFT, Self::RIGHT] {
+            let [first, second] = Self::buses_of(side);
 
-    fn power_ac_bus_1(&self, electricity: &mut Electricity, bus: &ElectricalBus) {
-        electricity.flow(&self.engine_generator_contactors[0], bus);
-        electricity.flow(&self.bus_tie_1_contactor, bus);
+            electricity.flow(apu.generator(side + 1), &self.apu_gen_contactors[side]);
+            electricity.flow(
+                &self.apu_gen_contactors[side],
+                &self.bus_tie_contactors[first],
+            );
+            electricity.flow(
+                &self.apu_gen_contactors[side],
+                &self.bus_tie_contactors[second],
+            );
+            electricity.flow(
+                &self.bus_tie_contactors[first],
+                &self.bus_tie_contactors[second],
+            );
+            electricity.flow(
+                &self.apu_gen_contactors[side],
+                &self.system_isolation_contactor,
+            );
+            electricity.flow(
+                &self.bus_tie_contactors[first],
+                &self.system_isolation_contactor,
+            );
+            electricity.flow(
+                &self.bus_tie_contactors[second],
+                &self.system_isolation_contactor,
+            );
+        }
     }
 
-    fn power_ac_bus_2(&self, electricity: &mut Electricity, bus: &ElectricalBus) {
-        electricity.flow(&self.engine_generator_contactors[1], bus);
-        electricity.flow(&self.bus_tie_2_contactor, bus);
+    fn power_ac_buses(&self, electricity: &mut Electricity, buses: &[ElectricalBus; 4]) {
+        for (index, bus) in buses.iter().enumerate() {
+            electricity.flow(&self.engine_generator_contactors[index], bus);
+            electricity.flow(&self.ext_pwr_contactors[index], bus);
+            electricity.flow(&self.bus_tie_contactors[index], bus);
+        }
     }
 
     pub fn gen_contactor_open(&self, number: usize) -> bool {
@@ -455,17 +578,12 @@ impl A380MainPowerSources {
 }
 impl SimulationElement for A380MainPowerSources {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-        self.engine_1_gen.accept(visitor);
-        self.engine_2_gen.accept(visitor);
-        self.engine_generator_contactors
-            .iter_mut()
-            .for_each(|contactor| {
-                contactor.accept(visitor);
-            });
-        self.bus_tie_1_contactor.accept(visitor);
-        self.bus_tie_2_contactor.accept(visitor);
-        self.apu_gen_contactor.accept(visitor);
-        self.ext_pwr_contactor.accept(visitor);
+        accept_iterable!(self.engine_generators, visitor);
+        accept_iterable!(self.engine_generator_contactors, visitor);
+        accept_iterable!(self.ext_pwr_contactors, visitor);
+        accept_iterable!(self.apu_gen_contactors, visitor);
+        accept_iterable!(self.bus_tie_contactors, visitor);
+        self.system_isolation_contactor.accept(visitor);
 
         visitor.visit(self);
     }
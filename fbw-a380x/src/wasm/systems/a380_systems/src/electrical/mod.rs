@@ -29,9 +29,9 @@ use systems::{
     },
     shared::{
         update_iterator::MaxStepLoop, ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical,
-        ElectricalBusType, ElectricalBuses, EmergencyElectricalRatPushButton,
-        EmergencyElectricalState, EngineCorrectedN2, EngineFirePushButtons, LgciuWeightOnWheels,
-        RamAirTurbineController,
+        AuxiliaryPowerUnitGenerators, CabinSimulation, ElectricalBusType, ElectricalBuses,
+        EmergencyElectricalRatPushButton, EmergencyElectricalState, EngineCorrectedN2,
+        EngineFirePushButtons, LgciuWeightOnWheels, RamAirTurbineController,
     },
     simulation::{
         InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
@@ -95,19 +95,20 @@ impl A380Electrical {
         &mut self,
         context: &UpdateContext,
         electricity: &mut Electricity,
-        ext_pwr: &ExternalPowerSource,
+        ext_pwrs: &[ExternalPowerSource; 4],
         overhead: &A380ElectricalOverheadPanel,
         emergency_overhead: &A380EmergencyElectricalOverheadPanel,
-        apu: &mut impl AuxiliaryPowerUnitElectrical,
+        apu: &mut (impl AuxiliaryPowerUnitElectrical + AuxiliaryPowerUnitGenerators),
         apu_overhead: &(impl ApuMaster + ApuStart),
         engine_fire_push_buttons: &impl EngineFirePushButtons,
-        engines: [&impl EngineCorrectedN2; 2],
+        engines: [&impl EngineCorrectedN2; 4],
         lgciu1: &impl LgciuWeightOnWheels,
+        cabin_simulation: &impl CabinSimulation,
     ) {
         self.alternating_current.update_main_power_sources(
             context,
             electricity,
-            ext_pwr,
+            ext_pwrs,
             overhead,
             emergency_overhead,
             apu,
@@ -142,11 +143,14 @@ impl A380Electrical {
         self.alternating_current.update(
             context,
             electricity,
-            ext_pwr,
+            ext_pwrs,
             overhead,
             &self.emergency_gen,
         );
 
+        // The batteries are installed in the avionics compartment, which is ventilated with cockpit air
+        let avionics_bay_temperature = cabin_simulation.cabin_temperature()[0];
+
         // Elec using LGCIU1 L&R compressed (14A output  ASM 32_62_00)
         self.direct_current.update(
             context,
@@ -158,6 +162,7 @@ impl A380Electrical {
             apu,
             apu_overhead,
             lgciu1,
+            avionics_bay_temperature,
         );
 
         self.alternating_current.update_after_direct_current(
@@ -235,6 +240,10 @@ impl A380Electrical {
     pub fn in_emergency_elec(&self) -> bool {
         self.emergency_elec.is_in_emergency_elec()
     }
+
+    fn battery_has_fault(&self, number: usize) -> bool {
+        self.direct_current.battery_has_fault(number)
+    }
 }
 impl SimulationElement for A380Electrical {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -266,13 +275,13 @@ trait A380AlternatingCurrentElectricalSystem: AlternatingCurrentElectricalSystem
 
 pub(super) struct A380ElectricalOverheadPanel {
     batteries: [AutoOffFaultPushButton; 2],
-    idgs: [FaultReleasePushButton; 2],
-    generators: [OnOffFaultPushButton; 2],
-    apu_gen: OnOffFaultPushButton,
+    idgs: [FaultReleasePushButton; 4],
+    generators: [OnOffFaultPushButton; 4],
+    apu_gens: [OnOffFaultPushButton; 2],
     bus_tie: AutoOffFaultPushButton,
     ac_ess_feed: NormalAltnFaultPushButton,
     galy_and_cab: AutoOffFaultPushButton,
-    ext_pwr: OnOffAvailablePushButton,
+    ext_pwrs: [OnOffAvailablePushButton; 4],
     commercial: OnOffFaultPushButton,
 }
 impl A380ElectricalOverheadPanel {
@@ -282,19 +291,21 @@ impl A380ElectricalOverheadPanel {
                 AutoOffFaultPushButton::new_auto(context, "ELEC_BAT_1"),
                 AutoOffFaultPushButton::new_auto(context, "ELEC_BAT_2"),
             ],
-            idgs: [
-                FaultReleasePushButton::new_in(context, "ELEC_IDG_1"),
-                FaultReleasePushButton::new_in(context, "ELEC_IDG_2"),
-            ],
-            generators: [
-                OnOffFaultPushButton::new_on(context, "ELEC_ENG_GEN_1"),
-                OnOffFaultPushButton::new_on(context, "ELEC_ENG_GEN_2"),
-            ],
-            apu_gen: OnOffFaultPushButton::new_on(context, "ELEC_APU_GEN"),
+            idgs: [1, 2, 3, 4].map(|number| {
+                FaultReleasePushButton::new_in(context, &format!("ELEC_IDG_{}", number))
+            }),
+            generators: [1, 2, 3, 4].map(|number| {
+                OnOffFaultPushButton::new_on(context, &format!("ELEC_ENG_GEN_{}", number))
+            }),
+            apu_gens: [1, 2].map(|number| {
+                OnOffFaultPushButton::new_on(context, &format!("ELEC_APU_GEN_{}", number))
+            }),
             bus_tie: AutoOffFaultPushButton::new_auto(context, "ELEC_BUS_TIE"),
             ac_ess_feed: NormalAltnFaultPushButton::new_normal(context, "ELEC_AC_ESS_FEED"),
             galy_and_cab: AutoOffFaultPushButton::new_auto(context, "ELEC_GALY_AND_CAB"),
-            ext_pwr: OnOffAvailablePushButton::new_off(context, "ELEC_EXT_PWR"),
+            ext_pwrs: [1, 2, 3, 4].map(|number| {
+                OnOffAvailablePushButton::new_off(context, &format!("ELEC_EXT_PWR_{}", number))
+            }),
             commercial: OnOffFaultPushButton::new_on(context, "ELEC_COMMERCIAL"),
         }
     }
@@ -313,22 +324,29 @@ impl A380ElectricalOverheadPanel {
             .for_each(|(index, gen)| {
                 gen.set_fault(electrical.gen_contactor_open(index + 1) && gen.is_on());
             });
+
+        self.batteries
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, bat)| {
+                bat.set_fault(electrical.battery_has_fault(index + 1));
+            });
     }
 
     fn generator_is_on(&self, number: usize) -> bool {
         self.generators[number - 1].is_on()
     }
 
-    pub fn external_power_is_available(&self) -> bool {
-        self.ext_pwr.is_available()
+    pub fn external_power_is_available(&self, number: usize) -> bool {
+        self.ext_pwrs[number - 1].is_available()
     }
 
-    pub fn external_power_is_on(&self) -> bool {
-        self.ext_pwr.is_on()
+    pub fn external_power_is_on(&self, number: usize) -> bool {
+        self.ext_pwrs[number - 1].is_on()
     }
 
-    pub fn apu_generator_is_on(&self) -> bool {
-        self.apu_gen.is_on()
+    pub fn apu_generator_is_on(&self, number: usize) -> bool {
+        self.apu_gens[number - 1].is_on()
     }
 
     fn bus_tie_is_auto(&self) -> bool {
@@ -370,12 +388,12 @@ impl SimulationElement for A380ElectricalOverheadPanel {
         accept_iterable!(self.batteries, visitor);
         accept_iterable!(self.idgs, visitor);
         accept_iterable!(self.generators, visitor);
+        accept_iterable!(self.apu_gens, visitor);
 
-        self.apu_gen.accept(visitor);
         self.bus_tie.accept(visitor);
         self.ac_ess_feed.accept(visitor);
         self.galy_and_cab.accept(visitor);
-        self.ext_pwr.accept(visitor);
+        accept_iterable!(self.ext_pwrs, visitor);
         self.commercial.accept(visitor);
 
         visitor.visit(self);
@@ -527,8 +545,8 @@ mod a380_electrical_circuit_tests {
     };
 
     use uom::si::{
-        angular_velocity::revolution_per_minute, electric_potential::volt, length::foot,
-        mass_density::slug_per_cubic_foot, ratio::percent,
+        angular_velocity::revolution_per_minute, electric_potential::volt, frequency::hertz,
+        length::foot, mass_density::slug_per_cubic_foot, ratio::percent,
         thermodynamic_temperature::degree_celsius, velocity::knot,
     };
 
@@ -835,23 +853,27 @@ mod a380_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_bus_output(1)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_bus_output(2)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_ess_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_ess_shed_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.static_inverter_input().is_unpowered());
         assert!(test_bed.ac_stat_inv_bus_output().is_unpowered());
         assert!(test_bed
             .ac_gnd_flt_service_bus_output()
-            .is_single(PotentialOrigin::External));
-        assert!(test_bed.tr_1_input().is_single(PotentialOrigin::External));
-        assert!(test_bed.tr_2_input().is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
+        assert!(test_bed
+            .tr_1_input()
+            .is_single(PotentialOrigin::External(1)));
+        assert!(test_bed
+            .tr_2_input()
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.tr_ess_input().is_unpowered());
         assert!(test_bed
             .dc_bus_output(1)
@@ -1279,9 +1301,11 @@ mod a380_electrical_circuit_tests {
             .is_single(PotentialOrigin::StaticInverter));
         assert!(test_bed
             .ac_gnd_flt_service_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.tr_1_input().is_unpowered());
-        assert!(test_bed.tr_2_input().is_single(PotentialOrigin::External));
+        assert!(test_bed
+            .tr_2_input()
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.tr_ess_input().is_unpowered());
         assert!(test_bed.dc_bus_output(1).is_unpowered());
         assert!(test_bed.dc_bus_output(2).is_unpowered());
@@ -1342,14 +1366,193 @@ mod a380_electrical_circuit_tests {
     ) {
         let test_bed = test_bed_with()
             .running_engine(engine)
+            .connected_external_power_unit(ac_bus as usize)
+            .and()
+            .ext_pwr_unit_on(ac_bus as usize)
+            .run();
+
+        assert!(test_bed
+            .ac_bus_output(ac_bus)
+            .is_single(PotentialOrigin::External(ac_bus as usize)));
+    }
+
+    #[test]
+    fn engine_generator_has_priority_over_external_power_of_its_ac_bus() {
+        let mut test_bed = test_bed_with()
+            .running_engine(1)
             .connected_external_power()
             .and()
             .ext_pwr_on()
             .run();
 
         assert!(test_bed
-            .ac_bus_output(ac_bus)
-            .is_single(PotentialOrigin::External));
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::EngineGenerator(1)));
+        assert!(!test_bed.contactor_is_closed("3XG1"));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    #[case(4)]
+    fn when_all_engines_running_each_generator_powers_its_own_ac_bus(#[case] number: usize) {
+        let mut test_bed = test_bed_with().running_engines().run();
+
+        assert!(test_bed
+            .ac_bus_output(number as u8)
+            .is_single(PotentialOrigin::EngineGenerator(number)));
+        assert!(!test_bed.contactor_is_closed(&format!("11XU{}", number)));
+        assert!(!test_bed.contactor_is_closed("1XU"));
+    }
+
+    #[rstest]
+    #[case(1, 2)]
+    #[case(2, 1)]
+    #[case(3, 4)]
+    #[case(4, 3)]
+    fn when_generator_lost_its_ac_bus_is_powered_by_the_other_generator_of_its_side(
+        #[case] lost_engine: usize,
+        #[case] other_engine: usize,
+    ) {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .gen_off(lost_engine)
+            .run();
+
+        assert!(test_bed
+            .ac_bus_output(lost_engine as u8)
+            .is_single(PotentialOrigin::EngineGenerator(other_engine)));
+        assert!(test_bed.contactor_is_closed(&format!("11XU{}", lost_engine)));
+        assert!(test_bed.contactor_is_closed(&format!("11XU{}", other_engine)));
+        assert!(!test_bed.contactor_is_closed("1XU"));
+    }
+
+    #[test]
+    fn when_both_generators_of_a_side_lost_the_other_side_powers_its_ac_buses() {
+        let mut test_bed = test_bed_with()
+            .running_engine(3)
+            .and()
+            .running_engine(4)
+            .run();
+
+        assert!(test_bed.contactor_is_closed("1XU"));
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::EngineGenerator(3)));
+        assert!(test_bed
+            .ac_bus_output(2)
+            .is_single(PotentialOrigin::EngineGenerator(3)));
+        assert!(test_bed
+            .ac_bus_output(4)
+            .is_single(PotentialOrigin::EngineGenerator(4)));
+    }
+
+    #[test]
+    fn when_bus_tie_off_and_generators_lost_on_one_side_its_ac_buses_are_unpowered() {
+        let test_bed = test_bed_with()
+            .running_engine(3)
+            .and()
+            .running_engine(4)
+            .bus_tie_off()
+            .run();
+
+        assert!(test_bed.ac_bus_output(1).is_unpowered());
+        assert!(test_bed.ac_bus_output(2).is_unpowered());
+        assert!(test_bed
+            .ac_bus_output(3)
+            .is_single(PotentialOrigin::EngineGenerator(3)));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    #[case(4)]
+    fn each_external_power_unit_powers_its_own_ac_bus(#[case] number: usize) {
+        let mut test_bed = test_bed_with()
+            .connected_external_power_unit(1)
+            .connected_external_power_unit(2)
+            .connected_external_power_unit(3)
+            .connected_external_power_unit(4)
+            .ext_pwr_unit_on(1)
+            .ext_pwr_unit_on(2)
+            .ext_pwr_unit_on(3)
+            .and()
+            .ext_pwr_unit_on(4)
+            .run();
+
+        assert!(test_bed
+            .ac_bus_output(number as u8)
+            .is_single(PotentialOrigin::External(number)));
+        assert!(test_bed.contactor_is_closed(&format!("3XG{}", number)));
+        assert!(!test_bed.contactor_is_closed(&format!("11XU{}", number)));
+    }
+
+    #[test]
+    fn when_only_apu_running_each_apu_generator_line_powers_its_side() {
+        let mut test_bed = test_bed_with().running_apu().run();
+
+        assert!(test_bed.contactor_is_closed("3XS1"));
+        assert!(test_bed.contactor_is_closed("3XS2"));
+        assert!(!test_bed.contactor_is_closed("1XU"));
+        for number in 1..=4 {
+            let apu_generator = if number <= 2 { 1 } else { 2 };
+            assert!(test_bed
+                .ac_bus_output(number)
+                .is_single(PotentialOrigin::ApuGenerator(apu_generator)));
+        }
+    }
+
+    #[test]
+    fn when_only_apu_running_and_one_apu_generator_line_off_the_other_powers_all_ac_buses() {
+        let mut test_bed = test_bed_with()
+            .running_apu()
+            .and()
+            .apu_gen_line_off(2)
+            .run();
+
+        assert!(test_bed.contactor_is_closed("3XS1"));
+        assert!(!test_bed.contactor_is_closed("3XS2"));
+        assert!(test_bed.contactor_is_closed("1XU"));
+        assert!(test_bed
+            .ac_bus_output(4)
+            .is_single(PotentialOrigin::ApuGenerator(1)));
+    }
+
+    #[test]
+    fn when_single_external_power_and_apu_running_apu_powers_the_other_side() {
+        let mut test_bed = test_bed_with()
+            .connected_external_power()
+            .ext_pwr_on()
+            .and()
+            .running_apu()
+            .run();
+
+        assert!(!test_bed.contactor_is_closed("3XS1"));
+        assert!(test_bed.contactor_is_closed("3XS2"));
+        assert!(test_bed
+            .ac_bus_output(3)
+            .is_single(PotentialOrigin::ApuGenerator(2)));
+        assert!(test_bed
+            .ac_bus_output(4)
+            .is_single(PotentialOrigin::ApuGenerator(2)));
+    }
+
+    #[test]
+    fn engine_generators_output_frequency_wild_power() {
+        let mut test_bed = test_bed_with().running_engine(1).run();
+
+        let frequency: Frequency = test_bed.read_by_name("ELEC_ENG_GEN_1_FREQUENCY");
+        assert!(frequency > Frequency::new::<hertz>(410.));
+        assert!(ReadByName::<A380ElectricalTestBed, bool>::read_by_name(
+            &mut test_bed,
+            "ELEC_ENG_GEN_1_FREQUENCY_NORMAL"
+        ));
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::EngineGenerator(1)));
     }
 
     #[test]
@@ -1362,10 +1565,35 @@ mod a380_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_bus_output(1)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_bus_output(2)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    #[case(4)]
+    fn abnormal_external_power_unit_is_not_connected_to_its_ac_bus(#[case] number: usize) {
+        let mut test_bed = test_bed_with()
+            .failed_external_power(FailureType::ExternalPowerWrongPhaseRotation(number))
+            .connected_external_power_unit(1)
+            .connected_external_power_unit(2)
+            .connected_external_power_unit(3)
+            .connected_external_power_unit(4)
+            .ext_pwr_unit_on(1)
+            .ext_pwr_unit_on(2)
+            .ext_pwr_unit_on(3)
+            .and()
+            .ext_pwr_unit_on(4)
+            .run();
+
+        for other in (1..=4).filter(|other| *other != number) {
+            assert!(test_bed.contactor_is_closed(&format!("3XG{}", other)));
+        }
+        assert!(!test_bed.contactor_is_closed(&format!("3XG{}", number)));
     }
 
     #[test]
@@ -1379,10 +1607,10 @@ mod a380_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_bus_output(1)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .ac_bus_output(2)
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
     }
 
     #[test]
@@ -1746,15 +1974,20 @@ mod a380_electrical_circuit_tests {
     }
 
     #[test]
-    fn when_bus_tie_off_external_power_does_not_power_ac_buses() {
+    fn when_bus_tie_off_external_power_only_powers_its_own_ac_bus() {
         let test_bed = test_bed_with()
             .connected_external_power()
+            .ext_pwr_on()
             .and()
             .bus_tie_off()
             .run();
 
-        assert!(test_bed.ac_bus_output(1).is_unpowered());
+        assert!(test_bed
+            .ac_bus_output(1)
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed.ac_bus_output(2).is_unpowered());
+        assert!(test_bed.ac_bus_output(3).is_unpowered());
+        assert!(test_bed.ac_bus_output(4).is_unpowered());
     }
 
     #[test]
@@ -1878,6 +2111,26 @@ mod a380_electrical_circuit_tests {
     #[ignore = "Generator overloading is not yet supported."]
     fn when_aircraft_on_the_ground_and_apu_gen_is_overloaded_galley_is_shed() {}
 
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    fn when_battery_in_thermal_runaway_bat_push_button_has_fault_and_contactor_opens(
+        #[case] bat_number: usize,
+    ) {
+        let mut test_bed = test_bed_with()
+            .running_engines()
+            .and()
+            .failed_battery_thermal_runaway(bat_number);
+
+        for _ in 0..20 {
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(60));
+        }
+
+        assert!(test_bed.bat_has_fault(bat_number));
+        assert!(!test_bed.battery_contactor_is_closed(bat_number));
+        assert!(!test_bed.bat_has_fault(3 - bat_number));
+    }
+
     #[rstest]
     #[case(1)]
     #[case(2)]
@@ -1996,7 +2249,7 @@ mod a380_electrical_circuit_tests {
 
         assert!(test_bed
             .ac_gnd_flt_service_bus_output()
-            .is_single(PotentialOrigin::External));
+            .is_single(PotentialOrigin::External(1)));
         assert!(test_bed
             .dc_gnd_flt_service_bus_output()
             .is_single(PotentialOrigin::TransformerRectifier(2)));
@@ -2026,39 +2279,32 @@ mod a380_electrical_circuit_tests {
     }
 
     #[test]
-    fn when_emergency_generator_not_supplying_while_ac_1_and_2_unavailable_in_flight_rat_and_emer_gen_has_fault(
+    fn when_emergency_generator_not_supplying_while_main_ac_buses_unavailable_in_flight_rat_and_emer_gen_has_fault(
     ) {
-        let mut test_bed = test_bed_with()
-            .running_engines()
-            .gen_off(1)
-            .and()
-            .gen_off(2)
-            .run();
+        let mut test_bed = test_bed_with().running_engines().and().all_gens_off().run();
 
         assert!(test_bed.rat_and_emer_gen_has_fault());
     }
 
     #[test]
-    fn when_emergency_generator_not_supplying_while_ac_1_and_2_unavailable_during_takeoff_rat_and_emer_gen_does_not_have_fault(
+    fn when_emergency_generator_not_supplying_while_main_ac_buses_unavailable_during_takeoff_rat_and_emer_gen_does_not_have_fault(
     ) {
         let mut test_bed = test_bed_with()
             .running_engines()
             .on_the_ground()
-            .gen_off(1)
             .and()
-            .gen_off(2)
+            .all_gens_off()
             .run();
 
         assert!(!test_bed.rat_and_emer_gen_has_fault());
     }
 
     #[test]
-    fn when_emergency_generator_not_supplying_while_ac_1_and_2_unavailable_during_low_speed_flight_rat_and_emer_gen_does_not_have_fault(
+    fn when_emergency_generator_not_supplying_while_main_ac_buses_unavailable_during_low_speed_flight_rat_and_emer_gen_does_not_have_fault(
     ) {
         let mut test_bed = test_bed_with()
             .running_engines()
-            .gen_off(1)
-            .gen_off(2)
+            .all_gens_off()
             .and()
             .airspeed(Velocity::new::<knot>(99.))
             .run();
@@ -2067,7 +2313,7 @@ mod a380_electrical_circuit_tests {
     }
 
     #[test]
-    fn when_rat_and_emer_gen_man_on_push_button_is_pressed_at_an_earlier_time_in_case_of_ac_1_and_2_unavailable_emergency_generator_provides_power_immediately(
+    fn when_rat_and_emer_gen_man_on_push_button_is_pressed_at_an_earlier_time_in_case_of_main_ac_buses_unavailable_emergency_generator_provides_power_immediately(
     ) {
         let test_bed = test_bed_with()
             .running_engines()
@@ -2077,9 +2323,8 @@ mod a380_electrical_circuit_tests {
             .rat_and_emer_gen_man_on_pressed()
             .run_waiting_for(Duration::from_secs(100))
             .then_continue_with()
-            .gen_off(1)
             .and()
-            .gen_off(2)
+            .all_gens_off()
             .run();
 
         assert!(test_bed
@@ -2088,12 +2333,11 @@ mod a380_electrical_circuit_tests {
     }
 
     #[test]
-    fn when_rat_and_emer_gen_man_on_push_button_is_pressed_in_case_of_ac_1_and_2_unavailable_emergency_generator_does_not_provide_power_immediately(
+    fn when_rat_and_emer_gen_man_on_push_button_is_pressed_in_case_of_main_ac_buses_unavailable_emergency_generator_does_not_provide_power_immediately(
     ) {
         let test_bed = test_bed_with()
             .running_engines()
-            .gen_off(1)
-            .gen_off(2)
+            .all_gens_off()
             .and()
             .rat_and_emer_gen_man_on_pressed()
             .run_waiting_for(Duration::from_secs(0));
@@ -2142,8 +2386,49 @@ mod a380_electrical_circuit_tests {
         A380ElectricalTestBed::new()
     }
 
+    struct TestApuGenerator {
+        number: usize,
+        identifier: ElectricalElementIdentifier,
+        is_available: bool,
+    }
+    impl TestApuGenerator {
+        fn new(context: &mut InitContext, number: usize) -> Self {
+            Self {
+                number,
+                identifier: context.next_electrical_identifier(),
+                is_available: false,
+            }
+        }
+    }
+    impl ElectricitySource for TestApuGenerator {
+        fn output_potential(&self) -> Potential {
+            if self.is_available {
+                Potential::new(
+                    PotentialOrigin::ApuGenerator(self.number),
+                    ElectricPotential::new::<volt>(115.),
+                )
+            } else {
+                Potential::none()
+            }
+        }
+    }
+    impl ElectricalElement for TestApuGenerator {
+        fn input_identifier(&self) -> systems::electrical::ElectricalElementIdentifier {
+            self.identifier
+        }
+
+        fn output_identifier(&self) -> systems::electrical::ElectricalElementIdentifier {
+            self.identifier
+        }
+
+        fn is_conductive(&self) -> bool {
+            true
+        }
+    }
+
     struct TestApu {
         identifier: ElectricalElementIdentifier,
+        generators: [TestApuGenerator; 2],
         is_available: bool,
         start_motor_is_powered: bool,
         should_close_start_contactor: bool,
@@ -2152,6 +2437,7 @@ mod a380_electrical_circuit_tests {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 identifier: context.next_electrical_identifier(),
+                generators: [1, 2].map(|number| TestApuGenerator::new(context, number)),
                 is_available: false,
                 start_motor_is_powered: false,
                 should_close_start_contactor: false,
@@ -2160,6 +2446,9 @@ mod a380_electrical_circuit_tests {
 
         fn set_available(&mut self, available: bool) {
             self.is_available = available;
+            self.generators
+                .iter_mut()
+                .for_each(|generator| generator.is_available = available);
         }
 
         fn command_closing_of_start_contactors(&mut self) {
@@ -2180,6 +2469,13 @@ mod a380_electrical_circuit_tests {
             self.is_available
         }
     }
+    impl AuxiliaryPowerUnitGenerators for TestApu {
+        type Generator = TestApuGenerator;
+
+        fn generator(&self, number: usize) -> &TestApuGenerator {
+            &self.generators[number - 1]
+        }
+    }
     impl ElectricitySource for TestApu {
         fn output_potential(&self) -> Potential {
             if self.is_available {
@@ -2221,12 +2517,12 @@ mod a380_electrical_circuit_tests {
     }
 
     struct TestEngineFirePushButtons {
-        is_released: [bool; 2],
+        is_released: [bool; 4],
     }
     impl TestEngineFirePushButtons {
         fn new() -> Self {
             Self {
-                is_released: [false, false],
+                is_released: [false; 4],
             }
         }
 
@@ -2370,9 +2666,21 @@ mod a380_electrical_circuit_tests {
         }
     }
 
+    struct TestCabin {}
+    impl TestCabin {
+        fn new() -> Self {
+            Self {}
+        }
+    }
+    impl CabinSimulation for TestCabin {
+        fn cabin_temperature(&self) -> Vec<ThermodynamicTemperature> {
+            vec![ThermodynamicTemperature::new::<degree_celsius>(20.); 3]
+        }
+    }
+
     struct A380ElectricalTestAircraft {
-        engines: [TestEngine; 2],
-        ext_pwr: ExternalPowerSource,
+        engines: [TestEngine; 4],
+        ext_pwrs: [ExternalPowerSource; 4],
         elec: A380Electrical,
         overhead: A380ElectricalOverheadPanel,
         emergency_overhead: A380EmergencyElectricalOverheadPanel,
@@ -2385,8 +2693,13 @@ mod a380_electrical_circuit_tests {
     impl A380ElectricalTestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
-                engines: [TestEngine::new(), TestEngine::new()],
-                ext_pwr: ExternalPowerSource::new(context),
+                engines: [
+                    TestEngine::new(),
+                    TestEngine::new(),
+                    TestEngine::new(),
+                    TestEngine::new(),
+                ],
+                ext_pwrs: [1, 2, 3, 4].map(|number| ExternalPowerSource::new(context, number)),
                 elec: A380Electrical::new(context),
                 overhead: A380ElectricalOverheadPanel::new(context),
                 emergency_overhead: A380EmergencyElectricalOverheadPanel::new(context),
@@ -2482,14 +2795,20 @@ mod a380_electrical_circuit_tests {
             self.elec.update(
                 context,
                 electricity,
-                &self.ext_pwr,
+                &self.ext_pwrs,
                 &self.overhead,
                 &self.emergency_overhead,
                 &mut self.apu,
                 &self.apu_overhead,
                 &self.engine_fire_push_buttons,
-                [&self.engines[0], &self.engines[1]],
+                [
+                    &self.engines[0],
+                    &self.engines[1],
+                    &self.engines[2],
+                    &self.engines[3],
+                ],
                 &TestLandingGear::new(),
+                &TestCabin::new(),
             );
             self.overhead
                 .update_after_electrical(&self.elec, electricity);
@@ -2499,7 +2818,7 @@ mod a380_electrical_circuit_tests {
     }
     impl SimulationElement for A380ElectricalTestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
-            self.ext_pwr.accept(visitor);
+            accept_iterable!(self.ext_pwrs, visitor);
             self.elec.accept(visitor);
             self.overhead.accept(visitor);
             self.emergency_overhead.accept(visitor);
@@ -2537,7 +2856,11 @@ mod a380_electrical_circuit_tests {
         }
 
         fn running_engines(self) -> Self {
-            self.running_engine(1).and().running_engine(2)
+            self.running_engine(1)
+                .running_engine(2)
+                .running_engine(3)
+                .and()
+                .running_engine(4)
         }
 
         fn flight_conditions_for_a_spinning_rat(mut self) -> Self {
@@ -2552,8 +2875,12 @@ mod a380_electrical_circuit_tests {
             self
         }
 
-        fn connected_external_power(mut self) -> Self {
-            self.write_by_name("EXTERNAL POWER AVAILABLE:1", true);
+        fn connected_external_power(self) -> Self {
+            self.connected_external_power_unit(1)
+        }
+
+        fn connected_external_power_unit(mut self, number: usize) -> Self {
+            self.write_by_name(&format!("EXTERNAL POWER AVAILABLE:{}", number), true);
 
             self.without_triggering_emergency_elec(|x| x.run())
         }
@@ -2606,6 +2933,17 @@ mod a380_electrical_circuit_tests {
             self
         }
 
+        fn failed_external_power(mut self, failure_type: FailureType) -> Self {
+            self.test_bed.fail(failure_type);
+            self
+        }
+
+        fn failed_battery_thermal_runaway(mut self, number: usize) -> Self {
+            self.test_bed
+                .fail(FailureType::BatteryThermalRunaway(number));
+            self
+        }
+
         fn running_emergency_generator(mut self) -> Self {
             self.command(|a| a.running_emergency_generator());
             self.run_waiting_for(Duration::from_secs(100))
@@ -2616,6 +2954,10 @@ mod a380_electrical_circuit_tests {
             self
         }
 
+        fn all_gens_off(self) -> Self {
+            self.gen_off(1).gen_off(2).gen_off(3).and().gen_off(4)
+        }
+
         fn released_engine_fire_push_button(mut self, engine_number: usize) -> Self {
             self.command(|a| a.release_engine_fire_push_button(engine_number));
             self
@@ -2626,18 +2968,26 @@ mod a380_electrical_circuit_tests {
             self
         }
 
-        fn apu_gen_off(mut self) -> Self {
-            self.write_by_name("OVHD_ELEC_APU_GEN_PB_IS_ON", false);
+        fn apu_gen_off(self) -> Self {
+            self.apu_gen_line_off(1).and().apu_gen_line_off(2)
+        }
+
+        fn apu_gen_line_off(mut self, number: usize) -> Self {
+            self.write_by_name(&format!("OVHD_ELEC_APU_GEN_{}_PB_IS_ON", number), false);
             self
         }
 
-        fn ext_pwr_on(mut self) -> Self {
-            self.write_by_name("OVHD_ELEC_EXT_PWR_PB_IS_ON", true);
+        fn ext_pwr_on(self) -> Self {
+            self.ext_pwr_unit_on(1)
+        }
+
+        fn ext_pwr_unit_on(mut self, number: usize) -> Self {
+            self.write_by_name(&format!("OVHD_ELEC_EXT_PWR_{}_PB_IS_ON", number), true);
             self
         }
 
         fn ext_pwr_off(mut self) -> Self {
-            self.write_by_name("OVHD_ELEC_EXT_PWR_PB_IS_ON", false);
+            self.write_by_name("OVHD_ELEC_EXT_PWR_1_PB_IS_ON", false);
             self
         }
 
@@ -2808,6 +3158,14 @@ mod a380_electrical_circuit_tests {
             self.read_by_name(&format!("OVHD_ELEC_ENG_GEN_{}_PB_HAS_FAULT", number))
         }
 
+        fn bat_has_fault(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("OVHD_ELEC_BAT_{}_PB_HAS_FAULT", number))
+        }
+
+        fn battery_contactor_is_closed(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("ELEC_CONTACTOR_6PB{}_IS_CLOSED", number))
+        }
+
         fn rat_and_emer_gen_has_fault(&mut self) -> bool {
             self.read_by_name("OVHD_EMER_ELEC_RAT_AND_EMER_GEN_HAS_FAULT")
         }
@@ -2826,6 +3184,10 @@ mod a380_electrical_circuit_tests {
             )
         }
 
+        fn contactor_is_closed(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("ELEC_CONTACTOR_{}_IS_CLOSED", name))
+        }
+
         fn dc_bus_2_tie_contactor_is_open(&mut self) -> bool {
             !ReadByName::<A380ElectricalTestBed, bool>::read_by_name(
                 self,
@@ -0,0 +1,240 @@
+use systems::{
+    failures::FailureType,
+    shared::{GearActuatorId, HeatedElement, HydraulicColor, LgciuId, ProximityDetectorId},
+};
+
+/// All failures of the aircraft which can be activated, with the identifier used by
+/// the failure orchestrator to activate and deactivate them.
+pub fn failures() -> Vec<(u64, FailureType)> {
+    vec![
+        (24_000, FailureType::TransformerRectifier(1)),
+        (24_001, FailureType::TransformerRectifier(2)),
+        (24_002, FailureType::TransformerRectifier(3)),
+        (24_003, FailureType::BatteryThermalRunaway(1)),
+        (24_004, FailureType::BatteryThermalRunaway(2)),
+        (24_005, FailureType::ExternalPowerOverVoltage(1)),
+        (24_006, FailureType::ExternalPowerOverVoltage(2)),
+        (24_007, FailureType::ExternalPowerOverVoltage(3)),
+        (24_008, FailureType::ExternalPowerOverVoltage(4)),
+        (24_009, FailureType::ExternalPowerUnderVoltage(1)),
+        (24_010, FailureType::ExternalPowerUnderVoltage(2)),
+        (24_011, FailureType::ExternalPowerUnderVoltage(3)),
+        (24_012, FailureType::ExternalPowerUnderVoltage(4)),
+        (24_013, FailureType::ExternalPowerWrongPhaseRotation(1)),
+        (24_014, FailureType::ExternalPowerWrongPhaseRotation(2)),
+        (24_015, FailureType::ExternalPowerWrongPhaseRotation(3)),
+        (24_016, FailureType::ExternalPowerWrongPhaseRotation(4)),
+        (24_017, FailureType::ExternalPowerFrequencyDeviation(1)),
+        (24_018, FailureType::ExternalPowerFrequencyDeviation(2)),
+        (24_019, FailureType::ExternalPowerFrequencyDeviation(3)),
+        (24_020, FailureType::ExternalPowerFrequencyDeviation(4)),
+        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
+        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
+        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
+        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
+        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
+        (
+            29_005,
+            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_006,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
+        ),
+        (
+            29_007,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
+        ),
+        (
+            29_008,
+            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
+        ),
+        (
+            29_013,
+            FailureType::HydraulicFluidContamination(HydraulicColor::Green),
+        ),
+        (
+            29_014,
+            FailureType::HydraulicFluidContamination(HydraulicColor::Blue),
+        ),
+        (
+            29_015,
+            FailureType::HydraulicFluidContamination(HydraulicColor::Yellow),
+        ),
+        (30_000, FailureType::ProbeHeat(1, HeatedElement::PitotProbe)),
+        (
+            30_001,
+            FailureType::ProbeHeat(1, HeatedElement::StaticPorts),
+        ),
+        (
+            30_002,
+            FailureType::ProbeHeat(1, HeatedElement::AngleOfAttackProbe),
+        ),
+        (
+            30_003,
+            FailureType::ProbeHeat(1, HeatedElement::TotalAirTemperatureProbe),
+        ),
+        (30_004, FailureType::ProbeHeat(1, HeatedElement::Windshield)),
+        (30_005, FailureType::ProbeHeat(1, HeatedElement::SideWindow)),
+        (30_010, FailureType::ProbeHeat(2, HeatedElement::PitotProbe)),
+        (
+            30_011,
+            FailureType::ProbeHeat(2, HeatedElement::StaticPorts),
+        ),
+        (
+            30_012,
+            FailureType::ProbeHeat(2, HeatedElement::AngleOfAttackProbe),
+        ),
+        (
+            30_013,
+            FailureType::ProbeHeat(2, HeatedElement::TotalAirTemperatureProbe),
+        ),
+        (30_014, FailureType::ProbeHeat(2, HeatedElement::Windshield)),
+        (30_015, FailureType::ProbeHeat(2, HeatedElement::SideWindow)),
+        (30_020, FailureType::ProbeHeat(3, HeatedElement::PitotProbe)),
+        (
+            30_021,
+            FailureType::ProbeHeat(3, HeatedElement::StaticPorts),
+        ),
+        (
+            30_022,
+            FailureType::ProbeHeat(3, HeatedElement::AngleOfAttackProbe),
+        ),
+        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
+        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
+        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
+        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
+        (
+            32_004,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
+        ),
+        (
+            32_005,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
+        ),
+        (
+            32_006,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
+        ),
+        (
+            32_007,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
+        ),
+        (
+            32_008,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
+        ),
+        (
+            32_009,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
+        ),
+        (
+            32_010,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
+        ),
+        (
+            32_011,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
+        ),
+        (
+            32_012,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
+        ),
+        (
+            32_013,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
+        ),
+        (
+            32_014,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
+        ),
+        (
+            32_015,
+            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
+        ),
+        (
+            32_020,
+            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
+        ),
+        (
+            32_021,
+            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
+        ),
+        (
+            32_022,
+            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
+        ),
+        (
+            32_023,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
+        ),
+        (
+            32_024,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
+        ),
+        (
+            32_025,
+            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
+        ),
+        (32_200, FailureType::TyreBurst(1)),
+        (32_201, FailureType::TyreBurst(2)),
+        (32_202, FailureType::TyreBurst(3)),
+        (32_203, FailureType::TyreBurst(4)),
+        (32_204, FailureType::TyreBurst(5)),
+        (32_205, FailureType::TyreBurst(6)),
+        (32_206, FailureType::TyreBurst(7)),
+        (32_207, FailureType::TyreBurst(8)),
+        (32_208, FailureType::TyreBurst(9)),
+        (32_209, FailureType::TyreBurst(10)),
+        (32_210, FailureType::TyreBurst(11)),
+        (32_211, FailureType::TyreBurst(12)),
+        (32_212, FailureType::TyreBurst(13)),
+        (32_213, FailureType::TyreBurst(14)),
+        (32_214, FailureType::TyreBurst(15)),
+        (32_215, FailureType::TyreBurst(16)),
+        (32_220, FailureType::TyreDeflation(1)),
+        (32_221, FailureType::TyreDeflation(2)),
+        (32_222, FailureType::TyreDeflation(3)),
+        (32_223, FailureType::TyreDeflation(4)),
+        (32_224, FailureType::TyreDeflation(5)),
+        (32_225, FailureType::TyreDeflation(6)),
+        (32_226, FailureType::TyreDeflation(7)),
+        (32_227, FailureType::TyreDeflation(8)),
+        (32_228, FailureType::TyreDeflation(9)),
+        (32_229, FailureType::TyreDeflation(10)),
+        (32_230, FailureType::TyreDeflation(11)),
+        (32_231, FailureType::TyreDeflation(12)),
+        (32_232, FailureType::TyreDeflation(13)),
+        (32_233, FailureType::TyreDeflation(14)),
+        (32_234, FailureType::TyreDeflation(15)),
+        (32_235, FailureType::TyreDeflation(16)),
+        (32_240, FailureType::BrakeSeizure(1)),
+        (32_241, FailureType::BrakeSeizure(2)),
+        (32_242, FailureType::BrakeSeizure(3)),
+        (32_243, FailureType::BrakeSeizure(4)),
+        (32_244, FailureType::BrakeSeizure(5)),
+        (32_245, FailureType::BrakeSeizure(6)),
+        (32_246, FailureType::BrakeSeizure(7)),
+        (32_247, FailureType::BrakeSeizure(8)),
+        (32_248, FailureType::BrakeSeizure(9)),
+        (32_249, FailureType::BrakeSeizure(10)),
+        (32_250, FailureType::BrakeSeizure(11)),
+        (32_251, FailureType::BrakeSeizure(12)),
+        (32_252, FailureType::BrakeSeizure(13)),
+        (32_253, FailureType::BrakeSeizure(14)),
+        (32_254, FailureType::BrakeSeizure(15)),
+        (32_255, FailureType::BrakeSeizure(16)),
+        (32_300, FailureType::BodyWheelSteering),
+        (34_000, FailureType::RadioAltimeter(1)),
+        (34_001, FailureType::RadioAltimeter(2)),
+        (34_002, FailureType::RadioAltimeter(3)),
+        (79_001, FailureType::EngineOilLeak(1)),
+        (79_002, FailureType::EngineOilLeak(2)),
+        (79_003, FailureType::EngineOilLeak(3)),
+        (79_004, FailureType::EngineOilLeak(4)),
+        (79_011, FailureType::EngineOilPumpFailure(1)),
+        (79_012, FailureType::EngineOilPumpFailure(2)),
+        (79_013, FailureType::EngineOilPumpFailure(3)),
+        (79_014, FailureType::EngineOilPumpFailure(4)),
+    ]
+}
@@ -1,34 +1,1269 @@
-use systems::simulation::{
-    InitContext, Read, SimulationElement, SimulatorReader, VariableIdentifier,
+use systems::{
+    accept_iterable,
+    apu::AuxiliaryPowerUnitFireOverheadPanel,
+    fuel::{FuelPump, FuelTank, FuelValve},
+    overhead::{AutoOffFaultPushButton, OnOffFaultPushButton, OnOffPushButton},
+    shared::{ElectricalBusType, EngineFirePushButtons},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        UpdateContext, VariableIdentifier,
+    },
 };
-use uom::si::{f64::*, mass::kilogram};
+use uom::si::{
+    f64::*,
+    mass::kilogram,
+    mass_rate::{kilogram_per_hour, kilogram_per_second},
+    ratio::percent,
+};
+
+/// The tanks in the order of the simulator's fuel system tank indices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum A380FuelTankType {
+    LeftOuter,
+    FeedOne,
+    LeftMid,
+    LeftInner,
+    FeedTwo,
+    FeedThree,
+    RightInner,
+    RightMid,
+    FeedFour,
+    RightOuter,
+    Trim,
+}
+impl A380FuelTankType {
+    fn feed(engine_number: usize) -> Self {
+        match engine_number {
+            1 => A380FuelTankType::FeedOne,
+            2 => A380FuelTankType::FeedTwo,
+            3 => A380FuelTankType::FeedThree,
+            _ => A380FuelTankType::FeedFour,
+        }
+    }
+
+    /// The order in which the wing and trim tanks refill the feed tanks of a side.
+    fn transfer_sequence(side: usize) -> [Self; 4] {
+        match side {
+            1 => [
+                A380FuelTankType::LeftInner,
+                A380FuelTankType::LeftMid,
+                A380FuelTankType::Trim,
+                A380FuelTankType::LeftOuter,
+            ],
+            _ => [
+                A380FuelTankType::RightInner,
+                A380FuelTankType::RightMid,
+                A380FuelTankType::Trim,
+                A380FuelTankType::RightOuter,
+            ],
+        }
+    }
+
+    fn transfer_pump_index(&self) -> Option<usize> {
+        match self {
+            A380FuelTankType::LeftOuter => Some(0),
+            A380FuelTankType::LeftMid => Some(1),
+            A380FuelTankType::LeftInner => Some(2),
+            A380FuelTankType::RightInner => Some(3),
+            A380FuelTankType::RightMid => Some(4),
+            A380FuelTankType::RightOuter => Some(5),
+            A380FuelTankType::Trim => Some(6),
+            _ => None,
+        }
+    }
+}
+
+fn side_of_engine(engine_number: usize) -> usize {
+    if engine_number <= 2 {
+        1
+    } else {
+        2
+    }
+}
 
 pub struct A380Fuel {
     unlimited_fuel_id: VariableIdentifier,
-    fuel_tank_left_main_quantity_id: VariableIdentifier,
+    sim_tank_quantity_ids: [VariableIdentifier; 11],
+    fuel_weight_per_gallon_id: VariableIdentifier,
+    engine_fuel_flow_ids: [VariableIdentifier; 4],
+    center_of_gravity_id: VariableIdentifier,
 
     unlimited_fuel: bool,
-    left_inner_tank_fuel_quantity: Mass,
+    sim_tank_quantities_gallons: [f64; 11],
+    fuel_weight_per_gallon: Mass,
+    engine_fuel_flows: [MassRate; 4],
+    center_of_gravity: Ratio,
+    is_initialised: bool,
+
+    tanks: [FuelTank; 11],
+    feed_tank_pumps: [[FuelPump; 2]; 4],
+    transfer_pumps: [FuelPump; 7],
+    feed_tank_inlet_valves: [FuelValve; 4],
+    trim_pipe_isolation_valve: FuelValve,
+    engine_lp_valves: [FuelValve; 4],
+    crossfeed_valves: [FuelValve; 4],
+    apu_lp_valve: FuelValve,
+
+    feed_tank_is_refilling: [bool; 4],
+    aft_trim_transfer_is_active: bool,
+    forward_trim_transfer_is_active: bool,
 }
 impl A380Fuel {
+    const OUTER_TANK_CAPACITY_KG: f64 = 8165.;
+    const OUTBOARD_FEED_TANK_CAPACITY_KG: f64 = 20490.;
+    const MID_TANK_CAPACITY_KG: f64 = 28340.;
+    const INNER_TANK_CAPACITY_KG: f64 = 36345.;
+    const INBOARD_FEED_TANK_CAPACITY_KG: f64 = 22295.;
+    const TRIM_TANK_CAPACITY_KG: f64 = 18605.;
+
+    // Feed tanks are kept close to full. A refill starts once this much has been consumed
+    // and stops when the tank is full again.
+    const FEED_TANK_REFILL_MARGIN_KG: f64 = 1500.;
+    const TRANSFER_RATE_KG_PER_S: f64 = 8.;
+    const TRIM_TRANSFER_RATE_KG_PER_S: f64 = 4.;
+
+    const TARGET_CENTER_OF_GRAVITY_PERCENT_MAC: f64 = 39.;
+    const CENTER_OF_GRAVITY_TOLERANCE_PERCENT_MAC: f64 = 0.5;
+
+    // Differences below this are caused by the unit conversions of the simulator.
+    const SIMULATOR_QUANTITY_TOLERANCE_KG: f64 = 1.;
+
     pub fn new(context: &mut InitContext) -> Self {
         A380Fuel {
             unlimited_fuel_id: context.get_identifier("UNLIMITED FUEL".to_owned()),
-            fuel_tank_left_main_quantity_id: context
-                .get_identifier("FUEL TANK LEFT MAIN QUANTITY".to_owned()),
+            sim_tank_quantity_ids: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]
+                .map(|index| context.get_identifier(format!("FUELSYSTEM TANK QUANTITY:{}", index))),
+            fuel_weight_per_gallon_id: context.get_identifier("FUEL WEIGHT PER GALLON".to_owned()),
+            engine_fuel_flow_ids: [1, 2, 3, 4]
+                .map(|number| context.get_identifier(format!("ENGINE_FF:{}", number))),
+            center_of_gravity_id: context.get_identifier("CG PERCENT".to_owned()),
 
             unlimited_fuel: false,
-            left_inner_tank_fuel_quantity: Mass::new::<kilogram>(0.),
+            sim_tank_quantities_gallons: [0.; 11],
+            fuel_weight_per_gallon: Mass::new::<kilogram>(0.),
+            engine_fuel_flows: [MassRate::new::<kilogram_per_second>(0.); 4],
+            center_of_gravity: Ratio::new::<percent>(0.),
+            is_initialised: false,
+
+            tanks: [
+                ("LEFT_OUTER", Self::OUTER_TANK_CAPACITY_KG),
+                ("FEED_1", Self::OUTBOARD_FEED_TANK_CAPACITY_KG),
+                ("LEFT_MID", Self::MID_TANK_CAPACITY_KG),
+                ("LEFT_INNER", Self::INNER_TANK_CAPACITY_KG),
+                ("FEED_2", Self::INBOARD_FEED_TANK_CAPACITY_KG),
+                ("FEED_3", Self::INBOARD_FEED_TANK_CAPACITY_KG),
+                ("RIGHT_INNER", Self::INNER_TANK_CAPACITY_KG),
+                ("RIGHT_MID", Self::MID_TANK_CAPACITY_KG),
+                ("FEED_4", Self::OUTBOARD_FEED_TANK_CAPACITY_KG),
+                ("RIGHT_OUTER", Self::OUTER_TANK_CAPACITY_KG),
+                ("TRIM", Self::TRIM_TANK_CAPACITY_KG),
+            ]
+            .map(|(name, capacity)| FuelTank::new(context, name, Mass::new::<kilogram>(capacity))),
+            feed_tank_pumps: [1, 2, 3, 4].map(|number| {
+                [
+                    FuelPump::new(
+                        context,
+                        &format!("FEED_{}_MAIN", number),
+                        ElectricalBusType::AlternatingCurrent(number as u8),
+                    ),
+                    FuelPump::new(
+                        context,
+                        &format!("FEED_{}_STBY", number),
+                        ElectricalBusType::AlternatingCurrentEssential,
+                    ),
+                ]
+            }),
+            transfer_pumps: [
+                ("LEFT_OUTER", ElectricalBusType::AlternatingCurrent(1)),
+                ("LEFT_MID", ElectricalBusType::AlternatingCurrent(1)),
+                ("LEFT_INNER", ElectricalBusType::AlternatingCurrent(2)),
+                ("RIGHT_INNER", ElectricalBusType::AlternatingCurrent(3)),
+                ("RIGHT_MID", ElectricalBusType::AlternatingCurrent(4)),
+                ("RIGHT_OUTER", ElectricalBusType::AlternatingCurrent(4)),
+                ("TRIM", ElectricalBusType::AlternatingCurrent(3)),
+            ]
+            .map(|(name, bus)| FuelPump::new(context, &format!("{}_TRANSFER", name), bus)),
+            feed_tank_inlet_valves: [1, 2, 3, 4].map(|number| {
+                FuelValve::new(
+                    context,
+                    &format!("FEED_{}_INLET", number),
+                    ElectricalBusType::DirectCurrent(side_of_engine(number) as u8),
+                    false,
+                )
+            }),
+            trim_pipe_isolation_valve: FuelValve::new(
+                context,
+                "TRIM_PIPE_ISOL",
+                ElectricalBusType::DirectCurrent(2),
+                false,
+            ),
+            engine_lp_valves: [1, 2, 3, 4].map(|number| {
+                FuelValve::new(
+                    context,
+                    &format!("ENG_{}_LP", number),
+                    ElectricalBusType::DirectCurrentEssential,
+                    true,
+                )
+            }),
+            crossfeed_valves: [1, 2, 3, 4].map(|number| {
+                FuelValve::new(
+                    context,
+                    &format!("XFEED_{}", number),
+                    ElectricalBusType::DirectCurrent(side_of_engine(number) as u8),
+                    false,
+                )
+            }),
+            apu_lp_valve: FuelValve::new(
+                context,
+                "APU_LP",
+                ElectricalBusType::DirectCurrentBattery,
+                true,
+            ),
+
+            feed_tank_is_refilling: [false; 4],
+            aft_trim_transfer_is_active: false,
+            forward_trim_transfer_is_active: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        overhead: &A380FuelOverheadPanel,
+        engine_fire_push_buttons: &impl EngineFirePushButtons,
+        apu_fire_overhead: &AuxiliaryPowerUnitFireOverheadPanel,
+        fuel_quantity_management_is_available: bool,
+    ) {
+        if !self.is_initialised || self.is_refuelled(context) {
+            self.take_quantities_from_simulator();
+            self.is_initialised = true;
+        }
+
+        for engine_number in 1..=4 {
+            for (index, pump) in self.feed_tank_pumps[engine_number - 1]
+                .iter_mut()
+                .enumerate()
+            {
+                pump.update(overhead.feed_tank_pump_is_on(engine_number, index == 0));
+            }
+
+            self.engine_lp_valves[engine_number - 1]
+                .update(!engine_fire_push_buttons.is_released(engine_number));
+            self.crossfeed_valves[engine_number - 1]
+                .update(overhead.crossfeed_is_on(engine_number));
+        }
+        self.apu_lp_valve
+            .update(!apu_fire_overhead.fire_button_is_released());
+
+        let transfers = if fuel_quantity_management_is_available
+            && overhead.transfer_is_auto()
+            && !self.unlimited_fuel
+        {
+            self.update_feed_tank_refill_demand();
+            self.update_trim_transfer_demand(context, overhead.trim_tank_is_auto());
+
+            self.required_transfers()
+        } else {
+            self.feed_tank_is_refilling = [false; 4];
+            self.aft_trim_transfer_is_active = false;
+            self.forward_trim_transfer_is_active = false;
+
+            vec![]
+        };
+
+        self.update_transfer_pumps_and_valves(&transfers);
+
+        if !self.unlimited_fuel {
+            self.transfer_fuel(context, &transfers);
+            self.burn_engine_fuel(context);
+        }
+    }
+
+    /// The modelled quantities are written to the simulator's tanks. Thus the simulator's tanks
+    /// only differ from them when fuel is loaded through the simulator. This is only taken into
+    /// account while on the ground with all engines not consuming any fuel.
+    fn is_refuelled(&self, context: &UpdateContext) -> bool {
+        context.is_on_ground()
+            && self
+                .engine_fuel_flows
+                .iter()
+                .all(|flow| flow.get::<kilogram_per_second>() <= 0.)
+            && self
+                .tanks
+                .iter()
+                .zip(self.sim_tank_quantities_gallons)
+                .any(|(tank, gallons)| {
+                    (tank.quantity() - self.fuel_weight_per_gallon * gallons)
+                        .abs()
+                        .get::<kilogram>()
+                        > Self::SIMULATOR_QUANTITY_TOLERANCE_KG
+                })
+    }
+
+    fn take_quantities_from_simulator(&mut self) {
+        for (tank, gallons) in self.tanks.iter_mut().zip(self.sim_tank_quantities_gallons) {
+            tank.set_quantity(self.fuel_weight_per_gallon * gallons);
+        }
+
+        self.feed_tank_is_refilling = [false; 4];
+        self.aft_trim_transfer_is_active = false;
+        self.forward_trim_transfer_is_active = false;
+    }
+
+    fn update_feed_tank_refill_demand(&mut self) {
+        for engine_number in 1..=4 {
+            let feed_tank = self.tank(A380FuelTankType::feed(engine_number));
+            let consumed = feed_tank.capacity() - feed_tank.quantity();
+
+            if consumed > Mass::new::<kilogram>(Self::FEED_TANK_REFILL_MARGIN_KG) {
+                self.feed_tank_is_refilling[engine_number - 1] = true;
+            } else if consumed <= Mass::new::<kilogram>(0.) {
+                self.feed_tank_is_refilling[engine_number - 1] = false;
+            }
+        }
+    }
+
+    /// The fuel management system moves fuel between the trim tank and the wing
+    /// to keep the center of gravity close to its target while airborne.
+    fn update_trim_transfer_demand(&mut self, context: &UpdateContext, trim_tank_is_auto: bool) {
+        if !trim_tank_is_auto || context.is_on_ground() {
+            self.aft_trim_transfer_is_active = false;
+            self.forward_trim_transfer_is_active = false;
+            return;
+        }
+
+        let center_of_gravity = self.center_of_gravity.get::<percent>();
+        let target = Self::TARGET_CENTER_OF_GRAVITY_PERCENT_MAC;
+        let tolerance = Self::CENTER_OF_GRAVITY_TOLERANCE_PERCENT_MAC;
+
+        if center_of_gravity < target - tolerance {
+            self.aft_trim_transfer_is_active = true;
+        } else if center_of_gravity >= target {
+            self.aft_trim_transfer_is_active = false;
+        }
+
+        if center_of_gravity > target + tolerance {
+            self.forward_trim_transfer_is_active = true;
+        } else if center_of_gravity <= target {
+            self.forward_trim_transfer_is_active = false;
+        }
+
+        let trim_tank = self.tank(A380FuelTankType::Trim);
+        let trim_tank_is_full = trim_tank.quantity() >= trim_tank.capacity();
+        let trim_tank_is_empty = trim_tank.is_empty();
+        if trim_tank_is_full {
+            self.aft_trim_transfer_is_active = false;
+        }
+        if trim_tank_is_empty {
+            self.forward_trim_transfer_is_active = false;
+        }
+    }
+
+    /// The list of transfers as source and destination tank which are currently required.
+    fn required_transfers(&self) -> Vec<(A380FuelTankType, A380FuelTankType)> {
+        let mut transfers = vec![];
+
+        for engine_number in 1..=4 {
+            let feed_tank = A380FuelTankType::feed(engine_number);
+
+            if self.feed_tank_is_refilling[engine_number - 1] {
+                if let Some(&source) =
+                    A380FuelTankType::transfer_sequence(side_of_engine(engine_number))
+                        .iter()
+                        .find(|&&tank| !self.tank(tank).is_empty())
+                {
+                    transfers.push((source, feed_tank));
+                }
+            } else if self.forward_trim_transfer_is_active {
+                transfers.push((A380FuelTankType::Trim, feed_tank));
+            }
+        }
+
+        if self.aft_trim_transfer_is_active {
+            [A380FuelTankType::LeftInner, A380FuelTankType::RightInner]
+                .iter()
+                .filter(|&&tank| !self.tank(tank).is_empty())
+                .for_each(|&tank| transfers.push((tank, A380FuelTankType::Trim)));
+        }
+
+        transfers
+    }
+
+    fn update_transfer_pumps_and_valves(
+        &mut self,
+        transfers: &[(A380FuelTankType, A380FuelTankType)],
+    ) {
+        let mut pump_is_required = [false; 7];
+        for (source, _) in transfers {
+            if let Some(index) = source.transfer_pump_index() {
+                pump_is_required[index] = true;
+            }
+        }
+        for (pump, is_required) in self.transfer_pumps.iter_mut().zip(pump_is_required) {
+            pump.update(is_required);
+        }
+
+        for engine_number in 1..=4 {
+            let feed_tank = A380FuelTankType::feed(engine_number);
+            self.feed_tank_inlet_valves[engine_number - 1].update(
+                transfers
+                    .iter()
+                    .any(|&(_, destination)| destination == feed_tank),
+            );
         }
+
+        self.trim_pipe_isolation_valve
+            .update(transfers.iter().any(|&(source, destination)| {
+                source == A380FuelTankType::Trim || destination == A380FuelTankType::Trim
+            }));
+    }
+
+    fn transfer_fuel(
+        &mut self,
+        context: &UpdateContext,
+        transfers: &[(A380FuelTankType, A380FuelTankType)],
+    ) {
+        for &(source, destination) in transfers {
+            if !self.transfer_path_is_available(source, destination) {
+                continue;
+            }
+
+            let rate = if source == A380FuelTankType::Trim || destination == A380FuelTankType::Trim
+            {
+                Self::TRIM_TRANSFER_RATE_KG_PER_S
+            } else {
+                Self::TRANSFER_RATE_KG_PER_S
+            };
+
+            let transferred = self
+                .tank_mut(source)
+                .take(MassRate::new::<kilogram_per_second>(rate) * context.delta_as_time());
+            let remainder = self.tank_mut(destination).fill(transferred);
+            self.tank_mut(source).fill(remainder);
+        }
+    }
+
+    fn transfer_path_is_available(
+        &self,
+        source: A380FuelTankType,
+        destination: A380FuelTankType,
+    ) -> bool {
+        let pump_is_running = source
+            .transfer_pump_index()
+            .map_or(false, |index| self.transfer_pumps[index].is_running());
+
+        let inlet_is_open = match destination {
+            A380FuelTankType::Trim => true,
+            _ => (1..=4)
+                .find(|&number| A380FuelTankType::feed(number) == destination)
+                .map_or(false, |number| {
+                    self.feed_tank_inlet_valves[number - 1].is_open()
+                }),
+        };
+
+        let trim_pipe_is_open = (source != A380FuelTankType::Trim
+            && destination != A380FuelTankType::Trim)
+            || self.trim_pipe_isolation_valve.is_open();
+
+        pump_is_running && inlet_is_open && trim_pipe_is_open
     }
 
-    pub fn left_inner_tank_has_fuel_remaining(&self) -> bool {
-        self.unlimited_fuel || self.left_inner_tank_fuel_quantity > Mass::new::<kilogram>(0.)
+    /// Burns the engine fuel flow from the feeding tank. When the feeding tank runs empty,
+    /// the remainder is taken from the tank which feeds the engine next.
+    fn burn_engine_fuel(&mut self, context: &UpdateContext) {
+        for engine_number in 1..=4 {
+            if !self.engine_lp_valves[engine_number - 1].is_open() {
+                continue;
+            }
+
+            let mut required = self.engine_fuel_flows[engine_number - 1] * context.delta_as_time();
+            while required > Mass::new::<kilogram>(0.) {
+                match self.feeding_tank(engine_number) {
+                    Some(tank_type) => required -= self.tank_mut(tank_type).take(required),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Each engine is fed by its own feed tank. When its pumps cannot deliver fuel, an open
+    /// crossfeed valve allows any other feed tank connected to the crossfeed manifold to
+    /// feed the engine. As a last resort the engine suction feeds from its own feed tank.
+    fn feeding_tank(&self, engine_number: usize) -> Option<A380FuelTankType> {
+        if self.feed_tank_delivers_pressure(engine_number) {
+            return Some(A380FuelTankType::feed(engine_number));
+        }
+
+        if self.crossfeed_valves[engine_number - 1].is_open() {
+            if let Some(other) = (1..=4).find(|&other| {
+                other != engine_number
+                    && self.crossfeed_valves[other - 1].is_open()
+                    && self.feed_tank_delivers_pressure(other)
+            }) {
+                return Some(A380FuelTankType::feed(other));
+            }
+        }
+
+        let feed_tank = A380FuelTankType::feed(engine_number);
+        if self.tank(feed_tank).is_empty() {
+            None
+        } else {
+            Some(feed_tank)
+        }
+    }
+
+    fn feed_tank_delivers_pressure(&self, engine_number: usize) -> bool {
+        self.feed_tank_pumps[engine_number - 1]
+            .iter()
+            .any(|pump| pump.is_running())
+            && !self.tank(A380FuelTankType::feed(engine_number)).is_empty()
+    }
+
+    fn tank(&self, tank_type: A380FuelTankType) -> &FuelTank {
+        &self.tanks[tank_type as usize]
+    }
+
+    fn tank_mut(&mut self, tank_type: A380FuelTankType) -> &mut FuelTank {
+        &mut self.tanks[tank_type as usize]
+    }
+
+    /// The APU is fed from feed tank 1.
+    pub fn apu_fuel_is_available(&self) -> bool {
+        self.apu_lp_valve.is_open() && (self.unlimited_fuel || self.feeding_tank(1).is_some())
+    }
+
+    fn feed_tank_pump_is_running(&self, engine_number: usize, is_main: bool) -> bool {
+        self.feed_tank_pumps[engine_number - 1][if is_main { 0 } else { 1 }].is_running()
     }
 }
 impl SimulationElement for A380Fuel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.tanks, visitor);
+        for pumps in self.feed_tank_pumps.iter_mut() {
+            accept_iterable!(pumps, visitor);
+        }
+        accept_iterable!(self.transfer_pumps, visitor);
+        accept_iterable!(self.feed_tank_inlet_valves, visitor);
+        self.trim_pipe_isolation_valve.accept(visitor);
+        accept_iterable!(self.engine_lp_valves, visitor);
+        accept_iterable!(self.crossfeed_valves, visitor);
+        self.apu_lp_valve.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.unlimited_fuel = reader.read(&self.unlimited_fuel_id);
-        self.left_inner_tank_fuel_quantity = reader.read(&self.fuel_tank_left_main_quantity_id);
+        self.fuel_weight_per_gallon = reader.read(&self.fuel_weight_per_gallon_id);
+        self.center_of_gravity = reader.read(&self.center_of_gravity_id);
+
+        for (gallons, id) in self
+            .sim_tank_quantities_gallons
+            .iter_mut()
+            .zip(&self.sim_tank_quantity_ids)
+        {
+            *gallons = reader.read(id);
+        }
+
+        for (flow, id) in self
+            .engine_fuel_flows
+            .iter_mut()
+            .zip(&self.engine_fuel_flow_ids)
+        {
+            let flow_kg_per_hour: f64 = reader.read(id);
+            *flow = MassRate::new::<kilogram_per_hour>(flow_kg_per_hour.max(0.));
+        }
+    }
+}
+
+pub struct A380FuelOverheadPanel {
+    feed_tank_pumps: [[OnOffFaultPushButton; 2]; 4],
+    crossfeeds: [OnOffPushButton; 4],
+    transfer: AutoOffFaultPushButton,
+    trim_tank: AutoOffFaultPushButton,
+}
+impl A380FuelOverheadPanel {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            feed_tank_pumps: [1, 2, 3, 4].map(|number| {
+                [
+                    OnOffFaultPushButton::new_on(context, &format!("FUEL_FEED_{}_MAIN", number)),
+                    OnOffFaultPushButton::new_on(context, &format!("FUEL_FEED_{}_STBY", number)),
+                ]
+            }),
+            crossfeeds: [1, 2, 3, 4]
+                .map(|number| OnOffPushButton::new_off(context, &format!("FUEL_XFEED_{}", number))),
+            transfer: AutoOffFaultPushButton::new_auto(context, "FUEL_TRANSFER"),
+            trim_tank: AutoOffFaultPushButton::new_auto(context, "FUEL_TRIM_TANK"),
+        }
+    }
+
+    pub fn update_after_fuel(&mut self, fuel: &A380Fuel) {
+        for engine_number in 1..=4 {
+            for (index, pb) in self.feed_tank_pumps[engine_number - 1]
+                .iter_mut()
+                .enumerate()
+            {
+                pb.set_fault(
+                    pb.is_on() && !fuel.feed_tank_pump_is_running(engine_number, index == 0),
+                );
+            }
+        }
+    }
+
+    fn feed_tank_pump_is_on(&self, engine_number: usize, is_main: bool) -> bool {
+        self.feed_tank_pumps[engine_number - 1][if is_main { 0 } else { 1 }].is_on()
+    }
+
+    fn crossfeed_is_on(&self, engine_number: usize) -> bool {
+        self.crossfeeds[engine_number - 1].is_on()
+    }
+
+    fn transfer_is_auto(&self) -> bool {
+        self.transfer.is_auto()
+    }
+
+    fn trim_tank_is_auto(&self) -> bool {
+        self.trim_tank.is_auto()
+    }
+}
+impl SimulationElement for A380FuelOverheadPanel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        for pbs in self.feed_tank_pumps.iter_mut() {
+            accept_iterable!(pbs, visitor);
+        }
+        accept_iterable!(self.crossfeeds, visitor);
+        self.transfer.accept(visitor);
+        self.trim_tank.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use systems::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        engine::EngineFireOverheadPanel,
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use uom::si::{electric_potential::volt, mass::pound};
+
+    const FUEL_WEIGHT_PER_GALLON_LB: f64 = 6.7;
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        buses: Vec<ElectricalBus>,
+        is_powered: bool,
+        fuel_quantity_management_is_available: bool,
+
+        fuel: A380Fuel,
+        fuel_overhead: A380FuelOverheadPanel,
+        engine_fire_overhead: EngineFireOverheadPanel<4>,
+        apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                buses: vec![
+                    ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                    ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                    ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(3)),
+                    ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(4)),
+                    ElectricalBus::new(context, ElectricalBusType::AlternatingCurrentEssential),
+                    ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                    ElectricalBus::new(context, ElectricalBusType::DirectCurrent(2)),
+                    ElectricalBus::new(context, ElectricalBusType::DirectCurrentEssential),
+                    ElectricalBus::new(context, ElectricalBusType::DirectCurrentBattery),
+                ],
+                is_powered: true,
+                fuel_quantity_management_is_available: true,
+                fuel: A380Fuel::new(context),
+                fuel_overhead: A380FuelOverheadPanel::new(context),
+                engine_fire_overhead: EngineFireOverheadPanel::new(context),
+                apu_fire_overhead: AuxiliaryPowerUnitFireOverheadPanel::new(context),
+            }
+        }
+
+        fn unpower(&mut self) {
+            self.is_powered = false;
+        }
+
+        fn set_fuel_quantity_management_available(&mut self, is_available: bool) {
+            self.fuel_quantity_management_is_available = is_available;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_powered {
+                for bus in &self.buses {
+                    electricity.flow(&self.electricity_source, bus);
+                }
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fuel.update(
+                context,
+                &self.fuel_overhead,
+                &self.engine_fire_overhead,
+                &self.apu_fire_overhead,
+                self.fuel_quantity_management_is_available,
+            );
+            self.fuel_overhead.update_after_fuel(&self.fuel);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fuel.accept(visitor);
+            self.fuel_overhead.accept(visitor);
+            self.engine_fire_overhead.accept(visitor);
+            self.apu_fire_overhead.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct A380FuelTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl A380FuelTestBed {
+        const FULL_FEED_TANKS: [(A380FuelTankType, f64); 4] = [
+            (
+                A380FuelTankType::FeedOne,
+                A380Fuel::OUTBOARD_FEED_TANK_CAPACITY_KG,
+            ),
+            (
+                A380FuelTankType::FeedTwo,
+                A380Fuel::INBOARD_FEED_TANK_CAPACITY_KG,
+            ),
+            (
+                A380FuelTankType::FeedThree,
+                A380Fuel::INBOARD_FEED_TANK_CAPACITY_KG,
+            ),
+            (
+                A380FuelTankType::FeedFour,
+                A380Fuel::OUTBOARD_FEED_TANK_CAPACITY_KG,
+            ),
+        ];
+
+        fn new() -> Self {
+            let mut fuel_test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            fuel_test_bed.write_by_name(
+                "FUEL WEIGHT PER GALLON",
+                Mass::new::<pound>(FUEL_WEIGHT_PER_GALLON_LB),
+            );
+            fuel_test_bed.write_by_name("CG PERCENT", Ratio::new::<percent>(39.));
+            fuel_test_bed.set_on_ground(true);
+            fuel_test_bed.run();
+
+            fuel_test_bed
+        }
+
+        const TANK_NAMES: [&'static str; 11] = [
+            "LEFT_OUTER",
+            "FEED_1",
+            "LEFT_MID",
+            "LEFT_INNER",
+            "FEED_2",
+            "FEED_3",
+            "RIGHT_INNER",
+            "RIGHT_MID",
+            "FEED_4",
+            "RIGHT_OUTER",
+            "TRIM",
+        ];
+
+        /// Writes the modelled quantities to the simulator's tanks, as the fuel aspect does.
+        fn write_quantities_to_sim_tanks(&mut self) {
+            for (index, name) in Self::TANK_NAMES.iter().enumerate() {
+                let quantity: Mass = self.read_by_name(&format!("FUEL_{}_QUANTITY", name));
+                self.write_by_name(
+                    &format!("FUELSYSTEM TANK QUANTITY:{}", index + 1),
+                    quantity.get::<pound>() / FUEL_WEIGHT_PER_GALLON_LB,
+                );
+            }
+        }
+
+        fn with_sim_tank_quantity(mut self, tank: A380FuelTankType, kg: f64) -> Self {
+            let gallons = Mass::new::<kilogram>(kg).get::<pound>() / FUEL_WEIGHT_PER_GALLON_LB;
+            self.write_by_name(
+                &format!("FUELSYSTEM TANK QUANTITY:{}", tank as usize + 1),
+                gallons,
+            );
+            self
+        }
+
+        fn loaded_with(mut self, quantities: &[(A380FuelTankType, f64)]) -> Self {
+            for &(tank, kg) in quantities {
+                self = self.with_sim_tank_quantity(tank, kg);
+            }
+            // Loading must not move any fuel, hence the empty time step.
+            self.set_on_ground(true);
+            self.run_with_delta(Duration::from_secs(0));
+
+            self.in_flight()
+        }
+
+        fn loaded_with_full_feed_tanks_and(self, quantities: &[(A380FuelTankType, f64)]) -> Self {
+            let mut all = Self::FULL_FEED_TANKS.to_vec();
+            all.extend_from_slice(quantities);
+
+            self.loaded_with(&all)
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.set_on_ground(true);
+            self
+        }
+
+        fn in_flight(mut self) -> Self {
+            self.set_on_ground(false);
+            self
+        }
+
+        fn engine_fuel_flow(mut self, engine_number: usize, kg_per_hour: f64) -> Self {
+            self.write_by_name(&format!("ENGINE_FF:{}", engine_number), kg_per_hour);
+            self
+        }
+
+        fn center_of_gravity(mut self, percent_mac: f64) -> Self {
+            self.write_by_name("CG PERCENT", Ratio::new::<percent>(percent_mac));
+            self
+        }
+
+        fn feed_tank_pumps_off(mut self, engine_number: usize) -> Self {
+            self.write_by_name(
+                &format!("OVHD_FUEL_FEED_{}_MAIN_PB_IS_ON", engine_number),
+                false,
+            );
+            self.write_by_name(
+                &format!("OVHD_FUEL_FEED_{}_STBY_PB_IS_ON", engine_number),
+                false,
+            );
+            self
+        }
+
+        fn crossfeed_on(mut self, engine_number: usize) -> Self {
+            self.write_by_name(&format!("OVHD_FUEL_XFEED_{}_PB_IS_ON", engine_number), true);
+            self
+        }
+
+        fn transfer_off(mut self) -> Self {
+            self.write_by_name("OVHD_FUEL_TRANSFER_PB_IS_AUTO", false);
+            self
+        }
+
+        fn fuel_quantity_management_unavailable(mut self) -> Self {
+            self.command(|a| a.set_fuel_quantity_management_available(false));
+            self
+        }
+
+        fn release_engine_fire_push_button(mut self, engine_number: usize) -> Self {
+            self.write_by_name(&format!("FIRE_BUTTON_ENG{}", engine_number), true);
+            self
+        }
+
+        fn release_apu_fire_push_button(mut self) -> Self {
+            self.write_by_name("FIRE_BUTTON_APU", true);
+            self
+        }
+
+        fn unpowered(mut self) -> Self {
+            self.command(|a| a.unpower());
+            self
+        }
+
+        fn run_for(mut self, delta: Duration) -> Self {
+            self.run_with_delta(delta);
+            self.write_quantities_to_sim_tanks();
+            self
+        }
+
+        fn and(self) -> Self {
+            self
+        }
+
+        fn tank_quantity_kg(&mut self, name: &str) -> f64 {
+            let quantity: Mass = self.read_by_name(&format!("FUEL_{}_QUANTITY", name));
+            quantity.get::<kilogram>()
+        }
+
+        fn pump_is_running(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("FUEL_{}_PUMP_IS_RUNNING", name))
+        }
+
+        fn valve_is_open(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("FUEL_{}_VALVE_IS_OPEN", name))
+        }
+
+        fn push_button_has_fault(&mut self, name: &str) -> bool {
+            self.read_by_name(&format!("OVHD_FUEL_{}_PB_HAS_FAULT", name))
+        }
+
+        fn apu_fuel_is_available(&self) -> bool {
+            self.query(|a| a.fuel.apu_fuel_is_available())
+        }
+    }
+    impl TestBed for A380FuelTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> A380FuelTestBed {
+        A380FuelTestBed::new()
+    }
+
+    fn test_bed_with() -> A380FuelTestBed {
+        test_bed()
+    }
+
+    fn assert_about_eq(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "expected {} but was {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn tanks_are_loaded_from_the_simulator() {
+        let mut test_bed = test_bed_with().loaded_with(&[
+            (A380FuelTankType::LeftOuter, 1000.),
+            (A380FuelTankType::FeedTwo, 2000.),
+            (A380FuelTankType::RightMid, 3000.),
+            (A380FuelTankType::Trim, 4000.),
+        ]);
+
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_OUTER"), 1000.);
+        assert_about_eq(test_bed.tank_quantity_kg("FEED_2"), 2000.);
+        assert_about_eq(test_bed.tank_quantity_kg("RIGHT_MID"), 3000.);
+        assert_about_eq(test_bed.tank_quantity_kg("TRIM"), 4000.);
+        assert_about_eq(test_bed.tank_quantity_kg("FEED_4"), 0.);
+    }
+
+    #[test]
+    fn engines_burn_fuel_from_their_feed_tank() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[])
+            .engine_fuel_flow(1, 3600.)
+            .engine_fuel_flow(4, 7200.)
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_1"),
+            A380Fuel::OUTBOARD_FEED_TANK_CAPACITY_KG - 10.,
+        );
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_4"),
+            A380Fuel::OUTBOARD_FEED_TANK_CAPACITY_KG - 20.,
+        );
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_2"),
+            A380Fuel::INBOARD_FEED_TANK_CAPACITY_KG,
+        );
+    }
+
+    #[test]
+    fn feed_tank_is_refilled_from_the_inner_tank_first() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[
+                (A380FuelTankType::FeedOne, 15000.),
+                (A380FuelTankType::LeftInner, 10000.),
+                (A380FuelTankType::LeftMid, 10000.),
+            ])
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.pump_is_running("LEFT_INNER_TRANSFER"));
+        assert!(!test_bed.pump_is_running("LEFT_MID_TRANSFER"));
+        assert!(test_bed.valve_is_open("FEED_1_INLET"));
+        assert_about_eq(test_bed.tank_quantity_kg("FEED_1"), 15080.);
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_INNER"), 9920.);
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_MID"), 10000.);
+    }
+
+    #[test]
+    fn feed_tank_is_refilled_from_the_mid_tank_when_inner_tank_is_empty() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[
+                (A380FuelTankType::FeedOne, 15000.),
+                (A380FuelTankType::LeftMid, 10000.),
+            ])
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.pump_is_running("LEFT_MID_TRANSFER"));
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_MID"), 9920.);
+    }
+
+    #[test]
+    fn feed_tank_is_refilled_from_the_trim_tank_before_the_outer_tank() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[
+                (A380FuelTankType::FeedFour, 15000.),
+                (A380FuelTankType::Trim, 5000.),
+                (A380FuelTankType::RightOuter, 5000.),
+            ])
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.pump_is_running("TRIM_TRANSFER"));
+        assert!(test_bed.valve_is_open("TRIM_PIPE_ISOL"));
+        assert!(!test_bed.pump_is_running("RIGHT_OUTER_TRANSFER"));
+        assert_about_eq(test_bed.tank_quantity_kg("TRIM"), 4960.);
+        assert_about_eq(test_bed.tank_quantity_kg("RIGHT_OUTER"), 5000.);
+    }
+
+    #[test]
+    fn feed_tank_refill_stops_when_full() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[
+                (
+                    A380FuelTankType::FeedOne,
+                    A380Fuel::OUTBOARD_FEED_TANK_CAPACITY_KG - 1600.,
+                ),
+                (A380FuelTankType::LeftInner, 10000.),
+            ])
+            .run_for(Duration::from_secs(300))
+            .and()
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_1"),
+            A380Fuel::OUTBOARD_FEED_TANK_CAPACITY_KG,
+        );
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_INNER"), 8400.);
+        assert!(!test_bed.pump_is_running("LEFT_INNER_TRANSFER"));
+        assert!(!test_bed.valve_is_open("FEED_1_INLET"));
+    }
+
+    #[test]
+    fn no_refill_while_feed_tank_is_within_margin() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[
+                (
+                    A380FuelTankType::FeedOne,
+                    A380Fuel::OUTBOARD_FEED_TANK_CAPACITY_KG - 1000.,
+                ),
+                (A380FuelTankType::LeftInner, 10000.),
+            ])
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.pump_is_running("LEFT_INNER_TRANSFER"));
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_INNER"), 10000.);
+    }
+
+    #[test]
+    fn no_automatic_transfer_without_fuel_quantity_management() {
+        let mut test_bed = test_bed_with()
+            .loaded_with(&[
+                (A380FuelTankType::FeedOne, 15000.),
+                (A380FuelTankType::LeftInner, 10000.),
+            ])
+            .fuel_quantity_management_unavailable()
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.pump_is_running("LEFT_INNER_TRANSFER"));
+        assert_about_eq(test_bed.tank_quantity_kg("FEED_1"), 15000.);
+    }
+
+    #[test]
+    fn no_automatic_transfer_when_transfer_push_button_off() {
+        let mut test_bed = test_bed_with()
+            .loaded_with(&[
+                (A380FuelTankType::FeedOne, 15000.),
+                (A380FuelTankType::LeftInner, 10000.),
+            ])
+            .transfer_off()
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq(test_bed.tank_quantity_kg("FEED_1"), 15000.);
+    }
+
+    #[test]
+    fn forward_center_of_gravity_transfers_inner_tank_fuel_to_trim_tank() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[
+                (A380FuelTankType::LeftInner, 10000.),
+                (A380FuelTankType::RightInner, 10000.),
+            ])
+            .center_of_gravity(37.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(test_bed.valve_is_open("TRIM_PIPE_ISOL"));
+        assert_about_eq(test_bed.tank_quantity_kg("TRIM"), 80.);
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_INNER"), 9960.);
+        assert_about_eq(test_bed.tank_quantity_kg("RIGHT_INNER"), 9960.);
+    }
+
+    #[test]
+    fn aft_center_of_gravity_transfers_trim_tank_fuel_forward() {
+        let mut test_bed = test_bed_with()
+            .loaded_with(&[
+                (A380FuelTankType::FeedOne, 20000.),
+                (A380FuelTankType::FeedTwo, 20000.),
+                (A380FuelTankType::FeedThree, 20000.),
+                (A380FuelTankType::FeedFour, 20000.),
+                (A380FuelTankType::Trim, 5000.),
+            ])
+            .center_of_gravity(41.)
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq(test_bed.tank_quantity_kg("TRIM"), 5000. - 4. * 40.);
+        assert_about_eq(test_bed.tank_quantity_kg("FEED_1"), 20040.);
+    }
+
+    #[test]
+    fn center_of_gravity_is_not_managed_on_ground() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[(A380FuelTankType::LeftInner, 10000.)])
+            .on_ground()
+            .center_of_gravity(37.)
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq(test_bed.tank_quantity_kg("TRIM"), 0.);
+    }
+
+    #[test]
+    fn center_of_gravity_within_tolerance_does_not_start_a_trim_transfer() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[
+                (A380FuelTankType::LeftInner, 10000.),
+                (A380FuelTankType::Trim, 5000.),
+            ])
+            .center_of_gravity(38.7)
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq(test_bed.tank_quantity_kg("TRIM"), 5000.);
+        assert_about_eq(test_bed.tank_quantity_kg("LEFT_INNER"), 10000.);
+    }
+
+    #[test]
+    fn crossfeed_allows_an_engine_to_be_fed_from_another_feed_tank() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[])
+            .feed_tank_pumps_off(2)
+            .crossfeed_on(2)
+            .crossfeed_on(3)
+            .engine_fuel_flow(2, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_2"),
+            A380Fuel::INBOARD_FEED_TANK_CAPACITY_KG,
+        );
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_3"),
+            A380Fuel::INBOARD_FEED_TANK_CAPACITY_KG - 10.,
+        );
+    }
+
+    #[test]
+    fn fuel_demand_not_met_by_an_emptying_feed_tank_is_taken_through_the_crossfeed() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[(A380FuelTankType::FeedOne, 5.)])
+            .crossfeed_on(1)
+            .crossfeed_on(2)
+            .engine_fuel_flow(1, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert_about_eq(test_bed.tank_quantity_kg("FEED_1"), 0.);
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_2"),
+            A380Fuel::INBOARD_FEED_TANK_CAPACITY_KG - 5.,
+        );
+    }
+
+    #[test]
+    fn fuel_loaded_through_the_simulator_on_the_ground_is_taken_over() {
+        let mut test_bed = test_bed_with()
+            .loaded_with(&[(A380FuelTankType::Trim, 1000.)])
+            .on_ground()
+            .run_for(Duration::from_secs(1))
+            .and()
+            .with_sim_tank_quantity(A380FuelTankType::Trim, 3000.)
+            .run_for(Duration::from_secs(1));
+
+        assert_about_eq(test_bed.tank_quantity_kg("TRIM"), 3000.);
+    }
+
+    #[test]
+    fn released_engine_fire_push_button_closes_lp_valve_and_stops_burn() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[])
+            .release_engine_fire_push_button(3)
+            .engine_fuel_flow(3, 3600.)
+            .run_for(Duration::from_secs(10));
+
+        assert!(!test_bed.valve_is_open("ENG_3_LP"));
+        assert_about_eq(
+            test_bed.tank_quantity_kg("FEED_3"),
+            A380Fuel::INBOARD_FEED_TANK_CAPACITY_KG,
+        );
+    }
+
+    #[test]
+    fn apu_has_fuel_available_from_feed_tank_1() {
+        let test_bed = test_bed_with()
+            .loaded_with(&[(A380FuelTankType::FeedOne, 1000.)])
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn apu_has_no_fuel_available_when_feed_tank_1_is_empty() {
+        let test_bed = test_bed_with()
+            .loaded_with(&[(A380FuelTankType::FeedTwo, 1000.)])
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn apu_has_no_fuel_available_when_apu_fire_push_button_released() {
+        let test_bed = test_bed_with()
+            .loaded_with(&[(A380FuelTankType::FeedOne, 1000.)])
+            .release_apu_fire_push_button()
+            .run_for(Duration::from_secs(1));
+
+        assert!(!test_bed.apu_fuel_is_available());
+    }
+
+    #[test]
+    fn unpowered_feed_pump_push_buttons_show_fault() {
+        let mut test_bed = test_bed_with()
+            .loaded_with_full_feed_tanks_and(&[])
+            .unpowered()
+            .run_for(Duration::from_secs(1));
+
+        assert!(test_bed.push_button_has_fault("FEED_1_MAIN"));
+        assert!(test_bed.push_button_has_fault("FEED_4_STBY"));
     }
 }
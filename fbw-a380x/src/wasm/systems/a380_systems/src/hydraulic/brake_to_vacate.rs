@@ -0,0 +1,328 @@
+use systems::simulation::{
+    InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+    VariableIdentifier, Write,
+};
+
+use uom::si::{
+    acceleration::meter_per_second_squared, f64::*, length::meter, velocity::knot,
+    velocity::meter_per_second,
+};
+
+/// Brake To Vacate computes the deceleration needed to reach the runway exit selected on the
+/// OANS at the exit speed. Before touchdown the distance to the exit is counted from a nominal
+/// touchdown point. At touchdown the position on the runway is latched, from the runway threshold
+/// when it is known, then the distance rolled on ground is subtracted from it.
+pub struct BrakeToVacate {
+    exit_distance_id: VariableIdentifier,
+    remaining_distance_id: VariableIdentifier,
+    runway_too_short_id: VariableIdentifier,
+
+    exit_distance: Length,
+    touchdown_distance_from_threshold: Length,
+    distance_since_touchdown: Length,
+    was_on_ground: bool,
+    ground_speed: Velocity,
+    required_deceleration: Acceleration,
+}
+impl BrakeToVacate {
+    const NOMINAL_TOUCHDOWN_DISTANCE_FROM_THRESHOLD_METERS: f64 = 400.;
+    const EXIT_SPEED_KNOTS: f64 = 10.;
+
+    pub const MAX_DECELERATION_MS2: f64 = 3.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            exit_distance_id: context.get_identifier("BTV_EXIT_DISTANCE".to_owned()),
+            remaining_distance_id: context
+                .get_identifier("BTV_REMAINING_DISTANCE_TO_EXIT".to_owned()),
+            runway_too_short_id: context.get_identifier("BTV_RUNWAY_TOO_SHORT".to_owned()),
+
+            exit_distance: Length::default(),
+            touchdown_distance_from_threshold: Length::new::<meter>(
+                Self::NOMINAL_TOUCHDOWN_DISTANCE_FROM_THRESHOLD_METERS,
+            ),
+            distance_since_touchdown: Length::default(),
+            was_on_ground: false,
+            ground_speed: Velocity::default(),
+            required_deceleration: Acceleration::default(),
+        }
+    }
+
+    /// `distance_from_threshold` is the current along track distance from the landing runway
+    /// threshold, `None` when the landing runway isn't known.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        ground_speed: Velocity,
+        is_on_ground: bool,
+        distance_from_threshold: Option<Length>,
+    ) {
+        self.ground_speed = ground_speed;
+
+        if is_on_ground {
+            if !self.was_on_ground {
+                if let Some(distance_from_threshold) = distance_from_threshold {
+                    self.touchdown_distance_from_threshold = distance_from_threshold;
+                }
+            }
+
+            self.distance_since_touchdown += ground_speed * context.delta_as_time();
+        } else {
+            self.touchdown_distance_from_threshold =
+                Length::new::<meter>(Self::NOMINAL_TOUCHDOWN_DISTANCE_FROM_THRESHOLD_METERS);
+            self.distance_since_touchdown = Length::default();
+        }
+        self.was_on_ground = is_on_ground;
+
+        self.required_deceleration = self.compute_required_deceleration();
+    }
+
+    fn compute_required_deceleration(&self) -> Acceleration {
+        if !self.exit_is_selected() || self.has_reached_exit_speed() {
+            return Acceleration::default();
+        }
+
+        let remaining_distance = self.remaining_distance().get::<meter>();
+        if remaining_distance <= 0. {
+            return Acceleration::new::<meter_per_second_squared>(f64::INFINITY);
+        }
+
+        let speed = self.ground_speed.get::<meter_per_second>();
+        let exit_speed = Velocity::new::<knot>(Self::EXIT_SPEED_KNOTS).get::<meter_per_second>();
+
+        Acceleration::new::<meter_per_second_squared>(
+            (speed.powi(2) - exit_speed.powi(2)) / (2. * remaining_distance),
+        )
+    }
+
+    pub fn exit_is_selected(&self) -> bool {
+        self.exit_distance > Length::default()
+    }
+
+    pub fn has_reached_exit_speed(&self) -> bool {
+        self.ground_speed <= Velocity::new::<knot>(Self::EXIT_SPEED_KNOTS)
+    }
+
+    fn remaining_distance(&self) -> Length {
+        self.exit_distance - self.touchdown_distance_from_threshold - self.distance_since_touchdown
+    }
+
+    /// Deceleration target for the autobrake, as a negative acceleration. It is limited to the
+    /// maximum BTV deceleration even if the exit can't be reached with it.
+    pub fn target(&self) -> Acceleration {
+        -self
+            .required_deceleration
+            .min(Acceleration::new::<meter_per_second_squared>(
+                Self::MAX_DECELERATION_MS2,
+            ))
+    }
+
+    /// The selected exit can't be reached at exit speed without exceeding the maximum BTV
+    /// deceleration.
+    pub fn runway_too_short(&self) -> bool {
+        self.required_deceleration
+            > Acceleration::new::<meter_per_second_squared>(Self::MAX_DECELERATION_MS2)
+    }
+}
+impl SimulationElement for BrakeToVacate {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let exit_distance_meters: f64 = reader.read(&self.exit_distance_id);
+        self.exit_distance = Length::new::<meter>(exit_distance_meters);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(
+            &self.remaining_distance_id,
+            if self.exit_is_selected() {
+                self.remaining_distance().get::<meter>().max(0.)
+            } else {
+                0.
+            },
+        );
+        writer.write(&self.runway_too_short_id, self.runway_too_short());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use systems::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+
+    struct TestAircraft {
+        btv: BrakeToVacate,
+        ground_speed: Velocity,
+        is_on_ground: bool,
+        distance_from_threshold: Option<Length>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                btv: BrakeToVacate::new(context),
+                ground_speed: Velocity::default(),
+                is_on_ground: false,
+                distance_from_threshold: None,
+            }
+        }
+
+        fn set_ground_speed(&mut self, ground_speed: Velocity) {
+            self.ground_speed = ground_speed;
+        }
+
+        fn set_on_ground(&mut self, is_on_ground: bool) {
+            self.is_on_ground = is_on_ground;
+        }
+
+        fn set_distance_from_threshold(&mut self, distance: Length) {
+            self.distance_from_threshold = Some(distance);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.btv.update(
+                context,
+                self.ground_speed,
+                self.is_on_ground,
+                self.distance_from_threshold,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.btv.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn assert_about_eq(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{} is not about {}",
+            actual,
+            expected
+        );
+    }
+
+    fn test_bed(exit_distance: Length, ground_speed: Velocity) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("BTV_EXIT_DISTANCE", exit_distance.get::<meter>());
+        test_bed.command(|a| a.set_ground_speed(ground_speed));
+
+        test_bed
+    }
+
+    #[test]
+    fn no_target_without_selected_exit() {
+        let mut test_bed = test_bed(Length::default(), Velocity::new::<knot>(140.));
+        test_bed.run();
+
+        assert_about_eq(
+            test_bed
+                .query(|a| a.btv.target())
+                .get::<meter_per_second_squared>(),
+            0.,
+        );
+        assert!(!test_bed.query(|a| a.btv.runway_too_short()));
+    }
+
+    #[test]
+    fn target_is_computed_from_touchdown_point_and_ground_speed() {
+        let mut test_bed = test_bed(Length::new::<meter>(2400.), Velocity::new::<knot>(140.));
+        test_bed.run();
+
+        let speed = Velocity::new::<knot>(140.).get::<meter_per_second>();
+        let exit_speed = Velocity::new::<knot>(10.).get::<meter_per_second>();
+        assert_about_eq(
+            test_bed
+                .query(|a| a.btv.target())
+                .get::<meter_per_second_squared>(),
+            -(speed.powi(2) - exit_speed.powi(2)) / (2. * 2000.),
+        );
+    }
+
+    #[test]
+    fn remaining_distance_decreases_while_rolling() {
+        let mut test_bed = test_bed(Length::new::<meter>(2400.), Velocity::new::<knot>(100.));
+        test_bed.command(|a| a.set_on_ground(true));
+        test_bed.run_with_delta(Duration::from_secs(10));
+
+        let rolled = Velocity::new::<knot>(100.).get::<meter_per_second>() * 10.;
+        let remaining: f64 = test_bed.read_by_name("BTV_REMAINING_DISTANCE_TO_EXIT");
+        assert_about_eq(remaining, 2000. - rolled);
+    }
+
+    #[test]
+    fn runway_too_short_when_exit_is_too_close() {
+        let mut test_bed = test_bed(Length::new::<meter>(1000.), Velocity::new::<knot>(140.));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.btv.runway_too_short()));
+        let runway_too_short: bool = test_bed.read_by_name("BTV_RUNWAY_TOO_SHORT");
+        assert!(runway_too_short);
+        assert_about_eq(
+            test_bed
+                .query(|a| a.btv.target())
+                .get::<meter_per_second_squared>(),
+            -BrakeToVacate::MAX_DECELERATION_MS2,
+        );
+    }
+
+    #[test]
+    fn runway_too_short_when_exit_is_passed() {
+        let mut test_bed = test_bed(Length::new::<meter>(1000.), Velocity::new::<knot>(60.));
+        test_bed.command(|a| a.set_on_ground(true));
+        test_bed.run_with_delta(Duration::from_secs(30));
+
+        assert!(test_bed.query(|a| a.btv.runway_too_short()));
+    }
+
+    #[test]
+    fn no_target_once_exit_speed_is_reached() {
+        let mut test_bed = test_bed(Length::new::<meter>(2400.), Velocity::new::<knot>(8.));
+        test_bed.command(|a| a.set_on_ground(true));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.btv.has_reached_exit_speed()));
+        assert_about_eq(
+            test_bed
+                .query(|a| a.btv.target())
+                .get::<meter_per_second_squared>(),
+            0.,
+        );
+    }
+
+    #[test]
+    fn remaining_distance_is_counted_from_the_actual_touchdown_point() {
+        let mut test_bed = test_bed(Length::new::<meter>(2400.), Velocity::new::<knot>(100.));
+        test_bed.run();
+        test_bed.command(|a| {
+            a.set_on_ground(true);
+            a.set_distance_from_threshold(Length::new::<meter>(700.));
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let rolled = Velocity::new::<knot>(100.).get::<meter_per_second>();
+        let remaining: f64 = test_bed.read_by_name("BTV_REMAINING_DISTANCE_TO_EXIT");
+        assert_about_eq(remaining, 1700. - rolled);
+    }
+
+    #[test]
+    fn touchdown_point_is_latched_at_touchdown() {
+        let mut test_bed = test_bed(Length::new::<meter>(2400.), Velocity::new::<knot>(100.));
+        test_bed.command(|a| {
+            a.set_on_ground(true);
+            a.set_distance_from_threshold(Length::new::<meter>(700.));
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+        test_bed.command(|a| a.set_distance_from_threshold(Length::new::<meter>(1500.)));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        let rolled = Velocity::new::<knot>(100.).get::<meter_per_second>() * 2.;
+        let remaining: f64 = test_bed.read_by_name("BTV_REMAINING_DISTANCE_TO_EXIT");
+        assert_about_eq(remaining, 1700. - rolled);
+    }
+}
@@ -7,6 +7,7 @@ use uom::si::{
     angular_velocity::{radian_per_second, revolution_per_minute},
     electric_current::ampere,
     f64::*,
+    force::newton,
     length::meter,
     mass::kilogram,
     pressure::psi,
@@ -19,11 +20,14 @@ use uom::si::{
 use systems::{
     accept_iterable,
     engine::Engine,
+    failures::{Failure, FailureType},
     hydraulic::{
         aerodynamic_model::AerodynamicModel,
+        anti_skid::AntiSkid,
         brake_circuit::{
             AutobrakeDecelerationGovernor, AutobrakeMode, AutobrakePanel,
-            BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController,
+            BrakeAccumulatorCharacteristics, BrakeCircuit, BrakeCircuitController, BrakeMode,
+            BrakeModeSelector, LocalAccumulatorBrake,
         },
         cargo_doors::{CargoDoor, HydraulicDoorController},
         flap_slat::FlapSlatAssembly,
@@ -40,6 +44,7 @@ use systems::{
         },
         pumps::PumpCharacteristics,
         pushback::PushbackTug,
+        runway_overrun_protection::RunwayOverrunProtection,
         trimmable_horizontal_stabilizer::{
             TrimmableHorizontalStabilizerActuator, TrimmableHorizontalStabilizerMotorController,
         },
@@ -47,29 +52,96 @@ use systems::{
         HydraulicPressureSensors, PressureSwitch, PressureSwitchType, PriorityValve,
         PumpController, Reservoir,
     },
-    landing_gear::{GearSystemSensors, LandingGearControlInterfaceUnitSet, TiltingGear},
+    landing_gear::{
+        brake_heat::BrakeTemperatureMonitoring, wheel::MainGearWheels, GearSystemSensors,
+        LandingGearControlInterfaceUnitSet, TiltingGear,
+    },
     overhead::{AutoOffFaultPushButton, AutoOnFaultPushButton},
     shared::{
         interpolation, random_from_range, update_iterator::MaxStepLoop, AdirsDiscreteOutputs,
-        AirbusElectricPumpId, AirbusEngineDrivenPumpId, DelayedFalseLogicGate,
-        DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType, ElectricalBuses,
-        EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle, LgciuInterface,
-        LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure,
+        AdirsMeasurementOutputs, AirbusElectricPumpId, AirbusEngineDrivenPumpId,
+        DelayedFalseLogicGate, DelayedPulseTrueLogicGate, DelayedTrueLogicGate, ElectricalBusType,
+        ElectricalBuses, EngineFirePushButtons, GearWheel, HydraulicColor, LandingGearHandle,
+        LgciuInterface, LgciuWeightOnWheels, ReservoirAirPressure, SectionPressure,
     },
     simulation::{
         InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, StartState, UpdateContext, VariableIdentifier, Write,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, StartState, UpdateContext,
+        VariableIdentifier, Write,
     },
 };
 
 use std::fmt::Debug;
 
+mod brake_to_vacate;
+use brake_to_vacate::BrakeToVacate;
+
 mod flaps_computer;
 use flaps_computer::SlatFlapComplex;
 
 #[cfg(test)]
 use systems::hydraulic::PressureSwitchState;
 
+struct A380BodyWheelSteeringFactory {}
+impl A380BodyWheelSteeringFactory {
+    const MAX_BODY_WHEEL_ANGLE_DEGREE: f64 = 15.;
+    const NOMINAL_SPEED_RAD_S: f64 = 0.1;
+    const ACTUATOR_DIAMETER_METER: f64 = 0.05;
+    const ANGULAR_TO_LINEAR_RATIO: f64 = 0.15;
+
+    fn new_body_wheel_steering(context: &mut InitContext, is_left: bool) -> SteeringActuator {
+        SteeringActuator::new(
+            context,
+            if is_left {
+                "LEFT_BODY_WHEEL"
+            } else {
+                "RIGHT_BODY_WHEEL"
+            },
+            Angle::new::<degree>(Self::MAX_BODY_WHEEL_ANGLE_DEGREE),
+            AngularVelocity::new::<radian_per_second>(Self::NOMINAL_SPEED_RAD_S),
+            Length::new::<meter>(Self::ACTUATOR_DIAMETER_METER),
+            Ratio::new::<ratio>(Self::ANGULAR_TO_LINEAR_RATIO),
+        )
+    }
+}
+
+struct A380EmergencyBrakeFactory {}
+impl A380EmergencyBrakeFactory {
+    const ACCUMULATOR_VOLUME_GAL: f64 = 0.25;
+    const ACCUMULATOR_GAS_PRE_CHARGE_PSI: f64 = 1000.;
+
+    // Brake displacement of one of the four main bogies
+    const BOGIE_BRAKES_DISPLACEMENT_GAL: f64 = 0.0325;
+
+    fn new_bogie_emergency_brake(
+        context: &mut InitContext,
+        id: &str,
+        is_left: bool,
+    ) -> LocalAccumulatorBrake {
+        LocalAccumulatorBrake::new(
+            context,
+            id,
+            is_left,
+            Accumulator::new_brake_accumulator(BrakeAccumulatorCharacteristics::new(
+                Volume::new::<gallon>(Self::ACCUMULATOR_VOLUME_GAL),
+                Pressure::new::<psi>(Self::ACCUMULATOR_GAS_PRE_CHARGE_PSI),
+                Pressure::new::<psi>(A380HydraulicCircuitFactory::HYDRAULIC_TARGET_PRESSURE_PSI),
+                Ratio::new::<ratio>(0.01),
+            )),
+            Volume::new::<gallon>(Self::BOGIE_BRAKES_DISPLACEMENT_GAL),
+        )
+    }
+
+    fn new_emergency_brakes(context: &mut InitContext) -> [LocalAccumulatorBrake; 4] {
+        [
+            Self::new_bogie_emergency_brake(context, "LEFT_WING", true),
+            Self::new_bogie_emergency_brake(context, "LEFT_BODY", true),
+            Self::new_bogie_emergency_brake(context, "RIGHT_BODY", false),
+            Self::new_bogie_emergency_brake(context, "RIGHT_WING", false),
+        ]
+    }
+}
+
 struct A380TiltingGearsFactory {}
 impl A380TiltingGearsFactory {
     fn new_a380_body_gear(context: &mut InitContext, is_left: bool) -> TiltingGear {
@@ -1501,6 +1573,9 @@ impl A380GearSystemFactory {
 
 pub(super) struct A380Hydraulic {
     nose_steering: SteeringActuator,
+    body_wheel_steering_control_unit: A380BodyWheelSteeringControlUnit,
+    left_body_wheel_steering: SteeringActuator,
+    right_body_wheel_steering: SteeringActuator,
 
     core_hydraulic_updater: MaxStepLoop,
 
@@ -1551,7 +1626,10 @@ pub(super) struct A380Hydraulic {
 
     braking_circuit_norm: BrakeCircuit,
     braking_circuit_altn: BrakeCircuit,
+    emergency_brakes: [LocalAccumulatorBrake; 4],
     braking_force: A380BrakingForce,
+    brake_temperature: BrakeTemperatureMonitoring<16>,
+    main_gear_wheels: MainGearWheels<16>,
 
     flap_system: FlapSlatAssembly,
     slat_system: FlapSlatAssembly,
@@ -1615,7 +1693,13 @@ impl A380Hydraulic {
     const EDP_CONTROL_POWER_BUS1: ElectricalBusType = ElectricalBusType::DirectCurrentEssential;
 
     const ALTERNATE_BRAKE_ACCUMULATOR_GAS_PRE_CHARGE: f64 = 1000.0; // Nitrogen PSI
-                                                                    // Refresh rate of core hydraulic simulation
+
+    const BRAKE_HEAT_SINK_MASS_KG: f64 = 140.;
+    // Braking force of each of the 16 brakes at full braking force factor
+    const MAX_BRAKING_FORCE_PER_BRAKE_N: f64 = 140000.;
+    const NOMINAL_TYRE_PRESSURE_PSI: f64 = 218.;
+
+    // Refresh rate of core hydraulic simulation
     const HYDRAULIC_SIM_TIME_STEP: Duration = Duration::from_millis(10);
 
     pub fn new(context: &mut InitContext) -> A380Hydraulic {
@@ -1629,11 +1713,19 @@ impl A380Hydraulic {
         A380Hydraulic {
             nose_steering: SteeringActuator::new(
                 context,
+                "NOSE_WHEEL",
                 Angle::new::<degree>(75.),
                 AngularVelocity::new::<radian_per_second>(0.35),
                 Length::new::<meter>(0.075),
                 Ratio::new::<ratio>(0.18),
             ),
+            body_wheel_steering_control_unit: A380BodyWheelSteeringControlUnit::new(context),
+            left_body_wheel_steering: A380BodyWheelSteeringFactory::new_body_wheel_steering(
+                context, true,
+            ),
+            right_body_wheel_steering: A380BodyWheelSteeringFactory::new_body_wheel_steering(
+                context, false,
+            ),
 
             core_hydraulic_updater: MaxStepLoop::new(Self::HYDRAULIC_SIM_TIME_STEP),
 
@@ -1809,7 +1901,18 @@ impl A380Hydraulic {
                 Volume::new::<gallon>(0.13),
             ),
 
+            emergency_brakes: A380EmergencyBrakeFactory::new_emergency_brakes(context),
+
             braking_force: A380BrakingForce::new(context),
+            brake_temperature: BrakeTemperatureMonitoring::new(
+                context,
+                Mass::new::<kilogram>(Self::BRAKE_HEAT_SINK_MASS_KG),
+                Force::new::<newton>(Self::MAX_BRAKING_FORCE_PER_BRAKE_N),
+            ),
+            main_gear_wheels: MainGearWheels::new(
+                context,
+                Pressure::new::<psi>(Self::NOMINAL_TYRE_PRESSURE_PSI),
+            ),
 
             flap_system: FlapSlatAssembly::new(
                 context,
@@ -1906,7 +2009,7 @@ impl A380Hydraulic {
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         lgcius: &LandingGearControlInterfaceUnitSet,
         reservoir_pneumatics: &impl ReservoirAirPressure,
-        adirs: &impl AdirsDiscreteOutputs,
+        adirs: &(impl AdirsDiscreteOutputs + AdirsMeasurementOutputs),
     ) {
         self.core_hydraulic_updater.update(context);
 
@@ -1932,6 +2035,14 @@ impl A380Hydraulic {
                 lgcius.lgciu2(),
                 reservoir_pneumatics,
             );
+
+            self.update_brakes_and_wheels(
+                &context.with_delta(cur_time_step),
+                engines[0],
+                engines[1],
+                lgcius.lgciu1(),
+                adirs,
+            );
         }
     }
 
@@ -2164,10 +2275,28 @@ impl A380Hydraulic {
             &self.pushback_tug,
         );
 
+        self.body_wheel_steering_control_unit.update(
+            self.nose_steering.position_feedback(),
+            &self.brake_steer_computer,
+            lgciu1,
+            &self.pushback_tug,
+        );
+        self.left_body_wheel_steering.update_hydraulic_steering(
+            context,
+            self.yellow_circuit.system_section(),
+            &self.body_wheel_steering_control_unit,
+        );
+        self.right_body_wheel_steering.update_hydraulic_steering(
+            context,
+            self.yellow_circuit.system_section(),
+            &self.body_wheel_steering_control_unit,
+        );
+
         // Process brake logic (which circuit brakes) and send brake demands (how much)
         self.brake_steer_computer.update(
             context,
             self.green_circuit.system_section(),
+            self.yellow_circuit.system_section(),
             &self.braking_circuit_altn,
             lgciu1,
             lgciu2,
@@ -2178,15 +2307,6 @@ impl A380Hydraulic {
 
         self.pushback_tug.update(context);
 
-        self.braking_force.update_forces(
-            context,
-            &self.braking_circuit_norm,
-            &self.braking_circuit_altn,
-            engine1,
-            engine2,
-            &self.pushback_tug,
-        );
-
         self.slats_flaps_complex
             .update(context, &self.flap_system, &self.slat_system);
 
@@ -2225,7 +2345,7 @@ impl A380Hydraulic {
             context,
             &self.forward_cargo_door_controller,
             &self.aft_cargo_door_controller,
-            &self.pushback_tug,
+            &self.body_wheel_steering_control_unit,
             overhead_panel,
         );
     }
@@ -2392,12 +2512,23 @@ impl A380Hydraulic {
         self.yellow_circuit
             .update_system_actuator_volumes(&mut self.braking_circuit_altn);
 
+        for emergency_brake in &mut self.emergency_brakes {
+            self.yellow_circuit
+                .update_system_actuator_volumes(emergency_brake);
+        }
+
         self.yellow_circuit
             .update_system_actuator_volumes(self.flap_system.right_motor());
 
         self.yellow_circuit
             .update_system_actuator_volumes(&mut self.nose_steering);
 
+        self.yellow_circuit
+            .update_system_actuator_volumes(&mut self.left_body_wheel_steering);
+
+        self.yellow_circuit
+            .update_system_actuator_volumes(&mut self.right_body_wheel_steering);
+
         self.yellow_circuit
             .update_system_actuator_volumes(self.right_elevator.actuator(
                 ElevatorActuatorPosition::Outward,
@@ -2734,6 +2865,52 @@ impl A380Hydraulic {
             self.yellow_circuit.system_section(),
             self.brake_steer_computer.alternate_controller(),
         );
+        for emergency_brake in &mut self.emergency_brakes {
+            emergency_brake.update(
+                context,
+                self.yellow_circuit.system_section(),
+                self.brake_steer_computer.emergency_controller(),
+            );
+        }
+    }
+
+    // Braking forces, wheel slip and anti skid are updated at the fixed step rate so wheels can't
+    // lock between two anti skid updates at low frame rates
+    fn update_brakes_and_wheels(
+        &mut self,
+        context: &UpdateContext,
+        engine1: &impl Engine,
+        engine2: &impl Engine,
+        lgciu1: &impl LgciuInterface,
+        adirs: &impl AdirsMeasurementOutputs,
+    ) {
+        self.braking_force.update_forces(
+            context,
+            &self.braking_circuit_norm,
+            &self.braking_circuit_altn,
+            &self.emergency_brakes,
+            engine1,
+            engine2,
+            &self.pushback_tug,
+            &self.main_gear_wheels,
+        );
+
+        self.brake_temperature.update(
+            context,
+            self.braking_force.left_braking_force(),
+            self.braking_force.right_braking_force(),
+            !lgciu1.main_up_and_locked(),
+            false,
+        );
+        self.main_gear_wheels.update(
+            context,
+            &self.brake_temperature,
+            self.braking_force.left_braking_force(),
+            self.braking_force.right_braking_force(),
+        );
+
+        self.brake_steer_computer
+            .update_anti_skid(context, &self.main_gear_wheels, adirs);
     }
 
     pub fn gear_system(&self) -> &impl GearSystemSensors {
@@ -2793,9 +2970,15 @@ impl SimulationElement for A380Hydraulic {
 
         self.braking_circuit_norm.accept(visitor);
         self.braking_circuit_altn.accept(visitor);
+        accept_iterable!(self.emergency_brakes, visitor);
         self.braking_force.accept(visitor);
+        self.brake_temperature.accept(visitor);
+        self.main_gear_wheels.accept(visitor);
 
         self.nose_steering.accept(visitor);
+        self.body_wheel_steering_control_unit.accept(visitor);
+        self.left_body_wheel_steering.accept(visitor);
+        self.right_body_wheel_steering.accept(visitor);
         self.slats_flaps_complex.accept(visitor);
         self.flap_system.accept(visitor);
         self.slat_system.accept(visitor);
@@ -3285,14 +3468,14 @@ impl A380ElectricPumpAutoLogic {
         context: &UpdateContext,
         forward_cargo_door_controller: &HydraulicDoorController,
         aft_cargo_door_controller: &HydraulicDoorController,
-        pushback_tug: &PushbackTug,
+        body_wheel_steering: &A380BodyWheelSteeringControlUnit,
         overhead: &A380HydraulicOverheadPanel,
     ) {
         self.update_auto_run_logic(
             context,
             forward_cargo_door_controller,
             aft_cargo_door_controller,
-            pushback_tug,
+            body_wheel_steering,
         );
 
         self.select_pump_in_use(overhead);
@@ -3303,7 +3486,7 @@ impl A380ElectricPumpAutoLogic {
         context: &UpdateContext,
         forward_cargo_door_controller: &HydraulicDoorController,
         aft_cargo_door_controller: &HydraulicDoorController,
-        pushback_tug: &PushbackTug,
+        body_wheel_steering: &A380BodyWheelSteeringControlUnit,
     ) {
         self.cargo_door_in_operation_previous = self.is_required_for_cargo_door_operation.output();
 
@@ -3317,7 +3500,7 @@ impl A380ElectricPumpAutoLogic {
             self.is_required_for_body_steering_operation.output();
 
         self.is_required_for_body_steering_operation
-            .update(context, pushback_tug.is_nose_wheel_steering_pin_inserted());
+            .update(context, body_wheel_steering.should_pressurise_hydraulics());
     }
 
     fn select_pump_in_use(&mut self, overhead: &A380HydraulicOverheadPanel) {
@@ -3488,6 +3671,8 @@ impl SimulationElement for A380ElectricPumpController {
 struct A380BrakeSystemOutputs {
     left_demand: Ratio,
     right_demand: Ratio,
+    left_anti_skid_ratio: f64,
+    right_anti_skid_ratio: f64,
     pressure_limit: Pressure,
 }
 impl A380BrakeSystemOutputs {
@@ -3495,6 +3680,8 @@ impl A380BrakeSystemOutputs {
         Self {
             left_demand: Ratio::new::<ratio>(0.),
             right_demand: Ratio::new::<ratio>(0.),
+            left_anti_skid_ratio: 1.,
+            right_anti_skid_ratio: 1.,
             pressure_limit: Pressure::new::<psi>(3000.),
         }
     }
@@ -3512,6 +3699,12 @@ impl A380BrakeSystemOutputs {
             .max(Ratio::new::<ratio>(0.));
     }
 
+    /// Share of the brake demands actually sent to the brakes once anti skid released slipping wheels
+    fn set_anti_skid_ratios(&mut self, left_ratio: f64, right_ratio: f64) {
+        self.left_anti_skid_ratio = left_ratio;
+        self.right_anti_skid_ratio = right_ratio;
+    }
+
     fn set_no_demands(&mut self) {
         self.left_demand = Ratio::new::<ratio>(0.);
         self.right_demand = Ratio::new::<ratio>(0.);
@@ -3536,11 +3729,11 @@ impl BrakeCircuitController for A380BrakeSystemOutputs {
     }
 
     fn left_brake_demand(&self) -> Ratio {
-        self.left_demand
+        self.left_demand * self.left_anti_skid_ratio
     }
 
     fn right_brake_demand(&self) -> Ratio {
-        self.right_demand
+        self.right_demand * self.right_anti_skid_ratio
     }
 }
 
@@ -3566,10 +3759,13 @@ struct A380HydraulicBrakeSteerComputerUnit {
 
     norm_brake_outputs: A380BrakeSystemOutputs,
     alternate_brake_outputs: A380BrakeSystemOutputs,
+    emergency_brake_outputs: A380BrakeSystemOutputs,
 
-    normal_brakes_available: bool,
+    brake_mode_selector: BrakeModeSelector,
+    is_in_flight_gear_lever_up: bool,
     should_disable_auto_brake_when_retracting: DelayedTrueLogicGate,
     anti_skid_activated: bool,
+    anti_skid: AntiSkid<16>,
 
     tiller_pedal_disconnect: bool,
     tiller_handle_position: Ratio,
@@ -3604,10 +3800,11 @@ impl A380HydraulicBrakeSteerComputerUnit {
 
     const MAX_STEERING_ANGLE_DEMAND_DEGREES: f64 = 74.;
 
-    // Minimum pressure hysteresis on green until main switched on ALTN brakes
-    // Feedback by Cpt. Chaos — 25/04/2021 #pilot-feedback
-    const MIN_PRESSURE_BRAKE_ALTN_HYST_LO: f64 = 1305.;
-    const MIN_PRESSURE_BRAKE_ALTN_HYST_HI: f64 = 2176.;
+    // Nominal braking from pedals is limited to 2538psi, alternate without anti skid to 1160psi
+    const NORMAL_BRAKE_PRESSURE_LIMIT_PSI: f64 = 2538.;
+    const ALTERNATE_BRAKE_PRESSURE_LIMIT_PSI: f64 = 2538.;
+    const ALTERNATE_WITHOUT_ANTI_SKID_BRAKE_PRESSURE_LIMIT_PSI: f64 = 1160.;
+    const PARKING_BRAKE_PRESSURE_LIMIT_PSI: f64 = 2103.;
 
     // Min pressure when parking brake enabled. Lower normal braking is allowed to use pilot input as emergency braking
     // Feedback by avteknisyan — 25/04/2021 #pilot-feedback
@@ -3615,8 +3812,6 @@ impl A380HydraulicBrakeSteerComputerUnit {
 
     const AUTOBRAKE_GEAR_RETRACTION_DURATION_S: f64 = 3.;
 
-    const PILOT_INPUT_DETECTION_TRESHOLD: f64 = 0.2;
-
     fn new(context: &mut InitContext) -> Self {
         Self {
             park_brake_lever_pos_id: context.get_identifier("PARK_BRAKE_LEVER_POS".to_owned()),
@@ -3640,11 +3835,21 @@ impl A380HydraulicBrakeSteerComputerUnit {
             right_brake_pilot_input: Ratio::new::<ratio>(0.0),
             norm_brake_outputs: A380BrakeSystemOutputs::new(),
             alternate_brake_outputs: A380BrakeSystemOutputs::new(),
-            normal_brakes_available: false,
+            emergency_brake_outputs: A380BrakeSystemOutputs::new(),
+            brake_mode_selector: BrakeModeSelector::new(
+                context,
+                true,
+                Pressure::new::<psi>(Self::NORMAL_BRAKE_PRESSURE_LIMIT_PSI),
+                Pressure::new::<psi>(Self::ALTERNATE_BRAKE_PRESSURE_LIMIT_PSI),
+                Pressure::new::<psi>(Self::ALTERNATE_WITHOUT_ANTI_SKID_BRAKE_PRESSURE_LIMIT_PSI),
+                Pressure::new::<psi>(Self::PARKING_BRAKE_PRESSURE_LIMIT_PSI),
+            ),
+            is_in_flight_gear_lever_up: false,
             should_disable_auto_brake_when_retracting: DelayedTrueLogicGate::new(
                 Duration::from_secs_f64(Self::AUTOBRAKE_GEAR_RETRACTION_DURATION_S),
             ),
             anti_skid_activated: true,
+            anti_skid: AntiSkid::new(),
 
             tiller_pedal_disconnect: false,
             tiller_handle_position: Ratio::new::<ratio>(0.),
@@ -3676,21 +3881,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
     }
 
     fn allow_autobrake_arming(&self) -> bool {
-        self.anti_skid_activated && self.normal_brakes_available
-    }
-
-    fn update_normal_braking_availability(&mut self, normal_braking_circuit_pressure: Pressure) {
-        if normal_braking_circuit_pressure.get::<psi>() > Self::MIN_PRESSURE_BRAKE_ALTN_HYST_HI
-            && (self.left_brake_pilot_input.get::<ratio>() < Self::PILOT_INPUT_DETECTION_TRESHOLD
-                && self.right_brake_pilot_input.get::<ratio>()
-                    < Self::PILOT_INPUT_DETECTION_TRESHOLD)
-        {
-            self.normal_brakes_available = true;
-        } else if normal_braking_circuit_pressure.get::<psi>()
-            < Self::MIN_PRESSURE_BRAKE_ALTN_HYST_LO
-        {
-            self.normal_brakes_available = false;
-        }
+        self.anti_skid_activated && self.brake_mode_selector.is_normal_braking_available()
     }
 
     fn update_brake_pressure_limitation(&mut self) {
@@ -3699,27 +3890,20 @@ impl A380HydraulicBrakeSteerComputerUnit {
             || self.right_brake_pilot_input
                 > self.alternate_brake_outputs.right_demand() + Ratio::new::<ratio>(0.2);
 
-        // Nominal braking from pedals is limited to 2538psi
         self.norm_brake_outputs
-            .set_pressure_limit(Pressure::new::<psi>(2538.));
+            .set_pressure_limit(self.brake_mode_selector.normal_pressure_limit());
 
-        let alternate_brake_pressure_limit = Pressure::new::<psi>(if self.parking_brake_demand {
-            // If no pilot action, standard park brake pressure limit
-            if !yellow_manual_braking_input {
-                2103.
-            } else {
-                // Else manual action limited to a higher max nominal pressure
-                2538.
-            }
-        } else if !self.anti_skid_activated {
-            1160.
-        } else {
-            // Else if any manual braking we use standard limit
-            2538.
-        });
+        // Parking brake pressure is limited unless the pilot is braking manually
+        self.alternate_brake_outputs.set_pressure_limit(
+            self.brake_mode_selector
+                .alternate_pressure_limit(yellow_manual_braking_input),
+        );
 
-        self.alternate_brake_outputs
-            .set_pressure_limit(alternate_brake_pressure_limit);
+        // Local accumulators have no anti skid
+        self.emergency_brake_outputs.set_pressure_limit(
+            self.brake_mode_selector
+                .alternate_pressure_limit(yellow_manual_braking_input),
+        );
     }
 
     /// Updates brakes and nose steering demands
@@ -3727,6 +3911,7 @@ impl A380HydraulicBrakeSteerComputerUnit {
         &mut self,
         context: &UpdateContext,
         current_pressure: &impl SectionPressure,
+        alternate_pressure: &impl SectionPressure,
         alternate_circuit: &BrakeCircuit,
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
@@ -3736,7 +3921,14 @@ impl A380HydraulicBrakeSteerComputerUnit {
     ) {
         self.update_steering_demands(lgciu1, engine1, engine2);
 
-        self.update_normal_braking_availability(current_pressure.pressure());
+        self.brake_mode_selector.update(
+            current_pressure,
+            alternate_pressure,
+            self.anti_skid_activated,
+            self.parking_brake_demand,
+            self.left_brake_pilot_input,
+            self.right_brake_pilot_input,
+        );
         self.update_brake_pressure_limitation();
 
         self.autobrake_controller.update(
@@ -3745,18 +3937,19 @@ impl A380HydraulicBrakeSteerComputerUnit {
             self.allow_autobrake_arming(),
             self.left_brake_pilot_input,
             self.right_brake_pilot_input,
+            self.ground_speed,
             lgciu1,
             lgciu2,
         );
 
-        let is_in_flight_gear_lever_up = !(lgciu1.left_and_right_gear_compressed(true)
+        self.is_in_flight_gear_lever_up = !(lgciu1.left_and_right_gear_compressed(true)
             || lgciu2.left_and_right_gear_compressed(true)
             || lgciu1.gear_handle_is_down());
 
         self.should_disable_auto_brake_when_retracting
-            .update(context, is_in_flight_gear_lever_up);
+            .update(context, self.is_in_flight_gear_lever_up);
 
-        if is_in_flight_gear_lever_up {
+        if self.is_in_flight_gear_lever_up {
             if self.should_disable_auto_brake_when_retracting.output() {
                 self.norm_brake_outputs.set_no_demands();
             } else {
@@ -3766,10 +3959,11 @@ impl A380HydraulicBrakeSteerComputerUnit {
             }
 
             self.alternate_brake_outputs.set_no_demands();
+            self.emergency_brake_outputs.set_no_demands();
         } else {
-            let green_used_for_brakes = self.normal_brakes_available
-                && self.anti_skid_activated
-                && !self.parking_brake_demand;
+            let green_used_for_brakes = self.brake_mode_selector.mode() == BrakeMode::Normal;
+
+            self.emergency_brake_outputs.set_no_demands();
 
             if green_used_for_brakes {
                 // Final output on normal brakes is max(pilot demand , autobrake demand) to allow pilot override autobrake demand
@@ -3784,7 +3978,14 @@ impl A380HydraulicBrakeSteerComputerUnit {
             } else {
                 self.norm_brake_outputs.set_no_demands();
 
-                if !self.parking_brake_demand {
+                if self.brake_mode_selector.is_emergency_braking() {
+                    // Both normal and alternate supplies lost: braking from bogies local accumulators
+                    self.alternate_brake_outputs.set_no_demands();
+                    self.emergency_brake_outputs.set_brake_demands(
+                        self.left_brake_pilot_input,
+                        self.right_brake_pilot_input,
+                    );
+                } else if !self.parking_brake_demand {
                     // Normal braking but using alternate circuit
                     self.alternate_brake_outputs.set_brake_demands(
                         self.left_brake_pilot_input,
@@ -3810,6 +4011,37 @@ impl A380HydraulicBrakeSteerComputerUnit {
         }
     }
 
+    /// Anti skid releases pressure of slipping wheels, in normal braking and in alternate braking
+    /// as long as the alternate supply is pressurised.
+    /// Updated at the hydraulic time step, as it needs to catch a slipping wheel before it locks.
+    fn update_anti_skid(
+        &mut self,
+        context: &UpdateContext,
+        main_gear_wheels: &MainGearWheels<16>,
+        adirs: &impl AdirsMeasurementOutputs,
+    ) {
+        let is_active = self.anti_skid_activated
+            && !self.is_in_flight_gear_lever_up
+            && matches!(
+                self.brake_mode_selector.mode(),
+                BrakeMode::Normal | BrakeMode::AlternateWithAntiSkid
+            );
+
+        let reference_speed = [1, 2, 3]
+            .iter()
+            .find_map(|&adiru_number| adirs.ground_speed(adiru_number).normal_value());
+
+        self.anti_skid
+            .update(context, is_active, reference_speed, main_gear_wheels);
+
+        let left_ratio = self.anti_skid.left_demand_ratio();
+        let right_ratio = self.anti_skid.right_demand_ratio();
+        self.norm_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+        self.alternate_brake_outputs
+            .set_anti_skid_ratios(left_ratio, right_ratio);
+    }
+
     fn update_steering_demands(
         &mut self,
         lgciu1: &impl LgciuInterface,
@@ -3871,13 +4103,26 @@ impl A380HydraulicBrakeSteerComputerUnit {
     fn alternate_controller(&self) -> &impl BrakeCircuitController {
         &self.alternate_brake_outputs
     }
+
+    fn emergency_controller(&self) -> &impl BrakeCircuitController {
+        &self.emergency_brake_outputs
+    }
 }
 impl SimulationElement for A380HydraulicBrakeSteerComputerUnit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.autobrake_controller.accept(visitor);
+        self.brake_mode_selector.accept(visitor);
         visitor.visit(self);
     }
 
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.anti_skid.snapshot(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.anti_skid.restore(snapshot);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.parking_brake_demand = reader.read(&self.park_brake_lever_pos_id);
 
@@ -3903,6 +4148,112 @@ impl SteeringController for A380HydraulicBrakeSteerComputerUnit {
     }
 }
 
+/// Body wheel steering control. The rear axles of the body gears steer opposite to the nose wheel
+/// to tighten turns and reduce tyre scrubbing. Steering engages on ground at low speed when the
+/// nose wheel angle exceeds a threshold, otherwise the body wheels are centered.
+/// The yellow electric pumps are run when steering is demanded, so body wheels can be steered
+/// while the yellow engine driven pumps are not running, and while towing.
+struct A380BodyWheelSteeringControlUnit {
+    ground_speed_id: VariableIdentifier,
+    engaged_id: VariableIdentifier,
+    fault_id: VariableIdentifier,
+
+    failure: Failure,
+
+    ground_speed: Velocity,
+    is_towing: bool,
+    is_engaged: bool,
+    is_steering_demanded: bool,
+    requested_position: Angle,
+}
+impl A380BodyWheelSteeringControlUnit {
+    const MAX_GROUND_SPEED_FOR_ENGAGEMENT_KNOT: f64 = 20.;
+
+    const NOSE_WHEEL_ANGLE_MAP_DEGREE: [f64; 3] = [0., 20., 70.];
+    const BODY_WHEEL_ANGLE_MAP_DEGREE: [f64; 3] = [0., 0., 15.];
+
+    fn new(context: &mut InitContext) -> Self {
+        Self {
+            ground_speed_id: context.get_identifier("GPS GROUND SPEED".to_owned()),
+            engaged_id: context.get_identifier("BODY_WHEEL_STEERING_ENGAGED".to_owned()),
+            fault_id: context.get_identifier("BODY_WHEEL_STEERING_FAULT".to_owned()),
+
+            failure: Failure::new(FailureType::BodyWheelSteering),
+
+            ground_speed: Velocity::new::<knot>(0.),
+            is_towing: false,
+            is_engaged: false,
+            is_steering_demanded: false,
+            requested_position: Angle::new::<degree>(0.),
+        }
+    }
+
+    fn update(
+        &mut self,
+        nose_wheel_position: Angle,
+        nose_wheel_steering: &impl SteeringController,
+        lgciu1: &impl LgciuWeightOnWheels,
+        pushback_tug: &impl Pushback,
+    ) {
+        self.is_towing = pushback_tug.is_nose_wheel_steering_pin_inserted();
+
+        let nose_wheel_angle_degree = nose_wheel_position.get::<degree>().abs();
+
+        let is_available = !self.failure.is_active()
+            && lgciu1.left_and_right_gear_compressed(true)
+            && self.ground_speed.get::<knot>().abs() < Self::MAX_GROUND_SPEED_FOR_ENGAGEMENT_KNOT;
+
+        self.is_engaged =
+            is_available && nose_wheel_angle_degree > Self::NOSE_WHEEL_ANGLE_MAP_DEGREE[1];
+
+        // The demand is used rather than the nose wheel position, which can't reach the
+        // engagement angle while the yellow system is unpressurised
+        self.is_steering_demanded = is_available
+            && nose_wheel_steering
+                .requested_position()
+                .get::<degree>()
+                .abs()
+                > Self::NOSE_WHEEL_ANGLE_MAP_DEGREE[1];
+
+        self.requested_position = if self.is_engaged {
+            -Angle::new::<degree>(interpolation(
+                &Self::NOSE_WHEEL_ANGLE_MAP_DEGREE,
+                &Self::BODY_WHEEL_ANGLE_MAP_DEGREE,
+                nose_wheel_angle_degree,
+            )) * nose_wheel_position.get::<degree>().signum()
+        } else {
+            Angle::new::<degree>(0.)
+        };
+    }
+
+    /// Body wheel steering needs hydraulic power while towing, when engines are not running,
+    /// and whenever a tight turn is demanded
+    fn should_pressurise_hydraulics(&self) -> bool {
+        (self.is_towing || self.is_steering_demanded) && !self.failure.is_active()
+    }
+}
+impl SteeringController for A380BodyWheelSteeringControlUnit {
+    fn requested_position(&self) -> Angle {
+        self.requested_position
+    }
+}
+impl SimulationElement for A380BodyWheelSteeringControlUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.ground_speed = reader.read(&self.ground_speed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.engaged_id, self.is_engaged);
+        writer.write(&self.fault_id, self.failure.is_active());
+    }
+}
+
 struct A380BrakingForce {
     brake_left_force_factor_id: VariableIdentifier,
     brake_right_force_factor_id: VariableIdentifier,
@@ -3915,6 +4266,10 @@ struct A380BrakingForce {
     left_braking_force: f64,
     right_braking_force: f64,
 
+    // Braking forces corrected with the state of the wheels, which are generated in the simulator
+    left_force_factor: f64,
+    right_force_factor: f64,
+
     flap_position: f64,
 
     is_chocks_enabled: bool,
@@ -3943,6 +4298,9 @@ impl A380BrakingForce {
             left_braking_force: 0.,
             right_braking_force: 0.,
 
+            left_force_factor: 0.,
+            right_force_factor: 0.,
+
             flap_position: 0.,
 
             is_chocks_enabled: false,
@@ -3955,9 +4313,11 @@ impl A380BrakingForce {
         context: &UpdateContext,
         norm_brakes: &BrakeCircuit,
         altn_brakes: &BrakeCircuit,
+        emergency_brakes: &[LocalAccumulatorBrake; 4],
         engine1: &impl Engine,
         engine2: &impl Engine,
         pushback_tug: &PushbackTug,
+        main_gear_wheels: &MainGearWheels<16>,
     ) {
         // Base formula for output force is output_force[0:1] = 50 * sqrt(current_pressure) / Max_brake_pressure
         // This formula gives a bit more punch for lower brake pressures (like 1000 psi alternate braking), as linear formula
@@ -3967,21 +4327,60 @@ impl A380BrakingForce {
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
         let left_force_altn = 50. * altn_brakes.left_brake_pressure().get::<psi>().sqrt()
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
-        self.left_braking_force = left_force_norm + left_force_altn;
+        let left_force_emer = 50.
+            * Self::mean_emergency_brake_pressure(emergency_brakes, true)
+                .get::<psi>()
+                .sqrt()
+            / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
+        self.left_braking_force = left_force_norm + left_force_altn + left_force_emer;
         self.left_braking_force = self.left_braking_force.max(0.).min(1.);
 
         let right_force_norm = 50. * norm_brakes.right_brake_pressure().get::<psi>().sqrt()
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
         let right_force_altn = 50. * altn_brakes.right_brake_pressure().get::<psi>().sqrt()
             / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
-        self.right_braking_force = right_force_norm + right_force_altn;
+        let right_force_emer = 50.
+            * Self::mean_emergency_brake_pressure(emergency_brakes, false)
+                .get::<psi>()
+                .sqrt()
+            / Self::REFERENCE_PRESSURE_FOR_MAX_FORCE;
+        self.right_braking_force = right_force_norm + right_force_altn + right_force_emer;
         self.right_braking_force = self.right_braking_force.max(0.).min(1.);
 
         self.correct_with_flaps_state(context);
 
+        self.left_force_factor = main_gear_wheels
+            .left_braking_force(self.left_braking_force())
+            .get::<ratio>();
+        self.right_force_factor = main_gear_wheels
+            .right_braking_force(self.right_braking_force())
+            .get::<ratio>();
+
         self.update_chocks_braking(context, engine1, engine2, pushback_tug);
     }
 
+    fn mean_emergency_brake_pressure(
+        emergency_brakes: &[LocalAccumulatorBrake; 4],
+        is_left: bool,
+    ) -> Pressure {
+        // Two of the four emergency brakes are on each side
+        emergency_brakes
+            .iter()
+            .filter(|brake| brake.is_left() == is_left)
+            .fold(Pressure::default(), |sum, brake| {
+                sum + brake.brake_pressure()
+            })
+            / 2.
+    }
+
+    fn left_braking_force(&self) -> Ratio {
+        Ratio::new::<ratio>(self.left_braking_force)
+    }
+
+    fn right_braking_force(&self) -> Ratio {
+        Ratio::new::<ratio>(self.right_braking_force)
+    }
+
     fn correct_with_flaps_state(&mut self, context: &UpdateContext) {
         let flap_correction = Ratio::new::<percent>(interpolation(
             &Self::FLAPS_BREAKPOINTS,
@@ -4020,6 +4419,8 @@ impl A380BrakingForce {
         if self.is_chocks_enabled && chocks_on_wheels {
             self.left_braking_force = 1.;
             self.right_braking_force = 1.;
+            self.left_force_factor = 1.;
+            self.right_force_factor = 1.;
         }
     }
 }
@@ -4027,8 +4428,8 @@ impl A380BrakingForce {
 impl SimulationElement for A380BrakingForce {
     fn write(&self, writer: &mut SimulatorWriter) {
         // BRAKE XXXX FORCE FACTOR is the actual braking force we want the plane to generate in the simulator
-        writer.write(&self.brake_left_force_factor_id, self.left_braking_force);
-        writer.write(&self.brake_right_force_factor_id, self.right_braking_force);
+        writer.write(&self.brake_left_force_factor_id, self.left_force_factor);
+        writer.write(&self.brake_right_force_factor_id, self.right_force_factor);
     }
 
     fn read(&mut self, reader: &mut SimulatorReader) {
@@ -4054,6 +4455,8 @@ pub struct A380AutobrakeController {
     external_disarm_event_id: VariableIdentifier,
 
     deceleration_governor: AutobrakeDecelerationGovernor,
+    runway_overrun_protection: RunwayOverrunProtection,
+    btv: BrakeToVacate,
 
     target: Acceleration,
     mode: AutobrakeMode,
@@ -4081,6 +4484,10 @@ impl A380AutobrakeController {
     const MED_MODE_DECEL_PROFILE_TIME_S: [f64; 5] = [0., 1.99, 2., 2.5, 4.];
 
     const MAX_MODE_DECEL_TARGET_MS2: f64 = -6.;
+
+    // BTV waits for the nose gear to be down before braking towards the selected exit
+    const BTV_MODE_BRAKING_DELAY_S: f64 = 2.;
+
     const OFF_MODE_DECEL_TARGET_MS2: f64 = 5.;
 
     const MARGIN_PERCENT_TO_TARGET_TO_SHOW_DECEL_IN_LO_MED: f64 = 80.;
@@ -4101,6 +4508,11 @@ impl A380AutobrakeController {
             external_disarm_event_id: context.get_identifier("AUTOBRAKE_DISARM".to_owned()),
 
             deceleration_governor: AutobrakeDecelerationGovernor::new(),
+            runway_overrun_protection: RunwayOverrunProtection::new(
+                context,
+                Force::new::<newton>(16. * A380Hydraulic::MAX_BRAKING_FORCE_PER_BRAKE_N),
+            ),
+            btv: BrakeToVacate::new(context),
             target: Acceleration::new::<meter_per_second_squared>(0.),
             mode: AutobrakeMode::NONE,
             arming_is_allowed_by_bcu: context.is_in_flight(),
@@ -4133,7 +4545,9 @@ impl A380AutobrakeController {
         context: &UpdateContext,
         autobrake_panel: &AutobrakePanel,
     ) -> AutobrakeMode {
-        if self.should_disarm(context) {
+        if self.should_disarm(context)
+            || (self.mode == AutobrakeMode::BTV && !self.btv.exit_is_selected())
+        {
             AutobrakeMode::NONE
         } else {
             match autobrake_panel.pressed_mode() {
@@ -4160,7 +4574,7 @@ impl A380AutobrakeController {
     fn is_decelerating(&self) -> bool {
         match self.mode {
             AutobrakeMode::NONE => false,
-            AutobrakeMode::LOW | AutobrakeMode::MED => {
+            AutobrakeMode::LOW | AutobrakeMode::MED | AutobrakeMode::BTV => {
                 self.deceleration_demanded()
                     && self
                         .deceleration_governor
@@ -4187,7 +4601,7 @@ impl A380AutobrakeController {
     fn should_disarm_due_to_pedal_input(&self) -> bool {
         match self.mode {
             AutobrakeMode::NONE => false,
-            AutobrakeMode::LOW | AutobrakeMode::MED => {
+            AutobrakeMode::LOW | AutobrakeMode::MED | AutobrakeMode::BTV => {
                 self.left_brake_pedal_input > Ratio::new::<percent>(53.)
                     || self.right_brake_pedal_input > Ratio::new::<percent>(53.)
                     || (self.left_brake_pedal_input > Ratio::new::<percent>(11.)
@@ -4215,7 +4629,24 @@ impl A380AutobrakeController {
                 && self.should_reject_max_mode_after_time_in_flight.output())
     }
 
+    /// Steady deceleration the armed mode settles to once its profile is complete
+    fn armed_mode_deceleration(&self) -> Option<Acceleration> {
+        let deceleration_ms2 = match self.mode {
+            AutobrakeMode::LOW => Self::LOW_MODE_DECEL_PROFILE_ACCEL_MS2.last().copied(),
+            AutobrakeMode::MED => Self::MED_MODE_DECEL_PROFILE_ACCEL_MS2.last().copied(),
+            AutobrakeMode::MAX => Some(Self::MAX_MODE_DECEL_TARGET_MS2),
+            AutobrakeMode::BTV => Some(-BrakeToVacate::MAX_DECELERATION_MS2),
+            _ => None,
+        };
+
+        deceleration_ms2.map(Acceleration::new::<meter_per_second_squared>)
+    }
+
     fn calculate_target(&mut self) -> Acceleration {
+        if self.runway_overrun_protection.rop_is_active() {
+            return Acceleration::new::<meter_per_second_squared>(Self::MAX_MODE_DECEL_TARGET_MS2);
+        }
+
         Acceleration::new::<meter_per_second_squared>(match self.mode {
             AutobrakeMode::NONE => Self::OFF_MODE_DECEL_TARGET_MS2,
             AutobrakeMode::LOW => interpolation(
@@ -4229,6 +4660,16 @@ impl A380AutobrakeController {
                 self.deceleration_governor.time_engaged().as_secs_f64(),
             ),
             AutobrakeMode::MAX => Self::MAX_MODE_DECEL_TARGET_MS2,
+            AutobrakeMode::BTV => {
+                if self.deceleration_governor.time_engaged()
+                    < Duration::from_secs_f64(Self::BTV_MODE_BRAKING_DELAY_S)
+                    || self.btv.has_reached_exit_speed()
+                {
+                    Self::OFF_MODE_DECEL_TARGET_MS2
+                } else {
+                    self.btv.target().get::<meter_per_second_squared>()
+                }
+            }
             _ => Self::OFF_MODE_DECEL_TARGET_MS2,
         })
     }
@@ -4239,6 +4680,7 @@ impl A380AutobrakeController {
         allow_arming: bool,
         pedal_input_left: Ratio,
         pedal_input_right: Ratio,
+        ground_speed: Velocity,
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
     ) {
@@ -4251,6 +4693,13 @@ impl A380AutobrakeController {
             .update(context, in_flight_lgciu1 && in_flight_lgciu2);
         self.should_reject_max_mode_after_time_in_flight
             .update(context, in_flight_lgciu1 && in_flight_lgciu2);
+        self.btv.update(
+            context,
+            ground_speed,
+            !(in_flight_lgciu1 && in_flight_lgciu2),
+            self.runway_overrun_protection
+                .distance_from_runway_threshold(),
+        );
 
         self.arming_is_allowed_by_bcu = allow_arming;
         self.left_brake_pedal_input = pedal_input_left;
@@ -4264,6 +4713,7 @@ impl A380AutobrakeController {
         allow_arming: bool,
         pedal_input_left: Ratio,
         pedal_input_right: Ratio,
+        ground_speed: Velocity,
         lgciu1: &impl LgciuInterface,
         lgciu2: &impl LgciuInterface,
     ) {
@@ -4272,6 +4722,7 @@ impl A380AutobrakeController {
             allow_arming,
             pedal_input_left,
             pedal_input_right,
+            ground_speed,
             lgciu1,
             lgciu2,
         );
@@ -4280,11 +4731,26 @@ impl A380AutobrakeController {
         self.deceleration_governor
             .engage_when(self.should_engage_deceleration_governor(context));
 
+        self.runway_overrun_protection.update(
+            context,
+            ground_speed,
+            self.armed_mode_deceleration(),
+            self.deceleration_governor.is_engaged(),
+            self.deceleration_governor.acceleration(),
+        );
+
         self.target = self.calculate_target();
         self.deceleration_governor.update(context, self.target);
     }
 }
 impl SimulationElement for A380AutobrakeController {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.btv.accept(visitor);
+        self.runway_overrun_protection.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.armed_mode_id, self.mode as u8 as f64);
         writer.write(&self.armed_mode_id_set, -1.);
@@ -6414,11 +6880,17 @@ mod tests {
                 test::TestElectricitySource, ElectricalBus, Electricity, ElectricitySource,
                 ExternalPowerSource,
             },
-            engine::{leap_engine::LeapEngine, EngineFireOverheadPanel},
+            engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
             failures::FailureType,
             hydraulic::cargo_doors::{DoorControlState, HydraulicDoorController},
-            landing_gear::{GearSystemState, LandingGear, LandingGearControlInterfaceUnitSet},
-            shared::{EmergencyElectricalState, LgciuId, PotentialOrigin},
+            landing_gear::{
+                wheel::SurfaceCondition, GearSystemState, LandingGear,
+                LandingGearControlInterfaceUnitSet,
+            },
+            shared::{
+                arinc429::{Arinc429Word, SignStatus},
+                EmergencyElectricalState, LgciuId, PotentialOrigin,
+            },
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
                 Aircraft, InitContext,
@@ -6430,15 +6902,57 @@ mod tests {
             electric_potential::volt,
             length::foot,
             ratio::{percent, ratio},
+            velocity::{foot_per_second, meter_per_second},
         };
 
         #[derive(Default)]
         struct A380TestAdirus {
             airspeed: Velocity,
+            ground_speed: Velocity,
         }
         impl A380TestAdirus {
             fn update(&mut self, context: &UpdateContext) {
-                self.airspeed = context.true_airspeed()
+                self.airspeed = context.true_airspeed();
+                self.ground_speed = Velocity::new::<meter_per_second>(
+                    context.local_velocity().to_ms_vector()[2].abs(),
+                );
+            }
+        }
+        impl AdirsMeasurementOutputs for A380TestAdirus {
+            fn is_fully_aligned(&self, _: usize) -> bool {
+                true
+            }
+
+            fn latitude(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn longitude(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn heading(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn true_heading(&self, _: usize) -> Arinc429Word<Angle> {
+                Arinc429Word::new(Angle::default(), SignStatus::NoComputedData)
+            }
+
+            fn vertical_speed(&self, _: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(Velocity::default(), SignStatus::NoComputedData)
+            }
+
+            fn altitude(&self, _: usize) -> Arinc429Word<Length> {
+                Arinc429Word::new(Length::default(), SignStatus::NoComputedData)
+            }
+
+            fn computed_airspeed(&self, _: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(self.airspeed, SignStatus::NormalOperation)
+            }
+
+            fn ground_speed(&self, _: usize) -> Arinc429Word<Velocity> {
+                Arinc429Word::new(self.ground_speed, SignStatus::NormalOperation)
             }
         }
         impl AdirsDiscreteOutputs for A380TestAdirus {
@@ -6520,10 +7034,10 @@ mod tests {
         }
         struct A380HydraulicsTestAircraft {
             pneumatics: A380TestPneumatics,
-            engine_1: LeapEngine,
-            engine_2: LeapEngine,
-            engine_3: LeapEngine,
-            engine_4: LeapEngine,
+            engine_1: TrentEngine,
+            engine_2: TrentEngine,
+            engine_3: TrentEngine,
+            engine_4: TrentEngine,
             hydraulics: A380Hydraulic,
             overhead: A380HydraulicOverheadPanel,
             autobrake_panel: AutobrakePanel,
@@ -6563,13 +7077,13 @@ mod tests {
             fn new(context: &mut InitContext) -> Self {
                 Self {
                     pneumatics: A380TestPneumatics::new(),
-                    engine_1: LeapEngine::new(context, 1),
-                    engine_2: LeapEngine::new(context, 2),
-                    engine_3: LeapEngine::new(context, 3),
-                    engine_4: LeapEngine::new(context, 4),
+                    engine_1: TrentEngine::new(context, 1),
+                    engine_2: TrentEngine::new(context, 2),
+                    engine_3: TrentEngine::new(context, 3),
+                    engine_4: TrentEngine::new(context, 4),
                     hydraulics: A380Hydraulic::new(context),
                     overhead: A380HydraulicOverheadPanel::new(context),
-                    autobrake_panel: AutobrakePanel::new(context),
+                    autobrake_panel: AutobrakePanel::new_with_btv(context),
                     engine_fire_overhead: EngineFireOverheadPanel::new(context),
                     landing_gear: LandingGear::new(context),
                     lgcius: LandingGearControlInterfaceUnitSet::new(
@@ -6579,7 +7093,7 @@ mod tests {
                     ),
                     adirus: A380TestAdirus::default(),
                     electrical: A380TestElectrical::new(),
-                    ext_pwr: ExternalPowerSource::new(context),
+                    ext_pwr: ExternalPowerSource::new(context, 1),
                     powered_source_ac: TestElectricitySource::powered(
                         context,
                         PotentialOrigin::EngineGenerator(1),
@@ -6937,6 +7451,28 @@ mod tests {
                 self.read_by_name("HYD_BRAKE_NORM_RIGHT_PRESS")
             }
 
+            fn get_emergency_brake_pressure(&mut self, id: &str) -> Pressure {
+                self.read_by_name(&format!("HYD_BRAKE_EMER_{}_PRESS", id))
+            }
+
+            fn is_main_wheel_locked(&self, number: usize) -> bool {
+                self.query(|a| a.hydraulics.main_gear_wheels.wheel(number).is_locked())
+            }
+
+            fn is_anti_skid_releasing(&self, number: usize) -> bool {
+                self.query(|a| {
+                    a.hydraulics
+                        .brake_steer_computer
+                        .anti_skid
+                        .is_releasing(number)
+                })
+            }
+
+            fn brake_mode(&mut self) -> BrakeMode {
+                ReadByName::<A380HydraulicsTestBed, f64>::read_by_name(self, "HYD_BRAKE_MODE")
+                    .into()
+            }
+
             fn _get_brake_yellow_accumulator_pressure(&mut self) -> Pressure {
                 self.read_by_name("HYD_BRAKE_ALTN_ACC_PRESS")
             }
@@ -7041,14 +7577,30 @@ mod tests {
                 Ratio::new::<ratio>(self.read_by_name("NOSE_WHEEL_POSITION_RATIO"))
             }
 
-            fn _is_fire_valve_eng1_closed(&mut self) -> bool {
-                !ReadByName::<A380HydraulicsTestBed, bool>::read_by_name(
-                    self,
-                    "HYD_GREEN_PUMP_1_FIRE_VALVE_OPENED",
-                ) && !self.query(|a| a.hydraulics.green_circuit.is_fire_shutoff_valve_open(0))
+            fn get_left_body_wheel_steering_ratio(&mut self) -> Ratio {
+                Ratio::new::<ratio>(self.read_by_name("LEFT_BODY_WHEEL_POSITION_RATIO"))
             }
 
-            fn _is_fire_valve_eng2_closed(&mut self) -> bool {
+            fn get_right_body_wheel_steering_ratio(&mut self) -> Ratio {
+                Ratio::new::<ratio>(self.read_by_name("RIGHT_BODY_WHEEL_POSITION_RATIO"))
+            }
+
+            fn is_body_wheel_steering_engaged(&mut self) -> bool {
+                self.read_by_name("BODY_WHEEL_STEERING_ENGAGED")
+            }
+
+            fn is_body_wheel_steering_fault(&mut self) -> bool {
+                self.read_by_name("BODY_WHEEL_STEERING_FAULT")
+            }
+
+            fn _is_fire_valve_eng1_closed(&mut self) -> bool {
+                !ReadByName::<A380HydraulicsTestBed, bool>::read_by_name(
+                    self,
+                    "HYD_GREEN_PUMP_1_FIRE_VALVE_OPENED",
+                ) && !self.query(|a| a.hydraulics.green_circuit.is_fire_shutoff_valve_open(0))
+            }
+
+            fn _is_fire_valve_eng2_closed(&mut self) -> bool {
                 !ReadByName::<A380HydraulicsTestBed, bool>::read_by_name(
                     self,
                     "HYD_YELLOW_PUMP_1_FIRE_VALVE_OPENED",
@@ -7075,6 +7627,11 @@ mod tests {
                 self
             }
 
+            fn rolling_at(mut self, ground_speed: Velocity) -> Self {
+                self.write_by_name("VELOCITY BODY Z", ground_speed.get::<foot_per_second>());
+                self
+            }
+
             fn on_the_ground_after_touchdown(mut self) -> Self {
                 self.set_indicated_altitude(Length::new::<foot>(0.));
                 self.set_on_ground(true);
@@ -7165,70 +7722,70 @@ mod tests {
 
             fn start_eng1(mut self, n2: Ratio) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
-                self.write_by_name("ENGINE_N2:1", n2);
+                self.write_by_name("ENGINE_N3:1", n2);
 
                 self
             }
 
             fn start_eng2(mut self, n2: Ratio) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
-                self.write_by_name("ENGINE_N2:2", n2);
+                self.write_by_name("ENGINE_N3:2", n2);
 
                 self
             }
 
             fn start_eng3(mut self, n2: Ratio) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
-                self.write_by_name("ENGINE_N2:3", n2);
+                self.write_by_name("ENGINE_N3:3", n2);
 
                 self
             }
 
             fn start_eng4(mut self, n2: Ratio) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
-                self.write_by_name("ENGINE_N2:4", n2);
+                self.write_by_name("ENGINE_N3:4", n2);
 
                 self
             }
 
             fn stop_eng1(mut self) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
-                self.write_by_name("ENGINE_N2:1", 0.);
+                self.write_by_name("ENGINE_N3:1", 0.);
 
                 self
             }
 
             fn _stopping_eng1(mut self) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
-                self.write_by_name("ENGINE_N2:1", 25.);
+                self.write_by_name("ENGINE_N3:1", 25.);
 
                 self
             }
 
             fn stop_eng2(mut self) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
-                self.write_by_name("ENGINE_N2:2", 0.);
+                self.write_by_name("ENGINE_N3:2", 0.);
 
                 self
             }
 
             fn _stopping_eng2(mut self) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
-                self.write_by_name("ENGINE_N2:2", 25.);
+                self.write_by_name("ENGINE_N3:2", 25.);
 
                 self
             }
 
             fn stop_eng3(mut self) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:3", false);
-                self.write_by_name("ENGINE_N2:2", 0.);
+                self.write_by_name("ENGINE_N3:3", 0.);
 
                 self
             }
 
             fn stop_eng4(mut self) -> Self {
                 self.write_by_name("GENERAL ENG STARTER ACTIVE:4", false);
-                self.write_by_name("ENGINE_N2:2", 0.);
+                self.write_by_name("ENGINE_N3:4", 0.);
 
                 self
             }
@@ -7257,6 +7814,11 @@ mod tests {
                 self
             }
 
+            fn set_surface_condition(mut self, surface_condition: SurfaceCondition) -> Self {
+                self.write_by_name("SURFACE CONDITION", surface_condition);
+                self
+            }
+
             fn set_yellow_e_pump_a(mut self, is_on: bool) -> Self {
                 self.write_by_name("OVHD_HYD_EPUMPYA_ON_PB_IS_AUTO", !is_on);
                 self
@@ -7434,6 +7996,21 @@ mod tests {
                 self
             }
 
+            fn set_autobrake_btv_with_set_variable(mut self) -> Self {
+                self.write_by_name("AUTOBRAKES_ARMED_MODE_SET", 6);
+                self
+            }
+
+            fn set_btv_exit_distance(mut self, distance: Length) -> Self {
+                self.write_by_name("BTV_EXIT_DISTANCE", distance.get::<meter>());
+                self
+            }
+
+            fn set_ground_speed(mut self, ground_speed: Velocity) -> Self {
+                self.write_by_name("GPS GROUND SPEED", ground_speed);
+                self
+            }
+
             fn set_autobrake_low(mut self) -> Self {
                 self.write_by_name("OVHD_AUTOBRK_LOW_ON_IS_PRESSED", true);
                 self = self.run_one_tick();
@@ -7455,6 +8032,13 @@ mod tests {
                 self
             }
 
+            fn set_autobrake_btv(mut self) -> Self {
+                self.write_by_name("OVHD_AUTOBRK_BTV_ON_IS_PRESSED", true);
+                self = self.run_one_tick();
+                self.write_by_name("OVHD_AUTOBRK_BTV_ON_IS_PRESSED", false);
+                self
+            }
+
             fn set_left_spoiler_6_elec_backup_active(mut self) -> Self {
                 self.write_by_name("LEFT_SPOILER_6_EBHA_ELECTRONIC_ENABLE", true);
                 self
@@ -7465,6 +8049,32 @@ mod tests {
                 self
             }
 
+            fn set_landing_runway(mut self, length: Length) -> Self {
+                self.write_by_name("ROW_ROP_RUNWAY_LENGTH", length.get::<meter>());
+                self.write_by_name("ROW_ROP_THRESHOLD_LATITUDE", 0.);
+                self.write_by_name("ROW_ROP_THRESHOLD_LONGITUDE", 0.);
+                self.write_by_name("ROW_ROP_RUNWAY_HEADING", 90.);
+                self
+            }
+
+            fn rolling_on_runway(
+                mut self,
+                ground_speed: Velocity,
+                distance_from_threshold: Length,
+            ) -> Self {
+                self.write_by_name("GPS GROUND SPEED", ground_speed);
+                self.write_by_name("PLANE LATITUDE", 0.);
+                self.write_by_name(
+                    "PLANE LONGITUDE",
+                    Angle::new::<radian>(distance_from_threshold.get::<meter>() / 6_371_000.),
+                );
+                self
+            }
+
+            fn is_rop_active(&mut self) -> bool {
+                self.read_by_name("ROP_ACTIVE")
+            }
+
             fn set_deploy_ground_spoilers(mut self) -> Self {
                 self.write_by_name("SEC_1_GROUND_SPOILER_OUT", true);
                 self.write_by_name("SEC_2_GROUND_SPOILER_OUT", true);
@@ -9034,6 +9644,120 @@ mod tests {
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
         }
 
+        #[test]
+        fn emergency_braking_from_local_accumulators_when_green_and_yellow_lost() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .start_eng3(Ratio::new::<percent>(100.))
+                .start_eng4(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(15));
+
+            assert!(test_bed.brake_mode() == BrakeMode::Normal);
+
+            test_bed = test_bed
+                .stop_eng1()
+                .stop_eng2()
+                .stop_eng3()
+                .stop_eng4()
+                .run_waiting_for(Duration::from_secs(30));
+
+            assert!(!test_bed.is_green_pressure_switch_pressurised());
+            assert!(!test_bed.is_yellow_pressure_switch_pressurised());
+            assert!(test_bed.brake_mode() == BrakeMode::Emergency);
+
+            test_bed = test_bed
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            for id in ["LEFT_WING", "LEFT_BODY", "RIGHT_BODY", "RIGHT_WING"] {
+                assert!(test_bed.get_emergency_brake_pressure(id) > Pressure::new::<psi>(1000.));
+                assert!(test_bed.get_emergency_brake_pressure(id) < Pressure::new::<psi>(1200.));
+            }
+
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
+        }
+
+        #[test]
+        fn anti_skid_keeps_wheels_rolling_on_icy_runway() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .start_eng3(Ratio::new::<percent>(100.))
+                .start_eng4(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(15));
+
+            test_bed = test_bed
+                .rolling_at(Velocity::new::<knot>(100.))
+                .set_surface_condition(SurfaceCondition::Icy)
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.brake_mode() == BrakeMode::Normal);
+            assert!((1..=16).all(|number| !test_bed.is_main_wheel_locked(number)));
+        }
+
+        #[test]
+        fn wheels_lock_on_icy_runway_without_anti_skid() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .start_eng3(Ratio::new::<percent>(100.))
+                .start_eng4(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .set_anti_skid(false)
+                .run_waiting_for(Duration::from_secs(15));
+
+            test_bed = test_bed
+                .rolling_at(Velocity::new::<knot>(100.))
+                .set_surface_condition(SurfaceCondition::Icy)
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(!test_bed.is_anti_skid_releasing(1));
+            assert!((1..=16).all(|number| test_bed.is_main_wheel_locked(number)));
+        }
+
+        #[test]
+        fn no_emergency_braking_while_alternate_supply_available() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .start_eng3(Ratio::new::<percent>(100.))
+                .start_eng4(Ratio::new::<percent>(100.))
+                .set_park_brake(false)
+                .set_anti_skid(false)
+                .set_left_brake(Ratio::new::<percent>(100.))
+                .set_right_brake(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(15));
+
+            assert!(test_bed.brake_mode() == BrakeMode::AlternateWithoutAntiSkid);
+            assert!(test_bed.get_brake_left_yellow_pressure() > Pressure::new::<psi>(1000.));
+            assert!(test_bed.get_brake_left_yellow_pressure() < Pressure::new::<psi>(1200.));
+
+            for id in ["LEFT_WING", "LEFT_BODY", "RIGHT_BODY", "RIGHT_WING"] {
+                assert!(test_bed.get_emergency_brake_pressure(id) < Pressure::new::<psi>(50.));
+            }
+        }
+
         #[test]
         fn autobrakes_arms_in_flight_lo_or_med() {
             let mut test_bed = test_bed_on_ground_with()
@@ -9229,6 +9953,145 @@ mod tests {
             assert!(test_bed.get_brake_right_yellow_pressure() < Pressure::new::<psi>(50.));
         }
 
+        #[test]
+        fn autobrakes_btv_arms_only_with_selected_exit() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_autobrake_btv_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
+
+            test_bed = test_bed
+                .set_btv_exit_distance(Length::new::<meter>(2000.))
+                .set_autobrake_btv_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::BTV);
+        }
+
+        #[test]
+        fn autobrakes_btv_arms_from_panel() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_btv_exit_distance(Length::new::<meter>(2000.))
+                .set_autobrake_btv()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::BTV);
+
+            test_bed = test_bed
+                .set_autobrake_btv()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::NONE);
+        }
+
+        #[test]
+        fn autobrakes_btv_brakes_towards_selected_exit() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_btv_exit_distance(Length::new::<meter>(2000.))
+                .set_autobrake_btv_with_set_variable()
+                .run_waiting_for(Duration::from_secs(1));
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(120.))
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            // No braking until the nose gear is down
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::BTV);
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(1000.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(1000.));
+        }
+
+        #[test]
+        fn autobrakes_btv_releases_brakes_at_exit_speed() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            test_bed = test_bed
+                .set_btv_exit_distance(Length::new::<meter>(2000.))
+                .set_autobrake_btv_with_set_variable()
+                .set_ground_speed(Velocity::new::<knot>(120.))
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(6));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(1000.));
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(8.))
+                .run_waiting_for(Duration::from_secs(2));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::BTV);
+            assert!(test_bed.get_brake_left_green_pressure() < Pressure::new::<psi>(50.));
+            assert!(test_bed.get_brake_right_green_pressure() < Pressure::new::<psi>(50.));
+        }
+
+        #[test]
+        fn autobrakes_rop_commands_max_braking_when_low_would_overrun() {
+            let mut test_bed = test_bed_on_ground_with()
+                .set_cold_dark_inputs()
+                .on_the_ground()
+                .set_park_brake(false)
+                .start_eng1(Ratio::new::<percent>(100.))
+                .start_eng2(Ratio::new::<percent>(100.))
+                .run_waiting_for(Duration::from_secs(3));
+
+            test_bed = test_bed
+                .set_landing_runway(Length::new::<meter>(3000.))
+                .rolling_on_runway(Velocity::new::<knot>(120.), Length::new::<meter>(600.))
+                .set_autobrake_low()
+                .set_deploy_ground_spoilers()
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.autobrake_mode() == AutobrakeMode::LOW);
+            assert!(!test_bed.is_rop_active());
+
+            test_bed = test_bed
+                .rolling_on_runway(Velocity::new::<knot>(120.), Length::new::<meter>(2200.))
+                .run_waiting_for(Duration::from_secs(1));
+
+            assert!(test_bed.is_rop_active());
+
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(5));
+
+            assert!(test_bed.get_brake_left_green_pressure() > Pressure::new::<psi>(1000.));
+            assert!(test_bed.get_brake_right_green_pressure() > Pressure::new::<psi>(1000.));
+        }
+
         #[test]
         fn autobrakes_disengage_on_spoiler_retract() {
             let mut test_bed = test_bed_on_ground_with()
@@ -10033,6 +10896,162 @@ mod tests {
             assert!(test_bed.green_pressure_auxiliary() > Pressure::new::<psi>(2800.));
         }
 
+        #[test]
+        fn body_wheels_steer_opposite_to_nose_wheel_in_tight_turns() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(5.))
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.get_nose_steering_ratio() > Ratio::new::<ratio>(0.9));
+            assert!(test_bed.is_body_wheel_steering_engaged());
+            assert!(test_bed.get_left_body_wheel_steering_ratio() < Ratio::new::<ratio>(-0.5));
+            assert!(test_bed.get_right_body_wheel_steering_ratio() < Ratio::new::<ratio>(-0.5));
+
+            test_bed = test_bed
+                .set_tiller_demand(Ratio::new::<ratio>(-1.))
+                .run_waiting_for(Duration::from_secs(15));
+
+            assert!(test_bed.get_left_body_wheel_steering_ratio() > Ratio::new::<ratio>(0.5));
+            assert!(test_bed.get_right_body_wheel_steering_ratio() > Ratio::new::<ratio>(0.5));
+        }
+
+        #[test]
+        fn body_wheels_stay_centered_in_small_turns() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(5.))
+                .set_tiller_demand(Ratio::new::<ratio>(0.3))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.get_nose_steering_ratio() > Ratio::new::<ratio>(0.));
+            assert!(!test_bed.is_body_wheel_steering_engaged());
+            assert!(
+                test_bed
+                    .get_left_body_wheel_steering_ratio()
+                    .get::<ratio>()
+                    .abs()
+                    < 0.01
+            );
+            assert!(
+                test_bed
+                    .get_right_body_wheel_steering_ratio()
+                    .get::<ratio>()
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn body_wheels_stay_centered_above_ground_speed_threshold() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(30.))
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.get_nose_steering_ratio() > Ratio::new::<ratio>(0.5));
+            assert!(!test_bed.is_body_wheel_steering_engaged());
+            assert!(
+                test_bed
+                    .get_left_body_wheel_steering_ratio()
+                    .get::<ratio>()
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn body_wheel_steering_failure_centers_the_body_wheels() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .start_eng3(Ratio::new::<percent>(80.))
+                .start_eng4(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .set_ground_speed(Velocity::new::<knot>(5.))
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.get_left_body_wheel_steering_ratio() < Ratio::new::<ratio>(-0.5));
+            assert!(!test_bed.is_body_wheel_steering_fault());
+
+            test_bed.fail(FailureType::BodyWheelSteering);
+            test_bed = test_bed.run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.is_body_wheel_steering_fault());
+            assert!(!test_bed.is_body_wheel_steering_engaged());
+            assert!(
+                test_bed
+                    .get_left_body_wheel_steering_ratio()
+                    .get::<ratio>()
+                    .abs()
+                    < 0.01
+            );
+            assert!(
+                test_bed
+                    .get_right_body_wheel_steering_ratio()
+                    .get::<ratio>()
+                    .abs()
+                    < 0.01
+            );
+        }
+
+        #[test]
+        fn yellow_epump_pressurises_body_wheel_steering_without_yellow_engines() {
+            let mut test_bed = test_bed_on_ground_with()
+                .engines_off()
+                .on_the_ground()
+                .set_cold_dark_inputs()
+                .start_eng1(Ratio::new::<percent>(80.))
+                .start_eng2(Ratio::new::<percent>(80.))
+                .set_park_brake(false)
+                .run_waiting_for(Duration::from_secs(5));
+
+            test_bed = test_bed
+                .set_ground_speed(Velocity::new::<knot>(5.))
+                .set_tiller_demand(Ratio::new::<ratio>(1.))
+                .run_waiting_for(Duration::from_secs(10));
+
+            assert!(test_bed.yellow_pressure() >= Pressure::new::<psi>(2500.));
+            assert!(test_bed.is_body_wheel_steering_engaged());
+            assert!(test_bed.get_left_body_wheel_steering_ratio() < Ratio::new::<ratio>(-0.5));
+        }
+
         #[test]
         fn yellow_epump_buildup_system_section_when_pushback() {
             let mut test_bed = test_bed_on_ground_with()
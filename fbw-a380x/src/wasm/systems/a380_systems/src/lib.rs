@@ -4,6 +4,7 @@ mod air_conditioning;
 mod avionics_data_communication_network;
 mod control_display_system;
 mod electrical;
+mod failures;
 mod fuel;
 pub mod hydraulic;
 mod navigation;
@@ -14,13 +15,14 @@ use self::{
     air_conditioning::{A380AirConditioning, A380PressurizationOverheadPanel},
     avionics_data_communication_network::A380AvionicsDataCommunicationNetwork,
     control_display_system::A380ControlDisplaySystem,
-    fuel::A380Fuel,
+    fuel::{A380Fuel, A380FuelOverheadPanel},
     pneumatic::{A380Pneumatic, A380PneumaticOverheadPanel},
 };
 use electrical::{
     A380Electrical, A380ElectricalOverheadPanel, A380EmergencyElectricalOverheadPanel,
     APU_START_MOTOR_BUS_TYPE,
 };
+pub use failures::failures;
 use hydraulic::{A380Hydraulic, A380HydraulicOverheadPanel};
 use navigation::A380RadioAltimeters;
 use power_consumption::A380PowerConsumption;
@@ -29,18 +31,20 @@ use systems::simulation::InitContext;
 use uom::si::{f64::Length, length::nautical_mile};
 
 use systems::{
+    accept_iterable,
     apu::{
         Aps3200ApuGenerator, Aps3200StartMotor, AuxiliaryPowerUnit, AuxiliaryPowerUnitFactory,
         AuxiliaryPowerUnitFireOverheadPanel, AuxiliaryPowerUnitOverheadPanel,
     },
     electrical::{Electricity, ElectricitySource, ExternalPowerSource},
     engine::engine_wing_flex::EnginesFlexiblePhysics,
-    engine::{leap_engine::LeapEngine, EngineFireOverheadPanel},
+    engine::{trent_engine::TrentEngine, EngineFireOverheadPanel},
     hydraulic::brake_circuit::AutobrakePanel,
     landing_gear::{LandingGear, LandingGearControlInterfaceUnitSet},
     navigation::adirs::{
         AirDataInertialReferenceSystem, AirDataInertialReferenceSystemOverheadPanel,
     },
+    probe_heat::ProbeHeat,
     shared::ElectricalBusType,
     simulation::{Aircraft, SimulationElement, SimulationElementVisitor, UpdateContext},
 };
@@ -58,14 +62,16 @@ pub struct A380 {
     electrical_overhead: A380ElectricalOverheadPanel,
     emergency_electrical_overhead: A380EmergencyElectricalOverheadPanel,
     fuel: A380Fuel,
-    engine_1: LeapEngine,
-    engine_2: LeapEngine,
-    engine_3: LeapEngine,
-    engine_4: LeapEngine,
+    fuel_overhead: A380FuelOverheadPanel,
+    engine_1: TrentEngine,
+    engine_2: TrentEngine,
+    engine_3: TrentEngine,
+    engine_4: TrentEngine,
     engine_fire_overhead: EngineFireOverheadPanel<4>,
     electrical: A380Electrical,
     power_consumption: A380PowerConsumption,
-    ext_pwr: ExternalPowerSource,
+    probe_heat: ProbeHeat,
+    ext_pwrs: [ExternalPowerSource; 4],
     lgcius: LandingGearControlInterfaceUnitSet,
     hydraulic: A380Hydraulic,
     hydraulic_overhead: A380HydraulicOverheadPanel,
@@ -84,9 +90,9 @@ impl A380 {
             adirs: AirDataInertialReferenceSystem::new(context),
             adirs_overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
             air_conditioning: A380AirConditioning::new(context),
-            apu: AuxiliaryPowerUnitFactory::new_aps3200(
+            apu: AuxiliaryPowerUnitFactory::new_aps3200_with_generators(
                 context,
-                1,
+                2,
                 APU_START_MOTOR_BUS_TYPE,
                 ElectricalBusType::DirectCurrentBattery,
                 ElectricalBusType::DirectCurrentBattery,
@@ -98,14 +104,32 @@ impl A380 {
             electrical_overhead: A380ElectricalOverheadPanel::new(context),
             emergency_electrical_overhead: A380EmergencyElectricalOverheadPanel::new(context),
             fuel: A380Fuel::new(context),
-            engine_1: LeapEngine::new(context, 1),
-            engine_2: LeapEngine::new(context, 2),
-            engine_3: LeapEngine::new(context, 3),
-            engine_4: LeapEngine::new(context, 4),
+            fuel_overhead: A380FuelOverheadPanel::new(context),
+            engine_1: TrentEngine::new(context, 1),
+            engine_2: TrentEngine::new(context, 2),
+            engine_3: TrentEngine::new(context, 3),
+            engine_4: TrentEngine::new(context, 4),
             engine_fire_overhead: EngineFireOverheadPanel::new(context),
             electrical: A380Electrical::new(context),
             power_consumption: A380PowerConsumption::new(context),
-            ext_pwr: ExternalPowerSource::new(context),
+            probe_heat: ProbeHeat::new(
+                context,
+                [
+                    ElectricalBusType::DirectCurrentEssential,
+                    ElectricalBusType::DirectCurrent(2),
+                    ElectricalBusType::DirectCurrent(1),
+                ],
+                [
+                    ElectricalBusType::AlternatingCurrentEssential,
+                    ElectricalBusType::AlternatingCurrent(4),
+                    ElectricalBusType::AlternatingCurrent(1),
+                ],
+                [
+                    ElectricalBusType::AlternatingCurrent(1),
+                    ElectricalBusType::AlternatingCurrent(4),
+                ],
+            ),
+            ext_pwrs: [1, 2, 3, 4].map(|number| ExternalPowerSource::new(context, number)),
             lgcius: LandingGearControlInterfaceUnitSet::new(
                 context,
                 ElectricalBusType::DirectCurrentEssential,
@@ -113,7 +137,7 @@ impl A380 {
             ),
             hydraulic: A380Hydraulic::new(context),
             hydraulic_overhead: A380HydraulicOverheadPanel::new(context),
-            autobrake_panel: AutobrakePanel::new(context),
+            autobrake_panel: AutobrakePanel::new_with_btv(context),
             landing_gear: LandingGear::new(context),
             pneumatic: A380Pneumatic::new(context),
             radio_altimeters: A380RadioAltimeters::new(context),
@@ -142,6 +166,11 @@ impl Aircraft for A380 {
         context: &UpdateContext,
         electricity: &mut Electricity,
     ) {
+        self.engine_1.update(context);
+        self.engine_2.update(context);
+        self.engine_3.update(context);
+        self.engine_4.update(context);
+
         self.apu.update_before_electrical(
             context,
             &self.apu_overhead,
@@ -150,24 +179,32 @@ impl Aircraft for A380 {
             // This will be replaced when integrating the whole electrical system.
             // For now we use the same logic as found in the JavaScript code; ignoring whether or not
             // the engine generators are supplying electricity.
-            self.electrical_overhead.apu_generator_is_on()
-                && !(self.electrical_overhead.external_power_is_on()
-                    && self.electrical_overhead.external_power_is_available()),
+            (1..=2).any(|number| self.electrical_overhead.apu_generator_is_on(number))
+                && !(1..=4).all(|number| {
+                    self.electrical_overhead.external_power_is_on(number)
+                        && self.electrical_overhead.external_power_is_available(number)
+                }),
             self.pneumatic.apu_bleed_air_valve(),
-            self.fuel.left_inner_tank_has_fuel_remaining(),
+            self.fuel.apu_fuel_is_available(),
         );
 
         self.electrical.update(
             context,
             electricity,
-            &self.ext_pwr,
+            &self.ext_pwrs,
             &self.electrical_overhead,
             &self.emergency_electrical_overhead,
             &mut self.apu,
             &self.apu_overhead,
             &self.engine_fire_overhead,
-            [&self.engine_1, &self.engine_2],
+            [
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
+            ],
             self.lgcius.lgciu1(),
+            &self.air_conditioning,
         );
 
         self.electrical_overhead
@@ -181,11 +218,22 @@ impl Aircraft for A380 {
         self.apu_overhead.update_after_apu(&self.apu);
 
         self.adcn.update();
+        self.fuel.update(
+            context,
+            &self.fuel_overhead,
+            &self.engine_fire_overhead,
+            &self.apu_fire_overhead,
+            self.adcn.fuel_quantity_management_is_available(),
+        );
+        self.fuel_overhead.update_after_fuel(&self.fuel);
+
         self.lgcius.update(
             context,
             &self.landing_gear,
             self.hydraulic.gear_system(),
-            self.ext_pwr.output_potential().is_powered(),
+            self.ext_pwrs
+                .iter()
+                .any(|ext_pwr| ext_pwr.output_potential().is_powered()),
         );
 
         self.radio_altimeters.update(context);
@@ -213,7 +261,18 @@ impl Aircraft for A380 {
 
         self.hydraulic_overhead.update(&self.hydraulic);
 
-        self.adirs.update(context, &self.adirs_overhead);
+        self.probe_heat.update(
+            context,
+            [
+                &self.engine_1,
+                &self.engine_2,
+                &self.engine_3,
+                &self.engine_4,
+            ],
+        );
+
+        self.adirs
+            .update(context, &self.adirs_overhead, &self.probe_heat);
         self.adirs_overhead.update(context, &self.adirs);
 
         self.power_consumption.update(context);
@@ -252,7 +311,12 @@ impl Aircraft for A380 {
         self.engines_flex_physics.update(context);
         self.cds.update();
 
-        self.egpwc.update(&self.adirs, self.lgcius.lgciu1());
+        self.egpwc.update(
+            context,
+            &self.adirs,
+            self.lgcius.lgciu1(),
+            self.radio_altimeters.radio_altimeter_1(),
+        );
     }
 }
 impl SimulationElement for A380 {
@@ -267,6 +331,7 @@ impl SimulationElement for A380 {
         self.electrical_overhead.accept(visitor);
         self.emergency_electrical_overhead.accept(visitor);
         self.fuel.accept(visitor);
+        self.fuel_overhead.accept(visitor);
         self.pneumatic_overhead.accept(visitor);
         self.pressurization_overhead.accept(visitor);
         self.engine_1.accept(visitor);
@@ -276,7 +341,8 @@ impl SimulationElement for A380 {
         self.engine_fire_overhead.accept(visitor);
         self.electrical.accept(visitor);
         self.power_consumption.accept(visitor);
-        self.ext_pwr.accept(visitor);
+        self.probe_heat.accept(visitor);
+        accept_iterable!(self.ext_pwrs, visitor);
         self.lgcius.accept(visitor);
         self.radio_altimeters.accept(visitor);
         self.autobrake_panel.accept(visitor);
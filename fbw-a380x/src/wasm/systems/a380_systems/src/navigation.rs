@@ -1,7 +1,8 @@
 use systems::navigation::ala52b::{
     Ala52BAircraftInstallationDelay, Ala52BRadioAltimeter, Ala52BTransceiverPair,
 };
-use systems::navigation::radio_altimeter::AntennaInstallation;
+use systems::navigation::radio_altimeter::{AntennaInstallation, RadioAltimeter};
+use systems::shared::arinc429::Arinc429Word;
 use systems::shared::ElectricalBusType;
 use systems::simulation::{
     InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
@@ -74,6 +75,10 @@ impl A380RadioAltimeters {
         self.radio_altimeter_2.update(context);
         self.radio_altimeter_3.update(context);
     }
+
+    pub fn radio_altimeter_1(&self) -> &impl RadioAltimeter {
+        &self.radio_altimeter_1
+    }
 }
 
 impl SimulationElement for A380RadioAltimeters {
@@ -115,6 +120,12 @@ impl A380RadioAltimeter {
     }
 }
 
+impl RadioAltimeter for A380RadioAltimeter {
+    fn radio_altitude(&self) -> Arinc429Word<Length> {
+        self.radio_altimeter.radio_altitude()
+    }
+}
+
 impl SimulationElement for A380RadioAltimeter {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
         self.transceivers.accept(visitor);
@@ -12,6 +12,7 @@ use uom::si::{
 use systems::{
     accept_iterable,
     air_conditioning::PackFlowControllers,
+    engine::fadec::FullAuthorityDigitalEngineControl,
     overhead::{AutoOffFaultPushButton, OnOffFaultPushButton},
     pneumatic::{
         valve::*, BleedMonitoringComputerIsAliveSignal, CompressionChamber,
@@ -24,12 +25,12 @@ use systems::{
     shared::{
         pid::PidController, update_iterator::MaxStepLoop, ControllerSignal, ElectricalBusType,
         ElectricalBuses, EngineBleedPushbutton, EngineCorrectedN1, EngineCorrectedN2,
-        EngineFirePushButtons, EngineStartState, HydraulicColor, PackFlowValveState,
-        PneumaticBleed, PneumaticValve, ReservoirAirPressure,
+        EngineFirePushButtons, EngineStartState, EngineUncorrectedN2, HydraulicColor,
+        PackFlowValveState, PneumaticBleed, PneumaticValve, ReservoirAirPressure,
     },
     simulation::{
-        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
+        VariableIdentifier, Write,
     },
 };
 
@@ -114,7 +115,7 @@ pub struct A380Pneumatic {
 
     cross_bleed_valves: [CrossBleedValve; 3],
 
-    fadec: FullAuthorityDigitalEngineControl,
+    fadec: FullAuthorityDigitalEngineControl<4>,
     engine_starter_valve_controllers: [EngineStarterValveController; 4],
 
     apu_compression_chamber: CompressionChamber,
@@ -199,12 +200,14 @@ impl A380Pneumatic {
     pub(crate) fn update(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 4],
+        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2 + EngineUncorrectedN2); 4],
         pneumatic_overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
         pack_flow_valve_signals: &impl PackFlowControllers<4>,
     ) {
+        self.fadec.update(context, engines);
+
         self.physics_updater.update(context);
 
         for cur_time_step in self.physics_updater {
@@ -222,7 +225,7 @@ impl A380Pneumatic {
     pub(crate) fn update_physics(
         &mut self,
         context: &UpdateContext,
-        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2); 4],
+        engines: [&(impl EngineCorrectedN1 + EngineCorrectedN2 + EngineUncorrectedN2); 4],
         overhead_panel: &A380PneumaticOverheadPanel,
         engine_fire_push_buttons: &impl EngineFirePushButtons,
         apu: &impl ControllerSignal<TargetPressureTemperatureSignal>,
@@ -419,13 +422,14 @@ impl ReservoirAirPressure for A380Pneumatic {
 
 struct EngineStarterValveController {
     number: usize,
-    engine_state: EngineState,
+    should_open: bool,
 }
 impl ControllerSignal<EngineStarterValveSignal> for EngineStarterValveController {
     fn signal(&self) -> Option<EngineStarterValveSignal> {
-        match self.engine_state {
-            EngineState::Starting => Some(EngineStarterValveSignal::new_open()),
-            _ => Some(EngineStarterValveSignal::new_closed()),
+        if self.should_open {
+            Some(EngineStarterValveSignal::new_open())
+        } else {
+            Some(EngineStarterValveSignal::new_closed())
         }
     }
 }
@@ -433,12 +437,12 @@ impl EngineStarterValveController {
     fn new(number: usize) -> Self {
         Self {
             number,
-            engine_state: EngineState::Off,
+            should_open: false,
         }
     }
 
-    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl) {
-        self.engine_state = fadec.engine_state(self.number);
+    fn update(&mut self, fadec: &FullAuthorityDigitalEngineControl<4>) {
+        self.should_open = fadec.starter_valve_should_open(self.number);
     }
 }
 
@@ -1110,62 +1114,6 @@ impl SimulationElement for A380PneumaticOverheadPanel {
     }
 }
 
-/// We use this simply as an interface to engine parameter simvars. It should probably not be part of the pneumatic system.
-pub struct FullAuthorityDigitalEngineControl {
-    engine_1_state_id: VariableIdentifier,
-    engine_2_state_id: VariableIdentifier,
-    engine_3_state_id: VariableIdentifier,
-    engine_4_state_id: VariableIdentifier,
-
-    engine_1_state: EngineState,
-    engine_2_state: EngineState,
-    engine_3_state: EngineState,
-    engine_4_state: EngineState,
-
-    engine_mode_selector1_id: VariableIdentifier,
-    engine_mode_selector1_position: EngineModeSelector,
-}
-impl FullAuthorityDigitalEngineControl {
-    fn new(context: &mut InitContext) -> Self {
-        Self {
-            engine_1_state_id: context.get_identifier("ENGINE_STATE:1".to_owned()),
-            engine_2_state_id: context.get_identifier("ENGINE_STATE:2".to_owned()),
-            engine_3_state_id: context.get_identifier("ENGINE_STATE:3".to_owned()),
-            engine_4_state_id: context.get_identifier("ENGINE_STATE:4".to_owned()),
-            engine_1_state: EngineState::Off,
-            engine_2_state: EngineState::Off,
-            engine_3_state: EngineState::Off,
-            engine_4_state: EngineState::Off,
-            engine_mode_selector1_id: context
-                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
-            engine_mode_selector1_position: EngineModeSelector::Norm,
-        }
-    }
-
-    fn engine_state(&self, number: usize) -> EngineState {
-        match number {
-            1 => self.engine_1_state,
-            2 => self.engine_2_state,
-            3 => self.engine_3_state,
-            4 => self.engine_4_state,
-            _ => panic!("Invalid engine number"),
-        }
-    }
-
-    fn engine_mode_selector(&self) -> EngineModeSelector {
-        self.engine_mode_selector1_position
-    }
-}
-impl SimulationElement for FullAuthorityDigitalEngineControl {
-    fn read(&mut self, reader: &mut SimulatorReader) {
-        self.engine_1_state = reader.read(&self.engine_1_state_id);
-        self.engine_2_state = reader.read(&self.engine_2_state_id);
-        self.engine_3_state = reader.read(&self.engine_3_state_id);
-        self.engine_4_state = reader.read(&self.engine_4_state_id);
-        self.engine_mode_selector1_position = reader.read(&self.engine_mode_selector1_id);
-    }
-}
-
 /// A struct to hold all the pack related components
 pub struct PackComplex {
     pack_number: usize,
@@ -1402,11 +1350,11 @@ mod tests {
             AdirsToAirCondInterface, PackFlowControllers, ZoneType,
         },
         electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
-        engine::leap_engine::LeapEngine,
+        engine::trent_engine::TrentEngine,
         failures::FailureType,
         pneumatic::{
-            ControllablePneumaticValve, CrossBleedValveSelectorMode, EngineState,
-            PneumaticContainer, PneumaticValveSignal, TargetPressureTemperatureSignal,
+            ControllablePneumaticValve, CrossBleedValveSelectorMode, EngineModeSelector,
+            EngineState, PneumaticContainer, PneumaticValveSignal, TargetPressureTemperatureSignal,
         },
         shared::{
             arinc429::{Arinc429Word, SignStatus},
@@ -1692,10 +1640,10 @@ mod tests {
         air_conditioning: TestAirConditioning,
         lgciu: TestLgciu,
         apu: TestApu,
-        engine_1: LeapEngine,
-        engine_2: LeapEngine,
-        engine_3: LeapEngine,
-        engine_4: LeapEngine,
+        engine_1: TrentEngine,
+        engine_2: TrentEngine,
+        engine_3: TrentEngine,
+        engine_4: TrentEngine,
         pneumatic_overhead_panel: A380PneumaticOverheadPanel,
         fire_pushbuttons: TestEngineFirePushButtons,
         electrical: A380TestElectrical,
@@ -1719,10 +1667,10 @@ mod tests {
                 air_conditioning: TestAirConditioning::new(context),
                 lgciu: TestLgciu::new(true),
                 apu: TestApu::new(),
-                engine_1: LeapEngine::new(context, 1),
-                engine_2: LeapEngine::new(context, 2),
-                engine_3: LeapEngine::new(context, 3),
-                engine_4: LeapEngine::new(context, 4),
+                engine_1: TrentEngine::new(context, 1),
+                engine_2: TrentEngine::new(context, 2),
+                engine_3: TrentEngine::new(context, 3),
+                engine_4: TrentEngine::new(context, 4),
                 pneumatic_overhead_panel: A380PneumaticOverheadPanel::new(context),
                 fire_pushbuttons: TestEngineFirePushButtons::new(),
                 electrical: A380TestElectrical::new(),
@@ -1884,7 +1832,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N3:1", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1893,7 +1841,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N3:2", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1902,7 +1850,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N3:3", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1911,7 +1859,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.55));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.2));
-            self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N3:4", Ratio::new::<ratio>(0.55));
 
             self
         }
@@ -1920,7 +1868,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:1", EngineState::On);
+            self.write_by_name("ENGINE_N3:1", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1929,7 +1877,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:2", EngineState::On);
+            self.write_by_name("ENGINE_N3:2", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1938,7 +1886,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:3", EngineState::On);
+            self.write_by_name("ENGINE_N3:3", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1947,7 +1895,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.65));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.5));
-            self.write_by_name("ENGINE_STATE:4", EngineState::On);
+            self.write_by_name("ENGINE_N3:4", Ratio::new::<ratio>(0.65));
 
             self
         }
@@ -1956,7 +1904,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
             self.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:1", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:1", EngineState::Off);
+            self.write_by_name("ENGINE_N3:1", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -1965,7 +1913,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", false);
             self.write_by_name("TURB ENG CORRECTED N2:2", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:2", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:2", EngineState::Off);
+            self.write_by_name("ENGINE_N3:2", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -1974,7 +1922,7 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", false);
             self.write_by_name("TURB ENG CORRECTED N2:3", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:3", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:3", EngineState::Off);
+            self.write_by_name("ENGINE_N3:3", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -1983,35 +1931,51 @@ mod tests {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", false);
             self.write_by_name("TURB ENG CORRECTED N2:4", Ratio::new::<ratio>(0.));
             self.write_by_name("TURB ENG CORRECTED N1:4", Ratio::new::<ratio>(0.));
-            self.write_by_name("ENGINE_STATE:4", EngineState::Off);
+            self.write_by_name("ENGINE_N3:4", Ratio::new::<ratio>(0.));
 
             self
         }
 
         fn start_eng1(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
-            self.write_by_name("ENGINE_STATE:1", EngineState::Starting);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("ENGINE_N3:1", Ratio::new::<ratio>(0.));
 
             self
         }
 
         fn start_eng2(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:2", true);
-            self.write_by_name("ENGINE_STATE:2", EngineState::Starting);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("ENGINE_N3:2", Ratio::new::<ratio>(0.));
 
             self
         }
 
         fn start_eng3(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:3", true);
-            self.write_by_name("ENGINE_STATE:3", EngineState::Starting);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("ENGINE_N3:3", Ratio::new::<ratio>(0.));
 
             self
         }
 
         fn start_eng4(mut self) -> Self {
             self.write_by_name("GENERAL ENG STARTER ACTIVE:4", true);
-            self.write_by_name("ENGINE_STATE:4", EngineState::Starting);
+            self.write_by_name(
+                "TURB ENG IGNITION SWITCH EX1:1",
+                EngineModeSelector::Ignition,
+            );
+            self.write_by_name("ENGINE_N3:4", Ratio::new::<ratio>(0.));
 
             self
         }
@@ -2203,8 +2167,26 @@ mod tests {
             self
         }
 
-        fn set_engine_state(mut self, number: usize, engine_state: EngineState) -> Self {
-            self.write_by_name(&format!("ENGINE_STATE:{}", number), engine_state);
+        fn engine_master(mut self, number: usize, is_on: bool) -> Self {
+            self.write_by_name(&format!("GENERAL ENG STARTER ACTIVE:{}", number), is_on);
+
+            self
+        }
+
+        fn engine_n3(mut self, number: usize, n3: Ratio) -> Self {
+            self.write_by_name(&format!("ENGINE_N3:{}", number), n3);
+
+            self
+        }
+
+        fn engine_mode_selector(mut self, position: EngineModeSelector) -> Self {
+            self.write_by_name("TURB ENG IGNITION SWITCH EX1:1", position);
+
+            self
+        }
+
+        fn on_ground(mut self) -> Self {
+            self.set_on_ground(true);
 
             self
         }
@@ -3054,22 +3036,28 @@ mod tests {
 
     #[rstest]
     fn fadec_represents_engine_state(#[values(1, 2, 3, 4)] engine: usize) {
-        let mut test_bed = test_bed_with().set_engine_state(engine, EngineState::Off);
+        let mut test_bed = test_bed_with().on_ground().and_run();
 
         assert_eq!(test_bed.engine_state(engine), EngineState::Off);
 
         test_bed = test_bed
-            .set_engine_state(engine, EngineState::Starting)
+            .engine_mode_selector(EngineModeSelector::Ignition)
+            .engine_master(engine, true)
             .and_run();
         assert_eq!(test_bed.engine_state(engine), EngineState::Starting);
 
-        test_bed = test_bed.set_engine_state(engine, EngineState::On).and_run();
+        test_bed = test_bed
+            .engine_n3(engine, Ratio::new::<ratio>(0.6))
+            .and_run();
         assert_eq!(test_bed.engine_state(engine), EngineState::On);
 
+        test_bed = test_bed.engine_master(engine, false).and_run();
+        assert_eq!(test_bed.engine_state(engine), EngineState::Shutting);
+
         test_bed = test_bed
-            .set_engine_state(engine, EngineState::Shutting)
+            .engine_n3(engine, Ratio::new::<ratio>(0.))
             .and_run();
-        assert_eq!(test_bed.engine_state(engine), EngineState::Shutting);
+        assert_eq!(test_bed.engine_state(engine), EngineState::Off);
     }
 
     #[test]
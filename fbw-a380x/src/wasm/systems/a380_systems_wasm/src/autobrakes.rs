@@ -71,6 +71,12 @@ pub(super) fn autobrakes(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn
         Variable::named("AUTOBRAKES_ARMED_MODE_SET"),
         options_set,
     )?;
+    builder.event_to_variable(
+        "A32NX.AUTOBRAKE_SET_BTV",
+        EventToVariableMapping::Value(6.),
+        Variable::named("AUTOBRAKES_ARMED_MODE_SET"),
+        options_set,
+    )?;
 
     let options_buttons = |options: EventToVariableOptions| {
         options
@@ -96,6 +102,21 @@ pub(super) fn autobrakes(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn
         Variable::named("OVHD_AUTOBRK_MAX_ON_IS_PRESSED"),
         options_buttons,
     )?;
+    builder.event_to_variable(
+        "A32NX.AUTOBRAKE_BUTTON_BTV",
+        EventToVariableMapping::Value(1.),
+        Variable::named("OVHD_AUTOBRK_BTV_ON_IS_PRESSED"),
+        options_buttons,
+    )?;
+
+    // Until the OANS selects the runway exit, the distance from the threshold to the exit in
+    // meters can be set with this event.
+    builder.event_to_variable(
+        "A32NX.BTV_SET_EXIT_DISTANCE",
+        EventToVariableMapping::EventDataToValue(|event_data| event_data as f64),
+        Variable::named("BTV_EXIT_DISTANCE"),
+        |options| options.mask(),
+    )?;
 
     Ok(())
 }
@@ -0,0 +1,21 @@
+use std::error::Error;
+use systems_wasm::aspects::{ExecuteOn, MsfsAspectBuilder};
+use systems_wasm::Variable;
+
+pub(super) fn body_wheel_steering(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    for side in ["LEFT", "RIGHT"] {
+        builder.map(
+            ExecuteOn::PostTick,
+            Variable::aspect(&format!("{}_BODY_WHEEL_POSITION_RATIO", side)),
+            steering_animation_from_body_wheel_position,
+            Variable::named(&format!("{}_BODY_WHEEL_POSITION", side)),
+        );
+    }
+
+    Ok(())
+}
+
+// Body wheel position ratio is [-1;1] over the full travel, the animation is [0;1], 0.5 centered.
+fn steering_animation_from_body_wheel_position(body_wheel_position: f64) -> f64 {
+    (body_wheel_position + 1.) / 2.
+}
@@ -0,0 +1,119 @@
+use std::error::Error;
+
+use msfs::sim_connect;
+use msfs::{sim_connect::SimConnect, sim_connect::SIMCONNECT_OBJECT_ID_USER};
+
+use systems_wasm::aspects::{MsfsAspectBuilder, ObjectWrite, VariablesToObject};
+use systems_wasm::{set_data_on_sim_object, Variable};
+
+pub(super) fn fuel(builder: &mut MsfsAspectBuilder) -> Result<(), Box<dyn Error>> {
+    // The fuel system owns the tank quantities: the FADEC doesn't burn fuel from the tanks.
+    // The modelled quantities are written to the simulator's tanks every frame, such that
+    // the fuel burnt by the simulator itself is overridden and its weight and CG follow the
+    // transfers of the fuel system. Only fuel loaded on the ground changes the simulator's
+    // tanks otherwise, which the fuel system then takes over.
+    builder.variables_to_object(Box::new(FuelTankQuantities {
+        left_outer: 0.,
+        feed_1: 0.,
+        left_mid: 0.,
+        left_inner: 0.,
+        feed_2: 0.,
+        feed_3: 0.,
+        right_inner: 0.,
+        right_mid: 0.,
+        feed_4: 0.,
+        right_outer: 0.,
+        trim: 0.,
+    }));
+
+    Ok(())
+}
+
+#[sim_connect::data_definition]
+struct FuelTankQuantities {
+    #[name = "FUELSYSTEM TANK QUANTITY:1"]
+    #[unit = "Gallons"]
+    left_outer: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:2"]
+    #[unit = "Gallons"]
+    feed_1: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:3"]
+    #[unit = "Gallons"]
+    left_mid: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:4"]
+    #[unit = "Gallons"]
+    left_inner: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:5"]
+    #[unit = "Gallons"]
+    feed_2: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:6"]
+    #[unit = "Gallons"]
+    feed_3: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:7"]
+    #[unit = "Gallons"]
+    right_inner: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:8"]
+    #[unit = "Gallons"]
+    right_mid: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:9"]
+    #[unit = "Gallons"]
+    feed_4: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:10"]
+    #[unit = "Gallons"]
+    right_outer: f64,
+
+    #[name = "FUELSYSTEM TANK QUANTITY:11"]
+    #[unit = "Gallons"]
+    trim: f64,
+}
+
+impl VariablesToObject for FuelTankQuantities {
+    fn variables(&self) -> Vec<Variable> {
+        vec![
+            Variable::named("FUEL_LEFT_OUTER_QUANTITY"),
+            Variable::named("FUEL_FEED_1_QUANTITY"),
+            Variable::named("FUEL_LEFT_MID_QUANTITY"),
+            Variable::named("FUEL_LEFT_INNER_QUANTITY"),
+            Variable::named("FUEL_FEED_2_QUANTITY"),
+            Variable::named("FUEL_FEED_3_QUANTITY"),
+            Variable::named("FUEL_RIGHT_INNER_QUANTITY"),
+            Variable::named("FUEL_RIGHT_MID_QUANTITY"),
+            Variable::named("FUEL_FEED_4_QUANTITY"),
+            Variable::named("FUEL_RIGHT_OUTER_QUANTITY"),
+            Variable::named("FUEL_TRIM_QUANTITY"),
+            Variable::aircraft("FUEL WEIGHT PER GALLON", "Pounds", 0),
+        ]
+    }
+
+    fn write(&mut self, values: Vec<f64>) -> ObjectWrite {
+        let fuel_weight_per_gallon = values[11];
+        if fuel_weight_per_gallon <= 0. {
+            return ObjectWrite::Ignore;
+        }
+
+        self.left_outer = values[0] / fuel_weight_per_gallon;
+        self.feed_1 = values[1] / fuel_weight_per_gallon;
+        self.left_mid = values[2] / fuel_weight_per_gallon;
+        self.left_inner = values[3] / fuel_weight_per_gallon;
+        self.feed_2 = values[4] / fuel_weight_per_gallon;
+        self.feed_3 = values[5] / fuel_weight_per_gallon;
+        self.right_inner = values[6] / fuel_weight_per_gallon;
+        self.right_mid = values[7] / fuel_weight_per_gallon;
+        self.feed_4 = values[8] / fuel_weight_per_gallon;
+        self.right_outer = values[9] / fuel_weight_per_gallon;
+        self.trim = values[10] / fuel_weight_per_gallon;
+
+        ObjectWrite::default()
+    }
+
+    set_data_on_sim_object!();
+}
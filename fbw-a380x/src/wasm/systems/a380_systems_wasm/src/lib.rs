@@ -1,31 +1,32 @@
 mod ailerons;
 mod autobrakes;
+mod body_wheel_steering;
 mod brakes;
 mod cargo_doors;
 mod elevators;
 mod flaps;
+mod fuel;
 mod gear;
 mod nose_wheel_steering;
 mod rudder;
 mod spoilers;
 mod trimmable_horizontal_stabilizer;
 
-use a380_systems::A380;
+use a380_systems::{failures, A380};
 use ailerons::ailerons;
 use autobrakes::autobrakes;
+use body_wheel_steering::body_wheel_steering;
 use brakes::brakes;
 use cargo_doors::cargo_doors;
 use elevators::elevators;
 use flaps::flaps;
+use fuel::fuel;
 use gear::gear;
 use nose_wheel_steering::nose_wheel_steering;
 use rudder::rudder;
 use spoilers::spoilers;
 use std::error::Error;
-use systems::failures::FailureType;
-use systems::shared::{
-    ElectricalBusType, GearActuatorId, HydraulicColor, LgciuId, ProximityDetectorId,
-};
+use systems::shared::ElectricalBusType;
 
 use systems_wasm::{MsfsSimulationBuilder, Variable};
 use trimmable_horizontal_stabilizer::trimmable_horizontal_stabilizer;
@@ -40,9 +41,20 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         Variable::named(&format!("{}START_STATE", key_prefix)),
         sim_connect.as_mut().get_mut(),
     )
+    .with_recording(
+        Variable::named(&format!("{}SYSTEMS_RECORDING_ENABLED", key_prefix)),
+        "\\work\\systems_recording.txt",
+    )
+    .with_manifest(
+        Variable::named(&format!("{}SYSTEMS_MANIFEST_ENABLED", key_prefix)),
+        "\\work\\systems_manifest.tsv",
+    )
+    .with_snapshots("\\work\\systems_snapshot.txt")
     .with_electrical_buses([
         (ElectricalBusType::AlternatingCurrent(1), 2),
         (ElectricalBusType::AlternatingCurrent(2), 3),
+        (ElectricalBusType::AlternatingCurrent(3), 16),
+        (ElectricalBusType::AlternatingCurrent(4), 17),
         (ElectricalBusType::AlternatingCurrentEssential, 4),
         (ElectricalBusType::AlternatingCurrentEssentialShed, 5),
         (ElectricalBusType::AlternatingCurrentStaticInverter, 6),
@@ -57,111 +69,7 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
         (ElectricalBusType::DirectCurrentGndFltService, 15),
     ])?
     .with_auxiliary_power_unit(Variable::named("OVHD_APU_START_PB_IS_AVAILABLE"), 8)?
-    .with_failures(vec![
-        (24_000, FailureType::TransformerRectifier(1)),
-        (24_001, FailureType::TransformerRectifier(2)),
-        (24_002, FailureType::TransformerRectifier(3)),
-        (29_000, FailureType::ReservoirLeak(HydraulicColor::Green)),
-        (29_001, FailureType::ReservoirLeak(HydraulicColor::Blue)),
-        (29_002, FailureType::ReservoirLeak(HydraulicColor::Yellow)),
-        (29_003, FailureType::ReservoirAirLeak(HydraulicColor::Green)),
-        (29_004, FailureType::ReservoirAirLeak(HydraulicColor::Blue)),
-        (
-            29_005,
-            FailureType::ReservoirAirLeak(HydraulicColor::Yellow),
-        ),
-        (
-            29_006,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Green),
-        ),
-        (
-            29_007,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Blue),
-        ),
-        (
-            29_008,
-            FailureType::ReservoirReturnLeak(HydraulicColor::Yellow),
-        ),
-        (32_000, FailureType::LgciuPowerSupply(LgciuId::Lgciu1)),
-        (32_001, FailureType::LgciuPowerSupply(LgciuId::Lgciu2)),
-        (32_002, FailureType::LgciuInternalError(LgciuId::Lgciu1)),
-        (32_003, FailureType::LgciuInternalError(LgciuId::Lgciu2)),
-        (
-            32_004,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearNose1),
-        ),
-        (
-            32_005,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearNose2),
-        ),
-        (
-            32_006,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearRight1),
-        ),
-        (
-            32_007,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearRight2),
-        ),
-        (
-            32_008,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockGearLeft2),
-        ),
-        (
-            32_009,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockGearLeft1),
-        ),
-        (
-            32_010,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorNose1),
-        ),
-        (
-            32_011,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorNose2),
-        ),
-        (
-            32_012,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorRight2),
-        ),
-        (
-            32_013,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorRight1),
-        ),
-        (
-            32_014,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::UplockDoorLeft2),
-        ),
-        (
-            32_015,
-            FailureType::GearProxSensorDamage(ProximityDetectorId::DownlockDoorLeft1),
-        ),
-        (
-            32_020,
-            FailureType::GearActuatorJammed(GearActuatorId::GearNose),
-        ),
-        (
-            32_021,
-            FailureType::GearActuatorJammed(GearActuatorId::GearLeft),
-        ),
-        (
-            32_022,
-            FailureType::GearActuatorJammed(GearActuatorId::GearRight),
-        ),
-        (
-            32_023,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorNose),
-        ),
-        (
-            32_024,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorLeft),
-        ),
-        (
-            32_025,
-            FailureType::GearActuatorJammed(GearActuatorId::GearDoorRight),
-        ),
-        (34_000, FailureType::RadioAltimeter(1)),
-        (34_001, FailureType::RadioAltimeter(2)),
-        (34_002, FailureType::RadioAltimeter(3)),
-    ])
+    .with_failures(failures())
     .provides_aircraft_variable("ACCELERATION BODY X", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Y", "feet per second squared", 0)?
     .provides_aircraft_variable("ACCELERATION BODY Z", "feet per second squared", 0)?
@@ -169,6 +77,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("AIRSPEED MACH", "Mach", 0)?
     .provides_aircraft_variable("AIRSPEED TRUE", "Knots", 0)?
     .provides_aircraft_variable("AMBIENT DENSITY", "Slugs per cubic feet", 0)?
+    .provides_aircraft_variable("AMBIENT IN CLOUD", "Bool", 0)?
+    .provides_aircraft_variable("AMBIENT PRECIP RATE", "millimeters of water", 0)?
     .provides_aircraft_variable("AMBIENT PRESSURE", "inHg", 0)?
     .provides_aircraft_variable("AMBIENT TEMPERATURE", "celsius", 0)?
     .provides_aircraft_variable("AMBIENT WIND DIRECTION", "Degrees", 0)?
@@ -177,8 +87,23 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("AMBIENT WIND Y", "meter per second", 0)?
     .provides_aircraft_variable("AMBIENT WIND Z", "meter per second", 0)?
     .provides_aircraft_variable("ANTISKID BRAKES ACTIVE", "Bool", 0)?
+    .provides_aircraft_variable("CG PERCENT", "Percent", 0)?
     .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 1)?
-    .provides_aircraft_variable("FUEL TANK LEFT MAIN QUANTITY", "Pounds", 0)?
+    .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 2)?
+    .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 3)?
+    .provides_aircraft_variable("EXTERNAL POWER AVAILABLE", "Bool", 4)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 1)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 2)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 3)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 4)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 5)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 6)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 7)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 8)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 9)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 10)?
+    .provides_aircraft_variable("FUELSYSTEM TANK QUANTITY", "Gallons", 11)?
+    .provides_aircraft_variable("FUEL WEIGHT PER GALLON", "Pounds", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 0)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 1)?
     .provides_aircraft_variable("GEAR ANIMATION POSITION", "Percent", 2)?
@@ -187,6 +112,8 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("GEAR RIGHT POSITION", "Percent", 0)?
     .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 1)?
     .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 2)?
+    .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 3)?
+    .provides_aircraft_variable("GENERAL ENG STARTER ACTIVE", "Bool", 4)?
     .provides_aircraft_variable("GPS GROUND SPEED", "Knots", 0)?
     .provides_aircraft_variable("GPS GROUND MAGNETIC TRACK", "Degrees", 0)?
     .provides_aircraft_variable("GPS GROUND TRUE TRACK", "Degrees", 0)?
@@ -208,7 +135,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("PUSHBACK ANGLE", "Radians", 0)?
     .provides_aircraft_variable("SEA LEVEL PRESSURE", "Millibars", 0)?
     .provides_aircraft_variable("SIM ON GROUND", "Bool", 0)?
+    .provides_aircraft_variable("SURFACE CONDITION", "Enum", 0)?
     .provides_aircraft_variable("TOTAL AIR TEMPERATURE", "celsius", 0)?
+    .provides_aircraft_variable("TOTAL WEIGHT", "Pounds", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS LEFT PERCENT", "Percent", 0)?
     .provides_aircraft_variable("TRAILING EDGE FLAPS RIGHT PERCENT", "Percent", 0)?
     .provides_aircraft_variable("TURB ENG CORRECTED N1", "Percent", 1)?
@@ -230,10 +159,12 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .provides_aircraft_variable("ROTATION VELOCITY BODY Y", "degree per second", 0)?
     .provides_aircraft_variable("ROTATION VELOCITY BODY Z", "degree per second", 0)?
     .with_aspect(|builder| {
-        builder.copy(
-            Variable::aircraft("APU GENERATOR SWITCH", "Bool", 0),
-            Variable::aspect("OVHD_ELEC_APU_GEN_PB_IS_ON"),
-        );
+        for number in 1..=2 {
+            builder.copy(
+                Variable::aircraft("APU GENERATOR SWITCH", "Bool", number),
+                Variable::aspect(&format!("OVHD_ELEC_APU_GEN_{}_PB_IS_ON", number)),
+            );
+        }
 
         builder.copy(
             Variable::aircraft("BLEED AIR ENGINE", "Bool", 1),
@@ -252,23 +183,21 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
             Variable::aspect("OVHD_PNEU_ENG_4_BLEED_PB_IS_AUTO"),
         );
 
-        builder.copy(
-            Variable::aircraft("EXTERNAL POWER AVAILABLE", "Bool", 1),
-            Variable::aspect("OVHD_ELEC_EXT_PWR_PB_IS_AVAILABLE"),
-        );
-        builder.copy(
-            Variable::aircraft("EXTERNAL POWER ON", "Bool", 1),
-            Variable::aspect("OVHD_ELEC_EXT_PWR_PB_IS_ON"),
-        );
+        for number in 1..=4 {
+            builder.copy(
+                Variable::aircraft("EXTERNAL POWER AVAILABLE", "Bool", number),
+                Variable::aspect(&format!("OVHD_ELEC_EXT_PWR_{}_PB_IS_AVAILABLE", number)),
+            );
+            builder.copy(
+                Variable::aircraft("EXTERNAL POWER ON", "Bool", number),
+                Variable::aspect(&format!("OVHD_ELEC_EXT_PWR_{}_PB_IS_ON", number)),
+            );
 
-        builder.copy(
-            Variable::aircraft("GENERAL ENG MASTER ALTERNATOR", "Bool", 1),
-            Variable::aspect("OVHD_ELEC_ENG_GEN_1_PB_IS_ON"),
-        );
-        builder.copy(
-            Variable::aircraft("GENERAL ENG MASTER ALTERNATOR", "Bool", 2),
-            Variable::aspect("OVHD_ELEC_ENG_GEN_2_PB_IS_ON"),
-        );
+            builder.copy(
+                Variable::aircraft("GENERAL ENG MASTER ALTERNATOR", "Bool", number),
+                Variable::aspect(&format!("OVHD_ELEC_ENG_GEN_{}_PB_IS_ON", number)),
+            );
+        }
 
         Ok(())
     })?
@@ -276,7 +205,9 @@ async fn systems(mut gauge: msfs::Gauge) -> Result<(), Box<dyn Error>> {
     .with_aspect(cargo_doors)?
     .with_aspect(autobrakes)?
     .with_aspect(nose_wheel_steering)?
+    .with_aspect(body_wheel_steering)?
     .with_aspect(flaps)?
+    .with_aspect(fuel)?
     .with_aspect(spoilers)?
     .with_aspect(ailerons)?
     .with_aspect(elevators)?
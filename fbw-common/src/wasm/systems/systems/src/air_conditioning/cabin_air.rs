@@ -2,8 +2,8 @@ use super::{Air, DuctTemperature, OutletAir, PressurizationConstants, ZoneType};
 use crate::{
     shared::{AverageExt, CabinSimulation},
     simulation::{
-        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use num_traits::Pow;
@@ -334,6 +334,36 @@ impl<C: PressurizationConstants, const ZONES: usize> SimulationElement
 
         visitor.visit(self);
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_initialised);
+        snapshot.write(self.internal_air.pressure());
+        snapshot.write(self.internal_air.temperature());
+
+        self.previous_exterior_pressure
+            .iter()
+            .for_each(|&pressure| snapshot.write(pressure));
+        snapshot.write(self.filtered_exterior_pressure);
+        self.previous_flow_in
+            .iter()
+            .for_each(|&flow| snapshot.write(flow));
+        snapshot.write(self.filtered_flow_in);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_initialised = snapshot.read();
+        self.internal_air.set_pressure(snapshot.read());
+        self.internal_air.set_temperature(snapshot.read());
+
+        self.previous_exterior_pressure
+            .iter_mut()
+            .for_each(|pressure| *pressure = snapshot.read());
+        self.filtered_exterior_pressure = snapshot.read();
+        self.previous_flow_in
+            .iter_mut()
+            .for_each(|flow| *flow = snapshot.read());
+        self.filtered_flow_in = snapshot.read();
+    }
 }
 
 pub struct CabinZone<C> {
@@ -414,6 +444,14 @@ impl<C: PressurizationConstants> SimulationElement for CabinZone<C> {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.zone_identifier, self.zone_air_temperature());
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.zone_air_temperature());
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.set_zone_air_temperature(snapshot.read());
+    }
 }
 
 struct ZoneAir {
@@ -951,6 +989,35 @@ mod cabin_air_tests {
         );
     }
 
+    #[test]
+    fn cabin_pressure_is_restored_from_snapshot() {
+        let mut cruising = test_bed_with()
+            .ambient_temperature_of(ThermodynamicTemperature::new::<degree_celsius>(-50.))
+            .ambient_pressure_of(InternationalStandardAtmosphere::pressure_at_altitude(
+                Length::new::<foot>(39000.),
+            ))
+            .flying(true)
+            .iterate(1);
+        let snapshot = cruising.snapshot();
+
+        let mut test_bed = test_bed();
+        test_bed.restore(&snapshot).unwrap();
+
+        assert!(
+            (test_bed.cabin_pressure() - cruising.cabin_pressure())
+                .get::<hectopascal>()
+                .abs()
+                < 1.
+        );
+        assert!(
+            (test_bed.cabin_pressure()
+                - InternationalStandardAtmosphere::pressure_at_altitude(Length::new::<foot>(0.)))
+            .get::<hectopascal>()
+            .abs()
+                > 100.
+        );
+    }
+
     #[test]
     fn cabin_air_warms_up_with_pax_and_no_ac() {
         let test_bed = test_bed_with()
@@ -4,8 +4,8 @@ use crate::{
         CabinSimulation, ControllerSignal, EngineCorrectedN1,
     },
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier, Write,
+        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 
@@ -601,6 +601,42 @@ impl<C: PressurizationConstants> SimulationElement for CabinPressureController<C
         self.landing_elevation = landing_elevation_word.normal_value().unwrap_or_default();
         self.destination_qnh = Pressure::new::<hectopascal>(reader.read(&self.destination_qnh_id));
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.pressure_schedule_manager
+            .as_ref()
+            .unwrap_or(&PressureScheduleManager::default())
+            .snapshot(snapshot);
+        snapshot.write(self.exterior_pressure.output());
+        snapshot.write(self.exterior_flight_altitude);
+        snapshot.write(self.exterior_vertical_speed.output());
+        snapshot.write(self.reference_pressure);
+        snapshot.write(self.previous_reference_pressure);
+        snapshot.write(self.cabin_alt);
+        snapshot.write(self.cabin_vertical_speed);
+        snapshot.write(self.cabin_filtered_vertical_speed.output());
+        snapshot.write(self.departure_elevation);
+        snapshot.write(self.is_in_man_mode);
+        snapshot.write(self.man_mode_duration);
+        snapshot.write(self.manual_to_auto_switch);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.pressure_schedule_manager = Some(PressureScheduleManager::restore(snapshot));
+        self.exterior_pressure.reset(snapshot.read());
+        self.exterior_flight_altitude = snapshot.read();
+        self.exterior_vertical_speed.reset(snapshot.read());
+        self.reference_pressure = snapshot.read();
+        self.previous_reference_pressure = snapshot.read();
+        self.cabin_alt = snapshot.read();
+        self.cabin_vertical_speed = snapshot.read();
+        self.cabin_filtered_vertical_speed.reset(snapshot.read());
+        self.departure_elevation = snapshot.read();
+        self.is_in_man_mode = snapshot.read();
+        self.man_mode_duration = snapshot.read();
+        self.manual_to_auto_switch = snapshot.read();
+        self.is_initialised = true;
+    }
 }
 
 struct OutflowValveController {
@@ -741,6 +777,60 @@ impl PressureScheduleManager {
             _ => false,
         }
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        let (schedule, timer, cpc_switch_reset) = match self {
+            PressureScheduleManager::Ground(val) => {
+                (0., val.timer, val.pressure_schedule.cpc_switch_reset)
+            }
+            PressureScheduleManager::TakeOff(val) => (1., val.timer, false),
+            PressureScheduleManager::ClimbInternal(val) => (2., val.timer, false),
+            PressureScheduleManager::Cruise(val) => (3., val.timer, false),
+            PressureScheduleManager::DescentInternal(val) => (4., val.timer, false),
+            PressureScheduleManager::Abort(val) => (5., val.timer, false),
+        };
+
+        snapshot.write(schedule);
+        snapshot.write(timer);
+        snapshot.write(cpc_switch_reset);
+    }
+
+    fn restore(snapshot: &mut SnapshotReader) -> Self {
+        let schedule: f64 = snapshot.read();
+        let timer = snapshot.read();
+        let cpc_switch_reset = snapshot.read();
+
+        match schedule as u8 {
+            0 => PressureScheduleManager::Ground(PressureSchedule {
+                timer,
+                pressure_schedule: Ground { cpc_switch_reset },
+            }),
+            1 => PressureScheduleManager::TakeOff(PressureSchedule {
+                timer,
+                pressure_schedule: TakeOff,
+            }),
+            2 => PressureScheduleManager::ClimbInternal(PressureSchedule {
+                timer,
+                pressure_schedule: ClimbInternal,
+            }),
+            3 => PressureScheduleManager::Cruise(PressureSchedule {
+                timer,
+                pressure_schedule: Cruise,
+            }),
+            4 => PressureScheduleManager::DescentInternal(PressureSchedule {
+                timer,
+                pressure_schedule: DescentInternal,
+            }),
+            5 => PressureScheduleManager::Abort(PressureSchedule {
+                timer,
+                pressure_schedule: Abort,
+            }),
+            _ => panic!(
+                "{} cannot be converted into PressureScheduleManager",
+                schedule
+            ),
+        }
+    }
 }
 
 impl Default for PressureScheduleManager {
@@ -1568,6 +1658,21 @@ mod tests {
         assert!(test_bed.query(|a| a.is_ground()));
     }
 
+    #[test]
+    fn restoring_a_snapshot_restores_the_schedule() {
+        let mut climbing_test_bed = test_bed();
+        climbing_test_bed.command(|a| a.set_true_airspeed(Velocity::new::<knot>(101.)));
+        climbing_test_bed.run();
+        let cabin_altitude = climbing_test_bed.query(|a| a.cpc.cabin_altitude());
+        let snapshot = climbing_test_bed.snapshot();
+
+        let mut test_bed = test_bed();
+        test_bed.restore(&snapshot).unwrap();
+
+        assert!(test_bed.query(|a| a.is_climb()));
+        assert_eq!(test_bed.query(|a| a.cpc.cabin_altitude()), cabin_altitude);
+    }
+
     #[test]
     fn schedule_does_not_instantly_change_from_climb_to_abort() {
         let mut test_bed = test_bed();
@@ -1,6 +1,8 @@
 use crate::{
     shared::{ControllerSignal, ElectricalBusType, ElectricalBuses},
-    simulation::{SimulationElement, SimulationElementVisitor, UpdateContext},
+    simulation::{
+        SimulationElement, SimulationElementVisitor, SnapshotReader, SnapshotWriter, UpdateContext,
+    },
 };
 
 use super::OutflowValveSignal;
@@ -63,6 +65,14 @@ impl SimulationElement for OutflowValve {
 
         visitor.visit(self);
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.valve.snapshot(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.valve.restore(snapshot);
+    }
 }
 
 struct OutflowValveMotor {
@@ -146,6 +156,14 @@ impl SafetyValve {
     pub fn open_amount(&self) -> Ratio {
         self.valve.open_amount()
     }
+
+    pub fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.valve.snapshot(snapshot);
+    }
+
+    pub fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.valve.restore(snapshot);
+    }
 }
 
 impl Default for SafetyValve {
@@ -197,6 +215,14 @@ impl PressureValve {
         }
     }
 
+    pub fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.open_amount);
+    }
+
+    pub fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.open_amount = snapshot.read();
+    }
+
     fn get_valve_change_for_delta(&self, context: &UpdateContext, travel_time: Duration) -> f64 {
         100. * (context.delta_as_secs_f64() / travel_time.as_secs_f64())
     }
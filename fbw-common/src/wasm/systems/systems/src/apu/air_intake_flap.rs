@@ -1,6 +1,6 @@
 use crate::{
     shared::{random_number, ConsumePower, ControllerSignal, ElectricalBusType, ElectricalBuses},
-    simulation::{SimulationElement, UpdateContext},
+    simulation::{SimulationElement, SnapshotReader, SnapshotWriter, UpdateContext},
 };
 use std::time::Duration;
 use uom::si::{f64::*, power::watt, ratio::percent};
@@ -103,6 +103,14 @@ impl SimulationElement for AirIntakeFlap {
             consumption.consume_from_bus(self.powered_by, Power::new::<watt>(20.))
         }
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.open_amount);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.open_amount = snapshot.read();
+    }
 }
 
 #[cfg(test)]
@@ -15,7 +15,10 @@ use crate::{
         calculate_towards_target_temperature, random_number, ConsumePower, ControllerSignal,
         ElectricalBusType, ElectricalBuses, PotentialOrigin, PowerConsumptionReport,
     },
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter,
+        UpdateContext,
+    },
 };
 
 use super::{ApuGenerator, ApuStartMotor, Turbine, TurbineSignal, TurbineState};
@@ -65,6 +68,21 @@ impl Turbine for ShutdownAps3200Turbine {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot_turbine(
+            snapshot,
+            self.state(),
+            Duration::default(),
+            self.n(),
+            self.egt,
+            false,
+        );
+    }
+
+    fn restored(self: Box<Self>, snapshot: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(snapshot)
+    }
 }
 
 struct Starting {
@@ -219,6 +237,21 @@ impl Turbine for Starting {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot_turbine(
+            snapshot,
+            self.state(),
+            self.since,
+            self.n,
+            self.egt,
+            self.ignore_calculated_egt,
+        );
+    }
+
+    fn restored(self: Box<Self>, snapshot: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(snapshot)
+    }
 }
 
 struct BleedAirUsageEgtDelta {
@@ -406,6 +439,21 @@ impl Turbine for Running {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(42.)
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot_turbine(
+            snapshot,
+            self.state(),
+            Duration::default(),
+            self.n(),
+            self.egt,
+            false,
+        );
+    }
+
+    fn restored(self: Box<Self>, snapshot: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(snapshot)
+    }
 }
 
 struct Stopping {
@@ -542,6 +590,53 @@ impl Turbine for Stopping {
     fn bleed_air_pressure(&self) -> Pressure {
         Pressure::new::<psi>(14.7)
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot_turbine(snapshot, self.state(), self.since, self.n, self.egt, false);
+    }
+
+    fn restored(self: Box<Self>, snapshot: &mut SnapshotReader) -> Box<dyn Turbine> {
+        restore_turbine(snapshot)
+    }
+}
+
+/// All states write the same values, such that a snapshot taken in one state
+/// can be restored into a turbine which is in another state.
+fn snapshot_turbine(
+    snapshot: &mut SnapshotWriter,
+    state: TurbineState,
+    since: Duration,
+    n: Ratio,
+    egt: ThermodynamicTemperature,
+    ignore_calculated_egt: bool,
+) {
+    snapshot.write(f64::from(state));
+    snapshot.write(since);
+    snapshot.write(n);
+    snapshot.write(egt);
+    snapshot.write(ignore_calculated_egt);
+}
+
+fn restore_turbine(snapshot: &mut SnapshotReader) -> Box<dyn Turbine> {
+    let state: TurbineState = snapshot.read::<f64>().into();
+    let since: Duration = snapshot.read();
+    let n: Ratio = snapshot.read();
+    let egt: ThermodynamicTemperature = snapshot.read();
+    let ignore_calculated_egt: bool = snapshot.read();
+
+    match state {
+        TurbineState::Shutdown => Box::new(ShutdownAps3200Turbine::new_with_egt(egt)),
+        TurbineState::Starting => Box::new(Starting {
+            since,
+            n,
+            egt,
+            ignore_calculated_egt,
+        }),
+        // The running EGT converges towards a newly determined base EGT.
+        TurbineState::Running => Box::new(Running::new(egt)),
+        // The spool down continues from the stored N.
+        TurbineState::Stopping => Box::new(Stopping::new(egt, n)),
+    }
 }
 
 fn calculate_towards_ambient_egt(
@@ -10,7 +10,9 @@ use crate::{
         arinc429::SignStatus, ApuBleedAirValveSignal, ApuMaster, ApuStart, ConsumePower,
         ContactorSignal, ControllerSignal, ElectricalBusType, ElectricalBuses, PneumaticValve,
     },
-    simulation::{SimulationElement, SimulatorWriter, UpdateContext, Write},
+    simulation::{
+        SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, Write,
+    },
 };
 use std::time::Duration;
 use uom::si::{
@@ -398,6 +400,24 @@ impl SimulationElement for ElectronicControlBox {
             consumption.consume_from_bus(self.powered_by, Power::new::<watt>(105.))
         }
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_powered);
+        snapshot.write(f64::from(self.turbine_state));
+        snapshot.write(self.n);
+        snapshot.write(self.egt);
+        snapshot.write(self.n_above_95_duration);
+        snapshot.write(self.bleed_air_valve_last_open_time_ago);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_powered = snapshot.read();
+        self.turbine_state = snapshot.read::<f64>().into();
+        self.n = snapshot.read();
+        self.egt = snapshot.read();
+        self.n_above_95_duration = snapshot.read();
+        self.bleed_air_valve_last_open_time_ago = snapshot.read();
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
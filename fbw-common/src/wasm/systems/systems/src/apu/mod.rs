@@ -3,6 +3,7 @@ use self::{
     electronic_control_box::ElectronicControlBox,
 };
 use crate::{
+    accept_iterable,
     electrical::{
         ElectricalElement, ElectricalElementIdentifier, ElectricitySource, Potential,
         ProvideFrequency, ProvidePotential,
@@ -11,10 +12,11 @@ use crate::{
     pneumatic::{ControllablePneumaticValve, TargetPressureTemperatureSignal},
     shared::{
         ApuAvailable, ApuBleedAirValveSignal, ApuMaster, ApuStart, AuxiliaryPowerUnitElectrical,
-        ContactorSignal, ControllerSignal, ElectricalBusType,
+        AuxiliaryPowerUnitGenerators, ContactorSignal, ControllerSignal, ElectricalBusType,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+        SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, Write,
     },
 };
 #[cfg(test)]
@@ -42,7 +44,28 @@ impl AuxiliaryPowerUnitFactory {
         AuxiliaryPowerUnit::new(
             context,
             Box::new(ShutdownAps3200Turbine::new()),
-            generator,
+            vec![generator],
+            Aps3200StartMotor::new(start_motor_powered_by),
+            electronic_control_box_powered_by,
+            air_intake_flap_powered_by,
+        )
+    }
+
+    /// Creates an APU driving the given number of generators, numbered from 1.
+    pub fn new_aps3200_with_generators(
+        context: &mut InitContext,
+        number_of_generators: usize,
+        start_motor_powered_by: ElectricalBusType,
+        electronic_control_box_powered_by: ElectricalBusType,
+        air_intake_flap_powered_by: ElectricalBusType,
+    ) -> AuxiliaryPowerUnit<Aps3200ApuGenerator, Aps3200StartMotor> {
+        let generators = (1..=number_of_generators)
+            .map(|number| Aps3200ApuGenerator::new(context, number))
+            .collect();
+        AuxiliaryPowerUnit::new(
+            context,
+            Box::new(ShutdownAps3200Turbine::new()),
+            generators,
             Aps3200StartMotor::new(start_motor_powered_by),
             electronic_control_box_powered_by,
             air_intake_flap_powered_by,
@@ -87,7 +110,7 @@ pub struct AuxiliaryPowerUnit<T: ApuGenerator, U: ApuStartMotor> {
     apu_flap_open_percentage_id: VariableIdentifier,
 
     turbine: Option<Box<dyn Turbine>>,
-    generator: T,
+    generators: Vec<T>,
     ecb: ElectronicControlBox,
     start_motor: U,
     air_intake_flap: AirIntakeFlap,
@@ -97,7 +120,7 @@ impl<T: ApuGenerator, U: ApuStartMotor> AuxiliaryPowerUnit<T, U> {
     pub fn new(
         context: &mut InitContext,
         turbine: Box<dyn Turbine>,
-        generator: T,
+        generators: Vec<T>,
         start_motor: U,
         electronic_control_box_powered_by: ElectricalBusType,
         air_intake_flap_powered_by: ElectricalBusType,
@@ -107,7 +130,7 @@ impl<T: ApuGenerator, U: ApuStartMotor> AuxiliaryPowerUnit<T, U> {
                 .get_identifier("APU_FLAP_OPEN_PERCENTAGE".to_owned()),
 
             turbine: Some(turbine),
-            generator,
+            generators,
             ecb: ElectronicControlBox::new(context, electronic_control_box_powered_by),
             start_motor,
             air_intake_flap: AirIntakeFlap::new(air_intake_flap_powered_by),
@@ -149,8 +172,11 @@ impl<T: ApuGenerator, U: ApuStartMotor> AuxiliaryPowerUnit<T, U> {
             self.turbine = Some(updated_turbine);
         }
 
-        self.generator
-            .update(self.ecb.n(), self.is_emergency_shutdown());
+        let n = self.ecb.n();
+        let is_emergency_shutdown = self.is_emergency_shutdown();
+        self.generators
+            .iter_mut()
+            .for_each(|generator| generator.update(n, is_emergency_shutdown));
     }
 
     pub fn update_after_power_distribution(&mut self) {
@@ -189,7 +215,14 @@ impl<T: ApuGenerator, U: ApuStartMotor> AuxiliaryPowerUnit<T, U> {
 }
 impl<T: ApuGenerator, U: ApuStartMotor> AuxiliaryPowerUnitElectrical for AuxiliaryPowerUnit<T, U> {
     fn output_within_normal_parameters(&self) -> bool {
-        self.generator.output_within_normal_parameters()
+        self.generators[0].output_within_normal_parameters()
+    }
+}
+impl<T: ApuGenerator, U: ApuStartMotor> AuxiliaryPowerUnitGenerators for AuxiliaryPowerUnit<T, U> {
+    type Generator = T;
+
+    fn generator(&self, number: usize) -> &T {
+        &self.generators[number - 1]
     }
 }
 impl<T: ApuGenerator, U: ApuStartMotor> ApuAvailable for AuxiliaryPowerUnit<T, U> {
@@ -206,20 +239,20 @@ impl<T: ApuGenerator, U: ApuStartMotor> ControllerSignal<ContactorSignal>
 }
 impl<T: ApuGenerator, U: ApuStartMotor> ElectricitySource for AuxiliaryPowerUnit<T, U> {
     fn output_potential(&self) -> Potential {
-        self.generator.output_potential()
+        self.generators[0].output_potential()
     }
 }
 impl<T: ApuGenerator, U: ApuStartMotor> ElectricalElement for AuxiliaryPowerUnit<T, U> {
     fn input_identifier(&self) -> ElectricalElementIdentifier {
-        self.generator.input_identifier()
+        self.generators[0].input_identifier()
     }
 
     fn output_identifier(&self) -> crate::electrical::ElectricalElementIdentifier {
-        self.generator.output_identifier()
+        self.generators[0].output_identifier()
     }
 
     fn is_conductive(&self) -> bool {
-        self.generator.is_conductive()
+        self.generators[0].is_conductive()
     }
 }
 impl<T: ApuGenerator, U: ApuStartMotor> ControllerSignal<ApuBleedAirValveSignal>
@@ -245,7 +278,7 @@ impl<T: ApuGenerator, U: ApuStartMotor> ControllerSignal<TargetPressureTemperatu
 }
 impl<T: ApuGenerator, U: ApuStartMotor> SimulationElement for AuxiliaryPowerUnit<T, U> {
     fn accept<V: SimulationElementVisitor>(&mut self, visitor: &mut V) {
-        self.generator.accept(visitor);
+        accept_iterable!(self.generators, visitor);
         self.start_motor.accept(visitor);
         self.air_intake_flap.accept(visitor);
         self.ecb.accept(visitor);
@@ -259,6 +292,18 @@ impl<T: ApuGenerator, U: ApuStartMotor> SimulationElement for AuxiliaryPowerUnit
             self.air_intake_flap.open_amount(),
         );
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        if let Some(turbine) = &self.turbine {
+            turbine.snapshot(snapshot);
+        }
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        if let Some(turbine) = self.turbine.take() {
+            self.turbine = Some(turbine.restored(snapshot));
+        }
+    }
 }
 
 pub trait Turbine {
@@ -273,15 +318,44 @@ pub trait Turbine {
     fn egt(&self) -> ThermodynamicTemperature;
     fn state(&self) -> TurbineState;
     fn bleed_air_pressure(&self) -> Pressure;
+
+    /// Saves the turbine into a snapshot. All states of a turbine must write the same
+    /// number of values, as the state at the time of restoring may differ.
+    fn snapshot(&self, _snapshot: &mut SnapshotWriter) {}
+
+    /// Returns the turbine in the state saved by [`snapshot`].
+    ///
+    /// [`snapshot`]: #method.snapshot
+    fn restored(self: Box<Self>, _snapshot: &mut SnapshotReader) -> Box<dyn Turbine>;
 }
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TurbineState {
     Shutdown,
     Starting,
     Running,
     Stopping,
 }
+impl From<TurbineState> for f64 {
+    fn from(state: TurbineState) -> Self {
+        match state {
+            TurbineState::Shutdown => 0.,
+            TurbineState::Starting => 1.,
+            TurbineState::Running => 2.,
+            TurbineState::Stopping => 3.,
+        }
+    }
+}
+impl From<f64> for TurbineState {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => TurbineState::Starting,
+            2 => TurbineState::Running,
+            3 => TurbineState::Stopping,
+            _ => TurbineState::Shutdown,
+        }
+    }
+}
 
 pub trait ApuGenerator:
     SimulationElement + ProvidePotential + ProvideFrequency + ElectricalElement + ElectricitySource
@@ -300,7 +374,7 @@ impl AuxiliaryPowerUnitFireOverheadPanel {
         }
     }
 
-    fn fire_button_is_released(&self) -> bool {
+    pub fn fire_button_is_released(&self) -> bool {
         self.apu_fire_button.is_released()
     }
 }
@@ -427,6 +501,10 @@ pub mod tests {
         fn bleed_air_pressure(&self) -> Pressure {
             Pressure::new::<psi>(42.)
         }
+
+        fn restored(self: Box<Self>, _: &mut SnapshotReader) -> Box<dyn Turbine> {
+            self
+        }
     }
 
     struct TestPneumatic {
@@ -1376,6 +1454,37 @@ pub mod tests {
             assert_eq!(test_bed.egt().value(), target_temperature);
         }
 
+        #[test]
+        fn hot_apu_egt_is_restored_from_snapshot() {
+            let mut cooling_down = test_bed().cooling_down_apu();
+            let egt = cooling_down.egt().value();
+            let snapshot = cooling_down.snapshot();
+
+            let mut test_bed = test_bed().master_on();
+            test_bed.restore(&snapshot).unwrap();
+            test_bed = test_bed.run(Duration::from_millis(1));
+
+            assert!(test_bed.turbine_is_shutdown());
+            assert!(
+                (test_bed.egt().value().get::<degree_celsius>() - egt.get::<degree_celsius>())
+                    .abs()
+                    < 1.
+            );
+        }
+
+        #[test]
+        fn running_apu_is_restored_from_snapshot() {
+            let mut running = test_bed().running_apu();
+            let snapshot = running.snapshot();
+
+            let mut test_bed = test_bed().master_on().and().start_on();
+            test_bed.restore(&snapshot).unwrap();
+            test_bed = test_bed.run(Duration::from_secs(1));
+
+            assert!(test_bed.apu_is_available());
+            assert!(test_bed.n().normal_value().unwrap().get::<percent>() > 99.);
+        }
+
         #[test]
         /// Q: What would you say is a normal running EGT?
         /// Komp: It cools down by a few degrees. Not much though. 340-350 I'd say.
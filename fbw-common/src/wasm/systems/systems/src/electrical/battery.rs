@@ -1,30 +1,94 @@
 use uom::si::{
     electric_charge::ampere_hour, electric_current::ampere, electric_potential::volt,
-    electrical_resistance::ohm, f64::*, time::second,
+    electrical_resistance::ohm, f64::*, power::watt, ratio::ratio,
+    thermodynamic_temperature::degree_celsius, time::second,
 };
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::{ConsumePower, PowerConsumptionReport},
-    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+    },
 };
 
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
     ElectricalStateWriter, ElectricitySource, Potential, PotentialOrigin, ProvideCurrent,
-    ProvidePotential,
+    ProvidePotential, ProvideTemperature,
 };
 
+/// A nickel-cadmium aircraft battery.
+///
+/// Besides its charge, the battery keeps track of its temperature and state of health.
+/// A cold battery has less of its charge available and a higher internal resistance,
+/// while an aged battery loses capacity and its internal resistance grows.
+/// The temperature follows the avionics bay temperature the battery is installed in,
+/// and rises through the heat released by its current. As the potential of a hot battery
+/// decreases, a hot battery draws more charging current, which can lead to a thermal runaway.
+/// The state of health is kept from one session to the next through a stored variable, which
+/// is read back once when it is first available.
 pub struct Battery {
     number: usize,
     identifier: ElectricalElementIdentifier,
     writer: ElectricalStateWriter,
+    temperature_id: VariableIdentifier,
+    temperature_normal_id: VariableIdentifier,
+    state_of_health_id: VariableIdentifier,
+    stored_state_of_health_id: VariableIdentifier,
+    thermal_runaway: Failure,
     charge: ElectricCharge,
     input_potential: ElectricPotential,
     output_potential: ElectricPotential,
     current: ElectricCurrent,
+    temperature: ThermodynamicTemperature,
+    temperature_is_initialised: bool,
+    bay_temperature: ThermodynamicTemperature,
+    state_of_health: Ratio,
+    state_of_health_is_initialised: bool,
 }
 impl Battery {
     const RATED_CAPACITY_AMPERE_HOURS: f64 = 23.;
+    // The charge of a new battery at which the potential curve reaches its highest potential.
+    // Charging beyond this charge is not possible, as the curve rises steeply thereafter.
+    const MAX_CHARGE_AMPERE_HOURS: f64 = 23.27;
+
+    // The internal resistance of a new battery at the reference temperature. The charging
+    // current is additionally limited by the resistance of the wiring.
+    const INTERNAL_RESISTANCE_OHM: f64 = 0.011;
+    const WIRE_RESISTANCE_OHM: f64 = 0.139;
+    const REFERENCE_TEMPERATURE_DEG_C: f64 = 20.;
+    // Below the reference temperature the internal resistance increases exponentially
+    const COLD_RESISTANCE_INCREASE_PER_DEG_C: f64 = 0.025;
+    // A battery at the end of its life (no health left) has this many times its new resistance
+    const AGED_RESISTANCE_FACTOR: f64 = 3.;
+
+    // Below this temperature, part of the charge can't be delivered anymore
+    const CAPACITY_DERATING_BELOW_DEG_C: f64 = 0.;
+    const CAPACITY_DERATING_PER_DEG_C: f64 = 0.01;
+    const MIN_TEMPERATURE_CAPACITY_RATIO: f64 = 0.4;
+
+    // Above this temperature the potential of the cells decreases
+    const POTENTIAL_DECREASE_ABOVE_DEG_C: f64 = 40.;
+    const POTENTIAL_DECREASE_VOLT_PER_DEG_C: f64 = 0.04;
+
+    const HEAT_CAPACITY_JOULE_PER_KELVIN: f64 = 25000.;
+    const HEAT_TRANSFER_TO_BAY_WATT_PER_KELVIN: f64 = 3.5;
+    // Share of the charging power released as heat, increasing to all of it when overcharged
+    const CHARGING_HEAT_RATIO: f64 = 0.1;
+    const OVERCHARGE_FULL_HEAT_RATIO: f64 = 0.1;
+    // A battery in thermal runaway releases heat through the breakdown of its cells
+    // and discharges through an internal short circuit
+    const THERMAL_RUNAWAY_HEAT_WATT: f64 = 1500.;
+    const THERMAL_RUNAWAY_INTERNAL_CURRENT_AMPERE: f64 = 30.;
+    const OVERHEAT_TEMPERATURE_DEG_C: f64 = 60.;
+
+    // Loss of health per ampere hour flowing through the battery, giving about
+    // a thousand full cycles until the battery is down to 80% of its capacity
+    const HEALTH_LOSS_PER_AMPERE_HOUR: f64 = 0.2 / (1000. * 2. * 23.);
+    // Above this temperature ageing doubles for every additional 10 degrees
+    const ACCELERATED_AGEING_ABOVE_DEG_C: f64 = 25.;
 
     pub fn full(context: &mut InitContext, number: usize) -> Battery {
         Battery::new(
@@ -47,14 +111,50 @@ impl Battery {
     }
 
     pub fn new(context: &mut InitContext, number: usize, charge: ElectricCharge) -> Self {
-        Self {
+        let reference_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::REFERENCE_TEMPERATURE_DEG_C);
+
+        let mut battery = Self {
             number,
             identifier: context.next_electrical_identifier(),
             writer: ElectricalStateWriter::new(context, &format!("BAT_{}", number)),
+            temperature_id: context.get_identifier(format!("ELEC_BAT_{}_TEMPERATURE", number)),
+            temperature_normal_id: context
+                .get_identifier(format!("ELEC_BAT_{}_TEMPERATURE_NORMAL", number)),
+            state_of_health_id: context
+                .get_identifier(format!("ELEC_BAT_{}_STATE_OF_HEALTH", number)),
+            stored_state_of_health_id: context
+                .get_identifier(format!("ELEC_BAT_{}_STORED_STATE_OF_HEALTH", number)),
+            thermal_runaway: Failure::new(FailureType::BatteryThermalRunaway(number)),
             charge,
             input_potential: ElectricPotential::new::<volt>(0.),
-            output_potential: Battery::calculate_output_potential_for_charge(charge),
+            output_potential: ElectricPotential::new::<volt>(0.),
             current: ElectricCurrent::new::<ampere>(0.),
+            temperature: reference_temperature,
+            temperature_is_initialised: false,
+            bay_temperature: reference_temperature,
+            state_of_health: Ratio::new::<ratio>(1.),
+            state_of_health_is_initialised: false,
+        };
+        battery.output_potential = battery.calculate_output_potential();
+
+        battery
+    }
+
+    /// Updates the temperature of the air surrounding the battery. When first updated, a battery
+    /// on the ground is assumed to be soaked at the ambient temperature, while a battery in flight
+    /// is assumed to be at the temperature of its bay.
+    pub fn update(&mut self, context: &UpdateContext, bay_temperature: ThermodynamicTemperature) {
+        self.bay_temperature = bay_temperature;
+
+        if !self.temperature_is_initialised {
+            self.temperature = if context.is_on_ground() {
+                context.ambient_temperature()
+            } else {
+                bay_temperature
+            };
+            self.temperature_is_initialised = true;
+            self.output_potential = self.calculate_output_potential();
         }
     }
 
@@ -62,6 +162,10 @@ impl Battery {
         self.charge <= ElectricCharge::new::<ampere_hour>(Battery::RATED_CAPACITY_AMPERE_HOURS - 3.)
     }
 
+    pub fn state_of_health(&self) -> Ratio {
+        self.state_of_health
+    }
+
     fn is_powered_by_other_potential(&self) -> bool {
         self.input_potential > self.output_potential
     }
@@ -75,7 +179,7 @@ impl Battery {
     fn set_charge(&mut self, charge: ElectricCharge) {
         self.charge = charge;
         self.input_potential = ElectricPotential::new::<volt>(0.);
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.output_potential = self.calculate_output_potential();
     }
 
     #[cfg(test)]
@@ -95,6 +199,83 @@ impl Battery {
         self.set_charge(ElectricCharge::new::<ampere_hour>(0.))
     }
 
+    /// Function for testing purposes.
+    pub fn set_state_of_health(&mut self, state_of_health: Ratio) {
+        self.state_of_health = state_of_health;
+        self.charge = self.charge.min(self.max_charge());
+        self.output_potential = self.calculate_output_potential();
+    }
+
+    /// The highest charge the battery can hold. An aged battery has less capacity.
+    fn max_charge(&self) -> ElectricCharge {
+        ElectricCharge::new::<ampere_hour>(
+            Self::MAX_CHARGE_AMPERE_HOURS * self.state_of_health.get::<ratio>(),
+        )
+    }
+
+    fn temperature_in_celsius(&self) -> f64 {
+        self.temperature.get::<degree_celsius>()
+    }
+
+    /// The share of the capacity which can be delivered at the current temperature.
+    fn temperature_capacity_ratio(&self) -> f64 {
+        (1. - (Self::CAPACITY_DERATING_BELOW_DEG_C - self.temperature_in_celsius()).max(0.)
+            * Self::CAPACITY_DERATING_PER_DEG_C)
+            .max(Self::MIN_TEMPERATURE_CAPACITY_RATIO)
+    }
+
+    /// The charge which can be delivered by the battery. Charge which can't be delivered
+    /// while the battery is cold is taken from the bottom of the charge, and becomes
+    /// available again once the battery warms up.
+    fn available_charge(&self) -> ElectricCharge {
+        let unavailable_charge = ElectricCharge::new::<ampere_hour>(
+            Self::RATED_CAPACITY_AMPERE_HOURS * (1. - self.temperature_capacity_ratio()),
+        );
+
+        (self.charge - unavailable_charge).max(ElectricCharge::new::<ampere_hour>(0.))
+    }
+
+    /// The charge a new battery would hold at the same state of charge. As an aged battery
+    /// has less capacity, its available charge represents a higher state of charge.
+    fn new_battery_equivalent_charge(&self) -> ElectricCharge {
+        self.available_charge() / self.state_of_health.get::<ratio>().max(f64::EPSILON)
+    }
+
+    fn internal_resistance(&self) -> ElectricalResistance {
+        let cold_factor = (Self::COLD_RESISTANCE_INCREASE_PER_DEG_C
+            * (Self::REFERENCE_TEMPERATURE_DEG_C - self.temperature_in_celsius()).max(0.))
+        .exp();
+        let ageing_factor =
+            1. + (Self::AGED_RESISTANCE_FACTOR - 1.) * (1. - self.state_of_health.get::<ratio>());
+
+        ElectricalResistance::new::<ohm>(
+            Self::INTERNAL_RESISTANCE_OHM * cold_factor * ageing_factor,
+        )
+    }
+
+    fn calculate_output_potential(&self) -> ElectricPotential {
+        let open_circuit_potential =
+            Battery::calculate_output_potential_for_charge(self.new_battery_equivalent_charge());
+        if open_circuit_potential <= ElectricPotential::new::<volt>(0.) {
+            return open_circuit_potential;
+        }
+
+        let hot_decrease = ElectricPotential::new::<volt>(
+            (self.temperature_in_celsius() - Self::POTENTIAL_DECREASE_ABOVE_DEG_C).max(0.)
+                * Self::POTENTIAL_DECREASE_VOLT_PER_DEG_C,
+        );
+
+        // The potential curve already accounts for the internal resistance of a new battery
+        // at the reference temperature, thus only the increase causes an additional drop.
+        let additional_resistance = (self.internal_resistance()
+            - ElectricalResistance::new::<ohm>(Self::INTERNAL_RESISTANCE_OHM))
+        .max(ElectricalResistance::new::<ohm>(0.));
+        let discharge_current = (-self.current).max(ElectricCurrent::new::<ampere>(0.));
+
+        (open_circuit_potential - hot_decrease - discharge_current * additional_resistance)
+            .max(ElectricPotential::new::<volt>(0.))
+    }
+
     fn calculate_output_potential_for_charge(charge: ElectricCharge) -> ElectricPotential {
         // There are four distinct charges, being:
         // 1. No charge, giving no potential.
@@ -120,18 +301,91 @@ impl Battery {
         })
     }
 
-    fn calculate_charging_current(
-        input: ElectricPotential,
-        output: ElectricPotential,
-    ) -> ElectricCurrent {
-        // Internal resistance = 0.011 ohm. However that would make current go through the roof.
+    fn calculate_charging_current(&self) -> ElectricCurrent {
+        // Only using the internal resistance would make current go through the roof.
         // Thus we add some fake wire resistance here too. If needed, later one can
         // add resistance of wires between buses to calculate correct values.
-        let resistance = ElectricalResistance::new::<ohm>(0.15);
-        ((input - output) / resistance)
+        let resistance = self.internal_resistance()
+            + ElectricalResistance::new::<ohm>(Self::WIRE_RESISTANCE_OHM);
+        ((self.input_potential - self.output_potential) / resistance)
             .min(ElectricCurrent::new::<ampere>(10.))
             .max(ElectricCurrent::new::<ampere>(0.))
     }
+
+    /// The current flowing through the cells. A nearly empty battery can't deliver more than
+    /// its short circuit current, even though its low potential results in a large current
+    /// for the power it supplies.
+    fn cell_current(&self) -> ElectricCurrent {
+        let short_circuit_current =
+            Battery::calculate_output_potential_for_charge(self.new_battery_equivalent_charge())
+                / self.internal_resistance();
+
+        self.current
+            .min(short_circuit_current)
+            .max(-short_circuit_current)
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext) {
+        let cell_current = self.cell_current();
+        let resistive_heat =
+            (cell_current * cell_current * self.internal_resistance()).get::<watt>();
+
+        let charging_heat = if self.current > ElectricCurrent::new::<ampere>(0.) {
+            let overcharge = (self.new_battery_equivalent_charge().get::<ampere_hour>()
+                / Self::RATED_CAPACITY_AMPERE_HOURS
+                - 1.)
+                .max(0.);
+            let heat_ratio = Self::CHARGING_HEAT_RATIO
+                + (1. - Self::CHARGING_HEAT_RATIO)
+                    * (overcharge / Self::OVERCHARGE_FULL_HEAT_RATIO).min(1.);
+
+            (self.input_potential * self.current).get::<watt>() * heat_ratio
+        } else {
+            0.
+        };
+
+        let thermal_runaway_heat = if self.thermal_runaway.is_active() {
+            Self::THERMAL_RUNAWAY_HEAT_WATT
+        } else {
+            0.
+        };
+
+        let heat_loss = (self.temperature_in_celsius()
+            - self.bay_temperature.get::<degree_celsius>())
+            * Self::HEAT_TRANSFER_TO_BAY_WATT_PER_KELVIN;
+
+        let temperature_change = (resistive_heat + charging_heat + thermal_runaway_heat
+            - heat_loss)
+            * context.delta_as_secs_f64()
+            / Self::HEAT_CAPACITY_JOULE_PER_KELVIN;
+        self.temperature = ThermodynamicTemperature::new::<degree_celsius>(
+            self.temperature_in_celsius() + temperature_change,
+        );
+    }
+
+    fn update_state_of_health(&mut self, context: &UpdateContext) {
+        let ampere_hours =
+            self.cell_current().get::<ampere>().abs() * context.delta_as_secs_f64() / 3600.;
+        let temperature_factor = 2_f64.powf(
+            (self.temperature_in_celsius() - Self::ACCELERATED_AGEING_ABOVE_DEG_C).max(0.) / 10.,
+        );
+
+        self.state_of_health = Ratio::new::<ratio>(
+            (self.state_of_health.get::<ratio>()
+                - ampere_hours * Self::HEALTH_LOSS_PER_AMPERE_HOUR * temperature_factor)
+                .max(0.),
+        );
+    }
+
+    fn discharge_through_internal_short(&mut self, context: &UpdateContext) {
+        if self.thermal_runaway.is_active() {
+            let time = Time::new::<second>(context.delta_as_secs_f64());
+            self.charge -=
+                (ElectricCurrent::new::<ampere>(Self::THERMAL_RUNAWAY_INTERNAL_CURRENT_AMPERE)
+                    * time)
+                    .min(self.charge);
+        }
+    }
 }
 impl ProvideCurrent for Battery {
     fn current(&self) -> ElectricCurrent {
@@ -153,6 +407,16 @@ impl ProvidePotential for Battery {
             .contains(&ProvidePotential::potential(self))
     }
 }
+impl ProvideTemperature for Battery {
+    fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// A cold battery delivers less, but is not considered abnormal.
+    fn temperature_normal(&self) -> bool {
+        self.temperature_in_celsius() < Self::OVERHEAT_TEMPERATURE_DEG_C
+    }
+}
 impl ElectricalElement for Battery {
     fn input_identifier(&self) -> ElectricalElementIdentifier {
         self.identifier
@@ -176,22 +440,59 @@ impl ElectricitySource for Battery {
     }
 }
 impl SimulationElement for Battery {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.thermal_runaway.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        if !self.state_of_health_is_initialised {
+            let stored_state_of_health: Ratio = reader.read(&self.stored_state_of_health_id);
+
+            // Nothing stored reads as zero
+            if stored_state_of_health > Ratio::new::<ratio>(0.) {
+                self.set_state_of_health(stored_state_of_health.min(Ratio::new::<ratio>(1.)));
+                self.state_of_health_is_initialised = true;
+            }
+        }
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_direct(self, writer);
+        writer.write(&self.temperature_id, self.temperature);
+        writer.write(&self.temperature_normal_id, self.temperature_normal());
+        writer.write(&self.state_of_health_id, self.state_of_health);
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.charge);
+        snapshot.write(self.state_of_health);
+        snapshot.write(self.temperature);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        let charge = snapshot.read();
+        self.state_of_health = snapshot.read();
+        self.state_of_health_is_initialised = true;
+        self.temperature = snapshot.read();
+        self.temperature_is_initialised = true;
+        self.set_charge(charge);
     }
 
     fn consume_power<T: ConsumePower>(&mut self, context: &UpdateContext, consumption: &mut T) {
         self.input_potential = consumption.input_of(self).raw();
 
         if self.is_powered_by_other_potential() {
-            self.current =
-                Battery::calculate_charging_current(self.input_potential, self.output_potential);
+            self.current = self.calculate_charging_current();
 
             let power = self.input_potential * self.current;
             consumption.consume_from_input(self, power);
 
             let time = Time::new::<second>(context.delta_as_secs_f64());
-            self.charge += ((self.input_potential * self.current) * time) / self.input_potential;
+            self.charge = (self.charge
+                + ((self.input_potential * self.current) * time) / self.input_potential)
+                .min(self.max_charge());
         }
     }
 
@@ -211,11 +512,16 @@ impl SimulationElement for Battery {
 
             if self.output_potential > ElectricPotential::new::<volt>(0.) {
                 let time = Time::new::<second>(context.delta_as_secs_f64());
-                self.charge -= ((consumption * time) / self.output_potential).min(self.charge);
+                self.charge -=
+                    ((consumption * time) / self.output_potential).min(self.available_charge());
             }
         }
 
-        self.output_potential = Battery::calculate_output_potential_for_charge(self.charge);
+        self.discharge_through_internal_short(context);
+        self.update_temperature(context);
+        self.update_state_of_health(context);
+
+        self.output_potential = self.calculate_output_potential();
     }
 }
 
@@ -226,7 +532,7 @@ mod tests {
     #[cfg(test)]
     mod battery_tests {
         use super::*;
-        use crate::simulation::test::ReadByName;
+        use crate::simulation::test::{ReadByName, WriteByName};
         use crate::simulation::InitContext;
         use crate::{
             electrical::{
@@ -269,6 +575,18 @@ mod tests {
                 }
             }
 
+            fn with_quarter_charged_batteries() -> Self {
+                Self {
+                    test_bed: SimulationTestBed::new(|context| {
+                        TestAircraft::new(
+                            Battery::new(context, 1, ElectricCharge::new::<ampere_hour>(5.75)),
+                            Battery::new(context, 2, ElectricCharge::new::<ampere_hour>(5.75)),
+                            context,
+                        )
+                    }),
+                }
+            }
+
             fn with_nearly_empty_batteries() -> Self {
                 Self {
                     test_bed: SimulationTestBed::new(|context| {
@@ -332,6 +650,24 @@ mod tests {
             fn potential(&mut self, number: usize) -> ElectricPotential {
                 self.read_by_name(&format!("ELEC_BAT_{}_POTENTIAL", number))
             }
+
+            fn temperature(&mut self, number: usize) -> ThermodynamicTemperature {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE", number))
+            }
+
+            fn temperature_is_normal(&mut self, number: usize) -> bool {
+                self.read_by_name(&format!("ELEC_BAT_{}_TEMPERATURE_NORMAL", number))
+            }
+
+            fn state_of_health(&mut self, number: usize) -> Ratio {
+                self.read_by_name(&format!("ELEC_BAT_{}_STATE_OF_HEALTH", number))
+            }
+
+            fn run_for_minutes(&mut self, minutes: u64) {
+                for _ in 0..minutes {
+                    self.run_with_delta(Duration::from_secs(60));
+                }
+            }
         }
         impl TestBed for BatteryTestBed {
             type Aircraft = TestAircraft;
@@ -354,6 +690,7 @@ mod tests {
             battery_2_contactor: Contactor,
             consumer: PowerConsumer,
             battery_consumption: Power,
+            bay_temperature: Option<ThermodynamicTemperature>,
         }
         impl TestAircraft {
             fn new(battery_1: Battery, battery_2: Battery, context: &mut InitContext) -> Self {
@@ -369,6 +706,7 @@ mod tests {
                     battery_2_contactor: Contactor::new(context, "BAT2"),
                     consumer: PowerConsumer::from(ElectricalBusType::DirectCurrentBattery),
                     battery_consumption: Power::new::<watt>(0.),
+                    bay_temperature: None,
                 };
 
                 aircraft.battery_1_contactor.close_when(true);
@@ -399,13 +737,26 @@ mod tests {
             fn bat_bus_is_powered(&self, electricity: &Electricity) -> bool {
                 electricity.is_powered(&self.bat_bus)
             }
+
+            fn set_bay_temperature(&mut self, temperature: ThermodynamicTemperature) {
+                self.bay_temperature = Some(temperature);
+            }
+
+            fn set_battery_1_state_of_health(&mut self, state_of_health: Ratio) {
+                self.battery_1.set_state_of_health(state_of_health);
+            }
         }
         impl Aircraft for TestAircraft {
             fn update_before_power_distribution(
                 &mut self,
-                _: &UpdateContext,
+                context: &UpdateContext,
                 electricity: &mut Electricity,
             ) {
+                if let Some(bay_temperature) = self.bay_temperature {
+                    self.battery_1.update(context, bay_temperature);
+                    self.battery_2.update(context, bay_temperature);
+                }
+
                 electricity.supplied_by(&self.battery_1);
                 electricity.supplied_by(&self.battery_2);
                 electricity.flow(&self.battery_1, &self.battery_1_contactor);
@@ -695,5 +1046,239 @@ mod tests {
                     < ElectricCharge::new::<ampere_hour>(0.001)
             );
         }
+
+        #[test]
+        fn restoring_a_snapshot_restores_the_charge() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            let snapshot = test_bed.snapshot();
+
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.restore(&snapshot).unwrap();
+
+            assert!(
+                (test_bed.query(|a| a.battery_1_charge())
+                    - ElectricCharge::new::<ampere_hour>(11.5))
+                .abs()
+                    < ElectricCharge::new::<ampere_hour>(0.001)
+            );
+            assert!(test_bed.potential(1) < ElectricPotential::new::<volt>(27.5));
+        }
+
+        #[test]
+        fn cold_battery_has_lower_potential_under_load() {
+            let mut warm_test_bed = BatteryTestBed::with_full_batteries();
+            warm_test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.))
+            });
+            warm_test_bed.command(|a| a.power_demand(Power::new::<watt>(1000.)));
+            warm_test_bed.run();
+
+            let mut cold_test_bed = BatteryTestBed::with_full_batteries();
+            cold_test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.))
+            });
+            cold_test_bed.command(|a| a.power_demand(Power::new::<watt>(1000.)));
+            cold_test_bed.run();
+
+            assert!(cold_test_bed.potential(1) < warm_test_bed.potential(1));
+        }
+
+        #[test]
+        fn cold_battery_cannot_deliver_its_full_charge() {
+            let mut test_bed = BatteryTestBed::with_nearly_empty_batteries();
+            test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(-30.))
+            });
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(1000.)));
+            test_bed.run_with_delta(Duration::from_secs(60));
+
+            assert!(
+                test_bed.query(|a| a.battery_1_charge()) > ElectricCharge::new::<ampere_hour>(0.)
+            );
+            assert!(!test_bed.query_elec(|a, elec| a.bat_bus_is_powered(elec)));
+        }
+
+        #[test]
+        fn when_first_updated_on_the_ground_battery_is_at_ambient_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-10.));
+            test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.))
+            });
+
+            test_bed.run();
+
+            assert!(
+                (test_bed.temperature(1).get::<degree_celsius>() + 10.).abs() < 0.1,
+                "Battery temperature: {:?}",
+                test_bed.temperature(1)
+            );
+        }
+
+        #[test]
+        fn battery_warms_up_towards_bay_temperature() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(0.));
+            test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(30.))
+            });
+
+            test_bed.run_for_minutes(60);
+
+            let temperature = test_bed.temperature(1).get::<degree_celsius>();
+            assert!(temperature > 10. && temperature < 30.);
+            assert!(test_bed.temperature_is_normal(1));
+        }
+
+        #[test]
+        fn battery_in_thermal_runaway_overheats() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.))
+            });
+            test_bed.fail(FailureType::BatteryThermalRunaway(1));
+
+            test_bed.run_for_minutes(20);
+
+            assert!(!test_bed.temperature_is_normal(1));
+            assert!(test_bed.temperature_is_normal(2));
+        }
+
+        #[test]
+        fn battery_in_thermal_runaway_discharges_through_internal_short() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(20.))
+            });
+            test_bed.fail(FailureType::BatteryThermalRunaway(2));
+
+            test_bed.run_for_minutes(10);
+
+            assert!(
+                test_bed.query(|a| a.battery_2_charge()) < test_bed.query(|a| a.battery_1_charge())
+            );
+        }
+
+        #[test]
+        fn state_of_health_decreases_when_discharging() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| a.power_demand(Power::new::<watt>(200.)));
+
+            test_bed.run_for_minutes(30);
+
+            assert!(test_bed.state_of_health(1) < Ratio::new::<ratio>(1.));
+            assert_eq!(test_bed.state_of_health(2), Ratio::new::<ratio>(1.));
+        }
+
+        #[test]
+        fn aged_battery_at_same_state_of_charge_has_lower_potential_under_load() {
+            // The potential drop caused by the load is only known at the end of a tick,
+            // thus a second tick is needed before it shows.
+            let mut new_test_bed = BatteryTestBed::with_half_charged_batteries();
+            new_test_bed.command(|a| a.power_demand(Power::new::<watt>(1000.)));
+            new_test_bed.run();
+            new_test_bed.run();
+
+            let mut aged_test_bed = BatteryTestBed::with_quarter_charged_batteries();
+            aged_test_bed.command(|a| a.set_battery_1_state_of_health(Ratio::new::<ratio>(0.5)));
+            aged_test_bed.command(|a| a.power_demand(Power::new::<watt>(1000.)));
+            aged_test_bed.run();
+            aged_test_bed.run();
+
+            assert!(aged_test_bed.potential(1) < new_test_bed.potential(1));
+        }
+
+        #[test]
+        fn full_aged_battery_holds_no_more_than_its_capacity() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| a.set_battery_1_state_of_health(Ratio::new::<ratio>(0.9)));
+
+            test_bed.run();
+
+            assert!(
+                test_bed.query(|a| a.battery_1_charge())
+                    <= ElectricCharge::new::<ampere_hour>(Battery::MAX_CHARGE_AMPERE_HOURS * 0.9)
+            );
+            assert!(test_bed.potential_is_normal(1));
+        }
+
+        #[test]
+        fn aged_battery_does_not_charge_beyond_its_capacity() {
+            let mut test_bed = BatteryTestBed::with_half_charged_batteries();
+            test_bed.command(|a| a.set_battery_1_state_of_health(Ratio::new::<ratio>(0.8)));
+            test_bed.command(|a| a.supply_input_potential(ElectricPotential::new::<volt>(28.)));
+
+            test_bed.run_for_minutes(120);
+
+            assert!(
+                test_bed.query(|a| a.battery_1_charge())
+                    <= ElectricCharge::new::<ampere_hour>(Battery::MAX_CHARGE_AMPERE_HOURS * 0.8)
+            );
+        }
+
+        #[test]
+        fn full_battery_with_stored_aged_state_of_health_has_normal_potential() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.write_by_name(
+                "ELEC_BAT_1_STORED_STATE_OF_HEALTH",
+                Ratio::new::<ratio>(0.9),
+            );
+
+            test_bed.run();
+
+            assert!(test_bed.potential_is_normal(1));
+        }
+
+        #[test]
+        fn restoring_a_snapshot_restores_the_temperature_and_state_of_health() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.command(|a| {
+                a.set_bay_temperature(ThermodynamicTemperature::new::<degree_celsius>(35.))
+            });
+            test_bed.command(|a| a.set_battery_1_state_of_health(Ratio::new::<ratio>(0.8)));
+            test_bed.run();
+            let snapshot = test_bed.snapshot();
+
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.restore(&snapshot).unwrap();
+            test_bed.run();
+
+            assert!((test_bed.temperature(1).get::<degree_celsius>() - 35.).abs() < 0.1);
+            assert!((test_bed.state_of_health(1).get::<ratio>() - 0.8).abs() < 0.001);
+        }
+
+        #[test]
+        fn stored_state_of_health_is_read_back() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.write_by_name(
+                "ELEC_BAT_1_STORED_STATE_OF_HEALTH",
+                Ratio::new::<ratio>(0.7),
+            );
+
+            test_bed.run();
+
+            assert!((test_bed.state_of_health(1).get::<ratio>() - 0.7).abs() < 0.001);
+            assert_eq!(test_bed.state_of_health(2), Ratio::new::<ratio>(1.));
+        }
+
+        #[test]
+        fn stored_state_of_health_is_only_read_back_once() {
+            let mut test_bed = BatteryTestBed::with_full_batteries();
+            test_bed.write_by_name(
+                "ELEC_BAT_1_STORED_STATE_OF_HEALTH",
+                Ratio::new::<ratio>(0.7),
+            );
+            test_bed.run();
+
+            test_bed.write_by_name(
+                "ELEC_BAT_1_STORED_STATE_OF_HEALTH",
+                Ratio::new::<ratio>(0.5),
+            );
+            test_bed.run();
+
+            assert!((test_bed.state_of_health(1).get::<ratio>() - 0.7).abs() < 0.001);
+        }
     }
 }
@@ -1,6 +1,6 @@
 use super::{
     AlternatingCurrentElectricalSystem, BatteryPushButtons, ElectricalElement, Electricity,
-    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential,
+    ElectricitySource, EmergencyElectrical, ProvideCurrent, ProvidePotential, ProvideTemperature,
 };
 use crate::simulation::{InitContext, VariableIdentifier};
 use crate::{
@@ -80,6 +80,7 @@ pub struct BatteryChargeLimiter {
     should_show_arrow_when_contactor_closed_id: VariableIdentifier,
     arrow: ArrowBetweenBatteryAndBatBus,
     observer: Option<State>,
+    thermal_runaway_detected: bool,
 }
 impl BatteryChargeLimiter {
     pub fn new(context: &mut InitContext, number: usize, contactor_id: &str) -> Self {
@@ -91,6 +92,7 @@ impl BatteryChargeLimiter {
             )),
             arrow: ArrowBetweenBatteryAndBatBus::new(),
             observer: Some(State::new()),
+            thermal_runaway_detected: false,
         }
     }
 
@@ -100,7 +102,7 @@ impl BatteryChargeLimiter {
         electricity: &Electricity,
         emergency_elec: &EmergencyElectrical,
         emergency_generator: &impl ElectricitySource,
-        battery: &(impl ProvidePotential + ProvideCurrent),
+        battery: &(impl ProvidePotential + ProvideCurrent + ProvideTemperature),
         battery_bus: &impl ElectricalElement,
         lgciu1: &impl LgciuWeightOnWheels,
         battery_push_buttons: &impl BatteryPushButtons,
@@ -110,6 +112,14 @@ impl BatteryChargeLimiter {
     ) {
         self.arrow.update(context, battery);
 
+        // A detected thermal runaway keeps the battery disconnected until the BCL
+        // is reset by switching the BAT push button OFF.
+        if !battery_push_buttons.bat_is_auto(self.number) {
+            self.thermal_runaway_detected = false;
+        } else if !battery.temperature_normal() {
+            self.thermal_runaway_detected = true;
+        }
+
         if let Some(observer) = self.observer.take() {
             self.observer = Some(observer.update(
                 context,
@@ -129,7 +139,12 @@ impl BatteryChargeLimiter {
     }
 
     pub fn should_close_contactor(&self) -> bool {
-        self.observer.as_ref().unwrap().should_close_contactor()
+        !self.thermal_runaway_detected && self.observer.as_ref().unwrap().should_close_contactor()
+    }
+
+    /// Indicates if the BAT push button's FAULT light should illuminate.
+    pub fn has_fault(&self) -> bool {
+        self.thermal_runaway_detected
     }
 }
 impl SimulationElement for BatteryChargeLimiter {
@@ -571,6 +586,7 @@ mod tests {
                 ElectricalElementIdentifier, ElectricalElementIdentifierProvider, Electricity,
                 Potential, PotentialOrigin,
             },
+            failures::FailureType,
             simulation::{
                 test::{ReadByName, SimulationTestBed, TestBed},
                 Aircraft, InitContext, SimulationElementVisitor,
@@ -723,6 +739,15 @@ mod tests {
                 self
             }
 
+            fn battery_in_thermal_runaway(mut self) -> Self {
+                self.fail(FailureType::BatteryThermalRunaway(1));
+                for _ in 0..20 {
+                    self = self.run(Duration::from_secs(60));
+                }
+
+                self
+            }
+
             fn and(self) -> Self {
                 self
             }
@@ -766,6 +791,10 @@ mod tests {
                 self.query(|a| a.battery_contactor_is_closed())
             }
 
+            fn has_fault(&self) -> bool {
+                self.query(|a| a.battery_charge_limiter.has_fault())
+            }
+
             fn apu_master_sw_pb_on(mut self) -> Self {
                 self.command(|a| a.set_apu_master_sw_pb_on());
                 self
@@ -1614,6 +1643,29 @@ mod tests {
             assert!(!test_bed.battery_contactor_is_closed());
         }
 
+        #[test]
+        fn thermal_runaway_opens_the_contactor_and_indicates_a_fault() {
+            let test_bed = test_bed()
+                .wait_for_closed_contactor(true)
+                .then_continue_with()
+                .battery_in_thermal_runaway();
+
+            assert!(!test_bed.battery_contactor_is_closed());
+            assert!(test_bed.has_fault());
+        }
+
+        #[test]
+        fn thermal_runaway_fault_is_reset_by_turning_off_the_battery() {
+            let test_bed = test_bed()
+                .wait_for_closed_contactor(true)
+                .then_continue_with()
+                .battery_in_thermal_runaway()
+                .and()
+                .battery_push_button_off();
+
+            assert!(!test_bed.has_fault());
+        }
+
         #[test]
         fn contactor_doesnt_close_while_the_battery_is_off() {
             let test_bed = test_bed_with()
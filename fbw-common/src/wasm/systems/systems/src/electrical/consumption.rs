@@ -59,6 +59,52 @@ impl SimulationElement for PowerConsumer {
     }
 }
 
+/// A power consumer which represents a single system, such as a heater, a light or a galley.
+/// It declares the bus it receives power from and its demand when running. The owning system
+/// determines from its own state whether, and to what extent, the consumer is running.
+pub struct SwitchedPowerConsumer {
+    consumer: PowerConsumer,
+    demand_when_running: Power,
+    is_running: bool,
+}
+impl SwitchedPowerConsumer {
+    pub fn new(bus_type: ElectricalBusType, demand_when_running: Power) -> Self {
+        Self {
+            consumer: PowerConsumer::from(bus_type),
+            demand_when_running,
+            is_running: false,
+        }
+    }
+
+    /// Switches the consumer on or off.
+    pub fn update(&mut self, is_on: bool) {
+        self.update_ratio(if is_on { 1. } else { 0. });
+    }
+
+    /// Runs the consumer at the given ratio of its full demand.
+    /// Used by systems which operate at reduced power, such as heaters on the ground.
+    pub fn update_ratio(&mut self, ratio: f64) {
+        let ratio = ratio.clamp(0., 1.);
+        self.is_running = ratio > 0. && self.consumer.is_powered;
+        self.consumer.demand(self.demand_when_running * ratio);
+    }
+
+    pub fn is_powered(&self) -> bool {
+        self.consumer.is_powered
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+}
+impl SimulationElement for SwitchedPowerConsumer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumer.accept(visitor);
+
+        visitor.visit(self);
+    }
+}
+
 /// A special type of power consumer which changes its consumption
 /// based on the phase of the flight.
 pub struct FlightPhasePowerConsumer {
@@ -351,4 +397,146 @@ mod tests {
             assert!(test_bed.query(|a| a.consumption_equals(Power::new::<watt>(0.))));
         }
     }
+
+    #[cfg(test)]
+    mod switched_power_consumer_tests {
+        use crate::{
+            electrical::{test::TestElectricitySource, ElectricalBus},
+            simulation::{
+                test::{SimulationTestBed, TestBed},
+                Aircraft, InitContext,
+            },
+        };
+
+        use super::*;
+
+        struct SwitchedPowerConsumerTestAircraft {
+            electricity_source: TestElectricitySource,
+            apu_generator_consumption: Option<Power>,
+            consumer: SwitchedPowerConsumer,
+            bus: ElectricalBus,
+            ratio: f64,
+        }
+        impl SwitchedPowerConsumerTestAircraft {
+            fn new(context: &mut InitContext, demand: Power) -> Self {
+                Self {
+                    electricity_source: TestElectricitySource::unpowered(
+                        context,
+                        PotentialOrigin::ApuGenerator(1),
+                    ),
+                    apu_generator_consumption: None,
+                    consumer: SwitchedPowerConsumer::new(
+                        ElectricalBusType::AlternatingCurrent(1),
+                        demand,
+                    ),
+                    bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                    ratio: 0.,
+                }
+            }
+
+            fn power(&mut self) {
+                self.electricity_source.power()
+            }
+
+            fn set_ratio(&mut self, ratio: f64) {
+                self.ratio = ratio;
+            }
+
+            fn consumption(&self) -> Option<Power> {
+                self.apu_generator_consumption
+            }
+        }
+        impl Aircraft for SwitchedPowerConsumerTestAircraft {
+            fn update_before_power_distribution(
+                &mut self,
+                _: &UpdateContext,
+                electricity: &mut crate::electrical::Electricity,
+            ) {
+                electricity.supplied_by(&self.electricity_source);
+                electricity.flow(&self.electricity_source, &self.bus);
+            }
+
+            fn update_after_power_distribution(&mut self, _: &UpdateContext) {
+                self.consumer.update_ratio(self.ratio);
+            }
+        }
+        impl SimulationElement for SwitchedPowerConsumerTestAircraft {
+            fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+                self.consumer.accept(visitor);
+
+                visitor.visit(self);
+            }
+
+            fn process_power_consumption_report<T: PowerConsumptionReport>(
+                &mut self,
+                _: &UpdateContext,
+                report: &T,
+            ) {
+                self.apu_generator_consumption =
+                    Some(report.total_consumption_of(PotentialOrigin::ApuGenerator(1)));
+            }
+        }
+
+        #[test]
+        fn switched_off_consumer_consumes_nothing() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                SwitchedPowerConsumerTestAircraft::new(context, Power::new::<watt>(1000.))
+            });
+            test_bed.command(|a| a.power());
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.consumption()),
+                Some(Power::new::<watt>(0.))
+            );
+            assert!(!test_bed.query(|a| a.consumer.is_running()));
+        }
+
+        #[test]
+        fn switched_on_consumer_consumes_its_demand() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                SwitchedPowerConsumerTestAircraft::new(context, Power::new::<watt>(1000.))
+            });
+            test_bed.command(|a| a.power());
+            test_bed.command(|a| a.set_ratio(1.));
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.consumption()),
+                Some(Power::new::<watt>(1000.))
+            );
+            assert!(test_bed.query(|a| a.consumer.is_running()));
+        }
+
+        #[test]
+        fn consumer_running_at_reduced_ratio_consumes_part_of_its_demand() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                SwitchedPowerConsumerTestAircraft::new(context, Power::new::<watt>(1000.))
+            });
+            test_bed.command(|a| a.power());
+            test_bed.command(|a| a.set_ratio(0.25));
+
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.query(|a| a.consumption()),
+                Some(Power::new::<watt>(250.))
+            );
+        }
+
+        #[test]
+        fn unpowered_consumer_is_not_running() {
+            let mut test_bed = SimulationTestBed::new(|context| {
+                SwitchedPowerConsumerTestAircraft::new(context, Power::new::<watt>(1000.))
+            });
+            test_bed.command(|a| a.set_ratio(1.));
+
+            test_bed.run();
+
+            assert!(!test_bed.query(|a| a.consumer.is_running()));
+            assert!(!test_bed.query(|a| a.consumer.is_powered()));
+        }
+    }
 }
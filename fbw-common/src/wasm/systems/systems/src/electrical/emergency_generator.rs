@@ -1,4 +1,6 @@
-use crate::simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext};
+use crate::simulation::{
+    InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext,
+};
 
 use super::{
     ElectricalElement, ElectricalElementIdentifier, ElectricalElementIdentifierProvider,
@@ -119,6 +121,22 @@ impl SimulationElement for EmergencyGenerator {
     fn write(&self, writer: &mut SimulatorWriter) {
         self.writer.write_alternating(self, writer);
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.supplying);
+        snapshot.write(self.output_frequency);
+        snapshot.write(self.output_potential);
+        snapshot.write(self.generated_power);
+        snapshot.write(self.demand);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.supplying = snapshot.read();
+        self.output_frequency = snapshot.read();
+        self.output_potential = snapshot.read();
+        self.generated_power = snapshot.read();
+        self.demand = snapshot.read();
+    }
 }
 
 #[cfg(test)]
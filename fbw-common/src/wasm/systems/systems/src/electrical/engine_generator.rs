@@ -138,7 +138,7 @@ impl SimulationElement for EngineGenerator {
     }
 }
 
-struct IntegratedDriveGenerator {
+pub(super) struct IntegratedDriveGenerator {
     oil_outlet_temperature_id: VariableIdentifier,
     oil_outlet_temperature: ThermodynamicTemperature,
     is_connected_id: VariableIdentifier,
@@ -146,13 +146,10 @@ struct IntegratedDriveGenerator {
     activated: bool,
     number: usize,
 
-    time_above_threshold_in_milliseconds: u64,
+    stabilization_timer: N2StabilizationTimer,
 }
 impl IntegratedDriveGenerator {
-    pub const ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD: f64 = 58.;
-    pub const ENGINE_N2_POWER_DOWN_OUTPUT_THRESHOLD: f64 = 56.;
-
-    fn new(context: &mut InitContext, number: usize) -> IntegratedDriveGenerator {
+    pub(super) fn new(context: &mut InitContext, number: usize) -> IntegratedDriveGenerator {
         IntegratedDriveGenerator {
             oil_outlet_temperature_id: context.get_identifier(format!(
                 "ELEC_ENG_GEN_{}_IDG_OIL_OUTLET_TEMPERATURE",
@@ -165,12 +162,11 @@ impl IntegratedDriveGenerator {
             activated: true,
             number,
 
-            time_above_threshold_in_milliseconds:
-                INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+            stabilization_timer: N2StabilizationTimer::new(),
         }
     }
 
-    pub fn update(
+    pub(super) fn update(
         &mut self,
         context: &UpdateContext,
         engine: &impl EngineCorrectedN2,
@@ -185,55 +181,19 @@ impl IntegratedDriveGenerator {
         self.activated = generator_buttons.engine_gen_push_button_is_on(self.number)
             && !fire_buttons.is_released(self.number);
 
-        self.update_stable_time(context, engine.corrected_n2());
+        self.stabilization_timer.update(
+            context,
+            engine.corrected_n2(),
+            self.connected && self.activated,
+        );
         self.update_temperature(
             context,
             self.get_target_temperature(context, engine.corrected_n2()),
         );
     }
 
-    fn provides_stable_power_output(&self) -> bool {
-        self.time_above_threshold_in_milliseconds
-            == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS
-    }
-
-    fn update_stable_time(&mut self, context: &UpdateContext, corrected_n2: Ratio) {
-        if !self.connected {
-            self.time_above_threshold_in_milliseconds = 0;
-            return;
-        }
-
-        if !self.activated {
-            self.time_above_threshold_in_milliseconds = 0;
-            return;
-        }
-
-        let mut new_time = self.time_above_threshold_in_milliseconds;
-        if corrected_n2
-            >= Ratio::new::<percent>(IntegratedDriveGenerator::ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD)
-            && self.time_above_threshold_in_milliseconds
-                < INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS
-        {
-            new_time =
-                self.time_above_threshold_in_milliseconds + context.delta().as_millis() as u64;
-        } else if corrected_n2
-            <= Ratio::new::<percent>(
-                IntegratedDriveGenerator::ENGINE_N2_POWER_DOWN_OUTPUT_THRESHOLD,
-            )
-            && self.time_above_threshold_in_milliseconds > 0
-        {
-            new_time = self.time_above_threshold_in_milliseconds
-                - min(
-                    context.delta().as_millis() as u64,
-                    self.time_above_threshold_in_milliseconds,
-                );
-        }
-
-        self.time_above_threshold_in_milliseconds = clamp(
-            new_time,
-            0,
-            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
-        );
+    pub(super) fn provides_stable_power_output(&self) -> bool {
+        self.stabilization_timer.is_stable()
     }
 
     fn update_temperature(&mut self, context: &UpdateContext, target: ThermodynamicTemperature) {
@@ -277,6 +237,60 @@ impl SimulationElement for IntegratedDriveGenerator {
     }
 }
 
+/// Delays the output of an engine driven generator until the engine's N2 stayed above the
+/// power up threshold for the stabilization time. The output is lost again once N2 stayed
+/// below the power down threshold for that time, or immediately when the generator is
+/// disconnected or switched off.
+pub(super) struct N2StabilizationTimer {
+    time_above_threshold_in_milliseconds: u64,
+}
+impl N2StabilizationTimer {
+    const ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD: f64 = 58.;
+    const ENGINE_N2_POWER_DOWN_OUTPUT_THRESHOLD: f64 = 56.;
+
+    pub(super) fn new() -> Self {
+        Self {
+            time_above_threshold_in_milliseconds:
+                INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+        }
+    }
+
+    pub(super) fn update(&mut self, context: &UpdateContext, corrected_n2: Ratio, is_driven: bool) {
+        if !is_driven {
+            self.time_above_threshold_in_milliseconds = 0;
+            return;
+        }
+
+        let mut new_time = self.time_above_threshold_in_milliseconds;
+        if corrected_n2 >= Ratio::new::<percent>(Self::ENGINE_N2_POWER_UP_OUTPUT_THRESHOLD)
+            && self.time_above_threshold_in_milliseconds
+                < INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS
+        {
+            new_time =
+                self.time_above_threshold_in_milliseconds + context.delta().as_millis() as u64;
+        } else if corrected_n2 <= Ratio::new::<percent>(Self::ENGINE_N2_POWER_DOWN_OUTPUT_THRESHOLD)
+            && self.time_above_threshold_in_milliseconds > 0
+        {
+            new_time = self.time_above_threshold_in_milliseconds
+                - min(
+                    context.delta().as_millis() as u64,
+                    self.time_above_threshold_in_milliseconds,
+                );
+        }
+
+        self.time_above_threshold_in_milliseconds = clamp(
+            new_time,
+            0,
+            INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS,
+        );
+    }
+
+    pub(super) fn is_stable(&self) -> bool {
+        self.time_above_threshold_in_milliseconds
+            == INTEGRATED_DRIVE_GENERATOR_STABILIZATION_TIME_IN_MILLISECONDS
+    }
+}
+
 /// Experimental feature copied from Rust stb lib.
 fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
     assert!(min <= max);
@@ -1,10 +1,11 @@
-use uom::si::{electric_potential::volt, f64::*, frequency::hertz};
+use uom::si::{electric_potential::volt, f64::*, frequency::hertz, power::watt};
 
 use crate::{
+    failures::{Failure, FailureType},
     shared::PowerConsumptionReport,
     simulation::{
-        InitContext, Read, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
-        VariableIdentifier,
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier,
     },
 };
 
@@ -14,40 +15,152 @@ use super::{
     ProvidePotential,
 };
 
+/// A ground power unit connected to one of the aircraft's external power receptacles.
+///
+/// The unit regulates its output to the nominal potential and frequency, though its
+/// potential sags under load. Connecting the unit causes a short overshoot and load steps
+/// cause a short dip, both of which decay as the unit's regulator catches up.
+/// Failures of the unit are judged by the aircraft against its own normal ranges,
+/// independently of the unit's nominal output.
 pub struct ExternalPowerSource {
+    number: usize,
     external_power_available_id: VariableIdentifier,
 
     identifier: ElectricalElementIdentifier,
     writer: ElectricalStateWriter,
     is_connected: bool,
+    was_connected: bool,
+    nominal_potential: ElectricPotential,
+    nominal_frequency: Frequency,
     output_frequency: Frequency,
     output_potential: ElectricPotential,
+    load_sag: ElectricPotential,
+    transient: ElectricPotential,
+
+    over_voltage: Failure,
+    under_voltage: Failure,
+    wrong_phase_rotation: Failure,
+    frequency_deviation: Failure,
 }
 impl ExternalPowerSource {
-    pub fn new(context: &mut InitContext) -> ExternalPowerSource {
+    const NOMINAL_POTENTIAL_VOLT: f64 = 115.;
+    const NOMINAL_FREQUENCY_HERTZ: f64 = 400.;
+
+    // 90 kVA at a power factor of 0.8
+    const RATED_TRUE_POWER_WATT: f64 = 72000.;
+    const POTENTIAL_SAG_AT_RATED_POWER_VOLT: f64 = 3.;
+
+    const CONNECTION_OVERSHOOT_VOLT: f64 = 20.;
+    // Part of a load step's steady state sag which additionally dips the potential
+    // until the regulator catches up
+    const LOAD_STEP_DIP_RATIO: f64 = 0.5;
+    const TRANSIENT_TIME_CONSTANT_SECONDS: f64 = 0.1;
+
+    const OVER_VOLTAGE_INCREASE_VOLT: f64 = 15.;
+    const UNDER_VOLTAGE_DECREASE_VOLT: f64 = 20.;
+    const FREQUENCY_DEVIATION_HERTZ: f64 = 25.;
+
+    /// Creates the external power source of the given receptacle. The first receptacle
+    /// writes its state without a number, as single receptacle aircraft always have.
+    pub fn new(context: &mut InitContext, number: usize) -> ExternalPowerSource {
+        ExternalPowerSource::new_with_nominal_output(
+            context,
+            number,
+            ElectricPotential::new::<volt>(Self::NOMINAL_POTENTIAL_VOLT),
+            Frequency::new::<hertz>(Self::NOMINAL_FREQUENCY_HERTZ),
+        )
+    }
+
+    /// Creates the external power source of the given receptacle, supplied by a ground
+    /// power unit which regulates to the given potential and frequency.
+    pub fn new_with_nominal_output(
+        context: &mut InitContext,
+        number: usize,
+        nominal_potential: ElectricPotential,
+        nominal_frequency: Frequency,
+    ) -> ExternalPowerSource {
         ExternalPowerSource {
+            number,
             external_power_available_id: context
-                .get_identifier("EXTERNAL POWER AVAILABLE:1".to_owned()),
+                .get_identifier(format!("EXTERNAL POWER AVAILABLE:{}", number)),
             identifier: context.next_electrical_identifier(),
-            writer: ElectricalStateWriter::new(context, "EXT_PWR"),
+            writer: ElectricalStateWriter::new(
+                context,
+                &if number == 1 {
+                    "EXT_PWR".to_owned()
+                } else {
+                    format!("EXT_PWR_{}", number)
+                },
+            ),
             is_connected: false,
+            was_connected: false,
+            nominal_potential,
+            nominal_frequency,
             output_frequency: Frequency::new::<hertz>(0.),
             output_potential: ElectricPotential::new::<volt>(0.),
+            load_sag: ElectricPotential::new::<volt>(0.),
+            transient: ElectricPotential::new::<volt>(0.),
+            over_voltage: Failure::new(FailureType::ExternalPowerOverVoltage(number)),
+            under_voltage: Failure::new(FailureType::ExternalPowerUnderVoltage(number)),
+            wrong_phase_rotation: Failure::new(FailureType::ExternalPowerWrongPhaseRotation(
+                number,
+            )),
+            frequency_deviation: Failure::new(FailureType::ExternalPowerFrequencyDeviation(number)),
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
-
-    /// Indicates if the provided electricity's potential and frequency
-    /// are within normal parameters. Use this to decide if the
+    /// Indicates if the provided electricity's potential, frequency and phase
+    /// rotation are within normal parameters. Use this to decide if the
     /// external power contactor should close.
     pub fn output_within_normal_parameters(&self) -> bool {
-        self.should_provide_output() && self.potential_normal() && self.frequency_normal()
+        self.should_provide_output()
+            && self.potential_normal()
+            && self.frequency_normal()
+            && self.phase_rotation_normal()
     }
 
     fn should_provide_output(&self) -> bool {
         self.is_connected
     }
+
+    fn phase_rotation_normal(&self) -> bool {
+        !self.wrong_phase_rotation.is_active()
+    }
+
+    fn update_transient(&mut self, context: &UpdateContext, load_sag: ElectricPotential) {
+        if !self.was_connected {
+            self.transient = ElectricPotential::new::<volt>(Self::CONNECTION_OVERSHOOT_VOLT);
+        }
+
+        self.transient *=
+            (-context.delta_as_secs_f64() / Self::TRANSIENT_TIME_CONSTANT_SECONDS).exp();
+
+        if self.was_connected {
+            self.transient -= (load_sag - self.load_sag) * Self::LOAD_STEP_DIP_RATIO;
+        }
+    }
+
+    fn calculate_potential(&self) -> ElectricPotential {
+        let mut potential = self.nominal_potential - self.load_sag + self.transient;
+
+        if self.over_voltage.is_active() {
+            potential += ElectricPotential::new::<volt>(Self::OVER_VOLTAGE_INCREASE_VOLT);
+        }
+
+        if self.under_voltage.is_active() {
+            potential -= ElectricPotential::new::<volt>(Self::UNDER_VOLTAGE_DECREASE_VOLT);
+        }
+
+        potential.max(ElectricPotential::new::<volt>(0.))
+    }
+
+    fn calculate_frequency(&self) -> Frequency {
+        if self.frequency_deviation.is_active() {
+            self.nominal_frequency + Frequency::new::<hertz>(Self::FREQUENCY_DEVIATION_HERTZ)
+        } else {
+            self.nominal_frequency
+        }
+    }
 }
 impl ElectricalElement for ExternalPowerSource {
     fn input_identifier(&self) -> super::ElectricalElementIdentifier {
@@ -65,7 +178,10 @@ impl ElectricalElement for ExternalPowerSource {
 impl ElectricitySource for ExternalPowerSource {
     fn output_potential(&self) -> Potential {
         if self.should_provide_output() {
-            Potential::new(PotentialOrigin::External, self.output_potential)
+            Potential::new(
+                PotentialOrigin::External(self.number),
+                self.output_potential,
+            )
         } else {
             Potential::none()
         }
@@ -74,6 +190,15 @@ impl ElectricitySource for ExternalPowerSource {
 provide_potential!(ExternalPowerSource, (110.0..=120.0));
 provide_frequency!(ExternalPowerSource, (390.0..=410.0));
 impl SimulationElement for ExternalPowerSource {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.over_voltage.accept(visitor);
+        self.under_voltage.accept(visitor);
+        self.wrong_phase_rotation.accept(visitor);
+        self.frequency_deviation.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.is_connected = reader.read(&self.external_power_available_id);
     }
@@ -84,20 +209,49 @@ impl SimulationElement for ExternalPowerSource {
 
     fn process_power_consumption_report<T: PowerConsumptionReport>(
         &mut self,
-        _: &UpdateContext,
-        _: &T,
+        context: &UpdateContext,
+        report: &T,
     ) {
-        self.output_frequency = if self.should_provide_output() {
-            Frequency::new::<hertz>(400.)
+        if self.should_provide_output() {
+            let load_sag = ElectricPotential::new::<volt>(
+                report
+                    .total_consumption_of(PotentialOrigin::External(self.number))
+                    .get::<watt>()
+                    / Self::RATED_TRUE_POWER_WATT
+                    * Self::POTENTIAL_SAG_AT_RATED_POWER_VOLT,
+            );
+            self.update_transient(context, load_sag);
+            self.load_sag = load_sag;
+
+            self.output_potential = self.calculate_potential();
+            self.output_frequency = self.calculate_frequency();
         } else {
-            Frequency::new::<hertz>(0.)
-        };
+            // When disconnected, the output is lost at once.
+            self.load_sag = ElectricPotential::new::<volt>(0.);
+            self.transient = ElectricPotential::new::<volt>(0.);
+            self.output_potential = ElectricPotential::new::<volt>(0.);
+            self.output_frequency = Frequency::new::<hertz>(0.);
+        }
 
-        self.output_potential = if self.should_provide_output() {
-            ElectricPotential::new::<volt>(115.)
-        } else {
-            ElectricPotential::new::<volt>(0.)
-        };
+        self.was_connected = self.should_provide_output();
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_connected);
+        snapshot.write(self.was_connected);
+        snapshot.write(self.output_frequency);
+        snapshot.write(self.output_potential);
+        snapshot.write(self.load_sag);
+        snapshot.write(self.transient);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_connected = snapshot.read();
+        self.was_connected = snapshot.read();
+        self.output_frequency = snapshot.read();
+        self.output_potential = snapshot.read();
+        self.load_sag = snapshot.read();
+        self.transient = snapshot.read();
     }
 }
 
@@ -107,12 +261,14 @@ mod external_power_source_tests {
     use crate::simulation::test::{ReadByName, WriteByName};
     use crate::simulation::InitContext;
     use crate::{
-        electrical::Electricity,
+        electrical::{consumption::PowerConsumer, ElectricalBus, Electricity},
+        shared::ElectricalBusType,
         simulation::{
             test::{SimulationTestBed, TestBed},
             Aircraft, SimulationElementVisitor,
         },
     };
+    use std::time::Duration;
 
     struct ExternalPowerTestBed {
         test_bed: SimulationTestBed<TestAircraft>,
@@ -146,6 +302,25 @@ mod external_power_source_tests {
             self.read_by_name("ELEC_EXT_PWR_POTENTIAL_NORMAL")
         }
 
+        fn potential(&mut self) -> ElectricPotential {
+            self.read_by_name("ELEC_EXT_PWR_POTENTIAL")
+        }
+
+        fn frequency(&mut self) -> Frequency {
+            self.read_by_name("ELEC_EXT_PWR_FREQUENCY")
+        }
+
+        fn power_demand(&mut self, power: Power) {
+            self.command(|a| a.power_demand(power));
+        }
+
+        fn output_within_normal_parameters(&self) -> bool {
+            self.query(|a| {
+                a.ext_pwr_output_within_normal_parameters_after_processing_power_consumption_report(
+                )
+            })
+        }
+
         fn ext_pwr_is_powered(&self) -> bool {
             self.query_elec(|a, elec| a.ext_pwr_is_powered(elec))
         }
@@ -164,16 +339,33 @@ mod external_power_source_tests {
 
     struct TestAircraft {
         ext_pwr: ExternalPowerSource,
+        bus: ElectricalBus,
+        consumer: PowerConsumer,
         ext_pwr_output_within_normal_parameters_before_processing_power_consumption_report: bool,
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
+            Self::new_with_receptacle(context, 1)
+        }
+
+        fn new_with_receptacle(context: &mut InitContext, number: usize) -> Self {
+            let ext_pwr = ExternalPowerSource::new(context, number);
+            Self::new_with_ext_pwr(context, ext_pwr)
+        }
+
+        fn new_with_ext_pwr(context: &mut InitContext, ext_pwr: ExternalPowerSource) -> Self {
             Self {
-                ext_pwr: ExternalPowerSource::new(context),
+                ext_pwr,
+                bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
                 ext_pwr_output_within_normal_parameters_before_processing_power_consumption_report: false,
             }
         }
 
+        fn power_demand(&mut self, power: Power) {
+            self.consumer.demand(power);
+        }
+
         fn ext_pwr_is_powered(&self, electricity: &Electricity) -> bool {
             electricity.is_powered(&self.ext_pwr)
         }
@@ -197,12 +389,14 @@ mod external_power_source_tests {
             electricity: &mut Electricity,
         ) {
             electricity.supplied_by(&self.ext_pwr);
+            electricity.flow(&self.ext_pwr, &self.bus);
             self.ext_pwr_output_within_normal_parameters_before_processing_power_consumption_report = self.ext_pwr.output_within_normal_parameters();
         }
     }
     impl SimulationElement for TestAircraft {
         fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
             self.ext_pwr.accept(visitor);
+            self.consumer.accept(visitor);
             visitor.visit(self);
         }
     }
@@ -311,4 +505,191 @@ mod external_power_source_tests {
         assert!(test_bed.contains_variable_with_name("ELEC_EXT_PWR_FREQUENCY"));
         assert!(test_bed.contains_variable_with_name("ELEC_EXT_PWR_FREQUENCY_NORMAL"));
     }
+
+    #[test]
+    fn other_receptacles_are_numbered() {
+        let mut test_bed = ExternalPowerTestBed {
+            test_bed: SimulationTestBed::new(|context| {
+                TestAircraft::new_with_receptacle(context, 3)
+            }),
+        };
+
+        test_bed.write_by_name("EXTERNAL POWER AVAILABLE:1", true);
+        test_bed.run();
+        assert!(!test_bed.ext_pwr_is_powered());
+
+        test_bed.write_by_name("EXTERNAL POWER AVAILABLE:3", true);
+        test_bed.run();
+        assert!(test_bed.ext_pwr_is_powered());
+        assert!(test_bed.contains_variable_with_name("ELEC_EXT_PWR_3_POTENTIAL"));
+    }
+
+    #[test]
+    fn when_connected_without_load_provides_nominal_potential_and_frequency() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+
+        test_bed.run();
+
+        assert!((test_bed.potential().get::<volt>() - 115.).abs() < 0.01);
+        assert!((test_bed.frequency().get::<hertz>() - 400.).abs() < 0.01);
+    }
+
+    #[test]
+    fn potential_sags_under_load() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.power_demand(Power::new::<watt>(72000.));
+
+        test_bed.run();
+        test_bed.run();
+
+        assert!(test_bed.potential() < ElectricPotential::new::<volt>(113.));
+        assert!(test_bed.potential_is_normal());
+    }
+
+    #[test]
+    fn potential_dips_when_load_is_applied_and_recovers_afterwards() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.run();
+
+        test_bed.power_demand(Power::new::<watt>(72000.));
+        test_bed.run_with_delta(Duration::from_millis(10));
+        let dipped_potential = test_bed.potential();
+
+        test_bed.run();
+
+        assert!(dipped_potential < test_bed.potential());
+        assert!((test_bed.potential().get::<volt>() - 112.).abs() < 0.01);
+    }
+
+    #[test]
+    fn potential_overshoots_right_after_connecting() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.potential() > ElectricPotential::new::<volt>(120.));
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn output_within_normal_parameters_once_connection_transient_decayed() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed.run_with_delta(Duration::from_millis(500));
+
+        assert!(test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn restoring_a_snapshot_of_a_connected_unit_does_not_overshoot() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.run_with_delta(Duration::from_millis(10));
+        test_bed.run_with_delta(Duration::from_millis(500));
+        let snapshot = test_bed.snapshot();
+
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.restore(&snapshot).unwrap();
+        test_bed.run_with_delta(Duration::from_millis(10));
+
+        assert!(test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn over_voltage_is_not_within_normal_parameters() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::ExternalPowerOverVoltage(1));
+
+        test_bed.run();
+
+        assert!(!test_bed.potential_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn under_voltage_is_not_within_normal_parameters() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::ExternalPowerUnderVoltage(1));
+
+        test_bed.run();
+
+        assert!(!test_bed.potential_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn wrong_phase_rotation_is_not_within_normal_parameters() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::ExternalPowerWrongPhaseRotation(1));
+
+        test_bed.run();
+
+        assert!(test_bed.potential_is_normal());
+        assert!(test_bed.frequency_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn frequency_deviation_is_not_within_normal_parameters() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::ExternalPowerFrequencyDeviation(1));
+
+        test_bed.run();
+
+        assert!(!test_bed.frequency_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn failures_only_affect_their_own_receptacle() {
+        let mut test_bed = ExternalPowerTestBed::new().with_connected_external_power();
+        test_bed.fail(FailureType::ExternalPowerUnderVoltage(2));
+
+        test_bed.run();
+
+        assert!(test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn provides_configured_nominal_output() {
+        let mut test_bed = ExternalPowerTestBed {
+            test_bed: SimulationTestBed::new(|context| {
+                let ext_pwr = ExternalPowerSource::new_with_nominal_output(
+                    context,
+                    1,
+                    ElectricPotential::new::<volt>(118.),
+                    Frequency::new::<hertz>(400.),
+                );
+                TestAircraft::new_with_ext_pwr(context, ext_pwr)
+            }),
+        }
+        .with_connected_external_power();
+
+        test_bed.run();
+
+        assert!((test_bed.potential().get::<volt>() - 118.).abs() < 0.01);
+        assert!(test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn unit_with_nominal_frequency_outside_the_aircraft_normal_range_is_not_within_normal_parameters(
+    ) {
+        let mut test_bed = ExternalPowerTestBed {
+            test_bed: SimulationTestBed::new(|context| {
+                let ext_pwr = ExternalPowerSource::new_with_nominal_output(
+                    context,
+                    1,
+                    ElectricPotential::new::<volt>(115.),
+                    Frequency::new::<hertz>(60.),
+                );
+                TestAircraft::new_with_ext_pwr(context, ext_pwr)
+            }),
+        }
+        .with_connected_external_power();
+
+        test_bed.run();
+
+        assert!(test_bed.potential_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
 }
@@ -9,6 +9,7 @@ mod external_power_source;
 mod ram_air_turbine;
 mod static_inverter;
 mod transformer_rectifier;
+mod variable_frequency_generator;
 
 use std::{
     cell::{Ref, RefCell},
@@ -23,7 +24,8 @@ use crate::{
         PotentialOrigin, PowerConsumptionReport,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, UpdateContext, Write,
+        SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, Write,
     },
 };
 pub use battery::Battery;
@@ -37,6 +39,7 @@ use fxhash::{FxHashMap, FxHashSet};
 pub use static_inverter::StaticInverter;
 pub use transformer_rectifier::TransformerRectifier;
 use uom::si::{electric_potential::volt, f64::*, power::watt, velocity::knot};
+pub use variable_frequency_generator::VariableFrequencyGenerator;
 
 pub use ram_air_turbine::{GeneratorControlUnit, RamAirTurbine};
 
@@ -101,6 +104,14 @@ impl SimulationElement for Contactor {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.closed_id, self.is_closed());
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.closed);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.closed = snapshot.read();
+    }
 }
 
 pub struct ElectricalBus {
@@ -253,6 +264,11 @@ pub trait ProvidePotential {
     fn potential_normal(&self) -> bool;
 }
 
+pub trait ProvideTemperature {
+    fn temperature(&self) -> ThermodynamicTemperature;
+    fn temperature_normal(&self) -> bool;
+}
+
 pub trait ProvideFrequency {
     fn frequency(&self) -> Frequency;
     fn frequency_normal(&self) -> bool;
@@ -1136,7 +1152,7 @@ mod tests {
                     contactor,
                     power_source: TestElectricitySource::unpowered(
                         context,
-                        PotentialOrigin::External,
+                        PotentialOrigin::External(1),
                     ),
                 }
             }
@@ -1182,6 +1198,17 @@ mod tests {
             }
         }
 
+        #[test]
+        fn restoring_a_snapshot_restores_the_contactor_position() {
+            let mut test_bed = SimulationTestBed::new(ContactorTestAircraft::new_closed);
+            let snapshot = test_bed.snapshot();
+
+            test_bed.command(|a| a.open_contactor());
+            test_bed.restore(&snapshot).unwrap();
+
+            assert!(test_bed.query(|a| a.contactor_is_closed()));
+        }
+
         #[test]
         fn open_contactor_when_toggled_open_stays_open() {
             let mut test_bed = SimulationTestBed::new(ContactorTestAircraft::new_open);
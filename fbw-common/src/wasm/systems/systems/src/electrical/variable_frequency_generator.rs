@@ -0,0 +1,367 @@
+use uom::si::{electric_potential::volt, f64::*, frequency::hertz, power::watt, ratio::percent};
+
+use crate::{
+    shared::{EngineCorrectedN2, EngineFirePushButtons, PowerConsumptionReport},
+    simulation::{InitContext, SimulationElement, SimulatorWriter, UpdateContext},
+};
+
+use super::{
+    engine_generator::N2StabilizationTimer, ElectricalElement, ElectricalElementIdentifier,
+    ElectricalElementIdentifierProvider, ElectricalStateWriter, ElectricitySource,
+    EngineGeneratorPushButtons, Potential, PotentialOrigin, ProvideFrequency, ProvideLoad,
+    ProvidePotential,
+};
+
+/// An engine generator without a constant speed drive. As it is directly driven by the
+/// engine's accessory gearbox, its output frequency follows the engine's N2 and is
+/// considered normal anywhere within the 360 Hz to 800 Hz frequency-wild range.
+pub struct VariableFrequencyGenerator {
+    writer: ElectricalStateWriter,
+    number: usize,
+    identifier: ElectricalElementIdentifier,
+    drive_connected: bool,
+    activated: bool,
+    stabilization_timer: N2StabilizationTimer,
+    corrected_n2: Ratio,
+    output_frequency: Frequency,
+    output_potential: ElectricPotential,
+    load: Ratio,
+}
+impl VariableFrequencyGenerator {
+    const FREQUENCY_PER_PERCENT_N2_IN_HERTZ: f64 = 8.;
+    const MAXIMUM_TRUE_POWER_IN_WATT: f64 = 150000.;
+
+    pub fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            writer: ElectricalStateWriter::new(context, &format!("ENG_GEN_{}", number)),
+            number,
+            identifier: context.next_electrical_identifier(),
+            drive_connected: true,
+            activated: true,
+            stabilization_timer: N2StabilizationTimer::new(),
+            corrected_n2: Ratio::new::<percent>(0.),
+            output_frequency: Frequency::new::<hertz>(0.),
+            output_potential: ElectricPotential::new::<volt>(0.),
+            load: Ratio::new::<percent>(0.),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl EngineCorrectedN2,
+        generator_buttons: &impl EngineGeneratorPushButtons,
+        fire_buttons: &impl EngineFirePushButtons,
+    ) {
+        if generator_buttons.idg_push_button_is_released(self.number) {
+            // The drive cannot be reconnected.
+            self.drive_connected = false;
+        }
+
+        self.activated = generator_buttons.engine_gen_push_button_is_on(self.number)
+            && !fire_buttons.is_released(self.number);
+
+        self.stabilization_timer.update(
+            context,
+            engine.corrected_n2(),
+            self.drive_connected && self.activated,
+        );
+        self.corrected_n2 = engine.corrected_n2();
+    }
+
+    /// Indicates if the provided electricity's potential and frequency
+    /// are within normal parameters. Use this to decide if the
+    /// generator line contactor should close.
+    pub fn output_within_normal_parameters(&self) -> bool {
+        self.should_provide_output() && self.frequency_normal() && self.potential_normal()
+    }
+
+    fn should_provide_output(&self) -> bool {
+        self.stabilization_timer.is_stable()
+    }
+}
+impl ElectricitySource for VariableFrequencyGenerator {
+    fn output_potential(&self) -> Potential {
+        if self.should_provide_output() {
+            Potential::new(
+                PotentialOrigin::EngineGenerator(self.number),
+                self.output_potential,
+            )
+        } else {
+            Potential::none()
+        }
+    }
+}
+provide_potential!(VariableFrequencyGenerator, (110.0..=120.0));
+provide_frequency!(VariableFrequencyGenerator, (360.0..=800.0));
+provide_load!(VariableFrequencyGenerator);
+impl ElectricalElement for VariableFrequencyGenerator {
+    fn input_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn output_identifier(&self) -> ElectricalElementIdentifier {
+        self.identifier
+    }
+
+    fn is_conductive(&self) -> bool {
+        true
+    }
+}
+impl SimulationElement for VariableFrequencyGenerator {
+    fn process_power_consumption_report<T: PowerConsumptionReport>(
+        &mut self,
+        _: &UpdateContext,
+        report: &T,
+    ) {
+        self.output_frequency = if self.should_provide_output() {
+            Frequency::new::<hertz>(
+                self.corrected_n2.get::<percent>() * Self::FREQUENCY_PER_PERCENT_N2_IN_HERTZ,
+            )
+        } else {
+            Frequency::new::<hertz>(0.)
+        };
+
+        self.output_potential = if self.should_provide_output() {
+            ElectricPotential::new::<volt>(115.)
+        } else {
+            ElectricPotential::new::<volt>(0.)
+        };
+
+        let power_consumption = report
+            .total_consumption_of(PotentialOrigin::EngineGenerator(self.number))
+            .get::<watt>();
+        let power_factor_correction = 0.8;
+        self.load = Ratio::new::<percent>(
+            (power_consumption * power_factor_correction / Self::MAXIMUM_TRUE_POWER_IN_WATT) * 100.,
+        );
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        self.writer.write_alternating_with_load(self, writer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{consumption::PowerConsumer, ElectricalBus, Electricity},
+        shared::ElectricalBusType,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft, SimulationElementVisitor,
+        },
+    };
+
+    struct TestEngine {
+        corrected_n2: Ratio,
+    }
+    impl EngineCorrectedN2 for TestEngine {
+        fn corrected_n2(&self) -> Ratio {
+            self.corrected_n2
+        }
+    }
+
+    struct TestOverhead {
+        drive_push_button_is_released: bool,
+    }
+    impl EngineGeneratorPushButtons for TestOverhead {
+        fn engine_gen_push_button_is_on(&self, _: usize) -> bool {
+            true
+        }
+
+        fn idg_push_button_is_released(&self, _: usize) -> bool {
+            self.drive_push_button_is_released
+        }
+    }
+
+    struct TestFireOverhead;
+    impl EngineFirePushButtons for TestFireOverhead {
+        fn is_released(&self, _: usize) -> bool {
+            false
+        }
+    }
+
+    struct TestAircraft {
+        generator: VariableFrequencyGenerator,
+        bus: ElectricalBus,
+        consumer: PowerConsumer,
+        corrected_n2: Ratio,
+        drive_push_button_released: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                generator: VariableFrequencyGenerator::new(context, 1),
+                bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                consumer: PowerConsumer::from(ElectricalBusType::AlternatingCurrent(1)),
+                corrected_n2: Ratio::new::<percent>(70.),
+                drive_push_button_released: false,
+            }
+        }
+
+        fn generator_is_powered(&self, electricity: &Electricity) -> bool {
+            electricity.is_powered(&self.generator)
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            context: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.generator.update(
+                context,
+                &TestEngine {
+                    corrected_n2: self.corrected_n2,
+                },
+                &TestOverhead {
+                    drive_push_button_is_released: self.drive_push_button_released,
+                },
+                &TestFireOverhead,
+            );
+            electricity.supplied_by(&self.generator);
+            electricity.flow(&self.generator, &self.bus);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.generator.accept(visitor);
+            self.consumer.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct VariableFrequencyGeneratorTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl VariableFrequencyGeneratorTestBed {
+        fn new() -> Self {
+            Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            }
+        }
+
+        fn with_corrected_n2(mut self, n2: f64) -> Self {
+            self.command(|a| a.corrected_n2 = Ratio::new::<percent>(n2));
+            self
+        }
+
+        fn frequency(&mut self) -> Frequency {
+            self.read_by_name("ELEC_ENG_GEN_1_FREQUENCY")
+        }
+
+        fn frequency_is_normal(&mut self) -> bool {
+            self.read_by_name("ELEC_ENG_GEN_1_FREQUENCY_NORMAL")
+        }
+
+        fn load(&mut self) -> Ratio {
+            self.read_by_name("ELEC_ENG_GEN_1_LOAD")
+        }
+
+        fn generator_is_powered(&self) -> bool {
+            self.query_elec(|a, elec| a.generator_is_powered(elec))
+        }
+
+        fn output_within_normal_parameters(&self) -> bool {
+            self.query(|a| a.generator.output_within_normal_parameters())
+        }
+    }
+    impl TestBed for VariableFrequencyGeneratorTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn when_engine_running_provides_output() {
+        let mut test_bed = VariableFrequencyGeneratorTestBed::new();
+        test_bed.run();
+
+        assert!(test_bed.generator_is_powered());
+        assert!(test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn when_engine_shutdown_provides_no_output() {
+        let mut test_bed = VariableFrequencyGeneratorTestBed::new().with_corrected_n2(0.);
+        test_bed.run();
+
+        assert!(!test_bed.generator_is_powered());
+        assert!(!test_bed.frequency_is_normal());
+    }
+
+    #[test]
+    fn when_drive_disconnected_provides_no_output() {
+        let mut test_bed = VariableFrequencyGeneratorTestBed::new();
+
+        test_bed.command(|a| a.drive_push_button_released = true);
+        test_bed.run();
+
+        assert!(!test_bed.generator_is_powered());
+    }
+
+    #[test]
+    fn frequency_follows_engine_n2() {
+        let mut test_bed = VariableFrequencyGeneratorTestBed::new().with_corrected_n2(60.);
+        test_bed.run();
+        let idle_frequency = test_bed.frequency();
+
+        test_bed = test_bed.with_corrected_n2(95.);
+        test_bed.run();
+
+        assert_eq!(idle_frequency, Frequency::new::<hertz>(480.));
+        assert_eq!(test_bed.frequency(), Frequency::new::<hertz>(760.));
+    }
+
+    #[test]
+    fn frequency_is_normal_over_the_whole_frequency_wild_range() {
+        for n2 in [60., 75., 100.] {
+            let mut test_bed = VariableFrequencyGeneratorTestBed::new().with_corrected_n2(n2);
+            test_bed.run();
+
+            assert!(test_bed.frequency_is_normal());
+            assert!(test_bed.output_within_normal_parameters());
+        }
+    }
+
+    #[test]
+    fn frequency_is_not_normal_when_engine_overspeeds() {
+        let mut test_bed = VariableFrequencyGeneratorTestBed::new().with_corrected_n2(105.);
+        test_bed.run();
+
+        assert!(!test_bed.frequency_is_normal());
+        assert!(!test_bed.output_within_normal_parameters());
+    }
+
+    #[test]
+    fn when_potential_used_has_load() {
+        let mut test_bed = VariableFrequencyGeneratorTestBed::new();
+
+        test_bed.command(|a| a.consumer.demand(Power::new::<watt>(150000. / 0.8)));
+        test_bed.run();
+
+        assert!((test_bed.load().get::<percent>() - 100.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn writes_its_state() {
+        let mut test_bed = VariableFrequencyGeneratorTestBed::new();
+        test_bed.run();
+
+        assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_POTENTIAL"));
+        assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_FREQUENCY"));
+        assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_FREQUENCY_NORMAL"));
+        assert!(test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_LOAD"));
+        assert!(!test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_IS_CONNECTED"));
+        assert!(!test_bed.contains_variable_with_name("ELEC_ENG_GEN_1_IDG_OIL_OUTLET_TEMPERATURE"));
+    }
+}
@@ -0,0 +1,897 @@
+use std::time::Duration;
+
+use uom::si::{f64::*, ratio::percent, thermodynamic_temperature::degree_celsius};
+
+use crate::{
+    pneumatic::{EngineModeSelector, EngineState},
+    shared::EngineUncorrectedN2,
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+        Writer,
+    },
+};
+
+/// Phases of the automatic start sequence of an engine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EngineStartPhase {
+    Stopped = 0,
+    /// The starter is engaged, waiting for the core to reach ignition speed.
+    Cranking = 1,
+    /// An igniter is energised, waiting for the core to reach fuel-on speed.
+    Ignition = 2,
+    /// Fuel is on, the starter assists the core until starter cut-out speed.
+    Accelerating = 3,
+    Running = 4,
+    /// The start was automatically aborted. On ground the starter keeps dry cranking the
+    /// engine for a while to purge any remaining fuel before a new attempt is made.
+    Aborted = 5,
+}
+read_write_enum!(EngineStartPhase);
+impl From<f64> for EngineStartPhase {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => EngineStartPhase::Stopped,
+            1 => EngineStartPhase::Cranking,
+            2 => EngineStartPhase::Ignition,
+            3 => EngineStartPhase::Accelerating,
+            4 => EngineStartPhase::Running,
+            5 => EngineStartPhase::Aborted,
+            _ => panic!("{} cannot be converted into EngineStartPhase", value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EngineStartFault {
+    None = 0,
+    HotStart = 1,
+    HungStart = 2,
+    NoLightUp = 3,
+}
+read_write_enum!(EngineStartFault);
+impl From<f64> for EngineStartFault {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => EngineStartFault::None,
+            1 => EngineStartFault::HotStart,
+            2 => EngineStartFault::HungStart,
+            3 => EngineStartFault::NoLightUp,
+            _ => panic!("{} cannot be converted into EngineStartFault", value),
+        }
+    }
+}
+
+struct EngineStartSequencer {
+    master_switch_id: VariableIdentifier,
+    egt_id: VariableIdentifier,
+    start_phase_id: VariableIdentifier,
+    start_fault_id: VariableIdentifier,
+    fuel_commanded_on_id: VariableIdentifier,
+    igniter_a_id: VariableIdentifier,
+    igniter_b_id: VariableIdentifier,
+
+    master_is_on: bool,
+    egt: ThermodynamicTemperature,
+    n2: Ratio,
+
+    phase: EngineStartPhase,
+    fault: EngineStartFault,
+    time_in_phase: Duration,
+    is_in_flight_start: bool,
+    egt_at_fuel_on: ThermodynamicTemperature,
+    start_attempts: u8,
+
+    next_ground_start_uses_igniter_a: bool,
+    igniter_a_is_on: bool,
+    igniter_b_is_on: bool,
+}
+impl EngineStartSequencer {
+    const IGNITION_N2_PERCENT: f64 = 16.;
+    const FUEL_ON_N2_PERCENT: f64 = 22.;
+    const STARTER_CUT_OUT_N2_PERCENT: f64 = 50.;
+    // A running engine decelerating below this speed with its master switch on has flamed out
+    const FLAMEOUT_N2_PERCENT: f64 = 45.;
+    // Below this speed a stopped engine is considered spooled down
+    const SPOOLED_DOWN_N2_PERCENT: f64 = 1.;
+
+    const START_EGT_LIMIT_DEG_C: f64 = 725.;
+    const LIGHT_UP_EGT_RISE_DEG_C: f64 = 50.;
+    const LIGHT_UP_TIME_LIMIT: Duration = Duration::from_secs(15);
+    const HUNG_START_TIME_LIMIT: Duration = Duration::from_secs(60);
+    const DRY_CRANK_DURATION: Duration = Duration::from_secs(30);
+    // A hung start isn't retried, other faults are retried after dry cranking
+    const MAX_GROUND_START_ATTEMPTS: u8 = 3;
+
+    fn new(context: &mut InitContext, number: usize) -> Self {
+        Self {
+            master_switch_id: context
+                .get_identifier(format!("GENERAL ENG STARTER ACTIVE:{}", number)),
+            egt_id: context.get_identifier(format!("ENGINE_EGT:{}", number)),
+            start_phase_id: context.get_identifier(format!("ENGINE_{}_START_PHASE", number)),
+            start_fault_id: context.get_identifier(format!("ENGINE_{}_START_FAULT", number)),
+            fuel_commanded_on_id: context
+                .get_identifier(format!("ENGINE_{}_FUEL_COMMANDED_ON", number)),
+            igniter_a_id: context.get_identifier(format!("FADEC_IGNITER_A_ACTIVE_ENG{}", number)),
+            igniter_b_id: context.get_identifier(format!("FADEC_IGNITER_B_ACTIVE_ENG{}", number)),
+
+            master_is_on: false,
+            egt: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            n2: Ratio::new::<percent>(0.),
+
+            phase: EngineStartPhase::Stopped,
+            fault: EngineStartFault::None,
+            time_in_phase: Duration::from_secs(0),
+            is_in_flight_start: false,
+            egt_at_fuel_on: ThermodynamicTemperature::new::<degree_celsius>(0.),
+            start_attempts: 0,
+
+            next_ground_start_uses_igniter_a: true,
+            igniter_a_is_on: false,
+            igniter_b_is_on: false,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        engine: &impl EngineUncorrectedN2,
+        mode_selector: EngineModeSelector,
+    ) {
+        self.n2 = engine.uncorrected_n2();
+        self.time_in_phase += context.delta();
+
+        // A fast spooling core can pass several thresholds within a single update
+        loop {
+            let next_phase = self.next_phase(context, mode_selector);
+            if next_phase == self.phase {
+                break;
+            }
+
+            self.enter_phase(context, next_phase);
+        }
+
+        self.update_igniters();
+    }
+
+    fn next_phase(
+        &mut self,
+        context: &UpdateContext,
+        mode_selector: EngineModeSelector,
+    ) -> EngineStartPhase {
+        if !self.master_is_on {
+            return EngineStartPhase::Stopped;
+        }
+
+        let n2 = self.n2.get::<percent>();
+        match self.phase {
+            EngineStartPhase::Stopped => {
+                if n2 >= Self::STARTER_CUT_OUT_N2_PERCENT {
+                    EngineStartPhase::Running
+                } else if mode_selector == EngineModeSelector::Ignition {
+                    EngineStartPhase::Cranking
+                } else {
+                    EngineStartPhase::Stopped
+                }
+            }
+            EngineStartPhase::Cranking if n2 >= Self::IGNITION_N2_PERCENT => {
+                EngineStartPhase::Ignition
+            }
+            EngineStartPhase::Ignition if n2 >= Self::FUEL_ON_N2_PERCENT => {
+                EngineStartPhase::Accelerating
+            }
+            // In flight the FADEC automatically relights an engine which flamed out.
+            EngineStartPhase::Running if n2 < Self::FLAMEOUT_N2_PERCENT => {
+                if context.is_on_ground() {
+                    EngineStartPhase::Stopped
+                } else {
+                    EngineStartPhase::Ignition
+                }
+            }
+            EngineStartPhase::Accelerating => {
+                if n2 >= Self::STARTER_CUT_OUT_N2_PERCENT {
+                    return EngineStartPhase::Running;
+                }
+
+                if self.fault == EngineStartFault::None {
+                    self.fault = self.detect_start_fault();
+                }
+
+                // In flight the FADEC only monitors the start, it is up to the crew to abort it.
+                if self.fault != EngineStartFault::None && context.is_on_ground() {
+                    EngineStartPhase::Aborted
+                } else {
+                    EngineStartPhase::Accelerating
+                }
+            }
+            EngineStartPhase::Aborted
+                if self.time_in_phase >= Self::DRY_CRANK_DURATION
+                    && self.fault != EngineStartFault::HungStart
+                    && self.start_attempts < Self::MAX_GROUND_START_ATTEMPTS
+                    && mode_selector == EngineModeSelector::Ignition =>
+            {
+                EngineStartPhase::Cranking
+            }
+            phase => phase,
+        }
+    }
+
+    fn detect_start_fault(&self) -> EngineStartFault {
+        let egt = self.egt.get::<degree_celsius>();
+
+        if egt > Self::START_EGT_LIMIT_DEG_C {
+            EngineStartFault::HotStart
+        } else if self.time_in_phase > Self::LIGHT_UP_TIME_LIMIT
+            && egt - self.egt_at_fuel_on.get::<degree_celsius>() < Self::LIGHT_UP_EGT_RISE_DEG_C
+        {
+            EngineStartFault::NoLightUp
+        } else if self.time_in_phase > Self::HUNG_START_TIME_LIMIT {
+            EngineStartFault::HungStart
+        } else {
+            EngineStartFault::None
+        }
+    }
+
+    fn enter_phase(&mut self, context: &UpdateContext, phase: EngineStartPhase) {
+        match phase {
+            EngineStartPhase::Stopped => {
+                self.fault = EngineStartFault::None;
+                self.start_attempts = 0;
+            }
+            EngineStartPhase::Cranking => {
+                self.fault = EngineStartFault::None;
+                self.start_attempts += 1;
+                self.is_in_flight_start = !context.is_on_ground();
+                if !self.is_in_flight_start {
+                    self.next_ground_start_uses_igniter_a = !self.next_ground_start_uses_igniter_a;
+                }
+            }
+            EngineStartPhase::Ignition if self.phase == EngineStartPhase::Running => {
+                self.fault = EngineStartFault::None;
+                self.is_in_flight_start = true;
+            }
+            EngineStartPhase::Accelerating => self.egt_at_fuel_on = self.egt,
+            _ => {}
+        }
+
+        self.phase = phase;
+        self.time_in_phase = Duration::from_secs(0);
+    }
+
+    fn update_igniters(&mut self) {
+        let ignition_is_required = matches!(
+            self.phase,
+            EngineStartPhase::Ignition | EngineStartPhase::Accelerating
+        );
+
+        // On ground igniters are alternated between start attempts. In flight both are used.
+        // The selection was toggled when the start began, hence the inverted flag.
+        self.igniter_a_is_on = ignition_is_required
+            && (self.is_in_flight_start || !self.next_ground_start_uses_igniter_a);
+        self.igniter_b_is_on = ignition_is_required
+            && (self.is_in_flight_start || self.next_ground_start_uses_igniter_a);
+    }
+
+    fn starter_valve_should_open(&self) -> bool {
+        match self.phase {
+            EngineStartPhase::Cranking
+            | EngineStartPhase::Ignition
+            | EngineStartPhase::Accelerating => true,
+            EngineStartPhase::Aborted => self.time_in_phase < Self::DRY_CRANK_DURATION,
+            _ => false,
+        }
+    }
+
+    fn fuel_is_commanded_on(&self) -> bool {
+        matches!(
+            self.phase,
+            EngineStartPhase::Accelerating | EngineStartPhase::Running
+        )
+    }
+
+    fn engine_state(&self) -> EngineState {
+        match self.phase {
+            EngineStartPhase::Cranking
+            | EngineStartPhase::Ignition
+            | EngineStartPhase::Accelerating => {
+                if self.is_in_flight_start {
+                    EngineState::Restarting
+                } else {
+                    EngineState::Starting
+                }
+            }
+            EngineStartPhase::Running => EngineState::On,
+            EngineStartPhase::Stopped | EngineStartPhase::Aborted => {
+                if self.n2.get::<percent>() > Self::SPOOLED_DOWN_N2_PERCENT {
+                    EngineState::Shutting
+                } else {
+                    EngineState::Off
+                }
+            }
+        }
+    }
+}
+impl SimulationElement for EngineStartSequencer {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.master_is_on = reader.read(&self.master_switch_id);
+        self.egt = reader.read(&self.egt_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.start_phase_id, self.phase);
+        writer.write(&self.start_fault_id, self.fault);
+        writer.write(&self.fuel_commanded_on_id, self.fuel_is_commanded_on());
+        writer.write(&self.igniter_a_id, self.igniter_a_is_on);
+        writer.write(&self.igniter_b_id, self.igniter_b_is_on);
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.phase as u8 as f64);
+        snapshot.write(self.fault as u8 as f64);
+        snapshot.write(self.time_in_phase);
+        snapshot.write(self.is_in_flight_start);
+        snapshot.write(self.egt_at_fuel_on);
+        snapshot.write(self.start_attempts as f64);
+        snapshot.write(self.next_ground_start_uses_igniter_a);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.phase = snapshot.read::<f64>().into();
+        self.fault = snapshot.read::<f64>().into();
+        self.time_in_phase = snapshot.read();
+        self.is_in_flight_start = snapshot.read();
+        self.egt_at_fuel_on = snapshot.read();
+        self.start_attempts = snapshot.read::<f64>() as u8;
+        self.next_ground_start_uses_igniter_a = snapshot.read();
+        self.update_igniters();
+    }
+}
+
+/// Full authority digital engine control of N engines.
+///
+/// N1 and N2 are computed by the engine model. The FADEC runs the automatic start sequence:
+/// with the mode selector in IGN/START and the master switch on it engages the starter,
+/// energises an igniter, commands fuel on and cuts out the starter once the core is
+/// self-sustaining. Hot, hung and no light up starts are detected and abort the start on ground,
+/// after dry cranking hot and no light up starts are retried. An engine flaming out in flight is
+/// automatically relit.
+///
+/// The start phase, start fault and fuel command are published for the engine model, which
+/// spools the engine up with the starter and only lights it once fuel is commanded on.
+pub struct FullAuthorityDigitalEngineControl<const N: usize> {
+    engine_mode_selector_id: VariableIdentifier,
+    engine_mode_selector_position: EngineModeSelector,
+
+    sequencers: [EngineStartSequencer; N],
+}
+impl<const N: usize> FullAuthorityDigitalEngineControl<N> {
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            engine_mode_selector_id: context
+                .get_identifier("TURB ENG IGNITION SWITCH EX1:1".to_owned()),
+            engine_mode_selector_position: EngineModeSelector::Norm,
+            sequencers: std::array::from_fn(|index| EngineStartSequencer::new(context, index + 1)),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, engines: [&impl EngineUncorrectedN2; N]) {
+        for (sequencer, engine) in self.sequencers.iter_mut().zip(engines) {
+            sequencer.update(context, engine, self.engine_mode_selector_position);
+        }
+    }
+
+    pub fn engine_state(&self, number: usize) -> EngineState {
+        self.sequencers[number - 1].engine_state()
+    }
+
+    pub fn starter_valve_should_open(&self, number: usize) -> bool {
+        self.sequencers[number - 1].starter_valve_should_open()
+    }
+
+    pub fn fuel_is_commanded_on(&self, number: usize) -> bool {
+        self.sequencers[number - 1].fuel_is_commanded_on()
+    }
+
+    pub fn is_single_vs_dual_bleed_config(&self) -> bool {
+        self.sequencers
+            .iter()
+            .filter(|sequencer| sequencer.engine_state() == EngineState::On)
+            .count()
+            == 1
+    }
+
+    pub fn engine_mode_selector(&self) -> EngineModeSelector {
+        self.engine_mode_selector_position
+    }
+}
+impl<const N: usize> SimulationElement for FullAuthorityDigitalEngineControl<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.sequencers, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.engine_mode_selector_position = reader.read(&self.engine_mode_selector_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+
+    struct TestEngine {
+        n2: Ratio,
+    }
+    impl EngineUncorrectedN2 for TestEngine {
+        fn uncorrected_n2(&self) -> Ratio {
+            self.n2
+        }
+    }
+
+    struct TestAircraft {
+        engine: TestEngine,
+        fadec: FullAuthorityDigitalEngineControl<1>,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine: TestEngine {
+                    n2: Ratio::new::<percent>(0.),
+                },
+                fadec: FullAuthorityDigitalEngineControl::new(context),
+            }
+        }
+
+        fn set_n2(&mut self, n2: Ratio) {
+            self.engine.n2 = n2;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.fadec.update(context, [&self.engine]);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.fadec.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct FadecTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl FadecTestBed {
+        fn new() -> Self {
+            let mut fadec_test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            fadec_test_bed.set_on_ground(true);
+            fadec_test_bed.egt(15.);
+
+            fadec_test_bed
+        }
+
+        fn mode_selector(mut self, position: EngineModeSelector) -> Self {
+            self.write_by_name("TURB ENG IGNITION SWITCH EX1:1", position);
+            self
+        }
+
+        fn master_on(mut self) -> Self {
+            self.write_by_name("GENERAL ENG STARTER ACTIVE:1", true);
+            self
+        }
+
+        fn master_off(mut self) -> Self {
+            self.write_by_name("GENERAL ENG STARTER ACTIVE:1", false);
+            self
+        }
+
+        fn in_flight(mut self) -> Self {
+            self.set_on_ground(false);
+            self
+        }
+
+        fn n2(mut self, percent_n2: f64) -> Self {
+            self.command(|a| a.set_n2(Ratio::new::<percent>(percent_n2)));
+            self
+        }
+
+        fn egt(&mut self, deg_c: f64) {
+            self.write_by_name(
+                "ENGINE_EGT:1",
+                ThermodynamicTemperature::new::<degree_celsius>(deg_c),
+            );
+        }
+
+        fn with_egt(mut self, deg_c: f64) -> Self {
+            self.egt(deg_c);
+            self
+        }
+
+        fn run_for(mut self, duration: Duration) -> Self {
+            self.run_with_delta(duration);
+            self
+        }
+
+        fn and_run(self) -> Self {
+            self.run_for(Duration::from_millis(100))
+        }
+
+        fn start_until_fuel_on(self) -> Self {
+            self.mode_selector(EngineModeSelector::Ignition)
+                .master_on()
+                .and_run()
+                .n2(17.)
+                .and_run()
+                .n2(23.)
+                .and_run()
+        }
+
+        fn phase(&self) -> EngineStartPhase {
+            self.query(|a| a.fadec.sequencers[0].phase)
+        }
+
+        fn fault(&self) -> EngineStartFault {
+            self.query(|a| a.fadec.sequencers[0].fault)
+        }
+
+        fn engine_state(&self) -> EngineState {
+            self.query(|a| a.fadec.engine_state(1))
+        }
+
+        fn starter_valve_should_open(&self) -> bool {
+            self.query(|a| a.fadec.starter_valve_should_open(1))
+        }
+
+        fn fuel_is_commanded_on(&mut self) -> bool {
+            self.read_by_name("ENGINE_1_FUEL_COMMANDED_ON")
+        }
+
+        fn published_phase(&mut self) -> EngineStartPhase {
+            self.read_by_name("ENGINE_1_START_PHASE")
+        }
+
+        fn published_fault(&mut self) -> EngineStartFault {
+            self.read_by_name("ENGINE_1_START_FAULT")
+        }
+
+        fn igniters(&mut self) -> (bool, bool) {
+            (
+                self.read_by_name("FADEC_IGNITER_A_ACTIVE_ENG1"),
+                self.read_by_name("FADEC_IGNITER_B_ACTIVE_ENG1"),
+            )
+        }
+    }
+    impl TestBed for FadecTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> FadecTestBed {
+        FadecTestBed::new()
+    }
+
+    #[test]
+    fn engine_is_off_by_default() {
+        let test_bed = test_bed().and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Stopped);
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+        assert!(!test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn master_on_with_mode_selector_in_norm_does_not_start() {
+        let test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Norm)
+            .master_on()
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Stopped);
+    }
+
+    #[test]
+    fn start_engages_starter_without_fuel_or_ignition() {
+        let mut test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .master_on()
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Cranking);
+        assert_eq!(test_bed.engine_state(), EngineState::Starting);
+        assert!(test_bed.starter_valve_should_open());
+        assert_eq!(test_bed.igniters(), (false, false));
+    }
+
+    #[test]
+    fn one_igniter_is_energised_at_ignition_speed_on_ground() {
+        let mut test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .master_on()
+            .and_run()
+            .n2(17.)
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Ignition);
+        assert_eq!(test_bed.igniters(), (true, false));
+    }
+
+    #[test]
+    fn fuel_is_not_commanded_on_before_fuel_on_speed() {
+        let mut test_bed = test_bed()
+            .mode_selector(EngineModeSelector::Ignition)
+            .master_on()
+            .and_run()
+            .n2(17.)
+            .and_run();
+
+        assert!(!test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn fuel_is_commanded_on_at_fuel_on_speed() {
+        let mut test_bed = test_bed().start_until_fuel_on();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Accelerating);
+        assert!(test_bed.fuel_is_commanded_on());
+        assert!(test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn fuel_stays_commanded_on_once_running() {
+        let mut test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(400.)
+            .n2(51.)
+            .and_run();
+
+        assert!(test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn start_phase_and_fault_are_published() {
+        let mut test_bed = test_bed().start_until_fuel_on().with_egt(750.).and_run();
+
+        assert_eq!(test_bed.published_phase(), EngineStartPhase::Aborted);
+        assert_eq!(test_bed.published_fault(), EngineStartFault::HotStart);
+        assert!(!test_bed.fuel_is_commanded_on());
+    }
+
+    #[test]
+    fn starter_cuts_out_once_engine_is_self_sustaining() {
+        let mut test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(400.)
+            .n2(51.)
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Running);
+        assert_eq!(test_bed.engine_state(), EngineState::On);
+        assert!(!test_bed.starter_valve_should_open());
+        assert_eq!(test_bed.igniters(), (false, false));
+    }
+
+    #[test]
+    fn igniters_alternate_between_ground_starts() {
+        let mut test_bed = test_bed()
+            .start_until_fuel_on()
+            .master_off()
+            .n2(0.)
+            .and_run()
+            .start_until_fuel_on();
+
+        assert_eq!(test_bed.igniters(), (false, true));
+    }
+
+    #[test]
+    fn both_igniters_are_used_for_an_in_flight_start() {
+        let mut test_bed = test_bed().in_flight().start_until_fuel_on();
+
+        assert_eq!(test_bed.igniters(), (true, true));
+        assert_eq!(test_bed.engine_state(), EngineState::Restarting);
+    }
+
+    #[test]
+    fn hot_start_is_aborted_on_ground() {
+        let mut test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(750.)
+            .n2(35.)
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+        assert_eq!(test_bed.fault(), EngineStartFault::HotStart);
+        assert_eq!(test_bed.igniters(), (false, false));
+    }
+
+    #[test]
+    fn aborted_start_dry_cranks_then_closes_starter_valve() {
+        let mut test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(500.)
+            .n2(35.)
+            .run_for(Duration::from_secs(61))
+            .and_run()
+            .run_for(Duration::from_secs(20));
+
+        assert!(test_bed.starter_valve_should_open());
+
+        test_bed = test_bed.run_for(Duration::from_secs(11));
+
+        assert!(!test_bed.starter_valve_should_open());
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+    }
+
+    #[test]
+    fn aborted_start_is_retried_after_dry_cranking() {
+        let mut test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(750.)
+            .and_run()
+            .with_egt(300.)
+            .n2(20.)
+            .run_for(Duration::from_secs(31));
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Ignition);
+        assert_eq!(test_bed.fault(), EngineStartFault::None);
+        assert!(test_bed.starter_valve_should_open());
+        assert_eq!(test_bed.igniters(), (false, true));
+    }
+
+    #[test]
+    fn start_is_no_longer_retried_after_the_last_attempt() {
+        let mut test_bed = test_bed().start_until_fuel_on().with_egt(750.);
+        for _ in 0..EngineStartSequencer::MAX_GROUND_START_ATTEMPTS {
+            test_bed = test_bed.and_run().run_for(Duration::from_secs(31));
+        }
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+        assert_eq!(test_bed.fault(), EngineStartFault::HotStart);
+        assert!(!test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn hung_start_is_not_retried() {
+        let test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(500.)
+            .n2(35.)
+            .run_for(Duration::from_secs(61))
+            .and_run()
+            .run_for(Duration::from_secs(31));
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+        assert_eq!(test_bed.fault(), EngineStartFault::HungStart);
+    }
+
+    #[test]
+    fn no_light_up_is_aborted_on_ground() {
+        let test_bed = test_bed()
+            .start_until_fuel_on()
+            .run_for(Duration::from_secs(16))
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+        assert_eq!(test_bed.fault(), EngineStartFault::NoLightUp);
+    }
+
+    #[test]
+    fn hung_start_is_aborted_on_ground() {
+        let test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(500.)
+            .n2(35.)
+            .run_for(Duration::from_secs(61))
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Aborted);
+        assert_eq!(test_bed.fault(), EngineStartFault::HungStart);
+    }
+
+    #[test]
+    fn start_fault_in_flight_does_not_abort_the_start() {
+        let test_bed = test_bed()
+            .in_flight()
+            .start_until_fuel_on()
+            .with_egt(750.)
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Accelerating);
+        assert_eq!(test_bed.fault(), EngineStartFault::HotStart);
+    }
+
+    #[test]
+    fn master_off_resets_an_aborted_start() {
+        let test_bed = test_bed()
+            .start_until_fuel_on()
+            .with_egt(750.)
+            .and_run()
+            .master_off()
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Stopped);
+        assert_eq!(test_bed.fault(), EngineStartFault::None);
+    }
+
+    #[test]
+    fn running_engine_is_recognised_without_start_sequence() {
+        let test_bed = test_bed().n2(60.).master_on().and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Running);
+        assert!(!test_bed.starter_valve_should_open());
+    }
+
+    #[test]
+    fn engine_is_shutting_down_while_spooling_down() {
+        let mut test_bed = test_bed()
+            .n2(60.)
+            .master_on()
+            .and_run()
+            .master_off()
+            .n2(30.)
+            .and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::Shutting);
+
+        test_bed = test_bed.n2(0.).and_run();
+
+        assert_eq!(test_bed.engine_state(), EngineState::Off);
+    }
+
+    #[test]
+    fn engine_flaming_out_in_flight_is_relit_with_both_igniters() {
+        let mut test_bed = test_bed()
+            .in_flight()
+            .n2(90.)
+            .master_on()
+            .and_run()
+            .n2(40.)
+            .and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Accelerating);
+        assert_eq!(test_bed.engine_state(), EngineState::Restarting);
+        assert_eq!(test_bed.igniters(), (true, true));
+
+        test_bed = test_bed.with_egt(500.).n2(60.).and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Running);
+        assert_eq!(test_bed.igniters(), (false, false));
+    }
+
+    #[test]
+    fn engine_flaming_out_on_ground_is_not_relit() {
+        let mut test_bed = test_bed().n2(60.).master_on().and_run().n2(40.).and_run();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Stopped);
+        assert_eq!(test_bed.engine_state(), EngineState::Shutting);
+        assert_eq!(test_bed.igniters(), (false, false));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_restores_the_start_sequence() {
+        let mut test_bed = test_bed().start_until_fuel_on();
+        let snapshot = test_bed.snapshot();
+
+        test_bed = test_bed.master_off().and_run();
+        assert_eq!(test_bed.phase(), EngineStartPhase::Stopped);
+
+        test_bed.restore(&snapshot).unwrap();
+
+        assert_eq!(test_bed.phase(), EngineStartPhase::Accelerating);
+        assert_eq!(test_bed.igniters(), (true, false));
+    }
+}
@@ -1,11 +1,16 @@
-use uom::si::{angular_velocity::revolution_per_minute, f64::*, pressure::psi, ratio::percent};
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_hour, pressure::psi,
+    ratio::percent,
+};
 
 use crate::{
     shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
-    simulation::{Read, SimulationElement, SimulatorReader, UpdateContext},
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+    },
 };
 
-use super::Engine;
+use super::{oil::EngineOilSystem, Engine};
 use crate::simulation::{InitContext, VariableIdentifier};
 
 pub struct LeapEngine {
@@ -17,9 +22,12 @@ pub struct LeapEngine {
     uncorrected_n2_id: VariableIdentifier,
     uncorrected_n2: Ratio,
 
+    fuel_flow_id: VariableIdentifier,
+    fuel_flow: MassRate,
+
     n2_speed: AngularVelocity,
     hydraulic_pump_output_speed: AngularVelocity,
-    oil_pressure: Pressure,
+    oil: EngineOilSystem,
 }
 impl LeapEngine {
     // According to the Type Certificate Data Sheet of LEAP 1A26
@@ -41,13 +49,18 @@ impl LeapEngine {
             corrected_n2: Ratio::new::<percent>(0.),
             uncorrected_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
             uncorrected_n2: Ratio::new::<percent>(0.),
+            fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
+            fuel_flow: MassRate::new::<kilogram_per_hour>(0.),
             n2_speed: AngularVelocity::new::<revolution_per_minute>(0.),
             hydraulic_pump_output_speed: AngularVelocity::new::<revolution_per_minute>(0.),
-            oil_pressure: Pressure::new::<psi>(0.),
+            oil: EngineOilSystem::new(context, number),
         }
     }
 
-    pub fn update(&mut self, _: &UpdateContext) {}
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.oil
+            .update(context, self.uncorrected_n2, self.fuel_flow);
+    }
 
     fn update_parameters(&mut self) {
         self.n2_speed = AngularVelocity::new::<revolution_per_minute>(
@@ -55,15 +68,26 @@ impl LeapEngine {
         );
         self.hydraulic_pump_output_speed = self.n2_speed * Self::PUMP_N2_GEAR_RATIO;
 
-        // Ultra stupid model just to have 18psi crossing at 25% N2
-        self.oil_pressure = Pressure::new::<psi>(18. / 25. * self.uncorrected_n2.get::<percent>());
+        self.oil.update_pressure(self.uncorrected_n2);
+    }
+
+    pub fn oil(&self) -> &EngineOilSystem {
+        &self.oil
     }
 }
 impl SimulationElement for LeapEngine {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.oil.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn read(&mut self, reader: &mut SimulatorReader) {
         self.corrected_n1 = reader.read(&self.corrected_n1_id);
         self.corrected_n2 = reader.read(&self.corrected_n2_id);
         self.uncorrected_n2 = reader.read(&self.uncorrected_n2_id);
+        let fuel_flow_kg_per_hour: f64 = reader.read(&self.fuel_flow_id);
+        self.fuel_flow = MassRate::new::<kilogram_per_hour>(fuel_flow_kg_per_hour.max(0.));
         self.update_parameters();
     }
 }
@@ -88,7 +112,7 @@ impl Engine for LeapEngine {
     }
 
     fn oil_pressure_is_low(&self) -> bool {
-        self.oil_pressure.get::<psi>() < LeapEngine::LOW_OIL_PRESSURE_THRESHOLD_PSI
+        self.oil.pressure().get::<psi>() < LeapEngine::LOW_OIL_PRESSURE_THRESHOLD_PSI
     }
 
     fn is_above_minimum_idle(&self) -> bool {
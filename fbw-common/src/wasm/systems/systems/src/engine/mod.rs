@@ -8,7 +8,10 @@ use crate::{
 };
 
 pub mod engine_wing_flex;
+pub mod fadec;
 pub mod leap_engine;
+pub mod oil;
+pub mod trent_engine;
 
 pub trait Engine: EngineCorrectedN2 + EngineUncorrectedN2 + EngineCorrectedN1 {
     fn hydraulic_pump_output_speed(&self) -> AngularVelocity;
@@ -0,0 +1,526 @@
+use std::time::Duration;
+
+use uom::si::{
+    f64::*, mass_rate::kilogram_per_hour, pressure::psi, ratio::percent,
+    thermodynamic_temperature::degree_celsius, time::second, volume::quart_liquid,
+};
+
+use crate::{
+    failures::{Failure, FailureType},
+    shared::low_pass_filter::LowPassFilter,
+    simulation::{
+        InitContext, SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+/// Lubrication system of a turbofan engine.
+///
+/// The oil pump is driven by the accessory gearbox, thus pressure follows core speed and
+/// increases with oil viscosity, which is higher when the oil is cold.
+/// Oil heats up with engine speed and is cooled by the fuel cooled oil cooler, a fuel/oil heat
+/// exchanger which rejects heat into the fuel flowing to the engine. The more fuel flows through
+/// the heat exchanger, the closer the oil gets to the fuel temperature. Without fuel flow the oil
+/// slowly cools down to ambient temperature. The fuel temperature follows ambient temperature,
+/// as the fuel soaks in the tanks.
+pub struct EngineOilSystem {
+    quantity_id: VariableIdentifier,
+    pressure_id: VariableIdentifier,
+    temperature_id: VariableIdentifier,
+
+    quantity: Volume,
+    pressure: Pressure,
+    temperature_deg_c: LowPassFilter<f64>,
+    fuel_temperature_deg_c: LowPassFilter<f64>,
+    temperature_is_initialised: bool,
+
+    leak_failure: Failure,
+    pump_failure: Failure,
+}
+impl EngineOilSystem {
+    const TANK_CAPACITY_QT: f64 = 20.;
+    const INITIAL_QUANTITY_QT: f64 = 17.;
+
+    // Below this quantity the pump starts to suck air and pressure decreases
+    const MIN_QUANTITY_FOR_FULL_PRESSURE_QT: f64 = 3.;
+
+    const NORMAL_CONSUMPTION_QT_PER_HOUR: f64 = 0.3;
+    const LEAK_RATE_QT_PER_MINUTE: f64 = 0.5;
+
+    // With oil at reference temperature, pressure crosses 18psi at 25% N2
+    const PRESSURE_PSI_PER_N2_PERCENT: f64 = 18. / 25.;
+    const REFERENCE_TEMPERATURE_DEG_C: f64 = 15.;
+    const VISCOSITY_PRESSURE_GAIN_PER_DEG_C: f64 = 0.003;
+    const MIN_VISCOSITY_PRESSURE_FACTOR: f64 = 0.75;
+    const MAX_VISCOSITY_PRESSURE_FACTOR: f64 = 1.3;
+
+    // Stabilised temperature above the heat sink is this rise scaled by (N2 / 100%)^exponent
+    const MAX_TEMPERATURE_RISE_DEG_C: f64 = 110.;
+    const TEMPERATURE_RISE_EXPONENT: f64 = 1.5;
+    const RUNNING_TEMPERATURE_TIME_CONSTANT_S: f64 = 180.;
+    const STOPPED_TEMPERATURE_TIME_CONSTANT_S: f64 = 1800.;
+
+    // The heat exchanger reaches half of its effectiveness at this fuel flow
+    const HEAT_EXCHANGER_HALF_EFFECTIVENESS_FUEL_FLOW_KG_PER_HOUR: f64 = 1000.;
+    // Part of the temperature rise removed by a fully effective heat exchanger
+    const HEAT_EXCHANGER_MAX_TEMPERATURE_RISE_REDUCTION: f64 = 0.3;
+    const FUEL_TEMPERATURE_TIME_CONSTANT_S: f64 = 3600.;
+
+    // Above this speed the engine is considered running when the simulation starts
+    const MIN_RUNNING_N2_PERCENT: f64 = 50.;
+
+    pub fn new(context: &mut InitContext, engine_number: usize) -> Self {
+        Self {
+            quantity_id: context.get_identifier(format!("ENGINE_{}_OIL_QUANTITY", engine_number)),
+            pressure_id: context.get_identifier(format!("ENGINE_{}_OIL_PRESSURE", engine_number)),
+            temperature_id: context
+                .get_identifier(format!("ENGINE_{}_OIL_TEMPERATURE", engine_number)),
+
+            quantity: Volume::new::<quart_liquid>(Self::INITIAL_QUANTITY_QT),
+            pressure: Pressure::new::<psi>(0.),
+            temperature_deg_c: LowPassFilter::new_with_init_value(
+                Duration::from_secs_f64(Self::STOPPED_TEMPERATURE_TIME_CONSTANT_S),
+                Self::REFERENCE_TEMPERATURE_DEG_C,
+            ),
+            fuel_temperature_deg_c: LowPassFilter::new_with_init_value(
+                Duration::from_secs_f64(Self::FUEL_TEMPERATURE_TIME_CONSTANT_S),
+                Self::REFERENCE_TEMPERATURE_DEG_C,
+            ),
+            temperature_is_initialised: false,
+
+            leak_failure: Failure::new(FailureType::EngineOilLeak(engine_number)),
+            pump_failure: Failure::new(FailureType::EngineOilPumpFailure(engine_number)),
+        }
+    }
+
+    /// Pressure must be updated beforehand, see [`EngineOilSystem::update_pressure`].
+    pub fn update(&mut self, context: &UpdateContext, n2: Ratio, fuel_flow: MassRate) {
+        if !self.temperature_is_initialised {
+            // An engine already running when the simulation starts has its oil at running temperature
+            let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+            self.fuel_temperature_deg_c.reset(ambient_temperature);
+            let initial_temperature = if n2.get::<percent>() > Self::MIN_RUNNING_N2_PERCENT {
+                Self::stabilised_temperature(
+                    ambient_temperature,
+                    ambient_temperature,
+                    n2,
+                    fuel_flow,
+                )
+            } else {
+                ambient_temperature
+            };
+            self.temperature_deg_c.reset(initial_temperature);
+            self.temperature_is_initialised = true;
+        }
+
+        self.update_temperature(context, n2, fuel_flow);
+        self.update_quantity(context);
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext, n2: Ratio, fuel_flow: MassRate) {
+        let ambient_temperature = context.ambient_temperature().get::<degree_celsius>();
+        let fuel_temperature = self
+            .fuel_temperature_deg_c
+            .update(context.delta(), ambient_temperature);
+
+        self.temperature_deg_c
+            .set_time_constant(Duration::from_secs_f64(
+                if self.pressure > Pressure::new::<psi>(0.) {
+                    Self::RUNNING_TEMPERATURE_TIME_CONSTANT_S
+                } else {
+                    Self::STOPPED_TEMPERATURE_TIME_CONSTANT_S
+                },
+            ));
+        self.temperature_deg_c.update(
+            context.delta(),
+            Self::stabilised_temperature(ambient_temperature, fuel_temperature, n2, fuel_flow),
+        );
+    }
+
+    fn stabilised_temperature(
+        ambient_temperature: f64,
+        fuel_temperature: f64,
+        n2: Ratio,
+        fuel_flow: MassRate,
+    ) -> f64 {
+        let heat_exchanger_effectiveness = Self::heat_exchanger_effectiveness(fuel_flow);
+        let heat_sink_temperature = ambient_temperature
+            + (fuel_temperature - ambient_temperature) * heat_exchanger_effectiveness;
+
+        let n2_ratio = (n2.get::<percent>() / 100.).max(0.);
+        let temperature_rise = Self::MAX_TEMPERATURE_RISE_DEG_C
+            * n2_ratio.powf(Self::TEMPERATURE_RISE_EXPONENT)
+            * (1.
+                - Self::HEAT_EXCHANGER_MAX_TEMPERATURE_RISE_REDUCTION
+                    * heat_exchanger_effectiveness);
+
+        heat_sink_temperature + temperature_rise
+    }
+
+    fn heat_exchanger_effectiveness(fuel_flow: MassRate) -> f64 {
+        let fuel_flow = fuel_flow.get::<kilogram_per_hour>().max(0.);
+
+        fuel_flow / (fuel_flow + Self::HEAT_EXCHANGER_HALF_EFFECTIVENESS_FUEL_FLOW_KG_PER_HOUR)
+    }
+
+    fn update_quantity(&mut self, context: &UpdateContext) {
+        // Oil is only consumed or lost through a leak when the circuit is pressurised
+        if self.pressure <= Pressure::new::<psi>(0.) {
+            return;
+        }
+
+        let delta_seconds = context.delta_as_time().get::<second>();
+        let mut lost_quantity_qt = Self::NORMAL_CONSUMPTION_QT_PER_HOUR / 3600. * delta_seconds;
+        if self.leak_failure.is_active() {
+            lost_quantity_qt += Self::LEAK_RATE_QT_PER_MINUTE / 60. * delta_seconds;
+        }
+
+        self.quantity = Volume::new::<quart_liquid>(
+            (self.quantity.get::<quart_liquid>() - lost_quantity_qt).max(0.),
+        );
+    }
+
+    /// Updated when the engine speed is read, so that consumers of the oil pressure
+    /// see the pressure matching the current engine speed before the systems update.
+    pub fn update_pressure(&mut self, n2: Ratio) {
+        if self.pump_failure.is_active() {
+            self.pressure = Pressure::new::<psi>(0.);
+            return;
+        }
+
+        let viscosity_factor = (1.
+            + Self::VISCOSITY_PRESSURE_GAIN_PER_DEG_C
+                * (Self::REFERENCE_TEMPERATURE_DEG_C - self.temperature_deg_c.output()))
+        .clamp(
+            Self::MIN_VISCOSITY_PRESSURE_FACTOR,
+            Self::MAX_VISCOSITY_PRESSURE_FACTOR,
+        );
+
+        let suction_factor = (self.quantity.get::<quart_liquid>()
+            / Self::MIN_QUANTITY_FOR_FULL_PRESSURE_QT)
+            .clamp(0., 1.);
+
+        self.pressure = Pressure::new::<psi>(
+            (Self::PRESSURE_PSI_PER_N2_PERCENT
+                * n2.get::<percent>()
+                * viscosity_factor
+                * suction_factor)
+                .max(0.),
+        );
+    }
+
+    pub fn quantity(&self) -> Volume {
+        self.quantity
+    }
+
+    pub fn pressure(&self) -> Pressure {
+        self.pressure
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.temperature_deg_c.output())
+    }
+
+    pub fn capacity(&self) -> Volume {
+        Volume::new::<quart_liquid>(Self::TANK_CAPACITY_QT)
+    }
+}
+impl SimulationElement for EngineOilSystem {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.leak_failure.accept(visitor);
+        self.pump_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.quantity_id, self.quantity.get::<quart_liquid>());
+        writer.write(&self.pressure_id, self.pressure);
+        writer.write(&self.temperature_id, self.temperature());
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.quantity);
+        snapshot.write(self.pressure);
+        snapshot.write(self.temperature_deg_c.output());
+        snapshot.write(self.fuel_temperature_deg_c.output());
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.quantity = snapshot.read();
+        self.pressure = snapshot.read();
+        self.temperature_deg_c.reset(snapshot.read());
+        self.fuel_temperature_deg_c.reset(snapshot.read());
+        self.temperature_is_initialised = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed},
+        Aircraft,
+    };
+    use std::time::Duration;
+
+    struct TestAircraft {
+        oil: EngineOilSystem,
+        n2: Ratio,
+        fuel_flow: MassRate,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                oil: EngineOilSystem::new(context, 1),
+                n2: Ratio::new::<percent>(0.),
+                fuel_flow: MassRate::new::<kilogram_per_hour>(0.),
+            }
+        }
+
+        fn set_n2(&mut self, n2: Ratio) {
+            self.n2 = n2;
+        }
+
+        fn set_fuel_flow(&mut self, fuel_flow: MassRate) {
+            self.fuel_flow = fuel_flow;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.oil.update_pressure(self.n2);
+            self.oil.update(context, self.n2, self.fuel_flow);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.oil.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_at_ambient(ambient_deg_c: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(
+            ambient_deg_c,
+        ));
+
+        test_bed
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        test_bed_at_ambient(EngineOilSystem::REFERENCE_TEMPERATURE_DEG_C)
+    }
+
+    fn run_at_n2(test_bed: &mut SimulationTestBed<TestAircraft>, n2: f64, duration: Duration) {
+        test_bed.command(|a| a.set_n2(Ratio::new::<percent>(n2)));
+        test_bed.run_with_delta(duration);
+    }
+
+    #[test]
+    fn oil_temperature_initialises_to_ambient() {
+        let mut test_bed = test_bed_at_ambient(-20.);
+
+        run_at_n2(&mut test_bed, 0., Duration::from_millis(100));
+
+        assert!(
+            (test_bed
+                .query(|a| a.oil.temperature())
+                .get::<degree_celsius>()
+                + 20.)
+                .abs()
+                < 0.1
+        );
+    }
+
+    #[test]
+    fn oil_temperature_initialises_warm_when_engine_running() {
+        let mut test_bed = test_bed_at_ambient(-20.);
+
+        run_at_n2(&mut test_bed, 80., Duration::from_millis(100));
+
+        assert!(
+            test_bed
+                .query(|a| a.oil.temperature())
+                .get::<degree_celsius>()
+                > 50.
+        );
+    }
+
+    #[test]
+    fn no_pressure_when_engine_stopped() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 0., Duration::from_secs(1));
+
+        assert_eq!(
+            test_bed.query(|a| a.oil.pressure()),
+            Pressure::new::<psi>(0.)
+        );
+    }
+
+    #[test]
+    fn pressure_crosses_18_psi_at_25_percent_n2_at_reference_temperature() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 24., Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.oil.pressure()) < Pressure::new::<psi>(18.));
+
+        run_at_n2(&mut test_bed, 26., Duration::from_millis(10));
+        assert!(test_bed.query(|a| a.oil.pressure()) > Pressure::new::<psi>(18.));
+    }
+
+    #[test]
+    fn cold_oil_gives_higher_pressure_than_hot_oil() {
+        let mut cold_test_bed = test_bed_at_ambient(-30.);
+        run_at_n2(&mut cold_test_bed, 0., Duration::from_millis(10));
+        run_at_n2(&mut cold_test_bed, 60., Duration::from_millis(10));
+
+        let mut hot_test_bed = test_bed();
+        run_at_n2(&mut hot_test_bed, 100., Duration::from_secs(1800));
+        run_at_n2(&mut hot_test_bed, 60., Duration::from_millis(10));
+
+        assert!(
+            cold_test_bed.query(|a| a.oil.pressure()) > hot_test_bed.query(|a| a.oil.pressure())
+        );
+    }
+
+    #[test]
+    fn oil_heats_up_with_engine_speed() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 60., Duration::from_secs(1800));
+        let idle_temperature = test_bed.query(|a| a.oil.temperature());
+
+        run_at_n2(&mut test_bed, 100., Duration::from_secs(1800));
+        let takeoff_temperature = test_bed.query(|a| a.oil.temperature());
+
+        assert!(idle_temperature.get::<degree_celsius>() > 50.);
+        assert!(takeoff_temperature > idle_temperature);
+        assert!(takeoff_temperature.get::<degree_celsius>() < 140.);
+    }
+
+    #[test]
+    fn fuel_flowing_through_heat_exchanger_cools_the_oil() {
+        let mut uncooled_test_bed = test_bed();
+        run_at_n2(&mut uncooled_test_bed, 90., Duration::from_secs(1800));
+
+        let mut cooled_test_bed = test_bed();
+        cooled_test_bed.command(|a| a.set_fuel_flow(MassRate::new::<kilogram_per_hour>(3000.)));
+        run_at_n2(&mut cooled_test_bed, 90., Duration::from_secs(1800));
+
+        assert!(
+            cooled_test_bed.query(|a| a.oil.temperature())
+                < uncooled_test_bed.query(|a| a.oil.temperature())
+        );
+    }
+
+    #[test]
+    fn oil_cools_down_to_ambient_once_engine_stopped() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 100., Duration::from_secs(1800));
+        for _ in 0..10 {
+            run_at_n2(&mut test_bed, 0., Duration::from_secs(1800));
+        }
+
+        assert!(
+            (test_bed
+                .query(|a| a.oil.temperature())
+                .get::<degree_celsius>()
+                - EngineOilSystem::REFERENCE_TEMPERATURE_DEG_C)
+                .abs()
+                < 1.
+        );
+    }
+
+    #[test]
+    fn oil_is_consumed_while_engine_running() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 1., Duration::from_millis(10));
+        for _ in 0..10 {
+            run_at_n2(&mut test_bed, 80., Duration::from_secs(360));
+        }
+
+        let consumed = EngineOilSystem::INITIAL_QUANTITY_QT
+            - test_bed.query(|a| a.oil.quantity()).get::<quart_liquid>();
+        assert!((consumed - EngineOilSystem::NORMAL_CONSUMPTION_QT_PER_HOUR).abs() < 0.01);
+    }
+
+    #[test]
+    fn oil_is_not_consumed_while_engine_stopped() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 0., Duration::from_secs(3600));
+
+        assert_eq!(
+            test_bed.query(|a| a.oil.quantity()),
+            Volume::new::<quart_liquid>(EngineOilSystem::INITIAL_QUANTITY_QT)
+        );
+    }
+
+    #[test]
+    fn oil_leak_drains_tank_and_pressure_is_lost() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineOilLeak(1));
+
+        for _ in 0..60 {
+            run_at_n2(&mut test_bed, 80., Duration::from_secs(60));
+        }
+
+        assert!(test_bed.query(|a| a.oil.quantity()) < Volume::new::<quart_liquid>(0.1));
+        assert!(test_bed.query(|a| a.oil.pressure()) < Pressure::new::<psi>(18.));
+    }
+
+    #[test]
+    fn oil_pump_failure_gives_no_pressure() {
+        let mut test_bed = test_bed();
+        test_bed.fail(FailureType::EngineOilPumpFailure(1));
+
+        run_at_n2(&mut test_bed, 80., Duration::from_secs(1));
+
+        assert_eq!(
+            test_bed.query(|a| a.oil.pressure()),
+            Pressure::new::<psi>(0.)
+        );
+    }
+
+    #[test]
+    fn writes_its_state() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 80., Duration::from_secs(1));
+
+        let quantity_qt: f64 = test_bed.read_by_name("ENGINE_1_OIL_QUANTITY");
+        let pressure: Pressure = test_bed.read_by_name("ENGINE_1_OIL_PRESSURE");
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("ENGINE_1_OIL_TEMPERATURE");
+
+        assert!((quantity_qt - EngineOilSystem::INITIAL_QUANTITY_QT).abs() < 0.01);
+        assert!(pressure > Pressure::new::<psi>(18.));
+        assert!(temperature.get::<degree_celsius>() > 15.);
+    }
+
+    #[test]
+    fn restoring_a_snapshot_restores_quantity_and_temperature() {
+        let mut test_bed = test_bed();
+
+        run_at_n2(&mut test_bed, 1., Duration::from_millis(10));
+        for _ in 0..10 {
+            run_at_n2(&mut test_bed, 80., Duration::from_secs(360));
+        }
+        let quantity = test_bed.query(|a| a.oil.quantity());
+        let temperature = test_bed.query(|a| a.oil.temperature());
+        let snapshot = test_bed.snapshot();
+
+        for _ in 0..10 {
+            run_at_n2(&mut test_bed, 0., Duration::from_secs(1800));
+        }
+        test_bed.restore(&snapshot).unwrap();
+
+        assert_eq!(test_bed.query(|a| a.oil.quantity()), quantity);
+        assert_eq!(test_bed.query(|a| a.oil.temperature()), temperature);
+    }
+}
@@ -0,0 +1,265 @@
+use uom::si::{
+    angular_velocity::revolution_per_minute, f64::*, mass_rate::kilogram_per_hour, pressure::psi,
+    ratio::percent,
+};
+
+use crate::{
+    shared::{EngineCorrectedN1, EngineCorrectedN2, EngineUncorrectedN2},
+    simulation::{
+        Read, SimulationElement, SimulationElementVisitor, SimulatorReader, UpdateContext,
+    },
+};
+
+use super::{oil::EngineOilSystem, Engine};
+use crate::simulation::{InitContext, VariableIdentifier};
+
+/// Three spool Trent 900 engine.
+///
+/// Aircraft systems shared with two spool engines use N2 as the speed of the high pressure spool,
+/// which drives the accessory gearbox. On this engine that spool is N3, so the N2 traits
+/// provide the N3 speed. The intermediate pressure spool speed is available on its own.
+pub struct TrentEngine {
+    corrected_n1_id: VariableIdentifier,
+    corrected_n1: Ratio,
+    // The simulator models a two spool engine, its second spool is our high pressure spool
+    corrected_n3_id: VariableIdentifier,
+    corrected_n3: Ratio,
+
+    uncorrected_n1_id: VariableIdentifier,
+    uncorrected_n1: Ratio,
+    intermediate_pressure_n2_id: VariableIdentifier,
+    intermediate_pressure_n2: Ratio,
+    uncorrected_n3_id: VariableIdentifier,
+    uncorrected_n3: Ratio,
+
+    fuel_flow_id: VariableIdentifier,
+    fuel_flow: MassRate,
+
+    n3_speed: AngularVelocity,
+    hydraulic_pump_output_speed: AngularVelocity,
+    generator_drive_speed: AngularVelocity,
+    oil: EngineOilSystem,
+}
+impl TrentEngine {
+    // According to the Type Certificate Data Sheet of Trent 900
+    // 100% N3 @ 12200 RPM
+    const TRENT_900_MAX_N3_RPM: f64 = 12200.0;
+    // Gear ratio from the HP spool to the EDP drive shafts of the accessory gearbox
+    const PUMP_N3_GEAR_RATIO: f64 = 0.309;
+    // Gear ratio from the HP spool to the variable frequency generator drive
+    const GENERATOR_N3_GEAR_RATIO: f64 = 1.;
+
+    const MIN_IDLE_N3_UNCORRECTED_THRESHOLD_PERCENT: f64 = 57.;
+
+    const LOW_OIL_PRESSURE_THRESHOLD_PSI: f64 = 25.;
+
+    pub fn new(context: &mut InitContext, number: usize) -> TrentEngine {
+        TrentEngine {
+            corrected_n1_id: context.get_identifier(format!("TURB ENG CORRECTED N1:{}", number)),
+            corrected_n1: Ratio::new::<percent>(0.),
+            corrected_n3_id: context.get_identifier(format!("TURB ENG CORRECTED N2:{}", number)),
+            corrected_n3: Ratio::new::<percent>(0.),
+            uncorrected_n1_id: context.get_identifier(format!("ENGINE_N1:{}", number)),
+            uncorrected_n1: Ratio::new::<percent>(0.),
+            intermediate_pressure_n2_id: context.get_identifier(format!("ENGINE_N2:{}", number)),
+            intermediate_pressure_n2: Ratio::new::<percent>(0.),
+            uncorrected_n3_id: context.get_identifier(format!("ENGINE_N3:{}", number)),
+            uncorrected_n3: Ratio::new::<percent>(0.),
+            fuel_flow_id: context.get_identifier(format!("ENGINE_FF:{}", number)),
+            fuel_flow: MassRate::new::<kilogram_per_hour>(0.),
+            n3_speed: AngularVelocity::new::<revolution_per_minute>(0.),
+            hydraulic_pump_output_speed: AngularVelocity::new::<revolution_per_minute>(0.),
+            generator_drive_speed: AngularVelocity::new::<revolution_per_minute>(0.),
+            oil: EngineOilSystem::new(context, number),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        self.oil
+            .update(context, self.uncorrected_n3, self.fuel_flow);
+    }
+
+    fn update_parameters(&mut self) {
+        self.n3_speed = AngularVelocity::new::<revolution_per_minute>(
+            self.uncorrected_n3.get::<percent>() * Self::TRENT_900_MAX_N3_RPM / 100.,
+        );
+        self.hydraulic_pump_output_speed = self.n3_speed * Self::PUMP_N3_GEAR_RATIO;
+        self.generator_drive_speed = self.n3_speed * Self::GENERATOR_N3_GEAR_RATIO;
+
+        self.oil.update_pressure(self.uncorrected_n3);
+    }
+
+    pub fn low_pressure_spool_speed(&self) -> Ratio {
+        self.uncorrected_n1
+    }
+
+    pub fn intermediate_pressure_spool_speed(&self) -> Ratio {
+        self.intermediate_pressure_n2
+    }
+
+    pub fn high_pressure_spool_speed(&self) -> Ratio {
+        self.uncorrected_n3
+    }
+
+    pub fn generator_drive_speed(&self) -> AngularVelocity {
+        self.generator_drive_speed
+    }
+
+    pub fn oil(&self) -> &EngineOilSystem {
+        &self.oil
+    }
+}
+impl SimulationElement for TrentEngine {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.oil.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.corrected_n1 = reader.read(&self.corrected_n1_id);
+        self.corrected_n3 = reader.read(&self.corrected_n3_id);
+        self.uncorrected_n1 = reader.read(&self.uncorrected_n1_id);
+        self.intermediate_pressure_n2 = reader.read(&self.intermediate_pressure_n2_id);
+        self.uncorrected_n3 = reader.read(&self.uncorrected_n3_id);
+        let fuel_flow_kg_per_hour: f64 = reader.read(&self.fuel_flow_id);
+        self.fuel_flow = MassRate::new::<kilogram_per_hour>(fuel_flow_kg_per_hour.max(0.));
+        self.update_parameters();
+    }
+}
+impl EngineCorrectedN1 for TrentEngine {
+    fn corrected_n1(&self) -> Ratio {
+        self.corrected_n1
+    }
+}
+impl EngineCorrectedN2 for TrentEngine {
+    fn corrected_n2(&self) -> Ratio {
+        self.corrected_n3
+    }
+}
+impl EngineUncorrectedN2 for TrentEngine {
+    fn uncorrected_n2(&self) -> Ratio {
+        self.uncorrected_n3
+    }
+}
+impl Engine for TrentEngine {
+    fn hydraulic_pump_output_speed(&self) -> AngularVelocity {
+        self.hydraulic_pump_output_speed
+    }
+
+    fn oil_pressure_is_low(&self) -> bool {
+        self.oil.pressure().get::<psi>() < TrentEngine::LOW_OIL_PRESSURE_THRESHOLD_PSI
+    }
+
+    fn is_above_minimum_idle(&self) -> bool {
+        self.uncorrected_n3
+            >= Ratio::new::<percent>(TrentEngine::MIN_IDLE_N3_UNCORRECTED_THRESHOLD_PERCENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+
+    struct TestAircraft {
+        engine: TrentEngine,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                engine: TrentEngine::new(context, 1),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.engine.update(context);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.engine.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed_with_spools(n1: f64, n2: f64, n3: f64) -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.write_by_name("ENGINE_N1:1", Ratio::new::<percent>(n1));
+        test_bed.write_by_name("ENGINE_N2:1", Ratio::new::<percent>(n2));
+        test_bed.write_by_name("ENGINE_N3:1", Ratio::new::<percent>(n3));
+        test_bed.write_by_name("TURB ENG CORRECTED N2:1", Ratio::new::<percent>(n3));
+        test_bed.run();
+
+        test_bed
+    }
+
+    #[test]
+    fn shared_n2_is_the_high_pressure_spool() {
+        let test_bed = test_bed_with_spools(20., 61., 60.);
+
+        assert_eq!(
+            test_bed.query(|a| a.engine.uncorrected_n2()),
+            Ratio::new::<percent>(60.)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.engine.corrected_n2()),
+            Ratio::new::<percent>(60.)
+        );
+        assert_eq!(
+            test_bed.query(|a| a.engine.intermediate_pressure_spool_speed()),
+            Ratio::new::<percent>(61.)
+        );
+    }
+
+    #[test]
+    fn pumps_and_generator_are_driven_by_the_high_pressure_spool() {
+        let test_bed = test_bed_with_spools(100., 100.7, 100.);
+
+        assert!(
+            (test_bed
+                .query(|a| a.engine.hydraulic_pump_output_speed())
+                .get::<revolution_per_minute>()
+                - 3769.8)
+                .abs()
+                < 0.1
+        );
+        assert!(
+            (test_bed
+                .query(|a| a.engine.generator_drive_speed())
+                .get::<revolution_per_minute>()
+                - 12200.)
+                .abs()
+                < 0.1
+        );
+    }
+
+    #[test]
+    fn engine_at_idle_is_above_minimum_idle() {
+        let test_bed = test_bed_with_spools(20., 60.7, 60.);
+
+        assert!(test_bed.query(|a| a.engine.is_above_minimum_idle()));
+    }
+
+    #[test]
+    fn engine_spooling_up_is_below_minimum_idle() {
+        let test_bed = test_bed_with_spools(10., 45.7, 45.);
+
+        assert!(!test_bed.query(|a| a.engine.is_above_minimum_idle()));
+    }
+
+    #[test]
+    fn oil_pressure_is_low_when_engine_is_stopped() {
+        let mut test_bed = test_bed_with_spools(0., 0., 0.);
+
+        let pressure: Pressure = test_bed.read_by_name("ENGINE_1_OIL_PRESSURE");
+
+        assert!(pressure.get::<psi>() < 1.);
+        assert!(test_bed.query(|a| a.engine.oil_pressure_is_low()));
+    }
+}
@@ -0,0 +1,712 @@
+use crate::{
+    shared::{low_pass_filter::LowPassFilter, DelayedTrueLogicGate},
+    simulation::UpdateContext,
+};
+use std::time::Duration;
+use uom::si::{
+    angle::degree,
+    f64::{Angle, Length, Velocity},
+    length::foot,
+    velocity::{foot_per_minute, knot},
+};
+
+/// The sensor and configuration data shared by all basic GPWS modes for one computation cycle.
+/// Any value which is `None` is considered invalid and inhibits the modes which rely on it.
+#[derive(Clone, Copy)]
+pub struct GpwsModesInput {
+    pub radio_altitude: Length,
+    pub radio_altitude_rate: Velocity,
+    pub barometric_altitude: Option<Length>,
+    pub vertical_speed: Option<Velocity>,
+    pub computed_airspeed: Option<Velocity>,
+    pub glideslope_deviation: Option<Angle>,
+    pub gear_is_down: bool,
+    pub flaps_in_landing_configuration: bool,
+    pub flap_mode_inhibited: bool,
+    pub glideslope_mode_inhibited: bool,
+    pub is_in_takeoff_phase: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExcessiveDescentRateAlert {
+    None,
+    SinkRate,
+    PullUp,
+}
+
+/// Mode 1: alerts the crew when the barometric sink rate is excessive with respect to the
+/// height above terrain.
+pub struct ExcessiveDescentRate {
+    alert: ExcessiveDescentRateAlert,
+}
+impl ExcessiveDescentRate {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 10.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 2450.;
+    const MINIMUM_SINK_RATE_FOOT_PER_MINUTE: f64 = 1000.;
+
+    pub fn new() -> Self {
+        Self {
+            alert: ExcessiveDescentRateAlert::None,
+        }
+    }
+
+    pub fn update(&mut self, input: &GpwsModesInput) {
+        let radio_altitude = input.radio_altitude.get::<foot>();
+
+        self.alert = match input.vertical_speed {
+            Some(vertical_speed)
+                if (Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+                    .contains(&radio_altitude) =>
+            {
+                let sink_rate = -vertical_speed.get::<foot_per_minute>();
+
+                if sink_rate <= Self::MINIMUM_SINK_RATE_FOOT_PER_MINUTE {
+                    ExcessiveDescentRateAlert::None
+                } else if radio_altitude <= Self::pull_up_boundary(sink_rate) {
+                    ExcessiveDescentRateAlert::PullUp
+                } else if radio_altitude <= Self::sink_rate_boundary(sink_rate) {
+                    ExcessiveDescentRateAlert::SinkRate
+                } else {
+                    ExcessiveDescentRateAlert::None
+                }
+            }
+            _ => ExcessiveDescentRateAlert::None,
+        };
+    }
+
+    fn sink_rate_boundary(sink_rate: f64) -> f64 {
+        0.61 * sink_rate - 600.
+    }
+
+    fn pull_up_boundary(sink_rate: f64) -> f64 {
+        if sink_rate < 1700. {
+            1.3 * sink_rate - 1940.
+        } else {
+            0.4 * sink_rate - 410.
+        }
+    }
+
+    pub fn alert(&self) -> ExcessiveDescentRateAlert {
+        self.alert
+    }
+
+    pub fn reset(&mut self) {
+        self.alert = ExcessiveDescentRateAlert::None;
+    }
+}
+impl Default for ExcessiveDescentRate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExcessiveClosureRateAlert {
+    None,
+    Terrain,
+    PullUp,
+}
+
+/// Mode 2: alerts the crew when the rate of closure with the terrain, as derived from the radio
+/// altitude, is excessive. Mode 2A applies when the flaps are not in landing configuration,
+/// mode 2B when they are.
+pub struct ExcessiveClosureRate {
+    in_envelope: DelayedTrueLogicGate,
+    pull_up: DelayedTrueLogicGate,
+    envelope_exit_radio_altitude: Option<Length>,
+    alert: ExcessiveClosureRateAlert,
+}
+impl ExcessiveClosureRate {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 30.;
+    const MINIMUM_CLOSURE_RATE_FOOT_PER_MINUTE: f64 = 2000.;
+    const MAXIMUM_MODE_2B_CLOSURE_RATE_FOOT_PER_MINUTE: f64 = 10000.;
+    const MAXIMUM_MODE_2B_RADIO_ALTITUDE_FOOT: f64 = 775.;
+    const ALTITUDE_GAIN_TO_CLEAR_TERRAIN_FOOT: f64 = 300.;
+
+    // The derived closure rate is noisy, the envelope must be penetrated for some time
+    // before alerting.
+    const ENVELOPE_CONFIRMATION_TIME: Duration = Duration::from_millis(500);
+    const TERRAIN_TO_PULL_UP_TIME: Duration = Duration::from_millis(1600);
+
+    pub fn new() -> Self {
+        Self {
+            in_envelope: DelayedTrueLogicGate::new(Self::ENVELOPE_CONFIRMATION_TIME),
+            pull_up: DelayedTrueLogicGate::new(Self::TERRAIN_TO_PULL_UP_TIME),
+            envelope_exit_radio_altitude: None,
+            alert: ExcessiveClosureRateAlert::None,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, input: &GpwsModesInput) {
+        self.in_envelope
+            .update(context, self.is_within_envelope(input));
+        self.pull_up
+            .update(context, self.in_envelope.output() && !input.gear_is_down);
+
+        if self.in_envelope.output() {
+            self.envelope_exit_radio_altitude = None;
+            self.alert = if self.pull_up.output() {
+                ExcessiveClosureRateAlert::PullUp
+            } else {
+                ExcessiveClosureRateAlert::Terrain
+            };
+        } else if self.alert != ExcessiveClosureRateAlert::None {
+            // After leaving the envelope, the TERRAIN alert remains until the aircraft gained
+            // enough altitude.
+            let exit_altitude = *self
+                .envelope_exit_radio_altitude
+                .get_or_insert(input.radio_altitude);
+
+            if input.radio_altitude
+                >= exit_altitude + Length::new::<foot>(Self::ALTITUDE_GAIN_TO_CLEAR_TERRAIN_FOOT)
+            {
+                self.envelope_exit_radio_altitude = None;
+                self.alert = ExcessiveClosureRateAlert::None;
+            } else {
+                self.alert = ExcessiveClosureRateAlert::Terrain;
+            }
+        }
+    }
+
+    fn is_within_envelope(&self, input: &GpwsModesInput) -> bool {
+        let radio_altitude = input.radio_altitude.get::<foot>();
+        let closure_rate = -input.radio_altitude_rate.get::<foot_per_minute>();
+
+        if radio_altitude < Self::MINIMUM_RADIO_ALTITUDE_FOOT
+            || closure_rate <= Self::MINIMUM_CLOSURE_RATE_FOOT_PER_MINUTE
+        {
+            return false;
+        }
+
+        let rate_boundary = if closure_rate < 3500. {
+            0.7937 * closure_rate - 1557.5
+        } else {
+            0.19166 * closure_rate + 610.
+        };
+
+        if input.flaps_in_landing_configuration {
+            radio_altitude < Self::MAXIMUM_MODE_2B_RADIO_ALTITUDE_FOOT
+                && radio_altitude < rate_boundary
+                && closure_rate < Self::MAXIMUM_MODE_2B_CLOSURE_RATE_FOOT_PER_MINUTE
+        } else {
+            let speed_boundary = input
+                .computed_airspeed
+                .map_or(1650., |speed| 8.8888 * speed.get::<knot>() - 305.555)
+                .clamp(1650., 2450.);
+
+            radio_altitude < speed_boundary && radio_altitude < rate_boundary
+        }
+    }
+
+    pub fn alert(&self) -> ExcessiveClosureRateAlert {
+        self.alert
+    }
+
+    pub fn reset(&mut self) {
+        self.in_envelope = DelayedTrueLogicGate::new(Self::ENVELOPE_CONFIRMATION_TIME);
+        self.pull_up = DelayedTrueLogicGate::new(Self::TERRAIN_TO_PULL_UP_TIME);
+        self.envelope_exit_radio_altitude = None;
+        self.alert = ExcessiveClosureRateAlert::None;
+    }
+}
+impl Default for ExcessiveClosureRate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Mode 3: alerts the crew of a significant barometric altitude loss after takeoff or during a
+/// go around.
+pub struct AltitudeLossAfterTakeoff {
+    maximum_barometric_altitude: Option<Length>,
+    dont_sink: bool,
+}
+impl AltitudeLossAfterTakeoff {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 10.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 1500.;
+
+    pub fn new() -> Self {
+        Self {
+            maximum_barometric_altitude: None,
+            dont_sink: false,
+        }
+    }
+
+    pub fn update(&mut self, input: &GpwsModesInput) {
+        let radio_altitude = input.radio_altitude.get::<foot>();
+
+        match input.barometric_altitude {
+            Some(barometric_altitude)
+                if input.is_in_takeoff_phase
+                    && (Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+                        .contains(&radio_altitude) =>
+            {
+                let maximum_barometric_altitude = match self.maximum_barometric_altitude {
+                    Some(maximum) if maximum >= barometric_altitude => maximum,
+                    _ => barometric_altitude,
+                };
+                self.maximum_barometric_altitude = Some(maximum_barometric_altitude);
+
+                self.dont_sink = (maximum_barometric_altitude - barometric_altitude)
+                    > Self::maximum_altitude_loss(input.radio_altitude);
+            }
+            _ => self.reset(),
+        }
+    }
+
+    fn maximum_altitude_loss(radio_altitude: Length) -> Length {
+        Length::new::<foot>(0.09 * radio_altitude.get::<foot>() + 7.1)
+    }
+
+    pub fn dont_sink(&self) -> bool {
+        self.dont_sink
+    }
+
+    pub fn reset(&mut self) {
+        self.maximum_barometric_altitude = None;
+        self.dont_sink = false;
+    }
+}
+impl Default for AltitudeLossAfterTakeoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnsafeTerrainClearanceAlert {
+    None,
+    TooLowGear,
+    TooLowFlaps,
+    TooLowTerrain,
+}
+
+/// Mode 4: alerts the crew of insufficient terrain clearance with respect to the phase of flight,
+/// the landing configuration and the airspeed. Mode 4A applies with the gear up, mode 4B with
+/// the flaps not in landing configuration and mode 4C after takeoff or during a go around.
+pub struct UnsafeTerrainClearance {
+    maximum_radio_altitude_since_takeoff: Option<Length>,
+    alert: UnsafeTerrainClearanceAlert,
+}
+impl UnsafeTerrainClearance {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 30.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 1000.;
+
+    pub fn new() -> Self {
+        Self {
+            maximum_radio_altitude_since_takeoff: None,
+            alert: UnsafeTerrainClearanceAlert::None,
+        }
+    }
+
+    pub fn update(&mut self, input: &GpwsModesInput) {
+        if input.is_in_takeoff_phase {
+            self.maximum_radio_altitude_since_takeoff = Some(
+                self.maximum_radio_altitude_since_takeoff
+                    .map_or(input.radio_altitude, |maximum| {
+                        maximum.max(input.radio_altitude)
+                    }),
+            );
+        } else {
+            self.maximum_radio_altitude_since_takeoff = None;
+        }
+
+        let radio_altitude = input.radio_altitude.get::<foot>();
+        if !(Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+            .contains(&radio_altitude)
+        {
+            self.alert = UnsafeTerrainClearanceAlert::None;
+            return;
+        }
+
+        let speed = input
+            .computed_airspeed
+            .map_or(0., |speed| speed.get::<knot>());
+
+        self.alert = if input.is_in_takeoff_phase {
+            self.mode_4c_alert(input, radio_altitude, speed)
+        } else if !input.gear_is_down {
+            Self::mode_4a_alert(radio_altitude, speed)
+        } else if !input.flaps_in_landing_configuration && !input.flap_mode_inhibited {
+            Self::mode_4b_alert(radio_altitude, speed)
+        } else {
+            UnsafeTerrainClearanceAlert::None
+        };
+    }
+
+    fn mode_4a_alert(radio_altitude: f64, speed: f64) -> UnsafeTerrainClearanceAlert {
+        if speed < 190. {
+            if radio_altitude < 500. {
+                UnsafeTerrainClearanceAlert::TooLowGear
+            } else {
+                UnsafeTerrainClearanceAlert::None
+            }
+        } else if radio_altitude < 8.333 * speed - 1083.333 {
+            UnsafeTerrainClearanceAlert::TooLowTerrain
+        } else {
+            UnsafeTerrainClearanceAlert::None
+        }
+    }
+
+    fn mode_4b_alert(radio_altitude: f64, speed: f64) -> UnsafeTerrainClearanceAlert {
+        if speed < 159. {
+            if radio_altitude < 245. {
+                UnsafeTerrainClearanceAlert::TooLowFlaps
+            } else {
+                UnsafeTerrainClearanceAlert::None
+            }
+        } else if radio_altitude < 8.2967 * speed - 1074.18 {
+            UnsafeTerrainClearanceAlert::TooLowTerrain
+        } else {
+            UnsafeTerrainClearanceAlert::None
+        }
+    }
+
+    fn mode_4c_alert(
+        &self,
+        input: &GpwsModesInput,
+        radio_altitude: f64,
+        speed: f64,
+    ) -> UnsafeTerrainClearanceAlert {
+        let maximum_radio_altitude = self
+            .maximum_radio_altitude_since_takeoff
+            .map_or(0., |maximum| maximum.get::<foot>());
+
+        if (input.flaps_in_landing_configuration && input.gear_is_down)
+            || maximum_radio_altitude <= 100.
+        {
+            return UnsafeTerrainClearanceAlert::None;
+        }
+
+        let speed_boundary = (8.3333 * speed - 1083.33).clamp(500., 1000.);
+        let minimum_terrain_clearance = 0.75 * maximum_radio_altitude - 0.75;
+
+        if radio_altitude < speed_boundary && radio_altitude < minimum_terrain_clearance {
+            UnsafeTerrainClearanceAlert::TooLowTerrain
+        } else {
+            UnsafeTerrainClearanceAlert::None
+        }
+    }
+
+    pub fn alert(&self) -> UnsafeTerrainClearanceAlert {
+        self.alert
+    }
+
+    pub fn reset(&mut self) {
+        self.alert = UnsafeTerrainClearanceAlert::None;
+    }
+}
+impl Default for UnsafeTerrainClearance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BelowGlideslopeAlert {
+    None,
+    Soft,
+    Hard,
+}
+
+/// Mode 5: alerts the crew of an excessive deviation below the glideslope during an ILS approach
+/// with the gear down.
+pub struct BelowGlideslope {
+    alert: BelowGlideslopeAlert,
+}
+impl BelowGlideslope {
+    const MINIMUM_RADIO_ALTITUDE_FOOT: f64 = 30.;
+    const MAXIMUM_RADIO_ALTITUDE_FOOT: f64 = 1000.;
+    const MAXIMUM_HARD_ALERT_RADIO_ALTITUDE_FOOT: f64 = 350.;
+
+    // One dot of glideslope deviation is approximately 0.4°.
+    const DEGREES_PER_DOT: f64 = 0.4;
+
+    pub fn new() -> Self {
+        Self {
+            alert: BelowGlideslopeAlert::None,
+        }
+    }
+
+    pub fn update(&mut self, input: &GpwsModesInput) {
+        let radio_altitude = input.radio_altitude.get::<foot>();
+
+        self.alert = match input.glideslope_deviation {
+            Some(deviation)
+                if input.gear_is_down
+                    && !input.glideslope_mode_inhibited
+                    && (Self::MINIMUM_RADIO_ALTITUDE_FOOT..=Self::MAXIMUM_RADIO_ALTITUDE_FOOT)
+                        .contains(&radio_altitude) =>
+            {
+                // A negative deviation means the aircraft is below the glideslope.
+                let dots_below = -deviation.get::<degree>() / Self::DEGREES_PER_DOT;
+
+                let soft_alert_floor = if dots_below < 2.9 {
+                    -75. * dots_below + 247.5
+                } else {
+                    Self::MINIMUM_RADIO_ALTITUDE_FOOT
+                };
+                let hard_alert_floor = if dots_below < 3.8 {
+                    -66.66 * dots_below + 283.33
+                } else {
+                    Self::MINIMUM_RADIO_ALTITUDE_FOOT
+                };
+
+                if dots_below > 2.
+                    && radio_altitude > hard_alert_floor
+                    && radio_altitude < Self::MAXIMUM_HARD_ALERT_RADIO_ALTITUDE_FOOT
+                {
+                    BelowGlideslopeAlert::Hard
+                } else if dots_below > 1.3 && radio_altitude > soft_alert_floor {
+                    BelowGlideslopeAlert::Soft
+                } else {
+                    BelowGlideslopeAlert::None
+                }
+            }
+            _ => BelowGlideslopeAlert::None,
+        };
+    }
+
+    pub fn alert(&self) -> BelowGlideslopeAlert {
+        self.alert
+    }
+
+    pub fn reset(&mut self) {
+        self.alert = BelowGlideslopeAlert::None;
+    }
+}
+impl Default for BelowGlideslope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Derives the radio altitude rate, which is used as the terrain closure rate.
+pub struct RadioAltitudeRate {
+    previous_radio_altitude: Option<Length>,
+    filtered_rate: LowPassFilter<Velocity>,
+}
+impl RadioAltitudeRate {
+    const FILTER_TIME_CONSTANT: Duration = Duration::from_millis(500);
+
+    pub fn new() -> Self {
+        Self {
+            previous_radio_altitude: None,
+            filtered_rate: LowPassFilter::new(Self::FILTER_TIME_CONSTANT),
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext, radio_altitude: Option<Length>) {
+        match (self.previous_radio_altitude, radio_altitude) {
+            (Some(previous), Some(current)) if context.delta_as_secs_f64() > 0. => {
+                let rate = Velocity::new::<foot_per_minute>(
+                    (current - previous).get::<foot>() / context.delta_as_secs_f64() * 60.,
+                );
+                self.filtered_rate.update(context.delta(), rate);
+            }
+            (_, None) => self.filtered_rate.reset(Velocity::default()),
+            _ => {}
+        }
+
+        self.previous_radio_altitude = radio_altitude;
+    }
+
+    pub fn rate(&self) -> Velocity {
+        self.filtered_rate.output()
+    }
+}
+impl Default for RadioAltitudeRate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> GpwsModesInput {
+        GpwsModesInput {
+            radio_altitude: Length::new::<foot>(1000.),
+            radio_altitude_rate: Velocity::default(),
+            barometric_altitude: Some(Length::new::<foot>(1000.)),
+            vertical_speed: Some(Velocity::default()),
+            computed_airspeed: Some(Velocity::new::<knot>(150.)),
+            glideslope_deviation: None,
+            gear_is_down: true,
+            flaps_in_landing_configuration: true,
+            flap_mode_inhibited: false,
+            glideslope_mode_inhibited: false,
+            is_in_takeoff_phase: false,
+        }
+    }
+
+    #[test]
+    fn mode_1_no_alert_with_low_sink_rate() {
+        let mut mode = ExcessiveDescentRate::new();
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(100.),
+            vertical_speed: Some(Velocity::new::<foot_per_minute>(-900.)),
+            ..input()
+        });
+
+        assert_eq!(mode.alert(), ExcessiveDescentRateAlert::None);
+    }
+
+    #[test]
+    fn mode_1_sink_rate_then_pull_up_when_descending_deeper_into_envelope() {
+        let mut mode = ExcessiveDescentRate::new();
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(1500.),
+            vertical_speed: Some(Velocity::new::<foot_per_minute>(-3500.)),
+            ..input()
+        });
+        assert_eq!(mode.alert(), ExcessiveDescentRateAlert::SinkRate);
+
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(900.),
+            vertical_speed: Some(Velocity::new::<foot_per_minute>(-3500.)),
+            ..input()
+        });
+        assert_eq!(mode.alert(), ExcessiveDescentRateAlert::PullUp);
+    }
+
+    #[test]
+    fn mode_1_inhibited_without_vertical_speed() {
+        let mut mode = ExcessiveDescentRate::new();
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(900.),
+            vertical_speed: None,
+            ..input()
+        });
+
+        assert_eq!(mode.alert(), ExcessiveDescentRateAlert::None);
+    }
+
+    #[test]
+    fn mode_3_dont_sink_after_altitude_loss_in_takeoff_phase() {
+        let mut mode = AltitudeLossAfterTakeoff::new();
+        let takeoff_input = GpwsModesInput {
+            radio_altitude: Length::new::<foot>(500.),
+            barometric_altitude: Some(Length::new::<foot>(600.)),
+            is_in_takeoff_phase: true,
+            ..input()
+        };
+        mode.update(&takeoff_input);
+        assert!(!mode.dont_sink());
+
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(440.),
+            barometric_altitude: Some(Length::new::<foot>(540.)),
+            ..takeoff_input
+        });
+        assert!(mode.dont_sink());
+    }
+
+    #[test]
+    fn mode_3_inactive_outside_takeoff_phase() {
+        let mut mode = AltitudeLossAfterTakeoff::new();
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(500.),
+            barometric_altitude: Some(Length::new::<foot>(600.)),
+            ..input()
+        });
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(400.),
+            barometric_altitude: Some(Length::new::<foot>(500.)),
+            ..input()
+        });
+
+        assert!(!mode.dont_sink());
+    }
+
+    #[test]
+    fn mode_4a_too_low_gear_at_low_speed() {
+        let mut mode = UnsafeTerrainClearance::new();
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(400.),
+            gear_is_down: false,
+            ..input()
+        });
+
+        assert_eq!(mode.alert(), UnsafeTerrainClearanceAlert::TooLowGear);
+    }
+
+    #[test]
+    fn mode_4b_too_low_flaps_unless_flap_mode_inhibited() {
+        let mut mode = UnsafeTerrainClearance::new();
+        let flaps_up_input = GpwsModesInput {
+            radio_altitude: Length::new::<foot>(200.),
+            flaps_in_landing_configuration: false,
+            ..input()
+        };
+        mode.update(&flaps_up_input);
+        assert_eq!(mode.alert(), UnsafeTerrainClearanceAlert::TooLowFlaps);
+
+        mode.update(&GpwsModesInput {
+            flap_mode_inhibited: true,
+            ..flaps_up_input
+        });
+        assert_eq!(mode.alert(), UnsafeTerrainClearanceAlert::None);
+    }
+
+    #[test]
+    fn mode_4c_too_low_terrain_when_terrain_rises_after_takeoff() {
+        let mut mode = UnsafeTerrainClearance::new();
+        let takeoff_input = GpwsModesInput {
+            radio_altitude: Length::new::<foot>(600.),
+            gear_is_down: false,
+            flaps_in_landing_configuration: false,
+            is_in_takeoff_phase: true,
+            ..input()
+        };
+        mode.update(&takeoff_input);
+        assert_eq!(mode.alert(), UnsafeTerrainClearanceAlert::None);
+
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(400.),
+            ..takeoff_input
+        });
+        assert_eq!(mode.alert(), UnsafeTerrainClearanceAlert::TooLowTerrain);
+    }
+
+    #[test]
+    fn mode_5_soft_and_hard_glideslope() {
+        let mut mode = BelowGlideslope::new();
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(800.),
+            glideslope_deviation: Some(Angle::new::<degree>(-0.6)),
+            ..input()
+        });
+        assert_eq!(mode.alert(), BelowGlideslopeAlert::Soft);
+
+        mode.update(&GpwsModesInput {
+            radio_altitude: Length::new::<foot>(300.),
+            glideslope_deviation: Some(Angle::new::<degree>(-1.)),
+            ..input()
+        });
+        assert_eq!(mode.alert(), BelowGlideslopeAlert::Hard);
+    }
+
+    #[test]
+    fn mode_5_inhibited_by_glideslope_mode_off_and_gear_up() {
+        let mut mode = BelowGlideslope::new();
+        let below_glideslope_input = GpwsModesInput {
+            radio_altitude: Length::new::<foot>(800.),
+            glideslope_deviation: Some(Angle::new::<degree>(-0.6)),
+            ..input()
+        };
+
+        mode.update(&GpwsModesInput {
+            glideslope_mode_inhibited: true,
+            ..below_glideslope_input
+        });
+        assert_eq!(mode.alert(), BelowGlideslopeAlert::None);
+
+        mode.update(&GpwsModesInput {
+            gear_is_down: false,
+            ..below_glideslope_input
+        });
+        assert_eq!(mode.alert(), BelowGlideslopeAlert::None);
+    }
+}
@@ -1,22 +1,32 @@
 use crate::{
     accept_iterable,
-    enhanced_gpwc::navigation_display::NavigationDisplay,
+    enhanced_gpwc::{
+        gpws_modes::{
+            AltitudeLossAfterTakeoff, BelowGlideslope, BelowGlideslopeAlert, ExcessiveClosureRate,
+            ExcessiveClosureRateAlert, ExcessiveDescentRate, ExcessiveDescentRateAlert,
+            GpwsModesInput, RadioAltitudeRate, UnsafeTerrainClearance, UnsafeTerrainClearanceAlert,
+        },
+        navigation_display::NavigationDisplay,
+    },
+    navigation::radio_altimeter::RadioAltimeter,
     shared::{
         arinc429::{Arinc429Word, SignStatus},
         AdirsMeasurementOutputs, ElectricalBusType, ElectricalBuses, LgciuGearExtension,
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        SimulatorWriter, VariableIdentifier, Write,
+        SimulatorWriter, UpdateContext, VariableIdentifier, Write,
     },
 };
 use std::vec::Vec;
 use uom::si::{
     angle::degree,
     f64::{Angle, Length, Velocity},
+    length::foot,
     velocity::foot_per_minute,
 };
 
+pub mod gpws_modes;
 pub mod navigation_display;
 
 pub struct EnhancedGroundProximityWarningComputer {
@@ -37,6 +47,29 @@ pub struct EnhancedGroundProximityWarningComputer {
     navigation_displays: [NavigationDisplay; 2],
     gear_is_down: bool,
     terronnd_rendering_mode: u8,
+    // inputs of the basic GPWS modes
+    gpws_sys_off_id: VariableIdentifier,
+    gpws_sys_off: bool,
+    gpws_gs_off_id: VariableIdentifier,
+    gpws_gs_off: bool,
+    gpws_flap_off_id: VariableIdentifier,
+    gpws_flap_off: bool,
+    gpws_flaps3_id: VariableIdentifier,
+    gpws_flaps3: bool,
+    flaps_handle_index_id: VariableIdentifier,
+    flaps_handle_index: u8,
+    glideslope_is_valid_id: VariableIdentifier,
+    glideslope_is_valid: bool,
+    glideslope_deviation_id: VariableIdentifier,
+    glideslope_deviation: Angle,
+    radio_altitude_rate: RadioAltitudeRate,
+    is_in_takeoff_phase: bool,
+    was_in_landing_configuration: bool,
+    mode_1: ExcessiveDescentRate,
+    mode_2: ExcessiveClosureRate,
+    mode_3: AltitudeLossAfterTakeoff,
+    mode_4: UnsafeTerrainClearance,
+    mode_5: BelowGlideslope,
     // output variables of the EGPWC
     egpwc_destination_longitude_id: VariableIdentifier,
     egpwc_destination_latitude_id: VariableIdentifier,
@@ -47,9 +80,26 @@ pub struct EnhancedGroundProximityWarningComputer {
     egpwc_present_vertical_speed_id: VariableIdentifier,
     egpwc_gear_is_down_id: VariableIdentifier,
     egpwc_terronnd_rendering_mode: VariableIdentifier,
+    egpwc_sink_rate_id: VariableIdentifier,
+    egpwc_pull_up_id: VariableIdentifier,
+    egpwc_terrain_id: VariableIdentifier,
+    egpwc_dont_sink_id: VariableIdentifier,
+    egpwc_too_low_gear_id: VariableIdentifier,
+    egpwc_too_low_flaps_id: VariableIdentifier,
+    egpwc_too_low_terrain_id: VariableIdentifier,
+    egpwc_glideslope_id: VariableIdentifier,
+    egpwc_glideslope_hard_id: VariableIdentifier,
+    egpwc_gpws_warning_id: VariableIdentifier,
+    egpwc_gs_warning_id: VariableIdentifier,
 }
 
 impl EnhancedGroundProximityWarningComputer {
+    const TAKEOFF_PHASE_ENTRY_RADIO_ALTITUDE_FOOT: f64 = 30.;
+    const TAKEOFF_PHASE_EXIT_RADIO_ALTITUDE_FOOT: f64 = 1500.;
+    const GO_AROUND_DETECTION_RADIO_ALTITUDE_FOOT: f64 = 245.;
+    const FLAPS_3_HANDLE_INDEX: u8 = 3;
+    const FLAPS_FULL_HANDLE_INDEX: u8 = 4;
+
     pub fn new(
         context: &mut InitContext,
         powered_by: ElectricalBusType,
@@ -78,6 +128,29 @@ impl EnhancedGroundProximityWarningComputer {
             ],
             gear_is_down: true,
             terronnd_rendering_mode,
+            gpws_sys_off_id: context.get_identifier("GPWS_SYS_OFF".to_owned()),
+            gpws_sys_off: false,
+            gpws_gs_off_id: context.get_identifier("GPWS_GS_OFF".to_owned()),
+            gpws_gs_off: false,
+            gpws_flap_off_id: context.get_identifier("GPWS_FLAP_OFF".to_owned()),
+            gpws_flap_off: false,
+            gpws_flaps3_id: context.get_identifier("GPWS_FLAPS3".to_owned()),
+            gpws_flaps3: false,
+            flaps_handle_index_id: context.get_identifier("FLAPS_HANDLE_INDEX".to_owned()),
+            flaps_handle_index: 0,
+            glideslope_is_valid_id: context.get_identifier("RADIO_RECEIVER_GS_IS_VALID".to_owned()),
+            glideslope_is_valid: false,
+            glideslope_deviation_id: context
+                .get_identifier("RADIO_RECEIVER_GS_DEVIATION".to_owned()),
+            glideslope_deviation: Angle::default(),
+            radio_altitude_rate: RadioAltitudeRate::new(),
+            is_in_takeoff_phase: context.is_on_ground(),
+            was_in_landing_configuration: false,
+            mode_1: ExcessiveDescentRate::new(),
+            mode_2: ExcessiveClosureRate::new(),
+            mode_3: AltitudeLossAfterTakeoff::new(),
+            mode_4: UnsafeTerrainClearance::new(),
+            mode_5: BelowGlideslope::new(),
             egpwc_destination_longitude_id: context.get_identifier("EGPWC_DEST_LONG".to_owned()),
             egpwc_destination_latitude_id: context.get_identifier("EGPWC_DEST_LAT".to_owned()),
             egpwc_present_latitude_id: context.get_identifier("EGPWC_PRESENT_LAT".to_owned()),
@@ -89,6 +162,17 @@ impl EnhancedGroundProximityWarningComputer {
             egpwc_gear_is_down_id: context.get_identifier("EGPWC_GEAR_IS_DOWN".to_owned()),
             egpwc_terronnd_rendering_mode: context
                 .get_identifier("EGPWC_TERRONND_RENDERING_MODE".to_owned()),
+            egpwc_sink_rate_id: context.get_identifier("EGPWC_SINK_RATE".to_owned()),
+            egpwc_pull_up_id: context.get_identifier("EGPWC_PULL_UP".to_owned()),
+            egpwc_terrain_id: context.get_identifier("EGPWC_TERRAIN".to_owned()),
+            egpwc_dont_sink_id: context.get_identifier("EGPWC_DONT_SINK".to_owned()),
+            egpwc_too_low_gear_id: context.get_identifier("EGPWC_TOO_LOW_GEAR".to_owned()),
+            egpwc_too_low_flaps_id: context.get_identifier("EGPWC_TOO_LOW_FLAPS".to_owned()),
+            egpwc_too_low_terrain_id: context.get_identifier("EGPWC_TOO_LOW_TERRAIN".to_owned()),
+            egpwc_glideslope_id: context.get_identifier("EGPWC_GLIDESLOPE".to_owned()),
+            egpwc_glideslope_hard_id: context.get_identifier("EGPWC_GLIDESLOPE_HARD".to_owned()),
+            egpwc_gpws_warning_id: context.get_identifier("EGPWC_GPWS_WARNING".to_owned()),
+            egpwc_gs_warning_id: context.get_identifier("EGPWC_GS_WARNING".to_owned()),
         }
     }
 
@@ -105,10 +189,102 @@ impl EnhancedGroundProximityWarningComputer {
         self.vertical_speed = adirs_output.vertical_speed(1);
     }
 
+    fn flaps_in_landing_configuration(&self) -> bool {
+        if self.gpws_flaps3 {
+            self.flaps_handle_index == Self::FLAPS_3_HANDLE_INDEX
+        } else {
+            self.flaps_handle_index == Self::FLAPS_FULL_HANDLE_INDEX
+        }
+    }
+
+    /// The takeoff phase starts on ground, or when leaving the landing configuration at low height
+    /// during a go around. It ends when climbing through 1500ft radio altitude.
+    fn update_takeoff_phase(&mut self, radio_altitude: Length, landing_configuration: bool) {
+        let radio_altitude = radio_altitude.get::<foot>();
+
+        if radio_altitude < Self::TAKEOFF_PHASE_ENTRY_RADIO_ALTITUDE_FOOT {
+            self.is_in_takeoff_phase = true;
+        } else if radio_altitude > Self::TAKEOFF_PHASE_EXIT_RADIO_ALTITUDE_FOOT {
+            self.is_in_takeoff_phase = false;
+        } else if radio_altitude < Self::GO_AROUND_DETECTION_RADIO_ALTITUDE_FOOT
+            && self.was_in_landing_configuration
+            && !landing_configuration
+        {
+            self.is_in_takeoff_phase = true;
+        }
+
+        self.was_in_landing_configuration = landing_configuration;
+    }
+
+    fn update_gpws_modes(
+        &mut self,
+        context: &UpdateContext,
+        adirs_output: &impl AdirsMeasurementOutputs,
+        radio_altimeter: &impl RadioAltimeter,
+    ) {
+        let radio_altitude = radio_altimeter.radio_altitude().normal_value();
+        self.radio_altitude_rate.update(context, radio_altitude);
+
+        match radio_altitude {
+            Some(radio_altitude) if self.is_powered && !self.gpws_sys_off => {
+                let flaps_in_landing_configuration = self.flaps_in_landing_configuration();
+                self.update_takeoff_phase(
+                    radio_altitude,
+                    self.gear_is_down && flaps_in_landing_configuration,
+                );
+
+                let input = GpwsModesInput {
+                    radio_altitude,
+                    radio_altitude_rate: self.radio_altitude_rate.rate(),
+                    barometric_altitude: adirs_output.altitude(1).normal_value(),
+                    vertical_speed: adirs_output.vertical_speed(1).normal_value(),
+                    computed_airspeed: adirs_output.computed_airspeed(1).normal_value(),
+                    glideslope_deviation: if self.glideslope_is_valid {
+                        Some(self.glideslope_deviation)
+                    } else {
+                        None
+                    },
+                    gear_is_down: self.gear_is_down,
+                    flaps_in_landing_configuration,
+                    flap_mode_inhibited: self.gpws_flap_off,
+                    glideslope_mode_inhibited: self.gpws_gs_off,
+                    is_in_takeoff_phase: self.is_in_takeoff_phase,
+                };
+
+                self.mode_1.update(&input);
+                self.mode_2.update(context, &input);
+                self.mode_3.update(&input);
+                self.mode_4.update(&input);
+                self.mode_5.update(&input);
+            }
+            _ => {
+                self.mode_1.reset();
+                self.mode_2.reset();
+                self.mode_3.reset();
+                self.mode_4.reset();
+                self.mode_5.reset();
+            }
+        }
+    }
+
+    fn pull_up(&self) -> bool {
+        self.mode_1.alert() == ExcessiveDescentRateAlert::PullUp
+            || self.mode_2.alert() == ExcessiveClosureRateAlert::PullUp
+    }
+
+    fn gpws_warning(&self) -> bool {
+        self.mode_1.alert() != ExcessiveDescentRateAlert::None
+            || self.mode_2.alert() != ExcessiveClosureRateAlert::None
+            || self.mode_3.dont_sink()
+            || self.mode_4.alert() != UnsafeTerrainClearanceAlert::None
+    }
+
     pub fn update(
         &mut self,
+        context: &UpdateContext,
         adirs_output: &impl AdirsMeasurementOutputs,
         lgcius: &impl LgciuGearExtension,
+        radio_altimeter: &impl RadioAltimeter,
     ) {
         if !self.is_powered {
             self.destination_longitude =
@@ -128,6 +304,8 @@ impl EnhancedGroundProximityWarningComputer {
             self.gear_is_down = lgcius.main_down_and_locked();
         }
 
+        self.update_gpws_modes(context, adirs_output, radio_altimeter);
+
         self.navigation_displays.iter_mut().for_each(|display| {
             display.update(
                 self.is_powered,
@@ -157,6 +335,14 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             Angle::new::<degree>(destination_lat),
             SignStatus::from(destination_lat_ssm),
         );
+
+        self.gpws_sys_off = reader.read(&self.gpws_sys_off_id);
+        self.gpws_gs_off = reader.read(&self.gpws_gs_off_id);
+        self.gpws_flap_off = reader.read(&self.gpws_flap_off_id);
+        self.gpws_flaps3 = reader.read(&self.gpws_flaps3_id);
+        self.flaps_handle_index = reader.read(&self.flaps_handle_index_id);
+        self.glideslope_is_valid = reader.read(&self.glideslope_is_valid_id);
+        self.glideslope_deviation = reader.read(&self.glideslope_deviation_id);
     }
 
     fn write(&self, writer: &mut SimulatorWriter) {
@@ -182,6 +368,42 @@ impl SimulationElement for EnhancedGroundProximityWarningComputer {
             &self.egpwc_terronnd_rendering_mode,
             self.terronnd_rendering_mode,
         );
+
+        writer.write(
+            &self.egpwc_sink_rate_id,
+            self.mode_1.alert() == ExcessiveDescentRateAlert::SinkRate,
+        );
+        writer.write(&self.egpwc_pull_up_id, self.pull_up());
+        writer.write(
+            &self.egpwc_terrain_id,
+            self.mode_2.alert() == ExcessiveClosureRateAlert::Terrain,
+        );
+        writer.write(&self.egpwc_dont_sink_id, self.mode_3.dont_sink());
+        writer.write(
+            &self.egpwc_too_low_gear_id,
+            self.mode_4.alert() == UnsafeTerrainClearanceAlert::TooLowGear,
+        );
+        writer.write(
+            &self.egpwc_too_low_flaps_id,
+            self.mode_4.alert() == UnsafeTerrainClearanceAlert::TooLowFlaps,
+        );
+        writer.write(
+            &self.egpwc_too_low_terrain_id,
+            self.mode_4.alert() == UnsafeTerrainClearanceAlert::TooLowTerrain,
+        );
+        writer.write(
+            &self.egpwc_glideslope_id,
+            self.mode_5.alert() == BelowGlideslopeAlert::Soft,
+        );
+        writer.write(
+            &self.egpwc_glideslope_hard_id,
+            self.mode_5.alert() == BelowGlideslopeAlert::Hard,
+        );
+        writer.write(&self.egpwc_gpws_warning_id, self.gpws_warning());
+        writer.write(
+            &self.egpwc_gs_warning_id,
+            self.mode_5.alert() != BelowGlideslopeAlert::None,
+        );
     }
 
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
@@ -207,7 +429,7 @@ mod tests {
         electric_potential::volt,
         f64::*,
         length::{foot, nautical_mile},
-        velocity::foot_per_minute,
+        velocity::{foot_per_minute, knot},
     };
 
     struct TestAdirs {
@@ -217,6 +439,7 @@ mod tests {
         heading: Arinc429Word<Angle>,
         vertical_speed: Arinc429Word<Velocity>,
         altitude: Arinc429Word<Length>,
+        computed_airspeed: Arinc429Word<Velocity>,
     }
     impl TestAdirs {
         fn new() -> Self {
@@ -227,6 +450,10 @@ mod tests {
                 heading: Arinc429Word::new(Angle::default(), SignStatus::FailureWarning),
                 vertical_speed: Arinc429Word::new(Velocity::default(), SignStatus::FailureWarning),
                 altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+                computed_airspeed: Arinc429Word::new(
+                    Velocity::default(),
+                    SignStatus::FailureWarning,
+                ),
             }
         }
 
@@ -244,6 +471,21 @@ mod tests {
             );
             self.altitude =
                 Arinc429Word::new(Length::new::<foot>(15000.0), SignStatus::NormalOperation);
+            self.computed_airspeed =
+                Arinc429Word::new(Velocity::new::<knot>(250.), SignStatus::NormalOperation);
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.vertical_speed = Arinc429Word::new(vertical_speed, SignStatus::NormalOperation);
+        }
+
+        fn set_altitude(&mut self, altitude: Length) {
+            self.altitude = Arinc429Word::new(altitude, SignStatus::NormalOperation);
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.computed_airspeed =
+                Arinc429Word::new(computed_airspeed, SignStatus::NormalOperation);
         }
     }
     impl AdirsMeasurementOutputs for TestAdirs {
@@ -274,6 +516,34 @@ mod tests {
         fn altitude(&self, _adiru_number: usize) -> Arinc429Word<Length> {
             self.altitude
         }
+
+        fn computed_airspeed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            self.computed_airspeed
+        }
+
+        fn ground_speed(&self, _adiru_number: usize) -> Arinc429Word<Velocity> {
+            Arinc429Word::new(Velocity::default(), SignStatus::NoComputedData)
+        }
+    }
+
+    struct TestRadioAltimeter {
+        radio_altitude: Arinc429Word<Length>,
+    }
+    impl TestRadioAltimeter {
+        fn new() -> Self {
+            Self {
+                radio_altitude: Arinc429Word::new(Length::default(), SignStatus::FailureWarning),
+            }
+        }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altitude = Arinc429Word::new(radio_altitude, SignStatus::NormalOperation);
+        }
+    }
+    impl RadioAltimeter for TestRadioAltimeter {
+        fn radio_altitude(&self) -> Arinc429Word<Length> {
+            self.radio_altitude
+        }
     }
 
     struct TestLgciu {
@@ -318,6 +588,7 @@ mod tests {
     struct EgpwcTestAircraft {
         adirs: TestAdirs,
         lgciu: TestLgciu,
+        radio_altimeter: TestRadioAltimeter,
         egpwc: EnhancedGroundProximityWarningComputer,
         powered_source_dc: TestElectricitySource,
         dc_1_bus: ElectricalBus,
@@ -328,6 +599,7 @@ mod tests {
             Self {
                 adirs: TestAdirs::new(),
                 lgciu: TestLgciu::new(),
+                radio_altimeter: TestRadioAltimeter::new(),
                 egpwc: EnhancedGroundProximityWarningComputer::new(
                     context,
                     ElectricalBusType::DirectCurrent(1),
@@ -350,8 +622,9 @@ mod tests {
             }
         }
 
-        fn update(&mut self) {
-            self.egpwc.update(&self.adirs, &self.lgciu);
+        fn update(&mut self, context: &UpdateContext) {
+            self.egpwc
+                .update(context, &self.adirs, &self.lgciu, &self.radio_altimeter);
         }
 
         fn initialize_adiru(&mut self) {
@@ -365,6 +638,22 @@ mod tests {
         fn set_elec_powered(&mut self, is_powered: bool) {
             self.is_elec_powered = is_powered;
         }
+
+        fn set_radio_altitude(&mut self, radio_altitude: Length) {
+            self.radio_altimeter.set_radio_altitude(radio_altitude);
+        }
+
+        fn set_vertical_speed(&mut self, vertical_speed: Velocity) {
+            self.adirs.set_vertical_speed(vertical_speed);
+        }
+
+        fn set_altitude(&mut self, altitude: Length) {
+            self.adirs.set_altitude(altitude);
+        }
+
+        fn set_computed_airspeed(&mut self, computed_airspeed: Velocity) {
+            self.adirs.set_computed_airspeed(computed_airspeed);
+        }
     }
     impl Aircraft for EgpwcTestAircraft {
         fn update_before_power_distribution(
@@ -381,8 +670,8 @@ mod tests {
             }
         }
 
-        fn update_after_power_distribution(&mut self, _: &UpdateContext) {
-            self.update();
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.update(context);
         }
     }
     impl SimulationElement for EgpwcTestAircraft {
@@ -617,4 +906,172 @@ mod tests {
         let nd_terr_activate_fo: bool = test_bed.read_by_name("EGPWC_ND_R_TERRAIN_ACTIVE");
         assert!(!nd_terr_activate_fo);
     }
+
+    fn powered_test_bed_at_radio_altitude(
+        radio_altitude: Length,
+    ) -> SimulationTestBed<EgpwcTestAircraft> {
+        let mut test_bed = SimulationTestBed::new(EgpwcTestAircraft::new);
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 4);
+
+        test_bed.command(|a| a.set_elec_powered(true));
+        test_bed.command(|a| a.gear_down());
+        test_bed.command(|a| a.initialize_adiru());
+        test_bed.command(|a| a.set_radio_altitude(radio_altitude));
+
+        test_bed
+    }
+
+    #[test]
+    fn no_gpws_alert_in_normal_approach() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(800.));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-700.)));
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(140.)));
+        test_bed.run();
+
+        let gpws_warning: bool = test_bed.read_by_name("EGPWC_GPWS_WARNING");
+        assert!(!gpws_warning);
+        let gs_warning: bool = test_bed.read_by_name("EGPWC_GS_WARNING");
+        assert!(!gs_warning);
+    }
+
+    #[test]
+    fn sink_rate_alert_with_excessive_descent_rate() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(1500.));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3500.)));
+        test_bed.run();
+
+        let sink_rate: bool = test_bed.read_by_name("EGPWC_SINK_RATE");
+        assert!(sink_rate);
+        let pull_up: bool = test_bed.read_by_name("EGPWC_PULL_UP");
+        assert!(!pull_up);
+        let gpws_warning: bool = test_bed.read_by_name("EGPWC_GPWS_WARNING");
+        assert!(gpws_warning);
+    }
+
+    #[test]
+    fn pull_up_alert_with_excessive_descent_rate_close_to_terrain() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(900.));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3500.)));
+        test_bed.run();
+
+        let pull_up: bool = test_bed.read_by_name("EGPWC_PULL_UP");
+        assert!(pull_up);
+    }
+
+    #[test]
+    fn no_gpws_alert_when_system_off() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(900.));
+        test_bed.write_by_name("GPWS_SYS_OFF", true);
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3500.)));
+        test_bed.run();
+
+        let pull_up: bool = test_bed.read_by_name("EGPWC_PULL_UP");
+        assert!(!pull_up);
+        let gpws_warning: bool = test_bed.read_by_name("EGPWC_GPWS_WARNING");
+        assert!(!gpws_warning);
+    }
+
+    #[test]
+    fn no_gpws_alert_when_unpowered() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(900.));
+        test_bed.command(|a| a.set_elec_powered(false));
+        test_bed.command(|a| a.set_vertical_speed(Velocity::new::<foot_per_minute>(-3500.)));
+        test_bed.run();
+
+        let pull_up: bool = test_bed.read_by_name("EGPWC_PULL_UP");
+        assert!(!pull_up);
+    }
+
+    #[test]
+    fn too_low_flaps_alert_when_not_in_landing_configuration() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(200.));
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 2);
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(140.)));
+
+        // Leave the takeoff phase first
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(2000.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let too_low_flaps: bool = test_bed.read_by_name("EGPWC_TOO_LOW_FLAPS");
+        assert!(too_low_flaps);
+    }
+
+    #[test]
+    fn too_low_flaps_alert_is_inhibited_by_flap_mode_off() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(2000.));
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 2);
+        test_bed.write_by_name("GPWS_FLAP_OFF", true);
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(140.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let too_low_flaps: bool = test_bed.read_by_name("EGPWC_TOO_LOW_FLAPS");
+        assert!(!too_low_flaps);
+    }
+
+    #[test]
+    fn flaps_3_is_landing_configuration_with_flaps_3_selected() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(2000.));
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 3);
+        test_bed.write_by_name("GPWS_FLAPS3", true);
+        test_bed.command(|a| a.set_computed_airspeed(Velocity::new::<knot>(140.)));
+        test_bed.run();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(200.)));
+        test_bed.run();
+
+        let too_low_flaps: bool = test_bed.read_by_name("EGPWC_TOO_LOW_FLAPS");
+        assert!(!too_low_flaps);
+    }
+
+    #[test]
+    fn dont_sink_alert_when_losing_altitude_after_takeoff() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(0.));
+        test_bed.write_by_name("FLAPS_HANDLE_INDEX", 1);
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(100.)));
+        test_bed.run();
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(500.)));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(600.)));
+        test_bed.run();
+
+        let dont_sink: bool = test_bed.read_by_name("EGPWC_DONT_SINK");
+        assert!(!dont_sink);
+
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(430.)));
+        test_bed.command(|a| a.set_altitude(Length::new::<foot>(530.)));
+        test_bed.run();
+
+        let dont_sink: bool = test_bed.read_by_name("EGPWC_DONT_SINK");
+        assert!(dont_sink);
+    }
+
+    #[test]
+    fn glideslope_alert_when_below_glideslope() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(2000.));
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.6);
+        test_bed.run();
+        test_bed.command(|a| a.set_radio_altitude(Length::new::<foot>(800.)));
+        test_bed.run();
+
+        let glideslope: bool = test_bed.read_by_name("EGPWC_GLIDESLOPE");
+        assert!(glideslope);
+        let gs_warning: bool = test_bed.read_by_name("EGPWC_GS_WARNING");
+        assert!(gs_warning);
+    }
+
+    #[test]
+    fn no_glideslope_alert_when_glideslope_mode_off() {
+        let mut test_bed = powered_test_bed_at_radio_altitude(Length::new::<foot>(800.));
+        test_bed.write_by_name("RADIO_RECEIVER_GS_IS_VALID", true);
+        test_bed.write_by_name("RADIO_RECEIVER_GS_DEVIATION", -0.6);
+        test_bed.write_by_name("GPWS_GS_OFF", true);
+        test_bed.run();
+
+        let gs_warning: bool = test_bed.read_by_name("EGPWC_GS_WARNING");
+        assert!(!gs_warning);
+    }
 }
@@ -1,15 +1,21 @@
 use crate::shared::{
-    AirbusElectricPumpId, AirbusEngineDrivenPumpId, GearActuatorId, HydraulicColor, LgciuId,
-    ProximityDetectorId,
+    AirbusElectricPumpId, AirbusEngineDrivenPumpId, GearActuatorId, HeatedElement, HydraulicColor,
+    LgciuId, ProximityDetectorId,
 };
-use crate::simulation::SimulationElement;
+use crate::simulation::{SimulationElement, SnapshotReader, SnapshotWriter};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum FailureType {
     TransformerRectifier(usize),
+    BatteryThermalRunaway(usize),
+    ExternalPowerOverVoltage(usize),
+    ExternalPowerUnderVoltage(usize),
+    ExternalPowerWrongPhaseRotation(usize),
+    ExternalPowerFrequencyDeviation(usize),
     ReservoirLeak(HydraulicColor),
     ReservoirAirLeak(HydraulicColor),
     ReservoirReturnLeak(HydraulicColor),
+    HydraulicFluidContamination(HydraulicColor),
     EnginePumpOverheat(AirbusEngineDrivenPumpId),
     ElecPumpOverheat(AirbusElectricPumpId),
     LgciuPowerSupply(LgciuId),
@@ -18,7 +24,18 @@ pub enum FailureType {
     GearActuatorJammed(GearActuatorId),
     BrakeHydraulicLeak(HydraulicColor),
     BrakeAccumulatorGasLeak,
+    TyreBurst(usize),
+    TyreDeflation(usize),
+    BrakeSeizure(usize),
+    BodyWheelSteering,
+    PtuShaftShear,
+    PtuInternalLeakage,
+    PtuJam,
+    PtuBarkThresholdDrift,
     RadioAltimeter(usize),
+    ProbeHeat(usize, HeatedElement),
+    EngineOilLeak(usize),
+    EngineOilPumpFailure(usize),
 }
 
 pub struct Failure {
@@ -43,6 +60,14 @@ impl SimulationElement for Failure {
             self.is_active = is_active;
         }
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_active);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_active = snapshot.read();
+    }
 }
 
 #[cfg(test)]
@@ -76,4 +101,19 @@ mod tests {
 
         assert!(test_bed.query_element(|el| !el.is_active()));
     }
+
+    #[test]
+    fn restoring_a_snapshot_restores_the_active_failure() {
+        let mut test_bed =
+            SimulationTestBed::from(Failure::new(FailureType::TransformerRectifier(1)));
+        test_bed.fail(FailureType::TransformerRectifier(1));
+        test_bed.run();
+        let snapshot = test_bed.snapshot();
+
+        test_bed.unfail(FailureType::TransformerRectifier(1));
+        test_bed.run();
+        test_bed.restore(&snapshot).unwrap();
+
+        assert!(test_bed.query_element(|el| el.is_active()));
+    }
 }
@@ -0,0 +1,369 @@
+use crate::{
+    shared::{ConsumePower, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter,
+        UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use uom::si::{f64::*, mass::kilogram, power::watt};
+
+/// A fuel tank holding a quantity of fuel up to its capacity.
+pub struct FuelTank {
+    quantity_id: VariableIdentifier,
+
+    capacity: Mass,
+    quantity: Mass,
+}
+impl FuelTank {
+    pub fn new(context: &mut InitContext, name: &str, capacity: Mass) -> Self {
+        Self {
+            quantity_id: context.get_identifier(format!("FUEL_{}_QUANTITY", name)),
+            capacity,
+            quantity: Mass::new::<kilogram>(0.),
+        }
+    }
+
+    pub fn quantity(&self) -> Mass {
+        self.quantity
+    }
+
+    pub fn capacity(&self) -> Mass {
+        self.capacity
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.quantity <= Mass::new::<kilogram>(0.)
+    }
+
+    pub fn set_quantity(&mut self, quantity: Mass) {
+        self.quantity = quantity.max(Mass::new::<kilogram>(0.)).min(self.capacity);
+    }
+
+    /// Removes up to the requested mass from the tank.
+    /// Returns the mass which was actually removed.
+    pub fn take(&mut self, requested: Mass) -> Mass {
+        let taken = requested.max(Mass::new::<kilogram>(0.)).min(self.quantity);
+        self.quantity -= taken;
+
+        taken
+    }
+
+    /// Adds up to the given mass to the tank.
+    /// Returns the mass which didn't fit in the tank.
+    pub fn fill(&mut self, mass: Mass) -> Mass {
+        let added = mass
+            .max(Mass::new::<kilogram>(0.))
+            .min(self.capacity - self.quantity);
+        self.quantity += added;
+
+        mass - added
+    }
+}
+impl SimulationElement for FuelTank {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.quantity_id, self.quantity);
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.quantity);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        let quantity = snapshot.read();
+        self.set_quantity(quantity);
+    }
+}
+
+/// An electrically driven fuel pump. The pump only runs when it is commanded on
+/// and its bus is powered. A running pump only consumes power when it has been given a
+/// power demand, such that aircraft which still account for their pumps in an aggregated
+/// consumer don't count them twice.
+pub struct FuelPump {
+    is_running_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_commanded_on: bool,
+    power_demand: Power,
+}
+impl FuelPump {
+    pub fn new(context: &mut InitContext, name: &str, powered_by: ElectricalBusType) -> Self {
+        Self {
+            is_running_id: context.get_identifier(format!("FUEL_{}_PUMP_IS_RUNNING", name)),
+            powered_by,
+            is_powered: false,
+            is_commanded_on: false,
+            power_demand: Power::new::<watt>(0.),
+        }
+    }
+
+    pub fn with_power_demand(mut self, power_demand: Power) -> Self {
+        self.power_demand = power_demand;
+        self
+    }
+
+    pub fn update(&mut self, should_run: bool) {
+        self.is_commanded_on = should_run;
+    }
+
+    pub fn is_commanded_on(&self) -> bool {
+        self.is_commanded_on
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_commanded_on && self.is_powered
+    }
+}
+impl SimulationElement for FuelPump {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_running_id, self.is_running());
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn consume_power<T: ConsumePower>(&mut self, _: &UpdateContext, consumption: &mut T) {
+        if self.is_running() {
+            consumption.consume_from_bus(self.powered_by, self.power_demand);
+        }
+    }
+}
+
+/// A motor operated fuel valve. The valve only moves when its motor is powered,
+/// otherwise it remains in its last position.
+pub struct FuelValve {
+    is_open_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    is_open: bool,
+}
+impl FuelValve {
+    pub fn new(
+        context: &mut InitContext,
+        name: &str,
+        powered_by: ElectricalBusType,
+        is_open: bool,
+    ) -> Self {
+        Self {
+            is_open_id: context.get_identifier(format!("FUEL_{}_VALVE_IS_OPEN", name)),
+            powered_by,
+            is_powered: false,
+            is_open,
+        }
+    }
+
+    pub fn update(&mut self, should_open: bool) {
+        if self.is_powered {
+            self.is_open = should_open;
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+}
+impl SimulationElement for FuelValve {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_open_id, self.is_open);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_open);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_open = snapshot.read();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed},
+            Aircraft, SimulationElementVisitor, UpdateContext,
+        },
+    };
+    use uom::si::{electric_potential::volt, mass::pound};
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_1_bus: ElectricalBus,
+        is_dc_1_powered: bool,
+
+        tank: FuelTank,
+        pump: FuelPump,
+        valve: FuelValve,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_1_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                is_dc_1_powered: true,
+                tank: FuelTank::new(context, "TEST", Mass::new::<kilogram>(1000.)),
+                pump: FuelPump::new(context, "TEST", ElectricalBusType::DirectCurrent(1)),
+                valve: FuelValve::new(context, "TEST", ElectricalBusType::DirectCurrent(1), false),
+            }
+        }
+
+        fn unpower_dc_1_bus(&mut self) {
+            self.is_dc_1_powered = false;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(28.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_1_powered {
+                electricity.flow(&self.electricity_source, &self.dc_1_bus);
+            }
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.tank.accept(visitor);
+            self.pump.accept(visitor);
+            self.valve.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        SimulationTestBed::new(TestAircraft::new)
+    }
+
+    #[test]
+    fn tank_quantity_is_limited_by_capacity() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.tank.set_quantity(Mass::new::<kilogram>(1200.)));
+
+        assert_eq!(
+            test_bed.query(|a| a.tank.quantity()),
+            Mass::new::<kilogram>(1000.)
+        );
+    }
+
+    #[test]
+    fn tank_take_never_removes_more_than_available() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.tank.set_quantity(Mass::new::<kilogram>(100.)));
+        let mut taken = Mass::new::<kilogram>(0.);
+        test_bed.command(|a| taken = a.tank.take(Mass::new::<kilogram>(150.)));
+
+        assert_eq!(taken, Mass::new::<kilogram>(100.));
+        assert!(test_bed.query(|a| a.tank.is_empty()));
+    }
+
+    #[test]
+    fn tank_fill_returns_what_does_not_fit() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.tank.set_quantity(Mass::new::<kilogram>(900.)));
+        let mut remainder = Mass::new::<kilogram>(0.);
+        test_bed.command(|a| remainder = a.tank.fill(Mass::new::<kilogram>(150.)));
+
+        assert_eq!(remainder, Mass::new::<kilogram>(50.));
+        assert_eq!(
+            test_bed.query(|a| a.tank.quantity()),
+            Mass::new::<kilogram>(1000.)
+        );
+    }
+
+    #[test]
+    fn tank_writes_its_quantity() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.tank.set_quantity(Mass::new::<pound>(500.)));
+        test_bed.run();
+
+        let quantity: Mass = test_bed.read_by_name("FUEL_TEST_QUANTITY");
+        assert!((quantity.get::<pound>() - 500.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn pump_runs_when_commanded_on_and_powered() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.pump.update(true));
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.pump.is_running()));
+        let is_running: bool = test_bed.read_by_name("FUEL_TEST_PUMP_IS_RUNNING");
+        assert!(is_running);
+    }
+
+    #[test]
+    fn pump_does_not_run_when_unpowered() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.unpower_dc_1_bus());
+        test_bed.command(|a| a.pump.update(true));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.pump.is_running()));
+    }
+
+    #[test]
+    fn valve_moves_when_powered() {
+        let mut test_bed = test_bed();
+
+        test_bed.run();
+        test_bed.command(|a| a.valve.update(true));
+
+        assert!(test_bed.query(|a| a.valve.is_open()));
+    }
+
+    #[test]
+    fn valve_stays_in_position_when_unpowered() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.unpower_dc_1_bus());
+        test_bed.run();
+        test_bed.command(|a| a.valve.update(true));
+
+        assert!(!test_bed.query(|a| a.valve.is_open()));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_restores_the_tank_quantity_and_valve_position() {
+        let mut test_bed = test_bed();
+
+        test_bed.command(|a| a.tank.set_quantity(Mass::new::<kilogram>(600.)));
+        test_bed.run();
+        test_bed.command(|a| a.valve.update(true));
+        let snapshot = test_bed.snapshot();
+
+        test_bed.command(|a| a.tank.set_quantity(Mass::new::<kilogram>(100.)));
+        test_bed.command(|a| a.valve.update(false));
+        test_bed.restore(&snapshot).unwrap();
+
+        assert_eq!(
+            test_bed.query(|a| a.tank.quantity()),
+            Mass::new::<kilogram>(600.)
+        );
+        assert!(test_bed.query(|a| a.valve.is_open()));
+    }
+}
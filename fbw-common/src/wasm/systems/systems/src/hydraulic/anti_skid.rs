@@ -0,0 +1,403 @@
+use crate::{
+    landing_gear::wheel::MainGearWheels,
+    simulation::{SnapshotReader, SnapshotWriter, UpdateContext},
+};
+
+use uom::si::{f64::*, ratio::ratio, velocity::knot};
+
+/// Anti skid channel of a single braked wheel.
+///
+/// Releases the brake pressure of its wheel when the wheel speed drops too far below the aircraft
+/// reference speed, and progressively reapplies it once the wheel has spun back up.
+struct AntiSkidChannel {
+    is_releasing: bool,
+    demand_ratio: f64,
+}
+impl AntiSkidChannel {
+    const RELEASE_SLIP_THRESHOLD: f64 = 0.15;
+    const REAPPLY_SLIP_THRESHOLD: f64 = 0.05;
+
+    // Share of the brake demand removed or given back per second
+    const RELEASE_RATE_PER_SECOND: f64 = 50.;
+    const REAPPLY_RATE_PER_SECOND: f64 = 5.;
+
+    fn new() -> Self {
+        Self {
+            is_releasing: false,
+            demand_ratio: 1.,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        reference_speed: Velocity,
+        wheel_speed: Velocity,
+    ) {
+        let slip = 1. - (wheel_speed / reference_speed).get::<ratio>();
+
+        if slip > Self::RELEASE_SLIP_THRESHOLD {
+            self.is_releasing = true;
+        } else if slip < Self::REAPPLY_SLIP_THRESHOLD {
+            self.is_releasing = false;
+        }
+
+        self.demand_ratio = if self.is_releasing {
+            self.demand_ratio - Self::RELEASE_RATE_PER_SECOND * context.delta_as_secs_f64()
+        } else {
+            self.demand_ratio + Self::REAPPLY_RATE_PER_SECOND * context.delta_as_secs_f64()
+        }
+        .max(0.)
+        .min(1.);
+    }
+
+    fn reset(&mut self) {
+        self.is_releasing = false;
+        self.demand_ratio = 1.;
+    }
+
+    fn demand_ratio(&self) -> f64 {
+        self.demand_ratio
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_releasing);
+        snapshot.write(self.demand_ratio);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_releasing = snapshot.read();
+        self.demand_ratio = snapshot.read();
+    }
+}
+
+/// Per wheel anti skid of the brake and steering control unit.
+///
+/// Each wheel speed is compared to the aircraft reference speed, given by the ADIRS ground speed, or by
+/// the fastest wheel when no valid ground speed is available. Wheels are numbered as their brakes: the
+/// first half is on the left main gears, the second half on the right ones.
+/// Brake demands are given per side, so the pressure demand of a side is reduced by the share of its
+/// wheels currently released.
+///
+/// Anti skid must be updated at the hydraulic simulation time step, together with the brakes and wheels
+/// it regulates, so that it reacts to a slipping wheel before it locks whatever the frame rate.
+pub struct AntiSkid<const N: usize> {
+    channels: [AntiSkidChannel; N],
+    is_active: bool,
+}
+impl<const N: usize> AntiSkid<N> {
+    // Below this speed wheel speed can't be measured accurately and anti skid is inhibited
+    const MIN_REFERENCE_SPEED_KNOT: f64 = 20.;
+
+    pub fn new() -> Self {
+        Self {
+            channels: std::array::from_fn(|_| AntiSkidChannel::new()),
+            is_active: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_active: bool,
+        reference_speed: Option<Velocity>,
+        wheels: &MainGearWheels<N>,
+    ) {
+        let reference_speed = reference_speed.unwrap_or_else(|| {
+            (1..=N)
+                .map(|number| wheels.wheel(number).wheel_speed())
+                .fold(Velocity::new::<knot>(0.), |fastest, speed| {
+                    fastest.max(speed)
+                })
+        });
+
+        self.is_active =
+            is_active && reference_speed.get::<knot>() > Self::MIN_REFERENCE_SPEED_KNOT;
+
+        for (index, channel) in self.channels.iter_mut().enumerate() {
+            if self.is_active {
+                channel.update(
+                    context,
+                    reference_speed,
+                    wheels.wheel(index + 1).wheel_speed(),
+                );
+            } else {
+                channel.reset();
+            }
+        }
+    }
+
+    /// Ratio of the pilot or autobrake demand to apply on the left brakes
+    pub fn left_demand_ratio(&self) -> f64 {
+        Self::mean_demand_ratio(&self.channels[..N / 2])
+    }
+
+    /// Ratio of the pilot or autobrake demand to apply on the right brakes
+    pub fn right_demand_ratio(&self) -> f64 {
+        Self::mean_demand_ratio(&self.channels[N / 2..])
+    }
+
+    fn mean_demand_ratio(channels: &[AntiSkidChannel]) -> f64 {
+        channels
+            .iter()
+            .map(|channel| channel.demand_ratio())
+            .sum::<f64>()
+            / channels.len() as f64
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn is_releasing(&self, wheel_number: usize) -> bool {
+        self.channels[wheel_number - 1].is_releasing
+    }
+
+    pub fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_active);
+        self.channels
+            .iter()
+            .for_each(|channel| channel.snapshot(snapshot));
+    }
+
+    pub fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_active = snapshot.read();
+        self.channels
+            .iter_mut()
+            .for_each(|channel| channel.restore(snapshot));
+    }
+}
+impl<const N: usize> Default for AntiSkid<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::FailureType,
+        landing_gear::{brake_heat::BrakeTemperatureMonitoring, wheel::SurfaceCondition},
+        simulation::{
+            test::{SimulationTestBed, TestBed, WriteByName},
+            Aircraft, InitContext, SimulationElement, SimulationElementVisitor, UpdateContext,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::{
+        force::newton, mass::kilogram, pressure::psi, thermodynamic_temperature::degree_celsius,
+        velocity::foot_per_second,
+    };
+
+    struct TestAircraft {
+        brakes: BrakeTemperatureMonitoring<4>,
+        wheels: MainGearWheels<4>,
+        anti_skid: AntiSkid<4>,
+
+        is_anti_skid_active: bool,
+        reference_speed: Option<Velocity>,
+        braking_demand: f64,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                brakes: BrakeTemperatureMonitoring::new(
+                    context,
+                    Mass::new::<kilogram>(70.),
+                    Force::new::<newton>(80000.),
+                ),
+                wheels: MainGearWheels::new(context, Pressure::new::<psi>(200.)),
+                anti_skid: AntiSkid::new(),
+
+                is_anti_skid_active: true,
+                reference_speed: None,
+                braking_demand: 0.,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.anti_skid.update(
+                context,
+                self.is_anti_skid_active,
+                self.reference_speed,
+                &self.wheels,
+            );
+
+            let left_force =
+                Ratio::new::<ratio>(self.braking_demand * self.anti_skid.left_demand_ratio());
+            let right_force =
+                Ratio::new::<ratio>(self.braking_demand * self.anti_skid.right_demand_ratio());
+
+            self.brakes
+                .update(context, left_force, right_force, true, false);
+            self.wheels
+                .update(context, &self.brakes, left_force, right_force);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.brakes.accept(visitor);
+            self.wheels.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct AntiSkidTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl AntiSkidTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.set_ground_speed(Velocity::new::<knot>(100.));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            test_bed
+        }
+
+        fn set_ground_speed(&mut self, speed: Velocity) {
+            self.write_by_name("VELOCITY BODY Z", speed.get::<foot_per_second>());
+            self.set_reference_speed(Some(speed));
+        }
+
+        fn set_reference_speed(&mut self, speed: Option<Velocity>) {
+            self.command(|a| a.reference_speed = speed);
+        }
+
+        fn set_surface_condition(&mut self, surface_condition: SurfaceCondition) {
+            self.write_by_name("SURFACE CONDITION", surface_condition);
+        }
+
+        fn set_anti_skid_active(&mut self, is_active: bool) {
+            self.command(|a| a.is_anti_skid_active = is_active);
+        }
+
+        fn set_braking_demand(&mut self, demand: f64) {
+            self.command(|a| a.braking_demand = demand);
+        }
+
+        /// Runs the given duration in small steps, returning how many steps had at least one wheel released
+        fn run_for(&mut self, duration: Duration) -> usize {
+            let step = Duration::from_millis(10);
+            let mut released_steps = 0;
+
+            for _ in 0..(duration.as_millis() / step.as_millis()) {
+                self.run_with_delta(step);
+
+                if (1..=4).any(|number| self.is_releasing(number)) {
+                    released_steps += 1;
+                }
+            }
+
+            released_steps
+        }
+
+        fn is_releasing(&self, number: usize) -> bool {
+            self.query(|a| a.anti_skid.is_releasing(number))
+        }
+
+        fn is_locked(&self, number: usize) -> bool {
+            self.query(|a| a.wheels.wheel(number).is_locked())
+        }
+
+        fn left_demand_ratio(&self) -> f64 {
+            self.query(|a| a.anti_skid.left_demand_ratio())
+        }
+    }
+    impl TestBed for AntiSkidTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn no_release_when_braking_on_dry_runway() {
+        let mut test_bed = AntiSkidTestBed::new();
+
+        test_bed.set_braking_demand(1.);
+
+        assert_eq!(test_bed.run_for(Duration::from_secs(2)), 0);
+        assert!((test_bed.left_demand_ratio() - 1.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn anti_skid_cycles_brakes_on_icy_runway() {
+        let mut test_bed = AntiSkidTestBed::new();
+
+        test_bed.set_surface_condition(SurfaceCondition::Icy);
+        test_bed.set_braking_demand(1.);
+        test_bed.run_for(Duration::from_millis(500));
+
+        let released_steps = test_bed.run_for(Duration::from_secs(2));
+        assert!(released_steps > 0);
+        assert!(released_steps < 200);
+        assert!((1..=4).all(|number| !test_bed.is_locked(number)));
+    }
+
+    #[test]
+    fn wheels_lock_on_icy_runway_without_anti_skid() {
+        let mut test_bed = AntiSkidTestBed::new();
+
+        test_bed.set_anti_skid_active(false);
+        test_bed.set_surface_condition(SurfaceCondition::Icy);
+        test_bed.set_braking_demand(1.);
+
+        assert_eq!(test_bed.run_for(Duration::from_secs(2)), 0);
+        assert!((1..=4).all(|number| test_bed.is_locked(number)));
+    }
+
+    #[test]
+    fn anti_skid_inhibited_at_low_speed() {
+        let mut test_bed = AntiSkidTestBed::new();
+
+        test_bed.set_ground_speed(Velocity::new::<knot>(15.));
+        test_bed.set_surface_condition(SurfaceCondition::Icy);
+        test_bed.set_braking_demand(1.);
+
+        assert_eq!(test_bed.run_for(Duration::from_secs(1)), 0);
+        assert!(!test_bed.query(|a| a.anti_skid.is_active()));
+    }
+
+    #[test]
+    fn fastest_wheel_is_used_as_reference_without_ground_speed() {
+        let mut test_bed = AntiSkidTestBed::new();
+
+        test_bed.set_reference_speed(None);
+        test_bed.fail(FailureType::BrakeSeizure(2));
+        test_bed.run_for(Duration::from_millis(100));
+
+        assert!(test_bed.is_releasing(2));
+        assert!(!test_bed.is_releasing(1));
+        assert!((test_bed.left_demand_ratio() - 0.5).abs() < f64::EPSILON);
+        assert!(!test_bed.is_releasing(3));
+    }
+
+    #[test]
+    fn released_brake_is_progressively_reapplied() {
+        let mut test_bed = AntiSkidTestBed::new();
+
+        test_bed.set_reference_speed(None);
+        test_bed.fail(FailureType::BrakeSeizure(2));
+        test_bed.run_for(Duration::from_millis(100));
+        assert!(test_bed.is_releasing(2));
+
+        test_bed.unfail(FailureType::BrakeSeizure(2));
+        test_bed.run_for(Duration::from_millis(250));
+
+        assert!(!test_bed.is_releasing(2));
+        assert!(test_bed.left_demand_ratio() > 0.5);
+        assert!(test_bed.left_demand_ratio() < 1.);
+    }
+}
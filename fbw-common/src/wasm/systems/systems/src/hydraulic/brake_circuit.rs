@@ -5,8 +5,8 @@ use crate::{
         pid::PidController, random_from_normal_distribution, random_from_range, HydraulicColor,
     },
     simulation::{
-        SimulationElement, SimulationElementVisitor, SimulatorWriter, StartState, UpdateContext,
-        Write,
+        SimulationElement, SimulationElementVisitor, SimulatorWriter, SnapshotReader,
+        SnapshotWriter, StartState, UpdateContext, Write,
     },
 };
 
@@ -418,6 +418,338 @@ impl SimulationElement for BrakeCircuit {
             writer.write(&self.acc_press_id, self.accumulator_pressure());
         }
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.left_brake_actuator.current_position);
+        snapshot.write(self.right_brake_actuator.current_position);
+        snapshot.write(self.accumulator_fluid_pressure_sensor_filter.output());
+
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.snapshot(snapshot);
+        }
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.left_brake_actuator.current_position = snapshot.read();
+        self.right_brake_actuator.current_position = snapshot.read();
+        self.accumulator_fluid_pressure_sensor_filter
+            .reset(snapshot.read());
+
+        if let Some(accumulator) = &mut self.accumulator {
+            accumulator.restore(snapshot);
+        }
+    }
+}
+
+/// Braking mode selected by the brake and steering control unit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BrakeMode {
+    Normal = 0,
+    AlternateWithAntiSkid = 1,
+    AlternateWithoutAntiSkid = 2,
+    Parking = 3,
+    Emergency = 4,
+}
+impl From<f64> for BrakeMode {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            0 => BrakeMode::Normal,
+            1 => BrakeMode::AlternateWithAntiSkid,
+            2 => BrakeMode::AlternateWithoutAntiSkid,
+            3 => BrakeMode::Parking,
+            _ => BrakeMode::Emergency,
+        }
+    }
+}
+
+/// State machine selecting which brake circuit is used and how much pressure it is allowed to deliver.
+///
+/// Normal braking is used as long as the normal supply is pressurised and anti skid is available.
+/// Alternate braking keeps anti skid only while the alternate supply itself is pressurised: braking on
+/// accumulator pressure only is done without anti skid. Parking mode is the parking brake. Emergency mode is
+/// only used on aircraft fitted with local brake accumulators, braking from them when both normal and
+/// alternate supplies are lost.
+///
+/// The brake pressure triple indicator isn't driven by the selected mode: it keeps showing the alternate
+/// circuit and accumulator pressures as published by the alternate brake circuit.
+pub struct BrakeModeSelector {
+    mode_id: VariableIdentifier,
+
+    mode: BrakeMode,
+    normal_brakes_available: bool,
+
+    has_emergency_accumulators: bool,
+
+    normal_pressure_limit: Pressure,
+    alternate_pressure_limit: Pressure,
+    alternate_without_anti_skid_pressure_limit: Pressure,
+    parking_pressure_limit: Pressure,
+}
+impl BrakeModeSelector {
+    // Minimum pressure hysteresis on normal supply until switched on ALTN brakes
+    // Feedback by Cpt. Chaos — 25/04/2021 #pilot-feedback
+    const MIN_PRESSURE_BRAKE_ALTN_HYST_LO_PSI: f64 = 1305.;
+    const MIN_PRESSURE_BRAKE_ALTN_HYST_HI_PSI: f64 = 2176.;
+
+    // Pilot needs to release pedals before normal brakes can be recovered
+    const PILOT_INPUT_DETECTION_TRESHOLD: f64 = 0.2;
+
+    pub fn new(
+        context: &mut InitContext,
+        has_emergency_accumulators: bool,
+        normal_pressure_limit: Pressure,
+        alternate_pressure_limit: Pressure,
+        alternate_without_anti_skid_pressure_limit: Pressure,
+        parking_pressure_limit: Pressure,
+    ) -> Self {
+        Self {
+            mode_id: context.get_identifier("HYD_BRAKE_MODE".to_owned()),
+
+            mode: BrakeMode::Parking,
+            normal_brakes_available: false,
+
+            has_emergency_accumulators,
+
+            normal_pressure_limit,
+            alternate_pressure_limit,
+            alternate_without_anti_skid_pressure_limit,
+            parking_pressure_limit,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        normal_section: &impl SectionPressure,
+        alternate_section: &impl SectionPressure,
+        anti_skid_activated: bool,
+        parking_brake_demand: bool,
+        left_brake_pilot_input: Ratio,
+        right_brake_pilot_input: Ratio,
+    ) {
+        self.update_normal_braking_availability(
+            normal_section.pressure(),
+            left_brake_pilot_input,
+            right_brake_pilot_input,
+        );
+
+        let alternate_supply_available = alternate_section.is_pressure_switch_pressurised();
+
+        self.mode = if parking_brake_demand {
+            BrakeMode::Parking
+        } else if self.normal_brakes_available && anti_skid_activated {
+            BrakeMode::Normal
+        } else if alternate_supply_available && anti_skid_activated {
+            BrakeMode::AlternateWithAntiSkid
+        } else if !alternate_supply_available && self.has_emergency_accumulators {
+            BrakeMode::Emergency
+        } else {
+            BrakeMode::AlternateWithoutAntiSkid
+        };
+    }
+
+    fn update_normal_braking_availability(
+        &mut self,
+        normal_braking_circuit_pressure: Pressure,
+        left_brake_pilot_input: Ratio,
+        right_brake_pilot_input: Ratio,
+    ) {
+        if normal_braking_circuit_pressure.get::<psi>() > Self::MIN_PRESSURE_BRAKE_ALTN_HYST_HI_PSI
+            && (left_brake_pilot_input.get::<ratio>() < Self::PILOT_INPUT_DETECTION_TRESHOLD
+                && right_brake_pilot_input.get::<ratio>() < Self::PILOT_INPUT_DETECTION_TRESHOLD)
+        {
+            self.normal_brakes_available = true;
+        } else if normal_braking_circuit_pressure.get::<psi>()
+            < Self::MIN_PRESSURE_BRAKE_ALTN_HYST_LO_PSI
+        {
+            self.normal_brakes_available = false;
+        }
+    }
+
+    pub fn mode(&self) -> BrakeMode {
+        self.mode
+    }
+
+    pub fn is_normal_braking_available(&self) -> bool {
+        self.normal_brakes_available
+    }
+
+    /// True when pilot braking is done from local brake accumulators only
+    pub fn is_emergency_braking(&self) -> bool {
+        self.mode == BrakeMode::Emergency
+    }
+
+    pub fn normal_pressure_limit(&self) -> Pressure {
+        self.normal_pressure_limit
+    }
+
+    /// Pressure limit of the alternate and emergency brakes. Manual braking input while parking brake
+    /// is set is allowed up to the alternate pressure limit.
+    pub fn alternate_pressure_limit(&self, manual_braking_input: bool) -> Pressure {
+        match self.mode {
+            BrakeMode::Normal | BrakeMode::AlternateWithAntiSkid => self.alternate_pressure_limit,
+            BrakeMode::AlternateWithoutAntiSkid | BrakeMode::Emergency => {
+                self.alternate_without_anti_skid_pressure_limit
+            }
+            BrakeMode::Parking => {
+                if manual_braking_input {
+                    self.alternate_pressure_limit
+                } else {
+                    self.parking_pressure_limit
+                }
+            }
+        }
+    }
+}
+impl SimulationElement for BrakeModeSelector {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.mode_id, self.mode as u8 as f64);
+    }
+}
+
+/// Single brake fed only by its own local accumulator, which is charged from a hydraulic section through a check valve.
+/// Used for emergency braking once the main brake supplies are lost.
+pub struct LocalAccumulatorBrake {
+    press_id: VariableIdentifier,
+    acc_press_id: VariableIdentifier,
+
+    is_left: bool,
+
+    brake_actuator: BrakeActuator,
+    accumulator: Accumulator,
+
+    pressure_applied: Pressure,
+
+    total_volume_to_actuator: Volume,
+    total_volume_to_reservoir: Volume,
+
+    accumulator_fluid_pressure_sensor_filter: LowPassFilter<Pressure>,
+}
+impl LocalAccumulatorBrake {
+    pub fn new(
+        context: &mut InitContext,
+        id: &str,
+        is_left: bool,
+        accumulator: Accumulator,
+        displacement: Volume,
+    ) -> Self {
+        Self {
+            press_id: context.get_identifier(format!("HYD_BRAKE_EMER_{}_PRESS", id)),
+            acc_press_id: context.get_identifier(format!("HYD_BRAKE_EMER_{}_ACC_PRESS", id)),
+
+            is_left,
+
+            brake_actuator: BrakeActuator::new(context, displacement),
+            accumulator,
+
+            pressure_applied: Pressure::default(),
+
+            total_volume_to_actuator: Volume::default(),
+            total_volume_to_reservoir: Volume::default(),
+
+            accumulator_fluid_pressure_sensor_filter: LowPassFilter::<Pressure>::new(
+                BrakeCircuit::ACC_PRESSURE_SENSOR_FILTER_TIMECONST,
+            ),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        section: &impl SectionPressure,
+        brake_circuit_controller: &impl BrakeCircuitController,
+    ) {
+        let demand = if self.is_left {
+            brake_circuit_controller.left_brake_demand()
+        } else {
+            brake_circuit_controller.right_brake_demand()
+        };
+
+        self.brake_actuator
+            .set_position_demand(demand.get::<ratio>().min(1.).max(0.));
+
+        // Brake is isolated from the section: only the accumulator can move the actuator
+        let actual_pressure_available = if self.accumulator.fluid_volume() > Volume::default() {
+            self.accumulator.raw_gas_press()
+        } else {
+            Pressure::default()
+        };
+
+        self.brake_actuator.update(
+            context,
+            actual_pressure_available.min(brake_circuit_controller.pressure_limit()),
+        );
+
+        let mut volume_into_accumulator = Volume::default();
+        self.accumulator.update(
+            context,
+            &mut volume_into_accumulator,
+            section.pressure(),
+            Volume::new::<gallon>(1.),
+        );
+        self.total_volume_to_actuator += volume_into_accumulator.abs();
+
+        let delta_vol = self.brake_actuator.used_volume();
+        if delta_vol > Volume::default() {
+            self.accumulator.get_delta_vol(delta_vol);
+        }
+
+        self.total_volume_to_reservoir += self.brake_actuator.reservoir_return();
+        self.brake_actuator.reset_volumes();
+
+        self.pressure_applied = self.brake_actuator.get_applied_brake_pressure();
+
+        self.accumulator_fluid_pressure_sensor_filter
+            .update(context.delta(), actual_pressure_available);
+    }
+
+    pub fn is_left(&self) -> bool {
+        self.is_left
+    }
+
+    pub fn brake_pressure(&self) -> Pressure {
+        self.pressure_applied
+    }
+
+    pub fn accumulator_fluid_volume(&self) -> Volume {
+        self.accumulator.fluid_volume()
+    }
+
+    fn accumulator_pressure(&self) -> Pressure {
+        self.accumulator_fluid_pressure_sensor_filter.output()
+    }
+}
+impl Actuator for LocalAccumulatorBrake {
+    fn used_volume(&self) -> Volume {
+        self.total_volume_to_actuator
+    }
+
+    fn reservoir_return(&self) -> Volume {
+        self.total_volume_to_reservoir
+    }
+
+    fn reset_volumes(&mut self) {
+        self.total_volume_to_actuator = Volume::default();
+        self.total_volume_to_reservoir = Volume::default();
+    }
+}
+impl SimulationElement for LocalAccumulatorBrake {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.press_id, self.brake_pressure());
+        writer.write(&self.acc_press_id, self.accumulator_pressure());
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.brake_actuator.current_position);
+        snapshot.write(self.accumulator_fluid_pressure_sensor_filter.output());
+        self.accumulator.snapshot(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.brake_actuator.current_position = snapshot.read();
+        self.accumulator_fluid_pressure_sensor_filter
+            .reset(snapshot.read());
+        self.accumulator.restore(snapshot);
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -449,6 +781,7 @@ pub struct AutobrakePanel {
     lo_button: PressSingleSignalButton,
     med_button: PressSingleSignalButton,
     max_button: PressSingleSignalButton,
+    btv_button: Option<PressSingleSignalButton>,
 }
 impl AutobrakePanel {
     pub fn new(context: &mut InitContext) -> AutobrakePanel {
@@ -456,6 +789,15 @@ impl AutobrakePanel {
             lo_button: PressSingleSignalButton::new(context, "AUTOBRK_LOW_ON"),
             med_button: PressSingleSignalButton::new(context, "AUTOBRK_MED_ON"),
             max_button: PressSingleSignalButton::new(context, "AUTOBRK_MAX_ON"),
+            btv_button: None,
+        }
+    }
+
+    /// Autobrake panel which can also select the brake to vacate mode
+    pub fn new_with_btv(context: &mut InitContext) -> AutobrakePanel {
+        AutobrakePanel {
+            btv_button: Some(PressSingleSignalButton::new(context, "AUTOBRK_BTV_ON")),
+            ..Self::new(context)
         }
     }
 
@@ -471,6 +813,12 @@ impl AutobrakePanel {
         self.max_button.is_pressed()
     }
 
+    fn btv_pressed(&self) -> bool {
+        self.btv_button
+            .as_ref()
+            .map_or(false, |button| button.is_pressed())
+    }
+
     pub fn pressed_mode(&self) -> Option<AutobrakeMode> {
         if self.low_pressed() {
             Some(AutobrakeMode::LOW)
@@ -478,6 +826,8 @@ impl AutobrakePanel {
             Some(AutobrakeMode::MED)
         } else if self.max_pressed() {
             Some(AutobrakeMode::MAX)
+        } else if self.btv_pressed() {
+            Some(AutobrakeMode::BTV)
         } else {
             None
         }
@@ -488,6 +838,9 @@ impl SimulationElement for AutobrakePanel {
         self.lo_button.accept(visitor);
         self.med_button.accept(visitor);
         self.max_button.accept(visitor);
+        if let Some(btv_button) = &mut self.btv_button {
+            btv_button.accept(visitor);
+        }
 
         visitor.visit(self);
     }
@@ -576,6 +929,11 @@ impl AutobrakeDecelerationGovernor {
         self.current_output
     }
 
+    /// Filtered longitudinal acceleration the governor is regulating
+    pub fn acceleration(&self) -> Acceleration {
+        self.acceleration_filter.output()
+    }
+
     pub fn decelerating_at_or_above_rate(&self, target_threshold: Acceleration) -> bool {
         self.acceleration_filter.output() < target_threshold
     }
@@ -670,7 +1028,7 @@ impl Debug for BrakeAccumulatorCharacteristics {
 mod tests {
     use super::*;
 
-    use crate::simulation::test::{ElementCtorFn, SimulationTestBed, TestBed};
+    use crate::simulation::test::{ElementCtorFn, ReadByName, SimulationTestBed, TestBed};
     use crate::simulation::{Aircraft, UpdateContext};
     use std::time::Duration;
     use uom::si::{pressure::psi, volume::gallon};
@@ -1041,6 +1399,223 @@ mod tests {
         assert!(test_bed.query(|a| a.right_brake_pressure()) <= pressure_limit);
     }
 
+    #[test]
+    fn brake_mode_normal_with_pressurised_normal_supply() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(brake_mode_selector));
+
+        update_selector(&mut test_bed, 3000., 3000., true, false, 0.);
+
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::Normal);
+        assert!(
+            test_bed.query_element(|e| e.alternate_pressure_limit(false))
+                == Pressure::new::<psi>(2538.)
+        );
+
+        let mode: f64 = test_bed.read_by_name("HYD_BRAKE_MODE");
+        assert!(mode == BrakeMode::Normal as u8 as f64);
+    }
+
+    #[test]
+    fn brake_mode_alternate_with_anti_skid_on_normal_supply_loss() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(brake_mode_selector));
+
+        update_selector(&mut test_bed, 3000., 3000., true, false, 0.);
+        update_selector(&mut test_bed, 1500., 3000., true, false, 0.);
+
+        // Still in hysteresis
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::Normal);
+
+        update_selector(&mut test_bed, 1000., 3000., true, false, 0.);
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::AlternateWithAntiSkid);
+
+        let mode: f64 = test_bed.read_by_name("HYD_BRAKE_MODE");
+        assert!(BrakeMode::from(mode) == BrakeMode::AlternateWithAntiSkid);
+    }
+
+    #[test]
+    fn brake_mode_back_to_normal_only_when_pedals_released() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(brake_mode_selector));
+
+        update_selector(&mut test_bed, 0., 3000., true, false, 0.5);
+        update_selector(&mut test_bed, 3000., 3000., true, false, 0.5);
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::AlternateWithAntiSkid);
+
+        update_selector(&mut test_bed, 3000., 3000., true, false, 0.);
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::Normal);
+    }
+
+    #[test]
+    fn brake_mode_alternate_without_anti_skid_on_accumulator_only() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(brake_mode_selector));
+
+        update_selector(&mut test_bed, 0., 0., true, false, 0.);
+
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::AlternateWithoutAntiSkid);
+        assert!(
+            test_bed.query_element(|e| e.alternate_pressure_limit(false))
+                == Pressure::new::<psi>(1160.)
+        );
+        assert!(!test_bed.query_element(|e| e.is_emergency_braking()));
+    }
+
+    #[test]
+    fn brake_mode_alternate_without_anti_skid_when_anti_skid_off() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(brake_mode_selector));
+
+        update_selector(&mut test_bed, 3000., 3000., false, false, 0.);
+
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::AlternateWithoutAntiSkid);
+        assert!(test_bed.query_element(|e| e.is_normal_braking_available()));
+    }
+
+    #[test]
+    fn brake_mode_parking_pressure_limits() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(brake_mode_selector));
+
+        update_selector(&mut test_bed, 3000., 3000., true, true, 0.);
+
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::Parking);
+        assert!(!test_bed.query_element(|e| e.is_emergency_braking()));
+        assert!(
+            test_bed.query_element(|e| e.alternate_pressure_limit(false))
+                == Pressure::new::<psi>(2103.)
+        );
+        assert!(
+            test_bed.query_element(|e| e.alternate_pressure_limit(true))
+                == Pressure::new::<psi>(2538.)
+        );
+    }
+
+    #[test]
+    fn brake_mode_emergency_with_local_accumulators_when_all_supplies_lost() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+            BrakeModeSelector::new(
+                context,
+                true,
+                Pressure::new::<psi>(2538.),
+                Pressure::new::<psi>(2538.),
+                Pressure::new::<psi>(1160.),
+                Pressure::new::<psi>(2103.),
+            )
+        }));
+
+        update_selector(&mut test_bed, 0., 3000., true, false, 0.);
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::AlternateWithAntiSkid);
+
+        update_selector(&mut test_bed, 0., 0., true, false, 0.);
+        assert!(test_bed.query_element(|e| e.mode()) == BrakeMode::Emergency);
+        assert!(test_bed.query_element(|e| e.is_emergency_braking()));
+
+        let mode: f64 = test_bed.read_by_name("HYD_BRAKE_MODE");
+        assert!(BrakeMode::from(mode) == BrakeMode::Emergency);
+        assert!(
+            test_bed.query_element(|e| e.alternate_pressure_limit(false))
+                == Pressure::new::<psi>(1160.)
+        );
+    }
+
+    #[test]
+    fn local_accumulator_brake_charges_then_brakes_without_supply() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(local_accumulator_brake));
+
+        test_bed.set_update_after_power_distribution(|e, context| {
+            e.update(
+                context,
+                &test_section(3000.),
+                &test_controller(0., 0., 2538.),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(5));
+
+        assert!(test_bed.query_element(|e| e.accumulator_fluid_volume()) > Volume::default());
+        assert!(test_bed.query_element(|e| e.brake_pressure()) < Pressure::new::<psi>(10.));
+
+        test_bed.set_update_after_power_distribution(|e, context| {
+            e.update(context, &test_section(0.), &test_controller(1., 0., 1160.))
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.brake_pressure()) > Pressure::new::<psi>(1000.));
+        assert!(test_bed.query_element(|e| e.brake_pressure()) <= Pressure::new::<psi>(1161.));
+    }
+
+    #[test]
+    fn local_accumulator_brake_ignores_other_side_demand() {
+        let mut test_bed = SimulationTestBed::from(ElementCtorFn(local_accumulator_brake));
+
+        test_bed.set_update_after_power_distribution(|e, context| {
+            e.update(
+                context,
+                &test_section(3000.),
+                &test_controller(0., 1., 2538.),
+            )
+        });
+        test_bed.run_multiple_frames(Duration::from_secs(2));
+
+        assert!(test_bed.query_element(|e| e.brake_pressure()) < Pressure::new::<psi>(10.));
+    }
+
+    fn update_selector(
+        test_bed: &mut SimulationTestBed<crate::simulation::test::TestAircraft<BrakeModeSelector>>,
+        normal_pressure_psi: f64,
+        alternate_pressure_psi: f64,
+        anti_skid_activated: bool,
+        parking_brake_demand: bool,
+        pedal_input: f64,
+    ) {
+        test_bed.command_element(|e| {
+            e.update(
+                &test_section(normal_pressure_psi),
+                &test_section(alternate_pressure_psi),
+                anti_skid_activated,
+                parking_brake_demand,
+                Ratio::new::<ratio>(pedal_input),
+                Ratio::new::<ratio>(pedal_input),
+            )
+        });
+        test_bed.run_with_delta(Duration::from_millis(1));
+    }
+
+    fn brake_mode_selector(context: &mut InitContext) -> BrakeModeSelector {
+        BrakeModeSelector::new(
+            context,
+            false,
+            Pressure::new::<psi>(2538.),
+            Pressure::new::<psi>(2538.),
+            Pressure::new::<psi>(1160.),
+            Pressure::new::<psi>(2103.),
+        )
+    }
+
+    fn local_accumulator_brake(context: &mut InitContext) -> LocalAccumulatorBrake {
+        LocalAccumulatorBrake::new(
+            context,
+            "TEST",
+            true,
+            Accumulator::new(
+                Pressure::new::<psi>(1000.),
+                Volume::new::<gallon>(0.5),
+                Volume::new::<gallon>(0.),
+                true,
+                Pressure::new::<psi>(3000.),
+            ),
+            Volume::new::<gallon>(0.03),
+        )
+    }
+
+    fn test_section(pressure_psi: f64) -> TestHydraulicSection {
+        let mut section = TestHydraulicSection::default();
+        section.set_pressure(Pressure::new::<psi>(pressure_psi));
+        section
+    }
+
+    fn test_controller(left: f64, right: f64, pressure_limit_psi: f64) -> TestBrakeController {
+        let mut controller =
+            TestBrakeController::new(Ratio::new::<ratio>(left), Ratio::new::<ratio>(right));
+        controller.set_pressure_limit(Pressure::new::<psi>(pressure_limit_psi));
+        controller
+    }
+
     fn brake_circuit(context: &mut InitContext, init_max_vol: Volume) -> BrakeCircuit {
         BrakeCircuit::new(
             context,
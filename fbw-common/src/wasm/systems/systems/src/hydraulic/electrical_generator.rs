@@ -17,7 +17,8 @@ use crate::shared::{
 };
 
 use crate::simulation::{
-    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext,
+    VariableIdentifier, Write,
 };
 
 use std::time::Duration;
@@ -301,6 +302,17 @@ impl SimulationElement for HydraulicGeneratorMotor {
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.generator_rpm_id, self.speed());
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.speed);
+        snapshot.write(self.valve.position());
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.speed = snapshot.read();
+        self.valve.position.reset(snapshot.read());
+        self.update_virtual_displacement();
+    }
 }
 impl AngularSpeedSensor for HydraulicGeneratorMotor {
     fn speed(&self) -> AngularVelocity {
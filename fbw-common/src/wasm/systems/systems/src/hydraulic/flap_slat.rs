@@ -3,7 +3,8 @@ use crate::shared::{
     interpolation, low_pass_filter::LowPassFilter, FeedbackPositionPickoffUnit, SectionPressure,
 };
 use crate::simulation::{
-    InitContext, SimulationElement, SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    InitContext, SimulationElement, SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext,
+    VariableIdentifier, Write,
 };
 
 use uom::si::{
@@ -488,6 +489,18 @@ impl SimulationElement for FlapSlatAssembly {
 
         writer.write(&self.is_moving_id, self.is_surface_moving());
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.surface_control_arm_position);
+        snapshot.write(self.final_requested_synchro_gear_position);
+        snapshot.write(self.speed);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.surface_control_arm_position = snapshot.read();
+        self.final_requested_synchro_gear_position = snapshot.read();
+        self.speed = snapshot.read();
+    }
 }
 impl FeedbackPositionPickoffUnit for FlapSlatAssembly {
     fn angle(&self) -> Angle {
@@ -26,7 +26,7 @@ use crate::{
     },
     simulation::{
         InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-        UpdateContext, VariableIdentifier,
+        SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier,
     },
 };
 
@@ -39,6 +39,9 @@ pub trait Actuator {
     fn used_volume(&self) -> Volume;
     fn reservoir_return(&self) -> Volume;
     fn reset_volumes(&mut self);
+
+    /// Ratio of its nominal max flow the actuator can draw, given current fluid viscosity
+    fn set_fluid_flow_ratio(&mut self, _flow_ratio: Ratio) {}
 }
 
 /// Trait linked to anything moving bounded between a minimum and maximum position.
@@ -397,6 +400,7 @@ struct CoreHydraulicForce {
 
     max_flow: VolumeRate,
     min_flow: VolumeRate,
+    fluid_flow_ratio: Ratio,
     flow_error_prev: VolumeRate,
 
     bore_side_area: Area,
@@ -488,6 +492,7 @@ impl CoreHydraulicForce {
 
             max_flow,
             min_flow,
+            fluid_flow_ratio: Ratio::new::<ratio>(1.),
             flow_error_prev: VolumeRate::new::<gallon_per_second>(0.),
             bore_side_area,
             rod_side_area,
@@ -769,7 +774,9 @@ impl CoreHydraulicForce {
             )
         };
 
-        (open_loop_flow_target.min(self.max_flow).max(self.min_flow))
+        (open_loop_flow_target
+            .min(self.max_flow * self.fluid_flow_ratio)
+            .max(self.min_flow * self.fluid_flow_ratio))
             * open_loop_modifier_from_position
     }
 
@@ -1149,6 +1156,14 @@ impl LinearActuator {
         self.speed = self.delta_displacement / context.delta_as_time();
     }
 
+    fn restore_position(&mut self, connected_body: &LinearActuatedRigidBodyOnHingeAxis) {
+        self.position = connected_body.linear_extension_to_anchor();
+        self.last_position = self.position;
+        self.position_normalized = (self.position - self.min_absolute_length) / self.total_travel;
+        self.delta_displacement = Length::default();
+        self.speed = Velocity::default();
+    }
+
     fn update_fluid_displacements(&mut self, context: &UpdateContext) {
         let mut volume_to_actuator = Volume::new::<cubic_meter>(0.);
         let mut volume_to_reservoir = Volume::new::<cubic_meter>(0.);
@@ -1237,6 +1252,10 @@ impl Actuator for LinearActuator {
             eha.reset_volumes();
         }
     }
+
+    fn set_fluid_flow_ratio(&mut self, flow_ratio: Ratio) {
+        self.core_hydraulics.fluid_flow_ratio = flow_ratio;
+    }
 }
 impl HydraulicLocking for LinearActuator {
     fn should_soft_lock(&self) -> bool {
@@ -1450,6 +1469,18 @@ impl<const N: usize> HydraulicLinearActuatorAssembly<N> {
         self.rigid_body.apply_global_angle_offset(trim_angle);
     }
 
+    fn snapshot_body(&self, snapshot: &mut SnapshotWriter) {
+        self.rigid_body.snapshot(snapshot);
+    }
+
+    fn restore_body(&mut self, snapshot: &mut SnapshotReader) {
+        self.rigid_body.restore(snapshot);
+
+        for actuator in &mut self.linear_actuators {
+            actuator.restore_position(&self.rigid_body);
+        }
+    }
+
     #[cfg(test)]
     pub fn actuator_flow(&self, index: usize) -> VolumeRate {
         self.linear_actuators[index].signed_flow().abs()
@@ -1461,6 +1492,13 @@ impl SimulationElement for HydraulicLinearActuatorAssembly<1> {
 
         visitor.visit(self);
     }
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.snapshot_body(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.restore_body(snapshot);
+    }
 }
 impl Debug for HydraulicLinearActuatorAssembly<1> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1477,6 +1515,13 @@ impl SimulationElement for HydraulicLinearActuatorAssembly<2> {
 
         visitor.visit(self);
     }
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.snapshot_body(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.restore_body(snapshot);
+    }
 }
 impl Debug for HydraulicLinearActuatorAssembly<2> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1493,6 +1538,13 @@ impl SimulationElement for HydraulicLinearActuatorAssembly<3> {
 
         visitor.visit(self);
     }
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        self.snapshot_body(snapshot);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.restore_body(snapshot);
+    }
 }
 impl Debug for HydraulicLinearActuatorAssembly<3> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -1835,6 +1887,26 @@ impl LinearActuatedRigidBodyOnHingeAxis {
         self.is_lock_requested = false;
     }
 
+    pub fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.angular_position);
+        snapshot.write(self.angular_speed);
+        snapshot.write(self.is_locked);
+        snapshot.write(self.is_lock_requested);
+        snapshot.write(self.lock_position_request);
+    }
+
+    pub fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.angular_position = snapshot.read();
+        self.angular_speed = snapshot.read();
+        self.is_locked = snapshot.read();
+        self.is_lock_requested = snapshot.read();
+        self.lock_position_request = snapshot.read();
+
+        self.update_position_normalized();
+        self.position_normalized_prev = self.position_normalized;
+        self.update_all_rotations();
+    }
+
     pub fn lock_at_position_normalized(&mut self, position_normalized: Ratio) {
         self.is_lock_requested = true;
         self.lock_position_request = position_normalized;
@@ -2193,6 +2265,12 @@ mod tests {
             self.hydraulic_assembly.set_trim_offset(trim_angle);
         }
 
+        fn set_fluid_flow_ratio(&mut self, flow_ratio: Ratio) {
+            for actuator in &mut self.hydraulic_assembly.linear_actuators {
+                actuator.set_fluid_flow_ratio(flow_ratio);
+            }
+        }
+
         fn body_position(&self) -> Ratio {
             self.hydraulic_assembly.position_normalized()
         }
@@ -2604,6 +2682,42 @@ mod tests {
         assert!(test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.9));
     }
 
+    #[test]
+    fn restoring_a_snapshot_restores_body_and_actuator_positions() {
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = cargo_door_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+
+        test_bed.command(|a| a.command_unlock());
+        test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(1.1), 0));
+        test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(3000.)]));
+        test_bed.run_with_delta(Duration::from_secs(25));
+
+        let body_position = test_bed.query(|a| a.body_position());
+        let actuator_position =
+            test_bed.query(|a| a.hydraulic_assembly.actuator_position_normalized(0));
+        let snapshot = test_bed.snapshot();
+
+        let mut test_bed = SimulationTestBed::new(|context| {
+            let tested_object = cargo_door_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+        test_bed.restore(&snapshot).unwrap();
+
+        assert!(!test_bed.query(|a| a.is_locked()));
+        assert_about_eq!(
+            test_bed.query(|a| a.body_position()).get::<ratio>(),
+            body_position.get::<ratio>()
+        );
+        assert_about_eq!(
+            test_bed
+                .query(|a| a.hydraulic_assembly.actuator_position_normalized(0))
+                .get::<ratio>(),
+            actuator_position.get::<ratio>()
+        );
+    }
+
     #[test]
     fn linear_actuator_resists_body_drop_when_valves_closed() {
         let mut test_bed = SimulationTestBed::new(|context| {
@@ -2851,6 +2965,33 @@ mod tests {
         assert!(test_bed.query(|a| a.body_position()) < Ratio::new::<ratio>(0.22));
     }
 
+    #[test]
+    fn linear_actuator_moves_slower_with_restricted_fluid_flow() {
+        let mut warm_test_bed = SimulationTestBed::new(|context| {
+            let tested_object = cargo_door_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+        let mut cold_test_bed = SimulationTestBed::new(|context| {
+            let tested_object = cargo_door_assembly(context, true);
+            TestAircraft::new(context, tested_object)
+        });
+        cold_test_bed.command(|a| a.set_fluid_flow_ratio(Ratio::new::<ratio>(0.45)));
+
+        for test_bed in [&mut warm_test_bed, &mut cold_test_bed] {
+            test_bed.command(|a| a.command_unlock());
+            test_bed.command(|a| a.command_position_control(Ratio::new::<ratio>(0.7), 0));
+            test_bed.command(|a| a.set_pressures([Pressure::new::<psi>(3000.)]));
+
+            test_bed.run_with_delta(Duration::from_secs(3));
+        }
+
+        assert!(warm_test_bed.query(|a| a.body_position()) > Ratio::new::<ratio>(0.1));
+        assert!(
+            cold_test_bed.query(|a| a.body_position())
+                < warm_test_bed.query(|a| a.body_position()) * 0.7
+        );
+    }
+
     #[test]
     fn right_main_gear_door_drops_when_unlocked() {
         let mut test_bed = SimulationTestBed::new(|context| {
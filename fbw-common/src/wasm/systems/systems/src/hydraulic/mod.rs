@@ -14,7 +14,7 @@ use crate::shared::{
 };
 use crate::simulation::{
     InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
-    SimulatorWriter, UpdateContext, VariableIdentifier, Write,
+    SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
 };
 use nalgebra::Vector3;
 
@@ -25,14 +25,17 @@ use uom::si::{
     f64::*,
     length::meter,
     mass::kilogram,
+    power::watt,
     pressure::{pascal, psi},
     ratio::ratio,
+    thermodynamic_temperature::{degree_celsius, kelvin},
     torque::{newton_meter, pound_force_inch},
     volume::{cubic_inch, cubic_meter, gallon},
     volume_rate::{gallon_per_minute, gallon_per_second},
 };
 
 pub mod aerodynamic_model;
+pub mod anti_skid;
 pub mod brake_circuit;
 pub mod cargo_doors;
 pub mod electrical_generator;
@@ -44,6 +47,7 @@ pub mod nose_steering;
 pub mod pumps;
 pub mod pushback;
 pub mod rudder_control;
+pub mod runway_overrun_protection;
 pub mod trimmable_horizontal_stabilizer;
 
 /// Indicates the pressure sensors info of an hydraulic circuit at different locations
@@ -79,9 +83,26 @@ pub trait PressureSource {
     fn displacement(&self) -> Volume;
 }
 
+/// Hydraulic fluid of a circuit.
+///
+/// Fluid temperature results from the heat produced by pumps and ptu against the cooling to
+/// ambient air. Temperature changes the fluid viscosity, which in turn limits pump delivery and
+/// actuator speed when the fluid is very cold or very hot.
+/// Contamination loads the circuit filters with the pumped volume. It raises pump case drain
+/// until the filter is clogged, and is cleared as a maintenance action once its failure is removed.
 pub struct Fluid {
+    temperature_id: VariableIdentifier,
+    filter_clogged_id: VariableIdentifier,
+
     current_bulk: Pressure,
     heat_state: HeatingProperties,
+
+    temperature: ThermodynamicTemperature,
+    is_temperature_initialised: bool,
+
+    contamination_failure: Failure,
+    contamination_failure_active_previous_state: bool,
+    filter_loading: Ratio,
 }
 impl Fluid {
     const HEATING_TIME_CONSTANT_MEAN_S: f64 = 40.;
@@ -90,8 +111,45 @@ impl Fluid {
     const COOLING_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
     const DAMAGE_TIME_CONSTANT: Duration = Duration::from_secs(60 * 3);
 
-    pub fn new(bulk: Pressure) -> Self {
+    // Temperature of the fluid when simulation starts with engines running
+    const OPERATING_TEMPERATURE_DEGREE_CELSIUS: f64 = 45.;
+
+    // Heat capacity of the circuit fluid and of the metal parts it flows through
+    const THERMAL_MASS_JOULE_PER_KELVIN: f64 = 120000.;
+
+    // Heat exchanged with outside through lines, reservoir and heat exchanger
+    const COOLING_COEFFICIENT_WATT_PER_KELVIN: f64 = 58.;
+
+    // Additional heat brought by an overheating pump or ptu
+    const OVERHEATING_SOURCE_HEAT_WATT: f64 = 20000.;
+
+    const OVERHEAT_TEMPERATURE_DEGREE_CELSIUS: f64 = 110.;
+
+    const VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS: [f64; 10] =
+        [-54., -40., -20., 0., 20., 40., 60., 80., 100., 120.];
+    const VISCOSITY_CENTISTOKES: [f64; 10] = [1200., 400., 110., 38., 17., 10., 6.5, 4.8, 3.8, 3.1];
+
+    // Thin fluid leaks more inside the pump, thick fluid can't fill the pump pistons fast enough
+    const PUMP_VISCOSITY_BREAKPOINTS_CENTISTOKES: [f64; 6] = [2., 6., 60., 150., 400., 1200.];
+    const PUMP_DELIVERY_EFFICIENCY: [f64; 6] = [0.95, 1., 1., 0.92, 0.7, 0.4];
+
+    const ACTUATOR_VISCOSITY_BREAKPOINTS_CENTISTOKES: [f64; 4] = [60., 150., 400., 1200.];
+    const ACTUATOR_FLOW_RATIO: [f64; 4] = [1., 0.9, 0.7, 0.45];
+
+    // Filter loading added by each gallon pumped through a contaminated circuit
+    const CONTAMINATION_FILTER_LOADING_PER_GALLON: f64 = 0.02;
+    const FILTER_CLOGGED_LOADING_THRESHOLD: f64 = 0.6;
+
+    // Part of the pump flow lost to case drain with a fully loaded filter
+    const MAX_CONTAMINATION_CASE_DRAIN_RATIO: f64 = 0.3;
+
+    pub fn new(context: &mut InitContext, hyd_loop_id: HydraulicColor, bulk: Pressure) -> Self {
         Self {
+            temperature_id: context
+                .get_identifier(format!("HYD_{}_FLUID_TEMPERATURE", hyd_loop_id)),
+            filter_clogged_id: context
+                .get_identifier(format!("HYD_{}_FILTER_CLOGGED", hyd_loop_id)),
+
             current_bulk: bulk,
             heat_state: HeatingProperties::new(
                 Duration::from_secs_f64(
@@ -104,6 +162,18 @@ impl Fluid {
                 Self::COOLING_TIME_CONSTANT,
                 Self::DAMAGE_TIME_CONSTANT,
             ),
+
+            // Without running engines the fluid is cold soaked and takes ambient temperature at first update
+            temperature: ThermodynamicTemperature::new::<degree_celsius>(
+                Self::OPERATING_TEMPERATURE_DEGREE_CELSIUS,
+            ),
+            is_temperature_initialised: context.has_engines_running(),
+
+            contamination_failure: Failure::new(FailureType::HydraulicFluidContamination(
+                hyd_loop_id,
+            )),
+            contamination_failure_active_previous_state: false,
+            filter_loading: Ratio::default(),
         }
     }
 
@@ -111,13 +181,127 @@ impl Fluid {
         self.current_bulk
     }
 
-    fn update(&mut self, context: &UpdateContext, is_heating: bool) {
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        self.temperature
+    }
+
+    /// Kinematic viscosity of the fluid in centistokes
+    pub fn viscosity_centistokes(&self) -> f64 {
+        interpolation(
+            &Self::VISCOSITY_TEMPERATURE_BREAKPOINTS_DEGREE_CELSIUS,
+            &Self::VISCOSITY_CENTISTOKES,
+            self.temperature.get::<degree_celsius>(),
+        )
+    }
+
+    /// Part of the flow produced by a pump that is actually delivered to its section.
+    /// The remaining part goes back to reservoir through the pump case drain.
+    pub fn pump_delivery_efficiency(&self) -> Ratio {
+        Ratio::new::<ratio>(
+            interpolation(
+                &Self::PUMP_VISCOSITY_BREAKPOINTS_CENTISTOKES,
+                &Self::PUMP_DELIVERY_EFFICIENCY,
+                self.viscosity_centistokes(),
+            ) * (1.
+                - self.filter_loading.get::<ratio>() * Self::MAX_CONTAMINATION_CASE_DRAIN_RATIO),
+        )
+    }
+
+    /// Ratio of their nominal max flow actuators can draw through their valves with current viscosity
+    pub fn actuator_flow_ratio(&self) -> Ratio {
+        Ratio::new::<ratio>(interpolation(
+            &Self::ACTUATOR_VISCOSITY_BREAKPOINTS_CENTISTOKES,
+            &Self::ACTUATOR_FLOW_RATIO,
+            self.viscosity_centistokes(),
+        ))
+    }
+
+    pub fn is_filter_clogged(&self) -> bool {
+        self.filter_loading.get::<ratio>() >= Self::FILTER_CLOGGED_LOADING_THRESHOLD
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        is_heating: bool,
+        heat_power: Power,
+        pumped_volume: Volume,
+    ) {
         self.heat_state.update(context, is_heating);
+
+        self.update_temperature(context, is_heating, heat_power);
+        self.update_contamination(pumped_volume);
+    }
+
+    fn update_temperature(&mut self, context: &UpdateContext, is_heating: bool, heat_power: Power) {
+        if !self.is_temperature_initialised {
+            self.temperature = context.ambient_temperature();
+            self.is_temperature_initialised = true;
+        }
+
+        let overheating_heat_watt = if is_heating {
+            Self::OVERHEATING_SOURCE_HEAT_WATT
+        } else {
+            0.
+        };
+
+        let cooling_watt = Self::COOLING_COEFFICIENT_WATT_PER_KELVIN
+            * (self.temperature.get::<kelvin>() - context.ambient_temperature().get::<kelvin>());
+
+        let delta_temperature_kelvin = (heat_power.get::<watt>() + overheating_heat_watt
+            - cooling_watt)
+            * context.delta_as_secs_f64()
+            / Self::THERMAL_MASS_JOULE_PER_KELVIN;
+
+        self.temperature = ThermodynamicTemperature::new::<kelvin>(
+            self.temperature.get::<kelvin>() + delta_temperature_kelvin,
+        );
+    }
+
+    fn update_contamination(&mut self, pumped_volume: Volume) {
+        if self.contamination_failure.is_active() {
+            self.filter_loading = Ratio::new::<ratio>(
+                (self.filter_loading.get::<ratio>()
+                    + pumped_volume.get::<gallon>().abs()
+                        * Self::CONTAMINATION_FILTER_LOADING_PER_GALLON)
+                    .min(1.),
+            );
+        } else if self.contamination_failure_active_previous_state {
+            // Maintenance action once failure is removed: fluid is flushed and filters replaced
+            self.filter_loading = Ratio::default();
+        }
+
+        self.contamination_failure_active_previous_state = self.contamination_failure.is_active();
+    }
+}
+impl SimulationElement for Fluid {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.contamination_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature());
+        writer.write(&self.filter_clogged_id, self.is_filter_clogged());
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.temperature);
+        snapshot.write(self.is_temperature_initialised);
+        snapshot.write(self.filter_loading);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.temperature = snapshot.read();
+        self.is_temperature_initialised = snapshot.read();
+        self.filter_loading = snapshot.read();
     }
 }
 impl HeatingElement for Fluid {
     fn is_overheating(&self) -> bool {
         self.heat_state.is_overheating()
+            || self.temperature.get::<degree_celsius>() > Self::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS
     }
 
     fn is_damaged(&self) -> bool {
@@ -303,6 +487,10 @@ pub trait PowerTransferUnitCharacteristics {
     fn deactivation_delta_pressure(&self) -> Pressure;
     fn activation_delta_pressure(&self) -> Pressure;
     fn shot_to_shot_variability(&self) -> Ratio;
+
+    /// Age of the ptu, from 0 for a new part to 1 for the most worn out part still acceptable.
+    /// Wear slows down the displacement response.
+    fn wear(&self) -> Ratio;
 }
 
 pub struct PowerTransferUnit {
@@ -312,6 +500,8 @@ pub struct PowerTransferUnit {
 
     dev_efficiency_id: VariableIdentifier,
     dev_delta_pressure: VariableIdentifier,
+    dev_wear_id: VariableIdentifier,
+    dev_wear_is_forced_id: VariableIdentifier,
 
     is_enabled: bool,
     is_active_right: bool,
@@ -344,6 +534,16 @@ pub struct PowerTransferUnit {
     has_stopped_since_last_write: bool,
 
     efficiency: Ratio,
+    wear: Ratio,
+
+    internal_leak_flow_left: VolumeRate,
+    internal_leak_flow_right: VolumeRate,
+    threshold_drift: LowPassFilter<Ratio>,
+
+    shaft_shear_failure: Failure,
+    internal_leakage_failure: Failure,
+    jam_failure: Failure,
+    bark_threshold_drift_failure: Failure,
 
     heat_state: HeatingProperties,
 }
@@ -355,6 +555,19 @@ impl PowerTransferUnit {
 
     const DISPLACEMENT_TIME_CONSTANT: Duration = Duration::from_millis(45);
 
+    // Displacement time constant of the most worn out ptu, relative to a new one
+    const WORN_DISPLACEMENT_TIME_CONSTANT_FACTOR: f64 = 2.;
+
+    // Internal leakage at max pressure with an internal leakage failure
+    const FAILURE_INTERNAL_LEAK_GAL_P_S: f64 = 0.15;
+    const INTERNAL_LEAK_REFERENCE_PRESSURE_PSI: f64 = 3000.;
+    const INTERNAL_LEAKAGE_FAILURE_EFFICIENCY_FACTOR: f64 = 0.7;
+
+    // With bark threshold drift failure, activation and deactivation thresholds slowly drift down to this ratio
+    // of their nominal value, so that ptu activates for smaller and smaller pressure differences
+    const THRESHOLD_DRIFT_FAILED_RATIO: f64 = 0.3;
+    const THRESHOLD_DRIFT_TIME_CONSTANT: Duration = Duration::from_secs(60);
+
     const PRESSURE_BREAKPOINTS_PSI: [f64; 10] =
         [-500., -250., -100., -50., -10., 0., 100., 220., 250., 500.];
     const DISPLACEMENT_CARAC_CUBIC_INCH: [f64; 10] = [
@@ -400,6 +613,8 @@ impl PowerTransferUnit {
             dev_delta_pressure: context.get_identifier("HYD_PTU_DEV_DEACTIVATION_DELTA".to_owned()),
             bark_strength_id: context.get_identifier("HYD_PTU_BARK_STRENGTH".to_owned()),
             dev_efficiency_id: context.get_identifier("HYD_PTU_DEV_EFFICIENCY".to_owned()),
+            dev_wear_id: context.get_identifier("HYD_PTU_DEV_WEAR".to_owned()),
+            dev_wear_is_forced_id: context.get_identifier("HYD_PTU_DEV_WEAR_IS_FORCED".to_owned()),
 
             is_enabled: false,
             is_active_right: false,
@@ -409,7 +624,9 @@ impl PowerTransferUnit {
             left_displacement: Volume::new::<cubic_inch>(
                 Self::DEFAULT_LEFT_DISPLACEMENT_CUBIC_INCH,
             ),
-            right_displacement: LowPassFilter::<Volume>::new(Self::DISPLACEMENT_TIME_CONSTANT), //::<cubic_inch>(Self::DEFAULT_RIGHT_DISPLACEMENT),
+            right_displacement: LowPassFilter::<Volume>::new(Self::displacement_time_constant(
+                characteristics.wear(),
+            )),
             last_flow: VolumeRate::new::<gallon_per_second>(0.0),
 
             control_valve_opened: false,
@@ -436,6 +653,19 @@ impl PowerTransferUnit {
             has_stopped_since_last_write: false,
 
             efficiency: characteristics.efficiency(),
+            wear: characteristics.wear(),
+
+            internal_leak_flow_left: VolumeRate::default(),
+            internal_leak_flow_right: VolumeRate::default(),
+            threshold_drift: LowPassFilter::new_with_init_value(
+                Self::THRESHOLD_DRIFT_TIME_CONSTANT,
+                Ratio::new::<ratio>(1.),
+            ),
+
+            shaft_shear_failure: Failure::new(FailureType::PtuShaftShear),
+            internal_leakage_failure: Failure::new(FailureType::PtuInternalLeakage),
+            jam_failure: Failure::new(FailureType::PtuJam),
+            bark_threshold_drift_failure: Failure::new(FailureType::PtuBarkThresholdDrift),
 
             heat_state: HeatingProperties::new(
                 Duration::from_secs_f64(
@@ -476,11 +706,13 @@ impl PowerTransferUnit {
     ) {
         self.is_enabled = controller.should_enable();
 
+        self.update_threshold_drift(context);
         self.update_displacement(context, loop_left_section, loop_right_section);
         self.update_shaft_physics(context, loop_left_section, loop_right_section);
         self.update_active_state(context);
         self.update_continuous_state(context);
         self.capture_bark_strength();
+        self.update_internal_leak(loop_left_section, loop_right_section);
         self.update_flows();
 
         self.heat_state.update(
@@ -507,12 +739,19 @@ impl PowerTransferUnit {
             Pressure::new::<psi>(0.)
         };
 
-        if delta_p.abs() > self.activation_delta_pressure * self.shot_to_shot_activation_coefficient
+        let threshold_drift = self.threshold_drift.output().get::<ratio>();
+
+        if delta_p.abs()
+            > self.activation_delta_pressure
+                * self.shot_to_shot_activation_coefficient
+                * threshold_drift
         {
             self.control_valve_opened = true;
             self.shot_to_shot_activation_coefficient = self.rand_shot_to_shot();
         } else if delta_p.abs()
-            < self.deactivation_delta_pressure * self.shot_to_shot_deactivation_coefficient
+            < self.deactivation_delta_pressure
+                * self.shot_to_shot_deactivation_coefficient
+                * threshold_drift
         {
             self.shot_to_shot_deactivation_coefficient = self.rand_shot_to_shot();
             self.control_valve_opened = false;
@@ -584,10 +823,20 @@ impl PowerTransferUnit {
             Pressure::new::<psi>(0.)
         };
 
-        let left_side_torque = -Self::calc_generated_torque(left_pressure, self.left_displacement);
-        let right_side_torque =
+        let mut left_side_torque =
+            -Self::calc_generated_torque(left_pressure, self.left_displacement);
+        let mut right_side_torque =
             Self::calc_generated_torque(right_pressure, self.right_displacement.output());
 
+        // With a sheared shaft, the strongest side spins freely without driving the other side
+        if self.shaft_shear_failure.is_active() {
+            if left_side_torque.abs() > right_side_torque.abs() {
+                right_side_torque = Torque::default();
+            } else {
+                left_side_torque = Torque::default();
+            }
+        }
+
         let friction_torque = Torque::new::<newton_meter>(
             Self::SHAFT_FRICTION * -self.shaft_speed.get::<radian_per_second>(),
         );
@@ -595,6 +844,7 @@ impl PowerTransferUnit {
         let total_torque = friction_torque + left_side_torque + right_side_torque;
 
         if !self.heat_state.is_damaged()
+            && !self.jam_failure.is_active()
             && (self.is_rotating()
                 || total_torque.abs().get::<newton_meter>() > Self::BREAKOUT_TORQUE_NM)
         {
@@ -629,6 +879,64 @@ impl PowerTransferUnit {
         self.activation_delta_pressure = characteristics.activation_delta_pressure();
         self.deactivation_delta_pressure = characteristics.deactivation_delta_pressure();
         self.shot_to_shot_variability = characteristics.shot_to_shot_variability();
+
+        self.set_wear(characteristics.wear());
+    }
+
+    fn set_wear(&mut self, wear: Ratio) {
+        self.wear = wear;
+        self.right_displacement
+            .set_time_constant(Self::displacement_time_constant(self.wear));
+    }
+
+    fn displacement_time_constant(wear: Ratio) -> Duration {
+        Self::DISPLACEMENT_TIME_CONSTANT.mul_f64(
+            1. + (Self::WORN_DISPLACEMENT_TIME_CONSTANT_FACTOR - 1.)
+                * wear.get::<ratio>().max(0.).min(1.),
+        )
+    }
+
+    fn update_threshold_drift(&mut self, context: &UpdateContext) {
+        self.threshold_drift.update(
+            context.delta(),
+            Ratio::new::<ratio>(if self.bark_threshold_drift_failure.is_active() {
+                Self::THRESHOLD_DRIFT_FAILED_RATIO
+            } else {
+                1.
+            }),
+        );
+    }
+
+    /// Leakage through damaged seals, from each pressurised side back to its own reservoir
+    fn update_internal_leak(
+        &mut self,
+        loop_left_section: &impl SectionPressure,
+        loop_right_section: &impl SectionPressure,
+    ) {
+        self.internal_leak_flow_left = self.internal_leak_flow(loop_left_section);
+        self.internal_leak_flow_right = self.internal_leak_flow(loop_right_section);
+    }
+
+    fn internal_leak_flow(&self, section: &impl SectionPressure) -> VolumeRate {
+        if self.is_enabled && self.internal_leakage_failure.is_active() {
+            VolumeRate::new::<gallon_per_second>(
+                Self::FAILURE_INTERNAL_LEAK_GAL_P_S
+                    * (section.pressure_downstream_priority_valve().get::<psi>() - 14.7).max(0.)
+                    / Self::INTERNAL_LEAK_REFERENCE_PRESSURE_PSI,
+            )
+        } else {
+            VolumeRate::default()
+        }
+    }
+
+    fn current_efficiency(&self) -> Ratio {
+        if self.shaft_shear_failure.is_active() {
+            Ratio::default()
+        } else if self.internal_leakage_failure.is_active() {
+            self.efficiency * Self::INTERNAL_LEAKAGE_FAILURE_EFFICIENCY_FACTOR
+        } else {
+            self.efficiency
+        }
     }
 
     fn calc_generated_torque(pressure: Pressure, displacement: Volume) -> Torque {
@@ -658,17 +966,18 @@ impl PowerTransferUnit {
 
     fn update_flows(&mut self) {
         let shaft_rpm = self.shaft_speed.get::<revolution_per_minute>();
+        let efficiency = self.current_efficiency();
 
         if shaft_rpm < -Self::MIN_SPEED_SIMULATION_RPM {
             // Left sends flow to right
             let flow = Self::calc_flow(self.shaft_speed.abs(), self.left_displacement);
             self.flow_to_left = -flow;
-            self.flow_to_right = flow * self.efficiency;
+            self.flow_to_right = flow * efficiency;
             self.last_flow = flow;
         } else if shaft_rpm > Self::MIN_SPEED_SIMULATION_RPM {
             // Right sends flow to left
             let flow = Self::calc_flow(self.shaft_speed.abs(), self.right_displacement.output());
-            self.flow_to_left = flow * self.efficiency;
+            self.flow_to_left = flow * efficiency;
             self.flow_to_right = -flow;
             self.last_flow = flow;
         } else {
@@ -676,6 +985,9 @@ impl PowerTransferUnit {
             self.flow_to_right = VolumeRate::new::<gallon_per_second>(0.);
             self.last_flow = VolumeRate::new::<gallon_per_second>(0.);
         }
+
+        self.flow_to_left -= self.internal_leak_flow_left;
+        self.flow_to_right -= self.internal_leak_flow_right;
     }
 
     fn calc_flow(speed: AngularVelocity, displacement: Volume) -> VolumeRate {
@@ -716,6 +1028,15 @@ impl PowerTransferUnit {
     }
 }
 impl SimulationElement for PowerTransferUnit {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.shaft_shear_failure.accept(visitor);
+        self.internal_leakage_failure.accept(visitor);
+        self.jam_failure.accept(visitor);
+        self.bark_threshold_drift_failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
     fn write(&self, writer: &mut SimulatorWriter) {
         writer.write(&self.valve_opened_id, self.is_enabled());
         writer.write(
@@ -748,10 +1069,33 @@ impl SimulationElement for PowerTransferUnit {
             self.efficiency = Ratio::new::<ratio>(efficiency_raw);
         }
 
+        // Wear has its own flag, as a forced wear of zero is a valid value
+        let wear_is_forced: bool = reader.read(&self.dev_wear_is_forced_id);
+        if wear_is_forced {
+            let wear_raw: f64 = reader.read(&self.dev_wear_id);
+            self.set_wear(Ratio::new::<ratio>(wear_raw));
+        }
+
         // As read/write can happen slower than ptu update, if we had ptu stopping between two writes
         // we ensure here to reset the flag indicating we missed a stop
         self.has_stopped_since_last_write = false;
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.efficiency);
+        snapshot.write(self.activation_delta_pressure);
+        snapshot.write(self.deactivation_delta_pressure);
+        snapshot.write(self.wear);
+        snapshot.write(self.threshold_drift.output());
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.efficiency = snapshot.read();
+        self.activation_delta_pressure = snapshot.read();
+        self.deactivation_delta_pressure = snapshot.read();
+        self.set_wear(snapshot.read());
+        self.threshold_drift.reset(snapshot.read());
+    }
 }
 impl HeatingElement for PowerTransferUnit {
     fn is_overheating(&self) -> bool {
@@ -943,6 +1287,22 @@ impl Accumulator {
         gas_precharge * total_volume / (total_volume - current_volume)
     }
 
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.current_gas_init_precharge);
+        snapshot.write(self.gas_pressure);
+        snapshot.write(self.gas_volume);
+        snapshot.write(self.fluid_volume);
+        snapshot.write(self.current_flow);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.current_gas_init_precharge = snapshot.read();
+        self.gas_pressure = snapshot.read();
+        self.gas_volume = snapshot.read();
+        self.fluid_volume = snapshot.read();
+        self.current_flow = snapshot.read();
+    }
+
     #[cfg(test)]
     fn total_volume(&self) -> Volume {
         self.total_volume
@@ -1109,7 +1469,11 @@ impl HydraulicCircuit {
             },
             pump_sections_check_valves: pump_to_system_check_valves,
             pump_section_routed_to_auxiliary_section: pump_section_to_auxiliary,
-            fluid: Fluid::new(Pressure::new::<pascal>(Self::FLUID_BULK_MODULUS_PASCAL)),
+            fluid: Fluid::new(
+                context,
+                id,
+                Pressure::new::<pascal>(Self::FLUID_BULK_MODULUS_PASCAL),
+            ),
             reservoir,
             circuit_target_pressure,
         }
@@ -1120,11 +1484,13 @@ impl HydraulicCircuit {
     }
 
     pub fn update_system_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
+        actuator.set_fluid_flow_ratio(self.fluid.actuator_flow_ratio());
         self.system_section.update_actuator_volumes(actuator);
     }
 
     pub fn update_auxiliary_actuator_volumes(&mut self, actuator: &mut impl Actuator) {
         if let Some(auxiliary_section) = self.auxiliary_section.as_mut() {
+            actuator.set_fluid_flow_ratio(self.fluid.actuator_flow_ratio());
             auxiliary_section.update_actuator_volumes(actuator);
         } else {
             panic!("No auxiliary section available but an actuator was provided")
@@ -1162,8 +1528,20 @@ impl HydraulicCircuit {
 
         let ptu_overheats_fluid = ptu.map_or(false, |p| p.is_overheating() && p.is_rotating());
 
-        self.fluid
-            .update(context, ptu_overheats_fluid || any_pump_is_overheating);
+        let (heat_power, pumped_volume) = self.fluid_heat_power_and_pumped_volume(
+            context,
+            main_section_pumps,
+            &system_section_pump,
+            &auxiliary_section_pump,
+            ptu,
+        );
+
+        self.fluid.update(
+            context,
+            ptu_overheats_fluid || any_pump_is_overheating,
+            heat_power,
+            pumped_volume,
+        );
 
         self.reservoir
             .update(context, reservoir_pressure, &self.fluid);
@@ -1207,6 +1585,56 @@ impl HydraulicCircuit {
         self.update_final_delta_vol_and_pressure(context);
     }
 
+    /// Heat brought to the fluid by the hydraulic power of each pump, and by ptu losses which are shared
+    /// between both circuits it connects, along with the total volume pumped in the circuit.
+    fn fluid_heat_power_and_pumped_volume(
+        &self,
+        context: &UpdateContext,
+        main_section_pumps: &[&mut dyn HeatingPressureSource],
+        system_section_pump: &Option<&mut impl HeatingPressureSource>,
+        auxiliary_section_pump: &Option<&mut impl HeatingPressureSource>,
+        ptu: Option<&PowerTransferUnit>,
+    ) -> (Power, Volume) {
+        let mut heat_power = Power::default();
+        let mut pumped_flow = VolumeRate::default();
+
+        for (pump_index, pump) in main_section_pumps.iter().enumerate() {
+            heat_power += Self::hydraulic_power(pump.flow(), &self.pump_sections[pump_index]);
+            pumped_flow += pump.flow();
+        }
+
+        if let Some(pump) = system_section_pump {
+            heat_power += Self::hydraulic_power(pump.flow(), &self.system_section);
+            pumped_flow += pump.flow();
+        }
+
+        if let Some(pump) = auxiliary_section_pump {
+            heat_power += Self::hydraulic_power(
+                pump.flow(),
+                self.auxiliary_section
+                    .as_ref()
+                    .unwrap_or(&self.system_section),
+            );
+            pumped_flow += pump.flow();
+        }
+
+        if let Some(ptu) = ptu {
+            if self.system_section.connected_to_ptu_left_side
+                || self.system_section.connected_to_ptu_right_side
+            {
+                heat_power += Self::hydraulic_power(ptu.flow(), &self.system_section)
+                    * (Ratio::new::<ratio>(1.) - ptu.current_efficiency())
+                    * 0.5;
+            }
+        }
+
+        (heat_power, pumped_flow * context.delta_as_time())
+    }
+
+    fn hydraulic_power(flow: VolumeRate, section: &Section) -> Power {
+        flow * (section.pressure() - Pressure::new::<psi>(14.7)).max(Pressure::default())
+    }
+
     fn update_delta_vol_from_valves(&mut self) {
         for (pump_index, section) in self.pump_sections.iter_mut().enumerate() {
             section.update_downstream_delta_vol(&self.pump_sections_check_valves[pump_index]);
@@ -1238,18 +1666,34 @@ impl HydraulicCircuit {
         system_section_pump: Option<&mut impl HeatingPressureSource>,
         auxiliary_section_pump: Option<&mut impl HeatingPressureSource>,
     ) {
+        let delivery_efficiency = self.fluid.pump_delivery_efficiency();
+
         for (pump_index, section) in self.pump_sections.iter_mut().enumerate() {
-            section.update_pump_state(context, main_section_pumps[pump_index], &mut self.reservoir);
+            section.update_pump_state(
+                context,
+                main_section_pumps[pump_index],
+                &mut self.reservoir,
+                delivery_efficiency,
+            );
         }
 
         if let Some(pump) = system_section_pump {
-            self.system_section
-                .update_pump_state(context, pump, &mut self.reservoir);
+            self.system_section.update_pump_state(
+                context,
+                pump,
+                &mut self.reservoir,
+                delivery_efficiency,
+            );
         }
 
         if let Some(pump) = auxiliary_section_pump {
             if let Some(auxiliary_section) = self.auxiliary_section.as_mut() {
-                auxiliary_section.update_pump_state(context, pump, &mut self.reservoir);
+                auxiliary_section.update_pump_state(
+                    context,
+                    pump,
+                    &mut self.reservoir,
+                    delivery_efficiency,
+                );
             }
         }
     }
@@ -1290,17 +1734,23 @@ impl HydraulicCircuit {
         system_section_pump: &Option<&mut impl HeatingPressureSource>,
         auxiliary_section_pump: &Option<&mut impl HeatingPressureSource>,
     ) {
+        let delivery_efficiency = self.fluid.pump_delivery_efficiency();
+
         for (pump_index, section) in self.pump_sections.iter_mut().enumerate() {
-            section.update_maximum_pumping_capacity(main_section_pumps[pump_index]);
+            section.update_maximum_pumping_capacity(
+                main_section_pumps[pump_index],
+                delivery_efficiency,
+            );
         }
 
         if let Some(pump) = system_section_pump {
-            self.system_section.update_maximum_pumping_capacity(*pump);
+            self.system_section
+                .update_maximum_pumping_capacity(*pump, delivery_efficiency);
         }
 
         if let Some(pump) = auxiliary_section_pump {
             if let Some(auxiliary_section) = self.auxiliary_section.as_mut() {
-                auxiliary_section.update_maximum_pumping_capacity(*pump);
+                auxiliary_section.update_maximum_pumping_capacity(*pump, delivery_efficiency);
             }
         }
     }
@@ -1438,6 +1888,10 @@ impl HydraulicCircuit {
         &self.reservoir
     }
 
+    pub fn fluid(&self) -> &Fluid {
+        &self.fluid
+    }
+
     pub fn system_section_pressure(&self) -> Pressure {
         self.system_section.pressure()
     }
@@ -1460,6 +1914,7 @@ impl HydraulicCircuit {
 }
 impl SimulationElement for HydraulicCircuit {
     fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.fluid.accept(visitor);
         self.reservoir.accept(visitor);
 
         for section in &mut self.pump_sections {
@@ -1526,6 +1981,8 @@ pub struct Section {
     total_actuator_returned_volume: Volume,
 }
 impl Section {
+    const MIN_DELIVERY_EFFICIENCY: f64 = 0.1;
+
     pub fn new(
         context: &mut InitContext,
         loop_id: HydraulicColor,
@@ -1682,9 +2139,13 @@ impl Section {
         self.total_actuator_consumed_volume = Volume::new::<gallon>(0.);
     }
 
-    pub fn update_maximum_pumping_capacity(&mut self, pump: &dyn HeatingPressureSource) {
+    pub fn update_maximum_pumping_capacity(
+        &mut self,
+        pump: &dyn HeatingPressureSource,
+        delivery_efficiency: Ratio,
+    ) {
         self.max_pumpable_volume = if self.fire_valve_is_open() {
-            pump.delta_vol_max()
+            pump.delta_vol_max() * delivery_efficiency
         } else {
             Volume::new::<gallon>(0.)
         }
@@ -1705,19 +2166,25 @@ impl Section {
         context: &UpdateContext,
         pump: &mut dyn HeatingPressureSource,
         reservoir: &mut Reservoir,
+        delivery_efficiency: Ratio,
     ) {
         // Final volume target to reach target pressure is:
         // raw volume_target - (upstream volume - downstream volume)
         let final_volume_needed_to_reach_target_pressure =
             self.volume_target - self.delta_vol_from_valves;
 
+        // Pump has to produce more to compensate for what it loses in its case drain
         pump.update_after_pressure_regulation(
             context,
-            final_volume_needed_to_reach_target_pressure,
+            final_volume_needed_to_reach_target_pressure
+                / delivery_efficiency.max(Ratio::new::<ratio>(Self::MIN_DELIVERY_EFFICIENCY)),
             reservoir,
             self.fire_valve_is_open(),
         );
-        self.total_volume_pumped = pump.flow() * context.delta_as_time();
+
+        let volume_produced = pump.flow() * context.delta_as_time();
+        self.total_volume_pumped = volume_produced * delivery_efficiency;
+        reservoir.add_return_volume(volume_produced - self.total_volume_pumped);
     }
 
     pub fn update_final_delta_vol_and_pressure(&mut self, context: &UpdateContext, fluid: &Fluid) {
@@ -1823,6 +2290,26 @@ impl SimulationElement for Section {
             self.pressure_switch_state() == PressureSwitchState::Pressurised,
         );
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.current_volume);
+        snapshot.write(self.current_pressure);
+        snapshot.write(self.current_flow);
+
+        if let Some(accumulator) = &self.accumulator {
+            accumulator.snapshot(snapshot);
+        }
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.current_volume = snapshot.read();
+        self.current_pressure = snapshot.read();
+        self.current_flow = snapshot.read();
+
+        if let Some(accumulator) = &mut self.accumulator {
+            accumulator.restore(snapshot);
+        }
+    }
 }
 impl SectionPressure for Section {
     fn pressure(&self) -> Pressure {
@@ -2495,6 +2982,14 @@ impl SimulationElement for Reservoir {
         writer.write(&self.low_air_press_id, self.is_low_air_pressure());
         writer.write(&self.overheating_id, self.is_overheating());
     }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.current_level);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.current_level = snapshot.read();
+    }
 }
 impl PressurizeableReservoir for Reservoir {
     fn available_volume(&self) -> Volume {
@@ -3112,9 +3607,9 @@ impl HeatingPressureSource for RamAirTurbine {}
 #[cfg(test)]
 mod tests {
     use crate::simulation::test::{
-        ElementCtorFn, ReadByName, SimulationTestBed, TestBed, WriteByName,
+        ElementCtorFn, ReadByName, SimulationTestBed, TestAircraft, TestBed, WriteByName,
     };
-    use crate::simulation::InitContext;
+    use crate::simulation::{InitContext, StartState};
     use ntest::assert_about_eq;
 
     use uom::si::{f64::*, pressure::psi, ratio::percent, volume::gallon};
@@ -3175,6 +3670,123 @@ mod tests {
 
         assert!(!test_bed.contains_variable_with_name("HYD_GREEN_PUMP_3_SECTION_PRESSURE"));
         assert!(!test_bed.contains_variable_with_name("HYD_GREEN_PUMP_3_FIRE_VALVE_OPENED"));
+
+        assert!(test_bed.contains_variable_with_name("HYD_GREEN_FLUID_TEMPERATURE"));
+        assert!(test_bed.contains_variable_with_name("HYD_GREEN_FILTER_CLOGGED"));
+    }
+
+    #[test]
+    fn fluid_starts_at_operating_temperature_with_engines_running() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(StartState::Cruise, |context| {
+            TestAircraft::new(fluid(context))
+        });
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, false, Power::default(), Volume::default())
+        });
+        test_bed.run();
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_FLUID_TEMPERATURE");
+        assert!(temperature.get::<degree_celsius>() > 40.);
+        assert!(test_bed.query_element(|f| f.pump_delivery_efficiency().get::<ratio>() == 1.));
+        assert!(test_bed.query_element(|f| f.actuator_flow_ratio().get::<ratio>() == 1.));
+    }
+
+    #[test]
+    fn cold_soaked_fluid_reduces_pump_delivery_and_actuator_flow() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(StartState::Apron, |context| {
+            TestAircraft::new(fluid(context))
+        });
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-40.));
+
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, false, Power::default(), Volume::default())
+        });
+        test_bed.run();
+
+        let temperature: ThermodynamicTemperature =
+            test_bed.read_by_name("HYD_GREEN_FLUID_TEMPERATURE");
+        assert_about_eq!(temperature.get::<degree_celsius>(), -40., 0.1);
+        assert!(test_bed.query_element(|f| f.pump_delivery_efficiency().get::<ratio>() < 0.8));
+        assert!(test_bed.query_element(|f| f.actuator_flow_ratio().get::<ratio>() < 0.8));
+    }
+
+    #[test]
+    fn fluid_heats_up_with_pump_work_and_settles_above_ambient() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(StartState::Apron, |context| {
+            TestAircraft::new(fluid(context))
+        });
+
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, false, Power::new::<watt>(3000.), Volume::default())
+        });
+
+        test_bed.run_multiple_frames(Duration::from_secs(60 * 10));
+        let temperature_after_10_min = test_bed.query_element(|f| f.temperature());
+        assert!(temperature_after_10_min.get::<degree_celsius>() > 10.);
+
+        test_bed.run_multiple_frames(Duration::from_secs(60 * 60 * 3));
+        let temperature_after_3_hours = test_bed.query_element(|f| f.temperature());
+        assert!(temperature_after_3_hours > temperature_after_10_min);
+        assert!(temperature_after_3_hours.get::<degree_celsius>() < 60.);
+        assert!(!test_bed.query_element(|f| f.is_overheating()));
+    }
+
+    #[test]
+    fn fluid_heated_by_overheating_pump_overheats() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(StartState::Cruise, |context| {
+            TestAircraft::new(fluid(context))
+        });
+
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(context, true, Power::new::<watt>(3000.), Volume::default())
+        });
+
+        test_bed.run_multiple_frames(Duration::from_secs(60 * 10));
+
+        assert!(
+            test_bed.query_element(|f| f.temperature().get::<degree_celsius>()
+                > Fluid::OVERHEAT_TEMPERATURE_DEGREE_CELSIUS)
+        );
+        assert!(test_bed.query_element(|f| f.is_overheating()));
+    }
+
+    #[test]
+    fn contaminated_fluid_clogs_filter_and_reduces_pump_delivery() {
+        let mut test_bed = SimulationTestBed::new_with_start_state(StartState::Cruise, |context| {
+            TestAircraft::new(fluid(context))
+        });
+
+        test_bed.set_update_after_power_distribution(|fluid, context| {
+            fluid.update(
+                context,
+                false,
+                Power::default(),
+                VolumeRate::new::<gallon_per_second>(1.) * context.delta_as_time(),
+            )
+        });
+
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+        assert!(!test_bed.query_element(|f| f.is_filter_clogged()));
+
+        test_bed.fail(FailureType::HydraulicFluidContamination(
+            HydraulicColor::Green,
+        ));
+        test_bed.run_multiple_frames(Duration::from_secs(60));
+
+        let is_clogged: bool = test_bed.read_by_name("HYD_GREEN_FILTER_CLOGGED");
+        assert!(is_clogged);
+        assert!(test_bed.query_element(|f| f.pump_delivery_efficiency().get::<ratio>() < 0.9));
+
+        test_bed.unfail(FailureType::HydraulicFluidContamination(
+            HydraulicColor::Green,
+        ));
+        test_bed.run();
+
+        assert!(!test_bed.query_element(|f| f.is_filter_clogged()));
+        assert!(test_bed.query_element(|f| f.pump_delivery_efficiency().get::<ratio>() == 1.));
     }
 
     #[test]
@@ -3555,6 +4167,14 @@ mod tests {
         )
     }
 
+    fn fluid(context: &mut InitContext) -> Fluid {
+        Fluid::new(
+            context,
+            HydraulicColor::Green,
+            Pressure::new::<pascal>(HydraulicCircuit::FLUID_BULK_MODULUS_PASCAL),
+        )
+    }
+
     fn reservoir(
         context: &mut InitContext,
         hyd_loop_id: HydraulicColor,
@@ -3635,4 +4255,232 @@ mod tests {
             assert!(test_bed.query_element(|e| !e.is_active));
         }
     }
+
+    #[cfg(test)]
+    mod ptu_tests {
+        use super::*;
+
+        use crate::simulation::test::{ElementCtorFn, SimulationTestBed};
+
+        struct TestPtuCharacteristics {
+            wear: Ratio,
+        }
+        impl PowerTransferUnitCharacteristics for TestPtuCharacteristics {
+            fn efficiency(&self) -> Ratio {
+                Ratio::new::<ratio>(0.85)
+            }
+
+            fn deactivation_delta_pressure(&self) -> Pressure {
+                Pressure::new::<psi>(90.)
+            }
+
+            fn activation_delta_pressure(&self) -> Pressure {
+                Pressure::new::<psi>(500.)
+            }
+
+            fn shot_to_shot_variability(&self) -> Ratio {
+                Ratio::new::<ratio>(0.05)
+            }
+
+            fn wear(&self) -> Ratio {
+                self.wear
+            }
+        }
+
+        struct TestPtuController;
+        impl PowerTransferUnitController for TestPtuController {
+            fn should_enable(&self) -> bool {
+                true
+            }
+        }
+
+        struct TestHydraulicSection {
+            pressure: Pressure,
+        }
+        impl TestHydraulicSection {
+            fn new(pressure_psi: f64) -> Self {
+                Self {
+                    pressure: Pressure::new::<psi>(pressure_psi),
+                }
+            }
+        }
+        impl SectionPressure for TestHydraulicSection {
+            fn pressure(&self) -> Pressure {
+                self.pressure
+            }
+
+            fn pressure_downstream_leak_valve(&self) -> Pressure {
+                self.pressure
+            }
+
+            fn pressure_downstream_priority_valve(&self) -> Pressure {
+                self.pressure
+            }
+
+            fn is_pressure_switch_pressurised(&self) -> bool {
+                self.pressure.get::<psi>() > 1700.
+            }
+        }
+
+        fn test_bed_with_pressures(
+            left_pressure_psi: f64,
+            right_pressure_psi: f64,
+        ) -> SimulationTestBed<TestAircraft<PowerTransferUnit>> {
+            let mut test_bed = SimulationTestBed::from(ElementCtorFn(|context| {
+                PowerTransferUnit::new(
+                    context,
+                    &TestPtuCharacteristics {
+                        wear: Ratio::default(),
+                    },
+                )
+            }));
+
+            test_bed.set_update_after_power_distribution(move |ptu, context| {
+                ptu.update(
+                    context,
+                    &TestHydraulicSection::new(left_pressure_psi),
+                    &TestHydraulicSection::new(right_pressure_psi),
+                    &TestPtuController,
+                )
+            });
+
+            test_bed
+        }
+
+        // Ptu is updated at the hydraulic simulation time step of the aircraft
+        fn run(test_bed: &mut SimulationTestBed<TestAircraft<PowerTransferUnit>>, delta: Duration) {
+            for _ in 0..delta.as_millis() / 10 {
+                test_bed.run_with_delta(Duration::from_millis(10));
+            }
+        }
+
+        #[test]
+        fn ptu_transfers_flow_to_unpressurised_side() {
+            let mut test_bed = test_bed_with_pressures(3000., 1000.);
+
+            run(&mut test_bed, Duration::from_secs(2));
+
+            assert!(test_bed.query_element(|p| p.is_active_left_to_right()));
+            assert!(test_bed.query_element(|p| p.flow_to_right.get::<gallon_per_second>() > 0.));
+        }
+
+        #[test]
+        fn jammed_ptu_does_not_rotate() {
+            let mut test_bed = test_bed_with_pressures(3000., 1000.);
+            test_bed.fail(FailureType::PtuJam);
+
+            run(&mut test_bed, Duration::from_secs(2));
+
+            assert!(test_bed.query_element(|p| !p.is_rotating()));
+            assert!(test_bed.query_element(|p| p.flow().get::<gallon_per_second>() == 0.));
+        }
+
+        #[test]
+        fn ptu_with_sheared_shaft_spins_without_delivering_flow() {
+            let mut test_bed = test_bed_with_pressures(3000., 1000.);
+            test_bed.fail(FailureType::PtuShaftShear);
+
+            run(&mut test_bed, Duration::from_secs(2));
+
+            assert!(test_bed.query_element(|p| p.is_rotating()));
+            assert!(test_bed.query_element(|p| p.flow_to_left.get::<gallon_per_second>() < 0.));
+            assert!(test_bed.query_element(|p| p.flow_to_right.get::<gallon_per_second>() <= 0.));
+        }
+
+        #[test]
+        fn ptu_with_internal_leakage_leaks_from_both_pressurised_sides() {
+            let mut test_bed = test_bed_with_pressures(3000., 3000.);
+
+            run(&mut test_bed, Duration::from_secs(2));
+            assert!(test_bed.query_element(|p| p.flow_to_left.get::<gallon_per_second>() == 0.));
+
+            test_bed.fail(FailureType::PtuInternalLeakage);
+            run(&mut test_bed, Duration::from_secs(2));
+
+            assert!(test_bed.query_element(|p| !p.is_rotating()));
+            assert!(test_bed.query_element(|p| p.flow_to_left.get::<gallon_per_second>() < 0.));
+            assert!(test_bed.query_element(|p| p.flow_to_right.get::<gallon_per_second>() < 0.));
+        }
+
+        #[test]
+        fn ptu_with_drifting_threshold_activates_on_small_pressure_difference() {
+            let mut test_bed = test_bed_with_pressures(3000., 2700.);
+
+            run(&mut test_bed, Duration::from_secs(2));
+            assert!(test_bed.query_element(|p| !p.control_valve_opened));
+
+            test_bed.fail(FailureType::PtuBarkThresholdDrift);
+            run(&mut test_bed, Duration::from_secs(60 * 3));
+
+            assert!(test_bed.query_element(|p| p.control_valve_opened));
+        }
+
+        #[test]
+        fn worn_ptu_has_slower_displacement_response() {
+            assert_eq!(
+                PowerTransferUnit::displacement_time_constant(Ratio::default()),
+                PowerTransferUnit::DISPLACEMENT_TIME_CONSTANT
+            );
+            assert!(
+                PowerTransferUnit::displacement_time_constant(Ratio::new::<ratio>(1.))
+                    > PowerTransferUnit::DISPLACEMENT_TIME_CONSTANT
+            );
+        }
+
+        #[test]
+        fn ptu_wear_can_be_forced() {
+            let mut test_bed = test_bed_with_pressures(3000., 3000.);
+            test_bed.write_by_name("HYD_PTU_DEV_WEAR_IS_FORCED", true);
+            test_bed.write_by_name("HYD_PTU_DEV_WEAR", 0.8);
+
+            run(&mut test_bed, Duration::from_millis(100));
+
+            assert_about_eq!(test_bed.query_element(|p| p.wear.get::<ratio>()), 0.8);
+        }
+
+        #[test]
+        fn ptu_wear_can_be_forced_to_zero() {
+            let mut test_bed = test_bed_with_pressures(3000., 3000.);
+            test_bed.write_by_name("HYD_PTU_DEV_WEAR_IS_FORCED", true);
+            test_bed.write_by_name("HYD_PTU_DEV_WEAR", 0.8);
+            run(&mut test_bed, Duration::from_millis(100));
+
+            test_bed.write_by_name("HYD_PTU_DEV_WEAR", 0.);
+            run(&mut test_bed, Duration::from_millis(100));
+
+            assert_about_eq!(test_bed.query_element(|p| p.wear.get::<ratio>()), 0.);
+        }
+
+        #[test]
+        fn ptu_wear_isnt_forced_without_the_flag() {
+            let mut test_bed = test_bed_with_pressures(3000., 3000.);
+            let wear = test_bed.query_element(|p| p.wear.get::<ratio>());
+            test_bed.write_by_name("HYD_PTU_DEV_WEAR", 0.8);
+
+            run(&mut test_bed, Duration::from_millis(100));
+
+            assert_about_eq!(test_bed.query_element(|p| p.wear.get::<ratio>()), wear);
+        }
+
+        #[test]
+        fn ptu_wear_and_threshold_drift_are_restored_from_snapshot() {
+            let mut worn = test_bed_with_pressures(3000., 2700.);
+            worn.write_by_name("HYD_PTU_DEV_WEAR_IS_FORCED", true);
+            worn.write_by_name("HYD_PTU_DEV_WEAR", 0.8);
+            worn.fail(FailureType::PtuBarkThresholdDrift);
+            run(&mut worn, Duration::from_secs(30));
+
+            let drift = worn.query_element(|p| p.threshold_drift.output().get::<ratio>());
+            assert!(drift < 1.);
+
+            let mut test_bed = test_bed_with_pressures(3000., 2700.);
+            test_bed.restore(&worn.snapshot()).unwrap();
+
+            assert_about_eq!(test_bed.query_element(|p| p.wear.get::<ratio>()), 0.8);
+            assert_about_eq!(
+                test_bed.query_element(|p| p.threshold_drift.output().get::<ratio>()),
+                drift
+            );
+        }
+    }
 }
@@ -119,13 +119,14 @@ impl SteeringActuator {
 
     pub fn new(
         context: &mut InitContext,
+        name: &str,
         max_half_angle: Angle,
         nominal_speed: AngularVelocity,
         actuator_diameter: Length,
         angular_to_linear_ratio: Ratio,
     ) -> Self {
         Self {
-            position_id: context.get_identifier("NOSE_WHEEL_POSITION_RATIO".to_owned()),
+            position_id: context.get_identifier(format!("{}_POSITION_RATIO", name)),
 
             current_speed: LowPassFilter::<AngularVelocity>::new(
                 Self::CURRENT_SPEED_FILTER_TIMECONST,
@@ -155,21 +156,31 @@ impl SteeringActuator {
         pushback_tug: &impl Pushback,
     ) {
         if !pushback_tug.is_nose_wheel_steering_pin_inserted() {
-            self.update_max_speed(context, section_pressure);
-
-            let limited_requested_angle = steering_controller
-                .requested_position()
-                .min(self.max_half_angle)
-                .max(-self.max_half_angle);
-
-            self.update_current_speed(context, limited_requested_angle);
-
-            self.update_final_speed_position(context, limited_requested_angle);
+            self.update_hydraulic_steering(context, section_pressure, steering_controller);
         } else {
             self.update_speed_position_during_pushback(pushback_tug);
         }
+    }
+
+    /// Updates the actuator moved by hydraulic pressure only, for steering that a pushback tug never drives
+    pub fn update_hydraulic_steering(
+        &mut self,
+        context: &UpdateContext,
+        section_pressure: &impl SectionPressure,
+        steering_controller: &impl SteeringController,
+    ) {
+        self.update_max_speed(context, section_pressure);
 
-        self.update_flow(context, pushback_tug);
+        let limited_requested_angle = steering_controller
+            .requested_position()
+            .min(self.max_half_angle)
+            .max(-self.max_half_angle);
+
+        self.update_current_speed(context, limited_requested_angle);
+
+        self.update_final_speed_position(context, limited_requested_angle);
+
+        self.update_flow(context);
     }
 
     fn update_final_speed_position(&mut self, context: &UpdateContext, requested_angle: Angle) {
@@ -232,21 +243,19 @@ impl SteeringActuator {
         self.max_speed.update(context.delta(), new_max_speed);
     }
 
-    fn update_flow(&mut self, context: &UpdateContext, pushback_tug: &impl Pushback) {
-        if !pushback_tug.is_nose_wheel_steering_pin_inserted() {
-            let angular_position_delta_abs = Angle::new::<radian>(
-                self.current_speed.output().get::<radian_per_second>().abs()
-                    * context.delta_as_secs_f64(),
-            );
+    fn update_flow(&mut self, context: &UpdateContext) {
+        let angular_position_delta_abs = Angle::new::<radian>(
+            self.current_speed.output().get::<radian_per_second>().abs()
+                * context.delta_as_secs_f64(),
+        );
 
-            let linear_position_delta = Length::new::<meter>(
-                angular_position_delta_abs.get::<radian>()
-                    * self.angular_to_linear_ratio.get::<ratio>(),
-            );
+        let linear_position_delta = Length::new::<meter>(
+            angular_position_delta_abs.get::<radian>()
+                * self.angular_to_linear_ratio.get::<ratio>(),
+        );
 
-            self.total_volume_to_actuator = linear_position_delta * self.actuator_area;
-            self.total_volume_to_reservoir = linear_position_delta * self.actuator_area;
-        }
+        self.total_volume_to_actuator = linear_position_delta * self.actuator_area;
+        self.total_volume_to_reservoir = linear_position_delta * self.actuator_area;
     }
 
     pub fn position_feedback(&self) -> Angle {
@@ -667,6 +676,7 @@ mod tests {
     fn steering_actuator(context: &mut InitContext) -> SteeringActuator {
         SteeringActuator::new(
             context,
+            "NOSE_WHEEL",
             Angle::new::<degree>(75.),
             AngularVelocity::new::<radian_per_second>(0.35),
             Length::new::<meter>(0.05),
@@ -0,0 +1,751 @@
+use crate::simulation::{
+    InitContext, Read, Reader, SimulationElement, SimulatorReader, SimulatorWriter, UpdateContext,
+    VariableIdentifier, Write, Writer,
+};
+
+use uom::si::{
+    acceleration::meter_per_second_squared, angle::radian, f64::*, force::newton, length::foot,
+    length::meter, mass::kilogram, ratio::ratio, velocity::meter_per_second,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunwayCondition {
+    Dry = 0,
+    Wet = 1,
+    Contaminated = 2,
+}
+read_write_enum!(RunwayCondition);
+impl From<f64> for RunwayCondition {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => RunwayCondition::Wet,
+            2 => RunwayCondition::Contaminated,
+            _ => RunwayCondition::Dry,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunwayOverrunState {
+    None = 0,
+    IfWetRunwayTooShort = 1,
+    RunwayTooShort = 2,
+}
+read_write_enum!(RunwayOverrunState);
+impl From<f64> for RunwayOverrunState {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => RunwayOverrunState::IfWetRunwayTooShort,
+            2 => RunwayOverrunState::RunwayTooShort,
+            _ => RunwayOverrunState::None,
+        }
+    }
+}
+
+/// Runway overrun warning (ROW) and protection (ROP).
+///
+/// In flight, the prediction is armed on final approach. The landing distance from the threshold of
+/// the runway supplied by the flight management is predicted from the approach speed corrected for wind,
+/// the deceleration of the armed autobrake mode, the braking capability at the current weight and the
+/// runway condition. The approach speed computed by the flight management is used when available,
+/// the current true airspeed otherwise.
+/// On ground, the stopping distance is predicted from the position on the runway and the ground speed.
+/// When it exceeds the runway length while the autobrake is braking, max braking is commanded.
+pub struct RunwayOverrunProtection {
+    runway_length_id: VariableIdentifier,
+    threshold_latitude_id: VariableIdentifier,
+    threshold_longitude_id: VariableIdentifier,
+    runway_heading_id: VariableIdentifier,
+    runway_condition_id: VariableIdentifier,
+    latitude_id: VariableIdentifier,
+    longitude_id: VariableIdentifier,
+    gross_weight_id: VariableIdentifier,
+    radio_altitude_id: VariableIdentifier,
+    approach_speed_id: VariableIdentifier,
+
+    state_id: VariableIdentifier,
+    predicted_stopping_distance_id: VariableIdentifier,
+    rop_active_id: VariableIdentifier,
+
+    max_braking_force: Force,
+
+    runway_length: Length,
+    threshold_latitude: Angle,
+    threshold_longitude: Angle,
+    runway_heading: Angle,
+    runway_condition: RunwayCondition,
+    latitude: Angle,
+    longitude: Angle,
+    gross_weight: Mass,
+    radio_altitude: Length,
+    approach_speed: Velocity,
+
+    predicted_stopping_distance: Length,
+    state: RunwayOverrunState,
+    rop_is_active: bool,
+}
+impl RunwayOverrunProtection {
+    const EARTH_RADIUS_METERS: f64 = 6_371_000.;
+
+    // The in flight prediction is armed below this height on final approach
+    const ARMING_RADIO_ALTITUDE_FEET: f64 = 500.;
+
+    // Distance from crossing the threshold at 50ft to touchdown
+    const AIR_DISTANCE_METERS: f64 = 450.;
+
+    // Regulatory wind correction: only half the headwind and one and a half times the tailwind are accounted
+    const HEADWIND_FACTOR: f64 = 0.5;
+    const TAILWIND_FACTOR: f64 = 1.5;
+
+    const DRY_RUNWAY_MAX_DECELERATION_MS2: f64 = 4.;
+    const WET_RUNWAY_MAX_DECELERATION_MS2: f64 = 2.5;
+    const CONTAMINATED_RUNWAY_MAX_DECELERATION_MS2: f64 = 1.5;
+
+    pub fn new(context: &mut InitContext, max_braking_force: Force) -> Self {
+        Self {
+            runway_length_id: context.get_identifier("ROW_ROP_RUNWAY_LENGTH".to_owned()),
+            threshold_latitude_id: context.get_identifier("ROW_ROP_THRESHOLD_LATITUDE".to_owned()),
+            threshold_longitude_id: context
+                .get_identifier("ROW_ROP_THRESHOLD_LONGITUDE".to_owned()),
+            runway_heading_id: context.get_identifier("ROW_ROP_RUNWAY_HEADING".to_owned()),
+            runway_condition_id: context.get_identifier("ROW_ROP_RUNWAY_CONDITION".to_owned()),
+            latitude_id: context.get_identifier("PLANE LATITUDE".to_owned()),
+            longitude_id: context.get_identifier("PLANE LONGITUDE".to_owned()),
+            gross_weight_id: context.get_identifier("TOTAL WEIGHT".to_owned()),
+            radio_altitude_id: context.get_identifier("PLANE ALT ABOVE GROUND".to_owned()),
+            approach_speed_id: context.get_identifier("SPEEDS_VAPP".to_owned()),
+
+            state_id: context.get_identifier("ROW_ROP_STATE".to_owned()),
+            predicted_stopping_distance_id: context
+                .get_identifier("ROW_ROP_PREDICTED_STOPPING_DISTANCE".to_owned()),
+            rop_active_id: context.get_identifier("ROP_ACTIVE".to_owned()),
+
+            max_braking_force,
+
+            runway_length: Length::default(),
+            threshold_latitude: Angle::default(),
+            threshold_longitude: Angle::default(),
+            runway_heading: Angle::default(),
+            runway_condition: RunwayCondition::Dry,
+            latitude: Angle::default(),
+            longitude: Angle::default(),
+            gross_weight: Mass::default(),
+            radio_altitude: Length::default(),
+            approach_speed: Velocity::default(),
+
+            predicted_stopping_distance: Length::default(),
+            state: RunwayOverrunState::None,
+            rop_is_active: false,
+        }
+    }
+
+    /// Updates the predictions. `autobrake_deceleration` is the steady deceleration of the armed
+    /// autobrake mode, as a negative acceleration, or `None` when braking manually.
+    /// `measured_acceleration` is the acceleration seen by the autobrake.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        ground_speed: Velocity,
+        autobrake_deceleration: Option<Acceleration>,
+        autobrake_is_braking: bool,
+        measured_acceleration: Acceleration,
+    ) {
+        if !self.runway_is_selected() {
+            self.predicted_stopping_distance = Length::default();
+            self.state = RunwayOverrunState::None;
+            self.rop_is_active = false;
+            return;
+        }
+
+        if context.is_on_ground() {
+            self.update_on_ground(
+                ground_speed,
+                autobrake_deceleration,
+                autobrake_is_braking,
+                measured_acceleration,
+            );
+        } else {
+            self.update_in_flight(context, ground_speed, autobrake_deceleration);
+        }
+    }
+
+    fn update_in_flight(
+        &mut self,
+        context: &UpdateContext,
+        ground_speed: Velocity,
+        autobrake_deceleration: Option<Acceleration>,
+    ) {
+        self.rop_is_active = false;
+
+        if !self.is_armed_in_flight() {
+            self.predicted_stopping_distance = Length::default();
+            self.state = RunwayOverrunState::None;
+            return;
+        }
+
+        let speed = Self::wind_corrected_speed(
+            self.landing_speed(context.true_airspeed()),
+            context.true_airspeed(),
+            ground_speed,
+        );
+        let air_distance = Length::new::<meter>(Self::AIR_DISTANCE_METERS);
+
+        self.predicted_stopping_distance = air_distance
+            + self.stopping_distance(
+                speed,
+                self.deceleration(autobrake_deceleration, self.runway_condition),
+            );
+        let wet_stopping_distance = air_distance
+            + self.stopping_distance(
+                speed,
+                self.deceleration(autobrake_deceleration, RunwayCondition::Wet),
+            );
+
+        self.state = if self.predicted_stopping_distance > self.runway_length {
+            RunwayOverrunState::RunwayTooShort
+        } else if self.runway_condition == RunwayCondition::Dry
+            && wet_stopping_distance > self.runway_length
+        {
+            RunwayOverrunState::IfWetRunwayTooShort
+        } else {
+            RunwayOverrunState::None
+        };
+    }
+
+    fn update_on_ground(
+        &mut self,
+        ground_speed: Velocity,
+        autobrake_deceleration: Option<Acceleration>,
+        autobrake_is_braking: bool,
+        measured_acceleration: Acceleration,
+    ) {
+        let distance_from_threshold = self.distance_from_threshold().max(Length::default());
+
+        let deceleration = self
+            .deceleration(autobrake_deceleration, self.runway_condition)
+            .max(-measured_acceleration);
+        self.predicted_stopping_distance =
+            distance_from_threshold + self.stopping_distance(ground_speed, deceleration);
+
+        let max_braking_stopping_distance = distance_from_threshold
+            + self.stopping_distance(ground_speed, self.deceleration(None, self.runway_condition));
+
+        self.rop_is_active = autobrake_is_braking
+            && (self.rop_is_active || self.predicted_stopping_distance > self.runway_length);
+
+        self.state = if max_braking_stopping_distance > self.runway_length {
+            RunwayOverrunState::RunwayTooShort
+        } else {
+            RunwayOverrunState::None
+        };
+    }
+
+    fn runway_is_selected(&self) -> bool {
+        self.runway_length > Length::default()
+    }
+
+    fn is_armed_in_flight(&self) -> bool {
+        self.radio_altitude < Length::new::<foot>(Self::ARMING_RADIO_ALTITUDE_FEET)
+    }
+
+    fn landing_speed(&self, true_airspeed: Velocity) -> Velocity {
+        if self.approach_speed > Velocity::default() {
+            self.approach_speed
+        } else {
+            true_airspeed
+        }
+    }
+
+    /// Along track distance from the runway threshold, negative before it.
+    fn distance_from_threshold(&self) -> Length {
+        let north =
+            (self.latitude - self.threshold_latitude).get::<radian>() * Self::EARTH_RADIUS_METERS;
+        let east = (self.longitude - self.threshold_longitude).get::<radian>()
+            * Self::EARTH_RADIUS_METERS
+            * self.threshold_latitude.cos().get::<ratio>();
+
+        Length::new::<meter>(
+            north * self.runway_heading.cos().get::<ratio>()
+                + east * self.runway_heading.sin().get::<ratio>(),
+        )
+    }
+
+    /// The landing speed corrected for the wind currently measured by the difference
+    /// between the true airspeed and the ground speed.
+    fn wind_corrected_speed(
+        landing_speed: Velocity,
+        true_airspeed: Velocity,
+        ground_speed: Velocity,
+    ) -> Velocity {
+        let headwind = true_airspeed - ground_speed;
+
+        landing_speed
+            - headwind
+                * if headwind > Velocity::default() {
+                    Self::HEADWIND_FACTOR
+                } else {
+                    Self::TAILWIND_FACTOR
+                }
+    }
+
+    /// Deceleration magnitude achieved on the given runway condition, limited by the
+    /// autobrake mode when one is armed and by the braking force at the current weight.
+    fn deceleration(
+        &self,
+        autobrake_deceleration: Option<Acceleration>,
+        runway_condition: RunwayCondition,
+    ) -> Acceleration {
+        let runway_limit = Acceleration::new::<meter_per_second_squared>(match runway_condition {
+            RunwayCondition::Dry => Self::DRY_RUNWAY_MAX_DECELERATION_MS2,
+            RunwayCondition::Wet => Self::WET_RUNWAY_MAX_DECELERATION_MS2,
+            RunwayCondition::Contaminated => Self::CONTAMINATED_RUNWAY_MAX_DECELERATION_MS2,
+        });
+
+        let mut deceleration = runway_limit;
+        if self.gross_weight > Mass::default() {
+            deceleration = deceleration.min(Acceleration::new::<meter_per_second_squared>(
+                self.max_braking_force.get::<newton>() / self.gross_weight.get::<kilogram>(),
+            ));
+        }
+        if let Some(autobrake_deceleration) = autobrake_deceleration {
+            deceleration = deceleration.min(-autobrake_deceleration);
+        }
+
+        deceleration
+    }
+
+    fn stopping_distance(&self, speed: Velocity, deceleration: Acceleration) -> Length {
+        let deceleration = deceleration.get::<meter_per_second_squared>();
+        if deceleration <= 0. {
+            return Length::new::<meter>(f64::INFINITY);
+        }
+
+        Length::new::<meter>(speed.get::<meter_per_second>().max(0.).powi(2) / (2. * deceleration))
+    }
+
+    pub fn state(&self) -> RunwayOverrunState {
+        self.state
+    }
+
+    pub fn predicted_stopping_distance(&self) -> Length {
+        self.predicted_stopping_distance
+    }
+
+    /// Max braking must be commanded by the autobrake to stop before the end of the runway.
+    pub fn rop_is_active(&self) -> bool {
+        self.rop_is_active
+    }
+
+    /// Along track distance of the aircraft from the threshold of the runway supplied by the
+    /// flight management, `None` when no runway is supplied.
+    pub fn distance_from_runway_threshold(&self) -> Option<Length> {
+        self.runway_is_selected()
+            .then(|| self.distance_from_threshold())
+    }
+}
+impl SimulationElement for RunwayOverrunProtection {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        let runway_length_meters: f64 = reader.read(&self.runway_length_id);
+        self.runway_length = Length::new::<meter>(runway_length_meters);
+        self.threshold_latitude = reader.read(&self.threshold_latitude_id);
+        self.threshold_longitude = reader.read(&self.threshold_longitude_id);
+        self.runway_heading = reader.read(&self.runway_heading_id);
+        self.runway_condition = reader.read(&self.runway_condition_id);
+        self.latitude = reader.read(&self.latitude_id);
+        self.longitude = reader.read(&self.longitude_id);
+        self.gross_weight = reader.read(&self.gross_weight_id);
+        self.radio_altitude = reader.read(&self.radio_altitude_id);
+        self.approach_speed = reader.read(&self.approach_speed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.state_id, self.state);
+        writer.write(
+            &self.predicted_stopping_distance_id,
+            self.predicted_stopping_distance.get::<meter>(),
+        );
+        writer.write(&self.rop_active_id, self.rop_is_active);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft, SimulationElementVisitor,
+    };
+    use uom::si::{angle::degree, velocity::knot};
+
+    struct TestAircraft {
+        row_rop: RunwayOverrunProtection,
+        ground_speed: Velocity,
+        autobrake_deceleration: Option<Acceleration>,
+        autobrake_is_braking: bool,
+        measured_acceleration: Acceleration,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                row_rop: RunwayOverrunProtection::new(context, Force::new::<newton>(320000.)),
+                ground_speed: Velocity::default(),
+                autobrake_deceleration: None,
+                autobrake_is_braking: false,
+                measured_acceleration: Acceleration::default(),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.row_rop.update(
+                context,
+                self.ground_speed,
+                self.autobrake_deceleration,
+                self.autobrake_is_braking,
+                self.measured_acceleration,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.row_rop.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct RowRopTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl RowRopTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.write_by_name("ROW_ROP_THRESHOLD_LATITUDE", Angle::new::<degree>(0.));
+            test_bed.write_by_name("ROW_ROP_THRESHOLD_LONGITUDE", Angle::new::<degree>(0.));
+            test_bed.write_by_name("ROW_ROP_RUNWAY_HEADING", Angle::new::<degree>(90.));
+            test_bed.write_by_name("TOTAL WEIGHT", Mass::new::<kilogram>(60000.));
+
+            test_bed
+        }
+
+        fn runway(mut self, length: Length, condition: RunwayCondition) -> Self {
+            self.write_by_name("ROW_ROP_RUNWAY_LENGTH", length.get::<meter>());
+            self.write_by_name("ROW_ROP_RUNWAY_CONDITION", condition);
+            self
+        }
+
+        fn gross_weight(mut self, weight: Mass) -> Self {
+            self.write_by_name("TOTAL WEIGHT", weight);
+            self
+        }
+
+        fn approaching_at(mut self, true_airspeed: Velocity, ground_speed: Velocity) -> Self {
+            self.flying_at(true_airspeed, ground_speed, Length::new::<foot>(300.))
+        }
+
+        fn flying_at(
+            mut self,
+            true_airspeed: Velocity,
+            ground_speed: Velocity,
+            radio_altitude: Length,
+        ) -> Self {
+            self.set_on_ground(false);
+            self.set_true_airspeed(true_airspeed);
+            self.write_by_name("PLANE ALT ABOVE GROUND", radio_altitude);
+            self.command(|a| a.ground_speed = ground_speed);
+            self
+        }
+
+        fn approach_speed(mut self, speed: Velocity) -> Self {
+            self.write_by_name("SPEEDS_VAPP", speed);
+            self
+        }
+
+        fn rolling_at(mut self, ground_speed: Velocity, distance_from_threshold: Length) -> Self {
+            self.set_on_ground(true);
+            self.set_true_airspeed(ground_speed);
+            self.command(|a| a.ground_speed = ground_speed);
+
+            // Runway heading east from the threshold on the equator
+            self.write_by_name("PLANE LATITUDE", Angle::new::<degree>(0.));
+            self.write_by_name(
+                "PLANE LONGITUDE",
+                Angle::new::<radian>(
+                    distance_from_threshold.get::<meter>()
+                        / RunwayOverrunProtection::EARTH_RADIUS_METERS,
+                ),
+            );
+            self
+        }
+
+        fn autobrake(mut self, deceleration: Option<Acceleration>, is_braking: bool) -> Self {
+            self.command(|a| {
+                a.autobrake_deceleration = deceleration;
+                a.autobrake_is_braking = is_braking;
+            });
+            self
+        }
+
+        fn measured_deceleration(mut self, deceleration: Acceleration) -> Self {
+            self.command(|a| a.measured_acceleration = -deceleration);
+            self
+        }
+
+        fn and_run(mut self) -> Self {
+            self.run();
+            self
+        }
+
+        fn state(&mut self) -> RunwayOverrunState {
+            self.read_by_name("ROW_ROP_STATE")
+        }
+
+        fn predicted_stopping_distance(&mut self) -> Length {
+            let meters: f64 = self.read_by_name("ROW_ROP_PREDICTED_STOPPING_DISTANCE");
+            Length::new::<meter>(meters)
+        }
+
+        fn rop_is_active(&mut self) -> bool {
+            self.read_by_name("ROP_ACTIVE")
+        }
+    }
+    impl TestBed for RowRopTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    fn test_bed() -> RowRopTestBed {
+        RowRopTestBed::new()
+    }
+
+    fn meters(distance: f64) -> Length {
+        Length::new::<meter>(distance)
+    }
+
+    fn knots(speed: f64) -> Velocity {
+        Velocity::new::<knot>(speed)
+    }
+
+    fn decel(deceleration: f64) -> Acceleration {
+        Acceleration::new::<meter_per_second_squared>(deceleration)
+    }
+
+    #[test]
+    fn no_prediction_without_runway() {
+        let mut test_bed = test_bed()
+            .approaching_at(knots(140.), knots(140.))
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::None);
+        assert_eq!(test_bed.predicted_stopping_distance(), Length::default());
+    }
+
+    #[test]
+    fn no_prediction_in_cruise() {
+        let mut test_bed = test_bed()
+            .runway(meters(1000.), RunwayCondition::Dry)
+            .flying_at(knots(450.), knots(450.), Length::new::<foot>(35000.))
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::None);
+        assert_eq!(test_bed.predicted_stopping_distance(), Length::default());
+    }
+
+    #[test]
+    fn prediction_uses_the_approach_speed() {
+        let mut test_bed = test_bed()
+            .runway(meters(1300.), RunwayCondition::Dry)
+            .approach_speed(knots(140.))
+            .approaching_at(knots(180.), knots(180.))
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::IfWetRunwayTooShort);
+    }
+
+    #[test]
+    fn long_dry_runway_is_long_enough() {
+        let mut test_bed = test_bed()
+            .runway(meters(3000.), RunwayCondition::Dry)
+            .approaching_at(knots(140.), knots(140.))
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::None);
+    }
+
+    #[test]
+    fn runway_long_enough_only_if_dry() {
+        let mut test_bed = test_bed()
+            .runway(meters(1300.), RunwayCondition::Dry)
+            .approaching_at(knots(140.), knots(140.))
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::IfWetRunwayTooShort);
+    }
+
+    #[test]
+    fn wet_runway_too_short() {
+        let mut test_bed = test_bed()
+            .runway(meters(1300.), RunwayCondition::Wet)
+            .approaching_at(knots(140.), knots(140.))
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::RunwayTooShort);
+    }
+
+    #[test]
+    fn short_runway_is_too_short() {
+        let mut test_bed = test_bed()
+            .runway(meters(1000.), RunwayCondition::Dry)
+            .approaching_at(knots(140.), knots(140.))
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::RunwayTooShort);
+    }
+
+    #[test]
+    fn tailwind_increases_predicted_distance_more_than_headwind_decreases_it() {
+        let mut test_bed = test_bed()
+            .runway(meters(3000.), RunwayCondition::Dry)
+            .approaching_at(knots(140.), knots(140.))
+            .and_run();
+        let no_wind = test_bed.predicted_stopping_distance();
+
+        test_bed = test_bed.approaching_at(knots(140.), knots(130.)).and_run();
+        let headwind = test_bed.predicted_stopping_distance();
+
+        test_bed = test_bed.approaching_at(knots(140.), knots(150.)).and_run();
+        let tailwind = test_bed.predicted_stopping_distance();
+
+        assert!(headwind < no_wind);
+        assert!(tailwind > no_wind);
+        assert!(tailwind - no_wind > no_wind - headwind);
+    }
+
+    #[test]
+    fn low_autobrake_mode_increases_predicted_distance() {
+        let mut test_bed = test_bed()
+            .runway(meters(1500.), RunwayCondition::Dry)
+            .approaching_at(knots(140.), knots(140.))
+            .autobrake(Some(decel(-2.)), false)
+            .and_run();
+
+        assert_eq!(test_bed.state(), RunwayOverrunState::RunwayTooShort);
+    }
+
+    #[test]
+    fn heavy_aircraft_is_limited_by_braking_force() {
+        let mut test_bed = test_bed()
+            .runway(meters(3000.), RunwayCondition::Dry)
+            .approaching_at(knots(140.), knots(140.))
+            .and_run();
+        let light = test_bed.predicted_stopping_distance();
+
+        test_bed = test_bed
+            .gross_weight(Mass::new::<kilogram>(120000.))
+            .and_run();
+
+        assert!(test_bed.predicted_stopping_distance() > light);
+    }
+
+    #[test]
+    fn stopping_distance_on_ground_counts_from_the_threshold() {
+        let mut test_bed = test_bed()
+            .runway(meters(3000.), RunwayCondition::Dry)
+            .rolling_at(knots(0.), meters(1000.))
+            .and_run();
+
+        assert!((test_bed.predicted_stopping_distance() - meters(1000.)).abs() < meters(1.));
+    }
+
+    #[test]
+    fn rop_activates_when_autobrake_mode_would_overrun() {
+        let mut test_bed = test_bed()
+            .runway(meters(2000.), RunwayCondition::Dry)
+            .rolling_at(knots(120.), meters(1200.))
+            .autobrake(Some(decel(-2.)), true)
+            .and_run();
+
+        assert!(test_bed.rop_is_active());
+        assert_eq!(test_bed.state(), RunwayOverrunState::None);
+    }
+
+    #[test]
+    fn rop_does_not_activate_when_braking_harder_than_autobrake_mode() {
+        let mut test_bed = test_bed()
+            .runway(meters(2000.), RunwayCondition::Dry)
+            .rolling_at(knots(120.), meters(1200.))
+            .autobrake(Some(decel(-2.)), true)
+            .measured_deceleration(decel(3.5))
+            .and_run();
+
+        assert!(!test_bed.rop_is_active());
+    }
+
+    #[test]
+    fn rop_stays_active_until_autobrake_stops_braking() {
+        let mut test_bed = test_bed()
+            .runway(meters(2000.), RunwayCondition::Dry)
+            .rolling_at(knots(120.), meters(1200.))
+            .autobrake(Some(decel(-2.)), true)
+            .and_run();
+
+        test_bed = test_bed.rolling_at(knots(40.), meters(1500.)).and_run();
+        assert!(test_bed.rop_is_active());
+
+        test_bed = test_bed.autobrake(Some(decel(-2.)), false).and_run();
+        assert!(!test_bed.rop_is_active());
+    }
+
+    #[test]
+    fn rop_needs_autobrake() {
+        let mut test_bed = test_bed()
+            .runway(meters(2000.), RunwayCondition::Dry)
+            .rolling_at(knots(120.), meters(1200.))
+            .and_run();
+
+        assert!(!test_bed.rop_is_active());
+    }
+
+    #[test]
+    fn runway_too_short_on_ground_when_max_braking_would_overrun() {
+        let mut test_bed = test_bed()
+            .runway(meters(2000.), RunwayCondition::Dry)
+            .rolling_at(knots(120.), meters(1600.))
+            .autobrake(Some(decel(-2.)), true)
+            .and_run();
+
+        assert!(test_bed.rop_is_active());
+        assert_eq!(test_bed.state(), RunwayOverrunState::RunwayTooShort);
+    }
+
+    #[test]
+    fn distance_from_runway_threshold_is_unknown_without_runway() {
+        let test_bed = test_bed().rolling_at(knots(100.), meters(500.)).and_run();
+
+        assert!(test_bed
+            .query(|a| a.row_rop.distance_from_runway_threshold())
+            .is_none());
+    }
+
+    #[test]
+    fn distance_from_runway_threshold_is_measured_along_the_runway() {
+        let test_bed = test_bed()
+            .runway(meters(3000.), RunwayCondition::Dry)
+            .rolling_at(knots(100.), meters(500.))
+            .and_run();
+
+        let distance = test_bed
+            .query(|a| a.row_rop.distance_from_runway_threshold())
+            .unwrap();
+        assert!((distance.get::<meter>() - 500.).abs() < 0.1);
+    }
+}
@@ -0,0 +1,689 @@
+use crate::{
+    shared::{random_from_range, ElectricalBusType, ElectricalBuses},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+
+use uom::si::{
+    f64::*,
+    force::newton,
+    mass::kilogram,
+    ratio::ratio,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::meter_per_second,
+};
+
+/// Carbon brake heat sink of a wheel, with its temperature probe and the wheel and tyre it is fitted in.
+///
+/// The heat sink absorbs the kinetic energy dissipated by braking and cools down by convection,
+/// which increases with the airflow around an extended gear and with brake fans, and radiation.
+/// The wheel is heated by the heat sink. When the wheel gets too hot the tyre fusible plug melts and deflates the tyre.
+pub struct CarbonBrake {
+    temperature_id: VariableIdentifier,
+    reported_temperature_id: VariableIdentifier,
+    tyre_temperature_id: VariableIdentifier,
+    tyre_deflated_id: VariableIdentifier,
+
+    heat_sink_mass_kg: f64,
+    max_braking_force: Force,
+    heat_share: f64,
+
+    is_initialised: bool,
+    temperature: f64,
+    reported_temperature: f64,
+    tyre_temperature: f64,
+    fuse_plug_melted: bool,
+}
+impl CarbonBrake {
+    const HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_K: f64 = 1400.;
+
+    // Cooling conductances are given per kilogram of heat sink, as its surface grows with its mass
+    const STILL_AIR_COOLING_W_PER_K_KG: f64 = 0.35;
+    const AIRFLOW_COOLING_W_PER_K_KG_PER_M_S: f64 = 0.026;
+    const BRAKE_FAN_COOLING_W_PER_K_KG: f64 = 1.15;
+    const RETRACTED_GEAR_COOLING_FACTOR: f64 = 0.5;
+    // Stefan–Boltzmann constant times emissivity and radiating surface per kilogram
+    const RADIATION_W_PER_K4_KG: f64 = 5.67e-8 * 0.8 * 0.0064;
+
+    const PROBE_TIME_CONSTANT_S: f64 = 60.;
+    // Brake fans cool the probe faster than the heat sink, so it reports a lower temperature
+    const PROBE_REPORTED_DELTA_WITH_FANS_RATIO: f64 = 0.5;
+
+    const HEAT_SINK_TO_WHEEL_TIME_CONSTANT_S: f64 = 1200.;
+    const WHEEL_COOLING_TIME_CONSTANT_S: f64 = 2400.;
+
+    // Fusible plugs melt at 177°C (350°F)
+    const FUSE_PLUG_MELTING_TEMPERATURE_DEG_C: f64 = 177.;
+
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        heat_sink_mass: Mass,
+        max_braking_force: Force,
+    ) -> Self {
+        Self {
+            temperature_id: context.get_identifier(format!("BRAKE_TEMPERATURE_{}", number)),
+            reported_temperature_id: context
+                .get_identifier(format!("REPORTED_BRAKE_TEMPERATURE_{}", number)),
+            tyre_temperature_id: context.get_identifier(format!("TYRE_TEMPERATURE_{}", number)),
+            tyre_deflated_id: context.get_identifier(format!("TYRE_DEFLATED_{}", number)),
+
+            heat_sink_mass_kg: heat_sink_mass.get::<kilogram>(),
+            max_braking_force,
+            // Brakes of a same gear never share the braking work exactly evenly
+            heat_share: random_from_range(0.9, 1.1),
+
+            is_initialised: false,
+            temperature: 0.,
+            reported_temperature: 0.,
+            tyre_temperature: 0.,
+            fuse_plug_melted: false,
+        }
+    }
+
+    /// Updates the brake temperatures.
+    ///
+    /// `braking_force` is the ratio of the maximum braking force applied by the brake.
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        braking_force: Ratio,
+        gear_is_extended: bool,
+        brake_fans_are_running: bool,
+    ) {
+        let ambient = context.ambient_temperature().get::<degree_celsius>();
+        if !self.is_initialised {
+            self.temperature = ambient;
+            self.reported_temperature = ambient;
+            self.tyre_temperature = ambient;
+            self.is_initialised = true;
+        }
+
+        let delta_s = context.delta_as_secs_f64();
+        let mass = self.heat_sink_mass_kg;
+
+        let airflow_cooling = if gear_is_extended {
+            Self::STILL_AIR_COOLING_W_PER_K_KG
+                + Self::AIRFLOW_COOLING_W_PER_K_KG_PER_M_S
+                    * context.true_airspeed().get::<meter_per_second>().abs()
+        } else {
+            Self::STILL_AIR_COOLING_W_PER_K_KG * Self::RETRACTED_GEAR_COOLING_FACTOR
+        };
+        let fan_cooling = if brake_fans_are_running {
+            Self::BRAKE_FAN_COOLING_W_PER_K_KG
+        } else {
+            0.
+        };
+
+        let convection_power =
+            (airflow_cooling + fan_cooling) * mass * (self.temperature - ambient);
+        let radiation_power = Self::RADIATION_W_PER_K4_KG
+            * mass
+            * (Self::kelvin(self.temperature).powi(4) - Self::kelvin(ambient).powi(4));
+
+        self.temperature +=
+            (self.braking_power(context, braking_force) - convection_power - radiation_power)
+                * delta_s
+                / (mass * Self::HEAT_SINK_SPECIFIC_HEAT_J_PER_KG_K);
+
+        self.update_reported_temperature(ambient, delta_s, brake_fans_are_running);
+        self.update_tyre_temperature(
+            ambient,
+            delta_s,
+            airflow_cooling / Self::STILL_AIR_COOLING_W_PER_K_KG,
+        );
+    }
+
+    fn braking_power(&self, context: &UpdateContext, braking_force: Ratio) -> f64 {
+        if context.is_on_ground() {
+            let ground_speed = context.local_velocity().to_ms_vector()[2].abs();
+
+            braking_force.get::<ratio>()
+                * self.max_braking_force.get::<newton>()
+                * ground_speed
+                * self.heat_share
+        } else {
+            0.
+        }
+    }
+
+    fn update_reported_temperature(
+        &mut self,
+        ambient: f64,
+        delta_s: f64,
+        brake_fans_are_running: bool,
+    ) {
+        let probe_target = if brake_fans_are_running {
+            ambient + (self.temperature - ambient) * Self::PROBE_REPORTED_DELTA_WITH_FANS_RATIO
+        } else {
+            self.temperature
+        };
+
+        self.reported_temperature += (probe_target - self.reported_temperature)
+            * (delta_s / Self::PROBE_TIME_CONSTANT_S).min(1.);
+    }
+
+    fn update_tyre_temperature(&mut self, ambient: f64, delta_s: f64, airflow_factor: f64) {
+        self.tyre_temperature += ((self.temperature - self.tyre_temperature)
+            / Self::HEAT_SINK_TO_WHEEL_TIME_CONSTANT_S
+            - (self.tyre_temperature - ambient) * airflow_factor
+                / Self::WHEEL_COOLING_TIME_CONSTANT_S)
+            * delta_s;
+
+        if self.tyre_temperature > Self::FUSE_PLUG_MELTING_TEMPERATURE_DEG_C {
+            self.fuse_plug_melted = true;
+        }
+    }
+
+    fn kelvin(temperature: f64) -> f64 {
+        ThermodynamicTemperature::new::<degree_celsius>(temperature).get::<kelvin>()
+    }
+
+    pub fn temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.temperature)
+    }
+
+    pub fn reported_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.reported_temperature)
+    }
+
+    pub fn tyre_temperature(&self) -> ThermodynamicTemperature {
+        ThermodynamicTemperature::new::<degree_celsius>(self.tyre_temperature)
+    }
+
+    pub fn tyre_is_deflated(&self) -> bool {
+        self.fuse_plug_melted
+    }
+}
+impl SimulationElement for CarbonBrake {
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.temperature_id, self.temperature());
+        writer.write(&self.reported_temperature_id, self.reported_temperature());
+        writer.write(&self.tyre_temperature_id, self.tyre_temperature());
+        writer.write(&self.tyre_deflated_id, self.tyre_is_deflated());
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.is_initialised);
+        snapshot.write(self.temperature);
+        snapshot.write(self.reported_temperature);
+        snapshot.write(self.tyre_temperature);
+        snapshot.write(self.fuse_plug_melted);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.is_initialised = snapshot.read();
+        self.temperature = snapshot.read();
+        self.reported_temperature = snapshot.read();
+        self.tyre_temperature = snapshot.read();
+        self.fuse_plug_melted = snapshot.read();
+    }
+}
+
+/// Monitors the temperatures of all brakes of the aircraft.
+///
+/// The first half of the brakes are fitted on the left main gears, the second half on the right ones.
+pub struct BrakeTemperatureMonitoring<const N: usize> {
+    brakes_hot_id: VariableIdentifier,
+
+    brakes: Vec<CarbonBrake>,
+    brakes_hot: bool,
+}
+impl<const N: usize> BrakeTemperatureMonitoring<N> {
+    const BRAKES_HOT_TEMPERATURE_DEG_C: f64 = 300.;
+
+    pub fn new(context: &mut InitContext, heat_sink_mass: Mass, max_braking_force: Force) -> Self {
+        Self {
+            brakes_hot_id: context.get_identifier("BRAKES_HOT".to_owned()),
+
+            brakes: (1..=N)
+                .map(|number| CarbonBrake::new(context, number, heat_sink_mass, max_braking_force))
+                .collect(),
+            brakes_hot: false,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        left_braking_force: Ratio,
+        right_braking_force: Ratio,
+        gear_is_extended: bool,
+        brake_fans_are_running: bool,
+    ) {
+        for (index, brake) in self.brakes.iter_mut().enumerate() {
+            brake.update(
+                context,
+                if index < N / 2 {
+                    left_braking_force
+                } else {
+                    right_braking_force
+                },
+                gear_is_extended,
+                brake_fans_are_running,
+            );
+        }
+
+        self.brakes_hot = self.brakes.iter().any(|brake| {
+            brake.reported_temperature().get::<degree_celsius>()
+                > Self::BRAKES_HOT_TEMPERATURE_DEG_C
+        });
+    }
+
+    pub fn brakes_hot(&self) -> bool {
+        self.brakes_hot
+    }
+
+    pub fn brake(&self, number: usize) -> &CarbonBrake {
+        &self.brakes[number - 1]
+    }
+
+    pub fn any_tyre_deflated(&self) -> bool {
+        self.brakes.iter().any(|brake| brake.tyre_is_deflated())
+    }
+}
+impl<const N: usize> SimulationElement for BrakeTemperatureMonitoring<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.brakes, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.brakes_hot_id, self.brakes_hot);
+    }
+}
+
+/// Brake fans blowing air through the brakes of the main gears.
+/// They run when selected, powered and the main gears are down and locked.
+pub struct BrakeFans {
+    pushbutton_pressed_id: VariableIdentifier,
+    is_running_id: VariableIdentifier,
+
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+
+    pushbutton_pressed: bool,
+    is_running: bool,
+}
+impl BrakeFans {
+    pub fn new(context: &mut InitContext, powered_by: ElectricalBusType) -> Self {
+        Self {
+            pushbutton_pressed_id: context.get_identifier("BRAKE_FAN_BTN_PRESSED".to_owned()),
+            is_running_id: context.get_identifier("BRAKE_FAN".to_owned()),
+
+            powered_by,
+            is_powered: false,
+
+            pushbutton_pressed: false,
+            is_running: false,
+        }
+    }
+
+    pub fn update(&mut self, main_gear_down_and_locked: bool) {
+        self.is_running = self.pushbutton_pressed && self.is_powered && main_gear_down_and_locked;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.is_running
+    }
+}
+impl SimulationElement for BrakeFans {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.pushbutton_pressed = reader.read(&self.pushbutton_pressed_id);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_running_id, self.is_running);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::PotentialOrigin,
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::{
+        electric_potential::volt,
+        velocity::{foot_per_second, knot},
+    };
+
+    struct TestAircraft {
+        brakes: BrakeTemperatureMonitoring<4>,
+        brake_fans: BrakeFans,
+
+        powered_source_ac: TestElectricitySource,
+        ac_2_bus: ElectricalBus,
+        is_ac_powered: bool,
+
+        left_braking_force: Ratio,
+        right_braking_force: Ratio,
+        gear_is_extended: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                brakes: BrakeTemperatureMonitoring::new(
+                    context,
+                    Mass::new::<kilogram>(70.),
+                    Force::new::<newton>(80000.),
+                ),
+                brake_fans: BrakeFans::new(context, ElectricalBusType::AlternatingCurrent(2)),
+
+                powered_source_ac: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(2),
+                ),
+                ac_2_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(2)),
+                is_ac_powered: true,
+
+                left_braking_force: Ratio::new::<ratio>(0.),
+                right_braking_force: Ratio::new::<ratio>(0.),
+                gear_is_extended: true,
+            }
+        }
+
+        fn set_braking_force(&mut self, left: f64, right: f64) {
+            self.left_braking_force = Ratio::new::<ratio>(left);
+            self.right_braking_force = Ratio::new::<ratio>(right);
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            if self.is_ac_powered {
+                self.powered_source_ac
+                    .power_with_potential(ElectricPotential::new::<volt>(115.));
+                electricity.supplied_by(&self.powered_source_ac);
+                electricity.flow(&self.powered_source_ac, &self.ac_2_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.brake_fans.update(self.gear_is_extended);
+            self.brakes.update(
+                context,
+                self.left_braking_force,
+                self.right_braking_force,
+                self.gear_is_extended,
+                self.brake_fans.is_running(),
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.brakes.accept(visitor);
+            self.brake_fans.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct BrakeHeatTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl BrakeHeatTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+            test_bed.set_ground_speed(Velocity::new::<knot>(0.));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            test_bed
+        }
+
+        fn set_ground_speed(&mut self, speed: Velocity) {
+            self.write_by_name("VELOCITY BODY Z", speed.get::<foot_per_second>());
+        }
+
+        fn set_braking_force(&mut self, left: f64, right: f64) {
+            self.command(|a| a.set_braking_force(left, right));
+        }
+
+        fn set_brake_fan_pushbutton(&mut self, is_pressed: bool) {
+            self.write_by_name("BRAKE_FAN_BTN_PRESSED", is_pressed);
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            for _ in 0..duration.as_secs() {
+                self.run_with_delta(Duration::from_secs(1));
+            }
+        }
+
+        /// Decelerates the aircraft from the given speed to a stop with the given braking force,
+        /// assuming a deceleration proportional to the braking force.
+        fn brake_to_a_stop(&mut self, speed: Velocity, braking_force: f64) {
+            // Full braking force of an aircraft at its maximum weight decelerates it at 4 m/s²
+            let deceleration = 4. * braking_force;
+            let mut speed = speed.get::<meter_per_second>();
+
+            self.set_braking_force(braking_force, braking_force);
+            while speed > 0. {
+                self.set_true_airspeed(Velocity::new::<meter_per_second>(speed));
+                self.set_ground_speed(Velocity::new::<meter_per_second>(speed));
+                self.run_with_delta(Duration::from_secs(1));
+                speed -= deceleration;
+            }
+
+            self.set_braking_force(0., 0.);
+            self.set_true_airspeed(Velocity::new::<knot>(0.));
+            self.set_ground_speed(Velocity::new::<knot>(0.));
+        }
+
+        fn brake_temperature(&self, number: usize) -> f64 {
+            self.query(|a| a.brakes.brake(number).temperature())
+                .get::<degree_celsius>()
+        }
+
+        fn reported_brake_temperature(&mut self, number: usize) -> f64 {
+            let temperature: ThermodynamicTemperature =
+                self.read_by_name(&format!("REPORTED_BRAKE_TEMPERATURE_{}", number));
+            temperature.get::<degree_celsius>()
+        }
+
+        fn brakes_hot(&mut self) -> bool {
+            self.read_by_name("BRAKES_HOT")
+        }
+
+        fn brake_fans_running(&mut self) -> bool {
+            self.read_by_name("BRAKE_FAN")
+        }
+
+        fn tyre_deflated(&mut self, number: usize) -> bool {
+            self.read_by_name(&format!("TYRE_DEFLATED_{}", number))
+        }
+    }
+    impl TestBed for BrakeHeatTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn brakes_start_at_ambient_temperature() {
+        let test_bed = BrakeHeatTestBed::new();
+
+        for number in 1..=4 {
+            assert!((test_bed.brake_temperature(number) - 15.).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn braking_while_rolling_heats_the_braking_side() {
+        let mut test_bed = BrakeHeatTestBed::new();
+
+        test_bed.set_ground_speed(Velocity::new::<knot>(20.));
+        test_bed.set_braking_force(0.5, 0.);
+        test_bed.run_for(Duration::from_secs(10));
+
+        assert!(test_bed.brake_temperature(1) > 30.);
+        assert!(test_bed.brake_temperature(2) > 30.);
+        assert!(test_bed.brake_temperature(3) < 16.);
+        assert!(test_bed.brake_temperature(4) < 16.);
+    }
+
+    #[test]
+    fn braking_while_stopped_does_not_heat_the_brakes() {
+        let mut test_bed = BrakeHeatTestBed::new();
+
+        test_bed.set_braking_force(1., 1.);
+        test_bed.run_for(Duration::from_secs(60));
+
+        for number in 1..=4 {
+            assert!((test_bed.brake_temperature(number) - 15.).abs() < 0.1);
+        }
+    }
+
+    #[test]
+    fn braking_in_flight_does_not_heat_the_brakes() {
+        let mut test_bed = BrakeHeatTestBed::new();
+
+        test_bed.set_on_ground(false);
+        test_bed.set_ground_speed(Velocity::new::<knot>(150.));
+        test_bed.set_braking_force(1., 1.);
+        test_bed.run_for(Duration::from_secs(10));
+
+        assert!((test_bed.brake_temperature(1) - 15.).abs() < 0.1);
+    }
+
+    #[test]
+    fn hot_brakes_cool_down_to_ambient() {
+        let mut test_bed = BrakeHeatTestBed::new();
+        test_bed.brake_to_a_stop(Velocity::new::<knot>(60.), 0.5);
+        let hot_temperature = test_bed.brake_temperature(1);
+        assert!(hot_temperature > 50.);
+
+        test_bed.run_for(Duration::from_secs(600));
+        let cooled_temperature = test_bed.brake_temperature(1);
+        assert!(cooled_temperature < hot_temperature);
+
+        test_bed.run_for(Duration::from_secs(6 * 3600));
+        assert!((test_bed.brake_temperature(1) - 15.).abs() < 1.);
+    }
+
+    #[test]
+    fn brake_fans_cool_the_brakes_faster() {
+        let mut without_fans = BrakeHeatTestBed::new();
+        let mut with_fans = BrakeHeatTestBed::new();
+        with_fans.set_brake_fan_pushbutton(true);
+
+        let cooled_ratio = |test_bed: &mut BrakeHeatTestBed| {
+            test_bed.brake_to_a_stop(Velocity::new::<knot>(130.), 0.5);
+            let hot_delta = test_bed.brake_temperature(1) - 15.;
+            test_bed.run_for(Duration::from_secs(900));
+
+            (test_bed.brake_temperature(1) - 15.) / hot_delta
+        };
+
+        assert!(cooled_ratio(&mut with_fans) < 0.75 * cooled_ratio(&mut without_fans));
+    }
+
+    #[test]
+    fn brake_fans_run_when_selected_powered_and_gear_down() {
+        let mut test_bed = BrakeHeatTestBed::new();
+        assert!(!test_bed.brake_fans_running());
+
+        test_bed.set_brake_fan_pushbutton(true);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(test_bed.brake_fans_running());
+
+        test_bed.command(|a| a.is_ac_powered = false);
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(!test_bed.brake_fans_running());
+
+        test_bed.command(|a| {
+            a.is_ac_powered = true;
+            a.gear_is_extended = false;
+        });
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(!test_bed.brake_fans_running());
+    }
+
+    #[test]
+    fn brake_fans_make_the_probes_report_a_lower_temperature() {
+        let mut test_bed = BrakeHeatTestBed::new();
+        test_bed.brake_to_a_stop(Velocity::new::<knot>(100.), 0.5);
+        test_bed.run_for(Duration::from_secs(300));
+        assert!(
+            (test_bed.reported_brake_temperature(1) - test_bed.brake_temperature(1)).abs() < 5.
+        );
+
+        test_bed.set_brake_fan_pushbutton(true);
+        test_bed.run_for(Duration::from_secs(300));
+
+        assert!(test_bed.reported_brake_temperature(1) < test_bed.brake_temperature(1) - 20.);
+    }
+
+    #[test]
+    fn low_energy_stop_does_not_trigger_brakes_hot() {
+        let mut test_bed = BrakeHeatTestBed::new();
+
+        test_bed.brake_to_a_stop(Velocity::new::<knot>(30.), 0.3);
+        test_bed.run_for(Duration::from_secs(120));
+
+        assert!(!test_bed.brakes_hot());
+    }
+
+    #[test]
+    fn rejected_takeoff_triggers_brakes_hot() {
+        let mut test_bed = BrakeHeatTestBed::new();
+
+        test_bed.brake_to_a_stop(Velocity::new::<knot>(150.), 1.);
+        test_bed.run_for(Duration::from_secs(120));
+
+        assert!(test_bed.brakes_hot());
+        assert!(test_bed.brake_temperature(1) > 450.);
+    }
+
+    #[test]
+    fn rejected_takeoff_melts_the_fuse_plugs() {
+        let mut test_bed = BrakeHeatTestBed::new();
+
+        test_bed.brake_to_a_stop(Velocity::new::<knot>(150.), 1.);
+        assert!(!test_bed.tyre_deflated(1));
+
+        test_bed.run_for(Duration::from_secs(1800));
+
+        for number in 1..=4 {
+            assert!(test_bed.tyre_deflated(number));
+        }
+    }
+
+    #[test]
+    fn medium_energy_stop_does_not_melt_the_fuse_plugs() {
+        let mut test_bed = BrakeHeatTestBed::new();
+
+        test_bed.brake_to_a_stop(Velocity::new::<knot>(100.), 0.3);
+        test_bed.run_for(Duration::from_secs(3600));
+
+        for number in 1..=4 {
+            assert!(!test_bed.tyre_deflated(number));
+        }
+    }
+}
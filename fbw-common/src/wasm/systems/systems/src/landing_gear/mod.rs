@@ -1,5 +1,8 @@
 use std::time::Duration;
 
+pub mod brake_heat;
+pub mod wheel;
+
 use crate::{
     failures::{Failure, FailureType},
     shared::arinc429::{Arinc429Word, SignStatus},
@@ -0,0 +1,684 @@
+use super::brake_heat::{BrakeTemperatureMonitoring, CarbonBrake};
+use crate::{
+    failures::{Failure, FailureType},
+    simulation::{
+        InitContext, Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+        Writer,
+    },
+};
+
+use uom::si::{
+    f64::*,
+    pressure::psi,
+    ratio::ratio,
+    thermodynamic_temperature::{degree_celsius, kelvin},
+    velocity::{knot, meter_per_second},
+};
+
+/// Condition of the runway or taxiway surface under the main gears, as given by the simulator
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurfaceCondition {
+    Normal = 0,
+    Wet = 1,
+    Icy = 2,
+    Snow = 3,
+}
+impl SurfaceCondition {
+    /// Peak friction coefficient between a tyre and the surface
+    fn friction_coefficient(&self) -> f64 {
+        match self {
+            SurfaceCondition::Normal => 0.8,
+            SurfaceCondition::Wet => 0.45,
+            SurfaceCondition::Icy => 0.1,
+            SurfaceCondition::Snow => 0.25,
+        }
+    }
+}
+read_write_enum!(SurfaceCondition);
+impl From<f64> for SurfaceCondition {
+    fn from(value: f64) -> Self {
+        match value as u8 {
+            1 => SurfaceCondition::Wet,
+            2 => SurfaceCondition::Icy,
+            3 => SurfaceCondition::Snow,
+            _ => SurfaceCondition::Normal,
+        }
+    }
+}
+
+/// Braked wheel of a main gear, with its tyre.
+///
+/// The tyre pressure follows the tyre temperature and drops when the tyre bursts, leaks or when
+/// the fuse plugs of the wheel melt. A flat tyre drags the aircraft towards its side and reduces
+/// the braking efficiency of its wheel. A seized brake locks the wheel, which then skids.
+///
+/// The force a tyre can transmit is limited by the friction of the surface. Braking harder than
+/// that makes the wheel slip, friction then decreasing down to a locked wheel skidding on the surface.
+pub struct MainGearWheel {
+    tyre_pressure_id: VariableIdentifier,
+
+    tyre_burst: Failure,
+    tyre_deflation: Failure,
+    brake_seizure: Failure,
+
+    nominal_tyre_pressure_psi: f64,
+    // Amount of gas in the tyre, as a ratio of the amount at nominal pressure
+    inflation: f64,
+    tyre_pressure_psi: f64,
+    is_on_ground: bool,
+
+    surface_condition: SurfaceCondition,
+    ground_speed: Velocity,
+    // Share of the ground speed lost by the wheel, from 0 for a free rolling wheel to 1 for a locked one
+    slip: f64,
+}
+impl MainGearWheel {
+    const ATMOSPHERIC_PRESSURE_PSI: f64 = 14.7;
+    // Tyres are inflated to their nominal pressure at this temperature
+    const NOMINAL_TYRE_TEMPERATURE_DEG_C: f64 = 15.;
+
+    const SLOW_LEAK_TIME_CONSTANT_S: f64 = 900.;
+    const FUSE_PLUG_LEAK_TIME_CONSTANT_S: f64 = 20.;
+
+    // Braking efficiency of a wheel rolling on a flat tyre, as a ratio of its nominal efficiency
+    const FLAT_TYRE_BRAKING_EFFICIENCY: f64 = 0.5;
+    // Drags are given as a ratio of the maximum braking force of the wheel
+    const FLAT_TYRE_DRAG: f64 = 0.1;
+    // A burst tyre rolls on its shreds and on the wheel rim
+    const BURST_TYRE_DRAG: f64 = 0.25;
+    // A locked wheel skids, with less friction than a braking wheel at its peak
+    const SEIZED_BRAKE_DRAG: f64 = 0.7;
+
+    // Friction coefficient for which the wheel can transmit its maximum braking force
+    const REFERENCE_FRICTION_COEFFICIENT: f64 = 0.5;
+    // Friction peaks at a small slip, then decreases down to the friction of a skidding tyre,
+    // given as a ratio of the peak friction of the surface
+    const PEAK_FRICTION_SLIP: f64 = 0.2;
+    const SLIDING_FRICTION_RATIO: f64 = 0.7;
+    const LOCKED_WHEEL_SLIP: f64 = 0.9;
+
+    // Slip gained per second and per unit of braking force exceeding what the tyre can transmit
+    const SLIP_BUILD_UP_RATE: f64 = 4.;
+    // Slip lost per second by a wheel spinning back up once braking force is within limits
+    const SLIP_RECOVERY_RATE: f64 = 5.;
+    const MIN_GROUND_SPEED_FOR_SLIP_KNOT: f64 = 5.;
+
+    pub fn new(context: &mut InitContext, number: usize, nominal_tyre_pressure: Pressure) -> Self {
+        Self {
+            tyre_pressure_id: context.get_identifier(format!("TYRE_PRESSURE_{}", number)),
+
+            tyre_burst: Failure::new(FailureType::TyreBurst(number)),
+            tyre_deflation: Failure::new(FailureType::TyreDeflation(number)),
+            brake_seizure: Failure::new(FailureType::BrakeSeizure(number)),
+
+            nominal_tyre_pressure_psi: nominal_tyre_pressure.get::<psi>(),
+            inflation: 1.,
+            tyre_pressure_psi: nominal_tyre_pressure.get::<psi>(),
+            is_on_ground: false,
+
+            surface_condition: SurfaceCondition::Normal,
+            ground_speed: Velocity::new::<meter_per_second>(0.),
+            slip: 0.,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        brake: &CarbonBrake,
+        brake_force: Ratio,
+        surface_condition: SurfaceCondition,
+    ) {
+        self.is_on_ground = context.is_on_ground();
+        self.ground_speed =
+            Velocity::new::<meter_per_second>(context.local_velocity().to_ms_vector()[2].abs());
+
+        if self.tyre_burst.is_active() {
+            self.inflation = 0.;
+        } else if self.tyre_deflation.is_active() || brake.tyre_is_deflated() {
+            let leak_time_constant = if brake.tyre_is_deflated() {
+                Self::FUSE_PLUG_LEAK_TIME_CONSTANT_S
+            } else {
+                Self::SLOW_LEAK_TIME_CONSTANT_S
+            };
+
+            self.inflation -=
+                self.inflation * (context.delta_as_secs_f64() / leak_time_constant).min(1.);
+        } else {
+            // Without any failure the tyre is considered serviced to its nominal inflation
+            self.inflation = 1.;
+        }
+
+        self.update_tyre_pressure(brake.tyre_temperature());
+        self.update_slip(context, brake_force, surface_condition);
+    }
+
+    fn update_slip(
+        &mut self,
+        context: &UpdateContext,
+        brake_force: Ratio,
+        surface_condition: SurfaceCondition,
+    ) {
+        if !self.is_on_ground
+            || self.ground_speed.get::<knot>() < Self::MIN_GROUND_SPEED_FOR_SLIP_KNOT
+        {
+            self.slip = 0.;
+        } else if self.brake_seizure.is_active() {
+            self.slip = 1.;
+        } else {
+            let excess_force = brake_force.get::<ratio>() * self.braking_efficiency()
+                - Self::transmissible_force(surface_condition);
+
+            let slip_rate = if excess_force > 0. {
+                excess_force * Self::SLIP_BUILD_UP_RATE
+            } else {
+                -Self::SLIP_RECOVERY_RATE
+            };
+
+            self.slip = (self.slip + slip_rate * context.delta_as_secs_f64())
+                .max(0.)
+                .min(1.);
+        }
+
+        self.surface_condition = surface_condition;
+    }
+
+    /// Highest braking force the tyre can transmit on the given surface, as a ratio of the maximum
+    /// braking force of the wheel
+    fn transmissible_force(surface_condition: SurfaceCondition) -> f64 {
+        surface_condition.friction_coefficient() / Self::REFERENCE_FRICTION_COEFFICIENT
+    }
+
+    fn update_tyre_pressure(&mut self, tyre_temperature: ThermodynamicTemperature) {
+        let nominal_temperature =
+            ThermodynamicTemperature::new::<degree_celsius>(Self::NOMINAL_TYRE_TEMPERATURE_DEG_C);
+
+        let absolute_pressure = self.inflation
+            * (self.nominal_tyre_pressure_psi + Self::ATMOSPHERIC_PRESSURE_PSI)
+            * tyre_temperature.get::<kelvin>()
+            / nominal_temperature.get::<kelvin>();
+
+        self.tyre_pressure_psi = (absolute_pressure - Self::ATMOSPHERIC_PRESSURE_PSI).max(0.);
+    }
+
+    /// Share of the nominal pressure lost by the tyre, from 0 for an inflated tyre to 1 for a flat one
+    fn tyre_flatness(&self) -> f64 {
+        (1. - self.tyre_pressure_psi / self.nominal_tyre_pressure_psi)
+            .max(0.)
+            .min(1.)
+    }
+
+    /// Force applied on the aircraft by the wheel, as a ratio of its maximum braking force,
+    /// when its brake applies the given braking force.
+    pub fn braking_force(&self, brake_force: Ratio) -> Ratio {
+        if !self.is_on_ground {
+            return brake_force;
+        }
+
+        let transmissible_force = Self::transmissible_force(self.surface_condition);
+        let sliding_force = transmissible_force * Self::SLIDING_FRICTION_RATIO;
+
+        if self.brake_seizure.is_active() {
+            return Ratio::new::<ratio>(Self::SEIZED_BRAKE_DRAG.min(sliding_force));
+        }
+
+        let drag = if self.tyre_burst.is_active() {
+            Self::BURST_TYRE_DRAG
+        } else {
+            Self::FLAT_TYRE_DRAG * self.tyre_flatness()
+        };
+        let friction_loss = ((self.slip - Self::PEAK_FRICTION_SLIP)
+            / (1. - Self::PEAK_FRICTION_SLIP))
+            .max(0.)
+            .min(1.);
+        let friction_force =
+            transmissible_force - (transmissible_force - sliding_force) * friction_loss;
+        let wheel_braking_force =
+            (brake_force.get::<ratio>() * self.braking_efficiency()).min(friction_force);
+
+        Ratio::new::<ratio>((wheel_braking_force + drag).min(1.))
+    }
+
+    fn braking_efficiency(&self) -> f64 {
+        1. - self.tyre_flatness() * (1. - Self::FLAT_TYRE_BRAKING_EFFICIENCY)
+    }
+
+    /// Peripheral speed of the wheel, lower than the ground speed when the wheel slips
+    pub fn wheel_speed(&self) -> Velocity {
+        self.ground_speed * (1. - self.slip)
+    }
+
+    pub fn slip(&self) -> Ratio {
+        Ratio::new::<ratio>(self.slip)
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.slip > Self::LOCKED_WHEEL_SLIP
+    }
+
+    pub fn tyre_pressure(&self) -> Pressure {
+        Pressure::new::<psi>(self.tyre_pressure_psi)
+    }
+}
+impl SimulationElement for MainGearWheel {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.tyre_burst.accept(visitor);
+        self.tyre_deflation.accept(visitor);
+        self.brake_seizure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.tyre_pressure_id, self.tyre_pressure());
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.inflation);
+        snapshot.write(self.tyre_pressure_psi);
+        snapshot.write(self.slip);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.inflation = snapshot.read();
+        self.tyre_pressure_psi = snapshot.read();
+        self.slip = snapshot.read();
+    }
+}
+
+/// All braked wheels of the main gears, numbered as their brakes.
+///
+/// The first half of the wheels are fitted on the left main gears, the second half on the right ones.
+pub struct MainGearWheels<const N: usize> {
+    surface_condition_id: VariableIdentifier,
+
+    wheels: Vec<MainGearWheel>,
+    surface_condition: SurfaceCondition,
+}
+impl<const N: usize> MainGearWheels<N> {
+    pub fn new(context: &mut InitContext, nominal_tyre_pressure: Pressure) -> Self {
+        Self {
+            surface_condition_id: context.get_identifier("SURFACE CONDITION".to_owned()),
+
+            wheels: (1..=N)
+                .map(|number| MainGearWheel::new(context, number, nominal_tyre_pressure))
+                .collect(),
+            surface_condition: SurfaceCondition::Normal,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        brakes: &BrakeTemperatureMonitoring<N>,
+        left_brake_force: Ratio,
+        right_brake_force: Ratio,
+    ) {
+        for (index, wheel) in self.wheels.iter_mut().enumerate() {
+            let brake_force = if index < N / 2 {
+                left_brake_force
+            } else {
+                right_brake_force
+            };
+
+            wheel.update(
+                context,
+                brakes.brake(index + 1),
+                brake_force,
+                self.surface_condition,
+            );
+        }
+    }
+
+    /// Mean force applied by the left main gear wheels when their brakes apply the given braking force
+    pub fn left_braking_force(&self, brake_force: Ratio) -> Ratio {
+        Self::mean_braking_force(&self.wheels[..N / 2], brake_force)
+    }
+
+    /// Mean force applied by the right main gear wheels when their brakes apply the given braking force
+    pub fn right_braking_force(&self, brake_force: Ratio) -> Ratio {
+        Self::mean_braking_force(&self.wheels[N / 2..], brake_force)
+    }
+
+    fn mean_braking_force(wheels: &[MainGearWheel], brake_force: Ratio) -> Ratio {
+        wheels
+            .iter()
+            .map(|wheel| wheel.braking_force(brake_force))
+            .sum::<Ratio>()
+            / wheels.len() as f64
+    }
+
+    pub fn wheel(&self, number: usize) -> &MainGearWheel {
+        &self.wheels[number - 1]
+    }
+}
+impl<const N: usize> SimulationElement for MainGearWheels<N> {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.wheels, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.surface_condition = reader.read(&self.surface_condition_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{
+        test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+        Aircraft,
+    };
+    use std::time::Duration;
+    use uom::si::{force::newton, mass::kilogram, velocity::foot_per_second};
+
+    struct TestAircraft {
+        brakes: BrakeTemperatureMonitoring<4>,
+        wheels: MainGearWheels<4>,
+
+        braking_force: Ratio,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                brakes: BrakeTemperatureMonitoring::new(
+                    context,
+                    Mass::new::<kilogram>(70.),
+                    Force::new::<newton>(80000.),
+                ),
+                wheels: MainGearWheels::new(context, Pressure::new::<psi>(200.)),
+
+                braking_force: Ratio::new::<ratio>(0.),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.brakes
+                .update(context, self.braking_force, self.braking_force, true, false);
+            self.wheels.update(
+                context,
+                &self.brakes,
+                self.braking_force,
+                self.braking_force,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.brakes.accept(visitor);
+            self.wheels.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    struct WheelsTestBed {
+        test_bed: SimulationTestBed<TestAircraft>,
+    }
+    impl WheelsTestBed {
+        fn new() -> Self {
+            let mut test_bed = Self {
+                test_bed: SimulationTestBed::new(TestAircraft::new),
+            };
+            test_bed.set_on_ground(true);
+            test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(15.));
+            test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+            test_bed.set_ground_speed(Velocity::new::<knot>(0.));
+            test_bed.run_with_delta(Duration::from_secs(1));
+
+            test_bed
+        }
+
+        fn set_ground_speed(&mut self, speed: Velocity) {
+            self.write_by_name("VELOCITY BODY Z", speed.get::<foot_per_second>());
+        }
+
+        fn set_surface_condition(&mut self, surface_condition: SurfaceCondition) {
+            self.write_by_name("SURFACE CONDITION", surface_condition);
+        }
+
+        fn set_braking_force(&mut self, braking_force: f64) {
+            self.command(|a| a.braking_force = Ratio::new::<ratio>(braking_force));
+        }
+
+        fn run_for(&mut self, duration: Duration) {
+            for _ in 0..duration.as_secs() {
+                self.run_with_delta(Duration::from_secs(1));
+            }
+        }
+
+        fn tyre_pressure(&mut self, number: usize) -> f64 {
+            let pressure: Pressure = self.read_by_name(&format!("TYRE_PRESSURE_{}", number));
+            pressure.get::<psi>()
+        }
+
+        fn wheel_slip(&self, number: usize) -> f64 {
+            self.query(|a| a.wheels.wheel(number).slip().get::<ratio>())
+        }
+
+        fn left_braking_force(&self, brake_force: f64) -> f64 {
+            self.query(|a| {
+                a.wheels
+                    .left_braking_force(Ratio::new::<ratio>(brake_force))
+                    .get::<ratio>()
+            })
+        }
+
+        fn right_braking_force(&self, brake_force: f64) -> f64 {
+            self.query(|a| {
+                a.wheels
+                    .right_braking_force(Ratio::new::<ratio>(brake_force))
+                    .get::<ratio>()
+            })
+        }
+    }
+    impl TestBed for WheelsTestBed {
+        type Aircraft = TestAircraft;
+
+        fn test_bed(&self) -> &SimulationTestBed<TestAircraft> {
+            &self.test_bed
+        }
+
+        fn test_bed_mut(&mut self) -> &mut SimulationTestBed<TestAircraft> {
+            &mut self.test_bed
+        }
+    }
+
+    #[test]
+    fn tyres_start_at_nominal_pressure() {
+        let mut test_bed = WheelsTestBed::new();
+
+        for number in 1..=4 {
+            assert!((test_bed.tyre_pressure(number) - 200.).abs() < 1.);
+        }
+    }
+
+    #[test]
+    fn healthy_wheels_apply_the_brake_force() {
+        let test_bed = WheelsTestBed::new();
+
+        assert!(test_bed.left_braking_force(0.).abs() < f64::EPSILON);
+        assert!((test_bed.left_braking_force(0.6) - 0.6).abs() < f64::EPSILON);
+        assert!((test_bed.right_braking_force(0.6) - 0.6).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn tyre_burst_deflates_the_tyre_and_drags_its_side() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.fail(FailureType::TyreBurst(1));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.tyre_pressure(1) < 1.);
+        assert!((test_bed.tyre_pressure(2) - 200.).abs() < 1.);
+        assert!(test_bed.left_braking_force(0.) > 0.1);
+        assert!(test_bed.right_braking_force(0.) < f64::EPSILON);
+    }
+
+    #[test]
+    fn tyre_burst_reduces_braking_of_its_side() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.fail(FailureType::TyreBurst(3));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.right_braking_force(1.) < 0.95);
+        assert!((test_bed.left_braking_force(1.) - 1.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn tyre_deflation_slowly_loses_pressure() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.fail(FailureType::TyreDeflation(2));
+        test_bed.run_for(Duration::from_secs(60));
+
+        let pressure = test_bed.tyre_pressure(2);
+        assert!(pressure < 195.);
+        assert!(pressure > 150.);
+
+        test_bed.run_for(Duration::from_secs(3600));
+        assert!(test_bed.tyre_pressure(2) < 10.);
+        assert!(test_bed.left_braking_force(0.) > 0.);
+    }
+
+    #[test]
+    fn serviced_tyre_gets_back_to_nominal_pressure() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.fail(FailureType::TyreBurst(4));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!(test_bed.tyre_pressure(4) < 1.);
+
+        test_bed.unfail(FailureType::TyreBurst(4));
+        test_bed.run_with_delta(Duration::from_secs(1));
+        assert!((test_bed.tyre_pressure(4) - 200.).abs() < 1.);
+        assert!(test_bed.right_braking_force(0.) < f64::EPSILON);
+    }
+
+    #[test]
+    fn seized_brake_drags_its_side_without_braking() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.fail(FailureType::BrakeSeizure(3));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.right_braking_force(0.) > 0.3);
+        assert!(test_bed.left_braking_force(0.) < f64::EPSILON);
+    }
+
+    #[test]
+    fn failed_wheels_do_not_drag_in_flight() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_on_ground(false);
+        test_bed.fail(FailureType::TyreBurst(1));
+        test_bed.fail(FailureType::BrakeSeizure(3));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!(test_bed.left_braking_force(0.) < f64::EPSILON);
+        assert!(test_bed.right_braking_force(0.) < f64::EPSILON);
+    }
+
+    #[test]
+    fn tyre_pressure_rises_with_tyre_temperature() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_ground_speed(Velocity::new::<knot>(60.));
+        test_bed.set_braking_force(0.5);
+        test_bed.run_for(Duration::from_secs(30));
+        test_bed.set_braking_force(0.);
+        test_bed.set_ground_speed(Velocity::new::<knot>(0.));
+        test_bed.run_for(Duration::from_secs(600));
+
+        assert!(test_bed.tyre_pressure(1) > 210.);
+    }
+
+    #[test]
+    fn melted_fuse_plugs_deflate_the_tyre() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_true_airspeed(Velocity::new::<knot>(150.));
+        test_bed.set_ground_speed(Velocity::new::<knot>(150.));
+        test_bed.set_braking_force(1.);
+        test_bed.run_for(Duration::from_secs(40));
+        test_bed.set_braking_force(0.);
+        test_bed.set_true_airspeed(Velocity::new::<knot>(0.));
+        test_bed.set_ground_speed(Velocity::new::<knot>(0.));
+        test_bed.run_for(Duration::from_secs(1800));
+
+        assert!(test_bed.query(|a| a.brakes.any_tyre_deflated()));
+        assert!(test_bed.tyre_pressure(1) < 10.);
+    }
+
+    #[test]
+    fn braking_on_dry_runway_does_not_slip() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_ground_speed(Velocity::new::<knot>(100.));
+        test_bed.set_braking_force(1.);
+        test_bed.run_for(Duration::from_secs(2));
+
+        assert!(test_bed.wheel_slip(1) < f64::EPSILON);
+        assert!(!test_bed.query(|a| a.wheels.wheel(1).is_locked()));
+        assert!((test_bed.left_braking_force(1.) - 1.).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn wet_runway_limits_the_braking_force() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_surface_condition(SurfaceCondition::Wet);
+        test_bed.set_ground_speed(Velocity::new::<knot>(100.));
+        test_bed.run_with_delta(Duration::from_secs(1));
+
+        assert!((test_bed.left_braking_force(0.5) - 0.5).abs() < f64::EPSILON);
+        assert!((test_bed.left_braking_force(1.) - 0.9).abs() < 0.001);
+    }
+
+    #[test]
+    fn hard_braking_on_icy_runway_locks_the_wheels() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_surface_condition(SurfaceCondition::Icy);
+        test_bed.set_ground_speed(Velocity::new::<knot>(100.));
+        test_bed.set_braking_force(1.);
+        test_bed.run_for(Duration::from_secs(1));
+
+        assert!(test_bed.wheel_slip(1) > 0.99);
+        assert!(test_bed.query(|a| a.wheels.wheel(4).is_locked()));
+        assert!(test_bed.query(|a| a.wheels.wheel(4).wheel_speed().get::<knot>()) < 1.);
+        assert!(test_bed.right_braking_force(1.) < 0.15);
+    }
+
+    #[test]
+    fn locked_wheels_spin_up_when_brakes_are_released() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_surface_condition(SurfaceCondition::Icy);
+        test_bed.set_ground_speed(Velocity::new::<knot>(100.));
+        test_bed.set_braking_force(1.);
+        test_bed.run_for(Duration::from_secs(1));
+        assert!(test_bed.wheel_slip(2) > 0.99);
+
+        test_bed.set_braking_force(0.);
+        test_bed.run_for(Duration::from_secs(1));
+
+        assert!(test_bed.wheel_slip(2) < f64::EPSILON);
+    }
+
+    #[test]
+    fn wheels_do_not_slip_when_stopped() {
+        let mut test_bed = WheelsTestBed::new();
+
+        test_bed.set_surface_condition(SurfaceCondition::Icy);
+        test_bed.set_braking_force(1.);
+        test_bed.run_for(Duration::from_secs(2));
+
+        assert!(test_bed.wheel_slip(1) < f64::EPSILON);
+    }
+}
@@ -13,6 +13,7 @@ pub mod electrical;
 pub mod engine;
 pub mod enhanced_gpwc;
 pub mod failures;
+pub mod fuel;
 pub mod hydraulic;
 pub mod indicating_recording;
 pub mod integrated_modular_avionics;
@@ -21,6 +22,7 @@ pub mod navigation;
 pub mod overhead;
 pub mod payload;
 pub mod pneumatic;
+pub mod probe_heat;
 pub mod shared;
 pub mod simulation;
 pub mod wind_turbine;
@@ -48,12 +48,16 @@ macro_rules! provide_potential {
 macro_rules! read_write_enum {
     ($t: ty) => {
         impl<T: Reader> Read<$t> for T {
+            const UNIT: &'static str = stringify!($t);
+
             fn convert(&mut self, value: f64) -> $t {
                 value.into()
             }
         }
 
         impl<T: Writer> Write<$t> for T {
+            const UNIT: &'static str = stringify!($t);
+
             fn convert(&mut self, value: $t) -> f64 {
                 value.into()
             }
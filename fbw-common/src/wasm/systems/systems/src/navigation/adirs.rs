@@ -5,7 +5,7 @@ use crate::{
     shared::{
         arinc429::{Arinc429Word, SignStatus},
         low_pass_filter::LowPassFilter,
-        AdirsDiscreteOutputs, AdirsMeasurementOutputs, MachNumber,
+        AdirsDiscreteOutputs, AdirsMeasurementOutputs, AirDataProbeIcing, MachNumber,
     },
     simulation::{
         Read, Reader, SimulationElement, SimulationElementVisitor, SimulatorReader,
@@ -88,6 +88,11 @@ impl AirDataInertialReferenceSystemOverheadPanel {
         self.mode_selectors[number - 1].mode()
     }
 
+    /// Indicates if the mode selector of the given ADIRU is set to NAV or ATT.
+    pub fn adiru_is_selected_on(&self, number: usize) -> bool {
+        self.mode_of(number) != InertialReferenceMode::Off
+    }
+
     fn adr_is_on(&self, number: usize) -> bool {
         self.adr[number - 1].is_on()
     }
@@ -389,12 +394,13 @@ impl AirDataInertialReferenceSystem {
         &mut self,
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
+        probe_icing: &impl AirDataProbeIcing,
     ) {
         let align_time = self.configured_align_time;
         let simulator_data = self.simulator_data;
-        self.adirus
-            .iter_mut()
-            .for_each(|adiru| adiru.update(context, overhead, align_time, simulator_data));
+        self.adirus.iter_mut().for_each(|adiru| {
+            adiru.update(context, overhead, align_time, simulator_data, probe_icing)
+        });
     }
 
     fn remaining_align_duration(&self) -> Duration {
@@ -501,6 +507,14 @@ impl AdirsMeasurementOutputs for AirDataInertialReferenceSystem {
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length> {
         self.adirus[adiru_number - 1].altitude()
     }
+
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].computed_airspeed()
+    }
+
+    fn ground_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity> {
+        self.adirus[adiru_number - 1].ground_speed()
+    }
 }
 
 struct AirDataInertialReferenceUnit {
@@ -535,8 +549,10 @@ impl AirDataInertialReferenceUnit {
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         align_time: AlignTime,
         simulator_data: AdirsSimulatorData,
+        probe_icing: &impl AirDataProbeIcing,
     ) {
-        self.adr.update(context, overhead, simulator_data);
+        self.adr
+            .update(context, overhead, simulator_data, probe_icing);
         self.ir
             .update(context, &self.adr, overhead, align_time, simulator_data);
 
@@ -653,6 +669,10 @@ impl AirDataInertialReferenceUnit {
         self.adr.altitude()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        self.adr.computed_airspeed()
+    }
+
     fn ground_speed(&self) -> Arinc429Word<Velocity> {
         self.ir.ground_speed()
     }
@@ -899,10 +919,15 @@ impl AirDataReference {
         context: &UpdateContext,
         overhead: &AirDataInertialReferenceSystemOverheadPanel,
         simulator_data: AdirsSimulatorData,
+        probe_icing: &impl AirDataProbeIcing,
     ) {
         self.is_on = overhead.adr_is_on(self.number);
         self.update_remaining_initialisation_duration(context, overhead);
         self.update_values(context, simulator_data);
+
+        if self.is_valid() {
+            self.degrade_values_of_iced_probes(probe_icing);
+        }
     }
 
     fn update_remaining_initialisation_duration(
@@ -1008,6 +1033,35 @@ impl AirDataReference {
         }
     }
 
+    /// Ice blocking a probe renders the data derived from it unreliable. The ADR
+    /// detects the implausible measurements and flags the affected labels as failed.
+    fn degrade_values_of_iced_probes(&mut self, probe_icing: &impl AirDataProbeIcing) {
+        let static_ports_are_iced = probe_icing.static_ports_are_iced(self.number);
+
+        if static_ports_are_iced {
+            self.corrected_average_static_pressure.set_failure_warning();
+            self.altitude.set_failure_warning();
+            self.baro_corrected_altitude_1.set_failure_warning();
+            self.baro_corrected_altitude_2.set_failure_warning();
+            self.barometric_vertical_speed.set_failure_warning();
+        }
+
+        if static_ports_are_iced || probe_icing.pitot_probe_is_iced(self.number) {
+            self.computed_airspeed.set_failure_warning();
+            self.true_airspeed.set_failure_warning();
+            self.mach.set_failure_warning();
+        }
+
+        if probe_icing.angle_of_attack_probe_is_iced(self.number) {
+            self.angle_of_attack.set_failure_warning();
+        }
+
+        if probe_icing.total_air_temperature_probe_is_iced(self.number) {
+            self.total_air_temperature.set_failure_warning();
+            self.static_air_temperature.set_failure_warning();
+        }
+    }
+
     fn is_initialised(&self) -> bool {
         self.remaining_initialisation_duration == Some(Duration::from_secs(0))
     }
@@ -1020,6 +1074,10 @@ impl AirDataReference {
         self.computed_airspeed.value()
     }
 
+    fn computed_airspeed(&self) -> Arinc429Word<Velocity> {
+        Arinc429Word::new(self.computed_airspeed.value(), self.computed_airspeed.ssm())
+    }
+
     fn altitude(&self) -> Arinc429Word<Length> {
         Arinc429Word::new(self.altitude.value(), self.altitude.ssm())
     }
@@ -1868,21 +1926,48 @@ mod tests {
         velocity::{foot_per_minute, knot},
     };
 
+    #[derive(Default)]
+    struct TestAirDataProbes {
+        iced_pitot_probes: [bool; 3],
+        iced_static_ports: [bool; 3],
+        iced_angle_of_attack_probes: [bool; 3],
+        iced_total_air_temperature_probes: [bool; 3],
+    }
+    impl AirDataProbeIcing for TestAirDataProbes {
+        fn pitot_probe_is_iced(&self, adr_number: usize) -> bool {
+            self.iced_pitot_probes[adr_number - 1]
+        }
+
+        fn static_ports_are_iced(&self, adr_number: usize) -> bool {
+            self.iced_static_ports[adr_number - 1]
+        }
+
+        fn angle_of_attack_probe_is_iced(&self, adr_number: usize) -> bool {
+            self.iced_angle_of_attack_probes[adr_number - 1]
+        }
+
+        fn total_air_temperature_probe_is_iced(&self, adr_number: usize) -> bool {
+            self.iced_total_air_temperature_probes[adr_number - 1]
+        }
+    }
+
     struct TestAircraft {
         adirs: AirDataInertialReferenceSystem,
         overhead: AirDataInertialReferenceSystemOverheadPanel,
+        probes: TestAirDataProbes,
     }
     impl TestAircraft {
         fn new(context: &mut InitContext) -> Self {
             Self {
                 adirs: AirDataInertialReferenceSystem::new(context),
                 overhead: AirDataInertialReferenceSystemOverheadPanel::new(context),
+                probes: TestAirDataProbes::default(),
             }
         }
     }
     impl Aircraft for TestAircraft {
         fn update_after_power_distribution(&mut self, context: &UpdateContext) {
-            self.adirs.update(context, &self.overhead);
+            self.adirs.update(context, &self.overhead, &self.probes);
             self.overhead.update(context, &self.adirs);
         }
     }
@@ -2081,6 +2166,26 @@ mod tests {
             self
         }
 
+        fn iced_pitot_probe(mut self, adr_number: usize) -> Self {
+            self.command(|a| a.probes.iced_pitot_probes[adr_number - 1] = true);
+            self
+        }
+
+        fn iced_static_ports(mut self, adr_number: usize) -> Self {
+            self.command(|a| a.probes.iced_static_ports[adr_number - 1] = true);
+            self
+        }
+
+        fn iced_angle_of_attack_probe(mut self, adr_number: usize) -> Self {
+            self.command(|a| a.probes.iced_angle_of_attack_probes[adr_number - 1] = true);
+            self
+        }
+
+        fn iced_total_air_temperature_probe(mut self, adr_number: usize) -> Self {
+            self.command(|a| a.probes.iced_total_air_temperature_probes[adr_number - 1] = true);
+            self
+        }
+
         fn ir_push_button_off(mut self, number: usize) -> Self {
             self.write_by_name(
                 &OnOffFaultPushButton::is_on_id(&format!("ADIRS_IR_{}", number)),
@@ -3365,6 +3470,131 @@ mod tests {
                 SignStatus::NoComputedData
             );
         }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn airspeeds_are_failed_when_pitot_probe_is_iced(#[case] adiru_number: usize) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .mach_of(MachNumber::from(0.5))
+                .true_airspeed_of(Velocity::new::<knot>(300.))
+                .and()
+                .iced_pitot_probe(adiru_number);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.computed_airspeed(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+            assert_eq!(
+                test_bed.true_airspeed(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+            assert_eq!(
+                test_bed.mach(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+            assert_eq!(
+                test_bed.altitude(adiru_number).ssm(),
+                SignStatus::NormalOperation
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn altitude_and_airspeed_are_failed_when_static_ports_are_iced(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with().iced_static_ports(adiru_number);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.altitude(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+            assert_eq!(
+                test_bed.barometric_vertical_speed(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+            assert_eq!(
+                test_bed.computed_airspeed(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn angle_of_attack_is_failed_when_angle_of_attack_probe_is_iced(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .angle_of_attack_of(Angle::new::<degree>(1.))
+                .and()
+                .iced_angle_of_attack_probe(adiru_number);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.angle_of_attack(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+            assert_eq!(
+                test_bed.computed_airspeed(adiru_number).ssm(),
+                SignStatus::NormalOperation
+            );
+        }
+
+        #[rstest]
+        #[case(1)]
+        #[case(2)]
+        #[case(3)]
+        fn temperatures_are_failed_when_total_air_temperature_probe_is_iced(
+            #[case] adiru_number: usize,
+        ) {
+            let mut test_bed =
+                all_adirus_aligned_test_bed_with().iced_total_air_temperature_probe(adiru_number);
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.total_air_temperature(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+            assert_eq!(
+                test_bed.static_air_temperature(adiru_number).ssm(),
+                SignStatus::FailureWarning
+            );
+        }
+
+        #[rstest]
+        #[case(1, 2)]
+        #[case(2, 3)]
+        #[case(3, 1)]
+        fn iced_probes_only_affect_the_adr_they_supply(
+            #[case] iced_adr_number: usize,
+            #[case] other_adr_number: usize,
+        ) {
+            let mut test_bed = all_adirus_aligned_test_bed_with()
+                .iced_pitot_probe(iced_adr_number)
+                .iced_static_ports(iced_adr_number);
+            test_bed.set_indicated_airspeed(Velocity::new::<knot>(250.));
+            test_bed.run();
+
+            assert_eq!(
+                test_bed.computed_airspeed(other_adr_number).ssm(),
+                SignStatus::NormalOperation
+            );
+            assert_eq!(
+                test_bed.altitude(other_adr_number).ssm(),
+                SignStatus::NormalOperation
+            );
+        }
     }
 
     mod ir {
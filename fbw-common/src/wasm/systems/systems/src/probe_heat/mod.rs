@@ -0,0 +1,650 @@
+//! Electrical heating of the air data probes and the windows.
+//!
+//! Each probe heat computer controls and monitors the heaters of one side of the aircraft.
+//! Heaters which don't heat in icing conditions accrete ice. Ice blocking an air data probe
+//! renders the data derived from it unreliable.
+
+use crate::{
+    electrical::consumption::SwitchedPowerConsumer,
+    engine::Engine,
+    failures::{Failure, FailureType},
+    shared::{AirDataProbeIcing, ElectricalBusType, ElectricalBuses, HeatedElement},
+    simulation::{
+        InitContext, Read, SimulationElement, SimulationElementVisitor, SimulatorReader,
+        SimulatorWriter, SnapshotReader, SnapshotWriter, UpdateContext, VariableIdentifier, Write,
+    },
+};
+use uom::si::{f64::*, power::watt, ratio::ratio, thermodynamic_temperature::degree_celsius};
+
+/// Determines whether the aircraft flies in conditions in which ice accretes
+/// on unheated surfaces: visible moisture at temperatures near or below freezing.
+pub struct IcingConditions {
+    in_cloud_id: VariableIdentifier,
+    precipitation_rate_id: VariableIdentifier,
+
+    is_in_cloud: bool,
+    precipitation_rate: f64,
+    are_present: bool,
+}
+impl IcingConditions {
+    const MAX_ICING_TEMPERATURE_DEGREE_CELSIUS: f64 = 10.;
+    const MIN_ICING_TEMPERATURE_DEGREE_CELSIUS: f64 = -40.;
+
+    pub fn new(context: &mut InitContext) -> Self {
+        Self {
+            in_cloud_id: context.get_identifier("AMBIENT IN CLOUD".to_owned()),
+            precipitation_rate_id: context.get_identifier("AMBIENT PRECIP RATE".to_owned()),
+
+            is_in_cloud: false,
+            precipitation_rate: 0.,
+            are_present: false,
+        }
+    }
+
+    pub fn update(&mut self, context: &UpdateContext) {
+        let temperature = context.ambient_temperature().get::<degree_celsius>();
+        let has_visible_moisture = self.is_in_cloud || self.precipitation_rate > 0.;
+
+        self.are_present = has_visible_moisture
+            && (Self::MIN_ICING_TEMPERATURE_DEGREE_CELSIUS
+                ..=Self::MAX_ICING_TEMPERATURE_DEGREE_CELSIUS)
+                .contains(&temperature);
+    }
+
+    pub fn are_present(&self) -> bool {
+        self.are_present
+    }
+}
+impl SimulationElement for IcingConditions {
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.is_in_cloud = reader.read(&self.in_cloud_id);
+        self.precipitation_rate = reader.read(&self.precipitation_rate_id);
+    }
+}
+
+/// The electrical heater of a single probe or window.
+struct Heater {
+    is_heating_id: VariableIdentifier,
+    fault_id: VariableIdentifier,
+
+    element: HeatedElement,
+    consumer: SwitchedPowerConsumer,
+    failure: Failure,
+    is_heating: bool,
+    has_fault: bool,
+    ice: Ratio,
+}
+impl Heater {
+    const FULL_ICE_ACCRETION_DURATION_SECS: f64 = 120.;
+    const DE_ICING_DURATION_SECS: f64 = 20.;
+    const NATURAL_MELTING_DURATION_SECS: f64 = 300.;
+    const BLOCKING_ICE_RATIO: f64 = 0.5;
+
+    fn new(
+        context: &mut InitContext,
+        computer_number: usize,
+        element: HeatedElement,
+        powered_by: ElectricalBusType,
+    ) -> Self {
+        Self {
+            is_heating_id: context.get_identifier(format!(
+                "PROBE_HEAT_{}_{}_IS_HEATING",
+                computer_number, element
+            )),
+            fault_id: context.get_identifier(format!(
+                "PROBE_HEAT_{}_{}_HAS_FAULT",
+                computer_number, element
+            )),
+
+            element,
+            consumer: SwitchedPowerConsumer::new(powered_by, Self::demand(element)),
+            failure: Failure::new(FailureType::ProbeHeat(computer_number, element)),
+            is_heating: false,
+            has_fault: false,
+            ice: Ratio::new::<ratio>(0.),
+        }
+    }
+
+    fn demand(element: HeatedElement) -> Power {
+        Power::new::<watt>(match element {
+            HeatedElement::PitotProbe => 200.,
+            HeatedElement::StaticPorts => 60.,
+            HeatedElement::AngleOfAttackProbe => 120.,
+            HeatedElement::TotalAirTemperatureProbe => 100.,
+            HeatedElement::Windshield => 1500.,
+            HeatedElement::SideWindow => 250.,
+        })
+    }
+
+    /// On the ground the windshields and pitot probes are heated at low power,
+    /// and the TAT probes aren't heated at all to prevent them from overheating.
+    fn ground_power_ratio(element: HeatedElement) -> f64 {
+        match element {
+            HeatedElement::PitotProbe => 0.3,
+            HeatedElement::Windshield => 1. / 3.,
+            HeatedElement::TotalAirTemperatureProbe => 0.,
+            _ => 1.,
+        }
+    }
+
+    fn update(
+        &mut self,
+        context: &UpdateContext,
+        heating_is_required: bool,
+        computer_is_powered: bool,
+        icing_conditions: &IcingConditions,
+    ) {
+        let required_ratio = match (heating_is_required, context.is_in_flight()) {
+            (false, _) => 0.,
+            (true, true) => 1.,
+            (true, false) => Self::ground_power_ratio(self.element),
+        };
+
+        self.consumer
+            .update_ratio(if computer_is_powered && !self.failure.is_active() {
+                required_ratio
+            } else {
+                0.
+            });
+        self.is_heating = self.consumer.is_running();
+        // An unpowered computer cannot monitor its heaters.
+        self.has_fault = computer_is_powered && required_ratio > 0. && !self.is_heating;
+
+        self.update_ice(context, icing_conditions);
+    }
+
+    fn update_ice(&mut self, context: &UpdateContext, icing_conditions: &IcingConditions) {
+        let delta = context.delta_as_secs_f64();
+        let change = if self.is_heating {
+            -delta / Self::DE_ICING_DURATION_SECS
+        } else if icing_conditions.are_present() {
+            delta / Self::FULL_ICE_ACCRETION_DURATION_SECS
+        } else if context.ambient_temperature().get::<degree_celsius>() > 0. {
+            -delta / Self::NATURAL_MELTING_DURATION_SECS
+        } else {
+            0.
+        };
+
+        self.ice = Ratio::new::<ratio>((self.ice.get::<ratio>() + change).clamp(0., 1.));
+    }
+
+    fn is_iced(&self) -> bool {
+        self.ice.get::<ratio>() >= Self::BLOCKING_ICE_RATIO
+    }
+}
+impl SimulationElement for Heater {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.consumer.accept(visitor);
+        self.failure.accept(visitor);
+
+        visitor.visit(self);
+    }
+
+    fn write(&self, writer: &mut SimulatorWriter) {
+        writer.write(&self.is_heating_id, self.is_heating);
+        writer.write(&self.fault_id, self.has_fault);
+    }
+
+    fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+        snapshot.write(self.ice);
+    }
+
+    fn restore(&mut self, snapshot: &mut SnapshotReader) {
+        self.ice = snapshot.read();
+    }
+}
+
+/// A probe heat computer controls and monitors the heaters of the probes and windows
+/// of one side of the aircraft. Heating is automatically selected on when at least one
+/// engine is running or the aircraft is in flight. It can be selected on manually.
+/// A heater which should heat but doesn't is reported as faulty, as long as the computer
+/// itself is powered.
+pub struct ProbeHeatComputer {
+    powered_by: ElectricalBusType,
+    is_powered: bool,
+    heaters: Vec<Heater>,
+}
+impl ProbeHeatComputer {
+    pub fn new(
+        context: &mut InitContext,
+        number: usize,
+        powered_by: ElectricalBusType,
+        heaters: &[(HeatedElement, ElectricalBusType)],
+    ) -> Self {
+        Self {
+            powered_by,
+            is_powered: false,
+            heaters: heaters
+                .iter()
+                .map(|&(element, bus)| Heater::new(context, number, element, bus))
+                .collect(),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        context: &UpdateContext,
+        heating_is_selected_on: bool,
+        any_engine_running: bool,
+        icing_conditions: &IcingConditions,
+    ) {
+        let heating_is_required =
+            heating_is_selected_on || any_engine_running || context.is_in_flight();
+
+        let is_powered = self.is_powered;
+        self.heaters.iter_mut().for_each(|heater| {
+            heater.update(context, heating_is_required, is_powered, icing_conditions)
+        });
+    }
+
+    /// Indicates if the given element is blocked by ice. Elements which
+    /// aren't heated by this computer are never reported as iced.
+    pub fn is_iced(&self, element: HeatedElement) -> bool {
+        self.heater(element)
+            .map_or(false, |heater| heater.is_iced())
+    }
+
+    pub fn is_heating(&self, element: HeatedElement) -> bool {
+        self.heater(element)
+            .map_or(false, |heater| heater.is_heating)
+    }
+
+    pub fn has_fault(&self, element: HeatedElement) -> bool {
+        self.heater(element)
+            .map_or(false, |heater| heater.has_fault)
+    }
+
+    pub fn any_fault(&self) -> bool {
+        self.heaters.iter().any(|heater| heater.has_fault)
+    }
+
+    fn heater(&self, element: HeatedElement) -> Option<&Heater> {
+        self.heaters.iter().find(|heater| heater.element == element)
+    }
+}
+impl SimulationElement for ProbeHeatComputer {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        accept_iterable!(self.heaters, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn receive_power(&mut self, buses: &impl ElectricalBuses) {
+        self.is_powered = buses.is_powered(self.powered_by);
+    }
+}
+
+/// The probe and window heat of the aircraft. The captain's, first officer's and
+/// standby probes are each controlled by their own probe heat computer. The computers
+/// of the captain's and first officer's side also heat their windshield and side window.
+/// There is no standby TAT probe, thus the standby computer doesn't heat one.
+pub struct ProbeHeat {
+    heating_is_selected_on_id: VariableIdentifier,
+    heating_is_selected_on: bool,
+
+    icing_conditions: IcingConditions,
+    computers: [ProbeHeatComputer; 3],
+}
+impl ProbeHeat {
+    /// Creates the probe heat of an aircraft whose captain's, first officer's and
+    /// standby sides are powered by the given buses, in that order.
+    pub fn new(
+        context: &mut InitContext,
+        computer_powered_by: [ElectricalBusType; 3],
+        probes_powered_by: [ElectricalBusType; 3],
+        windows_powered_by: [ElectricalBusType; 2],
+    ) -> Self {
+        Self {
+            heating_is_selected_on_id: context.get_identifier("MAN_PITOT_HEAT".to_owned()),
+            heating_is_selected_on: false,
+
+            icing_conditions: IcingConditions::new(context),
+            computers: [1, 2, 3].map(|number| {
+                let probes_bus = probes_powered_by[number - 1];
+                let mut heaters = vec![
+                    (HeatedElement::PitotProbe, probes_bus),
+                    (HeatedElement::StaticPorts, probes_bus),
+                    (HeatedElement::AngleOfAttackProbe, probes_bus),
+                ];
+
+                if let Some(&windows_bus) = windows_powered_by.get(number - 1) {
+                    heaters.extend([
+                        (HeatedElement::TotalAirTemperatureProbe, probes_bus),
+                        (HeatedElement::Windshield, windows_bus),
+                        (HeatedElement::SideWindow, windows_bus),
+                    ]);
+                }
+
+                ProbeHeatComputer::new(context, number, computer_powered_by[number - 1], &heaters)
+            }),
+        }
+    }
+
+    pub fn update<const N: usize>(&mut self, context: &UpdateContext, engines: [&impl Engine; N]) {
+        self.icing_conditions.update(context);
+
+        let any_engine_running = engines.iter().any(|engine| engine.is_above_minimum_idle());
+        for computer in self.computers.iter_mut() {
+            computer.update(
+                context,
+                self.heating_is_selected_on,
+                any_engine_running,
+                &self.icing_conditions,
+            );
+        }
+    }
+}
+impl AirDataProbeIcing for ProbeHeat {
+    fn pitot_probe_is_iced(&self, adr_number: usize) -> bool {
+        self.computers[adr_number - 1].is_iced(HeatedElement::PitotProbe)
+    }
+
+    fn static_ports_are_iced(&self, adr_number: usize) -> bool {
+        self.computers[adr_number - 1].is_iced(HeatedElement::StaticPorts)
+    }
+
+    fn angle_of_attack_probe_is_iced(&self, adr_number: usize) -> bool {
+        self.computers[adr_number - 1].is_iced(HeatedElement::AngleOfAttackProbe)
+    }
+
+    fn total_air_temperature_probe_is_iced(&self, adr_number: usize) -> bool {
+        // There is no standby TAT probe. ADR 3 receives the total air temperature
+        // from the captain's TAT probe.
+        let computer_number = if adr_number == 3 { 1 } else { adr_number };
+
+        self.computers[computer_number - 1].is_iced(HeatedElement::TotalAirTemperatureProbe)
+    }
+}
+impl SimulationElement for ProbeHeat {
+    fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+        self.icing_conditions.accept(visitor);
+        accept_iterable!(self.computers, visitor);
+
+        visitor.visit(self);
+    }
+
+    fn read(&mut self, reader: &mut SimulatorReader) {
+        self.heating_is_selected_on = reader.read(&self.heating_is_selected_on_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        electrical::{test::TestElectricitySource, ElectricalBus, Electricity},
+        shared::{PotentialOrigin, PowerConsumptionReport},
+        simulation::{
+            test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+            Aircraft,
+        },
+    };
+    use std::time::Duration;
+    use uom::si::electric_potential::volt;
+
+    struct TestAircraft {
+        electricity_source: TestElectricitySource,
+        dc_bus: ElectricalBus,
+        ac_bus: ElectricalBus,
+        is_ac_bus_powered: bool,
+        is_dc_bus_powered: bool,
+        consumption: Power,
+
+        icing_conditions: IcingConditions,
+        computer: ProbeHeatComputer,
+        heating_is_selected_on: bool,
+        any_engine_running: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                electricity_source: TestElectricitySource::powered(
+                    context,
+                    PotentialOrigin::EngineGenerator(1),
+                ),
+                dc_bus: ElectricalBus::new(context, ElectricalBusType::DirectCurrent(1)),
+                ac_bus: ElectricalBus::new(context, ElectricalBusType::AlternatingCurrent(1)),
+                is_ac_bus_powered: true,
+                is_dc_bus_powered: true,
+                consumption: Power::new::<watt>(0.),
+
+                icing_conditions: IcingConditions::new(context),
+                computer: ProbeHeatComputer::new(
+                    context,
+                    1,
+                    ElectricalBusType::DirectCurrent(1),
+                    &[
+                        (
+                            HeatedElement::PitotProbe,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                        (
+                            HeatedElement::TotalAirTemperatureProbe,
+                            ElectricalBusType::AlternatingCurrent(1),
+                        ),
+                    ],
+                ),
+                heating_is_selected_on: false,
+                any_engine_running: false,
+            }
+        }
+
+        fn select_heating_on(&mut self) {
+            self.heating_is_selected_on = true;
+        }
+
+        fn unpower_ac_bus(&mut self) {
+            self.is_ac_bus_powered = false;
+        }
+
+        fn unpower_dc_bus(&mut self) {
+            self.is_dc_bus_powered = false;
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_before_power_distribution(
+            &mut self,
+            _: &UpdateContext,
+            electricity: &mut Electricity,
+        ) {
+            self.electricity_source
+                .power_with_potential(ElectricPotential::new::<volt>(115.));
+            electricity.supplied_by(&self.electricity_source);
+
+            if self.is_dc_bus_powered {
+                electricity.flow(&self.electricity_source, &self.dc_bus);
+            }
+
+            if self.is_ac_bus_powered {
+                electricity.flow(&self.electricity_source, &self.ac_bus);
+            }
+        }
+
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.icing_conditions.update(context);
+            self.computer.update(
+                context,
+                self.heating_is_selected_on,
+                self.any_engine_running,
+                &self.icing_conditions,
+            );
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.icing_conditions.accept(visitor);
+            self.computer.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn process_power_consumption_report<T: PowerConsumptionReport>(
+            &mut self,
+            _: &UpdateContext,
+            report: &T,
+        ) {
+            self.consumption = report.total_consumption_of(PotentialOrigin::EngineGenerator(1));
+        }
+    }
+
+    fn test_bed() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = SimulationTestBed::new(TestAircraft::new);
+        test_bed.set_ambient_temperature(ThermodynamicTemperature::new::<degree_celsius>(-5.));
+
+        test_bed
+    }
+
+    fn in_flight_in_icing_conditions() -> SimulationTestBed<TestAircraft> {
+        let mut test_bed = test_bed();
+        test_bed.set_on_ground(false);
+        test_bed.write_by_name("AMBIENT IN CLOUD", true);
+
+        test_bed
+    }
+
+    #[test]
+    fn heaters_are_off_on_the_ground_with_engines_off() {
+        let mut test_bed = test_bed();
+        test_bed.set_on_ground(true);
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.computer.is_heating(HeatedElement::PitotProbe)));
+        assert_eq!(test_bed.query(|a| a.consumption), Power::new::<watt>(0.));
+    }
+
+    #[test]
+    fn heaters_are_on_in_flight() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.computer.is_heating(HeatedElement::PitotProbe)));
+        assert!(test_bed.query(|a| a
+            .computer
+            .is_heating(HeatedElement::TotalAirTemperatureProbe)));
+        assert_eq!(test_bed.query(|a| a.consumption), Power::new::<watt>(300.));
+    }
+
+    #[test]
+    fn tat_probe_isnt_heated_and_pitot_probe_is_heated_at_low_power_on_the_ground() {
+        let mut test_bed = test_bed();
+        test_bed.set_on_ground(true);
+        test_bed.command(|a| a.select_heating_on());
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.computer.is_heating(HeatedElement::PitotProbe)));
+        assert!(!test_bed.query(|a| a
+            .computer
+            .is_heating(HeatedElement::TotalAirTemperatureProbe)));
+        assert!(!test_bed.query(|a| a.computer.any_fault()));
+        assert!((test_bed.query(|a| a.consumption).get::<watt>() - 60.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn heaters_are_on_when_an_engine_is_running() {
+        let mut test_bed = test_bed();
+        test_bed.set_on_ground(true);
+        test_bed.command(|a| a.any_engine_running = true);
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.computer.is_heating(HeatedElement::PitotProbe)));
+    }
+
+    #[test]
+    fn failed_heater_is_reported_as_faulty() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.fail(FailureType::ProbeHeat(1, HeatedElement::PitotProbe));
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.computer.is_heating(HeatedElement::PitotProbe)));
+        assert!(test_bed.query(|a| a.computer.has_fault(HeatedElement::PitotProbe)));
+        assert!(!test_bed.query(|a| a
+            .computer
+            .has_fault(HeatedElement::TotalAirTemperatureProbe)));
+
+        let has_fault: bool = test_bed.read_by_name("PROBE_HEAT_1_PITOT_HAS_FAULT");
+        assert!(has_fault);
+    }
+
+    #[test]
+    fn unpowered_heater_is_reported_as_faulty() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.command(|a| a.unpower_ac_bus());
+        test_bed.run();
+
+        assert!(test_bed.query(|a| a.computer.has_fault(HeatedElement::PitotProbe)));
+    }
+
+    #[test]
+    fn unpowered_computer_doesnt_report_faults() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.command(|a| a.unpower_dc_bus());
+        test_bed.run();
+
+        assert!(!test_bed.query(|a| a.computer.is_heating(HeatedElement::PitotProbe)));
+        assert!(!test_bed.query(|a| a.computer.any_fault()));
+    }
+
+    #[test]
+    fn writes_whether_heaters_are_heating() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.run();
+
+        let is_heating: bool = test_bed.read_by_name("PROBE_HEAT_1_PITOT_IS_HEATING");
+        assert!(is_heating);
+    }
+
+    #[test]
+    fn unheated_probe_ices_up_in_icing_conditions() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.fail(FailureType::ProbeHeat(1, HeatedElement::PitotProbe));
+
+        test_bed.run_multiple_frames(Duration::from_secs(90));
+
+        assert!(test_bed.query(|a| a.computer.is_iced(HeatedElement::PitotProbe)));
+        assert!(!test_bed.query(|a| a.computer.is_iced(HeatedElement::TotalAirTemperatureProbe)));
+    }
+
+    #[test]
+    fn unheated_probe_doesnt_ice_up_outside_icing_conditions() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.write_by_name("AMBIENT IN CLOUD", false);
+        test_bed.fail(FailureType::ProbeHeat(1, HeatedElement::PitotProbe));
+
+        test_bed.run_multiple_frames(Duration::from_secs(90));
+
+        assert!(!test_bed.query(|a| a.computer.is_iced(HeatedElement::PitotProbe)));
+    }
+
+    #[test]
+    fn restored_heating_clears_the_ice() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.fail(FailureType::ProbeHeat(1, HeatedElement::PitotProbe));
+        test_bed.run_multiple_frames(Duration::from_secs(120));
+
+        test_bed.unfail(FailureType::ProbeHeat(1, HeatedElement::PitotProbe));
+        test_bed.run_multiple_frames(Duration::from_secs(20));
+
+        assert!(!test_bed.query(|a| a.computer.is_iced(HeatedElement::PitotProbe)));
+    }
+
+    #[test]
+    fn ice_is_restored_from_snapshot() {
+        let mut iced = in_flight_in_icing_conditions();
+        iced.fail(FailureType::ProbeHeat(1, HeatedElement::PitotProbe));
+        iced.run_multiple_frames(Duration::from_secs(90));
+        let snapshot = iced.snapshot();
+
+        let mut test_bed = test_bed();
+        test_bed.restore(&snapshot).unwrap();
+
+        assert!(test_bed.query(|a| a.computer.is_iced(HeatedElement::PitotProbe)));
+    }
+
+    #[test]
+    fn elements_without_a_heater_are_never_iced() {
+        let mut test_bed = in_flight_in_icing_conditions();
+        test_bed.run_multiple_frames(Duration::from_secs(120));
+
+        assert!(!test_bed.query(|a| a.computer.is_iced(HeatedElement::StaticPorts)));
+    }
+}
@@ -41,6 +41,15 @@ pub trait AuxiliaryPowerUnitElectrical:
     fn output_within_normal_parameters(&self) -> bool;
 }
 
+/// An APU driving more than one generator, each of them supplying its own generator line.
+/// As an [`ElectricitySource`], such an APU is its first generator.
+pub trait AuxiliaryPowerUnitGenerators {
+    type Generator: ElectricalElement + ElectricitySource;
+
+    /// The generator with the given number, starting at 1.
+    fn generator(&self, number: usize) -> &Self::Generator;
+}
+
 pub trait ApuAvailable {
     fn is_available(&self) -> bool;
 }
@@ -238,6 +247,8 @@ pub trait AdirsMeasurementOutputs {
     fn true_heading(&self, adiru_number: usize) -> Arinc429Word<Angle>;
     fn vertical_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
     fn altitude(&self, adiru_number: usize) -> Arinc429Word<Length>;
+    fn computed_airspeed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
+    fn ground_speed(&self, adiru_number: usize) -> Arinc429Word<Velocity>;
 }
 
 pub trait AdirsDiscreteOutputs {
@@ -247,6 +258,14 @@ pub trait AdirsDiscreteOutputs {
     fn low_speed_warning_4_260kts(&self, adiru_number: usize) -> bool;
 }
 
+/// Reports whether the probes which supply an ADR with air data are blocked by ice.
+pub trait AirDataProbeIcing {
+    fn pitot_probe_is_iced(&self, adr_number: usize) -> bool;
+    fn static_ports_are_iced(&self, adr_number: usize) -> bool;
+    fn angle_of_attack_probe_is_iced(&self, adr_number: usize) -> bool;
+    fn total_air_temperature_probe_is_iced(&self, adr_number: usize) -> bool;
+}
+
 pub enum GearWheel {
     NOSE = 0,
     LEFT = 1,
@@ -260,6 +279,29 @@ pub trait SectionPressure {
     fn is_pressure_switch_pressurised(&self) -> bool;
 }
 
+/// The probes and windows which are electrically heated to prevent icing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeatedElement {
+    PitotProbe,
+    StaticPorts,
+    AngleOfAttackProbe,
+    TotalAirTemperatureProbe,
+    Windshield,
+    SideWindow,
+}
+impl Display for HeatedElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PitotProbe => write!(f, "PITOT"),
+            Self::StaticPorts => write!(f, "STATIC"),
+            Self::AngleOfAttackProbe => write!(f, "AOA"),
+            Self::TotalAirTemperatureProbe => write!(f, "TAT"),
+            Self::Windshield => write!(f, "WINDSHIELD"),
+            Self::SideWindow => write!(f, "WINDOW"),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HydraulicColor {
     Green,
@@ -396,7 +438,7 @@ pub trait ElectricalBuses {
 pub enum PotentialOrigin {
     EngineGenerator(usize),
     ApuGenerator(usize),
-    External,
+    External(usize),
     EmergencyGenerator,
     Battery(usize),
     TransformerRectifier(usize),
@@ -407,7 +449,7 @@ impl Display for PotentialOrigin {
         match self {
             PotentialOrigin::EngineGenerator(number) => write!(f, "EngineGenerator({})", number),
             PotentialOrigin::ApuGenerator(number) => write!(f, "ApuGenerator({})", number),
-            PotentialOrigin::External => write!(f, "ExternalPower"),
+            PotentialOrigin::External(number) => write!(f, "ExternalPower({})", number),
             PotentialOrigin::EmergencyGenerator => write!(f, "EmergencyGenerator"),
             PotentialOrigin::Battery(number) => write!(f, "Battery({})", number),
             PotentialOrigin::TransformerRectifier(number) => {
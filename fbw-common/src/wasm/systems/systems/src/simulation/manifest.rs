@@ -0,0 +1,459 @@
+//! A manifest of all variables through which an aircraft exchanges data with the simulator.
+//!
+//! The [`VariableManifest`] of an aircraft lists every registered variable together with the
+//! elements reading and writing it, and the unit or type in which they do so. It is built by
+//! visiting a freshly constructed aircraft once. Variables which an element only reads or
+//! writes in specific states, such as after a failure, might therefore appear as only
+//! registered. Accesses made outside of the aircraft, such as by the bridging layer between
+//! the simulator and the aircraft, can be added using [`VariableManifest::add_access`].
+use std::{
+    any::type_name,
+    fmt::{self, Display},
+    io::{self, Write},
+    time::Duration,
+};
+
+use fxhash::FxHashMap;
+
+use super::{
+    Aircraft, InitContext, Simulation, SimulationElement, SimulationElementVisitor,
+    SimulatorReader, SimulatorReaderWriter, SimulatorWriter, StartState, UpdateContext,
+    VariableIdentifier, VariableRegistry,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VariableDirection {
+    Read,
+    Written,
+}
+impl Display for VariableDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VariableDirection::Read => write!(f, "read"),
+            VariableDirection::Written => write!(f, "written"),
+        }
+    }
+}
+
+/// A read or write of a variable by an element. The unit is `None` when the
+/// element accesses the raw value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VariableAccess {
+    pub direction: VariableDirection,
+    pub element: &'static str,
+    pub unit: Option<&'static str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    name: String,
+    accesses: Vec<VariableAccess>,
+}
+impl ManifestEntry {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn accesses(&self) -> &[VariableAccess] {
+        &self.accesses
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.has_access(VariableDirection::Read)
+    }
+
+    pub fn is_written(&self) -> bool {
+        self.has_access(VariableDirection::Written)
+    }
+
+    fn has_access(&self, direction: VariableDirection) -> bool {
+        self.accesses
+            .iter()
+            .any(|access| access.direction == direction)
+    }
+}
+
+pub struct VariableManifest {
+    entries: Vec<ManifestEntry>,
+}
+impl VariableManifest {
+    const HEADER: &'static str = "name\tdirection\telement\tunit";
+    const REGISTERED_ONLY: &'static str = "registered";
+
+    /// Builds the manifest of the aircraft constructed by the given function.
+    pub fn new<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+        start_state: StartState,
+        aircraft_ctor_fn: U,
+    ) -> Self {
+        let mut registry = ManifestVariableRegistry::default();
+        let mut simulation = Simulation::new(start_state, aircraft_ctor_fn, &mut registry);
+
+        let mut reader_writer = ManifestReaderWriter::new(element_name::<UpdateContext>());
+        simulation.update_context.update(
+            &mut SimulatorReader::new(&mut reader_writer),
+            Duration::from_millis(50),
+            0.,
+        );
+        simulation.accept(&mut ManifestVisitor {
+            reader_writer: &mut reader_writer,
+        });
+
+        Self::from_accesses(registry.name_to_identifier, reader_writer.accesses)
+    }
+
+    fn from_accesses(
+        name_to_identifier: FxHashMap<String, VariableIdentifier>,
+        mut accesses: FxHashMap<VariableIdentifier, Vec<VariableAccess>>,
+    ) -> Self {
+        let mut entries: Vec<ManifestEntry> = name_to_identifier
+            .into_iter()
+            .map(|(name, identifier)| {
+                let mut accesses = accesses.remove(&identifier).unwrap_or_default();
+                accesses.sort();
+                accesses.dedup();
+
+                ManifestEntry { name, accesses }
+            })
+            .collect();
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self { entries }
+    }
+
+    /// Adds an access made outside of the aircraft. The variable is added to the
+    /// manifest when the aircraft doesn't register it itself.
+    pub fn add_access(&mut self, name: &str, access: VariableAccess) {
+        let index = match self
+            .entries
+            .binary_search_by(|entry| entry.name.as_str().cmp(name))
+        {
+            Ok(index) => index,
+            Err(index) => {
+                self.entries.insert(
+                    index,
+                    ManifestEntry {
+                        name: name.to_owned(),
+                        accesses: vec![],
+                    },
+                );
+
+                index
+            }
+        };
+
+        let accesses = &mut self.entries[index].accesses;
+        accesses.push(access);
+        accesses.sort();
+        accesses.dedup();
+    }
+
+    /// The entries of all registered variables, ordered by name.
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    pub fn entry(&self, name: &str) -> Option<&ManifestEntry> {
+        self.entries
+            .binary_search_by(|entry| entry.name.as_str().cmp(name))
+            .ok()
+            .map(|index| &self.entries[index])
+    }
+
+    /// Variables which the aircraft reads but doesn't write itself. These are
+    /// expected to be provided by the simulator, instruments or other modules.
+    pub fn read_but_never_written(&self) -> impl Iterator<Item = &ManifestEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.is_read() && !entry.is_written())
+    }
+
+    /// Writes the manifest as tab separated values with one line per access.
+    /// Variables which are registered but never accessed have a single line
+    /// without element and unit.
+    pub fn save(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "{}", Self::HEADER)?;
+        for entry in &self.entries {
+            if entry.accesses.is_empty() {
+                writeln!(writer, "{}\t{}\t\t", entry.name, Self::REGISTERED_ONLY)?;
+            }
+
+            for access in &entry.accesses {
+                writeln!(
+                    writer,
+                    "{}\t{}\t{}\t{}",
+                    entry.name,
+                    access.direction,
+                    access.element,
+                    access.unit.unwrap_or_default()
+                )?;
+            }
+        }
+
+        writer.flush()
+    }
+}
+
+/// The type name of the element without its generic arguments.
+fn element_name<T: ?Sized>() -> &'static str {
+    let name = type_name::<T>();
+    name.split('<').next().unwrap_or(name)
+}
+
+#[derive(Default)]
+struct ManifestVariableRegistry {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    next_identifier: VariableIdentifier,
+}
+impl VariableRegistry for ManifestVariableRegistry {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        match self.name_to_identifier.get(&name).copied() {
+            Some(identifier) => identifier,
+            None => {
+                let identifier = self.next_identifier;
+                self.name_to_identifier.insert(name, identifier);
+                self.next_identifier = identifier.next();
+
+                identifier
+            }
+        }
+    }
+}
+
+struct ManifestReaderWriter {
+    element: &'static str,
+    accesses: FxHashMap<VariableIdentifier, Vec<VariableAccess>>,
+}
+impl ManifestReaderWriter {
+    fn new(element: &'static str) -> Self {
+        Self {
+            element,
+            accesses: FxHashMap::default(),
+        }
+    }
+
+    fn record(
+        &mut self,
+        identifier: &VariableIdentifier,
+        direction: VariableDirection,
+        unit: Option<&'static str>,
+    ) {
+        self.accesses
+            .entry(*identifier)
+            .or_default()
+            .push(VariableAccess {
+                direction,
+                element: self.element,
+                unit,
+            });
+    }
+}
+impl SimulatorReaderWriter for ManifestReaderWriter {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        self.record(identifier, VariableDirection::Read, None);
+        0.
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, _value: f64) {
+        self.record(identifier, VariableDirection::Written, None);
+    }
+
+    fn read_in(&mut self, identifier: &VariableIdentifier, unit: &'static str) -> f64 {
+        self.record(identifier, VariableDirection::Read, Some(unit));
+        0.
+    }
+
+    fn write_in(&mut self, identifier: &VariableIdentifier, _value: f64, unit: &'static str) {
+        self.record(identifier, VariableDirection::Written, Some(unit));
+    }
+}
+
+struct ManifestVisitor<'a> {
+    reader_writer: &'a mut ManifestReaderWriter,
+}
+impl SimulationElementVisitor for ManifestVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        self.reader_writer.element = element_name::<T>();
+        visited.read(&mut SimulatorReader::new(self.reader_writer));
+        visited.write(&mut SimulatorWriter::new(self.reader_writer));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::{Read, Write, Writer};
+    use uom::si::{f64::*, pressure::psi};
+
+    struct Pump {
+        pressure_id: VariableIdentifier,
+        pressure: Pressure,
+        pushbutton_id: VariableIdentifier,
+        is_on: bool,
+        raw_id: VariableIdentifier,
+        fault_id: VariableIdentifier,
+    }
+    impl Pump {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                pressure_id: context.get_identifier("PUMP_PRESSURE".to_owned()),
+                pressure: Pressure::new::<psi>(0.),
+                pushbutton_id: context.get_identifier("OVHD_PUMP_PB_IS_ON".to_owned()),
+                is_on: false,
+                raw_id: context.get_identifier("PUMP_RAW".to_owned()),
+                fault_id: context.get_identifier("PUMP_FAULT".to_owned()),
+            }
+        }
+    }
+    impl SimulationElement for Pump {
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.is_on = reader.read(&self.pushbutton_id);
+            self.pressure = reader.read(&self.pressure_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.pressure_id, self.pressure);
+            writer.write_f64(&self.raw_id, 1.);
+
+            if self.pressure > Pressure::new::<psi>(3500.) {
+                writer.write(&self.fault_id, true);
+            }
+        }
+    }
+
+    struct TestAircraft {
+        pump: Pump,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                pump: Pump::new(context),
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {}
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.pump.accept(visitor);
+
+            visitor.visit(self);
+        }
+    }
+
+    fn manifest() -> VariableManifest {
+        VariableManifest::new(StartState::Cruise, TestAircraft::new)
+    }
+
+    #[test]
+    fn contains_the_element_and_unit_of_each_access() {
+        let manifest = manifest();
+
+        assert_eq!(
+            manifest.entry("PUMP_PRESSURE").unwrap().accesses(),
+            &[
+                VariableAccess {
+                    direction: VariableDirection::Read,
+                    element: "systems::simulation::manifest::tests::Pump",
+                    unit: Some("psi"),
+                },
+                VariableAccess {
+                    direction: VariableDirection::Written,
+                    element: "systems::simulation::manifest::tests::Pump",
+                    unit: Some("psi"),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn raw_accesses_have_no_unit() {
+        let manifest = manifest();
+
+        assert_eq!(manifest.entry("PUMP_RAW").unwrap().accesses()[0].unit, None);
+    }
+
+    #[test]
+    fn contains_variables_read_by_the_update_context() {
+        let manifest = manifest();
+
+        let entry = manifest.entry("AIRSPEED INDICATED").unwrap();
+        assert!(entry.is_read());
+        assert_eq!(
+            entry.accesses()[0].element,
+            "systems::simulation::update_context::UpdateContext"
+        );
+    }
+
+    #[test]
+    fn flags_variables_read_but_never_written() {
+        let manifest = manifest();
+
+        let names: Vec<&str> = manifest
+            .read_but_never_written()
+            .map(|entry| entry.name())
+            .collect();
+        assert!(names.contains(&"OVHD_PUMP_PB_IS_ON"));
+        assert!(!names.contains(&"PUMP_PRESSURE"));
+    }
+
+    #[test]
+    fn variables_which_are_never_accessed_are_registered_only() {
+        let manifest = manifest();
+
+        let entry = manifest.entry("PUMP_FAULT").unwrap();
+        assert!(!entry.is_read());
+        assert!(!entry.is_written());
+    }
+
+    #[test]
+    fn added_access_is_merged_into_the_registered_variable() {
+        let mut manifest = manifest();
+
+        manifest.add_access(
+            "OVHD_PUMP_PB_IS_ON",
+            VariableAccess {
+                direction: VariableDirection::Written,
+                element: "bridge",
+                unit: None,
+            },
+        );
+
+        let entry = manifest.entry("OVHD_PUMP_PB_IS_ON").unwrap();
+        assert!(entry.is_read());
+        assert!(entry.is_written());
+        assert!(!manifest
+            .read_but_never_written()
+            .any(|entry| entry.name() == "OVHD_PUMP_PB_IS_ON"));
+    }
+
+    #[test]
+    fn added_access_to_an_unregistered_variable_adds_the_variable() {
+        let mut manifest = manifest();
+
+        manifest.add_access(
+            "BRIDGE_ONLY",
+            VariableAccess {
+                direction: VariableDirection::Read,
+                element: "bridge",
+                unit: None,
+            },
+        );
+
+        assert!(manifest.entry("BRIDGE_ONLY").unwrap().is_read());
+        assert!(manifest.entry("PUMP_PRESSURE").is_some());
+    }
+
+    #[test]
+    fn saves_one_line_per_access() {
+        let manifest = manifest();
+
+        let mut buffer = Vec::new();
+        manifest.save(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+
+        assert!(text.starts_with("name\tdirection\telement\tunit\n"));
+        assert!(text.contains(
+            "OVHD_PUMP_PB_IS_ON\tread\tsystems::simulation::manifest::tests::Pump\tbool\n"
+        ));
+        assert!(text.contains("PUMP_FAULT\tregistered\t\t\n"));
+    }
+}
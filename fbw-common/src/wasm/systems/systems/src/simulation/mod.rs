@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+mod snapshot;
 mod update_context;
 use crate::electrical::{ElectricalElementIdentifier, ElectricalElementIdentifierProvider};
 use crate::shared::{from_bool, ElectricalBusType};
@@ -11,6 +12,9 @@ use crate::{
     shared::{to_bool, ConsumePower, ElectricalBuses, MachNumber, PowerConsumptionReport},
 };
 
+pub use snapshot::{
+    SimulationSnapshot, SnapshotMismatch, SnapshotReader, SnapshotValue, SnapshotWriter,
+};
 use uom::si::mass_rate::kilogram_per_second;
 use uom::si::{
     acceleration::foot_per_second_squared, angle::degree, angular_velocity::revolution_per_minute,
@@ -21,6 +25,9 @@ use uom::si::{
 };
 pub use update_context::*;
 
+pub mod manifest;
+pub mod recording;
+pub mod runner;
 pub mod test;
 
 /// Trait for a type which can read and write simulator data.
@@ -32,6 +39,18 @@ pub trait SimulatorReaderWriter {
     fn read(&mut self, identifier: &VariableIdentifier) -> f64;
     /// Writes a variable with the given identifier to the simulator.
     fn write(&mut self, identifier: &VariableIdentifier, value: f64);
+
+    /// Reads a variable which the aircraft interprets in the given unit or type.
+    /// Only implementors interested in the unit need to implement this.
+    fn read_in(&mut self, identifier: &VariableIdentifier, _unit: &'static str) -> f64 {
+        self.read(identifier)
+    }
+
+    /// Writes a variable which the aircraft provides in the given unit or type.
+    /// Only implementors interested in the unit need to implement this.
+    fn write_in(&mut self, identifier: &VariableIdentifier, value: f64, _unit: &'static str) {
+        self.write(identifier, value);
+    }
 }
 
 pub trait VariableRegistry {
@@ -329,6 +348,33 @@ pub trait SimulationElement {
 
     /// Receives a failure in order to activate or deactivate it.
     fn receive_failure(&mut self, _failure_type: FailureType, _is_active: bool) {}
+
+    /// Saves the internal state of the element which isn't read from the simulator,
+    /// such that it can be restored later on.
+    /// # Examples
+    /// ```rust
+    /// # use systems::simulation::{SimulationElement, SnapshotReader, SnapshotWriter};
+    /// # use uom::si::f64::*;
+    /// struct MySimulationElement {
+    ///     volume: Volume,
+    /// }
+    /// impl SimulationElement for MySimulationElement {
+    ///     fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+    ///         snapshot.write(self.volume);
+    ///     }
+    ///
+    ///     fn restore(&mut self, snapshot: &mut SnapshotReader) {
+    ///         self.volume = snapshot.read();
+    ///     }
+    /// }
+    /// ```
+    fn snapshot(&self, _snapshot: &mut SnapshotWriter) {}
+
+    /// Restores the state saved by [`snapshot`], reading the values in the order in
+    /// which they were written.
+    ///
+    /// [`snapshot`]: #method.snapshot
+    fn restore(&mut self, _snapshot: &mut SnapshotReader) {}
 }
 
 /// Trait for visitors that visit the aircraft's system simulation to call
@@ -469,6 +515,17 @@ impl<T: Aircraft> Simulation<T> {
         self.handle_failure(failure_type, false);
     }
 
+    /// Takes a snapshot of the state of all elements of the aircraft.
+    pub fn snapshot(&mut self) -> SimulationSnapshot {
+        SimulationSnapshot::take(&mut self.aircraft)
+    }
+
+    /// Restores the state of all elements of the aircraft from a snapshot
+    /// previously taken of an aircraft of the same type.
+    pub fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotMismatch> {
+        snapshot.restore_into(&mut self.aircraft)
+    }
+
     fn handle_failure(&mut self, failure_type: FailureType, is_active: bool) {
         self.aircraft
             .accept(&mut FailureSimulationElementVisitor::new(
@@ -549,6 +606,10 @@ impl<'a> SimulationElementVisitor for SimulationToSimulatorVisitor<'a> {
 
 pub trait Reader {
     fn read_f64(&mut self, identifier: &VariableIdentifier) -> f64;
+
+    fn read_f64_in(&mut self, identifier: &VariableIdentifier, _unit: &'static str) -> f64 {
+        self.read_f64(identifier)
+    }
 }
 
 /// Reads data from the simulator into the aircraft system simulation.
@@ -566,10 +627,18 @@ impl<'a> Reader for SimulatorReader<'a> {
     fn read_f64(&mut self, identifier: &VariableIdentifier) -> f64 {
         self.simulator_read_writer.read(identifier)
     }
+
+    fn read_f64_in(&mut self, identifier: &VariableIdentifier, unit: &'static str) -> f64 {
+        self.simulator_read_writer.read_in(identifier, unit)
+    }
 }
 
 pub trait Writer {
     fn write_f64(&mut self, identifier: &VariableIdentifier, value: f64);
+
+    fn write_f64_in(&mut self, identifier: &VariableIdentifier, value: f64, _unit: &'static str) {
+        self.write_f64(identifier, value);
+    }
 }
 
 /// Writes data from the aircraft system simulation into the the simulator.
@@ -587,9 +656,16 @@ impl<'a> Writer for SimulatorWriter<'a> {
     fn write_f64(&mut self, identifier: &VariableIdentifier, value: f64) {
         self.simulator_read_writer.write(identifier, value);
     }
+
+    fn write_f64_in(&mut self, identifier: &VariableIdentifier, value: f64, unit: &'static str) {
+        self.simulator_read_writer.write_in(identifier, value, unit);
+    }
 }
 
 pub trait Read<T: Copy> {
+    /// The unit, or type when the value has no unit, in which the value is read.
+    const UNIT: &'static str;
+
     /// Reads a value from the simulator.
     /// # Examples
     /// ```rust
@@ -609,7 +685,7 @@ pub trait Read<T: Copy> {
     where
         Self: Sized + Reader,
     {
-        let value = self.read_f64(identifier);
+        let value = self.read_f64_in(identifier, <Self as Read<T>>::UNIT);
         self.convert(value)
     }
 
@@ -633,7 +709,7 @@ pub trait Read<T: Copy> {
     where
         Self: Sized + Reader,
     {
-        let value = from_arinc429(self.read_f64(identifier));
+        let value = from_arinc429(self.read_f64_in(identifier, "arinc429"));
         Arinc429Word::new(self.convert(value.0), value.1)
     }
 
@@ -641,7 +717,7 @@ pub trait Read<T: Copy> {
     where
         Self: Sized + Reader,
     {
-        let value = from_arinc825(self.read_f64(identifier));
+        let value = from_arinc825(self.read_f64_in(identifier, "arinc825"));
         Arinc825Word::new_with_status(self.convert(value.0), value.1)
     }
 
@@ -649,6 +725,9 @@ pub trait Read<T: Copy> {
 }
 
 pub trait Write<T> {
+    /// The unit, or type when the value has no unit, in which the value is written.
+    const UNIT: &'static str;
+
     /// Write a value to the simulator.
     /// # Examples
     /// ```rust
@@ -669,7 +748,7 @@ pub trait Write<T> {
         Self: Sized + Writer,
     {
         let value = self.convert(value);
-        self.write_f64(identifier, value)
+        self.write_f64_in(identifier, value, <Self as Write<T>>::UNIT)
     }
 
     /// Write an ARINC 429 value to the simulator.
@@ -695,7 +774,7 @@ pub trait Write<T> {
         Self: Sized + Writer,
     {
         let value = self.convert(value);
-        self.write_f64(identifier, to_arinc429(value, ssm));
+        self.write_f64_in(identifier, to_arinc429(value, ssm), "arinc429");
     }
 
     fn write_arinc825(&mut self, identifier: &VariableIdentifier, value: T, status: u32)
@@ -703,7 +782,7 @@ pub trait Write<T> {
         Self: Sized + Writer,
     {
         let value = self.convert(value);
-        self.write_f64(identifier, to_arinc825(value, status));
+        self.write_f64_in(identifier, to_arinc825(value, status), "arinc825");
     }
 
     fn convert(&mut self, value: T) -> f64;
@@ -712,18 +791,24 @@ pub trait Write<T> {
 macro_rules! read_write_uom {
     ($t: ty, $t2: ty) => {
         impl<T: Reader> Read<$t> for T {
+            const UNIT: &'static str = stringify!($t2);
+
             fn convert(&mut self, value: f64) -> $t {
                 <$t>::new::<$t2>(value)
             }
         }
 
         impl<T: Writer> Write<$t> for T {
+            const UNIT: &'static str = stringify!($t2);
+
             fn convert(&mut self, value: $t) -> f64 {
                 value.get::<$t2>()
             }
         }
 
         impl<T: Writer> Write<Arinc429Word<$t>> for T {
+            const UNIT: &'static str = "arinc429";
+
             fn convert(&mut self, value: Arinc429Word<$t>) -> f64 {
                 let v = self.convert(value.value());
                 Arinc429Word::new(v, value.ssm()).into()
@@ -735,12 +820,16 @@ macro_rules! read_write_uom {
 macro_rules! read_write_as {
     ($t: ty) => {
         impl<T: Reader> Read<$t> for T {
+            const UNIT: &'static str = stringify!($t);
+
             fn convert(&mut self, value: f64) -> $t {
                 value as $t
             }
         }
 
         impl<T: Writer> Write<$t> for T {
+            const UNIT: &'static str = stringify!($t);
+
             fn convert(&mut self, value: $t) -> f64 {
                 value as f64
             }
@@ -751,12 +840,16 @@ macro_rules! read_write_as {
 macro_rules! read_write_into {
     ($t: ty) => {
         impl<T: Reader> Read<$t> for T {
+            const UNIT: &'static str = stringify!($t);
+
             fn convert(&mut self, value: f64) -> $t {
                 value.into()
             }
         }
 
         impl<T: Writer> Write<$t> for T {
+            const UNIT: &'static str = stringify!($t);
+
             fn convert(&mut self, value: $t) -> f64 {
                 value.into()
             }
@@ -799,60 +892,80 @@ read_write_into!(MachNumber);
 read_write_into!(StartState);
 
 impl<T: Reader> Read<Arinc429Word<u32>> for T {
+    const UNIT: &'static str = "arinc429";
+
     fn convert(&mut self, value: f64) -> Arinc429Word<u32> {
         value.into()
     }
 }
 
 impl<T: Writer> Write<Arinc429Word<u32>> for T {
+    const UNIT: &'static str = "arinc429";
+
     fn convert(&mut self, value: Arinc429Word<u32>) -> f64 {
         value.into()
     }
 }
 
 impl<T: Reader> Read<Arinc429Word<f64>> for T {
+    const UNIT: &'static str = "arinc429";
+
     fn convert(&mut self, value: f64) -> Arinc429Word<f64> {
         value.into()
     }
 }
 
 impl<T: Writer> Write<Arinc429Word<f64>> for T {
+    const UNIT: &'static str = "arinc429";
+
     fn convert(&mut self, value: Arinc429Word<f64>) -> f64 {
         value.into()
     }
 }
 
 impl<T: Reader> Read<f64> for T {
+    const UNIT: &'static str = "f64";
+
     fn convert(&mut self, value: f64) -> f64 {
         value
     }
 }
 
 impl<T: Writer> Write<f64> for T {
+    const UNIT: &'static str = "f64";
+
     fn convert(&mut self, value: f64) -> f64 {
         value
     }
 }
 
 impl<T: Reader> Read<bool> for T {
+    const UNIT: &'static str = "bool";
+
     fn convert(&mut self, value: f64) -> bool {
         to_bool(value)
     }
 }
 
 impl<T: Writer> Write<bool> for T {
+    const UNIT: &'static str = "bool";
+
     fn convert(&mut self, value: bool) -> f64 {
         from_bool(value)
     }
 }
 
 impl<T: Reader> Read<Duration> for T {
+    const UNIT: &'static str = "second";
+
     fn convert(&mut self, value: f64) -> Duration {
         Duration::from_secs_f64(value)
     }
 }
 
 impl<T: Writer> Write<Duration> for T {
+    const UNIT: &'static str = "second";
+
     fn convert(&mut self, value: Duration) -> f64 {
         value.as_secs_f64()
     }
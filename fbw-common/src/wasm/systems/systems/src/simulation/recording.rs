@@ -0,0 +1,735 @@
+//! Deterministic record and replay of the traffic between a [`Simulation`] and the simulator.
+//!
+//! A [`SimulationRecorder`] captures, for every tick, the delta, the simulation time, the failures
+//! activated or deactivated before the tick and all variables read from and written to the simulator.
+//! Recordings are stored by variable name, so they can be loaded as a [`SimulationRecording`] and
+//! replayed on any platform against a freshly built aircraft using [`replay`], which reports every
+//! output that differs from the recorded session.
+use std::{
+    fmt::{self, Display},
+    io::{self, BufRead, Write},
+    time::Duration,
+};
+
+use fxhash::FxHashMap;
+
+use crate::{failures::FailureType, shared::seed_random};
+
+use super::{
+    Aircraft, InitContext, Simulation, SimulatorReaderWriter, StartState, VariableIdentifier,
+    VariableRegistry,
+};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecordedFrame {
+    delta: Duration,
+    simulation_time: f64,
+    failures: Vec<(u64, bool)>,
+    reads: Vec<(VariableIdentifier, f64)>,
+    writes: Vec<(VariableIdentifier, f64)>,
+}
+impl RecordedFrame {
+    fn new(delta: Duration, simulation_time: f64) -> Self {
+        Self {
+            delta,
+            simulation_time,
+            failures: Vec::new(),
+            reads: Vec::new(),
+            writes: Vec::new(),
+        }
+    }
+
+    fn reset(&mut self, delta: Duration, simulation_time: f64) {
+        self.delta = delta;
+        self.simulation_time = simulation_time;
+        self.reads.clear();
+        self.writes.clear();
+    }
+
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn simulation_time(&self) -> f64 {
+        self.simulation_time
+    }
+}
+
+const START_STATE_KEY: &str = "start_state";
+const RANDOM_SEED_KEY: &str = "random_seed";
+const VARIABLE_KEY: &str = "variable";
+const FRAME_KEY: &str = "frame";
+const FAILURE_ACTIVATE_KEY: &str = "fa";
+const FAILURE_DEACTIVATE_KEY: &str = "fd";
+const READ_KEY: &str = "r";
+const WRITE_KEY: &str = "w";
+
+/// Records a session to the given writer. Every frame is written as soon as it is ticked,
+/// thus the memory used by the recorder doesn't grow with the length of the session.
+pub struct SimulationRecorder<W: Write> {
+    writer: W,
+    names: FxHashMap<VariableIdentifier, String>,
+    numbers: FxHashMap<VariableIdentifier, usize>,
+    frame: RecordedFrame,
+}
+impl<W: Write> SimulationRecorder<W> {
+    /// Starts a new recording. The random number generator is seeded with the given seed,
+    /// such that random behaviour of the aircraft is reproduced when replaying.
+    /// Create the recorder before the simulation it records.
+    pub fn new(mut writer: W, start_state: StartState, random_seed: u64) -> io::Result<Self> {
+        seed_random(random_seed);
+
+        writeln!(writer, "{}\t{}", START_STATE_KEY, f64::from(start_state))?;
+        writeln!(writer, "{}\t{}", RANDOM_SEED_KEY, random_seed)?;
+
+        Ok(Self {
+            writer,
+            names: FxHashMap::default(),
+            numbers: FxHashMap::default(),
+            frame: RecordedFrame::default(),
+        })
+    }
+
+    /// Wraps the given registry to capture the names of all variables registered
+    /// while constructing the recorded simulation.
+    pub fn variable_registry<'a, T: VariableRegistry>(
+        &'a mut self,
+        registry: &'a mut T,
+    ) -> RecordingVariableRegistry<'a, T> {
+        RecordingVariableRegistry {
+            registry,
+            names: &mut self.names,
+        }
+    }
+
+    /// Activates the failure in the simulation. The failure is recorded by its identifier
+    /// as part of the next frame.
+    pub fn activate_failure<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        identifier: u64,
+        failure_type: FailureType,
+    ) {
+        simulation.activate_failure(failure_type);
+        self.frame.failures.push((identifier, true));
+    }
+
+    /// Deactivates the failure in the simulation. The failure is recorded by its identifier
+    /// as part of the next frame.
+    pub fn deactivate_failure<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        identifier: u64,
+        failure_type: FailureType,
+    ) {
+        simulation.deactivate_failure(failure_type);
+        self.frame.failures.push((identifier, false));
+    }
+
+    /// Executes a single run of the simulation, recording all simulator traffic of the run.
+    pub fn tick<T: Aircraft>(
+        &mut self,
+        simulation: &mut Simulation<T>,
+        delta: Duration,
+        simulation_time: f64,
+        reader_writer: &mut impl SimulatorReaderWriter,
+    ) -> io::Result<()> {
+        self.frame.reset(delta, simulation_time);
+        simulation.tick(
+            delta,
+            simulation_time,
+            &mut RecordingSimulatorReaderWriter {
+                reader_writer,
+                frame: &mut self.frame,
+            },
+        );
+
+        self.write_frame()
+    }
+
+    fn write_frame(&mut self) -> io::Result<()> {
+        for (identifier, _) in self.frame.reads.iter().chain(&self.frame.writes) {
+            if !self.numbers.contains_key(identifier) {
+                let number = self.numbers.len();
+                writeln!(
+                    self.writer,
+                    "{}\t{}\t{}",
+                    VARIABLE_KEY,
+                    number,
+                    self.names
+                        .get(identifier)
+                        .map_or("UNKNOWN", |name| name.as_str())
+                )?;
+                self.numbers.insert(*identifier, number);
+            }
+        }
+
+        writeln!(
+            self.writer,
+            "{}\t{}\t{:?}",
+            FRAME_KEY,
+            self.frame.delta.as_nanos(),
+            self.frame.simulation_time
+        )?;
+        for (identifier, is_active) in self.frame.failures.drain(..) {
+            writeln!(
+                self.writer,
+                "{}\t{}",
+                if is_active {
+                    FAILURE_ACTIVATE_KEY
+                } else {
+                    FAILURE_DEACTIVATE_KEY
+                },
+                identifier
+            )?;
+        }
+        for (identifier, value) in &self.frame.reads {
+            writeln!(
+                self.writer,
+                "{}\t{}\t{:?}",
+                READ_KEY, self.numbers[identifier], value
+            )?;
+        }
+        for (identifier, value) in &self.frame.writes {
+            writeln!(
+                self.writer,
+                "{}\t{}\t{:?}",
+                WRITE_KEY, self.numbers[identifier], value
+            )?;
+        }
+
+        self.writer.flush()
+    }
+}
+
+/// A recorded session, loaded for replaying it.
+pub struct SimulationRecording {
+    start_state: StartState,
+    random_seed: u64,
+    names: FxHashMap<VariableIdentifier, String>,
+    frames: Vec<RecordedFrame>,
+}
+impl SimulationRecording {
+    pub fn start_state(&self) -> StartState {
+        self.start_state
+    }
+
+    pub fn frames(&self) -> &[RecordedFrame] {
+        &self.frames
+    }
+
+    fn name(&self, identifier: &VariableIdentifier) -> &str {
+        self.names
+            .get(identifier)
+            .map_or("UNKNOWN", |name| name.as_str())
+    }
+
+    pub fn load(reader: impl BufRead) -> io::Result<Self> {
+        let mut start_state = StartState::default();
+        let mut random_seed = 0;
+        let mut names = FxHashMap::default();
+        let mut identifiers: FxHashMap<usize, VariableIdentifier> = FxHashMap::default();
+        let mut next_identifier = VariableIdentifier::default();
+        let mut frames: Vec<RecordedFrame> = Vec::new();
+
+        for (line_index, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            let invalid = || invalid_data(line_index + 1, &line);
+            let fields: Vec<&str> = line.split('\t').collect();
+            match (fields[0], fields.len()) {
+                (START_STATE_KEY, 2) => {
+                    start_state = parse::<f64>(fields[1]).ok_or_else(invalid)?.into();
+                }
+                (RANDOM_SEED_KEY, 2) => {
+                    random_seed = parse(fields[1]).ok_or_else(invalid)?;
+                }
+                (VARIABLE_KEY, 3) => {
+                    let number: usize = parse(fields[1]).ok_or_else(invalid)?;
+                    identifiers.insert(number, next_identifier);
+                    names.insert(next_identifier, fields[2].to_owned());
+                    next_identifier = next_identifier.next();
+                }
+                (FRAME_KEY, 3) => frames.push(RecordedFrame::new(
+                    Duration::from_nanos(parse(fields[1]).ok_or_else(invalid)?),
+                    parse(fields[2]).ok_or_else(invalid)?,
+                )),
+                (key @ (FAILURE_ACTIVATE_KEY | FAILURE_DEACTIVATE_KEY), 2) => {
+                    let identifier = parse(fields[1]).ok_or_else(invalid)?;
+                    let frame = frames.last_mut().ok_or_else(invalid)?;
+
+                    frame
+                        .failures
+                        .push((identifier, key == FAILURE_ACTIVATE_KEY));
+                }
+                (key @ (READ_KEY | WRITE_KEY), 3) => {
+                    let identifier = parse(fields[1])
+                        .and_then(|number: usize| identifiers.get(&number).copied())
+                        .ok_or_else(invalid)?;
+                    let value = parse(fields[2]).ok_or_else(invalid)?;
+                    let frame = frames.last_mut().ok_or_else(invalid)?;
+
+                    if key == READ_KEY {
+                        frame.reads.push((identifier, value));
+                    } else {
+                        frame.writes.push((identifier, value));
+                    }
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(Self {
+            start_state,
+            random_seed,
+            names,
+            frames,
+        })
+    }
+}
+
+fn parse<T: std::str::FromStr>(field: &str) -> Option<T> {
+    field.parse().ok()
+}
+
+fn invalid_data(line_number: usize, line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid recording line {}: '{}'", line_number, line),
+    )
+}
+
+pub struct RecordingVariableRegistry<'a, T: VariableRegistry> {
+    registry: &'a mut T,
+    names: &'a mut FxHashMap<VariableIdentifier, String>,
+}
+impl<'a, T: VariableRegistry> VariableRegistry for RecordingVariableRegistry<'a, T> {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        let identifier = self.registry.get(name.clone());
+        self.names.insert(identifier, name);
+
+        identifier
+    }
+}
+
+struct RecordingSimulatorReaderWriter<'a, T: SimulatorReaderWriter> {
+    reader_writer: &'a mut T,
+    frame: &'a mut RecordedFrame,
+}
+impl<'a, T: SimulatorReaderWriter> SimulatorReaderWriter for RecordingSimulatorReaderWriter<'a, T> {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        let value = self.reader_writer.read(identifier);
+        self.frame.reads.push((*identifier, value));
+
+        value
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.reader_writer.write(identifier, value);
+        self.frame.writes.push((*identifier, value));
+    }
+}
+
+/// A variable written differently by the replayed simulation than by the recorded one.
+/// `None` indicates the variable wasn't written at all.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayDifference {
+    pub frame: usize,
+    pub name: String,
+    pub recorded: Option<f64>,
+    pub replayed: Option<f64>,
+}
+impl Display for ReplayDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame {}: {} recorded {:?}, replayed {:?}",
+            self.frame, self.name, self.recorded, self.replayed
+        )
+    }
+}
+
+#[derive(Default)]
+struct ReplayVariableRegistry {
+    name_to_identifier: FxHashMap<String, VariableIdentifier>,
+    identifier_to_name: FxHashMap<VariableIdentifier, String>,
+    next_identifier: VariableIdentifier,
+}
+impl ReplayVariableRegistry {
+    fn name(&self, identifier: &VariableIdentifier) -> &str {
+        &self.identifier_to_name[identifier]
+    }
+}
+impl VariableRegistry for ReplayVariableRegistry {
+    fn get(&mut self, name: String) -> VariableIdentifier {
+        match self.name_to_identifier.get(&name).copied() {
+            Some(identifier) => identifier,
+            None => {
+                let identifier = self.next_identifier;
+                self.name_to_identifier.insert(name.clone(), identifier);
+                self.identifier_to_name.insert(identifier, name);
+                self.next_identifier = identifier.next();
+
+                identifier
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ReplayReaderWriter {
+    reads: FxHashMap<VariableIdentifier, f64>,
+    writes: FxHashMap<VariableIdentifier, f64>,
+}
+impl SimulatorReaderWriter for ReplayReaderWriter {
+    fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+        *self.reads.get(identifier).unwrap_or(&0.)
+    }
+
+    fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+        self.writes.insert(*identifier, value);
+    }
+}
+
+/// Feeds the recorded session into a newly constructed simulation, frame by frame,
+/// and returns all outputs which differ from the recorded outputs. Recorded failures are
+/// mapped to their type through the failures of the aircraft.
+pub fn replay<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+    recording: &SimulationRecording,
+    aircraft_ctor_fn: U,
+    failures: &[(u64, FailureType)],
+) -> Vec<ReplayDifference> {
+    seed_random(recording.random_seed);
+
+    let mut registry = ReplayVariableRegistry::default();
+    let mut simulation = Simulation::new(recording.start_state, aircraft_ctor_fn, &mut registry);
+
+    let mut differences = Vec::new();
+    for (frame_index, frame) in recording.frames.iter().enumerate() {
+        for (identifier, is_active) in &frame.failures {
+            let failure_type = failures
+                .iter()
+                .find(|(failure_identifier, _)| failure_identifier == identifier)
+                .map(|(_, failure_type)| *failure_type);
+
+            match (failure_type, is_active) {
+                (Some(failure_type), true) => simulation.activate_failure(failure_type),
+                (Some(failure_type), false) => simulation.deactivate_failure(failure_type),
+                (None, _) => {}
+            }
+        }
+
+        let mut reader_writer = ReplayReaderWriter::default();
+        for (identifier, value) in &frame.reads {
+            reader_writer
+                .reads
+                .insert(registry.get(recording.name(identifier).to_owned()), *value);
+        }
+
+        simulation.tick(frame.delta, frame.simulation_time, &mut reader_writer);
+
+        let recorded_writes: FxHashMap<VariableIdentifier, f64> = frame
+            .writes
+            .iter()
+            .map(|(identifier, value)| {
+                (registry.get(recording.name(identifier).to_owned()), *value)
+            })
+            .collect();
+
+        let mut written: Vec<&VariableIdentifier> = recorded_writes
+            .keys()
+            .chain(reader_writer.writes.keys())
+            .collect();
+        written.sort_by_key(|identifier| registry.name(identifier));
+        written.dedup();
+
+        let frame_differences = written.into_iter().filter_map(|identifier| {
+            let recorded = recorded_writes.get(identifier).copied();
+            let replayed = reader_writer.writes.get(identifier).copied();
+
+            (!values_are_identical(recorded, replayed)).then(|| ReplayDifference {
+                frame: frame_index,
+                name: registry.name(identifier).to_owned(),
+                recorded,
+                replayed,
+            })
+        });
+        differences.extend(frame_differences);
+    }
+
+    differences
+}
+
+fn values_are_identical(recorded: Option<f64>, replayed: Option<f64>) -> bool {
+    match (recorded, replayed) {
+        (Some(recorded), Some(replayed)) => {
+            recorded == replayed || (recorded.is_nan() && replayed.is_nan())
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        failures::Failure,
+        shared::random_from_range,
+        simulation::{
+            test::TestVariableRegistry, Read, SimulationElement, SimulationElementVisitor,
+            SimulatorReader, SimulatorWriter, UpdateContext, Write,
+        },
+    };
+    use std::{cell::RefCell, io::BufReader, rc::Rc};
+
+    const FAILURE_IDENTIFIER: u64 = 24_000;
+    const FAILURE_TYPE: FailureType = FailureType::TransformerRectifier(1);
+
+    struct TestAircraft {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        noise_id: VariableIdentifier,
+        failed_id: VariableIdentifier,
+
+        failure: Failure,
+        input: f64,
+        output: f64,
+        noise: f64,
+        gain: f64,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext, gain: f64) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("OUTPUT".to_owned()),
+                noise_id: context.get_identifier("NOISE".to_owned()),
+                failed_id: context.get_identifier("FAILED".to_owned()),
+                failure: Failure::new(FAILURE_TYPE),
+                input: 0.,
+                output: 0.,
+                noise: 0.,
+                gain,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            self.output += self.input * self.gain * context.delta_as_secs_f64();
+            self.noise = random_from_range(0., 1.);
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.failure.accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.output);
+            writer.write(&self.noise_id, self.noise);
+            writer.write(&self.failed_id, self.failure.is_active());
+        }
+    }
+
+    #[derive(Default)]
+    struct TestReaderWriter {
+        variables: FxHashMap<VariableIdentifier, f64>,
+    }
+    impl SimulatorReaderWriter for TestReaderWriter {
+        fn read(&mut self, identifier: &VariableIdentifier) -> f64 {
+            *self.variables.get(identifier).unwrap_or(&0.)
+        }
+
+        fn write(&mut self, identifier: &VariableIdentifier, value: f64) {
+            self.variables.insert(*identifier, value);
+        }
+    }
+
+    /// Records the given number of frames, activating the failure before the given frame.
+    fn record_with_failure(frames: usize, failure_frame: Option<usize>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let mut recorder = SimulationRecorder::new(&mut buffer, StartState::Apron, 42).unwrap();
+        let mut registry = TestVariableRegistry::default();
+        let mut simulation = Simulation::new(
+            StartState::Apron,
+            |context| TestAircraft::new(context, 2.),
+            &mut recorder.variable_registry(&mut registry),
+        );
+
+        let input_id = registry.get("INPUT".to_owned());
+        let mut reader_writer = TestReaderWriter::default();
+        for frame in 0..frames {
+            if failure_frame == Some(frame) {
+                recorder.activate_failure(&mut simulation, FAILURE_IDENTIFIER, FAILURE_TYPE);
+            }
+
+            reader_writer.write(&input_id, frame as f64);
+            recorder
+                .tick(
+                    &mut simulation,
+                    Duration::from_millis(50),
+                    frame as f64 * 0.05,
+                    &mut reader_writer,
+                )
+                .unwrap();
+        }
+
+        buffer
+    }
+
+    fn record(frames: usize) -> SimulationRecording {
+        load(&record_with_failure(frames, None))
+    }
+
+    fn load(buffer: &[u8]) -> SimulationRecording {
+        SimulationRecording::load(BufReader::new(buffer)).unwrap()
+    }
+
+    fn recorded_value(recording: &SimulationRecording, frame: usize, name: &str) -> Option<f64> {
+        recording.frames()[frame]
+            .writes
+            .iter()
+            .find(|(identifier, _)| recording.name(identifier) == name)
+            .map(|(_, value)| *value)
+    }
+
+    #[test]
+    fn records_every_tick() {
+        let recording = record(5);
+
+        assert_eq!(recording.frames().len(), 5);
+        assert_eq!(recording.frames()[3].delta(), Duration::from_millis(50));
+        assert!((recording.frames()[3].simulation_time() - 0.15).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn records_reads_and_writes_by_name() {
+        let recording = record(3);
+
+        assert!(recording.frames()[2]
+            .reads
+            .iter()
+            .any(|(identifier, value)| recording.name(identifier) == "INPUT" && *value == 2.));
+        assert!((recorded_value(&recording, 2, "OUTPUT").unwrap() - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn loaded_recording_has_the_start_state_and_seed() {
+        let recording = record(4);
+
+        assert_eq!(recording.start_state(), StartState::Apron);
+        assert_eq!(recording.random_seed, 42);
+    }
+
+    #[test]
+    fn frames_are_written_as_they_are_ticked() {
+        #[derive(Clone, Default)]
+        struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+        impl io::Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                io::Write::write(&mut *self.0.borrow_mut(), buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer::default();
+        let mut recorder = SimulationRecorder::new(buffer.clone(), StartState::Apron, 42).unwrap();
+        let mut registry = TestVariableRegistry::default();
+        let mut simulation = Simulation::new(
+            StartState::Apron,
+            |context| TestAircraft::new(context, 2.),
+            &mut recorder.variable_registry(&mut registry),
+        );
+
+        let mut reader_writer = TestReaderWriter::default();
+        for frame in 0..3 {
+            recorder
+                .tick(
+                    &mut simulation,
+                    Duration::from_millis(50),
+                    frame as f64 * 0.05,
+                    &mut reader_writer,
+                )
+                .unwrap();
+
+            let recording = load(&buffer.0.borrow());
+            assert_eq!(recording.frames().len(), frame + 1);
+        }
+    }
+
+    #[test]
+    fn records_failures_with_the_frame_they_precede() {
+        let recording = load(&record_with_failure(3, Some(1)));
+
+        assert!(recording.frames()[0].failures.is_empty());
+        assert_eq!(
+            recording.frames()[1].failures,
+            vec![(FAILURE_IDENTIFIER, true)]
+        );
+        assert!(recording.frames()[2].failures.is_empty());
+        assert_eq!(recorded_value(&recording, 0, "FAILED"), Some(0.));
+        assert_eq!(recorded_value(&recording, 1, "FAILED"), Some(1.));
+    }
+
+    #[test]
+    fn replaying_the_same_aircraft_has_no_differences() {
+        let recording = record(10);
+
+        let differences = replay(&recording, |context| TestAircraft::new(context, 2.), &[]);
+
+        assert!(differences.is_empty(), "{:?}", differences);
+    }
+
+    #[test]
+    fn replaying_applies_the_recorded_failures() {
+        let recording = load(&record_with_failure(3, Some(1)));
+
+        let differences = replay(
+            &recording,
+            |context| TestAircraft::new(context, 2.),
+            &[(FAILURE_IDENTIFIER, FAILURE_TYPE)],
+        );
+
+        assert!(differences.is_empty(), "{:?}", differences);
+    }
+
+    #[test]
+    fn replaying_a_changed_aircraft_reports_differing_outputs() {
+        let recording = record(3);
+
+        let differences = replay(&recording, |context| TestAircraft::new(context, 3.), &[]);
+
+        assert_eq!(differences.len(), 2);
+        assert_eq!(differences[0].frame, 1);
+        assert_eq!(differences[0].name, "OUTPUT");
+        assert_eq!(differences[0].recorded, Some(0.1));
+        assert!((differences[0].replayed.unwrap() - 0.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn loading_a_malformed_recording_fails() {
+        let result = SimulationRecording::load(BufReader::new("frame\tabc\t0.0\n".as_bytes()));
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn loading_a_value_before_any_frame_fails() {
+        let result =
+            SimulationRecording::load(BufReader::new("variable\t0\tINPUT\nr\t0\t1.0\n".as_bytes()));
+
+        assert!(result.is_err());
+    }
+}
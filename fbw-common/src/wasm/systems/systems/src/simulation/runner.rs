@@ -0,0 +1,449 @@
+//! Headless execution of an aircraft outside of the simulator.
+//!
+//! A [`Scenario`] describes how the aircraft is started, which variables are written and
+//! which failures are activated at given times, and which outputs are of interest.
+//! [`run_scenario`] drives the aircraft through the scenario and writes the outputs as CSV.
+//!
+//! Scenario files contain one statement per line, `#` starts a comment:
+//!
+//! ```text
+//! start_state Apron
+//! duration 60
+//! step 0.05
+//! sample 1
+//! output HYD_GREEN_SYSTEM_1_SECTION_PRESSURE
+//! at 0 write OVHD_HYD_EPUMPB_PB_IS_AUTO 1
+//! at 10 fail 29000
+//! at 20 unfail 29000
+//! ```
+//!
+//! Variable names may contain spaces: an `output` takes the rest of the line as the name,
+//! a `write` takes the last field of the line as the value.
+use std::{
+    io::{self, BufRead, Write},
+    time::Duration,
+};
+
+use crate::failures::FailureType;
+
+use super::{
+    test::{ReadByName, SimulationTestBed, TestBed, WriteByName},
+    Aircraft, InitContext, StartState,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScenarioAction {
+    Write(String, f64),
+    Fail(u64),
+    Unfail(u64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScenarioEvent {
+    time: Duration,
+    action: ScenarioAction,
+}
+impl ScenarioEvent {
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn action(&self) -> &ScenarioAction {
+        &self.action
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scenario {
+    start_state: StartState,
+    duration: Duration,
+    step: Duration,
+    sample_interval: Option<Duration>,
+    outputs: Vec<String>,
+    events: Vec<ScenarioEvent>,
+}
+impl Scenario {
+    const DEFAULT_STEP_SECONDS: f64 = 0.05;
+
+    pub fn parse(reader: impl BufRead) -> io::Result<Self> {
+        let mut scenario = Self {
+            start_state: StartState::Cruise,
+            duration: Duration::ZERO,
+            step: Duration::from_secs_f64(Self::DEFAULT_STEP_SECONDS),
+            sample_interval: None,
+            outputs: Vec::new(),
+            events: Vec::new(),
+        };
+
+        parse_statements(reader, |fields| {
+            match fields[0] {
+                "start_state" if fields.len() == 2 => {
+                    scenario.start_state = parse_start_state(fields[1])?;
+                }
+                "duration" if fields.len() == 2 => {
+                    scenario.duration = parse_duration(fields[1])?;
+                }
+                "step" if fields.len() == 2 => {
+                    scenario.step = parse_duration(fields[1]).filter(|step| !step.is_zero())?;
+                }
+                "sample" if fields.len() == 2 => {
+                    scenario.sample_interval = Some(parse_duration(fields[1])?);
+                }
+                "output" if fields.len() >= 2 => {
+                    scenario.outputs.push(fields[1..].join(" "));
+                }
+                "at" if fields.len() >= 4 => {
+                    let time = parse_duration(fields[1])?;
+                    let action = match (fields[2], fields.len()) {
+                        ("write", len) if len >= 5 => ScenarioAction::Write(
+                            fields[3..len - 1].join(" "),
+                            fields[len - 1].parse().ok()?,
+                        ),
+                        ("fail", 4) => ScenarioAction::Fail(fields[3].parse().ok()?),
+                        ("unfail", 4) => ScenarioAction::Unfail(fields[3].parse().ok()?),
+                        _ => return None,
+                    };
+
+                    scenario.events.push(ScenarioEvent { time, action });
+                }
+                _ => return None,
+            }
+
+            Some(())
+        })?;
+
+        // Events at the same time are applied in the order in which they were written.
+        scenario.events.sort_by_key(|event| event.time);
+
+        Ok(scenario)
+    }
+
+    pub fn start_state(&self) -> StartState {
+        self.start_state
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    pub fn events(&self) -> &[ScenarioEvent] {
+        &self.events
+    }
+}
+
+/// Runs the scenario against a freshly built aircraft and writes the requested outputs as CSV.
+///
+/// The failures are the aircraft's failure identifiers as used by the failure orchestrator.
+/// Events are applied before the first step starting at or after their time. A row is
+/// written after the first step and whenever the sample interval elapsed, or after every
+/// step when the scenario has no sample interval.
+pub fn run_scenario<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+    scenario: &Scenario,
+    aircraft_ctor_fn: U,
+    failures: &[(u64, FailureType)],
+    output: &mut impl Write,
+) -> io::Result<()> {
+    let mut test_bed =
+        SimulationTestBed::new_with_start_state(scenario.start_state, aircraft_ctor_fn);
+    validate(scenario, &mut test_bed, failures)?;
+
+    let failure = |id: u64| {
+        failures
+            .iter()
+            .find(|(failure_id, _)| *failure_id == id)
+            .map(|(_, failure_type)| *failure_type)
+            .expect("Failure identifiers were validated before running.")
+    };
+
+    write!(output, "time")?;
+    for name in &scenario.outputs {
+        write!(output, ",{}", name)?;
+    }
+    writeln!(output)?;
+
+    let mut events = scenario.events.iter().peekable();
+    let mut time = Duration::ZERO;
+    let mut next_sample = Duration::ZERO;
+    while time < scenario.duration {
+        while let Some(event) = events.next_if(|event| event.time <= time) {
+            match &event.action {
+                ScenarioAction::Write(name, value) => test_bed.write_by_name(name, *value),
+                ScenarioAction::Fail(id) => test_bed.fail(failure(*id)),
+                ScenarioAction::Unfail(id) => test_bed.unfail(failure(*id)),
+            }
+        }
+
+        test_bed.run_with_delta(scenario.step);
+        time += scenario.step;
+
+        if time >= next_sample {
+            write!(output, "{}", time.as_secs_f64())?;
+            for name in &scenario.outputs {
+                let value: f64 = test_bed.read_by_name(name);
+                write!(output, ",{}", value)?;
+            }
+            writeln!(output)?;
+
+            next_sample = match scenario.sample_interval {
+                Some(interval) => next_sample + interval,
+                None => time,
+            };
+        }
+    }
+
+    output.flush()
+}
+
+fn validate<T: Aircraft>(
+    scenario: &Scenario,
+    test_bed: &mut SimulationTestBed<T>,
+    failures: &[(u64, FailureType)],
+) -> io::Result<()> {
+    let unknown =
+        |description: String| Err(io::Error::new(io::ErrorKind::InvalidInput, description));
+
+    for name in &scenario.outputs {
+        if test_bed.get_variable_identifier(name).is_none() {
+            return unknown(format!("Unknown output variable '{}'", name));
+        }
+    }
+
+    for event in &scenario.events {
+        match &event.action {
+            ScenarioAction::Write(name, _) if test_bed.get_variable_identifier(name).is_none() => {
+                return unknown(format!("Unknown variable '{}'", name));
+            }
+            ScenarioAction::Fail(id) | ScenarioAction::Unfail(id)
+                if !failures.iter().any(|(failure_id, _)| failure_id == id) =>
+            {
+                return unknown(format!("Unknown failure {}", id));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_start_state(field: &str) -> Option<StartState> {
+    match field {
+        "Hangar" => Some(StartState::Hangar),
+        "Apron" => Some(StartState::Apron),
+        "Taxi" => Some(StartState::Taxi),
+        "Runway" => Some(StartState::Runway),
+        "Climb" => Some(StartState::Climb),
+        "Cruise" => Some(StartState::Cruise),
+        "Approach" => Some(StartState::Approach),
+        "Final" => Some(StartState::Final),
+        _ => None,
+    }
+}
+
+/// Reads the statements of a scenario file. Each line holds one statement, `#` starts a
+/// comment. The whitespace separated fields of every statement are passed to
+/// `parse_statement`, a statement it rejects by returning `None` fails the whole file.
+pub fn parse_statements(
+    reader: impl BufRead,
+    mut parse_statement: impl FnMut(&[&str]) -> Option<()>,
+) -> io::Result<()> {
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let statement = line.split('#').next().unwrap_or_default().trim();
+        if statement.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = statement.split_whitespace().collect();
+        parse_statement(&fields).ok_or_else(|| invalid_data(index + 1, &line))?;
+    }
+
+    Ok(())
+}
+
+/// Parses a non-negative number of seconds.
+pub fn parse_duration(field: &str) -> Option<Duration> {
+    field
+        .parse::<f64>()
+        .ok()
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.)
+        .map(Duration::from_secs_f64)
+}
+
+pub fn invalid_data(line_number: usize, line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid scenario line {}: '{}'", line_number, line),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        shared::HydraulicColor,
+        simulation::{
+            Read, SimulationElement, SimulationElementVisitor, SimulatorReader, SimulatorWriter,
+            UpdateContext, VariableIdentifier, Write as _,
+        },
+    };
+
+    struct TestAircraft {
+        input_id: VariableIdentifier,
+        output_id: VariableIdentifier,
+        input: f64,
+        total: f64,
+        failed: bool,
+    }
+    impl TestAircraft {
+        fn new(context: &mut InitContext) -> Self {
+            Self {
+                input_id: context.get_identifier("INPUT".to_owned()),
+                output_id: context.get_identifier("TOTAL".to_owned()),
+                input: 0.,
+                total: 0.,
+                failed: false,
+            }
+        }
+    }
+    impl Aircraft for TestAircraft {
+        fn update_after_power_distribution(&mut self, context: &UpdateContext) {
+            if !self.failed {
+                self.total += self.input * context.delta_as_secs_f64();
+            }
+        }
+    }
+    impl SimulationElement for TestAircraft {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            visitor.visit(self);
+        }
+
+        fn read(&mut self, reader: &mut SimulatorReader) {
+            self.input = reader.read(&self.input_id);
+        }
+
+        fn write(&self, writer: &mut SimulatorWriter) {
+            writer.write(&self.output_id, self.total);
+        }
+
+        fn receive_failure(&mut self, failure_type: FailureType, is_active: bool) {
+            if failure_type == FailureType::ReservoirLeak(HydraulicColor::Green) {
+                self.failed = is_active;
+            }
+        }
+    }
+
+    fn failures() -> Vec<(u64, FailureType)> {
+        vec![(29_000, FailureType::ReservoirLeak(HydraulicColor::Green))]
+    }
+
+    fn parse(text: &str) -> io::Result<Scenario> {
+        Scenario::parse(text.as_bytes())
+    }
+
+    fn run(text: &str) -> io::Result<String> {
+        let mut output = Vec::new();
+        run_scenario(&parse(text)?, TestAircraft::new, &failures(), &mut output)?;
+
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn parses_a_scenario() {
+        let scenario = parse(
+            "# A comment\n\
+             start_state Apron\n\
+             duration 10\n\
+             step 0.5 # trailing comment\n\
+             output GENERAL ENG STARTER ACTIVE:1\n\
+             at 2 write TURB ENG IGNITION SWITCH EX1:1 2\n\
+             at 1 fail 29000\n",
+        )
+        .unwrap();
+
+        assert_eq!(scenario.start_state(), StartState::Apron);
+        assert_eq!(scenario.duration(), Duration::from_secs(10));
+        assert_eq!(scenario.step(), Duration::from_millis(500));
+        assert_eq!(scenario.outputs(), ["GENERAL ENG STARTER ACTIVE:1"]);
+        assert_eq!(
+            scenario.events(),
+            [
+                ScenarioEvent {
+                    time: Duration::from_secs(1),
+                    action: ScenarioAction::Fail(29_000)
+                },
+                ScenarioEvent {
+                    time: Duration::from_secs(2),
+                    action: ScenarioAction::Write("TURB ENG IGNITION SWITCH EX1:1".to_owned(), 2.)
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn parsing_an_unknown_statement_fails() {
+        let result = parse("duration 10\nfly 5\n");
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn parsing_a_zero_step_fails() {
+        let result = parse("step 0\n");
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn writes_outputs_after_every_step_as_csv() {
+        let output = run("duration 3\nstep 1\noutput TOTAL\nat 0 write INPUT 2\n").unwrap();
+
+        assert_eq!(output, "time,TOTAL\n1,2\n2,4\n3,6\n");
+    }
+
+    #[test]
+    fn writes_are_applied_from_their_time_onwards() {
+        let output = run("duration 3\nstep 1\noutput TOTAL\nat 1 write INPUT 1\n").unwrap();
+
+        assert_eq!(output, "time,TOTAL\n1,0\n2,1\n3,2\n");
+    }
+
+    #[test]
+    fn outputs_are_sampled_at_the_sample_interval() {
+        let output =
+            run("duration 4\nstep 1\nsample 2\noutput TOTAL\nat 0 write INPUT 1\n").unwrap();
+
+        assert_eq!(output, "time,TOTAL\n1,1\n2,2\n4,4\n");
+    }
+
+    #[test]
+    fn failures_are_activated_and_deactivated() {
+        let output = run(
+            "duration 4\nstep 1\noutput TOTAL\nat 0 write INPUT 1\nat 1 fail 29000\nat 3 unfail 29000\n",
+        )
+        .unwrap();
+
+        assert_eq!(output, "time,TOTAL\n1,1\n2,1\n3,1\n4,2\n");
+    }
+
+    #[test]
+    fn unknown_variable_is_rejected() {
+        let result = run("duration 1\noutput UNKNOWN\n");
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn unknown_failure_is_rejected() {
+        let result = run("duration 1\nat 0 fail 1\n");
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidInput);
+    }
+}
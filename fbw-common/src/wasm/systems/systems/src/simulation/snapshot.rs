@@ -0,0 +1,453 @@
+use std::{
+    any::type_name,
+    error::Error,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+    io::{self, BufRead, Write},
+    marker::PhantomData,
+    slice::Iter,
+    time::Duration,
+};
+
+use fxhash::FxHasher64;
+use uom::si::{Dimension, Quantity, Units};
+
+use crate::shared::{from_bool, to_bool};
+
+use super::{SimulationElement, SimulationElementVisitor};
+
+/// The layout of the values saved by a single element: the type of the element,
+/// a signature of the types of its values in the order they were written and their count.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SnapshotLayout {
+    element: String,
+    signature: u64,
+    count: usize,
+}
+
+/// The state of all [`SimulationElement`]s of an aircraft at a given moment.
+///
+/// The snapshot contains the values saved by each element in the order in which the
+/// elements are visited, together with the layout of the values of each element.
+/// It can therefore only be restored into an aircraft of the same type, which is
+/// verified element by element before restoring.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SimulationSnapshot {
+    layouts: Vec<SnapshotLayout>,
+    values: Vec<f64>,
+}
+impl SimulationSnapshot {
+    const HEADER: &'static str = "snapshot";
+    const ELEMENT_KEY: &'static str = "element";
+
+    pub(super) fn take<T: SimulationElement>(element: &mut T) -> Self {
+        let mut snapshot = Self::default();
+        element.accept(&mut SimulationToSnapshotVisitor {
+            layouts: &mut snapshot.layouts,
+            writer: SnapshotWriter {
+                values: &mut snapshot.values,
+                signature: FxHasher64::default(),
+            },
+        });
+
+        snapshot
+    }
+
+    pub(super) fn restore_into<T: SimulationElement>(
+        &self,
+        element: &mut T,
+    ) -> Result<(), SnapshotMismatch> {
+        self.verify_layout(&Self::take(element).layouts)?;
+
+        element.accept(&mut SnapshotToSimulationVisitor {
+            reader: SnapshotReader {
+                values: self.values.iter(),
+            },
+        });
+
+        Ok(())
+    }
+
+    fn verify_layout(&self, expected: &[SnapshotLayout]) -> Result<(), SnapshotMismatch> {
+        if let Some((index, (expected, actual))) = expected
+            .iter()
+            .zip(&self.layouts)
+            .enumerate()
+            .find(|(_, (expected, actual))| expected != actual)
+        {
+            return Err(SnapshotMismatch::Element {
+                index,
+                expected: expected.element.clone(),
+                actual: actual.element.clone(),
+            });
+        }
+
+        if expected.len() != self.layouts.len() {
+            return Err(SnapshotMismatch::ElementCount {
+                expected: expected.len(),
+                actual: self.layouts.len(),
+            });
+        }
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn save(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "{}\t{}", Self::HEADER, self.layouts.len())?;
+
+        let mut values = self.values.iter();
+        for layout in &self.layouts {
+            writeln!(
+                writer,
+                "{}\t{}\t{}\t{}",
+                Self::ELEMENT_KEY,
+                layout.element,
+                layout.signature,
+                layout.count
+            )?;
+            for value in values.by_ref().take(layout.count) {
+                writeln!(writer, "{:?}", value)?;
+            }
+        }
+
+        writer.flush()
+    }
+
+    pub fn load(reader: impl BufRead) -> io::Result<Self> {
+        let invalid = |line: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid snapshot line: '{}'", line),
+            )
+        };
+
+        let mut lines = reader.lines();
+        let header = lines.next().transpose()?.unwrap_or_default();
+        let element_count: usize = match header.split_once('\t') {
+            Some((Self::HEADER, count)) => count.parse().map_err(|_| invalid(&header))?,
+            _ => return Err(invalid(&header)),
+        };
+
+        let mut snapshot = Self::default();
+        for _ in 0..element_count {
+            let line = lines.next().transpose()?.unwrap_or_default();
+            let layout = match line.split('\t').collect::<Vec<_>>()[..] {
+                [Self::ELEMENT_KEY, element, signature, count] => SnapshotLayout {
+                    element: element.to_owned(),
+                    signature: signature.parse().map_err(|_| invalid(&line))?,
+                    count: count.parse().map_err(|_| invalid(&line))?,
+                },
+                _ => return Err(invalid(&line)),
+            };
+
+            for _ in 0..layout.count {
+                let line = lines.next().transpose()?.unwrap_or_default();
+                snapshot
+                    .values
+                    .push(line.trim().parse().map_err(|_| invalid(&line))?);
+            }
+            snapshot.layouts.push(layout);
+        }
+
+        match lines.next().transpose()? {
+            Some(line) if !line.trim().is_empty() => Err(invalid(&line)),
+            _ => Ok(snapshot),
+        }
+    }
+}
+
+/// Returned when a snapshot doesn't match the aircraft it is restored into.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotMismatch {
+    /// The aircraft has a different number of elements with state than the snapshot.
+    ElementCount { expected: usize, actual: usize },
+    /// The element at the given index differs in its type or the layout of its values.
+    Element {
+        index: usize,
+        expected: String,
+        actual: String,
+    },
+}
+impl Display for SnapshotMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotMismatch::ElementCount { expected, actual } => write!(
+                f,
+                "The aircraft expects {} snapshot elements but the snapshot contains {}",
+                expected, actual
+            ),
+            SnapshotMismatch::Element {
+                index,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Snapshot element {} of type {} doesn't match the aircraft element of type {}",
+                index, actual, expected
+            ),
+        }
+    }
+}
+impl Error for SnapshotMismatch {}
+
+/// A value which can be stored in a [`SimulationSnapshot`].
+/// Quantities are stored in their base unit.
+pub trait SnapshotValue {
+    fn to_snapshot(self) -> f64;
+    fn from_snapshot(value: f64) -> Self;
+}
+impl SnapshotValue for f64 {
+    fn to_snapshot(self) -> f64 {
+        self
+    }
+
+    fn from_snapshot(value: f64) -> Self {
+        value
+    }
+}
+impl SnapshotValue for bool {
+    fn to_snapshot(self) -> f64 {
+        from_bool(self)
+    }
+
+    fn from_snapshot(value: f64) -> Self {
+        to_bool(value)
+    }
+}
+impl SnapshotValue for Duration {
+    fn to_snapshot(self) -> f64 {
+        self.as_secs_f64()
+    }
+
+    fn from_snapshot(value: f64) -> Self {
+        Duration::from_secs_f64(value.max(0.))
+    }
+}
+impl<D, U> SnapshotValue for Quantity<D, U, f64>
+where
+    D: Dimension + ?Sized,
+    U: Units<f64> + ?Sized,
+{
+    fn to_snapshot(self) -> f64 {
+        self.value
+    }
+
+    fn from_snapshot(value: f64) -> Self {
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value,
+        }
+    }
+}
+
+/// Saves the state of a [`SimulationElement`] into a [`SimulationSnapshot`].
+pub struct SnapshotWriter<'a> {
+    values: &'a mut Vec<f64>,
+    signature: FxHasher64,
+}
+impl SnapshotWriter<'_> {
+    pub fn write<T: SnapshotValue>(&mut self, value: T) {
+        type_name::<T>().hash(&mut self.signature);
+        self.values.push(value.to_snapshot());
+    }
+}
+
+/// Restores the state of a [`SimulationElement`] from a [`SimulationSnapshot`].
+/// Values must be read in the order in which they were written.
+pub struct SnapshotReader<'a> {
+    values: Iter<'a, f64>,
+}
+impl SnapshotReader<'_> {
+    pub fn read<T: SnapshotValue>(&mut self) -> T {
+        T::from_snapshot(
+            *self
+                .values
+                .next()
+                .expect("Snapshot length was verified before restoring."),
+        )
+    }
+}
+
+struct SimulationToSnapshotVisitor<'a> {
+    layouts: &'a mut Vec<SnapshotLayout>,
+    writer: SnapshotWriter<'a>,
+}
+impl SimulationElementVisitor for SimulationToSnapshotVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        let start = self.writer.values.len();
+        self.writer.signature = FxHasher64::default();
+
+        visited.snapshot(&mut self.writer);
+
+        let count = self.writer.values.len() - start;
+        if count > 0 {
+            self.layouts.push(SnapshotLayout {
+                element: type_name::<T>().to_owned(),
+                signature: self.writer.signature.finish(),
+                count,
+            });
+        }
+    }
+}
+
+struct SnapshotToSimulationVisitor<'a> {
+    reader: SnapshotReader<'a>,
+}
+impl SimulationElementVisitor for SnapshotToSimulationVisitor<'_> {
+    fn visit<T: SimulationElement>(&mut self, visited: &mut T) {
+        visited.restore(&mut self.reader);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uom::si::{f64::*, pressure::psi, volume::gallon};
+
+    #[derive(Default)]
+    struct Element {
+        volume: Volume,
+        pressure: Pressure,
+        is_on: bool,
+    }
+    impl SimulationElement for Element {
+        fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+            snapshot.write(self.volume);
+            snapshot.write(self.pressure);
+            snapshot.write(self.is_on);
+        }
+
+        fn restore(&mut self, snapshot: &mut SnapshotReader) {
+            self.volume = snapshot.read();
+            self.pressure = snapshot.read();
+            self.is_on = snapshot.read();
+        }
+    }
+
+    #[derive(Default)]
+    struct Parent {
+        children: [Element; 2],
+        time: Duration,
+    }
+    impl SimulationElement for Parent {
+        fn accept<T: SimulationElementVisitor>(&mut self, visitor: &mut T) {
+            self.children[0].accept(visitor);
+            self.children[1].accept(visitor);
+
+            visitor.visit(self);
+        }
+
+        fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+            snapshot.write(self.time);
+        }
+
+        fn restore(&mut self, snapshot: &mut SnapshotReader) {
+            self.time = snapshot.read();
+        }
+    }
+
+    fn parent() -> Parent {
+        let mut parent = Parent::default();
+        parent.children[0].volume = Volume::new::<gallon>(1.5);
+        parent.children[1].pressure = Pressure::new::<psi>(3000.);
+        parent.children[1].is_on = true;
+        parent.time = Duration::from_millis(1500);
+
+        parent
+    }
+
+    #[derive(Default)]
+    struct OtherElement {
+        volume: Volume,
+        is_on: bool,
+        pressure: Pressure,
+    }
+    impl SimulationElement for OtherElement {
+        fn snapshot(&self, snapshot: &mut SnapshotWriter) {
+            snapshot.write(self.volume);
+            snapshot.write(self.is_on);
+            snapshot.write(self.pressure);
+        }
+
+        fn restore(&mut self, snapshot: &mut SnapshotReader) {
+            self.volume = snapshot.read();
+            self.is_on = snapshot.read();
+            self.pressure = snapshot.read();
+        }
+    }
+
+    #[test]
+    fn snapshot_contains_the_values_of_all_elements() {
+        let snapshot = SimulationSnapshot::take(&mut parent());
+
+        assert_eq!(snapshot.len(), 7);
+        assert_eq!(snapshot.layouts.len(), 3);
+    }
+
+    #[test]
+    fn restoring_a_snapshot_restores_all_elements() {
+        let snapshot = SimulationSnapshot::take(&mut parent());
+
+        let mut restored = Parent::default();
+        snapshot.restore_into(&mut restored).unwrap();
+
+        assert!((restored.children[0].volume.get::<gallon>() - 1.5).abs() < 1e-9);
+        assert!((restored.children[1].pressure.get::<psi>() - 3000.).abs() < 1e-9);
+        assert!(!restored.children[0].is_on);
+        assert!(restored.children[1].is_on);
+        assert_eq!(restored.time, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_of_another_aircraft_fails() {
+        let snapshot = SimulationSnapshot::take(&mut Element::default());
+
+        assert!(matches!(
+            snapshot.restore_into(&mut Parent::default()),
+            Err(SnapshotMismatch::ElementCount {
+                expected: 3,
+                actual: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn restoring_a_snapshot_with_the_same_length_but_another_layout_fails() {
+        let snapshot = SimulationSnapshot::take(&mut Element::default());
+
+        let mut other = OtherElement::default();
+        assert!(matches!(
+            snapshot.restore_into(&mut other),
+            Err(SnapshotMismatch::Element { index: 0, .. })
+        ));
+        assert!(!other.is_on);
+    }
+
+    #[test]
+    fn saved_snapshot_can_be_loaded() {
+        let snapshot = SimulationSnapshot::take(&mut parent());
+
+        let mut buffer = Vec::new();
+        snapshot.save(&mut buffer).unwrap();
+        let loaded = SimulationSnapshot::load(buffer.as_slice()).unwrap();
+
+        assert_eq!(loaded, snapshot);
+    }
+
+    #[test]
+    fn loading_a_truncated_snapshot_fails() {
+        let result =
+            SimulationSnapshot::load("snapshot\t1\nelement\tElement\t0\t3\n1.0\n2.0\n".as_bytes());
+
+        assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+    }
+}
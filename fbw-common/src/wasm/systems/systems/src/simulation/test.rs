@@ -19,8 +19,8 @@ use crate::{
 
 use super::{
     Aircraft, Read, Reader, Simulation, SimulationElement, SimulationElementVisitor,
-    SimulationToSimulatorVisitor, SimulatorReaderWriter, SimulatorWriter, UpdateContext, Write,
-    Writer,
+    SimulationSnapshot, SimulationToSimulatorVisitor, SimulatorReaderWriter, SimulatorWriter,
+    SnapshotMismatch, UpdateContext, Write, Writer,
 };
 use crate::landing_gear::LandingGear;
 use crate::shared::arinc429::{from_arinc429, to_arinc429, Arinc429Word, SignStatus};
@@ -55,6 +55,14 @@ pub trait TestBed {
         self.test_bed_mut().unfail(failure_type);
     }
 
+    fn snapshot(&mut self) -> SimulationSnapshot {
+        self.test_bed_mut().snapshot()
+    }
+
+    fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotMismatch> {
+        self.test_bed_mut().restore(snapshot)
+    }
+
     fn command<V: FnOnce(&mut Self::Aircraft)>(&mut self, func: V) {
         self.test_bed_mut().command(func);
     }
@@ -329,6 +337,19 @@ impl<T: Aircraft> SimulationTestBed<T> {
         self.simulation.deactivate_failure(failure_type);
     }
 
+    fn snapshot(&mut self) -> SimulationSnapshot {
+        self.simulation.snapshot()
+    }
+
+    /// Restores the aircraft state and writes it to the simulator variables,
+    /// such that they reflect the restored state before the next tick.
+    fn restore(&mut self, snapshot: &SimulationSnapshot) -> Result<(), SnapshotMismatch> {
+        self.simulation.restore(snapshot)?;
+        self.seed();
+
+        Ok(())
+    }
+
     fn aircraft(&self) -> &T {
         self.simulation.aircraft()
     }
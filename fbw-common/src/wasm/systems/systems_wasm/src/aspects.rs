@@ -7,7 +7,7 @@ use msfs::sim_connect::{SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER};
 use msfs::sys;
 use std::error::Error;
 use std::time::{Duration, Instant};
-use systems::simulation::VariableIdentifier;
+use systems::simulation::{manifest::VariableDirection, VariableIdentifier};
 
 /// A concern that should be handled by the bridging layer. Examples are
 /// the handling of events which move flaps up and down, triggering of brakes, etc.
@@ -69,6 +69,7 @@ pub struct MsfsAspectBuilder<'a, 'b> {
     variables: &'a mut MsfsVariableRegistry,
     message_handlers: Vec<MessageHandler>,
     actions: Vec<(VariableAction, ExecuteOn)>,
+    accesses: Vec<(VariableIdentifier, VariableDirection)>,
 }
 
 impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
@@ -81,6 +82,7 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
             variables,
             message_handlers: Default::default(),
             actions: Default::default(),
+            accesses: Default::default(),
         }
     }
 
@@ -88,11 +90,16 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
         MsfsAspect::new(self.message_handlers, self.actions)
     }
 
+    /// The variables read and written by the aspect, used to complete the variable manifest.
+    pub(super) fn accesses(&self) -> &[(VariableIdentifier, VariableDirection)] {
+        &self.accesses
+    }
+
     /// Initialise the variable with the given value.
     pub fn init_variable(&mut self, variable: Variable, value: f64) {
         Self::precondition_not_aircraft_variable(&variable);
 
-        let identifier = self.variables.register(&variable);
+        let identifier = self.register(&variable, VariableDirection::Written);
         self.variables.write(&identifier, value);
     }
 
@@ -100,8 +107,8 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
     pub fn copy(&mut self, input: Variable, output: Variable) {
         Self::precondition_not_aircraft_variable(&output);
 
-        let input = self.variables.register(&input);
-        let output = self.variables.register(&output);
+        let input = self.register(&input, VariableDirection::Read);
+        let output = self.register(&output, VariableDirection::Written);
 
         self.actions.push((
             Map::new(input, |value| value, output).into(),
@@ -119,8 +126,8 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
     ) {
         Self::precondition_not_aircraft_variable(&output);
 
-        let inputs = self.variables.register(&input);
-        let output = self.variables.register(&output);
+        let inputs = self.register(&input, VariableDirection::Read);
+        let output = self.register(&output, VariableDirection::Written);
 
         self.actions
             .push((Map::new(inputs, func, output).into(), execute_on));
@@ -136,8 +143,8 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
     ) {
         Self::precondition_not_aircraft_variable(&output);
 
-        let inputs = self.variables.register_many(&inputs);
-        let output = self.variables.register(&output);
+        let inputs = self.register_many(&inputs, VariableDirection::Read);
+        let output = self.register(&output, VariableDirection::Written);
 
         self.actions
             .push((MapMany::new(inputs, func, output).into(), execute_on));
@@ -154,8 +161,8 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
     ) {
         Self::precondition_not_aircraft_variable(&output);
 
-        let inputs = self.variables.register_many(&inputs);
-        let output = self.variables.register(&output);
+        let inputs = self.register_many(&inputs, VariableDirection::Read);
+        let output = self.register(&output, VariableDirection::Written);
 
         self.actions
             .push((Reduce::new(inputs, init, func, output).into(), execute_on));
@@ -163,7 +170,7 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
 
     /// Write a set of variables to an object.
     pub fn variables_to_object(&mut self, instance: Box<dyn VariablesToObject>) {
-        let variables = self.variables.register_many(&instance.variables());
+        let variables = self.register_many(&instance.variables(), VariableDirection::Read);
 
         self.actions.push((
             ToObject::new(instance, variables).into(),
@@ -184,7 +191,7 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
     ) -> Result<sys::DWORD, Box<dyn Error>> {
         Self::precondition_not_aircraft_variable(&target);
 
-        let target = self.variables.register(&target);
+        let target = self.register(&target, VariableDirection::Written);
 
         let event_to_variable = EventToVariable::new(
             self.sim_connect,
@@ -210,7 +217,7 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
         write_on: VariableToEventWriteOn,
         event_name: &str,
     ) -> Result<(), Box<dyn Error>> {
-        let input = self.variables.register(&input);
+        let input = self.register(&input, VariableDirection::Read);
 
         self.actions.push((
             ToEvent::new(self.sim_connect, input, mapping, write_on, event_name)?.into(),
@@ -229,7 +236,7 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
         write_on: VariableToEventWriteOn,
         event_id: sys::DWORD,
     ) {
-        let input = self.variables.register(&input);
+        let input = self.register(&input, VariableDirection::Read);
 
         self.actions.push((
             ToEvent::new_with_event_id(input, mapping, write_on, event_id).into(),
@@ -239,7 +246,7 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
 
     /// Execute the given function whenever any of the observed variable values changes.
     pub fn on_change(&mut self, execute_on: ExecuteOn, observed: Vec<Variable>, func: OnChangeFn) {
-        let observed = self.variables.register_many(&observed);
+        let observed = self.register_many(&observed, VariableDirection::Read);
         let starting_values = self.variables.read_many(&observed);
 
         self.actions.push((
@@ -248,6 +255,28 @@ impl<'a, 'b> MsfsAspectBuilder<'a, 'b> {
         ));
     }
 
+    fn register(
+        &mut self,
+        variable: &Variable,
+        direction: VariableDirection,
+    ) -> VariableIdentifier {
+        let identifier = self.variables.register(variable);
+        self.accesses.push((identifier, direction));
+
+        identifier
+    }
+
+    fn register_many(
+        &mut self,
+        variables: &[Variable],
+        direction: VariableDirection,
+    ) -> Vec<VariableIdentifier> {
+        variables
+            .iter()
+            .map(|variable| self.register(variable, direction))
+            .collect()
+    }
+
     fn precondition_not_aircraft_variable(variable: &Variable) {
         if matches!(variable, Variable::Aircraft(..)) {
             eprintln!("Writing to variable '{}' is unsupported.", variable);
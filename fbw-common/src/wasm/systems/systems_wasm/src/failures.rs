@@ -26,19 +26,19 @@ impl Failures {
             .insert(identifier, failure_type);
     }
 
-    pub(super) fn read_failure_activate(&self) -> Option<FailureType> {
+    pub(super) fn read_failure_activate(&self) -> Option<(u64, FailureType)> {
         self.read_failure(&self.activate_sim_var)
     }
 
-    pub(super) fn read_failure_deactivate(&self) -> Option<FailureType> {
+    pub(super) fn read_failure_deactivate(&self) -> Option<(u64, FailureType)> {
         self.read_failure(&self.deactivate_sim_var)
     }
 
-    fn read_failure(&self, from: &NamedVariable) -> Option<FailureType> {
-        let identifier: f64 = from.get_value();
-        if let Some(failure_type) = self.identifier_to_failure_type.get(&(identifier as u64)) {
+    fn read_failure(&self, from: &NamedVariable) -> Option<(u64, FailureType)> {
+        let identifier = from.get_value() as u64;
+        if let Some(failure_type) = self.identifier_to_failure_type.get(&identifier) {
             from.set_value(0.);
-            Some(*failure_type)
+            Some((identifier, *failure_type))
         } else {
             None
         }
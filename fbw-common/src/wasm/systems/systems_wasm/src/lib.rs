@@ -3,13 +3,14 @@ pub mod aspects;
 mod electrical;
 mod failures;
 mod msfs;
+mod snapshots;
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::msfs::legacy::{AircraftVariable, NamedVariable};
 #[cfg(target_arch = "wasm32")]
 use ::msfs::legacy::{AircraftVariable, NamedVariable};
 
-use crate::aspects::{Aspect, ExecuteOn, MsfsAspectBuilder};
+use crate::aspects::{Aspect, ExecuteOn, MsfsAspect, MsfsAspectBuilder};
 use crate::electrical::{auxiliary_power_unit, electrical_buses};
 use ::msfs::{
     sim_connect::{data_definition, Period, SimConnect, SimConnectRecv, SIMCONNECT_OBJECT_ID_USER},
@@ -17,10 +18,16 @@ use ::msfs::{
 };
 use failures::Failures;
 use fxhash::FxHashMap;
+use snapshots::Snapshots;
+use std::any::type_name;
 use std::fmt::{Display, Formatter};
-use std::{error::Error, time::Duration};
-use systems::shared::ElectricalBusType;
-use systems::simulation::{InitContext, StartState};
+use std::{error::Error, fs::File, io::BufWriter, time::Duration};
+use systems::shared::{random_number, ElectricalBusType};
+use systems::simulation::{
+    manifest::{VariableAccess, VariableDirection, VariableManifest},
+    recording::SimulationRecorder,
+    InitContext, StartState,
+};
 use systems::{
     failures::FailureType,
     simulation::{
@@ -28,6 +35,8 @@ use systems::{
     },
 };
 
+type Recorder = SimulationRecorder<BufWriter<File>>;
+
 /// Type used to configure and build a simulation and a handler which acts as a bridging layer
 /// between the simulation and Microsoft Flight Simulator.
 pub struct MsfsSimulationBuilder<'a, 'b> {
@@ -37,6 +46,10 @@ pub struct MsfsSimulationBuilder<'a, 'b> {
     sim_connect: &'a mut SimConnect<'b>,
     failures: Option<Failures>,
     aspects: Vec<Box<dyn Aspect>>,
+    aspect_accesses: Vec<(VariableIdentifier, VariableDirection)>,
+    recording_file_name: Option<String>,
+    manifest_file_name: Option<String>,
+    snapshots: Option<Snapshots>,
 }
 
 impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
@@ -54,19 +67,60 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
             sim_connect,
             failures: None,
             aspects: vec![],
+            aspect_accesses: vec![],
+            recording_file_name: None,
+            manifest_file_name: None,
+            snapshots: None,
         }
     }
 
-    pub fn build<T: Aircraft, U: FnOnce(&mut InitContext) -> T>(
+    pub fn build<T: Aircraft, U: Fn(&mut InitContext) -> T>(
         self,
         aircraft_ctor_fn: U,
     ) -> Result<(Simulation<T>, MsfsHandler), Box<dyn Error>> {
         let mut registry = self.variable_registry.unwrap();
-        let simulation = Simulation::new(self.start_state, aircraft_ctor_fn, &mut registry);
+        if let Some(file_name) = &self.manifest_file_name {
+            Self::write_manifest(
+                file_name,
+                self.start_state,
+                &aircraft_ctor_fn,
+                &registry,
+                &self.aspect_accesses,
+            )?;
+        }
+
+        let (simulation, recorder) = match self.recording_file_name {
+            Some(file_name) => {
+                let random_seed = u64::from_le_bytes([(); 8].map(|_| random_number()));
+                let mut recorder = SimulationRecorder::new(
+                    BufWriter::new(File::create(file_name)?),
+                    self.start_state,
+                    random_seed,
+                )?;
+                let simulation = Simulation::new(
+                    self.start_state,
+                    aircraft_ctor_fn,
+                    &mut recorder.variable_registry(&mut registry),
+                );
+
+                (simulation, Some(recorder))
+            }
+            None => (
+                Simulation::new(self.start_state, aircraft_ctor_fn, &mut registry),
+                None,
+            ),
+        };
 
         Ok((
             simulation,
-            MsfsHandler::new(registry, self.aspects, self.failures, self.sim_connect)?,
+            MsfsHandler::new(
+                registry,
+                self.aspects,
+                self.failures,
+                recorder,
+                self.snapshots,
+                self.sim_connect,
+            )?,
         ))
     }
 
@@ -79,6 +133,7 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         let variable_registry = &mut self.variable_registry.as_mut().unwrap();
         let mut builder = MsfsAspectBuilder::new(self.sim_connect, variable_registry);
         (builder_func)(&mut builder)?;
+        self.aspect_accesses.extend_from_slice(builder.accesses());
         self.aspects.push(Box::new(builder.build()));
 
         Ok(self)
@@ -107,6 +162,47 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         ))
     }
 
+    /// Records the session to the given file when the given variable is set at the time the
+    /// simulation is built. The recording can be replayed using
+    /// [`systems::simulation::recording::replay`].
+    pub fn with_recording(mut self, enabled_variable: Variable, file_name: &str) -> Self {
+        let enabled_variable_value: VariableValue = (&enabled_variable).into();
+        if enabled_variable_value.read() > 0. {
+            self.recording_file_name = Some(file_name.to_owned());
+        }
+
+        self
+    }
+
+    /// Writes the manifest of all variables the aircraft and its aspects read and write to the
+    /// given file when the given variable is set at the time the simulation is built.
+    /// See [`systems::simulation::manifest::VariableManifest`].
+    pub fn with_manifest(mut self, enabled_variable: Variable, file_name: &str) -> Self {
+        let enabled_variable_value: VariableValue = (&enabled_variable).into();
+        if enabled_variable_value.read() > 0. {
+            self.manifest_file_name = Some(file_name.to_owned());
+        }
+
+        self
+    }
+
+    /// Saves the state of the aircraft systems to the given file when `SYSTEMS_SNAPSHOT_SAVE`
+    /// is set, and restores it from that file when `SYSTEMS_SNAPSHOT_RESTORE` is set.
+    /// Snapshots are unavailable while recording, as the recording wouldn't contain the
+    /// restored state.
+    pub fn with_snapshots(mut self, file_name: &str) -> Self {
+        self.snapshots = Some(Snapshots::new(
+            NamedVariable::from(&format!("{}{}", &self.key_prefix, "SYSTEMS_SNAPSHOT_SAVE")),
+            NamedVariable::from(&format!(
+                "{}{}",
+                &self.key_prefix, "SYSTEMS_SNAPSHOT_RESTORE"
+            )),
+            file_name,
+        ));
+
+        self
+    }
+
     pub fn with_failures(mut self, failures: Vec<(u64, FailureType)>) -> Self {
         let mut f = Failures::new(
             NamedVariable::from(&format!("{}{}", &self.key_prefix, "FAILURE_ACTIVATE")),
@@ -138,6 +234,34 @@ impl<'a, 'b> MsfsSimulationBuilder<'a, 'b> {
         Ok(self)
     }
 
+    /// The manifest is built from a separately constructed aircraft, such that the aircraft
+    /// which is simulated isn't affected by it.
+    fn write_manifest<T: Aircraft, U: Fn(&mut InitContext) -> T>(
+        file_name: &str,
+        start_state: StartState,
+        aircraft_ctor_fn: &U,
+        registry: &MsfsVariableRegistry,
+        aspect_accesses: &[(VariableIdentifier, VariableDirection)],
+    ) -> Result<(), Box<dyn Error>> {
+        let mut manifest = VariableManifest::new(start_state, aircraft_ctor_fn);
+        for (identifier, direction) in aspect_accesses {
+            if let Some(name) = registry.name_of(identifier) {
+                manifest.add_access(
+                    name,
+                    VariableAccess {
+                        direction: *direction,
+                        element: type_name::<MsfsAspect>(),
+                        unit: None,
+                    },
+                );
+            }
+        }
+
+        manifest.save(&mut BufWriter::new(File::create(file_name)?))?;
+
+        Ok(())
+    }
+
     pub fn provides_named_variable(mut self, name: &str) -> Result<Self, Box<dyn Error>> {
         if let Some(registry) = &mut self.variable_registry {
             registry.register(&Variable::Named(name.to_owned(), false));
@@ -152,6 +276,8 @@ pub struct MsfsHandler {
     variables: Option<MsfsVariableRegistry>,
     aspects: Vec<Box<dyn Aspect>>,
     failures: Option<Failures>,
+    recorder: Option<Recorder>,
+    snapshots: Option<Snapshots>,
     time: Time,
 }
 impl MsfsHandler {
@@ -159,12 +285,16 @@ impl MsfsHandler {
         variables: MsfsVariableRegistry,
         aspects: Vec<Box<dyn Aspect>>,
         failures: Option<Failures>,
+        recorder: Option<Recorder>,
+        snapshots: Option<Snapshots>,
         sim_connect: &mut SimConnect,
     ) -> Result<Self, Box<dyn Error>> {
         Ok(Self {
             variables: Some(variables),
             aspects,
             failures,
+            recorder,
+            snapshots,
             time: Time::new(sim_connect)?,
         })
     }
@@ -180,11 +310,29 @@ impl MsfsHandler {
                 if !self.time.is_pausing() {
                     let delta_time = self.time.take();
                     self.pre_tick(sim_connect, delta_time)?;
+
+                    let mut recorder = self.recorder.take();
+                    if let (Some(snapshots), None) = (&self.snapshots, &recorder) {
+                        snapshots.handle(simulation);
+                    }
+
                     if let Some(failures) = &self.failures {
-                        Self::read_failures_into_simulation(failures, simulation);
+                        Self::read_failures_into_simulation(
+                            failures,
+                            simulation,
+                            recorder.as_mut(),
+                        );
                     }
 
-                    simulation.tick(delta_time, self.time.simulation_time(), self);
+                    let simulation_time = self.time.simulation_time();
+                    match recorder.as_mut() {
+                        Some(recorder) => {
+                            recorder.tick(simulation, delta_time, simulation_time, self)?
+                        }
+                        None => simulation.tick(delta_time, simulation_time, self),
+                    }
+                    self.recorder = recorder;
+
                     self.post_tick(sim_connect)?;
                 }
             }
@@ -246,16 +394,24 @@ impl MsfsHandler {
         Ok(())
     }
 
+    /// Failures are passed through the recorder when recording, such that they are replayed.
     fn read_failures_into_simulation<T: Aircraft>(
         failures: &Failures,
         simulation: &mut Simulation<T>,
+        mut recorder: Option<&mut Recorder>,
     ) {
-        if let Some(failure_type) = failures.read_failure_activate() {
-            simulation.activate_failure(failure_type);
+        if let Some((identifier, failure_type)) = failures.read_failure_activate() {
+            match recorder.as_deref_mut() {
+                Some(recorder) => recorder.activate_failure(simulation, identifier, failure_type),
+                None => simulation.activate_failure(failure_type),
+            }
         }
 
-        if let Some(failure_type) = failures.read_failure_deactivate() {
-            simulation.deactivate_failure(failure_type);
+        if let Some((identifier, failure_type)) = failures.read_failure_deactivate() {
+            match recorder {
+                Some(recorder) => recorder.deactivate_failure(simulation, identifier, failure_type),
+                None => simulation.deactivate_failure(failure_type),
+            }
         }
     }
 }
@@ -514,6 +670,13 @@ impl MsfsVariableRegistry {
         self.variables[identifier.identifier_type()][identifier.identifier_index()].read()
     }
 
+    fn name_of(&self, identifier: &VariableIdentifier) -> Option<&str> {
+        self.name_to_identifier
+            .iter()
+            .find(|(_, registered)| *registered == identifier)
+            .map(|(name, _)| name.as_str())
+    }
+
     fn read_many(&self, identifiers: &[VariableIdentifier]) -> Vec<f64> {
         identifiers
             .iter()
@@ -0,0 +1,73 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::msfs::legacy::NamedVariable;
+#[cfg(target_arch = "wasm32")]
+use msfs::legacy::NamedVariable;
+
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufReader, BufWriter},
+};
+
+use systems::simulation::{Aircraft, Simulation, SimulationSnapshot};
+
+/// Saves the state of the simulation to a file when the save variable is set,
+/// and restores it from that file when the restore variable is set.
+pub(super) struct Snapshots {
+    save_sim_var: NamedVariable,
+    restore_sim_var: NamedVariable,
+    file_name: String,
+}
+impl Snapshots {
+    pub(super) fn new(
+        save_sim_var: NamedVariable,
+        restore_sim_var: NamedVariable,
+        file_name: &str,
+    ) -> Self {
+        Self {
+            save_sim_var,
+            restore_sim_var,
+            file_name: file_name.to_owned(),
+        }
+    }
+
+    /// Handles pending save and restore requests. A failed request doesn't stop the
+    /// simulation, the aircraft then simply keeps its current state.
+    pub(super) fn handle<T: Aircraft>(&self, simulation: &mut Simulation<T>) {
+        if Self::is_requested(&self.save_sim_var) {
+            if let Err(error) = self.save(simulation) {
+                eprintln!("Saving the systems snapshot failed: {}", error);
+            }
+        }
+
+        if Self::is_requested(&self.restore_sim_var) {
+            if let Err(error) = self.restore(simulation) {
+                eprintln!("Restoring the systems snapshot failed: {}", error);
+            }
+        }
+    }
+
+    fn is_requested(sim_var: &NamedVariable) -> bool {
+        let is_requested = sim_var.get_value() > 0.;
+        if is_requested {
+            sim_var.set_value(0.);
+        }
+
+        is_requested
+    }
+
+    fn save<T: Aircraft>(&self, simulation: &mut Simulation<T>) -> Result<(), Box<dyn Error>> {
+        simulation
+            .snapshot()
+            .save(&mut BufWriter::new(File::create(&self.file_name)?))?;
+
+        Ok(())
+    }
+
+    fn restore<T: Aircraft>(&self, simulation: &mut Simulation<T>) -> Result<(), Box<dyn Error>> {
+        let snapshot = SimulationSnapshot::load(BufReader::new(File::open(&self.file_name)?))?;
+        simulation.restore(&snapshot)?;
+
+        Ok(())
+    }
+}